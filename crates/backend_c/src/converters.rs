@@ -1,7 +1,7 @@
 use crate::Interop;
 use crate::interop::{EnumVariants, ToNamingStyle};
 use interoptopus::lang::util::safe_name;
-use interoptopus::lang::{Composite, Constant, ConstantValue, Enum, FnPointer, Function, Opaque, Primitive, PrimitiveValue, Type, Variant};
+use interoptopus::lang::{Composite, Constant, ConstantValue, Enum, FnPointer, Function, Opaque, Primitive, PrimitiveValue, Type, Variant, VariantKind};
 use interoptopus::pattern::TypePattern;
 use interoptopus::pattern::callback::NamedCallback;
 
@@ -21,6 +21,9 @@ pub fn primitive_to_typename(x: Primitive) -> String {
         Primitive::Isize => "ptrdiff_t".to_string(),
         Primitive::F32 => "float".to_string(),
         Primitive::F64 => "double".to_string(),
+        Primitive::Char => "uint32_t".to_string(),
+        Primitive::U128 => "unsigned __int128".to_string(),
+        Primitive::I128 => "__int128".to_string(),
     }
 }
 
@@ -28,6 +31,14 @@ pub fn enum_to_typename(g: &Interop, x: &Enum) -> String {
     format!("{}{}", g.prefix, x.rust_name()).to_naming_style(&g.enum_variant_naming)
 }
 
+pub fn enum_tag_to_typename(g: &Interop, x: &Enum) -> String {
+    format!("{}{}Tag", g.prefix, x.rust_name()).to_naming_style(&g.enum_variant_naming)
+}
+
+pub fn is_data_enum(x: &Enum) -> bool {
+    x.variants().iter().any(|v| matches!(v.kind(), VariantKind::Typed(_, t) if !t.is_void()))
+}
+
 pub fn enum_variant_to_name(g: &Interop, the_enum: &Enum, x: &Variant) -> String {
     if g.enum_variant_style == EnumVariants::WithEnumName {
         format!("{}{}_{}", g.prefix, the_enum.rust_name().to_naming_style(&g.enum_variant_naming), x.name()).to_naming_style(&g.enum_variant_naming)
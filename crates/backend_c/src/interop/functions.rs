@@ -40,7 +40,7 @@ pub fn write_function_declaration(i: &Interop, w: &mut IndentWriter, function: &
     for p in function.signature().params() {
         match p.the_type() {
             Type::Array(a) => {
-                params.push(format!("{} {}[{}]", to_type_specifier(i, a.the_type()), p.name().to_naming_style(&i.function_parameter_naming), a.len(),));
+                params.push(format!("{} {}[{}]", to_type_specifier(i, a.the_type()), p.name().to_naming_style(&i.function_parameter_naming), a.len()));
             }
             _ => {
                 params.push(format!("{} {}", to_type_specifier(i, p.the_type()), p.name().to_naming_style(&i.function_parameter_naming)));
@@ -73,10 +73,10 @@ fn write_function_as_typedef_declaration(i: &Interop, w: &mut IndentWriter, func
     for p in function.signature().params() {
         match p.the_type() {
             Type::Array(a) => {
-                params.push(format!("{} [{}]", to_type_specifier(i, a.the_type()), a.len(),));
+                params.push(format!("{} [{}]", to_type_specifier(i, a.the_type()), a.len()));
             }
             _ => {
-                params.push(to_type_specifier(i, p.the_type()).to_string());
+                params.push(to_type_specifier(i, p.the_type()));
             }
         }
     }
@@ -1,9 +1,11 @@
 use crate::converters::{
-    composite_to_typename, enum_to_typename, enum_variant_to_name, fnpointer_to_typename, named_callback_to_typename, opaque_to_typename, to_type_specifier,
+    composite_to_typename, enum_tag_to_typename, enum_to_typename, enum_variant_to_name, fnpointer_to_typename, is_data_enum, named_callback_to_typename,
+    opaque_to_typename, to_type_specifier,
 };
 use crate::interop::ToNamingStyle;
 use crate::interop::docs::write_documentation;
 use crate::{DocStyle, Indentation, Interop};
+use heck::ToSnakeCase;
 use interoptopus::lang::util::sort_types_by_dependencies;
 use interoptopus::lang::{Composite, Enum, Field, FnPointer, Opaque, Type, Variant, VariantKind};
 use interoptopus::pattern::TypePattern;
@@ -60,6 +62,14 @@ pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type,
                 write_type_definition_composite(i, w, x.composite_type())?;
                 w.newline()?;
             }
+            TypePattern::NdSlice(x) => {
+                write_type_definition_composite(i, w, x.composite_type())?;
+                w.newline()?;
+            }
+            TypePattern::NdSliceMut(x) => {
+                write_type_definition_composite(i, w, x.composite_type())?;
+                w.newline()?;
+            }
             TypePattern::Option(x) => {
                 write_type_definition_enum(i, w, x.the_enum())?;
                 w.newline()?;
@@ -68,6 +78,10 @@ pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type,
                 write_type_definition_composite(i, w, x)?;
                 w.newline()?;
             }
+            TypePattern::Utf8Str(x) => {
+                write_type_definition_composite(i, w, x)?;
+                w.newline()?;
+            }
             TypePattern::Result(x) => {
                 write_type_definition_enum(i, w, x.the_enum())?;
                 w.newline()?;
@@ -83,6 +97,22 @@ pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type,
                 write_type_definition_composite(i, w, x.composite_type())?;
                 w.newline()?;
             }
+            TypePattern::Map(x) => {
+                write_type_definition_composite(i, w, x.composite_type())?;
+                w.newline()?;
+            }
+            TypePattern::Iter(x) => {
+                write_type_definition_composite(i, w, x.composite_type())?;
+                w.newline()?;
+            }
+            TypePattern::Tuple(x) => {
+                write_type_definition_composite(i, w, x.composite_type())?;
+                w.newline()?;
+            }
+            TypePattern::Duration(x) => {
+                write_type_definition_composite(i, w, x)?;
+                w.newline()?;
+            }
         },
     }
     Ok(())
@@ -124,12 +154,25 @@ fn write_type_definition_named_callback_body(i: &Interop, w: &mut IndentWriter,
         params.push(format!("{} {}", to_type_specifier(i, param.the_type()), param.name().to_naming_style(&i.function_parameter_naming)));
     }
 
+    // Owned callbacks also carry their data and a destructor Rust invokes when dropping them.
+    if the_type.is_owned() {
+        write_braced_declaration_opening(i, w, format!(r"typedef struct {name}").as_str())?;
+        indented!(w, "{}", format!("{} (*callback)({});", rval, params.join(", ")))?;
+        indented!(w, "const void* data;")?;
+        indented!(w, "void (*destructor)(const void* data);")?;
+        return write_braced_declaration_closing(i, w, name.as_str());
+    }
+
     indented!(w, "{}", format!("typedef {} (*{})({});", rval, name, params.join(", ")))?;
 
     Ok(())
 }
 
 fn write_type_definition_enum(i: &Interop, w: &mut IndentWriter, the_type: &Enum) -> Result<(), Error> {
+    if is_data_enum(the_type) {
+        return write_type_definition_data_enum(i, w, the_type);
+    }
+
     let name = enum_to_typename(i, the_type);
 
     if i.documentation == DocStyle::Inline {
@@ -155,11 +198,55 @@ fn write_type_definition_enum_variant(i: &Interop, w: &mut IndentWriter, variant
 
     match variant_kind {
         VariantKind::Unit(variant_value) => indented!(w, r"{} = {},", variant_name, variant_value)?,
-        VariantKind::Typed(_, _) => indented!(w, r"// TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN")?,
+        VariantKind::Typed(variant_value, _) => indented!(w, r"{} = {},", variant_name, variant_value)?,
     }
     Ok(())
 }
 
+/// Writes an enum carrying data as a tag enum, and a struct holding the tag and a union of all payloads.
+fn write_type_definition_data_enum(i: &Interop, w: &mut IndentWriter, the_type: &Enum) -> Result<(), Error> {
+    let name = enum_to_typename(i, the_type);
+    let tag_name = enum_tag_to_typename(i, the_type);
+
+    write_braced_declaration_opening(i, w, &format!("typedef enum {tag_name}"))?;
+    for variant in the_type.variants() {
+        write_type_definition_enum_variant(i, w, variant, the_type)?;
+    }
+    write_braced_declaration_closing(i, w, tag_name.as_str())?;
+    w.newline()?;
+
+    if i.documentation == DocStyle::Inline {
+        write_documentation(w, the_type.meta().docs())?;
+    }
+
+    write_braced_declaration_opening(i, w, &format!("typedef struct {name}"))?;
+    // C enums have an implementation-defined width, while Rust always uses a `u32` tag.
+    indented!(w, r"uint32_t tag; // {tag_name}")?;
+
+    // C doesn't allow empty unions, and without payloads Rust has nothing after the tag either.
+    let payloads = the_type
+        .variants()
+        .iter()
+        .filter_map(|v| match v.kind() {
+            VariantKind::Typed(_, t) if !t.is_void() => Some((v, &**t)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !payloads.is_empty() {
+        write_braced_declaration_opening(i, w, "union")?;
+        for (variant, t) in payloads {
+            if i.documentation == DocStyle::Inline {
+                write_documentation(w, variant.docs())?;
+            }
+            indented!(w, r"{} {};", to_type_specifier(i, t), variant.name().to_snake_case())?;
+        }
+        write_braced_declaration_closing(i, w, "payload")?;
+    }
+
+    write_braced_declaration_closing(i, w, name.as_str())
+}
+
 fn write_type_definition_opaque(i: &Interop, w: &mut IndentWriter, the_type: &Opaque) -> Result<(), Error> {
     if i.documentation == DocStyle::Inline {
         write_documentation(w, the_type.meta().docs())?;
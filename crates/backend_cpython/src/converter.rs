@@ -1,5 +1,6 @@
-use interoptopus::lang::{ConstantValue, Docs, FnPointer, Primitive, PrimitiveValue, Type};
+use interoptopus::lang::{ConstantValue, Docs, Enum, FnPointer, Primitive, PrimitiveValue, Type, VariantKind};
 use interoptopus::pattern::TypePattern;
+use interoptopus::pattern::tuple::TupleType;
 
 #[must_use]
 pub fn documentation(documentation: &Docs) -> String {
@@ -7,6 +8,18 @@ pub fn documentation(documentation: &Docs) -> String {
     format!(r#""""{docs}""""#)
 }
 
+/// Whether the enum carries data and is therefore written as a `ctypes.Union`.
+#[must_use]
+pub fn is_data_enum(e: &Enum) -> bool {
+    e.variants().iter().any(|v| matches!(v.kind(), VariantKind::Typed(_, t) if !t.is_void()))
+}
+
+/// Whether slices and vecs of this type can be backed by any object implementing the buffer protocol.
+#[must_use]
+pub const fn is_buffer_type(t: &Type) -> bool {
+    matches!(t, Type::Primitive(x) if !x.is_128_bit())
+}
+
 #[must_use]
 pub fn to_type_hint(the_type: &Type, is_parameter: bool) -> String {
     match the_type {
@@ -25,6 +38,9 @@ pub fn to_type_hint(the_type: &Type, is_parameter: bool) -> String {
             Primitive::Isize => "int".to_string(),
             Primitive::F32 => "float".to_string(),
             Primitive::F64 => "float".to_string(),
+            Primitive::Char => "int".to_string(),
+            Primitive::U128 => "int".to_string(),
+            Primitive::I128 => "int".to_string(),
         },
         Type::ReadPointer(x) => match &**x {
             Type::Opaque(_) => "ctypes.c_void_p".to_string(),
@@ -36,11 +52,14 @@ pub fn to_type_hint(the_type: &Type, is_parameter: bool) -> String {
             Type::Primitive(Primitive::Void) => "ctypes.c_void_p".to_string(),
             _ => format!("ctypes.POINTER({})", to_ctypes_name(x, true)),
         },
-        Type::Enum(_) => "TODO".to_string(), // is this correct?
+        Type::Enum(x) if is_data_enum(x) => x.rust_name().to_string(),
+        Type::Enum(_) => "int".to_string(),
         Type::Composite(x) => x.rust_name().to_string(),
         Type::Pattern(x) => match x {
             TypePattern::CStrPointer => "bytes".to_string(),
-            TypePattern::Option(_) => "TODO".to_string(),
+            TypePattern::Option(c) => c.the_enum().rust_name().to_string(),
+            TypePattern::Result(c) => c.the_enum().rust_name().to_string(),
+            TypePattern::Slice(c) | TypePattern::SliceMut(c) if is_parameter && is_buffer_type(c.t()) => format!("{} | collections.abc.Buffer", c.rust_name()),
             TypePattern::Slice(c) | TypePattern::SliceMut(c) => {
                 let mut res = c.rust_name().to_string();
                 let inner = to_ctypes_name(c.t(), false);
@@ -50,6 +69,18 @@ pub fn to_type_hint(the_type: &Type, is_parameter: bool) -> String {
                 res
             }
             TypePattern::CChar => "ctypes.c_char".to_string(),
+            TypePattern::Map(c) => c.rust_name().to_string(),
+            TypePattern::Iter(c) => c.rust_name().to_string(),
+            TypePattern::Tuple(c) if is_parameter => format!("{} | {}", c.rust_name(), tuple_type_hint(c)),
+            TypePattern::Tuple(c) => tuple_type_hint(c),
+            TypePattern::Duration(c) if is_parameter => format!("{} | datetime.timedelta", c.rust_name()),
+            TypePattern::Duration(_) => "datetime.timedelta".to_string(),
+            TypePattern::Vec(c) if is_parameter && is_buffer_type(c.t()) => format!("{} | collections.abc.Buffer", c.rust_name()),
+            TypePattern::Vec(c) => c.rust_name().to_string(),
+            TypePattern::NdSlice(c) | TypePattern::NdSliceMut(c) if is_parameter => format!("{} | collections.abc.Buffer", c.rust_name()),
+            TypePattern::NdSlice(c) | TypePattern::NdSliceMut(c) => c.rust_name().to_string(),
+            TypePattern::Utf8Str(c) if is_parameter => format!("{} | str | bytes", c.rust_name()),
+            TypePattern::Utf8Str(c) => c.rust_name().to_string(),
             _ => String::new(),
         },
         _ => String::new(),
@@ -75,7 +106,11 @@ pub fn to_ctypes_name(the_type: &Type, with_type_annotations: bool) -> String {
             Primitive::Isize => "ctypes.c_ssize_t".to_string(),
             Primitive::F32 => "ctypes.c_float".to_string(),
             Primitive::F64 => "ctypes.c_double".to_string(),
+            Primitive::Char => "ctypes.c_uint32".to_string(),
+            Primitive::U128 => "_U128".to_string(),
+            Primitive::I128 => "_I128".to_string(),
         },
+        Type::Enum(x) if is_data_enum(x) => x.rust_name().to_string(),
         Type::Enum(_) => "ctypes.c_int".to_string(), // is this correct?
         Type::Composite(x) => x.rust_name().to_string(),
         Type::Wire(_) => "todo".to_string(),
@@ -98,19 +133,37 @@ pub fn to_ctypes_name(the_type: &Type, with_type_annotations: bool) -> String {
             TypePattern::CStrPointer => to_ctypes_name(&pattern.fallback_type(), with_type_annotations),
             TypePattern::APIVersion => "ctypes.c_uint64".to_string(),
             TypePattern::Utf8String(c) => c.rust_name().to_string(),
+            TypePattern::Utf8Str(c) => c.rust_name().to_string(),
             TypePattern::Slice(c) => c.rust_name().to_string(),
             TypePattern::SliceMut(c) => c.rust_name().to_string(),
-            TypePattern::Option(_) => "TODO".to_string(),
+            TypePattern::NdSlice(c) => c.rust_name().to_string(),
+            TypePattern::NdSliceMut(c) => c.rust_name().to_string(),
+            TypePattern::Option(c) => c.the_enum().rust_name().to_string(),
             TypePattern::Bool => "ctypes.c_uint8".to_string(),
             TypePattern::CChar => "ctypes.c_char".to_string(),
-            TypePattern::NamedCallback(x) => fnpointer_to_typename(x.fnpointer()),
+            TypePattern::NamedCallback(x) => x.name().to_string(),
             TypePattern::Result(c) => c.the_enum().rust_name().to_string(),
             TypePattern::AsyncCallback(x) => fnpointer_to_typename(x.fnpointer()),
             TypePattern::Vec(c) => c.rust_name().to_string(),
+            TypePattern::Map(c) => c.rust_name().to_string(),
+            TypePattern::Iter(c) => c.rust_name().to_string(),
+            TypePattern::Tuple(c) => c.rust_name().to_string(),
+            TypePattern::Duration(c) => c.rust_name().to_string(),
         },
     }
 }
 
+/// Tuples are handed out as Python tuples, e.g., `typing.Tuple[int, float]`.
+fn tuple_type_hint(c: &TupleType) -> String {
+    let types = c
+        .types()
+        .iter()
+        .map(|x| to_type_hint(x, false))
+        .map(|x| if x.is_empty() { "typing.Any".to_string() } else { x })
+        .collect::<Vec<_>>();
+    format!("typing.Tuple[{}]", types.join(", "))
+}
+
 #[allow(clippy::useless_format)]
 #[must_use]
 pub fn to_type_hint_in(the_type: &Type, is_parameter: bool) -> String {
@@ -1,6 +1,6 @@
 use crate::Interop;
 use crate::interop::functions::write_function;
-use crate::interop::patterns::{write_pattern_class_ctor, write_pattern_class_method};
+use crate::interop::patterns::{write_pattern_class_ctor, write_pattern_class_method, write_pattern_class_static_method};
 use crate::interop::types::{write_enum, write_struct};
 use interoptopus::inventory::non_service_functions;
 use interoptopus::lang::{Composite, Function, Type};
@@ -65,6 +65,11 @@ impl<'a> Markdown<'a> {
                 let doc = x.meta().docs().lines().first().cloned().unwrap_or_default();
                 indented!(w, r"     - **[{}](#{})** - {}", x.name().replace(&prefix, ""), target, doc)?;
             }
+            for x in pattern.static_methods() {
+                let target = format!("{}.{}", name, x.name().replace(&prefix, ""));
+                let doc = x.meta().docs().lines().first().cloned().unwrap_or_default();
+                indented!(w, r"     - **[{}](#{})** <sup>**static**</sup> - {}", x.name().replace(&prefix, ""), target, doc)?;
+            }
         }
 
         w.newline()?;
@@ -292,6 +297,32 @@ impl<'a> Markdown<'a> {
                 w.newline()?;
             }
 
+            for x in pattern.static_methods() {
+                let fname = x.name().replace(&prefix, "");
+                let target = format!("{class_name}.{fname}");
+                indented!(w, r#"### <a name="{}">**{}**</a> <sup>static</sup>"#, target, fname)?;
+
+                let doc = x.meta().docs().lines();
+                for line in doc {
+                    let line = line.replace(" # ", " #### ");
+                    let line = line.replace(" ## ", " ##### ");
+                    let line = line.replace(" ### ", " ###### ");
+                    indented!(w, r"{}", line)?;
+                }
+
+                w.newline()?;
+                indented!(w, r"#### Definition ")?;
+                indented!(w, r"```python")?;
+                indented!(w, r"class {}:", class_name)?;
+                w.newline()?;
+                write_pattern_class_static_method(self.interop, w, pattern, x, WriteFor::Docs)?;
+                indented!(w, [()()], r"...")?;
+                indented!(w, r"```")?;
+                w.newline()?;
+                indented!(w, r"---")?;
+                w.newline()?;
+            }
+
             w.newline()?;
             w.newline()?;
         }
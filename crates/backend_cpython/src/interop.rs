@@ -18,7 +18,9 @@ use crate::interop::types::write_types;
 use crate::interop::utils::write_utils;
 use derive_builder::Builder;
 use interoptopus::inventory::Inventory;
-use interoptopus::lang::Function;
+use interoptopus::lang::util::rval_may_borrow_str;
+use interoptopus::lang::{Function, Type};
+use interoptopus::pattern::TypePattern;
 use interoptopus_backend_utils::{Error, IndentWriter, indented};
 use std::fs::File;
 use std::path::Path;
@@ -52,16 +54,24 @@ impl Interop {
         Ok(())
     }
 
+    /// Whether `interoptopus_last_error` from `builtins_string!()` is part of the inventory.
+    fn has_last_error(&self) -> bool {
+        self.inventory.functions().iter().any(|x| x.name() == "interoptopus_last_error")
+    }
+
     #[must_use]
     fn function_args_to_string(&self, function: &Function, type_hints: bool, skip_first: bool) -> String {
         let skip = usize::from(skip_first);
+        let borrows_str = rval_may_borrow_str(function.signature());
         function
             .signature()
             .params()
             .iter()
             .skip(skip)
             .map(|x| {
-                let type_hint = if type_hints { to_type_hint_in(x.the_type(), true) } else { String::new() };
+                // Arguments the return value may borrow from are not converted, see `write_param_helpers`.
+                let convertible = !(borrows_str && matches!(x.the_type(), Type::Pattern(TypePattern::Utf8Str(_))));
+                let type_hint = if type_hints { to_type_hint_in(x.the_type(), convertible) } else { String::new() };
                 format!("{}{}", x.name(), type_hint)
             })
             .collect::<Vec<_>>()
@@ -1,5 +1,6 @@
 use crate::Interop;
 use crate::converter::to_ctypes_name;
+use interoptopus::lang::{Primitive, Type};
 use interoptopus_backend_utils::{Error, IndentWriter, render};
 use std::collections::HashMap;
 
@@ -17,7 +18,10 @@ pub fn write_api_load_function(i: &Interop, w: &mut IndentWriter) -> Result<(),
                 .collect::<Vec<_>>()
                 .join(", ");
 
-            let rtype = to_ctypes_name(f.signature().rval(), false);
+            let rtype = match f.signature().rval() {
+                Type::Primitive(Primitive::Void) => "None".to_string(),
+                x => to_ctypes_name(x, false),
+            };
 
             (f.name(), [("signature", args), ("restype", rtype)].into())
         })
@@ -3,6 +3,7 @@ use crate::converter::fnpointer_to_typename;
 use interoptopus::lang::Type;
 use interoptopus::lang::util::safe_name;
 use interoptopus::pattern::TypePattern;
+use interoptopus::pattern::callback::NamedCallback;
 use interoptopus_backend_utils::{Error, IndentWriter, indented};
 
 pub fn write_callback_helpers(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
@@ -19,3 +20,70 @@ pub fn write_callback_helpers(i: &Interop, w: &mut IndentWriter) -> Result<(), E
 
     Ok(())
 }
+
+pub fn write_named_callback(_i: &Interop, w: &mut IndentWriter, c: &NamedCallback) -> Result<(), Error> {
+    let name = c.name();
+    let documentation = c.meta().docs().lines().join("\n");
+
+    indented!(w, r"class {}(ctypes.Structure):", name)?;
+    if documentation.is_empty() {
+        indented!(w, [()], r#""""Callback and its data, Python callbacks ignore the data.""""#)?;
+    } else {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+    w.newline()?;
+    indented!(w, [()], r"_fields_ = [")?;
+    indented!(w, [()()], r#"("callback", {}),"#, fnpointer_to_typename(c.fnpointer()))?;
+    indented!(w, [()()], r#"("data", ctypes.c_void_p),"#)?;
+    indented!(w, [()], r"]")?;
+    w.newline()?;
+    indented!(w, [()], r"def __init__(self, f):")?;
+    indented!(w, [()()], r#"if not hasattr(f, "__ctypes_from_outparam__"):"#)?;
+    indented!(w, [()()()], r"f = {}(f)", fnpointer_to_typename(c.fnpointer()))?;
+    indented!(w, [()()], r"super().__init__(f, None)")?;
+
+    Ok(())
+}
+
+pub fn write_owned_callback(_i: &Interop, w: &mut IndentWriter, c: &NamedCallback) -> Result<(), Error> {
+    let name = c.name();
+    let documentation = c.meta().docs().lines().join("\n");
+
+    indented!(w, r"class {}(ctypes.Structure):", name)?;
+    if documentation.is_empty() {
+        indented!(w, [()], r#""""Owned callback, kept alive until Rust drops it and invokes the destructor.""""#)?;
+    } else {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+    w.newline()?;
+    indented!(w, [()], r"# Native callbacks Rust still holds on to, keyed by their callback data.")?;
+    indented!(w, [()], r"_alive = {{}}")?;
+    w.newline()?;
+    indented!(w, [()], r"_fields_ = [")?;
+    indented!(w, [()()], r#"("callback", {}),"#, fnpointer_to_typename(c.fnpointer()))?;
+    indented!(w, [()()], r#"("data", ctypes.c_void_p),"#)?;
+    indented!(w, [()()], r#"("destructor", ctypes.CFUNCTYPE(None, ctypes.c_void_p)),"#)?;
+    indented!(w, [()], r"]")?;
+    w.newline()?;
+    indented!(w, [()], r"def __init__(self, f):")?;
+    indented!(w, [()()], r"self._f = {}(f)", fnpointer_to_typename(c.fnpointer()))?;
+    indented!(w, [()()], r"super().__init__(self._f, None, {}._release)", name)?;
+    w.newline()?;
+    indented!(w, [()], r"@classmethod")?;
+    indented!(w, [()], r"def from_param(cls, obj):")?;
+    indented!(w, [()()], r#""""Registers a new reference every time this is passed to Rust, each released by its own destructor call.""""#)?;
+    indented!(w, [()()], r"if not isinstance(obj, cls):")?;
+    indented!(w, [()()()], r"obj = cls(obj)")?;
+    indented!(w, [()()], r"entry = (obj._f,)")?;
+    indented!(w, [()()], r"key = id(entry)")?;
+    indented!(w, [()()], r"cls._alive[key] = entry")?;
+    indented!(w, [()()], r"rval = cls.__new__(cls)")?;
+    indented!(w, [()()], r"ctypes.Structure.__init__(rval, obj._f, key, cls._release)")?;
+    indented!(w, [()()], r"rval._f = obj._f")?;
+    indented!(w, [()()], r"return rval")?;
+    w.newline()?;
+    indented!(w, [()], r"# Invoked by Rust once it dropped the callback.")?;
+    indented!(w, [()], r"_release = ctypes.CFUNCTYPE(None, ctypes.c_void_p)(lambda data: {}._alive.pop(data, None))", name)?;
+
+    Ok(())
+}
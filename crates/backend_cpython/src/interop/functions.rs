@@ -1,8 +1,8 @@
 use crate::Interop;
-use crate::converter::{to_ctypes_name, to_type_hint_out};
-use crate::interop::patterns::write_library_call;
+use crate::converter::{is_buffer_type, to_ctypes_name, to_type_hint_out};
+use crate::interop::patterns::{vec_create_builtin, write_library_call};
 use interoptopus::inventory::non_service_functions;
-use interoptopus::lang::util::safe_name;
+use interoptopus::lang::util::{rval_may_borrow_str, safe_name};
 use interoptopus::lang::{Function, Type};
 use interoptopus::pattern::TypePattern;
 use interoptopus_backend_utils::{Error, IndentWriter, WriteFor, indented};
@@ -37,7 +37,7 @@ pub fn write_function(i: &Interop, w: &mut IndentWriter, function: &Function, wr
     Ok(())
 }
 
-pub fn write_param_helpers(_i: &Interop, w: &mut IndentWriter, function: &Function) -> Result<(), Error> {
+pub fn write_param_helpers(i: &Interop, w: &mut IndentWriter, function: &Function) -> Result<(), Error> {
     for arg in function.signature().params() {
         match arg.the_type() {
             Type::FnPointer(x) => {
@@ -45,17 +45,57 @@ pub fn write_param_helpers(_i: &Interop, w: &mut IndentWriter, function: &Functi
                 indented!(w, [()()], r"{} = callbacks.{}({})", arg.name(), safe_name(&x.internal_name()), arg.name())?;
                 w.newline()?;
             }
+            Type::Primitive(x) if x.is_128_bit() => {
+                let t = to_ctypes_name(arg.the_type(), false);
+                indented!(w, [()], r"if not isinstance({}, {}):", arg.name(), t)?;
+                indented!(w, [()()], r"{} = {}.from_int({})", arg.name(), t, arg.name())?;
+                w.newline()?;
+            }
             Type::Pattern(pattern) => match pattern {
                 TypePattern::NamedCallback(x) => {
-                    let x = x.fnpointer();
-                    indented!(w, [()], r#"if not hasattr({}, "__ctypes_from_outparam__"):"#, arg.name())?;
-                    indented!(w, [()()], r"{} = callbacks.{}({})", arg.name(), safe_name(&x.internal_name()), arg.name())?;
+                    indented!(w, [()], r"if not isinstance({}, {}):", arg.name(), x.name())?;
+                    indented!(w, [()()], r"{} = {}({})", arg.name(), x.name(), arg.name())?;
                     w.newline()?;
                 }
                 TypePattern::CStrPointer => {
                     indented!(w, [()], r#"if not hasattr({}, "__ctypes_from_outparam__"):"#, arg.name())?;
                     indented!(w, [()()], r"{} = ctypes.cast({}, ctypes.POINTER(ctypes.c_char))", arg.name(), arg.name())?;
                 }
+                // A `Utf8Str` created here would be gone before a returned `Utf8Str` borrowing from it.
+                TypePattern::Utf8Str(c) if !rval_may_borrow_str(function.signature()) => {
+                    indented!(w, [()], r"if not isinstance({}, {}):", arg.name(), c.rust_name())?;
+                    indented!(w, [()()], r"{} = {}.from_bytes({})", arg.name(), c.rust_name(), arg.name())?;
+                    w.newline()?;
+                }
+                TypePattern::NdSlice(t) | TypePattern::NdSliceMut(t) => {
+                    indented!(w, [()], r"if not isinstance({}, {}):", arg.name(), t.rust_name())?;
+                    indented!(w, [()()], r"{} = {}.from_buffer({})", arg.name(), t.rust_name(), arg.name())?;
+                    w.newline()?;
+                }
+                TypePattern::Slice(t) | TypePattern::SliceMut(t) if is_buffer_type(t.t()) => {
+                    indented!(w, [()], r"if not isinstance({}, {}):", arg.name(), t.rust_name())?;
+                    indented!(w, [()()], r"{} = {}.from_buffer({})", arg.name(), t.rust_name(), arg.name())?;
+                    w.newline()?;
+                }
+                TypePattern::Vec(t) if is_buffer_type(t.t()) && vec_create_builtin(i, t).is_some() => {
+                    indented!(w, [()], r"if not isinstance({}, {}):", arg.name(), t.rust_name())?;
+                    indented!(w, [()()], r"{} = {}.from_buffer({})", arg.name(), t.rust_name(), arg.name())?;
+                    w.newline()?;
+                }
+                TypePattern::Iter(_) => {
+                    indented!(w, [()], r"{} = {}._move()", arg.name(), arg.name())?;
+                    w.newline()?;
+                }
+                TypePattern::Tuple(t) => {
+                    indented!(w, [()], r"if not isinstance({}, {}):", arg.name(), t.rust_name())?;
+                    indented!(w, [()()], r"{} = {}(*{})", arg.name(), t.rust_name(), arg.name())?;
+                    w.newline()?;
+                }
+                TypePattern::Duration(t) => {
+                    indented!(w, [()], r"if isinstance({}, datetime.timedelta):", arg.name())?;
+                    indented!(w, [()()], r"{} = {}.from_timedelta({})", arg.name(), t.rust_name(), arg.name())?;
+                    w.newline()?;
+                }
                 TypePattern::Slice(t) | TypePattern::SliceMut(t) => {
                     let inner = to_ctypes_name(t.t(), false);
                     indented!(w, [()], r#"if hasattr({}, "_length_") and getattr({}, "_type_", "") == {}:"#, arg.name(), arg.name(), inner)?;
@@ -1,14 +1,22 @@
 use crate::Interop;
-use crate::converter::{documentation, to_ctypes_name, to_type_hint_in, to_type_hint_out};
+use crate::converter::{documentation, is_buffer_type, to_ctypes_name, to_type_hint_in, to_type_hint_out};
 use crate::interop::functions::write_param_helpers;
+use crate::interop::types::write_struct;
 use crate::interop::utils::write_success_enum_aware_rval;
+use heck::ToSnakeCase;
 use interoptopus::lang::util::longest_common_prefix;
-use interoptopus::lang::{Composite, Function, Type};
-use interoptopus::pattern::service::ServiceDefinition;
+use interoptopus::lang::{Composite, Function, Parameter, Type};
+use interoptopus::pattern::iter::IterType;
+use interoptopus::pattern::map::MapType;
+use interoptopus::pattern::ndslice::MAX_DIMS;
+use interoptopus::pattern::service::{ForeignInterface, ServiceDefinition, ServiceInterface, ServiceSync};
 use interoptopus::pattern::slice::SliceType;
+use interoptopus::pattern::tuple::TupleType;
+use interoptopus::pattern::vec::VecType;
 use interoptopus::pattern::{LibraryPattern, TypePattern};
 use interoptopus_backend_utils::{Error, IndentWriter, WriteFor, indented};
 
+#[allow(clippy::too_many_lines)]
 pub fn write_slice(_i: &Interop, w: &mut IndentWriter, c: &SliceType, mutable: bool) -> Result<(), Error> {
     let data_type = c.t();
     let data_type_python = to_ctypes_name(data_type, true);
@@ -22,6 +30,25 @@ pub fn write_slice(_i: &Interop, w: &mut IndentWriter, c: &SliceType, mutable: b
     indented!(w, [()], r#"    ("len", ctypes.c_uint64),"#)?;
     indented!(w, [()], r"]")?;
     w.newline()?;
+    if is_buffer_type(data_type) {
+        indented!(w, [()], r"@staticmethod")?;
+        indented!(w, [()], r#"def from_buffer(value: collections.abc.Buffer) -> "{}":"#, c.rust_name())?;
+        indented!(
+            w,
+            [()()],
+            r#""""Borrows a contiguous buffer (e.g., a numpy array, bytearray or array.array) without copying it.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used.""""#
+        )?;
+        indented!(w, [()()], r"address, view, owned = _buffer_address(value, {}, {})", data_type_python, if mutable { "True" } else { "False" })?;
+        indented!(w, [()()], r"if not view.c_contiguous:")?;
+        indented!(w, [()()()], r#"raise ValueError("Buffer is not contiguous")"#)?;
+        indented!(w, [()()], r"rval = {}(data=ctypes.cast(address, ctypes.POINTER({})), len=view.nbytes // view.itemsize)", c.rust_name(), data_type_python)?;
+        indented!(w, [()()], r"rval.owned = owned  # Store buffer in returned slice to prevent memory deallocation")?;
+        indented!(w, [()()], r"return rval")?;
+        w.newline()?;
+    }
     indented!(w, [()], r"def __len__(self):")?;
     indented!(w, [()()], r"return self.len")?;
     w.newline()?;
@@ -83,20 +110,360 @@ pub fn write_slice(_i: &Interop, w: &mut IndentWriter, c: &SliceType, mutable: b
     indented!(w, [()()], r#""""Returns the last element of this slice.""""#)?;
     indented!(w, [()()], r"return self[len(self)-1]")?;
 
+    if is_buffer_type(data_type) {
+        w.newline()?;
+        indented!(w, [()], r"def memoryview(self) -> memoryview:")?;
+        indented!(
+            w,
+            [()()],
+            r#""""Returns a zero-copy view of this slice, pass it to `numpy.asarray` to get a numpy array.
+
+        The view keeps this object alive, but can't outlive the data it points to. For slices
+        received from Rust that usually means the duration of the call or callback.""""#
+        )?;
+        indented!(w, [()()], r"return _as_memoryview(self.data, (len(self),), {}, self, {})", data_type_python, if mutable { "False" } else { "True" })?;
+    }
+
     // Only write this for byte-like types right now
     if data_type.size_of() == 1 {
         w.newline()?;
         indented!(w, [()], r"def bytearray(self):")?;
         indented!(w, [()()], r#""""Returns a bytearray with the content of this slice.""""#)?;
-        indented!(w, [()()], r"rval = bytearray(len(self))")?;
-        indented!(w, [()()], r"for i in range(len(self)):")?;
-        indented!(w, [()()()], r"rval[i] = self[i]")?;
+        if is_buffer_type(data_type) {
+            indented!(w, [()()], r"return bytearray(self.memoryview())")?;
+        } else {
+            indented!(w, [()()], r"rval = bytearray(len(self))")?;
+            indented!(w, [()()], r"for i in range(len(self)):")?;
+            indented!(w, [()()()], r"rval[i] = self[i]")?;
+            indented!(w, [()()], r"return rval")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn write_ndslice(_i: &Interop, w: &mut IndentWriter, c: &SliceType, mutable: bool) -> Result<(), Error> {
+    let documentation = c.meta().docs().lines().join("\n");
+    let name = c.rust_name();
+    let data_type = to_ctypes_name(c.t(), true);
+    let hint_in = to_type_hint_in(c.t(), false);
+    let hint_out = to_type_hint_out(c.t());
+
+    indented!(w, r"class {}(ctypes.Structure):", name)?;
+    if !documentation.is_empty() {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+    indented!(w, [()], r"# These fields represent the underlying C data layout")?;
+    indented!(w, [()], r"_fields_ = [")?;
+    indented!(w, [()], r#"    ("data", ctypes.POINTER({})),"#, data_type)?;
+    indented!(w, [()], r#"    ("ndim", ctypes.c_uint64),"#)?;
+    indented!(w, [()], r#"    ("shape", ctypes.c_uint64 * {}),"#, MAX_DIMS)?;
+    indented!(w, [()], r#"    ("strides", ctypes.c_int64 * {}),"#, MAX_DIMS)?;
+    indented!(w, [()], r"]")?;
+    w.newline()?;
+    indented!(w, [()], r"@staticmethod")?;
+    indented!(w, [()], r#"def from_buffer(value: collections.abc.Buffer) -> "{}":"#, name)?;
+    indented!(
+        w,
+        [()()],
+        r#""""Borrows a buffer (e.g., a numpy array or memoryview) without copying it, see `_buffer_address`.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used.""""#
+    )?;
+    indented!(w, [()()], r"address, view, owned = _buffer_address(value, {}, {})", data_type, if mutable { "True" } else { "False" })?;
+    indented!(w, [()()], r"itemsize = view.itemsize")?;
+    indented!(w, [()()], r"if not 0 < view.ndim <= {} or any(x % itemsize for x in view.strides):", MAX_DIMS)?;
+    indented!(w, [()()()], r#"raise ValueError("Buffer layout does not match {}")"#, name)?;
+    indented!(w, [()()], r"rval = {}(data=ctypes.cast(address, ctypes.POINTER({})), ndim=view.ndim)", name, data_type)?;
+    indented!(w, [()()], r"rval.shape[:view.ndim] = view.shape")?;
+    indented!(w, [()()], r"rval.strides[:view.ndim] = [x // itemsize for x in view.strides]")?;
+    indented!(w, [()()], r"rval.owned = owned  # Store buffer in returned object to prevent memory deallocation")?;
+    indented!(w, [()()], r"return rval")?;
+    w.newline()?;
+    indented!(w, [()], r"def dims(self) -> tuple[int, ...]:")?;
+    indented!(w, [()()], r#""""Returns the extent of each dimension, like numpy's `shape`.""""#)?;
+    indented!(w, [()()], r"return tuple(self.shape[:self.ndim])")?;
+    w.newline()?;
+    indented!(w, [()], r"def __len__(self):")?;
+    indented!(w, [()()], r#""""Returns the total number of elements.""""#)?;
+    indented!(w, [()()], r"return math.prod(self.dims()) if self.data else 0")?;
+    w.newline()?;
+    indented!(w, [()], r"def is_contiguous(self) -> bool:")?;
+    indented!(w, [()()], r#""""Whether all elements are laid out row-major without gaps.""""#)?;
+    indented!(w, [()()], r"expected = 1")?;
+    indented!(w, [()()], r"for d in reversed(range(self.ndim)):")?;
+    indented!(w, [()()()], r"if self.shape[d] != 1 and self.strides[d] != expected:")?;
+    indented!(w, [()()()()], r"return False")?;
+    indented!(w, [()()()], r"expected *= self.shape[d]")?;
+    indented!(w, [()()], r"return True")?;
+    w.newline()?;
+    indented!(w, [()], r"def _offset(self, index) -> int:")?;
+    indented!(w, [()()], r"if not isinstance(index, tuple):")?;
+    indented!(w, [()()()], r"index = (index,)")?;
+    indented!(w, [()()], r"if len(index) != self.ndim:")?;
+    indented!(w, [()()()], r#"raise IndexError("Expected {{}} indices, got {{}}".format(self.ndim, len(index)))"#)?;
+    indented!(w, [()()], r"offset = 0")?;
+    indented!(w, [()()], r"for i, extent, stride in zip(index, self.shape, self.strides):")?;
+    indented!(w, [()()()], r"if not 0 <= i < extent:")?;
+    indented!(w, [()()()()], r#"raise IndexError("Index out of range")"#)?;
+    indented!(w, [()()()], r"offset += i * stride")?;
+    indented!(w, [()()], r"return offset")?;
+    w.newline()?;
+    indented!(w, [()], r"def __getitem__(self, index){}:", hint_out)?;
+    indented!(w, [()()], r"return self.data[self._offset(index)]")?;
+    if mutable {
+        w.newline()?;
+        indented!(w, [()], r"def __setitem__(self, index, v{}):", hint_in)?;
+        indented!(w, [()()], r"self.data[self._offset(index)] = v")?;
+    }
+    w.newline()?;
+    indented!(w, [()], r"def memoryview(self) -> memoryview:")?;
+    indented!(
+        w,
+        [()()],
+        r#""""Returns a zero-copy view of the data, which must be contiguous.
+
+        Pass the result to `numpy.asarray` to get a numpy array. Neither may be used longer
+        than the data this object points to.""""#
+    )?;
+    indented!(w, [()()], r"if not self.is_contiguous():")?;
+    indented!(w, [()()()], r#"raise ValueError("{} is not contiguous")"#, name)?;
+    indented!(w, [()()], r"return _as_memoryview(self.data, self.dims(), {}, self, {})", data_type, if mutable { "False" } else { "True" })?;
+
+    Ok(())
+}
+
+pub fn write_vec(i: &Interop, w: &mut IndentWriter, c: &VecType) -> Result<(), Error> {
+    write_struct(i, w, c.composite_type(), WriteFor::Code)?;
+
+    if !is_buffer_type(c.t()) {
+        return Ok(());
+    }
+
+    let name = c.rust_name();
+    let data_type = to_ctypes_name(c.t(), true);
+
+    if let Some(create) = vec_create_builtin(i, c) {
+        w.newline()?;
+        indented!(w, [()], r"@staticmethod")?;
+        indented!(w, [()], r#"def from_buffer(value: collections.abc.Buffer) -> "{}":"#, name)?;
+        indented!(
+            w,
+            [()()],
+            r#""""Copies a contiguous buffer (e.g., bytes, a numpy array or array.array) into a new, Rust-owned Vec.
+
+        The data is copied once, in bulk. Once passed to Rust the returned object must not be used anymore.""""#
+        )?;
+        indented!(w, [()()], r"address, view, _ = _buffer_address(value, {}, False)", data_type)?;
+        indented!(w, [()()], r"if not view.c_contiguous:")?;
+        indented!(w, [()()()], r#"raise ValueError("Buffer is not contiguous")"#)?;
+        indented!(w, [()()], r"rval = {}()", name)?;
+        indented!(w, [()()], r"c_lib.{}(address, view.nbytes // view.itemsize, ctypes.byref(rval))", create.name())?;
+        indented!(w, [()()], r"return rval")?;
+    }
+
+    w.newline()?;
+    indented!(w, [()], r"def memoryview(self) -> memoryview:")?;
+    indented!(
+        w,
+        [()()],
+        r#""""Returns a zero-copy view of this Vec, pass it to `numpy.asarray` to get a numpy array.
+
+        The view keeps this object alive, but must not be used once the Vec was passed back to Rust.""""#
+    )?;
+    indented!(w, [()()], r"return _as_memoryview(self.ptr, (self.len,), {}, self, False)", data_type)?;
+
+    Ok(())
+}
+
+/// Returns the `interoptopus_vec_create` builtin for the given Vec, if it was registered.
+#[must_use]
+pub fn vec_create_builtin<'a>(i: &'a Interop, vec: &VecType) -> Option<&'a Function> {
+    i.inventory.functions().iter().find(|f| {
+        let rval = f.signature().params().last().map(Parameter::the_type);
+        f.name().starts_with("interoptopus_vec_create")
+            && matches!(rval, Some(Type::ReadWritePointer(x)) if matches!(x.as_ref(), Type::Pattern(TypePattern::Vec(x)) if x == vec))
+    })
+}
+
+pub fn write_map(_i: &Interop, w: &mut IndentWriter, c: &MapType) -> Result<(), Error> {
+    let documentation = c.meta().docs().lines().join("\n");
+    let key_type_python = to_ctypes_name(c.k(), true);
+    let value_type_python = to_ctypes_name(c.v(), true);
+    let hint_in = to_type_hint_in(c.k(), false);
+    let hint_out = to_type_hint_out(c.v());
+
+    indented!(w, r"class {}(ctypes.Structure):", c.rust_name())?;
+    if !documentation.is_empty() {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+    indented!(w, [()], r"# These fields represent the underlying C data layout")?;
+    indented!(w, [()], r"_fields_ = [")?;
+    indented!(w, [()], r#"    ("keys_ptr", ctypes.POINTER({})),"#, key_type_python)?;
+    indented!(w, [()], r#"    ("values_ptr", ctypes.POINTER({})),"#, value_type_python)?;
+    indented!(w, [()], r#"    ("len", ctypes.c_uint64),"#)?;
+    indented!(w, [()], r"]")?;
+    w.newline()?;
+    indented!(w, [()], r"def __len__(self):")?;
+    indented!(w, [()()], r"return self.len")?;
+    w.newline()?;
+    indented!(w, [()], r"def _index(self, key) -> int:")?;
+    indented!(w, [()()], r"# Keys are sorted by their Rust ordering, which might not match Python's, so we search linearly.")?;
+    indented!(w, [()()], r"for i in range(self.len):")?;
+    indented!(w, [()()()], r"if self.keys_ptr[i] == key:")?;
+    indented!(w, [()()()()], r"return i")?;
+    indented!(w, [()()], r"return -1")?;
+    w.newline()?;
+    indented!(w, [()], r"def __getitem__(self, key{}){}:", hint_in, hint_out)?;
+    indented!(w, [()()], r"index = self._index(key)")?;
+    indented!(w, [()()], r"if index < 0:")?;
+    indented!(w, [()()()], r"raise KeyError(key)")?;
+    indented!(w, [()()], r"return self.values_ptr[index]")?;
+    w.newline()?;
+    indented!(w, [()], r"def __contains__(self, key) -> bool:")?;
+    indented!(w, [()()], r"return self._index(key) >= 0")?;
+    w.newline()?;
+    indented!(w, [()], r"def __iter__(self) -> typing.Iterator[{}]:", key_type_python)?;
+    indented!(w, [()()], r"return (self.keys_ptr[i] for i in range(self.len))")?;
+    w.newline()?;
+    indented!(w, [()], r"def get(self, key, default=None):")?;
+    indented!(w, [()()], r"index = self._index(key)")?;
+    indented!(w, [()()], r"return default if index < 0 else self.values_ptr[index]")?;
+    w.newline()?;
+    indented!(w, [()], r"def keys(self) -> typing.List[{}]:", key_type_python)?;
+    indented!(w, [()()], r"return [self.keys_ptr[i] for i in range(self.len)]")?;
+    w.newline()?;
+    indented!(w, [()], r"def values(self) -> typing.List[{}]:", value_type_python)?;
+    indented!(w, [()()], r"return [self.values_ptr[i] for i in range(self.len)]")?;
+    w.newline()?;
+    indented!(w, [()], r"def items(self) -> typing.List[typing.Tuple[{}, {}]]:", key_type_python, value_type_python)?;
+    indented!(w, [()()], r"return [(self.keys_ptr[i], self.values_ptr[i]) for i in range(self.len)]")?;
+    w.newline()?;
+    w.newline()?;
+    indented!(w, r"collections.abc.Mapping.register({})", c.rust_name())?;
+
+    Ok(())
+}
+
+/// Writes a tuple as a structure that also behaves like, and converts to, a Python tuple.
+pub fn write_tuple(i: &Interop, w: &mut IndentWriter, c: &TupleType) -> Result<(), Error> {
+    let fields = (0..c.types().len()).map(|x| format!("self.x{x}")).collect::<Vec<_>>().join(", ");
+
+    write_struct(i, w, c.composite_type(), WriteFor::Code)?;
+    w.newline()?;
+    indented!(w, [()], r"def to_tuple(self){}:", to_type_hint_out(&c.to_type()))?;
+    indented!(w, [()()], r"return ({fields},)")?;
+    w.newline()?;
+    indented!(w, [()], r"def __len__(self) -> int:")?;
+    indented!(w, [()()], r"return {}", c.types().len())?;
+    w.newline()?;
+    indented!(w, [()], r"def __iter__(self):")?;
+    indented!(w, [()()], r"return iter(self.to_tuple())")?;
+    w.newline()?;
+    indented!(w, [()], r"def __getitem__(self, index: int):")?;
+    indented!(w, [()()], r"return self.to_tuple()[index]")?;
+    w.newline()?;
+    indented!(w, [()], r"def __eq__(self, other) -> bool:")?;
+    indented!(w, [()()], r"return isinstance(other, ({}, tuple)) and self.to_tuple() == tuple(other)", c.rust_name())?;
+    w.newline()?;
+    indented!(w, [()], r"def __repr__(self) -> str:")?;
+    indented!(w, [()()], r"return repr(self.to_tuple())")?;
+
+    Ok(())
+}
+
+pub fn write_duration(i: &Interop, w: &mut IndentWriter, c: &Composite) -> Result<(), Error> {
+    let name = c.rust_name();
+
+    write_struct(i, w, c, WriteFor::Code)?;
+    w.newline()?;
+    indented!(w, [()], r"@staticmethod")?;
+    indented!(w, [()], r#"def from_timedelta(value: datetime.timedelta) -> "{}":"#, name)?;
+    indented!(w, [()()], r#""""Converts a non-negative `timedelta`, which has a resolution of one microsecond.""""#)?;
+    indented!(w, [()()], r"if value < datetime.timedelta(0):")?;
+    indented!(w, [()()()], r#"raise ValueError("Durations can't be negative")"#)?;
+    indented!(w, [()()], r"return {}(value.days * 86400 + value.seconds, value.microseconds * 1000)", name)?;
+    w.newline()?;
+    indented!(w, [()], r"def to_timedelta(self) -> datetime.timedelta:")?;
+    indented!(w, [()()], r#""""Converts this into a `timedelta`, nanoseconds are truncated to microseconds.""""#)?;
+    indented!(w, [()()], r"return datetime.timedelta(seconds=self.secs, microseconds=self.nanos // 1000)")?;
+
+    Ok(())
+}
+
+pub fn write_iter(i: &Interop, w: &mut IndentWriter, c: &IterType) -> Result<(), Error> {
+    let documentation = c.meta().docs().lines().join("\n");
+    let item_type_python = to_ctypes_name(c.t(), true);
+    let hint_out = to_type_hint_out(c.t());
+
+    indented!(w, r"class {}(ctypes.Structure):", c.rust_name())?;
+    if !documentation.is_empty() {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+    indented!(w, [()], r"# These fields represent the underlying C data layout")?;
+    indented!(w, [()], r"_fields_ = [")?;
+    indented!(w, [()], r#"    ("ptr", ctypes.c_void_p),"#)?;
+    indented!(w, [()], r"]")?;
+    w.newline()?;
+    indented!(w, [()], r#"def _move(self) -> "{}":"#, c.rust_name())?;
+    indented!(w, [()()], r#""""Hands the handle over to Rust, which then owns it, leaving this object empty.""""#)?;
+    indented!(w, [()()], r"rval = {}(self.ptr)", c.rust_name())?;
+    indented!(w, [()()], r"self.ptr = None")?;
+    indented!(w, [()()], r"return rval")?;
+
+    let Some((next, destroy)) = iter_builtins(i, c) else {
+        return Ok(());
+    };
+
+    w.newline()?;
+    indented!(w, [()], r"def __iter__(self):")?;
+    indented!(w, [()()], r"return self")?;
+    w.newline()?;
+    indented!(w, [()], r"def __next__(self){}:", hint_out)?;
+    indented!(w, [()()], r"if not self.ptr:")?;
+    indented!(w, [()()()], r"raise StopIteration")?;
+    indented!(w, [()()], r"rval = {}()", item_type_python)?;
+    indented!(w, [()()], r"if c_lib.{}(ctypes.byref(self), ctypes.byref(rval)) == 0:", next.name())?;
+    indented!(w, [()()()], r"self.close()")?;
+    indented!(w, [()()()], r"raise StopIteration")?;
+    if is_buffer_type(c.t()) {
+        indented!(w, [()()], r"return rval.value")?;
+    } else {
         indented!(w, [()()], r"return rval")?;
     }
+    w.newline()?;
+    indented!(w, [()], r"def close(self):")?;
+    indented!(w, [()()], r#""""Frees the iterator early, which otherwise happens once it is exhausted.""""#)?;
+    indented!(w, [()()], r"if self.ptr:")?;
+    indented!(w, [()()()], r"c_lib.{}(self)", destroy.name())?;
+    indented!(w, [()()()], r"self.ptr = None")?;
+    w.newline()?;
+    indented!(w, [()], r"def __enter__(self):")?;
+    indented!(w, [()()], r"return self")?;
+    w.newline()?;
+    indented!(w, [()], r"def __exit__(self, *args):")?;
+    indented!(w, [()()], r"self.close()")?;
 
     Ok(())
 }
 
+/// Returns the `interoptopus_iter_next` and `interoptopus_iter_destroy` builtins for the given Iter, if they were registered.
+#[must_use]
+pub fn iter_builtins<'a>(i: &'a Interop, iter: &IterType) -> Option<(&'a Function, &'a Function)> {
+    let by_ref = Type::ReadWritePointer(Box::new(iter.to_type()));
+    let by_value = iter.to_type();
+    let functions = i.inventory.functions();
+    let next = functions
+        .iter()
+        .find(|f| f.name().starts_with("interoptopus_iter_next") && f.signature().params().first().map(Parameter::the_type) == Some(&by_ref))?;
+    let destroy = functions
+        .iter()
+        .find(|f| f.name().starts_with("interoptopus_iter_destroy") && f.signature().params().first().map(Parameter::the_type) == Some(&by_value))?;
+    Some((next, destroy))
+}
+
 pub fn _write_option(_i: &Interop, w: &mut IndentWriter, c: &Composite) -> Result<(), Error> {
     let data_type = c
         .fields()
@@ -138,6 +505,8 @@ pub fn write_patterns(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
     for pattern in i.inventory.patterns() {
         match pattern {
             LibraryPattern::Service(x) => write_pattern_class(i, w, x)?,
+            LibraryPattern::Interface(x) => write_pattern_protocol(i, w, x)?,
+            LibraryPattern::Foreign(x) => write_pattern_foreign(i, w, x)?,
             LibraryPattern::Builtins(_) => { /* TODO */ }
             _ => panic!("Pattern not explicitly handled"),
         }
@@ -154,7 +523,13 @@ pub fn write_pattern_class(i: &Interop, w: &mut IndentWriter, class: &ServiceDef
     all_functions.push(class.destructor().clone());
 
     let _common_prefix = longest_common_prefix(&all_functions);
-    let documentation = class.the_type().meta().docs().lines().join("\n");
+    let mut documentation = class.the_type().meta().docs().lines().to_vec();
+    match class.sync() {
+        ServiceSync::None => {}
+        ServiceSync::Mutex => documentation.push(" Calls into this service are serialized by a lock.".to_string()),
+        ServiceSync::ThreadAffine => documentation.push(" This service must only be used from the thread that created it.".to_string()),
+    }
+    let documentation = documentation.join("\n");
 
     indented!(w, r"class {}:", context_type_name)?;
     if !documentation.is_empty() {
@@ -186,6 +561,134 @@ pub fn write_pattern_class(i: &Interop, w: &mut IndentWriter, class: &ServiceDef
         write_pattern_class_method(i, w, class, function, WriteFor::Code)?;
     }
 
+    for function in class.static_methods() {
+        write_pattern_class_static_method(i, w, class, function, WriteFor::Code)?;
+    }
+
+    let common_prefix = longest_common_prefix(&all_functions);
+
+    for property in class.properties() {
+        let name = property.name().to_snake_case();
+
+        if let Some(getter) = property.getter() {
+            indented!(w, [()], r"@property")?;
+            indented!(w, [()], r"def {}(self){}:", name, to_type_hint_out(getter.signature().rval()))?;
+            indented!(w, [()()], r"return self.{}()", getter.name().replace(&common_prefix, ""))?;
+            w.newline()?;
+        }
+
+        if let Some(setter) = property.setter() {
+            let hint = to_type_hint_in(setter.signature().params()[1].the_type(), true);
+            if property.getter().is_some() {
+                indented!(w, [()], r"@{}.setter", name)?;
+            } else {
+                indented!(w, [()], r"{} = property()", name)?;
+                indented!(w, [()], r"@{}.setter", name)?;
+            }
+            indented!(w, [()], r"def {}(self, x{}):", name, hint)?;
+            indented!(w, [()()], r"self.{}(x)", setter.name().replace(&common_prefix, ""))?;
+            w.newline()?;
+        }
+    }
+
+    let existing = class.methods().iter().map(|x| x.name().replace(&common_prefix, "")).collect::<Vec<_>>();
+
+    for interface_impl in class.interfaces() {
+        let interface = interface_impl.interface();
+        let as_handle = interface_impl.as_handle();
+        let as_name = as_handle.name().replace(&common_prefix, "");
+
+        indented!(w, [()], r"def {}(self) -> {}:", as_name, interface.handle().rust_name())?;
+        indented!(w, [()()], r"return c_lib.{}(self._ctx)", as_handle.name())?;
+        w.newline()?;
+
+        for function in interface.methods() {
+            let name = function.name().replace(interface.common_prefix(), "");
+            if existing.contains(&name) {
+                continue;
+            }
+
+            let args = i.function_args_to_string(function, true, true);
+            let type_hint_out = to_type_hint_out(function.signature().rval());
+
+            indented!(w, [()], r"def {}(self, {}){}:", name, &args, type_hint_out)?;
+            indented!(w, [()()], r"{}", crate::converter::documentation(function.meta().docs()))?;
+            w.indent();
+            write_param_helpers(i, w, function)?;
+            w.unindent();
+            write_library_call(i, w, function, Some(&format!("self.{as_name}()")))?;
+            w.newline()?;
+        }
+    }
+
+    w.newline()?;
+    w.newline()?;
+
+    Ok(())
+}
+
+pub fn write_pattern_protocol(i: &Interop, w: &mut IndentWriter, interface: &ServiceInterface) -> Result<(), Error> {
+    i.debug(w, "write_pattern_protocol")?;
+
+    let documentation = interface.meta().docs().lines().join("\n");
+
+    indented!(w, r"class {}(typing.Protocol):", interface.name())?;
+    if !documentation.is_empty() {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+
+    for function in interface.methods() {
+        let args = i.function_args_to_string(function, true, true);
+        let type_hint_out = to_type_hint_out(function.signature().rval());
+        indented!(w, [()], r"def {}(self, {}){}: ...", function.name().replace(interface.common_prefix(), ""), &args, type_hint_out)?;
+    }
+
+    w.newline()?;
+    w.newline()?;
+
+    Ok(())
+}
+
+pub fn write_pattern_foreign(i: &Interop, w: &mut IndentWriter, foreign: &ForeignInterface) -> Result<(), Error> {
+    i.debug(w, "write_pattern_foreign")?;
+
+    let vtable = foreign.vtable().rust_name();
+    let documentation = foreign.meta().docs().lines().join("\n");
+
+    indented!(w, r"class {}Base:", foreign.name())?;
+    if !documentation.is_empty() {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+    indented!(w, [()], r"def __init__(self):")?;
+    indented!(w, [()()], r"self._vtable = {}()", vtable)?;
+    for (field, callback) in foreign.methods() {
+        let mut params = callback.fnpointer().signature().params().iter().map(|x| x.name().to_string()).collect::<Vec<_>>();
+        params.pop();
+        let mut lambda_params = params.clone();
+        lambda_params.push("_".to_string());
+        indented!(w, [()()], r"self._vtable.{} = {}(lambda {}: self.{}({}))", field.name(), callback.name(), lambda_params.join(", "), field.name(), params.join(", "))?;
+    }
+    w.newline()?;
+    indented!(w, [()], r"def vtable(self) -> {}:", vtable)?;
+    indented!(w, [()()], r#""""Returns a vtable forwarding to this instance, valid as long as this instance is alive.""""#)?;
+    indented!(w, [()()], r"return self._vtable")?;
+
+    for (field, callback) in foreign.methods() {
+        let signature = callback.fnpointer().signature();
+        let mut params = signature
+            .params()
+            .iter()
+            .map(|x| format!("{}{}", x.name(), to_type_hint_in(x.the_type(), true)))
+            .collect::<Vec<_>>();
+        params.pop();
+        params.insert(0, "self".to_string());
+
+        w.newline()?;
+        indented!(w, [()], r"def {}({}){}:", field.name(), params.join(", "), to_type_hint_out(signature.rval()))?;
+        indented!(w, [()()], r"{}", crate::converter::documentation(field.docs()))?;
+        indented!(w, [()()], r"raise NotImplementedError()")?;
+    }
+
     w.newline()?;
     w.newline()?;
 
@@ -202,7 +705,7 @@ pub fn write_pattern_class_ctor(i: &Interop, w: &mut IndentWriter, class: &Servi
 
     let common_prefix = longest_common_prefix(&all_functions);
 
-    let ctor_args = i.function_args_to_string(ctor, true, true);
+    let ctor_args = i.function_args_to_string(ctor, true, false);
     indented!(w, [()], r"@staticmethod")?;
     indented!(w, [()], r"def {}({}) -> {}:", ctor.name().replace(&common_prefix, ""), ctor_args, context_type_name)?;
 
@@ -213,8 +716,8 @@ pub fn write_pattern_class_ctor(i: &Interop, w: &mut IndentWriter, class: &Servi
     indented!(w, [()()], r"{}", documentation(ctor.meta().docs()))?;
     w.indent();
     write_param_helpers(i, w, ctor)?;
-    let invokes = i.function_args_to_string(ctor, false, true);
-    indented!(w, [()], r"ctx = c_lib.{}({invokes}).t", ctor.name())?;
+    let invokes = i.function_args_to_string(ctor, false, false);
+    indented!(w, [()], r"ctx = c_lib.{}({invokes}).unwrap()", ctor.name())?;
     w.unindent();
     indented!(w, [()()], r"self = {}({}.__api_lock, ctx)", context_type_name, context_type_name)?;
     indented!(w, [()()], r"return self")?;
@@ -252,6 +755,75 @@ pub fn write_pattern_class_method(i: &Interop, w: &mut IndentWriter, class: &Ser
     Ok(())
 }
 
+pub fn write_pattern_class_static_method(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition, function: &Function, write_for: WriteFor) -> Result<(), Error> {
+    i.debug(w, "write_pattern_class_static_method")?;
+
+    let args = i.function_args_to_string(function, true, false);
+    let type_hint_out = to_type_hint_out(function.signature().rval());
+
+    indented!(w, [()], r"@staticmethod")?;
+    indented!(w, [()], r"def {}({}){}:", function.name().replace(&class.common_prefix(), ""), &args, type_hint_out)?;
+
+    if write_for == WriteFor::Docs {
+        return Ok(());
+    }
+
+    indented!(w, [()()], r"{}", documentation(function.meta().docs()))?;
+
+    w.indent();
+    write_param_helpers(i, w, function)?;
+    write_library_call(i, w, function, None)?;
+    w.unindent();
+    w.newline()?;
+
+    Ok(())
+}
+
+pub fn write_str(_i: &Interop, w: &mut IndentWriter, c: &Composite) -> Result<(), Error> {
+    let documentation = c.meta().docs().lines().join("\n");
+    let name = c.rust_name();
+
+    indented!(w, r"class {}(ctypes.Structure):", name)?;
+    if !documentation.is_empty() {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+    indented!(w, [()], r"# These fields represent the underlying C data layout")?;
+    indented!(w, [()], r"_fields_ = [")?;
+    indented!(w, [()], r#"    ("ptr", ctypes.POINTER(ctypes.c_uint8)),"#)?;
+    indented!(w, [()], r#"    ("len", ctypes.c_uint64),"#)?;
+    indented!(w, [()], r"]")?;
+    w.newline()?;
+    indented!(w, [()], r"@staticmethod")?;
+    indented!(w, [()], r#"def from_bytes(value: str | bytes) -> "{}":"#, name)?;
+    indented!(
+        w,
+        [()()],
+        r#""""Borrows the given bytes (or the UTF-8 encoding of a str) without copying them.
+
+        The returned object keeps the bytes alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used.""""#
+    )?;
+    indented!(w, [()()], r"if isinstance(value, str):")?;
+    indented!(w, [()()()], r#"value = value.encode("utf-8")"#)?;
+    indented!(w, [()()], r"rval = {}(ptr=ctypes.cast(ctypes.c_char_p(value), ctypes.POINTER(ctypes.c_uint8)), len=len(value))", name)?;
+    indented!(w, [()()], r"rval.owned = value  # Store bytes in returned object to prevent memory deallocation")?;
+    indented!(w, [()()], r"return rval")?;
+    w.newline()?;
+    indented!(w, [()], r"def __len__(self):")?;
+    indented!(w, [()()], r"return self.len")?;
+    w.newline()?;
+    indented!(w, [()], r"def bytes(self) -> bytes:")?;
+    indented!(w, [()()], r#""""Returns a copy of the underlying bytes.""""#)?;
+    indented!(w, [()()], r"if not self.ptr:")?;
+    indented!(w, [()()()], r#"return b"""#)?;
+    indented!(w, [()()], r"return ctypes.string_at(self.ptr, self.len)")?;
+    w.newline()?;
+    indented!(w, [()], r"def __str__(self) -> str:")?;
+    indented!(w, [()()], r#"return self.bytes().decode("utf-8")"#)?;
+
+    Ok(())
+}
+
 pub fn write_library_call(i: &Interop, w: &mut IndentWriter, function: &Function, class_str: Option<&str>) -> Result<(), Error> {
     let args = match class_str {
         None => i.function_args_to_string(function, false, false),
@@ -266,6 +838,15 @@ pub fn write_library_call(i: &Interop, w: &mut IndentWriter, function: &Function
             indented!(w, [()], r"rval = c_lib.{}({})", function.name(), &args)?;
             indented!(w, [()], r"return ctypes.string_at(rval)")?;
         }
+        Type::Pattern(TypePattern::Tuple(_)) => {
+            indented!(w, [()], r"return c_lib.{}({}).to_tuple()", function.name(), &args)?;
+        }
+        Type::Pattern(TypePattern::Duration(_)) => {
+            indented!(w, [()], r"return c_lib.{}({}).to_timedelta()", function.name(), &args)?;
+        }
+        Type::Primitive(x) if x.is_128_bit() => {
+            indented!(w, [()], r"return c_lib.{}({}).value", function.name(), &args)?;
+        }
         _ => write_success_enum_aware_rval(i, w, function, &args, true)?,
     }
 
@@ -1,25 +1,52 @@
 use crate::Interop;
-use crate::converter::{to_ctypes_name, to_type_hint_in, to_type_hint_out};
-use crate::interop::patterns::write_slice;
-use interoptopus::lang::util::sort_types_by_dependencies;
-use interoptopus::lang::{Composite, Enum, Layout, Type, VariantKind};
+use crate::converter::{is_data_enum, to_ctypes_name, to_type_hint, to_type_hint_in, to_type_hint_out};
+use crate::interop::callbacks::{write_named_callback, write_owned_callback};
+use crate::interop::patterns::{write_duration, write_iter, write_map, write_ndslice, write_slice, write_str, write_tuple, write_vec};
+use heck::ToSnakeCase;
+use interoptopus::lang::util::{error_enums, error_variant_exception_name, sort_types_by_dependencies};
+use interoptopus::lang::{Composite, Enum, Field, Layout, Type, Variant, VariantKind};
 use interoptopus::pattern::TypePattern;
+use interoptopus::pattern::option::OptionType;
+use interoptopus::pattern::result::ResultType;
 use interoptopus_backend_utils::{Error, IndentWriter, WriteFor, indented};
 
 pub fn write_types(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
     let all_types = i.inventory.c_types().to_vec();
+    let error_enums = error_enums(&all_types);
     let sorted_types = sort_types_by_dependencies(all_types);
 
     for t in &sorted_types {
         match t {
             Type::Composite(c) => write_struct(i, w, c, WriteFor::Code)?,
+            Type::Enum(e) if error_enums.contains(e) => {
+                write_enum(i, w, e, WriteFor::Code)?;
+                w.newline()?;
+                w.newline()?;
+                write_enum_exceptions(i, w, e)?;
+            }
             Type::Enum(e) => write_enum(i, w, e, WriteFor::Code)?,
             Type::Pattern(p) => match p {
                 TypePattern::Slice(c) => write_slice(i, w, c, false)?,
                 TypePattern::SliceMut(c) => write_slice(i, w, c, true)?,
-                TypePattern::Result(c) => write_enum(i, w, c.the_enum(), WriteFor::Code)?,
+                TypePattern::NdSlice(c) => write_ndslice(i, w, c, false)?,
+                TypePattern::NdSliceMut(c) => write_ndslice(i, w, c, true)?,
+                TypePattern::Result(c) => {
+                    write_data_enum(i, w, c.the_enum(), WriteFor::Code)?;
+                    write_result_methods(i, w, c, &error_enums)?;
+                }
                 TypePattern::Utf8String(c) => write_struct(i, w, c, WriteFor::Code)?,
-                TypePattern::Option(c) => write_enum(i, w, c.the_enum(), WriteFor::Code)?,
+                TypePattern::Utf8Str(c) => write_str(i, w, c)?,
+                TypePattern::Vec(c) => write_vec(i, w, c)?,
+                TypePattern::Map(c) => write_map(i, w, c)?,
+                TypePattern::Iter(c) => write_iter(i, w, c)?,
+                TypePattern::Tuple(c) => write_tuple(i, w, c)?,
+                TypePattern::Duration(c) => write_duration(i, w, c)?,
+                TypePattern::Option(c) => {
+                    write_data_enum(i, w, c.the_enum(), WriteFor::Code)?;
+                    write_option_methods(i, w, c)?;
+                }
+                TypePattern::NamedCallback(c) if c.is_owned() => write_owned_callback(i, w, c)?,
+                TypePattern::NamedCallback(c) => write_named_callback(i, w, c)?,
                 _ => continue,
             },
             _ => continue,
@@ -45,20 +72,31 @@ pub fn write_struct(_i: &Interop, w: &mut IndentWriter, c: &Composite, write_for
     }
 
     let alignment = c.repr().alignment();
+    let align_fields = c.repr().layout() != Layout::Packed && c.fields().iter().any(|f| has_128_bit(f.the_type()));
     if let Some(align) = alignment {
         indented!(w, [()], r"_align_ = {}", align)?;
+    } else if align_fields {
+        indented!(w, [()], r"_align_ = 16")?;
     }
 
     w.newline()?;
     if write_for == WriteFor::Code {
         indented!(w, [()], r"# These fields represent the underlying C data layout")?;
     }
-    indented!(w, [()], r"_fields_ = [")?;
+    if align_fields {
+        indented!(w, [()], r"_fields_ = _align_fields([")?;
+    } else {
+        indented!(w, [()], r"_fields_ = [")?;
+    }
     for f in c.fields() {
         let type_name = to_ctypes_name(f.the_type(), true);
-        indented!(w, [()()], r#"("{}", {}),"#, f.name(), type_name)?;
+        indented!(w, [()()], r#"("{}", {}),"#, raw_field_name(f), type_name)?;
+    }
+    if align_fields {
+        indented!(w, [()], r"])")?;
+    } else {
+        indented!(w, [()], r"]")?;
     }
-    indented!(w, [()], r"]")?;
 
     // Ctor
     let extra_args = c
@@ -107,6 +145,9 @@ pub fn write_struct(_i: &Interop, w: &mut IndentWriter, c: &Composite, write_for
         }
 
         match f.the_type() {
+            Type::Pattern(TypePattern::Tuple(_)) => indented!(w, [()()], r#"return ctypes.Structure.__get__(self, "{}").to_tuple()"#, f.name())?,
+            Type::Pattern(TypePattern::Duration(_)) => indented!(w, [()()], r"return self.{}.to_timedelta()", raw_field_name(f))?,
+            Type::Primitive(x) if x.is_128_bit() => indented!(w, [()()], r"return self.{}.value", raw_field_name(f))?,
             Type::Pattern(_) => indented!(w, [()()], r#"return ctypes.Structure.__get__(self, "{}")"#, f.name())?,
             _ => indented!(w, [()()], r#"return ctypes.Structure.__get__(self, "{}")"#, f.name())?,
         }
@@ -118,13 +159,51 @@ pub fn write_struct(_i: &Interop, w: &mut IndentWriter, c: &Composite, write_for
         if !documentation.is_empty() {
             indented!(w, [()()], r#""""{}""""#, documentation)?;
         }
-        indented!(w, [()()], r#"return ctypes.Structure.__set__(self, "{}", value)"#, f.name())?;
+        match f.the_type() {
+            Type::Pattern(TypePattern::Duration(c)) => {
+                indented!(w, [()()], r"if isinstance(value, datetime.timedelta):")?;
+                indented!(w, [()()()], r"value = {}.from_timedelta(value)", c.rust_name())?;
+                indented!(w, [()()], r"self.{} = value", raw_field_name(f))?;
+            }
+            Type::Primitive(x) if x.is_128_bit() => {
+                let t = to_ctypes_name(f.the_type(), false);
+                indented!(w, [()()], r"if not isinstance(value, {}):", t)?;
+                indented!(w, [()()()], r"value = {}.from_int(value)", t)?;
+                indented!(w, [()()], r"self.{} = value", raw_field_name(f))?;
+            }
+            _ => indented!(w, [()()], r#"return ctypes.Structure.__set__(self, "{}", value)"#, f.name())?,
+        }
     }
 
     Ok(())
 }
 
-pub fn write_enum(_i: &Interop, w: &mut IndentWriter, e: &Enum, write_for: WriteFor) -> Result<(), Error> {
+/// Whether the type (or any of its fields) holds a 128-bit integer, which needs 16-byte alignment.
+fn has_128_bit(t: &Type) -> bool {
+    match t {
+        Type::Primitive(x) => x.is_128_bit(),
+        Type::Array(x) => has_128_bit(x.the_type()),
+        Type::Composite(x) => x.fields().iter().any(|f| has_128_bit(f.the_type())),
+        Type::Pattern(TypePattern::Tuple(x)) => x.types().iter().any(has_128_bit),
+        _ => false,
+    }
+}
+
+/// Fields converted from and to native Python types are stored under a private name, as the
+/// descriptors ctypes creates for `_fields_` would otherwise shadow our properties.
+fn raw_field_name(f: &Field) -> String {
+    match f.the_type() {
+        Type::Pattern(TypePattern::Duration(_)) => format!("_{}", f.name()),
+        Type::Primitive(x) if x.is_128_bit() => format!("_{}", f.name()),
+        _ => f.name().to_string(),
+    }
+}
+
+pub fn write_enum(i: &Interop, w: &mut IndentWriter, e: &Enum, write_for: WriteFor) -> Result<(), Error> {
+    if is_data_enum(e) {
+        return write_data_enum(i, w, e, write_for);
+    }
+
     let documentation = e.meta().docs().lines().join("\n");
 
     indented!(w, r"class {}:", e.rust_name())?;
@@ -141,9 +220,223 @@ pub fn write_enum(_i: &Interop, w: &mut IndentWriter, e: &Enum, write_for: Write
 
         match v.kind() {
             VariantKind::Unit(x) => indented!(w, [()], r"{} = {}", v.name(), x)?,
-            VariantKind::Typed(_, _) => indented!(w, r"# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN")?,
+            VariantKind::Typed(x, _) => indented!(w, [()], r"{} = {}", v.name(), x)?,
         }
     }
 
     Ok(())
 }
+
+/// Writes `{Enum}Exception` for an enum used as an error, and one subclass per variant.
+pub fn write_enum_exceptions(_i: &Interop, w: &mut IndentWriter, e: &Enum) -> Result<(), Error> {
+    let name = e.rust_name();
+
+    indented!(w, r"class {name}Exception(Exception):")?;
+    indented!(w, [()], r#""""Raised when a function returns `{name}` as its error.""""#)?;
+    indented!(w, [()], r#"def __init__(self, error, message=""):"#)?;
+    indented!(w, [()()], r#"super().__init__(message or f"{name}({{error}})")"#)?;
+    indented!(w, [()()], r"self.error = error")?;
+    w.newline()?;
+    indented!(w, [()], r"@staticmethod")?;
+    indented!(w, [()], r"def from_error(error):")?;
+    indented!(w, [()()], r#""""Creates the exception matching the error's variant, including details about the last error in Rust.""""#)?;
+    indented!(w, [()()], r#"message = f"{name}({{error}}) {{_last_error()}}".strip()"#)?;
+    indented!(w, [()()], r"for cls in {name}Exception.__subclasses__():")?;
+    indented!(w, [()()()], r"if cls.ERROR == error: return cls(error, message)")?;
+    indented!(w, [()()], r"return {name}Exception(error, message)")?;
+
+    // Data enums have no constant to compare a variant to.
+    let variants = if is_data_enum(e) { &[][..] } else { e.variants() };
+
+    for v in variants {
+        let VariantKind::Unit(_) = v.kind() else { continue };
+        let exception = error_variant_exception_name(e, v);
+        w.newline()?;
+        w.newline()?;
+        indented!(w, r"class {exception}({name}Exception):")?;
+        indented!(w, [()], r"ERROR = {name}.{}", v.name())?;
+    }
+
+    Ok(())
+}
+
+/// Writes an enum carrying data (and any `ffi::Result` or `ffi::Option`) as a tag followed by a `ctypes.Union` of all payloads,
+/// matching their `#[repr(C, u32)]` layout in Rust.
+pub fn write_data_enum(_i: &Interop, w: &mut IndentWriter, e: &Enum, write_for: WriteFor) -> Result<(), Error> {
+    let name = e.rust_name();
+    let documentation = e.meta().docs().lines().join("\n");
+    let payloads = e
+        .variants()
+        .iter()
+        .filter_map(|v| match v.kind() {
+            VariantKind::Typed(_, t) if !t.is_void() => Some((v, &**t)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    indented!(w, r"class {name}(ctypes.Structure):")?;
+    if !documentation.is_empty() && write_for == WriteFor::Code {
+        indented!(w, [()], r#""""{}""""#, documentation)?;
+    }
+
+    if !payloads.is_empty() {
+        w.newline()?;
+        indented!(w, [()], r"class _Payload(ctypes.Union):")?;
+        indented!(w, [()()], r"_fields_ = [")?;
+        for (v, t) in &payloads {
+            indented!(w, [()()()], r#"("{}", {}),"#, v.name(), to_ctypes_name(t, true))?;
+        }
+        indented!(w, [()()], r"]")?;
+    }
+
+    w.newline()?;
+    if write_for == WriteFor::Code {
+        indented!(w, [()], r"# These fields represent the underlying C data layout")?;
+    }
+    indented!(w, [()], r"_fields_ = [")?;
+    indented!(w, [()()], r#"("_variant", ctypes.c_uint32),"#)?;
+    if !payloads.is_empty() {
+        indented!(w, [()()], r#"("_payload", _Payload),"#)?;
+    }
+    indented!(w, [()], r"]")?;
+
+    if write_for == WriteFor::Code {
+        w.newline()?;
+        indented!(w, [()], r"# Variants as dataclasses, e.g., for use with `match`.")?;
+    }
+    for v in e.variants() {
+        w.newline()?;
+        indented!(w, [()], r"@dataclasses.dataclass(frozen=True)")?;
+        indented!(w, [()], r"class {}Variant:", v.name())?;
+        match payload_of(v) {
+            Some(t) => indented!(w, [()()], r"value: {}", value_type_hint(t))?,
+            None => indented!(w, [()()], r"pass")?,
+        }
+    }
+
+    for v in e.variants() {
+        let tag = variant_tag(v);
+        let ctor = safe_variant_name(v.name());
+        let snake = v.name().to_snake_case();
+
+        w.newline()?;
+        indented!(w, [()], r"@staticmethod")?;
+        match payload_of(v) {
+            Some(t) => indented!(w, [()], r"def {ctor}(value: {}) -> {name}:", value_type_hint(t))?,
+            None => indented!(w, [()], r"def {ctor}() -> {name}:")?,
+        }
+        if write_for == WriteFor::Docs {
+            indented!(w, [()()], r"...")?;
+            continue;
+        }
+        for line in v.docs().lines() {
+            indented!(w, [()()], r"# {}", line)?;
+        }
+        indented!(w, [()()], r"rval = {name}()")?;
+        if payload_of(v).is_some() {
+            indented!(w, [()()], r"rval._payload.{} = value", v.name())?;
+        }
+        indented!(w, [()()], r"rval._variant = {tag}")?;
+        indented!(w, [()()], r"return rval")?;
+
+        w.newline()?;
+        indented!(w, [()], r"def is_{snake}(self) -> bool:")?;
+        indented!(w, [()()], r"return self._variant == {tag}")?;
+
+        if let Some(t) = payload_of(v) {
+            w.newline()?;
+            indented!(w, [()], r"def as_{snake}(self) -> {}:", value_type_hint(t))?;
+            indented!(w, [()()], r#""""Returns the `{}` value, raises if this is another variant.""""#, v.name())?;
+            indented!(w, [()()], r#"if self._variant != {tag}: raise Exception(f"Expected `{}`, got {{self!r}}")"#, v.name())?;
+            indented!(w, [()()], r"return self._payload.{}", v.name())?;
+        }
+    }
+
+    if write_for == WriteFor::Docs {
+        return Ok(());
+    }
+
+    write_data_enum_variant(w, e)
+}
+
+/// Writes `variant()` converting the active variant into its dataclass, and comparisons based on it.
+fn write_data_enum_variant(w: &mut IndentWriter, e: &Enum) -> Result<(), Error> {
+    let name = e.rust_name();
+
+    w.newline()?;
+    indented!(w, [()], r"def variant(self):")?;
+    indented!(w, [()()], r#""""Returns the active variant as one of the `...Variant` dataclasses.""""#)?;
+    for v in e.variants() {
+        let tag = variant_tag(v);
+        match payload_of(v) {
+            Some(_) => indented!(w, [()()], r"if self._variant == {tag}: return {name}.{}Variant(self._payload.{})", v.name(), v.name())?,
+            None => indented!(w, [()()], r"if self._variant == {tag}: return {name}.{}Variant()", v.name())?,
+        }
+    }
+    indented!(w, [()()], r#"raise Exception(f"Invalid variant {{self._variant}}")"#)?;
+    w.newline()?;
+    indented!(w, [()], r"def __eq__(self, other):")?;
+    indented!(w, [()()], r"return isinstance(other, {name}) and self.variant() == other.variant()")?;
+    w.newline()?;
+    indented!(w, [()], r"def __repr__(self):")?;
+    indented!(w, [()()], r"return repr(self.variant())")?;
+
+    Ok(())
+}
+
+/// Writes `unwrap()` for an `ffi::Result`, raising the error's exception if there is one.
+pub fn write_result_methods(_i: &Interop, w: &mut IndentWriter, c: &ResultType, error_enums: &[Enum]) -> Result<(), Error> {
+    let hint = if c.t().is_void() { "None".to_string() } else { value_type_hint(c.t()) };
+    let ok = if c.t().is_void() { "return" } else { "return self._payload.Ok" };
+
+    w.newline()?;
+    indented!(w, [()], r"def unwrap(self) -> {hint}:")?;
+    indented!(w, [()()], r#""""Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise.""""#)?;
+    indented!(w, [()()], r"if self._variant == 0: {ok}")?;
+    match c.e() {
+        Type::Enum(e) if error_enums.contains(e) => {
+            indented!(w, [()()], r"if self._variant == 1: raise {}Exception.from_error(self._payload.Err)", e.rust_name())?;
+        }
+        _ => {}
+    }
+    indented!(w, [()()], r#"raise Exception(f"{{self!r}} {{_last_error()}}".strip())"#)?;
+
+    Ok(())
+}
+
+/// Writes `unwrap()` for an `ffi::Option`.
+pub fn write_option_methods(_i: &Interop, w: &mut IndentWriter, c: &OptionType) -> Result<(), Error> {
+    w.newline()?;
+    indented!(w, [()], r"def unwrap(self) -> {}:", value_type_hint(c.t()))?;
+    indented!(w, [()()], r#""""Returns the `Some` value, raises if this is `None`.""""#)?;
+    indented!(w, [()()], r#"if self._variant != 0: raise Exception("Called `unwrap()` on `None`")"#)?;
+    indented!(w, [()()], r"return self._payload.Some")?;
+
+    Ok(())
+}
+
+fn payload_of(v: &Variant) -> Option<&Type> {
+    match v.kind() {
+        VariantKind::Typed(_, t) if !t.is_void() => Some(t),
+        _ => None,
+    }
+}
+
+const fn variant_tag(v: &Variant) -> usize {
+    match v.kind() {
+        VariantKind::Unit(x) | VariantKind::Typed(x, _) => *x,
+    }
+}
+
+fn value_type_hint(t: &Type) -> String {
+    let hint = to_type_hint(t, false);
+    if hint.is_empty() { "typing.Any".to_string() } else { hint }
+}
+
+/// Variants such as `None` can't be used as Python identifiers.
+fn safe_variant_name(name: &str) -> String {
+    match name {
+        "None" | "True" | "False" => format!("{name}_"),
+        _ => name.to_string(),
+    }
+}
@@ -13,7 +13,7 @@ pub fn write_success_enum_aware_rval(i: &Interop, w: &mut IndentWriter, function
     Ok(())
 }
 
-pub fn write_utils(_i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
+pub fn write_utils(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
     // indented!(w, r#"class Slice(ctypes.Structure, typing.Generic[T]):"#)?;
     // indented!(w, [_], r#"# These fields represent the underlying C data layout"#)?;
     // indented!(w, [_], r#"_fields_ = ["#)?;
@@ -43,10 +43,23 @@ pub fn write_utils(_i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
     w.newline()?;
     w.newline()?;
 
+    indented!(w, r"def _last_error():")?;
+    indented!(w, [()], r#""""Returns and clears details about the last error or panic in Rust on this thread, or an empty string.""""#)?;
+    if i.has_last_error() {
+        indented!(w, [()], r"error = c_lib.interoptopus_last_error()")?;
+        indented!(w, [()], r#"rval = ctypes.string_at(error.ptr, error.len).decode("utf-8") if error.len else """#)?;
+        indented!(w, [()], r"c_lib.interoptopus_string_destroy(error)")?;
+        indented!(w, [()], r"return rval")?;
+    } else {
+        indented!(w, [()], r#"return """#)?;
+    }
+    w.newline()?;
+    w.newline()?;
+
     indented!(w, r"def _errcheck(returned, success):")?;
     indented!(w, [()], r#""""Checks for FFIErrors and converts them to an exception.""""#)?;
     indented!(w, [()], r"if returned == success: return")?;
-    indented!(w, [()], r#"else: raise Exception(f"Function returned error: {{returned}}")"#)?;
+    indented!(w, [()], r#"else: raise Exception(f"Function returned error: {{returned}} {{_last_error()}}")"#)?;
     w.newline()?;
     w.newline()?;
 
@@ -79,5 +92,114 @@ pub fn write_utils(_i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
     w.newline()?;
     w.newline()?;
 
+    write_buffer_helpers(w)?;
+    write_int128_helpers(w)?;
+
+    Ok(())
+}
+
+/// ctypes has no 128-bit integers, so we emulate them with two halves in (little endian) native order.
+fn write_int128_helpers(w: &mut IndentWriter) -> Result<(), Error> {
+    indented!(w, r"class _U128(ctypes.Structure):")?;
+    indented!(w, [()], r#""""Helper for `u128`, converted from and to `int` when crossing the FFI boundary.""""#)?;
+    indented!(w, [()], r"_align_ = 16")?;
+    indented!(w, [()], r#"_fields_ = [("lo", ctypes.c_uint64), ("hi", ctypes.c_uint64)]"#)?;
+    indented!(w, [()], r"_range = (0, 1 << 128)")?;
+    w.newline()?;
+    indented!(w, [()], r"@classmethod")?;
+    indented!(w, [()], r"def from_int(cls, value: int):")?;
+    indented!(w, [()()], r"if not cls._range[0] <= value < cls._range[1]:")?;
+    indented!(w, [()()()], r#"raise OverflowError(f"{{value}} does not fit into {{cls.__name__}}")"#)?;
+    indented!(w, [()()], r"return cls(value & 0xFFFFFFFFFFFFFFFF, (value >> 64) & 0xFFFFFFFFFFFFFFFF)")?;
+    w.newline()?;
+    indented!(w, [()], r"@property")?;
+    indented!(w, [()], r"def value(self) -> int:")?;
+    indented!(w, [()()], r"return self.lo | (self.hi << 64)")?;
+    w.newline()?;
+    w.newline()?;
+
+    indented!(w, r"class _I128(_U128):")?;
+    indented!(w, [()], r#""""Helper for `i128`, like `_U128` but in two's complement.""""#)?;
+    indented!(w, [()], r"_range = (-(1 << 127), 1 << 127)")?;
+    w.newline()?;
+    indented!(w, [()], r"@property")?;
+    indented!(w, [()], r"def value(self) -> int:")?;
+    indented!(w, [()()], r"rval = self.lo | (self.hi << 64)")?;
+    indented!(w, [()()], r"return rval - (1 << 128) if rval >> 127 else rval")?;
+    w.newline()?;
+    w.newline()?;
+
+    indented!(w, r"def _align_fields(fields):")?;
+    indented!(w, [()], r#""""Pads `_fields_` holding 128-bit integers like Rust does, as ctypes only honors `_align_` since Python 3.13.""""#)?;
+    indented!(w, [()], r"rval, offset, max_align = [], 0, 1")?;
+    indented!(w, [()], r"for name, ctype in fields:")?;
+    indented!(w, [()()], r#"align = max(ctypes.alignment(ctype), getattr(ctype, "_align_", 1))"#)?;
+    indented!(w, [()()], r"if -offset % align:")?;
+    indented!(w, [()()()], r#"rval.append((f"_pad{{len(rval)}}", ctypes.c_uint8 * (-offset % align)))"#)?;
+    indented!(w, [()()()], r"offset += -offset % align")?;
+    indented!(w, [()()], r"rval.append((name, ctype))")?;
+    indented!(w, [()()], r"offset += ctypes.sizeof(ctype)")?;
+    indented!(w, [()()], r"max_align = max(max_align, align)")?;
+    indented!(w, [()], r"if -offset % max_align:")?;
+    indented!(w, [()()], r#"rval.append(("_pad_end", ctypes.c_uint8 * (-offset % max_align)))"#)?;
+    indented!(w, [()], r"return rval")?;
+    w.newline()?;
+    w.newline()?;
+
+    Ok(())
+}
+
+/// Helpers to borrow objects implementing the buffer protocol, and to lend data back as `memoryview`s.
+fn write_buffer_helpers(w: &mut IndentWriter) -> Result<(), Error> {
+    indented!(w, r"def _buffer_address(value, ctype, writable: bool):")?;
+    indented!(
+        w,
+        [()],
+        r#""""Returns `(address, view, owned)` for a buffer of `ctype` elements (e.g., a numpy array,
+    memoryview, bytes or bytearray), where `owned` must be kept alive while `address` is used.
+
+    Nothing is copied, except for read-only buffers that don't expose their address.""""#
+    )?;
+    indented!(w, [()], r"view = memoryview(value)")?;
+    indented!(w, [()], r#"kind = getattr(ctype, "_type_", None)"#)?;
+    indented!(w, [()], r"if view.itemsize != ctypes.sizeof(ctype):")?;
+    indented!(w, [()()], r#"raise ValueError(f"Buffer items have {{view.itemsize}} bytes, expected {{ctypes.sizeof(ctype)}}")"#)?;
+    indented!(w, [()], r#"if isinstance(kind, str) and (view.format[-1] in "efd") != (kind in "fd"):"#)?;
+    indented!(w, [()()], r#"raise ValueError(f"Buffer format {{view.format}} does not match {{ctype.__name__}}")"#)?;
+    indented!(w, [()], r"if view.nbytes == 0:")?;
+    indented!(w, [()()], r"return None, view, value")?;
+    indented!(w, [()], r#"interface = getattr(value, "__array_interface__", None)"#)?;
+    indented!(w, [()], r#"if interface is not None and not (writable and interface["data"][1]):"#)?;
+    indented!(w, [()()], r#"return interface["data"][0], view, value"#)?;
+    indented!(w, [()], r"if not view.c_contiguous:")?;
+    indented!(w, [()()], r#"raise ValueError("Buffer is not contiguous, strided data must expose `__array_interface__`")"#)?;
+    indented!(w, [()], r"if not view.readonly:")?;
+    indented!(w, [()()], r"return ctypes.addressof(ctypes.c_char.from_buffer(view)), view, value")?;
+    indented!(w, [()], r"if writable:")?;
+    indented!(w, [()()], r#"raise ValueError("Buffer is read-only")"#)?;
+    indented!(w, [()], r"if isinstance(view.obj, bytes) and view.nbytes == len(view.obj):")?;
+    indented!(w, [()()], r"return ctypes.cast(ctypes.c_char_p(view.obj), ctypes.c_void_p).value, view, view.obj")?;
+    indented!(w, [()], r"owned = (ctypes.c_char * view.nbytes).from_buffer_copy(view)")?;
+    indented!(w, [()], r"return ctypes.addressof(owned), view, owned")?;
+    w.newline()?;
+    w.newline()?;
+
+    indented!(w, r"def _as_memoryview(pointer, shape, ctype, owner, readonly: bool) -> memoryview:")?;
+    indented!(w, [()], r#""""Returns a zero-copy view of contiguous data, which keeps `owner` alive while in use.""""#)?;
+    indented!(w, [()], r"array_type = ctype")?;
+    indented!(w, [()], r"for extent in reversed(shape):")?;
+    indented!(w, [()()], r"array_type = array_type * extent")?;
+    indented!(w, [()], r"address = ctypes.cast(pointer, ctypes.c_void_p).value")?;
+    indented!(w, [()], r"array = array_type.from_address(address) if address else array_type()")?;
+    indented!(w, [()], r"array.owner = owner")?;
+    indented!(w, [()], r"view = memoryview(array)")?;
+    indented!(w, [()], r"# ctypes reports explicit byte orders (e.g., `<I`) which memoryview itself can't index.")?;
+    indented!(w, [()], r#"format = view.format.lstrip("<>=@!")"#)?;
+    indented!(w, [()], r"if len(format) == 1:")?;
+    indented!(w, [()()], r#"view = view.cast("B").cast(format, shape)"#)?;
+    indented!(w, [()], r"return view.toreadonly() if readonly else view")?;
+    w.newline()?;
+    w.newline()?;
+
     Ok(())
 }
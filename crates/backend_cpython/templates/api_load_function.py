@@ -10,5 +10,5 @@ def init_lib(path):
     {%- endfor %}
 
     {%- for name, param in functions %}
-    c_lib.{{name}}.restype = {{param.restype}} {#- TODO: ONLY IF NOT EMPTY!! #}
+    c_lib.{{name}}.restype = {{param.restype}}
     {%- endfor %}
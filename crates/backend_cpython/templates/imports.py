@@ -1,5 +1,9 @@
 from __future__ import annotations
+import collections.abc
 import ctypes
+import datetime
+import dataclasses
+import math
 import typing
 
 T = typing.TypeVar("T")
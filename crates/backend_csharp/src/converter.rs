@@ -3,6 +3,8 @@ use heck::ToUpperCamelCase;
 use interoptopus::lang::util::safe_name;
 use interoptopus::lang::{Composite, ConstantValue, Field, FnPointer, Function, Parameter, Primitive, PrimitiveValue, SugaredReturnType, Type, VariantKind, WirePayload};
 use interoptopus::pattern::TypePattern;
+use interoptopus::pattern::iter::IterType;
+use interoptopus::pattern::map::MapType;
 use interoptopus::pattern::slice::SliceType;
 use interoptopus::pattern::vec::VecType;
 
@@ -23,6 +25,9 @@ pub fn primitive_to_type(x: Primitive) -> String {
         Primitive::Isize => "nint".to_string(),
         Primitive::F32 => "float".to_string(),
         Primitive::F64 => "double".to_string(),
+        Primitive::Char => "Rune".to_string(),
+        Primitive::U128 => "UInt128".to_string(),
+        Primitive::I128 => "Int128".to_string(),
     }
 }
 
@@ -57,6 +62,9 @@ pub fn field_to_type(x: &Type) -> String {
         Type::Pattern(x) => match x {
             TypePattern::CStrPointer => "string".to_string(),
             TypePattern::Utf8String(_) => "Utf8String".to_string(),
+            TypePattern::Utf8Str(x) => x.rust_name().to_string(),
+            TypePattern::NdSlice(x) => x.rust_name().to_string(),
+            TypePattern::NdSliceMut(x) => x.rust_name().to_string(),
             TypePattern::Slice(x) => format!("Slice{}", slice_t(x)),
             TypePattern::SliceMut(x) => format!("SliceMut{}", slice_t(x)),
             TypePattern::Option(e) => e.the_enum().rust_name().to_string(),
@@ -66,6 +74,10 @@ pub fn field_to_type(x: &Type) -> String {
             TypePattern::CChar => "sbyte".to_string(),
             TypePattern::APIVersion => field_to_type(&x.fallback_type()),
             TypePattern::Vec(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Map(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Iter(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Tuple(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Duration(x) => x.rust_name().to_string(),
             TypePattern::AsyncCallback(_) => todo!("Async callbacks not supported in fields"),
         },
     }
@@ -90,6 +102,9 @@ pub fn field_to_type_unmanaged(x: &Type) -> String {
         Type::Pattern(x) => match x {
             TypePattern::CStrPointer => "IntPtr".to_string(),
             TypePattern::Utf8String(_) => "Utf8String.Unmanaged".to_string(),
+            TypePattern::Utf8Str(x) => format!("{}.Unmanaged", x.rust_name()),
+            TypePattern::NdSlice(x) => format!("{}.Unmanaged", x.rust_name()),
+            TypePattern::NdSliceMut(x) => format!("{}.Unmanaged", x.rust_name()),
             TypePattern::Slice(x) => format!("Slice{}.Unmanaged", slice_t(x)),
             TypePattern::SliceMut(x) => format!("SliceMut{}.Unmanaged", slice_t(x)),
             TypePattern::Option(e) => format!("{}.Unmanaged", e.the_enum().rust_name()),
@@ -100,6 +115,10 @@ pub fn field_to_type_unmanaged(x: &Type) -> String {
             TypePattern::APIVersion => field_to_type(&x.fallback_type()),
             TypePattern::AsyncCallback(_) => todo!("Async callbacks not supported in fields"),
             TypePattern::Vec(x) => format!("{}.Unmanaged", x.composite_type().rust_name()),
+            TypePattern::Map(x) => format!("{}.Unmanaged", x.composite_type().rust_name()),
+            TypePattern::Iter(x) => format!("{}.Unmanaged", x.composite_type().rust_name()),
+            TypePattern::Tuple(x) => format!("{}.Unmanaged", x.composite_type().rust_name()),
+            TypePattern::Duration(x) => format!("{}.Unmanaged", x.rust_name()),
         },
     }
 }
@@ -158,11 +177,18 @@ pub fn param_to_type(x: &Type) -> String {
         Type::Pattern(x) => match x {
             TypePattern::CStrPointer => "[MarshalAs(UnmanagedType.LPStr)] string".to_string(),
             TypePattern::Utf8String(x) => x.rust_name().to_string(),
+            TypePattern::Utf8Str(x) => x.rust_name().to_string(),
+            TypePattern::NdSlice(x) => x.rust_name().to_string(),
+            TypePattern::NdSliceMut(x) => x.rust_name().to_string(),
             TypePattern::Slice(x) => x.composite_type().rust_name().to_string(),
             TypePattern::SliceMut(x) => x.composite_type().rust_name().to_string(),
             TypePattern::Option(x) => x.the_enum().rust_name().to_string(),
             TypePattern::Result(x) => x.the_enum().rust_name().to_string(),
             TypePattern::Vec(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Map(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Iter(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Tuple(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Duration(x) => x.rust_name().to_string(),
             TypePattern::NamedCallback(x) => x.name().to_string(),
             TypePattern::AsyncCallback(_) => "AsyncCallbackCommonNative".to_string(),
             TypePattern::Bool => "Bool".to_string(),
@@ -179,6 +205,7 @@ pub fn param_to_type_overloaded(x: &Type) -> String {
             TypePattern::NamedCallback(_) => {
                 format!("{}Delegate", param_to_type(x))
             }
+            TypePattern::Utf8Str(_) => "ReadOnlySpan<byte>".to_string(),
             _ => param_to_type(x),
         },
         x => param_to_type(x),
@@ -253,6 +280,9 @@ pub fn rval_to_type_sync(x: &Type) -> String {
         Type::Pattern(x) => match x {
             TypePattern::CStrPointer => "IntPtr".to_string(),
             TypePattern::Utf8String(x) => x.rust_name().to_string(),
+            TypePattern::Utf8Str(x) => x.rust_name().to_string(),
+            TypePattern::NdSlice(x) => x.rust_name().to_string(),
+            TypePattern::NdSliceMut(x) => x.rust_name().to_string(),
             TypePattern::Result(x) => x.the_enum().rust_name().to_string(),
             TypePattern::Slice(x) => x.composite_type().rust_name().to_string(),
             TypePattern::SliceMut(x) => x.composite_type().rust_name().to_string(),
@@ -262,6 +292,10 @@ pub fn rval_to_type_sync(x: &Type) -> String {
             TypePattern::CChar => "sbyte".to_string(),
             TypePattern::APIVersion => rval_to_type_sync(&x.fallback_type()),
             TypePattern::Vec(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Map(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Iter(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Tuple(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Duration(x) => x.rust_name().to_string(),
             TypePattern::AsyncCallback(_) => panic!("AsyncCallback not supported in rvals"),
         },
     }
@@ -332,6 +366,16 @@ pub fn vec_t(x: &VecType) -> String {
     param_to_type(x.t())
 }
 
+/// For an `Iter<u8>`, returns the `u8` as a C# type, e.g., `byte`.
+pub fn iter_t(x: &IterType) -> String {
+    param_to_type(x.t())
+}
+
+/// For a `Map<u32, u8>`, returns the `u32` and `u8` as C# types, e.g., `uint` and `byte`.
+pub fn map_kv(x: &MapType) -> (String, String) {
+    (param_to_type(x.k()), param_to_type(x.v()))
+}
+
 /// Checks whether the managed C# original will still be valid after it has been moved to FFI.
 ///
 /// Under the hood this indicates whether the type does allocations that might be freed on the
@@ -368,6 +412,9 @@ pub fn is_reusable(t: &Type) -> bool {
         Type::Pattern(p) => match p {
             TypePattern::CStrPointer => true,
             TypePattern::Utf8String(_) => false,
+            TypePattern::Utf8Str(_) => true,
+            TypePattern::NdSlice(_) => true,
+            TypePattern::NdSliceMut(_) => true,
             TypePattern::APIVersion => true,
             TypePattern::Slice(_) => true,
             TypePattern::SliceMut(_) => true,
@@ -378,6 +425,10 @@ pub fn is_reusable(t: &Type) -> bool {
             TypePattern::NamedCallback(_) => true,
             TypePattern::AsyncCallback(_) => true,
             TypePattern::Vec(_) => false,
+            TypePattern::Map(_) => false,
+            TypePattern::Iter(_) => false,
+            TypePattern::Tuple(x) => x.types().iter().all(is_reusable),
+            TypePattern::Duration(_) => true,
         },
     }
 }
@@ -418,6 +469,9 @@ pub fn has_dispose(t: &Type) -> bool {
         Type::Pattern(p) => match p {
             TypePattern::CStrPointer => false,
             TypePattern::Utf8String(_) => true,
+            TypePattern::Utf8Str(_) => false,
+            TypePattern::NdSlice(_) => false,
+            TypePattern::NdSliceMut(_) => false,
             TypePattern::APIVersion => false,
             TypePattern::Slice(_) => true,
             TypePattern::SliceMut(_) => true,
@@ -428,6 +482,10 @@ pub fn has_dispose(t: &Type) -> bool {
             TypePattern::NamedCallback(_) => true,
             TypePattern::AsyncCallback(_) => true,
             TypePattern::Vec(_) => true,
+            TypePattern::Map(_) => true,
+            TypePattern::Iter(_) => true,
+            TypePattern::Tuple(x) => x.types().iter().any(has_dispose),
+            TypePattern::Duration(_) => false,
         },
     }
 }
@@ -48,9 +48,9 @@ impl<'a> Markdown<'a> {
         indented!(w, r"### Classes")?;
         indented!(w, r"Methods operating on common state.")?;
 
-        for pattern in self.interop.inventory.patterns().iter().map(|x| match x {
-            LibraryPattern::Service(s) => s,
-            _ => panic!("Pattern not explicitly handled"),
+        for pattern in self.interop.inventory.patterns().iter().filter_map(|x| match x {
+            LibraryPattern::Service(s) => Some(s),
+            _ => None,
         }) {
             let prefix = pattern.common_prefix();
             let doc = pattern.the_type().meta().docs().lines().first().cloned().unwrap_or_default();
@@ -70,6 +70,12 @@ impl<'a> Markdown<'a> {
                 let doc = x.meta().docs().lines().first().cloned().unwrap_or_default();
                 indented!(w, r"     - **[{}](#{})** - {}", func_name, target, doc)?;
             }
+            for x in pattern.static_methods() {
+                let func_name = function_name(x, FunctionNameFlavor::CSharpMethodWithoutClass(&prefix));
+                let target = format!("{name}.{func_name}");
+                let doc = x.meta().docs().lines().first().cloned().unwrap_or_default();
+                indented!(w, r"     - **[{}](#{})** <sup>**static**</sup> - {}", func_name, target, doc)?;
+            }
         }
 
         w.newline()?;
@@ -232,9 +238,9 @@ impl<'a> Markdown<'a> {
     fn write_services(&self, w: &mut IndentWriter) -> Result<(), Error> {
         indented!(w, r"# Classes")?;
 
-        for pattern in self.interop.inventory.patterns().iter().map(|x| match x {
-            LibraryPattern::Service(s) => s,
-            _ => panic!("Pattern not explicitly handled"),
+        for pattern in self.interop.inventory.patterns().iter().filter_map(|x| match x {
+            LibraryPattern::Service(s) => Some(s),
+            _ => None,
         }) {
             let prefix = pattern.common_prefix();
             let doc = pattern.the_type().meta().docs().lines();
@@ -252,7 +258,7 @@ impl<'a> Markdown<'a> {
 
             for x in pattern.constructors() {
                 let fname = function_name(x, FunctionNameFlavor::CSharpMethodWithoutClass(&prefix));
-                let target = fname.to_string();
+                let target = fname.clone();
                 indented!(w, r#"### <a name="{}">**{}**</a> <sup>ctor</sup>"#, target, target)?;
 
                 let doc = x.meta().docs().lines();
@@ -274,7 +280,7 @@ impl<'a> Markdown<'a> {
 
             for x in pattern.methods() {
                 let fname = function_name(x, FunctionNameFlavor::CSharpMethodWithoutClass(&prefix));
-                let target = fname.to_string();
+                let target = fname.clone();
 
                 indented!(w, r#"### <a name="{}">**{}**</a>"#, target, target)?;
 
@@ -301,6 +307,34 @@ impl<'a> Markdown<'a> {
                 w.newline()?;
             }
 
+            for x in pattern.static_methods() {
+                let fname = function_name(x, FunctionNameFlavor::CSharpMethodWithoutClass(&prefix));
+                let target = fname.clone();
+
+                indented!(w, r#"### <a name="{}">**{}**</a> <sup>static</sup>"#, target, target)?;
+
+                let doc = x.meta().docs().lines();
+                for line in doc {
+                    let line = line.replace(" # ", " #### ");
+                    let line = line.replace(" ## ", " ##### ");
+                    let line = line.replace(" ### ", " ###### ");
+                    indented!(w, r"{}", line)?;
+                }
+
+                w.newline()?;
+                indented!(w, r"#### Definition ")?;
+                indented!(w, r"```csharp")?;
+                indented!(w, r"{} class {} {{", self.interop.visibility_types.to_access_modifier(), class_name)?;
+                w.indent();
+                write_pattern_service_method(self.interop, w, pattern, x, MethodType::Static, WriteFor::Docs)?;
+                w.unindent();
+                indented!(w, r"}}")?;
+                indented!(w, r"```")?;
+                w.newline()?;
+                indented!(w, r"---")?;
+                w.newline()?;
+            }
+
             w.newline()?;
             w.newline()?;
         }
@@ -24,7 +24,7 @@ use crate::interop::types::write_type_definitions;
 use crate::interop::wires::write_wire_helpers;
 use derive_builder::Builder;
 use interoptopus::inventory::Inventory;
-use interoptopus::lang::util::is_global_type;
+use interoptopus::lang::util::{is_global_type, rval_may_borrow_str};
 use interoptopus::lang::{Constant, Function, Meta, NamespaceMappings, Signature, Type, WirePayload};
 use interoptopus::pattern::TypePattern;
 use interoptopus_backend_utils::{Error, IndentWriter, indented};
@@ -219,6 +219,11 @@ impl Interop {
         Ok(())
     }
 
+    /// Whether `interoptopus_last_error` from `builtins_string!()` is part of the inventory.
+    fn has_last_error(&self) -> bool {
+        self.inventory.functions().iter().any(|x| x.name() == "interoptopus_last_error")
+    }
+
     #[must_use]
     #[allow(dead_code)] // TODO?
     fn should_emit_delegate(&self) -> bool {
@@ -320,6 +325,9 @@ impl Interop {
             Type::Pattern(TypePattern::Slice(_)) => format!("{}.Unmanaged", param_to_type(t)),
             Type::Pattern(TypePattern::SliceMut(_)) => format!("{}.Unmanaged", param_to_type(t)),
             Type::Pattern(TypePattern::Utf8String(_)) => format!("{}.Unmanaged", param_to_type(t)),
+            Type::Pattern(TypePattern::Utf8Str(_)) => format!("{}.Unmanaged", param_to_type(t)),
+            Type::Pattern(TypePattern::NdSlice(_)) => format!("{}.Unmanaged", param_to_type(t)),
+            Type::Pattern(TypePattern::NdSliceMut(_)) => format!("{}.Unmanaged", param_to_type(t)),
             Type::Composite(_) => format!("{}.Unmanaged", param_to_type(t)),
             Type::Enum(_) => format!("{}.Unmanaged", param_to_type(t)),
             _ => param_to_type(t),
@@ -328,10 +336,16 @@ impl Interop {
 
     #[allow(clippy::match_like_matches_macro)]
     fn has_overloadable(&self, signature: &Signature) -> bool {
+        // Overloads pin or convert `Utf8Str` arguments only for the duration of the call.
+        if rval_may_borrow_str(signature) {
+            return false;
+        }
+
         signature.params().iter().any(|x| match x.the_type() {
             Type::Pattern(p) => match p {
                 TypePattern::NamedCallback(_) => true,
                 TypePattern::AsyncCallback(_) => true,
+                TypePattern::Utf8Str(_) => true,
                 _ => false,
             },
             _ => false,
@@ -380,7 +394,14 @@ impl Interop {
                 TypePattern::NamedCallback(x) => self.should_emit_by_meta(x.meta()),
                 TypePattern::AsyncCallback(x) => self.should_emit_by_meta(x.meta()),
                 TypePattern::Vec(x) => self.should_emit_by_meta(x.meta()),
+                TypePattern::Map(x) => self.should_emit_by_meta(x.meta()),
+                TypePattern::Iter(x) => self.should_emit_by_meta(x.meta()),
+                TypePattern::Tuple(x) => self.should_emit_by_meta(x.meta()),
+                TypePattern::Duration(x) => self.should_emit_by_meta(x.meta()),
                 TypePattern::Utf8String(_) => false,
+                TypePattern::Utf8Str(x) => self.should_emit_by_meta(x.meta()),
+                TypePattern::NdSlice(x) => self.should_emit_by_meta(x.meta()),
+                TypePattern::NdSliceMut(x) => self.should_emit_by_meta(x.meta()),
             },
         }
     }
@@ -429,7 +450,7 @@ impl Interop {
                 }
             } else {
                 if self.has_emittable_constants(self.inventory.constants()) {
-                    write_class_context(self, self.class_constants.as_ref().unwrap(), w, |w| {
+                    write_class_context(self, self.class_constants.as_ref().unwrap_or(&self.class), w, |w| {
                         write_constants(self, w)?;
                         w.newline()?;
 
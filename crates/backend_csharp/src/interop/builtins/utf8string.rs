@@ -5,7 +5,8 @@ pub fn write_utf8_string(i: &Interop, w: &mut IndentWriter) -> Result<(), Error>
     if i.write_types.write_interoptopus_globals() {
         let class_name = i.class_constants.as_ref().map_or(&i.class, |name| name);
         let extra_fn_decorations = i.fn_decorations();
-        render!(w, "builtins/utf8string.cs", ("class_name", class_name), ("extra_fn_decorations", &extra_fn_decorations))?;
+        let has_last_error = i.has_last_error();
+        render!(w, "builtins/utf8string.cs", ("class_name", class_name), ("extra_fn_decorations", &extra_fn_decorations), ("has_last_error", &has_last_error))?;
     }
     Ok(())
 }
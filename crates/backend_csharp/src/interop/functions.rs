@@ -1,8 +1,9 @@
-use crate::converter::{function_name, param_to_type, param_to_type_overloaded, rval_to_type_async, rval_to_type_sync};
+use crate::converter::{field_to_type, function_name, is_reusable, param_to_type, param_to_type_overloaded, rval_to_type_async, rval_to_type_sync};
 use crate::interop::DecorateFn;
 use crate::interop::docs::write_documentation;
 use crate::utils::sugared_return_type;
 use crate::{FunctionNameFlavor, Interop};
+use interoptopus::lang::util::rval_may_borrow_str;
 use interoptopus::lang::{Function, Primitive, SugaredReturnType, Type};
 use interoptopus::pattern::TypePattern;
 use interoptopus_backend_utils::{Error, IndentWriter, WriteFor, indented};
@@ -28,6 +29,8 @@ pub fn write_function(i: &Interop, w: &mut IndentWriter, function: &Function, wr
     write_function_declaration(i, w, function, false)?;
     w.newline()?;
     write_function_overload(i, w, function, write_for)?;
+    write_function_overload_str(i, w, function, write_for)?;
+    write_function_overload_option(i, w, function, write_for)?;
 
     Ok(())
 }
@@ -95,6 +98,7 @@ pub fn write_function_overload(i: &Interop, w: &mut IndentWriter, function: &Fun
     let mut to_invoke = Vec::new();
     let mut to_wrap_name = Vec::new();
     let mut to_wrap_type = Vec::new();
+    let mut to_pin = Vec::new();
 
     let raw_name = function_name(function, FunctionNameFlavor::RawFFIName);
 
@@ -119,6 +123,10 @@ pub fn write_function_overload(i: &Interop, w: &mut IndentWriter, function: &Fun
                 to_wrap_type.push(param_to_type(p.the_type()));
                 to_invoke.push(format!("{name}_wrapped"));
             }
+            Type::Pattern(TypePattern::Utf8Str(x)) => {
+                to_pin.push(name);
+                to_invoke.push(format!("{}.From((IntPtr) {name}_ptr, (ulong) {name}.Length)", x.rust_name()));
+            }
             _ => fallback(),
         }
 
@@ -154,8 +162,20 @@ pub fn write_function_overload(i: &Interop, w: &mut IndentWriter, function: &Fun
         indented!(w, [()], r"var {}_wrapped = new {}({});", n, t, n)?;
     }
 
-    indented!(w, [()], r"try")?;
-    indented!(w, [()], r"{{")?;
+    // Spans are pinned in place, nothing is copied.
+    for n in &to_pin {
+        indented!(w, [()], r"fixed (byte* {n}_ptr = {n})")?;
+        indented!(w, [()], r"{{")?;
+        w.indent();
+    }
+
+    // Only wrapped callbacks need disposing afterwards.
+    let needs_finally = !to_wrap_name.is_empty();
+    if needs_finally {
+        indented!(w, [()], r"try")?;
+        indented!(w, [()], r"{{")?;
+        w.indent();
+    }
 
     let fn_name = function_name(function, FunctionNameFlavor::RawFFIName);
 
@@ -163,27 +183,35 @@ pub fn write_function_overload(i: &Interop, w: &mut IndentWriter, function: &Fun
 
     match function.signature().rval() {
         Type::Pattern(TypePattern::CStrPointer) => {
-            indented!(w, [()()], r"var _s = {};", call)?;
-            indented!(w, [()()], r"return Marshal.PtrToStringAnsi(_s);")?;
+            indented!(w, [()], r"var _s = {};", call)?;
+            indented!(w, [()], r"return Marshal.PtrToStringAnsi(_s);")?;
         }
         Type::Primitive(Primitive::Void) => {
-            indented!(w, [()()], r"{};", call)?;
+            indented!(w, [()], r"{};", call)?;
         }
         _ if matches!(async_rval, SugaredReturnType::Async(_)) => {
-            indented!(w, [()()], r"{call}.AsOk();")?;
-            indented!(w, [()()], r"return _cs;")?;
+            indented!(w, [()], r"{call}.AsOk();")?;
         }
         _ => {
-            indented!(w, [()()], r"return {call};")?;
+            indented!(w, [()], r"return {call};")?;
+        }
+    }
+
+    if needs_finally {
+        w.unindent();
+        indented!(w, [()], r"}}")?;
+        indented!(w, [()], r"finally")?;
+        indented!(w, [()], r"{{")?;
+        for n in to_wrap_name {
+            indented!(w, [()()], r"{}_wrapped.Dispose();", n)?;
         }
+        indented!(w, [()], r"}}")?;
     }
-    indented!(w, [()], r"}}")?;
-    indented!(w, [()], r"finally")?;
-    indented!(w, [()], r"{{")?;
-    for n in to_wrap_name {
-        indented!(w, [()()], r"{}_wrapped.Dispose();", n)?;
+
+    for _ in &to_pin {
+        w.unindent();
+        indented!(w, [()], r"}}")?;
     }
-    indented!(w, [()], r"}}")?;
 
     if matches!(async_rval, SugaredReturnType::Async(_)) {
         indented!(w, [()], r"return _cs;")?;
@@ -193,3 +221,152 @@ pub fn write_function_overload(i: &Interop, w: &mut IndentWriter, function: &Fun
 
     Ok(())
 }
+
+/// Writes an overload accepting `string` for each `Utf8Str`, forwarding to the `ReadOnlySpan<byte>` overload.
+pub fn write_function_overload_str(i: &Interop, w: &mut IndentWriter, function: &Function, write_for: WriteFor) -> Result<(), Error> {
+    i.debug(w, "write_function_overload_str")?;
+
+    if !has_str_param(function) {
+        return Ok(());
+    }
+
+    let async_rval = sugared_return_type(function);
+    let raw_name = function_name(function, FunctionNameFlavor::RawFFIName);
+    let rval = rval_to_type_async(&async_rval);
+
+    let mut params = Vec::new();
+    let mut to_invoke = Vec::new();
+    for p in function.signature().params() {
+        let name = p.name();
+        let native = param_to_type_overloaded(p.the_type());
+
+        match p.the_type() {
+            Type::Pattern(TypePattern::Utf8Str(_)) => {
+                params.push(format!("string {name}"));
+                to_invoke.push(format!("Encoding.UTF8.GetBytes({name})"));
+            }
+            _ if native.contains("ref ") => {
+                params.push(format!("{native} {name}"));
+                to_invoke.push(format!("ref {name}"));
+            }
+            _ => {
+                params.push(format!("{native} {name}"));
+                to_invoke.push(name.to_string());
+            }
+        }
+    }
+
+    if matches!(async_rval, SugaredReturnType::Async(_)) {
+        params.pop();
+        to_invoke.pop();
+    }
+
+    let signature = format!(r"public static {} {}({})", rval, raw_name, params.join(", "));
+    if write_for == WriteFor::Docs {
+        indented!(w, r"{};", signature)?;
+        return Ok(());
+    }
+
+    if write_for == WriteFor::Code {
+        w.newline()?;
+        write_documentation(w, function.meta().docs())?;
+    }
+
+    let call = format!(r"{}({})", raw_name, to_invoke.join(", "));
+
+    i.inline_hint(w, 0)?;
+    indented!(w, "{}", signature)?;
+    indented!(w, r"{{")?;
+    if rval == "void" {
+        indented!(w, [()], r"{call};")?;
+    } else {
+        indented!(w, [()], r"return {call};")?;
+    }
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
+/// Writes an overload accepting `T?` for each `Option<T>` of a value type, forwarding via `FromNullable`.
+pub fn write_function_overload_option(i: &Interop, w: &mut IndentWriter, function: &Function, write_for: WriteFor) -> Result<(), Error> {
+    i.debug(w, "write_function_overload_option")?;
+
+    if !has_nullable_option_param(function) {
+        return Ok(());
+    }
+
+    let async_rval = sugared_return_type(function);
+    let raw_name = function_name(function, FunctionNameFlavor::RawFFIName);
+    let rval = rval_to_type_async(&async_rval);
+
+    let mut params = Vec::new();
+    let mut to_invoke = Vec::new();
+    for p in function.signature().params() {
+        let name = p.name();
+        let native = param_to_type_overloaded(p.the_type());
+
+        match p.the_type() {
+            Type::Pattern(TypePattern::Option(x)) if is_reusable(x.t()) => {
+                params.push(format!("{}? {name}", field_to_type(x.t())));
+                to_invoke.push(format!("{native}.FromNullable({name})"));
+            }
+            _ if native.contains("ref ") => {
+                params.push(format!("{native} {name}"));
+                to_invoke.push(format!("ref {name}"));
+            }
+            _ => {
+                params.push(format!("{native} {name}"));
+                to_invoke.push(name.to_string());
+            }
+        }
+    }
+
+    if matches!(async_rval, SugaredReturnType::Async(_)) {
+        params.pop();
+        to_invoke.pop();
+    }
+
+    let signature = format!(r"public static {} {}({})", rval, raw_name, params.join(", "));
+    if write_for == WriteFor::Docs {
+        indented!(w, r"{};", signature)?;
+        return Ok(());
+    }
+
+    if write_for == WriteFor::Code {
+        // Only separate from other overloads, the declaration is already followed by a blank line.
+        if i.has_overloadable(function.signature()) || has_str_param(function) {
+            w.newline()?;
+        }
+        write_documentation(w, function.meta().docs())?;
+    }
+
+    let call = format!(r"{}({})", raw_name, to_invoke.join(", "));
+
+    i.inline_hint(w, 0)?;
+    indented!(w, "{}", signature)?;
+    indented!(w, r"{{")?;
+    if rval == "void" {
+        indented!(w, [()], r"{call};")?;
+    } else {
+        indented!(w, [()], r"return {call};")?;
+    }
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
+/// Options of reference types are left alone, as `null` would be ambiguous between both overloads.
+#[must_use]
+pub fn has_nullable_option_param(function: &Function) -> bool {
+    function
+        .signature()
+        .params()
+        .iter()
+        .any(|x| matches!(x.the_type(), Type::Pattern(TypePattern::Option(o)) if is_reusable(o.t())))
+}
+
+#[must_use]
+pub fn has_str_param(function: &Function) -> bool {
+    let signature = function.signature();
+    !rval_may_borrow_str(signature) && signature.params().iter().any(|x| matches!(x.the_type(), Type::Pattern(TypePattern::Utf8Str(_))))
+}
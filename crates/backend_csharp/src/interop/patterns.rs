@@ -1,12 +1,21 @@
 pub mod abi_guard;
 pub mod asynk;
 pub mod callbacks;
+pub mod duration;
+pub mod interfaces;
+pub mod iter;
+pub mod map;
+pub mod ndslice;
 pub mod options;
+pub mod results;
 pub mod services;
 pub mod slices;
+pub mod str;
+pub mod tuples;
 pub mod vec;
 
 use crate::Interop;
+use crate::interop::patterns::interfaces::{write_pattern_foreign, write_pattern_interface};
 use crate::interop::patterns::services::write_pattern_service;
 use interoptopus::pattern::LibraryPattern;
 use interoptopus_backend_utils::{Error, IndentWriter};
@@ -19,6 +28,16 @@ pub fn write_patterns(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
                     write_pattern_service(i, w, cls)?;
                 }
             }
+            LibraryPattern::Interface(interface) => {
+                if i.should_emit_by_meta(interface.meta()) {
+                    write_pattern_interface(i, w, interface)?;
+                }
+            }
+            LibraryPattern::Foreign(foreign) => {
+                if i.should_emit_by_meta(foreign.meta()) {
+                    write_pattern_foreign(i, w, foreign)?;
+                }
+            }
             LibraryPattern::Builtins(_) => {}
             _ => panic!("Pattern not explicitly handled"),
         }
@@ -3,6 +3,7 @@ use crate::converter::{is_reusable, param_to_managed, param_to_type, rval_to_typ
 use crate::interop::types::fnptrs::write_type_definition_fn_pointer_annotation;
 use crate::utils::{MoveSemantics, write_common_marshaller};
 use interoptopus::lang::{Primitive, Type};
+use interoptopus::pattern::TypePattern;
 use interoptopus::pattern::callback::NamedCallback;
 use interoptopus_backend_utils::{Error, IndentWriter, indented};
 
@@ -14,6 +15,7 @@ pub fn write_type_definition_named_callback(i: &Interop, w: &mut IndentWriter, t
 
     let name = the_type.name().to_string();
     let visibility = i.visibility_types.to_access_modifier();
+    let owned = the_type.is_owned();
 
     let mut params = Vec::new();
     let mut params_native = Vec::new();
@@ -40,6 +42,10 @@ pub fn write_type_definition_named_callback(i: &Interop, w: &mut IndentWriter, t
     indented!(w, [()], r"private {}Native _native; // Native callback ", name)?;
     indented!(w, [()], r"private IntPtr _ptr; // Raw function pointer of native callback")?;
     indented!(w, [()], r"private Exception _exception; // Set if the callback encountered an Exception")?;
+    if owned {
+        indented!(w, [()], r"private IntPtr _data; // Callback data of an owned callback received from Rust")?;
+        indented!(w, [()], r"private IntPtr _destructor; // Destructor of an owned callback received from Rust")?;
+    }
     indented!(w, r"}}")?;
     w.newline()?;
     indented!(w, r"[NativeMarshalling(typeof(MarshallerMeta))]")?;
@@ -70,14 +76,33 @@ pub fn write_type_definition_named_callback(i: &Interop, w: &mut IndentWriter, t
         _ => indented!(w, [()()()], r"return _managed({params_invoke}).IntoUnmanaged();")?,
     }
     indented!(w, [()()], r"}}")?;
-    indented!(w, [()()], r"catch (Exception e)")?;
-    indented!(w, [()()], r"{{")?;
-    indented!(w, [()()()], r"_exception = e;")?;
     match the_type.fnpointer().signature().rval() {
-        Type::Primitive(Primitive::Void) => indented!(w, [()()()], r"return;")?,
-        _ => indented!(w, [()()()], r"return default;")?,
+        // Callbacks returning a result report exceptions back to Rust instead of rethrowing them.
+        Type::Pattern(TypePattern::Result(x)) => {
+            let result = x.the_enum().rust_name();
+            let to_unmanaged = if is_reusable(&x.the_enum().to_type()) { "ToUnmanaged" } else { "IntoUnmanaged" };
+            if let Type::Enum(e) = x.e() {
+                indented!(w, [()()], r"catch ({}Exception e)", e.rust_name())?;
+                indented!(w, [()()], r"{{")?;
+                indented!(w, [()()()], r"return {result}.Err(e.Error).{to_unmanaged}();")?;
+                indented!(w, [()()], r"}}")?;
+            }
+            indented!(w, [()()], r"catch (Exception)")?;
+            indented!(w, [()()], r"{{")?;
+            indented!(w, [()()()], r"return {result}.Panic.{to_unmanaged}();")?;
+            indented!(w, [()()], r"}}")?;
+        }
+        rval => {
+            indented!(w, [()()], r"catch (Exception e)")?;
+            indented!(w, [()()], r"{{")?;
+            indented!(w, [()()()], r"_exception = e;")?;
+            match rval {
+                Type::Primitive(Primitive::Void) => indented!(w, [()()()], r"return;")?,
+                _ => indented!(w, [()()()], r"return default;")?,
+            }
+            indented!(w, [()()], r"}}")?;
+        }
     }
-    indented!(w, [()()], r"}}")?;
     indented!(w, [()], r"}}")?;
     w.newline()?;
     indented!(w, [()], r"// Invokes the callback.")?;
@@ -100,6 +125,15 @@ pub fn write_type_definition_named_callback(i: &Interop, w: &mut IndentWriter, t
     i.inline_hint(w, 1)?;
     indented!(w, [()], r"public void Dispose()")?;
     indented!(w, [()], r"{{")?;
+    if owned {
+        indented!(w, [()()], r"// If Rust handed us an owned callback we are now responsible for releasing it.")?;
+        indented!(w, [()()], r"if (_destructor != IntPtr.Zero)")?;
+        indented!(w, [()()], r"{{")?;
+        indented!(w, [()()()], r"Marshal.GetDelegateForFunctionPointer<DestructorNative>(_destructor)(_data);")?;
+        indented!(w, [()()()], r"_destructor = IntPtr.Zero;")?;
+        indented!(w, [()()], r"}}")?;
+        w.newline()?;
+    }
     indented!(w, [()()], r"// This means when the callback was invoked from Rust C# had an exception which")?;
     indented!(w, [()()], r"// we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is")?;
     indented!(w, [()()], r"// the time to rethrow it.")?;
@@ -111,10 +145,28 @@ pub fn write_type_definition_named_callback(i: &Interop, w: &mut IndentWriter, t
     indented!(w, [()], r"{{")?;
     indented!(w, [()()], r"var rval = new Unmanaged();")?;
     indented!(w, [()()], r"rval._callback = _ptr;")?;
-    indented!(w, [()()], r"rval._data = IntPtr.Zero;")?;
+    if owned {
+        indented!(w, [()()], r"// Keeps this callback alive until Rust drops it and invokes the destructor.")?;
+        indented!(w, [()()], r"rval._data = GCHandle.ToIntPtr(GCHandle.Alloc(this));")?;
+        indented!(w, [()()], r"rval._destructor = DestructorPtr;")?;
+    } else {
+        indented!(w, [()()], r"rval._data = IntPtr.Zero;")?;
+    }
     indented!(w, [()()], r"return rval;")?;
     indented!(w, [()], r"}}")?;
     w.newline()?;
+    if owned {
+        indented!(w, [()], r"private delegate void DestructorNative(IntPtr data);")?;
+        indented!(w, [()], r"private static readonly DestructorNative Destructor = ReleaseHandle;")?;
+        indented!(w, [()], r"private static readonly IntPtr DestructorPtr = Marshal.GetFunctionPointerForDelegate(Destructor);")?;
+        w.newline()?;
+        indented!(w, [()], r"// Invoked by Rust once it dropped the callback.")?;
+        indented!(w, [()], r"private static void ReleaseHandle(IntPtr data)")?;
+        indented!(w, [()], r"{{")?;
+        indented!(w, [()()], r"GCHandle.FromIntPtr(data).Free();")?;
+        indented!(w, [()], r"}}")?;
+        w.newline()?;
+    }
     indented!(w, [()], r"[CustomMarshaller(typeof({name}), MarshalMode.Default, typeof(Marshaller))]")?;
     indented!(w, [()], r"private struct MarshallerMeta {{  }}")?;
     w.newline()?;
@@ -123,11 +175,18 @@ pub fn write_type_definition_named_callback(i: &Interop, w: &mut IndentWriter, t
     indented!(w, [()], r"{{")?;
     indented!(w, [()()], r"internal IntPtr _callback;")?;
     indented!(w, [()()], r"internal IntPtr _data;")?;
+    if owned {
+        indented!(w, [()()], r"internal IntPtr _destructor;")?;
+    }
     w.newline()?;
     indented!(w, [()()], r"public {name} ToManaged()")?;
     indented!(w, [()()], r"{{")?;
     indented!(w, [()()()], r"var rval = new {name}();")?;
     indented!(w, [()()()], r"rval._ptr = _callback;")?;
+    if owned {
+        indented!(w, [()()()], r"rval._data = _data;")?;
+        indented!(w, [()()()], r"rval._destructor = _destructor;")?;
+    }
     indented!(w, [()()()], r"return rval;")?;
     indented!(w, [()()], r"}}")?;
     w.newline()?;
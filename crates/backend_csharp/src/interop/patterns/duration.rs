@@ -0,0 +1,34 @@
+use crate::Interop;
+use interoptopus::lang::Composite;
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+/// Writes conversions from and to `TimeSpan`, which has a resolution of 100ns and can be negative.
+pub fn write_pattern_duration(i: &Interop, w: &mut IndentWriter, duration: &Composite) -> Result<(), Error> {
+    i.debug(w, "write_pattern_duration")?;
+
+    let name = duration.rust_name();
+
+    indented!(w, r"public partial struct {name}")?;
+    indented!(w, r"{{")?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static implicit operator {name}(TimeSpan value) => FromTimeSpan(value);")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static implicit operator TimeSpan({name} value) => value.ToTimeSpan();")?;
+    w.newline()?;
+    indented!(w, [()], r"// Rust durations can't be negative, and sub-tick nanoseconds get truncated on the way back.")?;
+    indented!(w, [()], r"public static {name} FromTimeSpan(TimeSpan value)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"if (value < TimeSpan.Zero) throw new ArgumentOutOfRangeException(nameof(value), value, null);")?;
+    indented!(w, [()()], r"var rval = new {name}();")?;
+    indented!(w, [()()], r"rval.secs = (ulong) (value.Ticks / TimeSpan.TicksPerSecond);")?;
+    indented!(w, [()()], r"rval.nanos = (uint) (value.Ticks % TimeSpan.TicksPerSecond * 100);")?;
+    indented!(w, [()()], r"return rval;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public TimeSpan ToTimeSpan() => TimeSpan.FromTicks(checked((long) secs * TimeSpan.TicksPerSecond + nanos / 100));")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use crate::converter::{field_to_type, function_name, param_to_type, rval_to_type_sync};
+use crate::interop::docs::write_documentation;
+use crate::interop::patterns::services::{MethodType, params};
+use crate::{FunctionNameFlavor, Interop};
+use heck::ToUpperCamelCase;
+use interoptopus::lang::{Function, Primitive, Type};
+use interoptopus::pattern::TypePattern;
+use interoptopus::pattern::service::{ForeignInterface, ServiceDefinition, ServiceInterface, ServiceInterfaceImpl};
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+pub fn write_pattern_interface(i: &Interop, w: &mut IndentWriter, interface: &ServiceInterface) -> Result<(), Error> {
+    i.debug(w, "write_pattern_interface")?;
+
+    let name = interface.name();
+    let handle = interface.handle().rust_name();
+
+    write_documentation(w, interface.meta().docs())?;
+    indented!(w, r"{} partial interface I{}", i.visibility_types.to_access_modifier(), name)?;
+    indented!(w, r"{{")?;
+    w.indent();
+    indented!(w, r"{} As{}();", handle, name)?;
+
+    for function in interface.methods() {
+        write_documentation(w, function.meta().docs())?;
+        indented!(w, r"{};", signature(interface, function))?;
+    }
+
+    w.unindent();
+    indented!(w, r"}}")?;
+    w.newline()?;
+    w.newline()?;
+
+    Ok(())
+}
+
+/// Lists the interfaces a service class derives from, e.g., `, IStorage, ICache`.
+#[must_use]
+pub fn interface_list(class: &ServiceDefinition) -> String {
+    class.interfaces().iter().map(|x| ", I".to_string() + x.interface().name()).collect()
+}
+
+/// Writes the `AsX()` conversion and all interface methods not already provided by the service.
+pub fn write_service_interface_impl(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition, interface_impl: &ServiceInterfaceImpl) -> Result<(), Error> {
+    i.debug(w, "write_service_interface_impl")?;
+
+    let interface = interface_impl.interface();
+    let as_handle = interface_impl.as_handle();
+    let common_prefix = class.common_prefix();
+    let existing = class
+        .methods()
+        .iter()
+        .map(|x| function_name(x, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix)))
+        .collect::<Vec<_>>();
+
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public {} As{}()", interface.handle().rust_name(), interface.name())?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"return {}.{}(_context);", i.class, function_name(as_handle, FunctionNameFlavor::RawFFIName))?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    for function in interface.methods() {
+        let fn_name = function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(interface.common_prefix()));
+        if existing.contains(&fn_name) {
+            continue;
+        }
+
+        let (_, _, to_invoke) = params(function, MethodType::Regular, false);
+        let mut args = vec![format!("As{}()", interface.name())];
+        args.extend(to_invoke);
+        let fn_call = format!(r"{}.{}({})", i.class, function_name(function, FunctionNameFlavor::RawFFIName), args.join(", "));
+
+        write_documentation(w, function.meta().docs())?;
+        i.inline_hint(w, 0)?;
+        indented!(w, r"public {}", signature(interface, function))?;
+        indented!(w, r"{{")?;
+        match function.signature().rval() {
+            Type::Primitive(Primitive::Void) => indented!(w, [()], r"{fn_call};")?,
+            Type::Pattern(TypePattern::Result(x)) if x.t().is_void() => indented!(w, [()], r"{fn_call}.AsOk();")?,
+            Type::Pattern(TypePattern::Result(_)) => indented!(w, [()], r"return {fn_call}.AsOk();")?,
+            _ => indented!(w, [()], r"return {fn_call};")?,
+        }
+        indented!(w, r"}}")?;
+        w.newline()?;
+    }
+
+    Ok(())
+}
+
+fn signature(interface: &ServiceInterface, function: &Function) -> String {
+    let fn_name = function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(interface.common_prefix()));
+    let (names, types, _) = params(function, MethodType::Regular, false);
+    let args = names.iter().zip(types.iter()).map(|(n, t)| format!("{t} {n}")).collect::<Vec<_>>();
+
+    let rval = match function.signature().rval() {
+        Type::Pattern(TypePattern::Result(x)) if x.t().is_void() => "void".to_string(),
+        Type::Pattern(TypePattern::Result(x)) => field_to_type(x.t()),
+        x => rval_to_type_sync(x),
+    };
+
+    format!(r"{rval} {fn_name}({})", args.join(", "))
+}
+
+/// Writes an abstract base class users can subclass to implement a foreign trait.
+pub fn write_pattern_foreign(i: &Interop, w: &mut IndentWriter, foreign: &ForeignInterface) -> Result<(), Error> {
+    i.debug(w, "write_pattern_foreign")?;
+
+    let name = foreign.name();
+    let vtable = foreign.vtable().rust_name();
+
+    write_documentation(w, foreign.meta().docs())?;
+    indented!(w, r"{} abstract partial class {}Base", i.visibility_types.to_access_modifier(), name)?;
+    indented!(w, r"{{")?;
+    w.indent();
+    indented!(w, r"private {} _vtable;", vtable)?;
+    w.newline()?;
+    indented!(w, r"protected {}Base()", name)?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"_vtable = new {}", vtable)?;
+    indented!(w, [()], r"{{")?;
+    for (field, callback) in foreign.methods() {
+        indented!(w, [()()], r"{} = new {}({}),", field.name(), callback.name(), field.name().to_upper_camel_case())?;
+    }
+    indented!(w, [()], r"}};")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"/// Returns a vtable forwarding to this instance, valid as long as this instance is alive.")?;
+    indented!(w, r"public {} VTable => _vtable;", vtable)?;
+
+    for (field, callback) in foreign.methods() {
+        let signature = callback.fnpointer().signature();
+        let mut params = signature
+            .params()
+            .iter()
+            .map(|x| format!("{} {}", param_to_type(x.the_type()), x.name()))
+            .collect::<Vec<_>>();
+        params.pop();
+
+        w.newline()?;
+        write_documentation(w, field.docs())?;
+        indented!(w, r"public abstract {} {}({});", rval_to_type_sync(signature.rval()), field.name().to_upper_camel_case(), params.join(", "))?;
+    }
+
+    w.unindent();
+    indented!(w, r"}}")?;
+    w.newline()?;
+    w.newline()?;
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use crate::Interop;
+use crate::converter::{is_reusable, iter_t};
+use crate::utils::{MoveSemantics, write_common_marshaller};
+use interoptopus::lang::{Parameter, Type};
+use interoptopus::pattern::TypePattern;
+use interoptopus::pattern::iter::IterType;
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+pub fn write_pattern_iter(i: &Interop, w: &mut IndentWriter, iter: &IterType) -> Result<(), Error> {
+    i.debug(w, "write_pattern_iter")?;
+
+    let name = iter.rust_name();
+    let the_type = iter_t(iter);
+
+    write_pattern_iter_struct(i, w, iter)?;
+
+    indented!(w, r"[NativeMarshalling(typeof(MarshallerMeta))]")?;
+    indented!(w, r"public partial class {name} : IEnumerable<{the_type}>, IDisposable")?;
+    indented!(w, r"{{")?;
+    w.indent();
+
+    indented!(w, r"// An internal helper to create an empty object.")?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"private {name}() {{ }}")?;
+    w.newline()?;
+    write_pattern_iter_next(i, w, iter)?;
+    w.newline()?;
+    indented!(w, r"// Items can only be enumerated once, the handle is disposed afterwards.")?;
+    indented!(w, r"public IEnumerator<{the_type}> GetEnumerator()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"try")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"while (TryNext(out var item)) {{ yield return item; }}")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"finally")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"Dispose();")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"IEnumerator IEnumerable.GetEnumerator() {{ return GetEnumerator(); }}")?;
+    w.newline()?;
+    write_pattern_iter_to_unmanaged(i, w)?;
+    w.newline()?;
+    write_pattern_iter_helpers(i, w, iter)?;
+    w.newline()?;
+    write_pattern_iter_interop_helper(i, w, iter)?;
+    w.newline()?;
+    indented!(w, r"[CustomMarshaller(typeof({}), MarshalMode.Default, typeof(Marshaller))]", name)?;
+    indented!(w, r"private struct MarshallerMeta {{ }}")?;
+    w.newline()?;
+    indented!(w, r"[StructLayout(LayoutKind.Sequential)]")?;
+    indented!(w, r"public struct Unmanaged")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"internal IntPtr _ptr;")?;
+    w.newline()?;
+    write_pattern_iter_to_managed(i, w, name)?;
+    w.newline()?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    write_common_marshaller(i, w, name, MoveSemantics::Move)?;
+    w.unindent();
+    indented!(w, r"}}")?;
+    w.newline()?;
+    Ok(())
+}
+
+pub fn write_pattern_iter_struct(_: &Interop, w: &mut IndentWriter, iter: &IterType) -> Result<(), Error> {
+    let name = iter.rust_name();
+
+    indented!(w, r"// This must be a class because we only ever want to hold on to the")?;
+    indented!(w, r"// same instance, as we overwrite fields when this is sent over the FFI")?;
+    indented!(w, r"// boundary")?;
+    indented!(w, r"public partial class {}", name)?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"internal IntPtr _ptr;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    Ok(())
+}
+
+pub fn write_pattern_iter_next(i: &Interop, w: &mut IndentWriter, iter: &IterType) -> Result<(), Error> {
+    let the_type = iter_t(iter);
+
+    indented!(w, r"// Advances the iterator, returns false once it is exhausted.")?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public bool TryNext(out {the_type} item)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"var _unmanaged = AsUnmanaged();")?;
+    if is_reusable(iter.t()) {
+        indented!(w, [()], r"return InteropHelper.interoptopus_iter_next(ref _unmanaged, out item) != 0;")?;
+    } else {
+        indented!(w, [()], r"var rval = InteropHelper.interoptopus_iter_next(ref _unmanaged, out var _item) != 0;")?;
+        indented!(w, [()], r"item = rval ? _item.IntoManaged() : default;")?;
+        indented!(w, [()], r"return rval;")?;
+    }
+    indented!(w, r"}}")?;
+    Ok(())
+}
+
+pub fn write_pattern_iter_helpers(i: &Interop, w: &mut IndentWriter, iter: &IterType) -> Result<(), Error> {
+    let name = iter.rust_name();
+
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public void Dispose()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (_ptr == IntPtr.Zero) return;")?;
+    indented!(w, [()], r"var _unmanaged = new Unmanaged();")?;
+    indented!(w, [()], r"_unmanaged._ptr = _ptr;")?;
+    indented!(w, [()], r"InteropHelper.interoptopus_iter_destroy(_unmanaged);")?;
+    indented!(w, [()], r"_ptr = IntPtr.Zero;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public override string ToString()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r#"return "{name} {{ ... }}";"#)?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    Ok(())
+}
+
+#[allow(clippy::collapsible_if)]
+pub fn write_pattern_iter_interop_helper(i: &Interop, w: &mut IndentWriter, iter: &IterType) -> Result<(), Error> {
+    let the_type = iter_t(iter);
+    let item = if is_reusable(iter.t()) { the_type } else { format!("{the_type}.Unmanaged") };
+
+    indented!(w, r"public partial class InteropHelper")?;
+    indented!(w, r"{{")?;
+    for f in i.inventory.functions() {
+        let first_param = f.signature().params().first().map(Parameter::the_type).cloned();
+        let name = f.name();
+        let extra_fn_decorations = i.fn_decorations();
+
+        if name.starts_with("interoptopus_iter_next") {
+            if let Some(Type::ReadWritePointer(x)) = &first_param {
+                if let Type::Pattern(TypePattern::Iter(x)) = x.as_ref() {
+                    if x == iter {
+                        indented!(w, [()], r#"[LibraryImport(Interop.NativeLib, EntryPoint = "{name}")]"#)?;
+                        for decor in &extra_fn_decorations {
+                            indented!(w, [()], "{}", decor)?;
+                        }
+                        indented!(w, [()], r"internal static partial long interoptopus_iter_next(ref Unmanaged iter, out {item} rval);")?;
+                    }
+                }
+            }
+        }
+
+        if name.starts_with("interoptopus_iter_destroy") && first_param == Some(Type::Pattern(TypePattern::Iter(iter.clone()))) {
+            indented!(w, [()], r#"[LibraryImport(Interop.NativeLib, EntryPoint = "{name}")]"#)?;
+            for decor in &extra_fn_decorations {
+                indented!(w, [()], "{}", decor)?;
+            }
+            indented!(w, [()], r"internal static partial long interoptopus_iter_destroy(Unmanaged iter);")?;
+        }
+    }
+    indented!(w, r"}}")?;
+    Ok(())
+}
+
+pub fn write_pattern_iter_to_managed(i: &Interop, w: &mut IndentWriter, managed: &str) -> Result<(), Error> {
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public {managed} IntoManaged()")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"var rval = new {managed}();")?;
+    indented!(w, [()()], r"rval._ptr = _ptr;")?;
+    indented!(w, [()()], r"return rval;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    Ok(())
+}
+
+pub fn write_pattern_iter_to_unmanaged(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public Unmanaged IntoUnmanaged()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.")?;
+    indented!(w, [()], r"var rval = new Unmanaged();")?;
+    indented!(w, [()], r"rval._ptr = _ptr;")?;
+    indented!(w, [()], r"_ptr = IntPtr.Zero; // Mark this instance as moved.")?;
+    indented!(w, [()], r"return rval;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public Unmanaged AsUnmanaged()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.")?;
+    indented!(w, [()], r"var rval = new Unmanaged();")?;
+    indented!(w, [()], r"rval._ptr = _ptr;")?;
+    indented!(w, [()], r"return rval;")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
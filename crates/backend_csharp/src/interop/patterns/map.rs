@@ -0,0 +1,298 @@
+use crate::Interop;
+use crate::converter::{is_reusable, map_kv};
+use crate::utils::{MoveSemantics, write_common_marshaller};
+use interoptopus::lang::{Parameter, Type};
+use interoptopus::pattern::TypePattern;
+use interoptopus::pattern::map::MapType;
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+pub fn write_pattern_map(i: &Interop, w: &mut IndentWriter, map: &MapType) -> Result<(), Error> {
+    i.debug(w, "write_pattern_map")?;
+
+    let name = map.rust_name();
+    let (k, v) = map_kv(map);
+
+    write_pattern_map_struct(i, w, map)?;
+
+    indented!(w, r"[NativeMarshalling(typeof(MarshallerMeta))]")?;
+    indented!(w, r"public partial class {name} : IReadOnlyDictionary<{k}, {v}>, IDisposable")?;
+    indented!(w, r"{{")?;
+    w.indent();
+
+    indented!(w, r"// An internal helper to create an empty object.")?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"private {name}() {{ }}")?;
+    w.newline()?;
+    write_pattern_map_from(i, w, map)?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static unsafe {name} Empty()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"InteropHelper.interoptopus_map_create(IntPtr.Zero, IntPtr.Zero, 0, out var _out);")?;
+    indented!(w, [()], r"return _out.IntoManaged();")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"public int Count")?;
+    indented!(w, r"{{")?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"get {{ if (_keys == IntPtr.Zero) {{ throw new InteropException(); }} else {{ return (int) _len; }} }}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    write_pattern_map_accessors(i, w, map)?;
+    w.newline()?;
+    write_pattern_map_to_unmanaged(i, w)?;
+    w.newline()?;
+    write_pattern_map_helpers(i, w, map)?;
+    w.newline()?;
+    write_pattern_map_interop_helper(i, w, map)?;
+    w.newline()?;
+    indented!(w, r"[CustomMarshaller(typeof({}), MarshalMode.Default, typeof(Marshaller))]", name)?;
+    indented!(w, r"private struct MarshallerMeta {{ }}")?;
+    w.newline()?;
+    indented!(w, r"[StructLayout(LayoutKind.Sequential)]")?;
+    indented!(w, r"public struct Unmanaged")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"internal IntPtr _keys;")?;
+    indented!(w, [()], r"internal IntPtr _values;")?;
+    indented!(w, [()], r"internal ulong _len;")?;
+    w.newline()?;
+    write_pattern_map_to_managed(i, w, name)?;
+    w.newline()?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    write_common_marshaller(i, w, name, MoveSemantics::Move)?;
+    w.unindent();
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"public static class {name}Extensions")?;
+    indented!(w, r"{{")?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static {name} IntoMap(this IReadOnlyDictionary<{k}, {v}> s) {{ return {name}.From(s); }}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    Ok(())
+}
+
+pub fn write_pattern_map_struct(_: &Interop, w: &mut IndentWriter, map: &MapType) -> Result<(), Error> {
+    let name = map.rust_name();
+
+    indented!(w, r"// This must be a class because we only ever want to hold on to the")?;
+    indented!(w, r"// same instance, as we overwrite fields when this is sent over the FFI")?;
+    indented!(w, r"// boundary")?;
+    indented!(w, r"public partial class {}", name)?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"internal IntPtr _keys;")?;
+    indented!(w, [()], r"internal IntPtr _values;")?;
+    indented!(w, [()], r"internal ulong _len;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    Ok(())
+}
+
+pub fn write_pattern_map_from(i: &Interop, w: &mut IndentWriter, map: &MapType) -> Result<(), Error> {
+    let name = map.rust_name();
+    let (k, v) = map_kv(map);
+    let (k_native, k_convert) = if is_reusable(map.k()) {
+        (k.clone(), "")
+    } else {
+        (format!("{k}.Unmanaged"), ".IntoUnmanaged()")
+    };
+    let (v_native, v_convert) = if is_reusable(map.v()) {
+        (v.clone(), "")
+    } else {
+        (format!("{v}.Unmanaged"), ".IntoUnmanaged()")
+    };
+
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static unsafe {name} From(IReadOnlyDictionary<{k}, {v}> _data)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"var _keys = new {k_native}[_data.Count];")?;
+    indented!(w, [()], r"var _values = new {v_native}[_data.Count];")?;
+    indented!(w, [()], r"var i = 0;")?;
+    indented!(w, [()], r"foreach (var _entry in _data)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"_keys[i] = _entry.Key{k_convert};")?;
+    indented!(w, [()()], r"_values[i] = _entry.Value{v_convert};")?;
+    indented!(w, [()()], r"++i;")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"fixed (void* _keys_ptr = _keys)")?;
+    indented!(w, [()], r"fixed (void* _values_ptr = _values)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"InteropHelper.interoptopus_map_create((IntPtr) _keys_ptr, (IntPtr) _values_ptr, (ulong) _keys.Length, out var _out);")?;
+    indented!(w, [()()], r"return _out.IntoManaged();")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    Ok(())
+}
+
+pub fn write_pattern_map_accessors(i: &Interop, w: &mut IndentWriter, map: &MapType) -> Result<(), Error> {
+    let (k, v) = map_kv(map);
+
+    write_pattern_map_element_at(i, w, "KeyAt", "_keys", &k, is_reusable(map.k()))?;
+    w.newline()?;
+    write_pattern_map_element_at(i, w, "ValueAt", "_values", &v, is_reusable(map.v()))?;
+    w.newline()?;
+    indented!(w, r"// Keys are sorted by their Rust ordering, which might differ from C#, so we search linearly.")?;
+    indented!(w, r"private int IndexOf({k} key)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"for (var i = 0; i < Count; ++i)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"if (EqualityComparer<{k}>.Default.Equals(KeyAt(i), key)) return i;")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"return -1;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"public {v} this[{k} key]")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"get {{ if (TryGetValue(key, out var value)) {{ return value; }} else {{ throw new KeyNotFoundException(); }} }}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"public bool ContainsKey({k} key) {{ return IndexOf(key) >= 0; }}")?;
+    w.newline()?;
+    indented!(w, r"public bool TryGetValue({k} key, out {v} value)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"var i = IndexOf(key);")?;
+    indented!(w, [()], r"value = i >= 0 ? ValueAt(i) : default;")?;
+    indented!(w, [()], r"return i >= 0;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"public IEnumerable<{k}> Keys")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"get {{ for (var i = 0; i < Count; ++i) {{ yield return KeyAt(i); }} }}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"public IEnumerable<{v}> Values")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"get {{ for (var i = 0; i < Count; ++i) {{ yield return ValueAt(i); }} }}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"public IEnumerator<KeyValuePair<{k}, {v}>> GetEnumerator()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"for (var i = 0; i < Count; ++i)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"yield return new KeyValuePair<{k}, {v}>(KeyAt(i), ValueAt(i));")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"IEnumerator IEnumerable.GetEnumerator() {{ return GetEnumerator(); }}")?;
+    Ok(())
+}
+
+fn write_pattern_map_element_at(i: &Interop, w: &mut IndentWriter, fn_name: &str, field: &str, the_type: &str, reusable: bool) -> Result<(), Error> {
+    i.inline_hint(w, 0)?;
+    indented!(w, r"private unsafe {the_type} {fn_name}(int i)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (i >= Count) throw new IndexOutOfRangeException();")?;
+    if reusable {
+        indented!(w, [()], r"return Marshal.PtrToStructure<{the_type}>(new IntPtr({field}.ToInt64() + i * sizeof({the_type})));")?;
+    } else {
+        indented!(w, [()], r"var _element = Marshal.PtrToStructure<{the_type}.Unmanaged>(new IntPtr({field}.ToInt64() + i * sizeof({the_type}.Unmanaged)));")?;
+        indented!(w, [()], r"return _element.IntoManaged();")?;
+    }
+    indented!(w, r"}}")?;
+    Ok(())
+}
+
+pub fn write_pattern_map_helpers(i: &Interop, w: &mut IndentWriter, map: &MapType) -> Result<(), Error> {
+    let name = map.rust_name();
+
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public void Dispose()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (_keys == IntPtr.Zero) return;")?;
+    indented!(w, [()], r"var _unmanaged = new Unmanaged();")?;
+    indented!(w, [()], r"_unmanaged._keys = _keys;")?;
+    indented!(w, [()], r"_unmanaged._values = _values;")?;
+    indented!(w, [()], r"_unmanaged._len = _len;")?;
+    indented!(w, [()], r"InteropHelper.interoptopus_map_destroy(_unmanaged);")?;
+    indented!(w, [()], r"_keys = IntPtr.Zero;")?;
+    indented!(w, [()], r"_values = IntPtr.Zero;")?;
+    indented!(w, [()], r"_len = 0;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public override string ToString()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r#"return "{name} {{ ... }}";"#)?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    Ok(())
+}
+
+#[allow(clippy::collapsible_if)]
+pub fn write_pattern_map_interop_helper(i: &Interop, w: &mut IndentWriter, map: &MapType) -> Result<(), Error> {
+    indented!(w, r"public partial class InteropHelper")?;
+    indented!(w, r"{{")?;
+    for f in i.inventory.functions() {
+        let first_param = f.signature().params().first().map(Parameter::the_type).cloned();
+        let last_param = f.signature().params().last().map(Parameter::the_type).cloned();
+        let name = f.name();
+        let extra_fn_decorations = i.fn_decorations();
+
+        if name.starts_with("interoptopus_map_create") {
+            if let Some(Type::ReadWritePointer(x)) = last_param {
+                if let Type::Pattern(TypePattern::Map(x)) = x.as_ref() {
+                    if x == map {
+                        indented!(w, [()], r#"[LibraryImport(Interop.NativeLib, EntryPoint = "{name}")]"#)?;
+                        for decor in &extra_fn_decorations {
+                            indented!(w, [()], "{}", decor)?;
+                        }
+                        indented!(w, [()], r"internal static partial long interoptopus_map_create(IntPtr keys, IntPtr values, ulong len, out Unmanaged rval);")?;
+                    }
+                }
+            }
+        }
+
+        if name.starts_with("interoptopus_map_destroy") && first_param == Some(Type::Pattern(TypePattern::Map(map.clone()))) {
+            indented!(w, [()], r#"[LibraryImport(Interop.NativeLib, EntryPoint = "{name}")]"#)?;
+            for decor in &extra_fn_decorations {
+                indented!(w, [()], "{}", decor)?;
+            }
+            indented!(w, [()], r"internal static partial long interoptopus_map_destroy(Unmanaged map);")?;
+        }
+    }
+    indented!(w, r"}}")?;
+    Ok(())
+}
+
+pub fn write_pattern_map_to_managed(i: &Interop, w: &mut IndentWriter, managed: &str) -> Result<(), Error> {
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public {managed} IntoManaged()")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"var rval = new {managed}();")?;
+    indented!(w, [()()], r"rval._keys = _keys;")?;
+    indented!(w, [()()], r"rval._values = _values;")?;
+    indented!(w, [()()], r"rval._len = _len;")?;
+    indented!(w, [()()], r"return rval;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    Ok(())
+}
+
+pub fn write_pattern_map_to_unmanaged(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public Unmanaged IntoUnmanaged()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (_keys == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.")?;
+    indented!(w, [()], r"var rval = new Unmanaged();")?;
+    indented!(w, [()], r"rval._keys = _keys;")?;
+    indented!(w, [()], r"rval._values = _values;")?;
+    indented!(w, [()], r"rval._len = _len;")?;
+    indented!(w, [()], r"_keys = IntPtr.Zero; // Mark this instance as moved.")?;
+    indented!(w, [()], r"return rval;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public Unmanaged AsUnmanaged()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (_keys == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.")?;
+    indented!(w, [()], r"var rval = new Unmanaged();")?;
+    indented!(w, [()], r"rval._keys = _keys;")?;
+    indented!(w, [()], r"rval._values = _values;")?;
+    indented!(w, [()], r"rval._len = _len;")?;
+    indented!(w, [()], r"return rval;")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use crate::Interop;
+use crate::converter::slice_t;
+use crate::interop::patterns::slices::SliceKind;
+use interoptopus::pattern::ndslice::MAX_DIMS;
+use interoptopus::pattern::slice::SliceType;
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+pub fn write_pattern_ndslice(i: &Interop, w: &mut IndentWriter, slice: &SliceType, kind: SliceKind) -> Result<(), Error> {
+    i.debug(w, "write_pattern_ndslice")?;
+
+    let name = slice.rust_name();
+    let the_type = slice_t(slice);
+
+    // Like `Utf8Str` this is a plain blittable struct, so it can be passed without marshalling,
+    // it is up to the caller to keep the viewed data pinned.
+    indented!(w, r"[StructLayout(LayoutKind.Sequential)]")?;
+    indented!(w, r"public unsafe partial struct {name}")?;
+    indented!(w, r"{{")?;
+    w.indent();
+    indented!(w, r"IntPtr _data;")?;
+    indented!(w, r"ulong _ndim;")?;
+    indented!(w, r"fixed ulong _shape[{MAX_DIMS}];")?;
+    indented!(w, r"fixed long _strides[{MAX_DIMS}];")?;
+    w.newline()?;
+    indented!(w, r"public int Rank => (int) _ndim;")?;
+    w.newline()?;
+    indented!(w, r"public int Count")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"get")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"if (_data == IntPtr.Zero) return 0;")?;
+    indented!(w, [()()], r"long count = 1;")?;
+    indented!(w, [()()], r"for (var d = 0; d < Rank; ++d) count *= (long) _shape[d];")?;
+    indented!(w, [()()], r"return (int) count;")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"// True if all elements are laid out row-major without gaps.")?;
+    indented!(w, r"public bool IsContiguous")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"get")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"long expected = 1;")?;
+    indented!(w, [()()], r"for (var d = Rank - 1; d >= 0; --d)")?;
+    indented!(w, [()()], r"{{")?;
+    indented!(w, [()()()], r"if (_shape[d] != 1 && _strides[d] != expected) return false;")?;
+    indented!(w, [()()()], r"expected *= (long) _shape[d];")?;
+    indented!(w, [()()], r"}}")?;
+    indented!(w, [()()], r"return true;")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public int GetLength(int dimension) => dimension >= 0 && dimension < Rank ? (int) _shape[dimension] : throw new IndexOutOfRangeException();")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public long GetStride(int dimension) => dimension >= 0 && dimension < Rank ? _strides[dimension] : throw new IndexOutOfRangeException();")?;
+    w.newline()?;
+
+    indented!(w, r"public ReadOnlySpan<{the_type}> ReadOnlySpan")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    if kind == SliceKind::SliceMut {
+        indented!(w, r"public Span<{the_type}> Span")?;
+        indented!(w, r"{{")?;
+        indented!(w, [()], r"get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);")?;
+        indented!(w, r"}}")?;
+        w.newline()?;
+    }
+
+    let indexers = [
+        ("int i0", "1, i0, 0, 0, 0"),
+        ("int i0, int i1", "2, i0, i1, 0, 0"),
+        ("int i0, int i1, int i2", "3, i0, i1, i2, 0"),
+        ("int i0, int i1, int i2, int i3", "4, i0, i1, i2, i3"),
+    ];
+    for (params, args) in indexers {
+        indented!(w, r"public {the_type} this[{params}]")?;
+        indented!(w, r"{{")?;
+        i.inline_hint(w, 1)?;
+        indented!(w, [()], r"get => Unsafe.Read<{the_type}>(At({args}));")?;
+        if kind == SliceKind::SliceMut {
+            i.inline_hint(w, 1)?;
+            indented!(w, [()], r"set => Unsafe.Write(At({args}), value);")?;
+        }
+        indented!(w, r"}}")?;
+        w.newline()?;
+    }
+
+    indented!(w, r"void* At(int rank, int i0, int i1, int i2, int i3)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (rank != Rank) throw new IndexOutOfRangeException();")?;
+    indented!(w, [()], r"Span<int> index = stackalloc int[] {{ i0, i1, i2, i3 }};")?;
+    indented!(w, [()], r"long offset = 0;")?;
+    indented!(w, [()], r"for (var d = 0; d < rank; ++d)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"if ((ulong) index[d] >= _shape[d]) throw new IndexOutOfRangeException();")?;
+    indented!(w, [()()], r"offset += index[d] * _strides[d];")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"return (byte*) _data + offset * Unsafe.SizeOf<{the_type}>();")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    indented!(w, r"// The data must stay pinned for as long as this value is used.")?;
+    indented!(w, r"public static {name} From(IntPtr data, ReadOnlySpan<ulong> shape)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (shape.Length > {MAX_DIMS}) throw new ArgumentException(Dimensions);")?;
+    indented!(w, [()], r"Span<long> strides = stackalloc long[shape.Length];")?;
+    indented!(w, [()], r"long stride = 1;")?;
+    indented!(w, [()], r"for (var d = shape.Length - 1; d >= 0; --d)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"strides[d] = stride;")?;
+    indented!(w, [()()], r"stride *= (long) shape[d];")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"return From(data, shape, strides);")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"// Strides are counted in elements, not bytes.")?;
+    indented!(w, r"public static {name} From(IntPtr data, ReadOnlySpan<ulong> shape, ReadOnlySpan<long> strides)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (shape.Length > {MAX_DIMS} || shape.Length != strides.Length) throw new ArgumentException(Dimensions);")?;
+    indented!(w, [()], r"var rval = new {name}();")?;
+    indented!(w, [()], r"rval._data = data;")?;
+    indented!(w, [()], r"rval._ndim = (ulong) shape.Length;")?;
+    indented!(w, [()], r"for (var d = 0; d < shape.Length; ++d)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"rval._shape[d] = shape[d];")?;
+    indented!(w, [()()], r"rval._strides[d] = strides[d];")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"return rval;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r#"const string Noncontiguous = "NdSlice is not contiguous.";"#)?;
+    indented!(w, r#"const string Dimensions = "NdSlice supports up to {MAX_DIMS} dimensions, with one stride per dimension.";"#)?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"internal Unmanaged ToUnmanaged() => Unsafe.As<{name}, Unmanaged>(ref this);")?;
+    w.newline()?;
+    indented!(w, r"[StructLayout(LayoutKind.Sequential)]")?;
+    indented!(w, r"public struct Unmanaged")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"public IntPtr _data;")?;
+    indented!(w, [()], r"public ulong _ndim;")?;
+    indented!(w, [()], r"public fixed ulong _shape[{MAX_DIMS}];")?;
+    indented!(w, [()], r"public fixed long _strides[{MAX_DIMS}];")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"internal {name} ToManaged() => Unsafe.As<Unmanaged, {name}>(ref this);")?;
+    indented!(w, r"}}")?;
+    w.unindent();
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
@@ -1,47 +1,54 @@
 use crate::Interop;
-use crate::converter::rval_to_type_sync;
-use interoptopus::lang::Composite;
+use crate::converter::{field_to_type, is_reusable};
+use interoptopus::pattern::option::OptionType;
 use interoptopus_backend_utils::{Error, IndentWriter, indented};
 
-// TODO: Write helpers for options
-pub fn _write_pattern_option(i: &Interop, w: &mut IndentWriter, slice: &Composite) -> Result<(), Error> {
+/// Writes conversions from and to `T?`, as well as the usual accessors known from `Nullable<T>`.
+pub fn write_pattern_option(i: &Interop, w: &mut IndentWriter, option: &OptionType) -> Result<(), Error> {
     i.debug(w, "write_pattern_option")?;
 
-    let name = slice.rust_name();
-    let data_type = slice
-        .fields()
-        .iter()
-        .find(|x| x.name().eq("t"))
-        .expect("Option must contain field called 't'.")
-        .the_type();
+    let name = option.the_enum().rust_name();
+    let self_kind = if is_reusable(&option.the_enum().to_type()) { "struct" } else { "class" };
+    let t = field_to_type(option.t());
 
-    let type_string = rval_to_type_sync(data_type);
-    let is_some = "is_some";
-
-    indented!(w, r"{} partial struct {}", i.visibility_types.to_access_modifier(), name)?;
+    indented!(w, r"#nullable enable")?;
+    indented!(w, r"public partial {self_kind} {name}")?;
     indented!(w, r"{{")?;
-
-    // FromNullable
-    indented!(w, [()], r"public static {name} FromNullable({type_string}? nullable)")?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static implicit operator {name}({t}? value) => value is {{ }} x ? Some(x) : None;")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static implicit operator {t}?({name} value) => value.IsSome ? value._Some : null;")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static {name} FromNullable({t}? value) => value;")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public {t}? ToNullable() => this;")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public {t} GetValueOrDefault() => IsSome ? _Some : default!;")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public {t} GetValueOrDefault({t} defaultValue) => IsSome ? _Some : defaultValue;")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public bool TryGetValue(out {t} value)")?;
     indented!(w, [()], r"{{")?;
-    indented!(w, [()()], r"var result = new {}();", name)?;
-    indented!(w, [()()], r"if (nullable.HasValue)")?;
-    indented!(w, [()()], r"{{")?;
-    indented!(w, [()()()], r"result.{is_some} = 1;")?;
-    indented!(w, [()()()], r"result.t = nullable.Value;")?;
-    indented!(w, [()()], r"}}")?;
-    w.newline()?;
-    indented!(w, [()()], r"return result;")?;
+    indented!(w, [()()], r"value = GetValueOrDefault();")?;
+    indented!(w, [()()], r"return IsSome;")?;
     indented!(w, [()], r"}}")?;
     w.newline()?;
-
-    // ToNullable
-    indented!(w, [()], r"public {type_string}? ToNullable()")?;
+    indented!(w, [()], r"public TResult Match<TResult>(Func<{t}, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();")?;
+    w.newline()?;
+    indented!(w, [()], r"public void Match(Action<{t}> some, Action none)")?;
     indented!(w, [()], r"{{")?;
-    indented!(w, [()()], r"return this.{is_some} == 1 ? this.t : ({type_string}?)null;")?;
+    indented!(w, [()()], r"if (IsSome) {{ some(_Some); }} else {{ none(); }}")?;
     indented!(w, [()], r"}}")?;
-
-    indented!(w, r"}}")?;
     w.newline()?;
+    indented!(w, [()], r"public {name} Map(Func<{t}, {t}> f) => IsSome ? Some(f(_Some)) : None;")?;
+    indented!(w, r"}}")?;
+    indented!(w, r"#nullable restore")?;
+
     Ok(())
 }
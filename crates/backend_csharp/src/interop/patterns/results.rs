@@ -0,0 +1,55 @@
+use crate::Interop;
+use crate::converter::{field_to_type, is_reusable};
+use interoptopus::pattern::result::ResultType;
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+/// Writes helpers to unwrap, match and deconstruct results, errors other than `Err` are thrown via `ToException()`.
+pub fn write_pattern_result(i: &Interop, w: &mut IndentWriter, result: &ResultType) -> Result<(), Error> {
+    i.debug(w, "write_pattern_result")?;
+
+    if result.e().is_void() {
+        return Ok(());
+    }
+
+    let name = result.the_enum().rust_name();
+    let self_kind = if is_reusable(&result.the_enum().to_type()) { "struct" } else { "class" };
+    let e = field_to_type(result.e());
+
+    indented!(w, r"#nullable enable")?;
+    indented!(w, r"public partial {self_kind} {name}")?;
+    indented!(w, r"{{")?;
+    if result.t().is_void() {
+        i.inline_hint(w, 1)?;
+        indented!(w, [()], r"public void Unwrap() => AsOk();")?;
+        w.newline()?;
+        indented!(w, [()], r"public TResult Match<TResult>(Func<TResult> ok, Func<{e}, TResult> err) => IsOk ? ok() : IsErr ? err(_Err) : throw ToException();")?;
+    } else {
+        let t = field_to_type(result.t());
+        i.inline_hint(w, 1)?;
+        indented!(w, [()], r"public {t} Unwrap() => AsOk();")?;
+        w.newline()?;
+        i.inline_hint(w, 1)?;
+        indented!(w, [()], r"public {t} UnwrapOr({t} fallback) => IsOk ? _Ok : fallback;")?;
+        w.newline()?;
+        i.inline_hint(w, 1)?;
+        indented!(w, [()], r"public bool TryGet(out {t} value)")?;
+        indented!(w, [()], r"{{")?;
+        indented!(w, [()()], r"value = IsOk ? _Ok : default!;")?;
+        indented!(w, [()()], r"return IsOk;")?;
+        indented!(w, [()], r"}}")?;
+        w.newline()?;
+        indented!(w, [()], r"public TResult Match<TResult>(Func<{t}, TResult> ok, Func<{e}, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();")?;
+        w.newline()?;
+        indented!(w, [()], r"// Allows `var (value, error) = result;`, exactly one of both is set.")?;
+        indented!(w, [()], r"public void Deconstruct(out {t}? value, out {e}? error)")?;
+        indented!(w, [()], r"{{")?;
+        indented!(w, [()()], r"if (!IsOk && !IsErr) throw ToException();")?;
+        indented!(w, [()()], r"value = IsOk ? _Ok : null;")?;
+        indented!(w, [()()], r"error = IsErr ? _Err : null;")?;
+        indented!(w, [()], r"}}")?;
+    }
+    indented!(w, r"}}")?;
+    indented!(w, r"#nullable restore")?;
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use crate::converter::{field_to_type, function_name, param_to_type, param_to_type_overloaded, rval_to_type_async, rval_to_type_sync};
 use crate::interop::docs::write_documentation;
+use crate::interop::functions::has_str_param;
+use crate::interop::patterns::interfaces::{interface_list, write_service_interface_impl};
 use crate::utils::sugared_return_type;
 use crate::{FunctionNameFlavor, Interop};
 use interoptopus::lang::{Function, Primitive, SugaredReturnType, Type};
 use interoptopus::pattern::TypePattern;
-use interoptopus::pattern::service::ServiceDefinition;
+use interoptopus::pattern::service::{ServiceDefinition, ServiceProperty, ServiceSync};
 use interoptopus_backend_utils::{Error, IndentWriter, WriteFor, indented};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -12,6 +14,7 @@ pub enum MethodType {
     Ctor,
     Dtor,
     Regular,
+    Static,
 }
 
 pub fn write_pattern_service(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition) -> Result<(), Error> {
@@ -20,7 +23,8 @@ pub fn write_pattern_service(i: &Interop, w: &mut IndentWriter, class: &ServiceD
     let context_type_name = class.the_type().rust_name();
 
     write_documentation(w, class.the_type().meta().docs())?;
-    indented!(w, r"{} partial class {} : IDisposable", i.visibility_types.to_access_modifier(), context_type_name)?;
+    write_sync_documentation(w, class)?;
+    indented!(w, r"{} partial class {} : IDisposable{}", i.visibility_types.to_access_modifier(), context_type_name, interface_list(class))?;
     indented!(w, r"{{")?;
     w.indent();
     indented!(w, r"private IntPtr _context;")?;
@@ -45,6 +49,21 @@ pub fn write_pattern_service(i: &Interop, w: &mut IndentWriter, class: &ServiceD
         w.newline()?;
     }
 
+    for function in class.static_methods() {
+        write_documentation(w, function.meta().docs())?;
+        write_pattern_service_method(i, w, class, function, MethodType::Static, WriteFor::Code)?;
+        w.newline()?;
+    }
+
+    for property in class.properties() {
+        write_pattern_service_property(i, w, class, property)?;
+        w.newline()?;
+    }
+
+    for interface_impl in class.interfaces() {
+        write_service_interface_impl(i, w, class, interface_impl)?;
+    }
+
     indented!(w, r"public IntPtr Context => _context;")?;
 
     w.unindent();
@@ -72,7 +91,7 @@ pub fn write_pattern_service_method(
 
     let fn_name = match method_type {
         MethodType::Ctor => function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix)),
-        MethodType::Regular => function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix)),
+        MethodType::Regular | MethodType::Static => function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix)),
         MethodType::Dtor => "Dispose".to_string(),
     };
 
@@ -84,11 +103,16 @@ pub fn write_pattern_service_method(
                 static_prefix = "static ";
                 class.the_type().rust_name().to_string()
             }
-            MethodType::Regular => match function.signature().rval() {
-                Type::Pattern(TypePattern::Result(x)) if x.t().is_void() => "void".to_string(),
-                Type::Pattern(TypePattern::Result(x)) => field_to_type(x.t()),
-                x => rval_to_type_sync(x),
-            },
+            MethodType::Regular | MethodType::Static => {
+                if method_type == MethodType::Static {
+                    static_prefix = "static ";
+                }
+                match function.signature().rval() {
+                    Type::Pattern(TypePattern::Result(x)) if x.t().is_void() => "void".to_string(),
+                    Type::Pattern(TypePattern::Result(x)) => field_to_type(x.t()),
+                    x => rval_to_type_sync(x),
+                }
+            }
             MethodType::Dtor => "void".to_string(),
         },
         SugaredReturnType::Async(Type::Pattern(TypePattern::Result(_))) => {
@@ -109,13 +133,7 @@ pub fn write_pattern_service_method(
 
     // Assemble actual function call.
     let invoke_args = match method_type {
-        MethodType::Ctor => {
-            if to_invoke.is_empty() {
-                String::new()
-            } else {
-                to_invoke.join(", ")
-            }
-        }
+        MethodType::Ctor | MethodType::Static => to_invoke.join(", "),
         MethodType::Dtor => "_context".to_string(),
         MethodType::Regular => {
             if to_invoke.is_empty() {
@@ -176,6 +194,46 @@ pub fn write_pattern_service_method(
     Ok(())
 }
 
+/// Documents the service's thread safety, if it has any.
+fn write_sync_documentation(w: &mut IndentWriter, class: &ServiceDefinition) -> Result<(), Error> {
+    match class.sync() {
+        ServiceSync::None => {}
+        ServiceSync::Mutex => indented!(w, r"/// Calls into this service are serialized by a lock.")?,
+        ServiceSync::ThreadAffine => indented!(w, r"/// This service must only be used from the thread that created it.")?,
+    }
+
+    Ok(())
+}
+
+/// Writes a property forwarding to the service's getter and / or setter method.
+pub fn write_pattern_service_property(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition, property: &ServiceProperty) -> Result<(), Error> {
+    i.debug(w, "write_pattern_service_property")?;
+
+    let common_prefix = class.common_prefix();
+    let the_type = match (property.getter(), property.setter()) {
+        (Some(getter), _) => match getter.signature().rval() {
+            Type::Pattern(TypePattern::Result(x)) => field_to_type(x.t()),
+            x => rval_to_type_sync(x),
+        },
+        (None, Some(setter)) => param_to_type(setter.signature().params()[1].the_type()),
+        (None, None) => return Ok(()),
+    };
+
+    indented!(w, r"public {} {}", the_type, property.name())?;
+    indented!(w, r"{{")?;
+    if let Some(getter) = property.getter() {
+        let getter = function_name(getter, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix));
+        indented!(w, [()], r"get => {getter}();")?;
+    }
+    if let Some(setter) = property.setter() {
+        let setter = function_name(setter, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix));
+        indented!(w, [()], r"set => {setter}(value);")?;
+    }
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
 pub fn write_service_method_overload(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition, function: &Function, write_for: WriteFor) -> Result<(), Error> {
     i.debug(w, "write_service_method_overload")?;
 
@@ -188,19 +246,43 @@ pub fn write_service_method_overload(i: &Interop, w: &mut IndentWriter, class: &
         write_documentation(w, function.meta().docs())?;
     }
 
-    write_common_service_method_overload(i, w, class, function, write_for)?;
+    write_common_service_method_overload(i, w, class, function, write_for, false)?;
+
+    if has_str_param(function) {
+        if write_for == WriteFor::Code {
+            w.newline()?;
+            write_documentation(w, function.meta().docs())?;
+        }
+
+        write_common_service_method_overload(i, w, class, function, write_for, true)?;
+    }
 
     Ok(())
 }
 
-/// Writes common service overload code
-pub fn write_common_service_method_overload(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition, function: &Function, write_for: WriteFor) -> Result<(), Error> {
+/// Writes common service overload code, if `strings` is set `Utf8Str` parameters become `string`.
+pub fn write_common_service_method_overload(
+    i: &Interop,
+    w: &mut IndentWriter,
+    class: &ServiceDefinition,
+    function: &Function,
+    write_for: WriteFor,
+    strings: bool,
+) -> Result<(), Error> {
     i.debug(w, "write_common_service_method_overload")?;
 
     let fn_name = function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(&class.common_prefix()));
     let async_rval = sugared_return_type(function);
     let (mut names, mut types, mut to_invoke) = params(function, MethodType::Regular, true);
 
+    if strings {
+        for (p, t) in function.signature().params().iter().skip(1).zip(types.iter_mut()) {
+            if matches!(p.the_type(), Type::Pattern(TypePattern::Utf8Str(_))) {
+                *t = "string".to_string();
+            }
+        }
+    }
+
     // Write checked method. These are "normal" methods that accept
     // common C# types.
     let rval = match async_rval {
@@ -277,7 +359,7 @@ pub fn params(function: &Function, method_type: MethodType, is_overload: bool) -
     let mut types = Vec::new();
 
     let skip_params = match method_type {
-        MethodType::Ctor => 0,
+        MethodType::Ctor | MethodType::Static => 0,
         MethodType::Dtor => 1,
         MethodType::Regular => 1,
     };
@@ -0,0 +1,65 @@
+use crate::Interop;
+use interoptopus::lang::Composite;
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+pub fn write_pattern_str(i: &Interop, w: &mut IndentWriter, the_type: &Composite) -> Result<(), Error> {
+    i.debug(w, "write_pattern_str")?;
+
+    let name = the_type.rust_name();
+
+    // Unlike most other patterns this is a plain blittable struct, so it can be passed
+    // (and pinned from a span) without any marshalling or allocation.
+    indented!(w, r"[StructLayout(LayoutKind.Sequential)]")?;
+    indented!(w, r"public partial struct {name}")?;
+    indented!(w, r"{{")?;
+    w.indent();
+    indented!(w, r"IntPtr _ptr;")?;
+    indented!(w, r"ulong _len;")?;
+    w.newline()?;
+    indented!(w, r"public int Length => (int) _len;")?;
+    w.newline()?;
+    indented!(w, r"public unsafe ReadOnlySpan<byte> ReadOnlySpan")?;
+    indented!(w, r"{{")?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"get => _ptr == IntPtr.Zero ? ReadOnlySpan<byte>.Empty : new(_ptr.ToPointer(), (int) _len);")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"// The bytes must stay pinned for as long as this value is used.")?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static {name} From(IntPtr ptr, ulong len)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"var rval = new {name}();")?;
+    indented!(w, [()], r"rval._ptr = ptr;")?;
+    indented!(w, [()], r"rval._len = len;")?;
+    indented!(w, [()], r"return rval;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public override string ToString() => Encoding.UTF8.GetString(ReadOnlySpan);")?;
+    w.newline()?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"internal Unmanaged ToUnmanaged()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"var unmanaged = new Unmanaged();")?;
+    indented!(w, [()], r"unmanaged._ptr = _ptr;")?;
+    indented!(w, [()], r"unmanaged._len = _len;")?;
+    indented!(w, [()], r"return unmanaged;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"[StructLayout(LayoutKind.Sequential)]")?;
+    indented!(w, r"public struct Unmanaged")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"public IntPtr _ptr;")?;
+    indented!(w, [()], r"public ulong _len;")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"internal {name} ToManaged()")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"return {name}.From(_ptr, _len);")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.unindent();
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
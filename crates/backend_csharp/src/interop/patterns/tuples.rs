@@ -0,0 +1,58 @@
+use crate::Interop;
+use crate::converter::{field_to_type, is_reusable};
+use interoptopus::pattern::tuple::TupleType;
+use interoptopus_backend_utils::{Error, IndentWriter, indented};
+
+/// Writes conversions from and to the equivalent `ValueTuple`, which itself can't be marshalled.
+pub fn write_pattern_tuple(i: &Interop, w: &mut IndentWriter, tuple: &TupleType) -> Result<(), Error> {
+    i.debug(w, "write_pattern_tuple")?;
+
+    let name = tuple.rust_name();
+    let self_kind = if is_reusable(&tuple.to_type()) { "struct" } else { "class" };
+    let types = tuple.types().iter().map(field_to_type).collect::<Vec<_>>();
+    let value_tuple = format!("({})", types.join(", "));
+
+    let mut params = Vec::new();
+    let mut assigns = Vec::new();
+    let mut fields = Vec::new();
+    let mut items = Vec::new();
+    let mut outs = Vec::new();
+    for (n, t) in types.iter().enumerate() {
+        params.push(format!("{t} x{n}"));
+        assigns.push(format!("this.x{n} = x{n};"));
+        fields.push(format!("x{n}"));
+        items.push(format!("value.Item{}", n + 1));
+        outs.push(format!("out {t} _x{n}"));
+    }
+
+    let params = params.join(", ");
+    let assigns = assigns.join(" ");
+    let fields = fields.join(", ");
+    let items = items.join(", ");
+    let outs = outs.join(", ");
+
+    indented!(w, r"public partial {self_kind} {name}")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"public {name}({params}) {{ {assigns} }}")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static implicit operator {name}({value_tuple} value) => new({items});")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public static implicit operator {value_tuple}({name} value) => value.ToValueTuple();")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public {value_tuple} ToValueTuple() => ({fields});")?;
+    w.newline()?;
+    indented!(w, [()], r"// Allows `var (a, b) = tuple;` just like with a `ValueTuple`.")?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public void Deconstruct({outs})")?;
+    indented!(w, [()], r"{{")?;
+    for n in 0..types.len() {
+        indented!(w, [()()], r"_x{n} = x{n};")?;
+    }
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
@@ -6,7 +6,15 @@ pub mod fnptrs;
 use crate::Interop;
 use crate::interop::patterns::asynk::write_pattern_async_trampoline;
 use crate::interop::patterns::callbacks::write_type_definition_named_callback;
+use crate::interop::patterns::duration::write_pattern_duration;
+use crate::interop::patterns::iter::write_pattern_iter;
+use crate::interop::patterns::map::write_pattern_map;
+use crate::interop::patterns::ndslice::write_pattern_ndslice;
+use crate::interop::patterns::options::write_pattern_option;
+use crate::interop::patterns::results::write_pattern_result;
 use crate::interop::patterns::slices::{SliceKind, write_pattern_slice};
+use crate::interop::patterns::str::write_pattern_str;
+use crate::interop::patterns::tuples::write_pattern_tuple;
 use crate::interop::patterns::vec::write_pattern_vec;
 use crate::interop::types::bools::write_type_definition_ffibool;
 use crate::interop::types::composite::write_type_definition_composite;
@@ -86,12 +94,14 @@ pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type)
             TypePattern::Option(x) => {
                 write_type_definition_enum(i, w, x.the_enum())?;
                 w.newline()?;
-                // write_pattern_option(i, w, x)?;
-                // w.newline()?;
+                write_pattern_option(i, w, x)?;
+                w.newline()?;
             }
             TypePattern::Result(x) => {
                 write_type_definition_enum(i, w, x.the_enum())?;
                 w.newline()?;
+                write_pattern_result(i, w, x)?;
+                w.newline()?;
             }
             TypePattern::NamedCallback(x) => {
                 // Handle this better way
@@ -106,7 +116,39 @@ pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type)
                 write_pattern_vec(i, w, x)?;
                 w.newline()?;
             }
+            TypePattern::Map(x) => {
+                write_pattern_map(i, w, x)?;
+                w.newline()?;
+            }
+            TypePattern::Iter(x) => {
+                write_pattern_iter(i, w, x)?;
+                w.newline()?;
+            }
+            TypePattern::Tuple(x) => {
+                write_type_definition_composite(i, w, x.composite_type())?;
+                w.newline()?;
+                write_pattern_tuple(i, w, x)?;
+                w.newline()?;
+            }
+            TypePattern::Duration(x) => {
+                write_type_definition_composite(i, w, x)?;
+                w.newline()?;
+                write_pattern_duration(i, w, x)?;
+                w.newline()?;
+            }
             TypePattern::Utf8String(_) => {}
+            TypePattern::Utf8Str(x) => {
+                write_pattern_str(i, w, x)?;
+                w.newline()?;
+            }
+            TypePattern::NdSlice(x) => {
+                write_pattern_ndslice(i, w, x, SliceKind::Slice)?;
+                w.newline()?;
+            }
+            TypePattern::NdSliceMut(x) => {
+                write_pattern_ndslice(i, w, x, SliceKind::SliceMut)?;
+                w.newline()?;
+            }
             TypePattern::CChar => {}
             TypePattern::APIVersion => {}
             TypePattern::AsyncCallback(x) => {
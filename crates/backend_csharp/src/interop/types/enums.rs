@@ -2,7 +2,8 @@ use crate::Interop;
 use crate::converter::{field_as_unmanaged, field_to_managed, field_to_type, field_to_type_unmanaged, field_to_unmanaged, has_dispose, is_reusable};
 use crate::interop::docs::write_documentation;
 use crate::utils::{MoveSemantics, write_common_marshaller};
-use interoptopus::lang::{Enum, Field, Type, VariantKind};
+use interoptopus::lang::util::{error_enums, error_variant_exception_name};
+use interoptopus::lang::{Enum, Field, Type, Variant, VariantKind};
 use interoptopus_backend_utils::{Error, IndentWriter, indented};
 
 pub fn write_type_definition_enum(i: &Interop, w: &mut IndentWriter, the_type: &Enum) -> Result<(), Error> {
@@ -10,6 +11,55 @@ pub fn write_type_definition_enum(i: &Interop, w: &mut IndentWriter, the_type: &
     write_documentation(w, the_type.meta().docs())?;
     write_type_definition_enum_marshaller(i, w, the_type)?;
 
+    let error_enums = error_enums(i.inventory.c_types());
+    if error_enums.iter().any(|x| x.rust_name() == the_type.rust_name()) {
+        w.newline()?;
+        write_type_definition_enum_exceptions(i, w, the_type)?;
+    }
+
+    Ok(())
+}
+
+/// Writes `{Enum}Exception` for an enum used as an error, and one subclass per variant.
+pub fn write_type_definition_enum_exceptions(i: &Interop, w: &mut IndentWriter, the_type: &Enum) -> Result<(), Error> {
+    i.debug(w, "write_type_definition_enum_exceptions")?;
+    let name = the_type.rust_name();
+
+    indented!(w, r"/// Thrown when a function returns `{name}` as its error.")?;
+    indented!(w, r"public class {name}Exception : InteropException")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"public {name} Error {{ get; }}")?;
+    w.newline()?;
+    indented!(w, [()], r"public {name}Exception({name} error) : this(error, error.ToString()) {{ }}")?;
+    w.newline()?;
+    indented!(w, [()], r"public {name}Exception({name} error, string message) : base(message)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"Error = error;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    indented!(w, [()], r"/// Creates the exception matching the error's variant, including details about the last error in Rust.")?;
+    indented!(w, [()], r"public static {name}Exception FromError({name} error)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"var lastError = Utf8String.LastError();")?;
+    indented!(w, [()()], r#"var message = string.IsNullOrEmpty(lastError) ? error.ToString() : $"{{error}}: {{lastError}}";"#)?;
+    for variant in the_type.variants() {
+        let vname = variant.name();
+        let exception = error_variant_exception_name(the_type, variant);
+        indented!(w, [()()], r"if (error.Is{vname}) return new {exception}(error, message);")?;
+    }
+    indented!(w, [()()], r"return new {name}Exception(error, message);")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+
+    for variant in the_type.variants() {
+        let exception = error_variant_exception_name(the_type, variant);
+        w.newline()?;
+        indented!(w, r"public class {exception} : {name}Exception")?;
+        indented!(w, r"{{")?;
+        indented!(w, [()], r"public {exception}({name} error, string message) : base(error, message) {{ }}")?;
+        indented!(w, r"}}")?;
+    }
+
     Ok(())
 }
 
@@ -56,7 +106,7 @@ pub fn write_type_definition_enum_marshaller(i: &Interop, w: &mut IndentWriter,
     }
     w.newline()?;
 
-    indented!(w, [()], r"[StructLayout(LayoutKind.Explicit)]")?;
+    indented!(w, [()], r"[StructLayout(LayoutKind.Sequential)]")?;
     indented!(w, [()], r"public unsafe struct Unmanaged")?;
     indented!(w, [()], r"{{")?;
     write_type_definition_enum_variant_fields_unmanaged(i, w, the_type)?;
@@ -125,27 +175,36 @@ pub fn write_type_definition_enum_variant_fields_managed(i: &Interop, w: &mut In
     Ok(())
 }
 
+/// Whether any variant carries data, i.e., whether `Unmanaged` has a `_payload` after its tag.
+fn has_payloads(the_type: &Enum) -> bool {
+    the_type.variants().iter().any(|x| matches!(x.kind(), VariantKind::Typed(_, t) if !t.is_void()))
+}
+
 pub fn write_type_definition_enum_variant_unmanaged_types(i: &Interop, w: &mut IndentWriter, the_type: &Enum) -> Result<(), Error> {
     i.debug(w, "write_type_definition_enum_variant_unmanaged_types")?;
 
+    if !has_payloads(the_type) {
+        return Ok(());
+    }
+
+    // Mirrors the union following the tag in Rust's `#[repr(C, u32)]` layout.
+    indented!(w, [()], r"[StructLayout(LayoutKind.Explicit)]")?;
+    indented!(w, [()], r"internal unsafe struct UnmanagedPayload")?;
+    indented!(w, [()], r"{{")?;
     for variant in the_type.variants() {
         match variant.kind() {
             VariantKind::Unit(_) => {}
             VariantKind::Typed(_, t) if !t.is_void() => {
                 let ty = field_to_type_unmanaged(t);
                 let vname = variant.name();
-                indented!(w, [()], r"[StructLayout(LayoutKind.Sequential)]")?;
-                indented!(w, [()], r"internal unsafe struct Unmanaged{vname}")?;
-                indented!(w, [()], r"{{")?;
-                indented!(w, [()()], r"internal uint _variant;")?;
+                indented!(w, [()()], r"[FieldOffset(0)]")?;
                 indented!(w, [()()], r"internal {ty} _{vname};")?;
-                indented!(w, [()], r"}}")?;
             }
             VariantKind::Typed(_, _) => {}
         }
-
-        w.newline()?;
     }
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
 
     Ok(())
 }
@@ -153,21 +212,11 @@ pub fn write_type_definition_enum_variant_unmanaged_types(i: &Interop, w: &mut I
 pub fn write_type_definition_enum_variant_fields_unmanaged(i: &Interop, w: &mut IndentWriter, the_type: &Enum) -> Result<(), Error> {
     i.debug(w, "write_type_definition_enum_variant_fields_unmanaged")?;
 
-    indented!(w, [()()], r"[FieldOffset(0)]")?;
     indented!(w, [()()], r"internal uint _variant;")?;
-    w.newline()?;
-    for variant in the_type.variants() {
-        match variant.kind() {
-            VariantKind::Unit(_) => {}
-            VariantKind::Typed(_, t) if !t.is_void() => {
-                let vname = variant.name();
-                indented!(w, [()()], r"[FieldOffset(0)]")?;
-                indented!(w, [()()], r"internal Unmanaged{vname} _{vname};")?;
-                w.newline()?;
-            }
-            VariantKind::Typed(_, _) => {}
-        }
+    if has_payloads(the_type) {
+        indented!(w, [()()], r"internal UnmanagedPayload _payload;")?;
     }
+    w.newline()?;
 
     Ok(())
 }
@@ -211,9 +260,28 @@ pub fn write_type_definition_enum_variant_utils(i: &Interop, w: &mut IndentWrite
     }
     w.newline()?;
 
-    // As... "unwraps"
+    // For results, errors map to the typed exception of their error enum, if any.
+    let is_result = the_type.variants().iter().map(Variant::name).take(3).eq(["Ok", "Err", "Panic"]);
+    if is_result {
+        let to_exception = match the_type.variants()[1].kind() {
+            VariantKind::Typed(_, t) if matches!(&**t, Type::Enum(_)) => {
+                let e = field_to_type(t);
+                format!("_variant == 1 ? {e}Exception.FromError(_Err) : InteropException.FromLastError(ToString())")
+            }
+            _ => "InteropException.FromLastError(ToString())".to_string(),
+        };
+        indented!(w, [()], r"/// Converts this result's error into an exception, including details about the last error in Rust.")?;
+        indented!(w, [()], r"public InteropException ToException() => {to_exception};")?;
+        w.newline()?;
+    }
+
+    // As... "unwraps", for results `AsOk()` throws `ToException()`.
     for variant in the_type.variants() {
-        let throw = "throw new InteropException();";
+        let throw = if is_result && variant.name() == "Ok" {
+            "throw ToException();"
+        } else {
+            "throw new InteropException();"
+        };
         match variant.kind() {
             VariantKind::Unit(x) => {
                 let vname = variant.name();
@@ -270,7 +338,7 @@ pub fn write_type_definition_enum_variant_fields_to_unmanaged(i: &Interop, w: &m
             VariantKind::Typed(x, t) if !t.is_void() => {
                 let vname = variant.name();
                 let convert = field_to_unmanaged(&Field::new(vname.to_string(), t.to_type()));
-                indented!(w, [()()], r"if (_variant == {x}) _unmanaged._{vname}._{vname} = _{convert};")?;
+                indented!(w, [()()], r"if (_variant == {x}) _unmanaged._payload._{vname} = _{convert};")?;
             }
             _ => panic!("This should never happen"),
         }
@@ -290,7 +358,7 @@ pub fn write_type_definition_enum_variant_fields_as_unmanaged(i: &Interop, w: &m
             VariantKind::Typed(x, t) if !t.is_void() => {
                 let vname = variant.name();
                 let convert = field_as_unmanaged(&Field::new(vname.to_string(), t.to_type()));
-                indented!(w, [()()], r"if (_variant == {x}) _unmanaged._{vname}._{vname} = _{convert};")?;
+                indented!(w, [()()], r"if (_variant == {x}) _unmanaged._payload._{vname} = _{convert};")?;
             }
             _ => panic!("This should never happen"),
         }
@@ -310,7 +378,7 @@ pub fn write_type_definition_enum_variant_fields_to_managed(i: &Interop, w: &mut
             VariantKind::Typed(x, t) if !t.is_void() => {
                 let vname = variant.name();
                 let convert = field_to_managed(&Field::new(vname.to_string(), t.to_type()));
-                indented!(w, [()()()], r"if (_variant == {x}) _managed._{vname} = _{vname}._{convert};")?;
+                indented!(w, [()()()], r"if (_variant == {x}) _managed._{vname} = _payload._{convert};")?;
             }
             _ => panic!("This should never happen"),
         }
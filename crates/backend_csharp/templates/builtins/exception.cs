@@ -3,4 +3,15 @@ public class InteropException : Exception
     public InteropException() : base()
     {
     }
+
+    public InteropException(string message) : base(message)
+    {
+    }
+
+    /// Creates an exception for an unexpected result, including details about the last error or panic in Rust.
+    public static InteropException FromLastError(string result)
+    {
+        var lastError = Utf8String.LastError();
+        return string.IsNullOrEmpty(lastError) ? new InteropException(result) : new InteropException($"{result}: {lastError}");
+    }
 }
@@ -10,23 +10,37 @@ public partial class Utf8String : IDisposable
 {
     private Utf8String() { }
 
+    // Hands the UTF-16 contents of `s` to Rust, which converts them without an intermediate copy here. Like
+    // `Encoding.UTF8`, unpaired surrogates become U+FFFD.
     [MethodImpl(MethodImplOptions.AggressiveOptimization)]
     public static unsafe Utf8String From(string s)
     {
-        var rval = new Utf8String();
-        var source = s.AsSpan();
-        Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-        var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+        fixed (char* p = s)
+        {
+            InteropHelper.interoptopus_string_create_utf16((IntPtr)p, (ulong)s.Length, out var native);
+            return native.IntoManaged();
+        }
+    }
 
-        fixed (byte* p = utf8Bytes)
+    [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+    public static unsafe Utf8String FromUtf8(ReadOnlySpan<byte> utf8)
+    {
+        fixed (byte* p = utf8)
         {
-            InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-            rval._ptr = native._ptr;
-            rval._len = native._len;
-            rval._capacity = native._capacity;
+            var status = InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)utf8.Length, out var native);
+            if (status != 0) { throw new InteropException("Bytes are not valid UTF-8."); }
+            return native.IntoManaged();
         }
+    }
 
-        return rval;
+    /// Returns and clears details about the last error or panic in Rust on this thread, or an empty string.
+    public static string LastError()
+    {
+        {%- if has_last_error %}
+        return InteropHelper.interoptopus_last_error().IntoManaged().IntoString();
+        {%- else %}
+        return string.Empty;
+        {%- endif %}
     }
 
     [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -124,6 +138,11 @@ public partial class Utf8String : IDisposable
         {% for decor in extra_fn_decorations %}{{ decor }}{% endfor %}
         public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
 
+        [LibraryImport({{class_name}}.NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        {% for decor in extra_fn_decorations %}{{ decor }}{% endfor %}
+        public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, out Unmanaged rval);
+
         [LibraryImport({{class_name}}.NativeLib, EntryPoint = "interoptopus_string_destroy")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         {% for decor in extra_fn_decorations %}{{ decor }}{% endfor %}
@@ -133,6 +152,13 @@ public partial class Utf8String : IDisposable
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         {% for decor in extra_fn_decorations %}{{ decor }}{% endfor %}
         public static partial long interoptopus_string_clone(ref Unmanaged orig, ref Unmanaged cloned);
+        {%- if has_last_error %}
+
+        [LibraryImport({{class_name}}.NativeLib, EntryPoint = "interoptopus_last_error")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        {% for decor in extra_fn_decorations %}{{ decor }}{% endfor %}
+        public static partial Unmanaged interoptopus_last_error();
+        {%- endif %}
     }
 
     [CustomMarshaller(typeof(Utf8String), MarshalMode.Default, typeof(Marshaller))]
@@ -19,6 +19,12 @@ pub enum Error {
 
     /// Not valid UTF-8
     FromUtf8(std::string::FromUtf8Error),
+
+    /// An allocator was already set, or buffers were already created with the default one.
+    AllocatorInUse,
+
+    /// A shape or strides don't match the underlying data.
+    Shape,
 }
 
 impl From<std::fmt::Error> for Error {
@@ -1,11 +1,17 @@
 //! FFI-safe versions of common std Rust types.
 
 pub use crate::pattern::cstr::CStrPtr;
+pub use crate::pattern::duration::Duration;
+pub use crate::pattern::iter::Iter;
+pub use crate::pattern::map::Map;
+pub use crate::pattern::ndslice::{NdSlice, NdSliceMut};
 pub use crate::pattern::option::{Option, Option::None, Option::Some};
-pub use crate::pattern::primitive::{Bool, CChar};
+pub use crate::pattern::primitive::{Bool, CChar, Char};
 pub use crate::pattern::result::{Result, Result::Err, Result::Ok};
 pub use crate::pattern::slice::{Slice, SliceMut};
+pub use crate::pattern::str::Str;
 pub use crate::pattern::string::String;
+pub use crate::pattern::tuple::{Tuple2, Tuple3, Tuple4, Tuple5, Tuple6};
 pub use crate::pattern::vec::Vec;
 
 /// Logs an error if compiled with feature `log`.
@@ -1,7 +1,7 @@
 use crate::inventory::forbidden::FORBIDDEN_NAMES;
 use crate::lang::util::{extract_namespaces_from_types, extract_wire_types_from_functions, holds_opaque_without_ref, types_from_functions_types};
-use crate::lang::{Constant, Function, Included, Meta, Opaque, Parameter, Signature, Type};
-use crate::pattern::LibraryPattern;
+use crate::lang::{Constant, Function, Included, Meta, Opaque, Parameter, Primitive, Signature, Type};
+use crate::pattern::{LibraryPattern, TypePattern};
 use std::collections::HashSet;
 
 /// Tells the [`InventoryBuilder`] what to register.
@@ -88,25 +88,44 @@ impl InventoryBuilder {
             Symbol::Function(x) => self.functions.push(x),
             Symbol::Constant(x) => self.constants.push(x),
             Symbol::Type(x) => self.extra_types.push(x),
-            Symbol::Pattern(x) => {
-                match &x {
-                    LibraryPattern::Service(x) => {
-                        self.functions.push(x.destructor().clone());
-                        self.functions.extend(x.constructors().iter().cloned());
-                        self.functions.extend(x.methods().iter().cloned());
-                    }
-                    LibraryPattern::Builtins(x) => {
-                        self.functions.extend(x.functions().iter().cloned());
-                    }
-                }
-                self.patterns.push(x);
-            }
+            Symbol::Pattern(x) => self.register_pattern(x),
             Symbol::Included(et) => self.included_types.push(et),
         }
 
         self
     }
 
+    fn register_pattern(&mut self, x: LibraryPattern) {
+        // Interfaces are also registered implicitly by the services implementing them.
+        if self.patterns.contains(&x) {
+            return;
+        }
+
+        match &x {
+            LibraryPattern::Service(x) => {
+                self.functions.push(x.destructor().clone());
+                self.functions.extend(x.constructors().iter().cloned());
+                self.functions.extend(x.methods().iter().cloned());
+                self.functions.extend(x.static_methods().iter().cloned());
+
+                for implemented in x.interfaces() {
+                    self.functions.push(implemented.as_handle().clone());
+                    self.register_pattern(LibraryPattern::Interface(implemented.interface().clone()));
+                }
+            }
+            LibraryPattern::Interface(x) => {
+                self.functions.extend(x.methods().iter().cloned());
+            }
+            LibraryPattern::Foreign(x) => {
+                self.extra_types.push(x.vtable().to_type());
+            }
+            LibraryPattern::Builtins(x) => {
+                self.functions.extend(x.functions().iter().cloned());
+            }
+        }
+        self.patterns.push(x);
+    }
+
     /// Does additional sanity checking, highly recommended.
     ///
     /// This method tries to detect FFI issues that are hard to detect otherwise, and would
@@ -115,9 +134,30 @@ impl InventoryBuilder {
     /// # Panics
     ///
     /// If a function, type, or pattern is detected that doesn't make sense in interop
-    /// generation, a panic will be raised.
+    /// generation, a panic will be raised. This includes any `ffi::Vec` or `ffi::Map`
+    /// for which the corresponding [`builtins_vec!`](crate::builtins_vec) or
+    /// [`builtins_map!`](crate::builtins_map) was not registered, as well as `u128` and
+    /// `i128` on targets where their C ABI isn't stable.
+    ///
+    /// The latter is decided for the host running this check, which is only correct if your
+    /// library isn't cross compiled. Otherwise use [`validate_for_target`](Self::validate_for_target).
     #[must_use]
     pub fn validate(self) -> Self {
+        self.validate_with(STABLE_128_BIT_ABI)
+    }
+
+    /// Like [`validate`](Self::validate), but decides if `u128` and `i128` are allowed for the
+    /// given target triple (e.g., `x86_64-pc-windows-msvc`) instead of the host.
+    ///
+    /// # Panics
+    ///
+    /// Under the same conditions as [`validate`](Self::validate).
+    #[must_use]
+    pub fn validate_for_target(self, target: &str) -> Self {
+        self.validate_with(stable_128_bit_abi(target))
+    }
+
+    fn validate_with(self, stable_128_bit_abi: bool) -> Self {
         // Check for opaque parameters and return values
         for x in &self.functions {
             let has_opaque_param = x.signature().params().iter().any(|x| holds_opaque_without_ref(x.the_type()));
@@ -131,6 +171,9 @@ impl InventoryBuilder {
             validate_symbol_names(&self.functions, &self.extra_types);
         }
 
+        validate_builtins(&self.functions, &self.extra_types);
+        validate_layouts(&self.functions, &self.extra_types, stable_128_bit_abi);
+
         self
     }
 
@@ -565,3 +608,60 @@ fn validate_symbol_names(functions: &[Function], c_types: &[Type]) {
         }
     }
 }
+
+/// Checks that the builtins needed by every `ffi::Vec`, `ffi::Map` and `ffi::Iter` in use were registered.
+fn validate_builtins(functions: &[Function], extra_types: &[Type]) {
+    for t in types_from_functions_types(functions, extra_types) {
+        // We only know the FFI names of the types involved, so we refer to their Rust types indirectly.
+        let (prefix, access, registration, types) = match &t {
+            Type::Pattern(TypePattern::Vec(x)) => ("interoptopus_vec", "create", "builtins_vec!(T)", format!("`T` being the Rust type of `{}`", x.t().name_within_lib())),
+            Type::Pattern(TypePattern::Map(x)) => {
+                let types = format!("`K` and `V` being the Rust types of `{}` and `{}`", x.k().name_within_lib(), x.v().name_within_lib());
+                ("interoptopus_map", "create", "builtins_map!(K, V)", types)
+            }
+            Type::Pattern(TypePattern::Iter(x)) => {
+                ("interoptopus_iter", "next", "builtins_iter!(T)", format!("`T` being the Rust type of `{}`", x.t().name_within_lib()))
+            }
+            _ => continue,
+        };
+
+        // Builtins are exported with `export_unique`, so we match them by prefix and the type they handle.
+        let by_ref = Type::ReadWritePointer(Box::new(t.clone()));
+        let access = functions
+            .iter()
+            .any(|f| f.name().starts_with(&format!("{prefix}_{access}")) && f.signature().params().iter().any(|p| p.the_type() == &by_ref));
+        let destroy = functions
+            .iter()
+            .any(|f| f.name().starts_with(&format!("{prefix}_destroy")) && f.signature().params().first().is_some_and(|p| p.the_type() == &t));
+
+        let name = t.name_within_lib();
+        assert!(access && destroy, "Type `{name}` is used but its builtins are missing, register them via `.register({registration})` with {types}.");
+    }
+}
+
+/// Targets on which Rust's `u128` and `i128` match the C ABI of `__int128`, i.e., have the same
+/// alignment and are passed in the same registers (since Rust 1.77). MSVC has no `__int128`, so
+/// Windows is excluded.
+///
+/// Note this is evaluated for the host compiling the inventory, not necessarily the library's target.
+const STABLE_128_BIT_ABI: bool = cfg!(all(any(target_arch = "x86_64", target_arch = "aarch64"), not(target_os = "windows")));
+
+/// Same as [`STABLE_128_BIT_ABI`], for a target triple.
+fn stable_128_bit_abi(target: &str) -> bool {
+    let mut parts = target.split('-');
+    let arch = parts.next().unwrap_or_default();
+    matches!(arch, "x86_64" | "aarch64") && !parts.any(|x| x == "windows")
+}
+
+fn validate_layouts(functions: &[Function], extra_types: &[Type], stable_128_bit_abi: bool) {
+    if stable_128_bit_abi {
+        return;
+    }
+
+    for t in types_from_functions_types(functions, extra_types) {
+        if let Type::Primitive(x @ (Primitive::U128 | Primitive::I128)) = t {
+            let name = x.rust_name();
+            panic!("Type `{name}` is used but 128-bit integers don't have a stable C ABI on this target, pass them as two `u64` instead.");
+        }
+    }
+}
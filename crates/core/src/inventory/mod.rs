@@ -9,7 +9,7 @@ pub(crate) mod forbidden;
 use crate::lang::Function;
 pub use core::{Inventory, InventoryBuilder, InventoryItem, OwnedInventoryItem, Symbol};
 
-/// Returns all functions not belonging to a [`service`](crate::pattern::service) pattern.
+/// Returns all functions not belonging to a [`service`](crate::pattern::service) or service interface pattern.
 ///
 /// Useful in backends like Python that can fully encapsulate services and should not expose their
 /// raw methods in the main namespace.
@@ -20,10 +20,15 @@ pub fn non_service_functions(inventory: &Inventory) -> Vec<&Function> {
         match pattern {
             LibraryPattern::Service(service) => {
                 service_methods.extend_from_slice(service.methods());
+                service_methods.extend_from_slice(service.static_methods());
                 service_methods.extend_from_slice(service.constructors());
                 service_methods.push(service.destructor().clone());
+                service_methods.extend(service.interfaces().iter().map(|x| x.as_handle().clone()));
             }
-            LibraryPattern::Builtins(_) => {}
+            LibraryPattern::Interface(interface) => {
+                service_methods.extend_from_slice(interface.methods());
+            }
+            LibraryPattern::Foreign(_) | LibraryPattern::Builtins(_) => {}
         }
     }
 
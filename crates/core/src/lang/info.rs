@@ -98,25 +98,31 @@ impl_ctype_primitive!(isize, Primitive::Isize);
 impl_ctype_primitive!(f32, Primitive::F32);
 impl_ctype_primitive!(f64, Primitive::F64);
 impl_ctype_primitive!(bool, Primitive::Bool);
+impl_ctype_primitive!(u128, Primitive::U128);
+impl_ctype_primitive!(i128, Primitive::I128);
 impl_ctype_primitive!(std::num::NonZeroU8, Primitive::U8);
 impl_ctype_primitive!(std::num::NonZeroU16, Primitive::U16);
 impl_ctype_primitive!(std::num::NonZeroU32, Primitive::U32);
 impl_ctype_primitive!(std::num::NonZeroU64, Primitive::U64);
+impl_ctype_primitive!(std::num::NonZeroU128, Primitive::U128);
 impl_ctype_primitive!(std::num::NonZeroUsize, Primitive::Usize);
 impl_ctype_primitive!(std::num::NonZeroI8, Primitive::I8);
 impl_ctype_primitive!(std::num::NonZeroI16, Primitive::I16);
 impl_ctype_primitive!(std::num::NonZeroI32, Primitive::I32);
 impl_ctype_primitive!(std::num::NonZeroI64, Primitive::I64);
+impl_ctype_primitive!(std::num::NonZeroI128, Primitive::I128);
 impl_ctype_primitive!(std::num::NonZeroIsize, Primitive::Isize);
 impl_ctype_primitive!(Option<std::num::NonZeroU8>, Primitive::U8);
 impl_ctype_primitive!(Option<std::num::NonZeroU16>, Primitive::U16);
 impl_ctype_primitive!(Option<std::num::NonZeroU32>, Primitive::U32);
 impl_ctype_primitive!(Option<std::num::NonZeroU64>, Primitive::U64);
+impl_ctype_primitive!(Option<std::num::NonZeroU128>, Primitive::U128);
 impl_ctype_primitive!(Option<std::num::NonZeroUsize>, Primitive::Usize);
 impl_ctype_primitive!(Option<std::num::NonZeroI8>, Primitive::I8);
 impl_ctype_primitive!(Option<std::num::NonZeroI16>, Primitive::I16);
 impl_ctype_primitive!(Option<std::num::NonZeroI32>, Primitive::I32);
 impl_ctype_primitive!(Option<std::num::NonZeroI64>, Primitive::I64);
+impl_ctype_primitive!(Option<std::num::NonZeroI128>, Primitive::I128);
 impl_ctype_primitive!(Option<std::num::NonZeroIsize>, Primitive::Isize);
 
 unsafe impl<T> TypeInfo for NonNull<T>
@@ -182,203 +188,44 @@ where
     }
 }
 
-unsafe impl<R> TypeInfo for extern "C" fn() -> R
-where
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(vec![], R::type_info());
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<R> TypeInfo for Option<extern "C" fn() -> R>
-where
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(vec![], R::type_info());
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, R> TypeInfo for extern "C" fn(T1) -> R
-where
-    T1: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(vec![Parameter::new("x0".to_string(), T1::type_info())], R::type_info());
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, R> TypeInfo for Option<extern "C" fn(T1) -> R>
-where
-    T1: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(vec![Parameter::new("x0".to_string(), T1::type_info())], R::type_info());
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, T2, R> TypeInfo for extern "C" fn(T1, T2) -> R
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(vec![Parameter::new("x0".to_string(), T1::type_info()), Parameter::new("x1".to_string(), T2::type_info())], R::type_info());
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, T2, R> TypeInfo for Option<extern "C" fn(T1, T2) -> R>
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(vec![Parameter::new("x0".to_string(), T1::type_info()), Parameter::new("x1".to_string(), T2::type_info())], R::type_info());
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, T2, T3, R> TypeInfo for extern "C" fn(T1, T2, T3) -> R
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    T3: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(
-            vec![
-                Parameter::new("x0".to_string(), T1::type_info()),
-                Parameter::new("x1".to_string(), T2::type_info()),
-                Parameter::new("x2".to_string(), T3::type_info()),
-            ],
-            R::type_info(),
-        );
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, T2, T3, R> TypeInfo for Option<extern "C" fn(T1, T2, T3) -> R>
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    T3: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(
-            vec![
-                Parameter::new("x0".to_string(), T1::type_info()),
-                Parameter::new("x1".to_string(), T2::type_info()),
-                Parameter::new("x2".to_string(), T3::type_info()),
-            ],
-            R::type_info(),
-        );
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, T2, T3, T4, R> TypeInfo for extern "C" fn(T1, T2, T3, T4) -> R
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    T3: TypeInfo,
-    T4: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(
-            vec![
-                Parameter::new("x0".to_string(), T1::type_info()),
-                Parameter::new("x1".to_string(), T2::type_info()),
-                Parameter::new("x2".to_string(), T3::type_info()),
-                Parameter::new("x3".to_string(), T4::type_info()),
-            ],
-            R::type_info(),
-        );
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
-
-unsafe impl<T1, T2, T3, T4, R> TypeInfo for Option<extern "C" fn(T1, T2, T3, T4) -> R>
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    T3: TypeInfo,
-    T4: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(
-            vec![
-                Parameter::new("x0".to_string(), T1::type_info()),
-                Parameter::new("x1".to_string(), T2::type_info()),
-                Parameter::new("x2".to_string(), T3::type_info()),
-                Parameter::new("x3".to_string(), T4::type_info()),
-            ],
-            R::type_info(),
-        );
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
+macro_rules! impl_ctype_fnpointer {
+    ($($t:ident $name:literal),*) => {
+        unsafe impl<$($t,)* R> TypeInfo for extern "C" fn($($t),*) -> R
+        where
+            $($t: TypeInfo,)*
+            R: TypeInfo,
+        {
+            fn type_info() -> Type {
+                let sig = Signature::new(vec![$(Parameter::new($name.to_string(), $t::type_info())),*], R::type_info());
+                Type::FnPointer(FnPointer::new(sig))
+            }
+        }
 
-unsafe impl<T1, T2, T3, T4, T5, R> TypeInfo for extern "C" fn(T1, T2, T3, T4, T5) -> R
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    T3: TypeInfo,
-    T4: TypeInfo,
-    T5: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(
-            vec![
-                Parameter::new("x0".to_string(), T1::type_info()),
-                Parameter::new("x1".to_string(), T2::type_info()),
-                Parameter::new("x2".to_string(), T3::type_info()),
-                Parameter::new("x3".to_string(), T4::type_info()),
-                Parameter::new("x4".to_string(), T5::type_info()),
-            ],
-            R::type_info(),
-        );
-        Type::FnPointer(FnPointer::new(sig))
-    }
+        unsafe impl<$($t,)* R> TypeInfo for Option<extern "C" fn($($t),*) -> R>
+        where
+            $($t: TypeInfo,)*
+            R: TypeInfo,
+        {
+            fn type_info() -> Type {
+                <extern "C" fn($($t),*) -> R>::type_info()
+            }
+        }
+    };
 }
 
-unsafe impl<T1, T2, T3, T4, T5, R> TypeInfo for Option<extern "C" fn(T1, T2, T3, T4, T5) -> R>
-where
-    T1: TypeInfo,
-    T2: TypeInfo,
-    T3: TypeInfo,
-    T4: TypeInfo,
-    T5: TypeInfo,
-    R: TypeInfo,
-{
-    fn type_info() -> Type {
-        let sig = Signature::new(
-            vec![
-                Parameter::new("x0".to_string(), T1::type_info()),
-                Parameter::new("x1".to_string(), T2::type_info()),
-                Parameter::new("x2".to_string(), T3::type_info()),
-                Parameter::new("x3".to_string(), T4::type_info()),
-                Parameter::new("x4".to_string(), T5::type_info()),
-            ],
-            R::type_info(),
-        );
-        Type::FnPointer(FnPointer::new(sig))
-    }
-}
+impl_ctype_fnpointer!();
+impl_ctype_fnpointer!(T1 "x0");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4", T6 "x5");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4", T6 "x5", T7 "x6");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4", T6 "x5", T7 "x6", T8 "x7");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4", T6 "x5", T7 "x6", T8 "x7", T9 "x8");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4", T6 "x5", T7 "x6", T8 "x7", T9 "x8", T10 "x9");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4", T6 "x5", T7 "x6", T8 "x7", T9 "x8", T10 "x9", T11 "x10");
+impl_ctype_fnpointer!(T1 "x0", T2 "x1", T3 "x2", T4 "x3", T5 "x4", T6 "x5", T7 "x6", T8 "x7", T9 "x8", T10 "x9", T11 "x10", T12 "x11");
 
 unsafe impl<T, const N: usize> TypeInfo for [T; N]
 where
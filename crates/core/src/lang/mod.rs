@@ -54,8 +54,8 @@ pub enum Type {
     /// only to be able to serialize and deserialize themselves through a buffer.
     WirePayload(WirePayload),
     FnPointer(FnPointer),
-    ReadPointer(Box<Type>),
-    ReadWritePointer(Box<Type>),
+    ReadPointer(Box<Self>),
+    ReadWritePointer(Box<Self>),
     /// Special patterns with primitives existing on C-level but special semantics.
     /// useful to higher level languages.
     Pattern(TypePattern),
@@ -99,6 +99,9 @@ impl Type {
                 Primitive::Isize => std::mem::size_of::<isize>(),
                 Primitive::F32 => 4,
                 Primitive::F64 => 8,
+                Primitive::Char => 4,
+                Primitive::U128 => 16,
+                Primitive::I128 => 16,
             },
             // TODO
             _ => 999,
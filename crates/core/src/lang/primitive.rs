@@ -33,6 +33,10 @@ pub enum Primitive {
     Isize,
     F32,
     F64,
+    /// A Rust `char`, a Unicode scalar value with the layout of a `u32`, see [`Char`](crate::ffi::Char).
+    Char,
+    U128,
+    I128,
 }
 
 impl Primitive {
@@ -53,6 +57,15 @@ impl Primitive {
             Self::Isize => "isize",
             Self::F32 => "f32",
             Self::F64 => "f64",
+            Self::Char => "char",
+            Self::U128 => "u128",
+            Self::I128 => "i128",
         }
     }
+
+    /// If this is a 128-bit integer, whose C ABI is only stable on some targets.
+    #[must_use]
+    pub const fn is_128_bit(&self) -> bool {
+        matches!(self, Self::U128 | Self::I128)
+    }
 }
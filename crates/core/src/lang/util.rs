@@ -48,7 +48,7 @@ pub fn longest_common_prefix(functions: &[Function]) -> String {
     String::from_iter(&longest_common)
 }
 
-use crate::lang::{Function, Type, VariantKind, WirePayload};
+use crate::lang::{Enum, Function, Signature, Type, Variant, VariantKind, WirePayload};
 use crate::pattern::TypePattern;
 use std::collections::HashSet;
 use std::iter::FromIterator;
@@ -161,6 +161,15 @@ pub fn types_from_type(start: &Type) -> Vec<Type> {
     types_from_functions_types(&[], from_ref(start))
 }
 
+/// Whether the return value of `signature` contains a `Utf8Str`, which may borrow from an argument.
+///
+/// Backends must not convert `Utf8Str` arguments of such functions from temporaries, as the
+/// returned value would outlive them.
+#[must_use]
+pub fn rval_may_borrow_str(signature: &Signature) -> bool {
+    types_from_type(signature.rval()).iter().any(|x| matches!(x, Type::Pattern(TypePattern::Utf8Str(_))))
+}
+
 #[derive(PartialEq)]
 enum TypeChoice {
     IncludeWire,
@@ -263,6 +272,8 @@ fn types_from_type_recursive_inner(start: &Type, types: &mut HashSet<Type>, choi
             }
             TypePattern::Slice(x) => types_from_type_recursive(x.t(), types),
             TypePattern::SliceMut(x) => types_from_type_recursive(x.t(), types),
+            TypePattern::NdSlice(x) => types_from_type_recursive(x.t(), types),
+            TypePattern::NdSliceMut(x) => types_from_type_recursive(x.t(), types),
             TypePattern::Option(x) => types_from_type_recursive(x.t(), types),
             TypePattern::Result(x) => {
                 for variant in x.the_enum().variants() {
@@ -273,10 +284,17 @@ fn types_from_type_recursive_inner(start: &Type, types: &mut HashSet<Type>, choi
                 }
             }
             TypePattern::Vec(x) => types_from_type_recursive(x.t(), types),
+            TypePattern::Map(x) => {
+                types_from_type_recursive(x.k(), types);
+                types_from_type_recursive(x.v(), types);
+            }
+            TypePattern::Iter(x) => types_from_type_recursive(x.t(), types),
+            TypePattern::Tuple(x) => x.types().iter().for_each(|t| types_from_type_recursive(t, types)),
             TypePattern::Bool => {}
             TypePattern::CChar => {}
             TypePattern::APIVersion => {}
             TypePattern::Utf8String(_) => {}
+            TypePattern::Utf8Str(_) | TypePattern::Duration(_) => {}
         },
         Type::Included(_) => { /* Nothing to do */ }
     }
@@ -348,6 +366,12 @@ pub(crate) fn extract_namespaces_from_types(types: &[Type], into: &mut HashSet<S
                 TypePattern::SliceMut(x) => {
                     into.insert(x.meta().module().to_string());
                 }
+                TypePattern::NdSlice(x) => {
+                    into.insert(x.meta().module().to_string());
+                }
+                TypePattern::NdSliceMut(x) => {
+                    into.insert(x.meta().module().to_string());
+                }
                 TypePattern::Option(x) => {
                     into.insert(x.meta().module().to_string());
                 }
@@ -358,9 +382,19 @@ pub(crate) fn extract_namespaces_from_types(types: &[Type], into: &mut HashSet<S
                 TypePattern::CChar => {}
                 TypePattern::NamedCallback(_) => {}
                 TypePattern::Utf8String(_) => {}
+                TypePattern::Utf8Str(_) | TypePattern::Duration(_) => {}
                 TypePattern::Vec(x) => {
                     into.insert(x.meta().module().to_string());
                 }
+                TypePattern::Map(x) => {
+                    into.insert(x.meta().module().to_string());
+                }
+                TypePattern::Iter(x) => {
+                    into.insert(x.meta().module().to_string());
+                }
+                TypePattern::Tuple(x) => {
+                    into.insert(x.meta().module().to_string());
+                }
             },
             Type::Included(_) => { /* Nothing to do */ }
         }
@@ -411,9 +445,12 @@ pub(crate) fn holds_opaque_without_ref(typ: &Type) -> bool {
         Type::Pattern(x) => match x {
             TypePattern::CStrPointer => false,
             TypePattern::Utf8String(_) => false,
+            TypePattern::Utf8Str(_) | TypePattern::Duration(_) => false,
             TypePattern::APIVersion => false,
             TypePattern::Slice(x) => holds_opaque_without_ref(x.t()),
             TypePattern::SliceMut(x) => holds_opaque_without_ref(x.t()),
+            TypePattern::NdSlice(x) => holds_opaque_without_ref(x.t()),
+            TypePattern::NdSliceMut(x) => holds_opaque_without_ref(x.t()),
             TypePattern::Option(x) => holds_opaque_without_ref(&x.the_enum().to_type()),
             TypePattern::Result(x) => holds_opaque_without_ref(&x.the_enum().to_type()),
             TypePattern::Bool => false,
@@ -421,6 +458,9 @@ pub(crate) fn holds_opaque_without_ref(typ: &Type) -> bool {
             TypePattern::NamedCallback(_) => false,
             TypePattern::AsyncCallback(_) => false,
             TypePattern::Vec(x) => holds_opaque_without_ref(x.t()),
+            TypePattern::Map(x) => holds_opaque_without_ref(x.k()) || holds_opaque_without_ref(x.v()),
+            TypePattern::Iter(x) => holds_opaque_without_ref(x.t()),
+            TypePattern::Tuple(x) => x.types().iter().any(holds_opaque_without_ref),
         },
         Type::Included(_) => true, /* TODO: ? think so ? */
     }
@@ -499,6 +539,8 @@ pub fn is_global_type(t: &Type) -> bool {
             TypePattern::APIVersion => false,
             TypePattern::Slice(x) => is_global_type(x.t()),
             TypePattern::SliceMut(x) => is_global_type(x.t()),
+            TypePattern::NdSlice(x) => is_global_type(x.t()),
+            TypePattern::NdSliceMut(x) => is_global_type(x.t()),
             TypePattern::Option(x) => is_global_type(&x.the_enum().to_type()),
             TypePattern::Result(x) => is_global_type(&x.the_enum().to_type()),
             TypePattern::Bool => true,
@@ -506,12 +548,56 @@ pub fn is_global_type(t: &Type) -> bool {
             TypePattern::NamedCallback(_) => false,
             TypePattern::AsyncCallback(_) => false,
             TypePattern::Utf8String(_) => true,
+            TypePattern::Utf8Str(_) | TypePattern::Duration(_) => true,
             TypePattern::Vec(x) => is_global_type(x.t()),
+            TypePattern::Map(x) => is_global_type(x.k()) && is_global_type(x.v()),
+            TypePattern::Iter(x) => is_global_type(x.t()),
+            TypePattern::Tuple(x) => x.types().iter().all(is_global_type),
         },
         Type::Included(_) => true,
     }
 }
 
+/// Returns all enums used as the error type `E` of some `ffi::Result<T, E>` in the given types.
+///
+/// Backends use this to emit a typed exception hierarchy for each error enum.
+#[must_use]
+pub fn error_enums(types: &[Type]) -> Vec<Enum> {
+    let mut rval = types
+        .iter()
+        .filter_map(|t| match t {
+            Type::Pattern(TypePattern::Result(x)) => match x.e() {
+                Type::Enum(e) => Some(e.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    rval.sort_by(|a, b| a.rust_name().cmp(b.rust_name()));
+    rval.dedup_by(|a, b| a.rust_name() == b.rust_name());
+    rval
+}
+
+/// Returns the name of the exception raised for `variant` of the error enum `the_enum`.
+///
+/// This is always `{Enum}{Variant}Exception`, so names stay unique no matter which other
+/// error enums end up in the same bindings.
+///
+/// ```
+/// use interoptopus::lang::{Docs, Enum, Meta, Representation, Variant, VariantKind};
+/// use interoptopus::lang::util::error_variant_exception_name;
+///
+/// let variants = vec![Variant::new("NotFound".to_string(), VariantKind::Unit(0), Docs::new())];
+/// let error = Enum::new("Error".to_string(), variants, Meta::new(), Representation::default());
+///
+/// assert_eq!(error_variant_exception_name(&error, &error.variants()[0]), "ErrorNotFoundException");
+/// ```
+#[must_use]
+pub fn error_variant_exception_name(the_enum: &Enum, variant: &Variant) -> String {
+    format!("{}{}Exception", the_enum.rust_name(), variant.name())
+}
+
 /// Debug macro resolving to the current file and line number.
 ///
 /// ```
@@ -125,6 +125,7 @@
 //! - `derive` - Proc macros such as `ffi_type`, ...
 //! - `serde` - Serde attributes on internal types.
 //! - `log` - Invoke [log](https://crates.io/crates/log) on FFI errors.
+//! - `catch_panics` - Catch panics in `ffi_function`s without an explicit `on_panic` instead of aborting.
 //!
 //!
 //! ## Changelog
@@ -162,7 +163,7 @@
 
 pub use error::Error;
 #[cfg(feature = "derive")]
-pub use interoptopus_proc::{ffi_constant, ffi_function, ffi_service, ffi_service_method, ffi_type};
+pub use interoptopus_proc::{ffi_constant, ffi_foreign_trait, ffi_function, ffi_service, ffi_service_ctor, ffi_service_method, ffi_service_trait, ffi_type};
 
 mod error;
 pub mod ffi;
@@ -104,8 +104,17 @@ macro_rules! extra_type {
 ///         .validate()
 ///         .build()
 /// }
+/// ```
+///
+/// Service interfaces are registered via their trait object, e.g., `pattern!(dyn Storage)`. This
+/// is only needed if no registered service implements the interface, as services register the
+/// interfaces they implement.
 #[macro_export]
 macro_rules! pattern {
+    (dyn $x:path) => {{
+        let info = <dyn $x as $crate::pattern::service::ServiceInterfaceInfo>::interface_info();
+        $crate::inventory::Symbol::Pattern($crate::pattern::LibraryPattern::Interface(info))
+    }};
     ($x:path) => {{
         let info: $crate::pattern::LibraryPattern = <$x as $crate::pattern::LibraryPatternInfo>::pattern_info();
         $crate::inventory::Symbol::Pattern(info)
@@ -0,0 +1,226 @@
+//! Host-supplied allocation hooks used by [`Vec`](crate::pattern::vec::Vec), [`String`](crate::pattern::string::String) and [`WireBuffer`](crate::wire::WireBuffer).
+//!
+//! By default all FFI buffers live in Rust's global allocator. Hosts that manage their own
+//! memory budgets (e.g., game engines) can instead install an [`Allocator`] once, at library
+//! init and before the first buffer is created. From then on every buffer handed over the
+//! FFI boundary is allocated and freed through these hooks, so either side can free what the
+//! other side allocated.
+//!
+//! # Example
+//!
+//! ```
+//! use interoptopus::pattern::alloc::{self, Allocator};
+//! use std::ffi::c_void;
+//!
+//! extern "C" fn my_alloc(size: u64, align: u64) -> *mut c_void {
+//!     // Call into your engine here ...
+//!     # let layout = std::alloc::Layout::from_size_align(size as usize, align as usize).unwrap();
+//!     # unsafe { std::alloc::alloc(layout).cast() }
+//! }
+//!
+//! extern "C" fn my_free(ptr: *mut c_void, size: u64, align: u64) {
+//!     // ... and here.
+//!     # let layout = std::alloc::Layout::from_size_align(size as usize, align as usize).unwrap();
+//!     # unsafe { std::alloc::dealloc(ptr.cast(), layout) }
+//! }
+//!
+//! alloc::set_allocator(Allocator::new(my_alloc, my_free)).unwrap();
+//! ```
+//!
+//! Foreign code can do the same via the `interoptopus_allocator_set` function emitted by
+//! [`builtins_alloc!`](crate::builtins_alloc), which also emits `interoptopus_alloc` and
+//! `interoptopus_free` to allocate buffers Rust will later free, and vice versa.
+//!
+//! With hooks installed, converting between [`std::vec::Vec`] and FFI types copies the elements
+//! once, since Rust's own collections can't use the hooks.
+use crate::Error;
+use std::alloc::Layout;
+use std::ffi::c_void;
+use std::mem::{forget, size_of};
+use std::ptr::{NonNull, copy_nonoverlapping, drop_in_place, slice_from_raw_parts_mut};
+use std::sync::OnceLock;
+
+/// Allocates `size` bytes aligned to `align`, returns null on failure.
+pub type AllocFn = extern "C" fn(size: u64, align: u64) -> *mut c_void;
+
+/// Frees memory previously returned by the matching [`AllocFn`] for the same `size` and `align`.
+pub type FreeFn = extern "C" fn(ptr: *mut c_void, size: u64, align: u64);
+
+static ALLOCATOR: OnceLock<Option<Allocator>> = OnceLock::new();
+
+/// A pair of allocation hooks supplied by the host.
+#[derive(Copy, Clone, Debug)]
+pub struct Allocator {
+    alloc: AllocFn,
+    free: FreeFn,
+}
+
+impl Allocator {
+    #[must_use]
+    pub const fn new(alloc: AllocFn, free: FreeFn) -> Self {
+        Self { alloc, free }
+    }
+}
+
+/// Installs the allocator used for all FFI buffers.
+///
+/// # Errors
+/// Fails with [`Error::AllocatorInUse`] if an allocator was already set, or if a buffer was
+/// already created with the default allocator, as it would otherwise be freed by the wrong one.
+pub fn set_allocator(allocator: Allocator) -> Result<(), Error> {
+    ALLOCATOR.set(Some(allocator)).map_err(|_| Error::AllocatorInUse)
+}
+
+/// Returns the installed hooks, or `None` if Rust's global allocator is used.
+///
+/// Calling this locks in the current choice, later calls to [`set_allocator`] will fail.
+#[must_use]
+pub fn allocator() -> Option<Allocator> {
+    *ALLOCATOR.get_or_init(|| None)
+}
+
+/// Allocates `size` bytes aligned to `align` with the current allocator, returns null on failure.
+#[must_use]
+pub fn alloc(size: usize, align: usize) -> *mut u8 {
+    if size == 0 {
+        return std::ptr::without_provenance_mut(align);
+    }
+
+    match allocator() {
+        Some(x) => (x.alloc)(size as u64, align as u64).cast(),
+        None => match Layout::from_size_align(size, align) {
+            Ok(layout) => unsafe { std::alloc::alloc(layout) },
+            Err(_) => std::ptr::null_mut(),
+        },
+    }
+}
+
+/// Frees memory obtained from [`alloc`].
+///
+/// Since `size` and `align` may come from foreign code they are validated first; if they don't
+/// form a valid [`Layout`] nothing is freed, as [`alloc`] can't have returned such memory either.
+///
+/// # Safety
+/// The `ptr` must come from [`alloc`] with the same `size` and `align`, and must not be used afterwards.
+pub unsafe fn free(ptr: *mut u8, size: usize, align: usize) {
+    if ptr.is_null() || size == 0 {
+        return;
+    }
+
+    let Ok(layout) = Layout::from_size_align(size, align) else {
+        return;
+    };
+
+    match allocator() {
+        Some(x) => (x.free)(ptr.cast(), size as u64, align as u64),
+        None => unsafe { std::alloc::dealloc(ptr, layout) },
+    }
+}
+
+/// Splits a Rust `Vec` into `(ptr, len, capacity)` owned by the current allocator.
+pub(crate) fn vec_into_raw<T>(mut vec: std::vec::Vec<T>) -> (*mut T, usize, usize) {
+    if allocator().is_none() {
+        let rval = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        forget(vec);
+        return rval;
+    }
+
+    let len = vec.len();
+    let ptr = if len == 0 || size_of::<T>() == 0 {
+        NonNull::<T>::dangling().as_ptr()
+    } else {
+        let layout = Layout::array::<T>(len).expect("Too large FFI buffer");
+        let ptr = alloc(layout.size(), layout.align()).cast::<T>();
+        assert!(!ptr.is_null(), "Allocation of FFI buffer failed");
+        ptr
+    };
+
+    // Safety: Elements are moved to the new buffer, the old one only frees its memory.
+    unsafe {
+        copy_nonoverlapping(vec.as_ptr(), ptr, len);
+        vec.set_len(0);
+    }
+
+    (ptr, len, len)
+}
+
+/// Reassembles a Rust `Vec` from parts created by [`vec_into_raw`].
+///
+/// # Safety
+/// The parts must come from [`vec_into_raw`] and must not be used afterwards.
+pub(crate) unsafe fn vec_from_raw<T>(ptr: *mut T, len: usize, capacity: usize) -> std::vec::Vec<T> {
+    if allocator().is_none() {
+        return unsafe { std::vec::Vec::from_raw_parts(ptr, len, capacity) };
+    }
+
+    let mut rval = std::vec::Vec::with_capacity(len);
+    unsafe {
+        copy_nonoverlapping(ptr, rval.as_mut_ptr(), len);
+        rval.set_len(len);
+        free_array::<T>(ptr, capacity);
+    }
+    rval
+}
+
+/// Drops all elements and frees parts created by [`vec_into_raw`].
+///
+/// # Safety
+/// The parts must come from [`vec_into_raw`] and must not be used afterwards.
+pub(crate) unsafe fn vec_drop_raw<T>(ptr: *mut T, len: usize, capacity: usize) {
+    if allocator().is_none() {
+        let _ = unsafe { std::vec::Vec::from_raw_parts(ptr, len, capacity) };
+        return;
+    }
+
+    unsafe {
+        drop_in_place(slice_from_raw_parts_mut(ptr, len));
+        free_array::<T>(ptr, capacity);
+    }
+}
+
+unsafe fn free_array<T>(ptr: *mut T, capacity: usize) {
+    if capacity == 0 || size_of::<T>() == 0 {
+        return;
+    }
+
+    let layout = Layout::array::<T>(capacity).expect("Too large FFI buffer");
+    unsafe { free(ptr.cast(), layout.size(), layout.align()) };
+}
+
+/// Emits helper functions to install an [`Allocator`] and to share allocations with foreign code.
+#[macro_export]
+macro_rules! builtins_alloc {
+    () => {{
+        use ::interoptopus::lang::FunctionInfo;
+
+        /// Installs allocation hooks for all FFI buffers, returns `0` on success or `1` if it is too late to do so.
+        #[$crate::ffi_function(on_panic = "abort")]
+        pub fn interoptopus_allocator_set(alloc: $crate::pattern::alloc::AllocFn, free: $crate::pattern::alloc::FreeFn) -> i64 {
+            match $crate::pattern::alloc::set_allocator($crate::pattern::alloc::Allocator::new(alloc, free)) {
+                Ok(()) => 0,
+                Err(_) => 1,
+            }
+        }
+
+        /// Allocates `size` bytes aligned to `align` that Rust may later free, returns null on failure.
+        #[$crate::ffi_function(on_panic = "abort")]
+        pub fn interoptopus_alloc(size: u64, align: u64) -> *mut ::std::ffi::c_void {
+            $crate::pattern::alloc::alloc(size as usize, align as usize).cast()
+        }
+
+        /// Frees memory allocated by `interoptopus_alloc`, or owned by a buffer Rust handed out; invalid `size` and `align` values are ignored.
+        #[$crate::ffi_function(on_panic = "abort")]
+        pub fn interoptopus_free(ptr: *mut ::std::ffi::c_void, size: u64, align: u64) {
+            unsafe { $crate::pattern::alloc::free(ptr.cast(), size as usize, align as usize) };
+        }
+
+        let items = vec![
+            interoptopus_allocator_set::function_info(),
+            interoptopus_alloc::function_info(),
+            interoptopus_free::function_info(),
+        ];
+        let builtins = $crate::pattern::builtins::Builtins::new(items);
+        let pattern = $crate::pattern::LibraryPattern::Builtins(builtins);
+        $crate::inventory::Symbol::Pattern(pattern)
+    }};
+}
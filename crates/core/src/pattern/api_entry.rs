@@ -36,7 +36,7 @@ macro_rules! api_entry {
             $($function: <$function as interoptopus::lang::FunctionInfo>::Signature,)*
         }
 
-        #[interoptopus::ffi_function]
+        #[interoptopus::ffi_function(on_panic = "abort")]
         pub fn $init(api: *mut $struct) {
             if api.is_null() {
                 return;
@@ -150,7 +150,7 @@ impl ApiHash {
 #[macro_export]
 macro_rules! api_guard {
     ($f:tt) => {{
-        #[$crate::ffi_function]
+        #[$crate::ffi_function(on_panic = "abort")]
         pub fn __api_guard() -> $crate::pattern::api_guard::ApiVersion {
             $f().into()
         }
@@ -97,13 +97,16 @@
 //! }
 //! ```
 
+use crate::lang::util::capitalize_first_letter;
 use crate::lang::{FnPointer, Meta, Type};
+use crate::pattern::result::Result;
 
 /// Internal helper naming a generated callback type wrapper.
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct NamedCallback {
     fnpointer: FnPointer,
     meta: Meta,
+    owned: bool,
 }
 
 impl NamedCallback {
@@ -121,7 +124,20 @@ impl NamedCallback {
     #[must_use]
     pub fn with_meta(callback: FnPointer, meta: Meta) -> Self {
         assert!(callback.name().is_some(), "The pointer provided to a named callback must have a name.");
-        Self { fnpointer: callback, meta }
+        Self { fnpointer: callback, meta, owned: false }
+    }
+
+    /// Marks this callback as owned, i.e., carrying a destructor Rust invokes on drop.
+    #[must_use]
+    pub const fn with_owned(mut self, owned: bool) -> Self {
+        self.owned = owned;
+        self
+    }
+
+    /// Whether this callback is owned and carries a destructor, see [`callback!`](crate::callback).
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        self.owned
     }
 
     /// Gets the type name of this callback.
@@ -198,6 +214,54 @@ impl AsyncCallback {
     }
 }
 
+/// Why a [`call_checked`](crate::callback) invocation failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CallbackError<E> {
+    /// The callback returned an error.
+    Err(E),
+    /// The callback failed on the foreign side, e.g., by throwing an exception.
+    Panic,
+    /// The callback was not set.
+    Null,
+}
+
+/// Implemented by callback return types supporting [`call_checked`](crate::callback), used by generated code.
+#[doc(hidden)]
+pub trait CheckedReturn {
+    type Checked;
+
+    /// Converts the value returned by the callback.
+    fn into_checked(self) -> Self::Checked;
+
+    /// The value returned if the callback was not set.
+    fn null() -> Self::Checked;
+}
+
+impl<T, E> CheckedReturn for Result<T, E> {
+    type Checked = std::result::Result<T, CallbackError<E>>;
+
+    fn into_checked(self) -> Self::Checked {
+        match self {
+            Self::Ok(t) => Ok(t),
+            Self::Err(e) => Err(CallbackError::Err(e)),
+            Self::Panic => Err(CallbackError::Panic),
+            Self::Null => Err(CallbackError::Null),
+        }
+    }
+
+    fn null() -> Self::Checked {
+        Err(CallbackError::Null)
+    }
+}
+
+/// Names an instance of a generic callback, e.g., `OnItemU32` for `OnItem<u32>`, used by generated code.
+#[doc(hidden)]
+#[must_use]
+pub fn instance_name(name: &str, generics: &[Type]) -> String {
+    let generics = generics.iter().map(|x| capitalize_first_letter(&x.name_within_lib())).collect::<Vec<_>>();
+    format!("{name}{}", generics.join(""))
+}
+
 /// Defines a callback type, akin to a `fn f(T) -> R` wrapped in an [`Option`](std::option).
 ///
 /// A named delegate will be emitted in languages supporting them, otherwise a regular
@@ -236,57 +300,210 @@ impl AsyncCallback {
 /// let callback = MyCallback::new(my_rust_callback);
 /// assert_eq!(42, callback.call());
 /// ```
+///
+/// # Owned Callbacks
+///
+/// Prefixing the name with `owned` creates a callback that also carries a destructor. Rust
+/// invokes it with the callback data once the callback is dropped, telling the foreign side
+/// it may release whatever keeps the callback alive (e.g., a `GCHandle` in C#). Owned
+/// callbacks are therefore neither `Clone` nor `Copy`:
+///
+/// ```
+/// use interoptopus::callback;
+/// use std::ffi::c_void;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// callback!(owned MyOwnedCallback(x: u32) -> u32);
+///
+/// static RELEASED: AtomicBool = AtomicBool::new(false);
+///
+/// extern "C" fn double(x: u32, _: *const c_void) -> u32 { x * 2 }
+/// extern "C" fn release(_: *const c_void) { RELEASED.store(true, Ordering::Relaxed); }
+///
+/// let callback = MyOwnedCallback::with_destructor(double, std::ptr::null(), release);
+/// assert_eq!(callback.call(21), 42);
+///
+/// drop(callback);
+/// assert!(RELEASED.load(Ordering::Relaxed));
+/// ```
+///
+/// # Checked Calls
+///
+/// Callbacks returning a [`Result`](crate::ffi::Result) also get a `call_checked` method converting it
+/// into a [`std::result::Result`]. Backends catch exceptions thrown by the foreign callback and
+/// report them as [`CallbackError::Panic`](crate::pattern::callback::CallbackError), or as
+/// `CallbackError::Err` if they carry a matching error value. A callback that isn't set reports
+/// `CallbackError::Null` instead of panicking:
+///
+/// ```
+/// use interoptopus::{callback, ffi};
+/// use interoptopus::pattern::callback::CallbackError;
+/// use std::ffi::c_void;
+///
+/// callback!(MyFallibleCallback(x: u32) -> ffi::Result<u32, u8>);
+///
+/// extern "C" fn checked(x: u32, _: *const c_void) -> ffi::Result<u32, u8> {
+///     if x > 0 { ffi::Ok(x) } else { ffi::Result::Panic }
+/// }
+///
+/// let callback = MyFallibleCallback::new(checked);
+/// assert_eq!(callback.call_checked(1), Ok(1));
+/// assert_eq!(callback.call_checked(0), Err(CallbackError::Panic));
+/// assert_eq!(MyFallibleCallback::default().call_checked(1), Err(CallbackError::Null));
+/// ```
+///
+/// # Generic Callbacks
+///
+/// Callbacks can be generic over their parameter and return types. Each instantiation becomes its
+/// own named type on the FFI side, e.g., `OnItemU32` and `OnItemF32`:
+///
+/// ```
+/// use interoptopus::callback;
+/// use interoptopus::lang::TypeInfo;
+///
+/// callback!(OnItem<T>(value: T) -> u8);
+///
+/// assert_eq!(OnItem::<u32>::type_info().name_within_lib(), "OnItemU32");
+/// ```
 #[macro_export]
 macro_rules! callback {
-    ($name:ident($($param:ident: $ty:ty),*)) => {
-        callback!($name($($param: $ty),*) -> ());
+    (owned $name:ident $(<$($g:ident),+>)? ($($param:ident: $ty:ty),*)) => {
+        $crate::callback!(owned $name $(<$($g),+>)? ($($param: $ty),*) -> ());
     };
 
-    ($name:ident($($param:ident: $ty:ty),*) -> $rval:ty $(, namespace = $ns:expr)?) => {
-        #[derive(Default, Clone, Copy)]
+    (owned $name:ident $(<$($g:ident),+>)? ($($param:ident: $ty:ty),*) -> $rval:ty $(, namespace = $ns:expr)?) => {
         #[repr(C)]
-        pub struct $name(::std::option::Option<extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval>, *const ::std::ffi::c_void);
+        pub struct $name $(<$($g),+>)? (
+            ::std::option::Option<extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval>,
+            *const ::std::ffi::c_void,
+            ::std::option::Option<extern "C" fn(*const ::std::ffi::c_void)>,
+        );
 
-        // Safety: This is a transparent wrapper around a function pointer
-        //         and user-managed callback state. From out perspective
-        //         this is thread safe, as long as the caller's code is.
-        unsafe impl ::std::marker::Send for $name {}
-        unsafe impl ::std::marker::Sync for $name {}
+        impl $(<$($g),+>)? ::std::default::Default for $name $(<$($g),+>)? {
+            fn default() -> Self {
+                Self(None, ::std::ptr::null(), None)
+            }
+        }
+
+        // Safety: The foreign side must accept calls and the destructor from any thread, as
+        //         with regular callbacks.
+        unsafe impl $(<$($g),+>)? ::std::marker::Send for $name $(<$($g),+>)? {}
+        unsafe impl $(<$($g),+>)? ::std::marker::Sync for $name $(<$($g),+>)? {}
 
-        impl $name {
+        impl $(<$($g),+>)? $name $(<$($g),+>)? {
             /// Creates a new instance of the callback using `extern "C" fn`
             pub fn new(func: extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval) -> Self {
-                Self(Some(func), ::std::ptr::null())
+                Self(Some(func), ::std::ptr::null(), None)
             }
 
-            /// Will call function if it exists, panic otherwise.
-            pub fn call(&self, $($param: $ty),*) -> $rval {
-                self.0.expect("Assumed function would exist but it didn't.")($($param,)* self.1)
+            /// Creates a new instance with callback data, and a destructor invoked with that data on drop.
+            pub fn with_destructor(
+                func: extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval,
+                data: *const ::std::ffi::c_void,
+                destructor: extern "C" fn(*const ::std::ffi::c_void),
+            ) -> Self {
+                Self(Some(func), data, Some(destructor))
             }
 
-            /// Will call function only if it exists
-            pub fn call_if_some(&self, $($param: $ty,)*) -> ::std::option::Option<$rval> {
-                match self.0 {
-                    Some(c) => Some(c($($param,)* self.1)),
-                    None => None
+            $crate::callback!(@call ($($param: $ty),*) -> $rval);
+        }
+
+        impl $(<$($g),+>)? ::std::ops::Drop for $name $(<$($g),+>)? {
+            fn drop(&mut self) {
+                if let Some(destructor) = self.2.take() {
+                    destructor(self.1);
                 }
             }
         }
 
-        impl From<for<> extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval> for $name {
+        $crate::callback!(@type_info $name [$($($g),+)?], true, ($($param: $ty),*) -> $rval $(, $ns)?);
+    };
+
+    ($name:ident $(<$($g:ident),+>)? ($($param:ident: $ty:ty),*)) => {
+        $crate::callback!($name $(<$($g),+>)? ($($param: $ty),*) -> ());
+    };
+
+    ($name:ident $(<$($g:ident),+>)? ($($param:ident: $ty:ty),*) -> $rval:ty $(, namespace = $ns:expr)?) => {
+        #[repr(C)]
+        pub struct $name $(<$($g),+>)? (::std::option::Option<extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval>, *const ::std::ffi::c_void);
+
+        // Implemented by hand so generic callbacks don't require their parameters to be `Default`, `Clone` or `Copy`.
+        impl $(<$($g),+>)? ::std::default::Default for $name $(<$($g),+>)? {
+            fn default() -> Self {
+                Self(None, ::std::ptr::null())
+            }
+        }
+
+        #[allow(clippy::expl_impl_clone_on_copy)]
+        impl $(<$($g),+>)? ::std::clone::Clone for $name $(<$($g),+>)? {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl $(<$($g),+>)? ::std::marker::Copy for $name $(<$($g),+>)? {}
+
+        // Safety: This is a transparent wrapper around a function pointer
+        //         and user-managed callback state. From out perspective
+        //         this is thread safe, as long as the caller's code is.
+        unsafe impl $(<$($g),+>)? ::std::marker::Send for $name $(<$($g),+>)? {}
+        unsafe impl $(<$($g),+>)? ::std::marker::Sync for $name $(<$($g),+>)? {}
+
+        impl $(<$($g),+>)? $name $(<$($g),+>)? {
+            /// Creates a new instance of the callback using `extern "C" fn`
+            pub fn new(func: extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval) -> Self {
+                Self(Some(func), ::std::ptr::null())
+            }
+
+            $crate::callback!(@call ($($param: $ty),*) -> $rval);
+        }
+
+        impl $(<$($g),+>)? From<for<> extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval> for $name $(<$($g),+>)? {
             fn from(x: extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval) -> Self {
                 Self(Some(x), ::std::ptr::null())
             }
         }
 
-        impl From<$name> for ::std::option::Option<extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval> {
-            fn from(x: $name) -> Self {
+        impl $(<$($g),+>)? From<$name $(<$($g),+>)?> for ::std::option::Option<extern "C" fn($($ty,)* *const ::std::ffi::c_void) -> $rval> {
+            fn from(x: $name $(<$($g),+>)?) -> Self {
                 x.0
             }
         }
 
+        $crate::callback!(@type_info $name [$($($g),+)?], false, ($($param: $ty),*) -> $rval $(, $ns)?);
+    };
+
+    (@call ($($param:ident: $ty:ty),*) -> $rval:ty) => {
+        /// Will call function if it exists, panic otherwise.
+        pub fn call(&self, $($param: $ty),*) -> $rval {
+            self.0.expect("Assumed function would exist but it didn't.")($($param,)* self.1)
+        }
+
+        /// Will call function only if it exists
+        pub fn call_if_some(&self, $($param: $ty,)*) -> ::std::option::Option<$rval> {
+            match self.0 {
+                Some(c) => Some(c($($param,)* self.1)),
+                None => None
+            }
+        }
+
+        /// Will call function if it exists and convert the returned `ffi::Result` into a
+        /// [`std::result::Result`], telling failures on the foreign side apart from regular errors.
+        pub fn call_checked(&self, $($param: $ty),*) -> <$rval as $crate::pattern::callback::CheckedReturn>::Checked
+        where
+            // The `for<>` defers the bound to call sites, so callbacks not returning a `Result` still compile.
+            for<'__checked> $rval: $crate::pattern::callback::CheckedReturn,
+        {
+            match self.0 {
+                Some(c) => $crate::pattern::callback::CheckedReturn::into_checked(c($($param,)* self.1)),
+                None => <$rval as $crate::pattern::callback::CheckedReturn>::null(),
+            }
+        }
+    };
+
+    (@type_info $name:ident [$($g:ident),*], $owned:expr, ($($param:ident: $ty:ty),*) -> $rval:ty $(, $ns:expr)?) => {
         #[allow(unused_mut)]
-        unsafe impl $crate::lang::TypeInfo for $name {
+        unsafe impl<$($g: $crate::lang::TypeInfo),*> $crate::lang::TypeInfo for $name<$($g),*> {
             fn type_info() -> $crate::lang::Type {
                 use $crate::lang::{TypeInfo, Type, Meta, Docs, Primitive, Parameter, Signature, FnPointer};
 
@@ -304,10 +521,11 @@ macro_rules! callback {
                     namespace = ::std::string::String::from($ns);
                 )*
 
+                let name = $crate::pattern::callback::instance_name(stringify!($name), &[$(<$g as TypeInfo>::type_info()),*]);
                 let meta = Meta::with_module_docs(namespace, Docs::new());
                 let sig = Signature::new(params, rval);
-                let fn_pointer = FnPointer::new_named(sig, stringify!($name).to_string());
-                let named_callback = $crate::pattern::callback::NamedCallback::with_meta(fn_pointer, meta);
+                let fn_pointer = FnPointer::new_named(sig, name);
+                let named_callback = $crate::pattern::callback::NamedCallback::with_meta(fn_pointer, meta).with_owned($owned);
 
                 Type::Pattern($crate::pattern::TypePattern::NamedCallback(named_callback))
             }
@@ -0,0 +1,104 @@
+//! Like a regular [`Duration`](std::time::Duration), but FFI safe.<sup>🚧</sup>
+//!
+//! The layout of [`std::time::Duration`] is unspecified, so it can't cross an FFI boundary. This
+//! `#[repr(C)]` twin converts from and to it, and supported backends map it to a `TimeSpan` in C#,
+//! or a `datetime.timedelta` in Python.
+//!
+//! # Example
+//!
+//! ```
+//! use interoptopus::{ffi, ffi_function};
+//! use std::time::Duration;
+//!
+//! #[ffi_function]
+//! pub fn twice(x: ffi::Duration) -> ffi::Option<ffi::Duration> {
+//!     x.checked_into_duration().and_then(|x| x.checked_mul(2)).map(Into::into).into()
+//! }
+//!
+//! assert_eq!(twice(Duration::from_millis(1500).into()).unwrap().secs, 3);
+//! assert!(twice(ffi::Duration { secs: u64::MAX, nanos: 1_500_000_000 }).is_none());
+//! ```
+//!
+//! Since the other side may send any `nanos`, prefer [`Duration::checked_into_duration`] over
+//! [`Duration::into_duration`] for values received over FFI.
+use crate::lang::{Composite, Docs, Field, Layout, Meta, NAMESPACE_COMMON, Primitive, Representation, Type, TypeInfo};
+use crate::pattern::TypePattern;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// FFI analog of [`std::time::Duration`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    /// Whole seconds.
+    pub secs: u64,
+    /// Nanoseconds within the current second, other sides must keep this below `1_000_000_000`.
+    pub nanos: u32,
+}
+
+impl Duration {
+    pub const ZERO: Self = Self { secs: 0, nanos: 0 };
+
+    /// Converts this into a [`std::time::Duration`], surplus `nanos` carry over into `secs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the carry overflows `secs`, which can only happen if the other side sent garbage,
+    /// see [`checked_into_duration`](Self::checked_into_duration).
+    #[must_use]
+    pub const fn into_duration(self) -> std::time::Duration {
+        std::time::Duration::new(self.secs, self.nanos)
+    }
+
+    /// Like [`into_duration`](Self::into_duration), but returns `None` if the carry of surplus
+    /// `nanos` overflows `secs`.
+    #[must_use]
+    pub const fn checked_into_duration(self) -> Option<std::time::Duration> {
+        match self.secs.checked_add((self.nanos / NANOS_PER_SEC) as u64) {
+            Some(secs) => Some(std::time::Duration::new(secs, self.nanos % NANOS_PER_SEC)),
+            None => None,
+        }
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    fn from(x: std::time::Duration) -> Self {
+        Self { secs: x.as_secs(), nanos: x.subsec_nanos() }
+    }
+}
+
+impl From<Duration> for std::time::Duration {
+    fn from(x: Duration) -> Self {
+        x.into_duration()
+    }
+}
+
+unsafe impl TypeInfo for Duration {
+    fn type_info() -> Type {
+        let fields = vec![
+            Field::new("secs".to_string(), Type::Primitive(Primitive::U64)),
+            Field::new("nanos".to_string(), Type::Primitive(Primitive::U32)),
+        ];
+
+        let doc = Docs::from_line(" A span of time, `nanos` is always below one second.");
+        let repr = Representation::new(Layout::C, None);
+        let meta = Meta::with_module_docs(NAMESPACE_COMMON.to_string(), doc);
+        let composite = Composite::with_meta_repr("Duration".to_string(), fields, meta, repr);
+        Type::Pattern(TypePattern::Duration(composite))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Duration;
+
+    #[test]
+    fn converts_from_and_to_std() {
+        let x = Duration::from(std::time::Duration::from_micros(2_500_001));
+        assert_eq!(x, Duration { secs: 2, nanos: 500_001_000 });
+        assert_eq!(Duration { secs: 1, nanos: 1_500_000_000 }.into_duration(), std::time::Duration::from_millis(2500));
+        assert_eq!(Duration { secs: 1, nanos: 1_500_000_000 }.checked_into_duration(), Some(std::time::Duration::from_millis(2500)));
+        assert_eq!(Duration { secs: u64::MAX, nanos: 999_999_999 }.checked_into_duration(), Some(std::time::Duration::MAX));
+        assert_eq!(Duration { secs: u64::MAX, nanos: 1_000_000_000 }.checked_into_duration(), None);
+    }
+}
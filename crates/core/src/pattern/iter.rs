@@ -0,0 +1,211 @@
+//! Lazily hands out items of any Rust [`Iterator`] over FFI.<sup>🚧</sup>
+//!
+//! An iterator is an opaque, Rust-owned handle wrapping an `Iterator<Item = T>`. Unlike a
+//! [`Vec`](crate::pattern::vec::Vec), items are only produced once the other side asks for them, so
+//! large or expensive collections never have to be materialized at once.
+//!
+//! # Example
+//!
+//! ```
+//! use interoptopus::{ffi, ffi_function};
+//!
+//! #[ffi_function]
+//! pub fn squares(n: u32) -> ffi::Iter<u32> {
+//!     ffi::Iter::new((0..n).map(|x| x * x))
+//! }
+//! ```
+//!
+//! Backends need [`builtins_iter!`](crate::builtins_iter) registered for each used item type
+//! to advance and free iterators on their side. Supported backends then expose the handle as an
+//! `IEnumerable<T>` in C#, or as a regular iterator in Python, and free it once exhausted.
+use crate::lang::util::capitalize_first_letter;
+use crate::lang::{Composite, Docs, Field, Layout, Meta, Representation, Type, TypeInfo};
+use crate::pattern::TypePattern;
+use std::ffi::c_void;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+type BoxedIterator<T> = Box<dyn Iterator<Item = T> + Send>;
+
+#[repr(C)]
+pub struct Iter<T> {
+    ptr: *mut c_void,
+    _phantom: PhantomData<T>,
+}
+
+unsafe impl<T> Send for Iter<T> where T: Send {}
+
+impl<T: 'static> Iter<T> {
+    /// Wraps the given iterator, which must own all data it produces.
+    #[must_use]
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + 'static,
+    {
+        let iter: Box<BoxedIterator<T>> = Box::new(Box::new(iter.into_iter()));
+        Self { ptr: Box::into_raw(iter).cast(), _phantom: PhantomData }
+    }
+
+    /// Returns an iterator that never produces any item.
+    #[must_use]
+    pub fn empty() -> Self
+    where
+        T: Send,
+    {
+        Self::new(std::iter::empty())
+    }
+}
+
+impl<T: Send + 'static> Default for Iter<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> Iterator for Iter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ptr.is_null() {
+            return None;
+        }
+
+        let iter = unsafe { &mut *self.ptr.cast::<BoxedIterator<T>>() };
+        iter.next()
+    }
+}
+
+impl<T> Debug for Iter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Iter").field("ptr", &self.ptr).finish()
+    }
+}
+
+impl<T> Drop for Iter<T> {
+    fn drop(&mut self) {
+        if self.ptr.is_null() {
+            return;
+        }
+        drop(unsafe { Box::from_raw(self.ptr.cast::<BoxedIterator<T>>()) });
+    }
+}
+
+unsafe impl<T> TypeInfo for Iter<T>
+where
+    T: TypeInfo,
+{
+    #[rustfmt::skip]
+    fn type_info() -> Type {
+        let fields = vec![
+            Field::new("ptr".to_string(), Type::ReadWritePointer(Box::new(c_void::type_info()))),
+        ];
+
+        let doc = Docs::from_lines(vec![
+            " Iterator marshalling helper.".to_string(),
+            " An opaque, Rust-owned handle that produces items on demand. Like".to_string(),
+            " a Vec it must be freed exactly once, either by the other side or".to_string(),
+            " by passing it back to Rust.".to_string(),
+        ]);
+        let repr = Representation::new(Layout::C, None);
+        let meta = Meta::with_docs(doc);
+        let name = capitalize_first_letter(T::type_info().name_within_lib().as_str());
+        let composite = Composite::with_meta_repr(format!("Iter{name}"), fields, meta, repr);
+        let iter_type = IterType::new(composite, Box::new(T::type_info()));
+        Type::Pattern(TypePattern::Iter(iter_type))
+    }
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct IterType {
+    composite_type: Composite,
+    target_type: Box<Type>,
+}
+
+impl IterType {
+    #[must_use]
+    pub const fn new(composite_type: Composite, target_type: Box<Type>) -> Self {
+        Self { composite_type, target_type }
+    }
+
+    #[must_use]
+    pub fn rust_name(&self) -> &str {
+        self.composite_type.rust_name()
+    }
+
+    #[must_use]
+    pub const fn composite_type(&self) -> &Composite {
+        &self.composite_type
+    }
+
+    #[must_use]
+    pub const fn t(&self) -> &Type {
+        &self.target_type
+    }
+
+    #[must_use]
+    pub const fn meta(&self) -> &Meta {
+        self.composite_type.meta()
+    }
+
+    #[must_use]
+    pub fn to_type(&self) -> Type {
+        Type::Pattern(TypePattern::Iter(self.clone()))
+    }
+}
+
+/// Emits helper functions used by [`Iter`](crate::pattern::iter::Iter).
+///
+/// Register this once for every `ffi::Iter<T>` used in your API, [`validate`](crate::inventory::InventoryBuilder::validate)
+/// will tell you about any you missed.
+#[macro_export]
+macro_rules! builtins_iter {
+    ($t:ty) => {{
+        use ::interoptopus::lang::FunctionInfo;
+
+        /// Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked.
+        #[$crate::ffi_function(export_unique, on_panic = "return_default")]
+        pub fn interoptopus_iter_next(iter: &mut $crate::pattern::iter::Iter<$t>, rval: &mut ::std::mem::MaybeUninit<$t>) -> i64 {
+            match ::std::iter::Iterator::next(iter) {
+                Some(x) => {
+                    rval.write(x);
+                    1
+                }
+                None => 0,
+            }
+        }
+
+        #[$crate::ffi_function(export_unique, on_panic = "return_default")]
+        pub fn interoptopus_iter_destroy(_: $crate::ffi::Iter<$t>) -> i64 {
+            0
+        }
+
+        let functions = vec![interoptopus_iter_next::function_info(), interoptopus_iter_destroy::function_info()];
+        let builtins = $crate::pattern::builtins::Builtins::new(functions);
+        let pattern = $crate::pattern::LibraryPattern::Builtins(builtins);
+        $crate::inventory::Symbol::Pattern(pattern)
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use super::Iter;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn yields_lazily() {
+        let produced = Arc::new(AtomicUsize::new(0));
+        let counter = produced.clone();
+        let mut iter = Iter::new((0..100).inspect(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(produced.load(Ordering::SeqCst), 2);
+        assert_eq!(iter.by_ref().sum::<u32>(), (2..100).sum());
+        assert_eq!(iter.next(), None);
+        assert_eq!(Iter::<u8>::empty().next(), None);
+    }
+}
@@ -0,0 +1,283 @@
+//! Like a regular [`BTreeMap`], but FFI safe.<sup>🚧</sup>
+//!
+//! A map is stored as two boxed slices of equal length, one holding the keys in ascending
+//! order, the other holding the value for each key at the same index. This lets other languages
+//! read entries without calling back into Rust, while Rust can still look up keys quickly.
+//!
+//! # Example
+//!
+//! ```
+//! use interoptopus::{ffi, ffi_function};
+//!
+//! #[ffi_function]
+//! pub fn scores() -> ffi::Map<u32, f32> {
+//!     [(1, 0.5), (2, 0.75)].into_iter().collect()
+//! }
+//! ```
+//!
+//! Similar to [`Vec`](crate::pattern::vec::Vec), backends need [`builtins_map!`](crate::builtins_map)
+//! registered for each used key and value combination to create and free maps on their side.
+//! Supported backends then expose the map as a read-only dictionary, e.g., an
+//! `IReadOnlyDictionary<K, V>` in C# or a `Mapping` in Python.
+use crate::lang::util::capitalize_first_letter;
+use crate::lang::{Composite, Docs, Field, Layout, Meta, Primitive, Representation, Type, TypeInfo};
+use crate::pattern::TypePattern;
+use std::collections::{BTreeMap, HashMap};
+use std::mem::forget;
+use std::ptr::slice_from_raw_parts_mut;
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Map<K, V> {
+    keys_ptr: *mut K,
+    values_ptr: *mut V,
+    len: u64,
+}
+
+unsafe impl<K, V> Send for Map<K, V>
+where
+    K: Send,
+    V: Send,
+{
+}
+
+unsafe impl<K, V> Sync for Map<K, V>
+where
+    K: Sync,
+    V: Sync,
+{
+}
+
+impl<K: Ord, V> Map<K, V> {
+    /// Returns the value stored for `key`, if any.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let index = self.keys().binary_search(key).ok()?;
+        self.values().get(index)
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys().binary_search(key).is_ok()
+    }
+}
+
+impl<K, V> Map<K, V> {
+    fn from_sorted(keys: std::vec::Vec<K>, values: std::vec::Vec<V>) -> Self {
+        debug_assert_eq!(keys.len(), values.len());
+        let len = keys.len() as u64;
+        let keys_ptr = Box::into_raw(keys.into_boxed_slice()).cast::<K>();
+        let values_ptr = Box::into_raw(values.into_boxed_slice()).cast::<V>();
+        Self { keys_ptr, values_ptr, len }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// All keys, in ascending order.
+    #[must_use]
+    pub const fn keys(&self) -> &[K] {
+        unsafe { std::slice::from_raw_parts(self.keys_ptr, self.len()) }
+    }
+
+    /// All values, in the order of their keys.
+    #[must_use]
+    pub const fn values(&self) -> &[V] {
+        unsafe { std::slice::from_raw_parts(self.values_ptr, self.len()) }
+    }
+
+    /// Iterates over all entries, ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.keys().iter().zip(self.values())
+    }
+
+    #[must_use]
+    pub fn into_btree_map(self) -> BTreeMap<K, V>
+    where
+        K: Ord,
+    {
+        let keys = unsafe { Box::from_raw(slice_from_raw_parts_mut(self.keys_ptr, self.len())) };
+        let values = unsafe { Box::from_raw(slice_from_raw_parts_mut(self.values_ptr, self.len())) };
+        forget(self);
+        keys.into_vec().into_iter().zip(values.into_vec()).collect()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for Map<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        iter.into_iter().collect::<BTreeMap<_, _>>().into()
+    }
+}
+
+impl<K: Ord, V> From<BTreeMap<K, V>> for Map<K, V> {
+    fn from(value: BTreeMap<K, V>) -> Self {
+        let (keys, values) = value.into_iter().unzip();
+        Self::from_sorted(keys, values)
+    }
+}
+
+impl<K: Ord, V, S> From<HashMap<K, V, S>> for Map<K, V> {
+    fn from(value: HashMap<K, V, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: Ord, V> From<Map<K, V>> for BTreeMap<K, V> {
+    fn from(value: Map<K, V>) -> Self {
+        value.into_btree_map()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for Map<K, V> {
+    fn clone(&self) -> Self {
+        Self::from_sorted(self.keys().to_vec(), self.values().to_vec())
+    }
+}
+
+impl<K, V> Drop for Map<K, V> {
+    fn drop(&mut self) {
+        if self.keys_ptr.is_null() || self.values_ptr.is_null() {
+            return;
+        }
+        unsafe {
+            let _ = Box::from_raw(slice_from_raw_parts_mut(self.keys_ptr, self.len()));
+            let _ = Box::from_raw(slice_from_raw_parts_mut(self.values_ptr, self.len()));
+        }
+    }
+}
+
+unsafe impl<K, V> TypeInfo for Map<K, V>
+where
+    K: TypeInfo,
+    V: TypeInfo,
+{
+    #[rustfmt::skip]
+    fn type_info() -> Type {
+        let fields = vec![
+            Field::new("keys_ptr".to_string(), Type::ReadWritePointer(Box::new(K::type_info()))),
+            Field::new("values_ptr".to_string(), Type::ReadWritePointer(Box::new(V::type_info()))),
+            Field::new("len".to_string(), Type::Primitive(Primitive::U64)),
+        ];
+
+        let doc = Docs::from_lines(vec![
+            " Map marshalling helper, holding `len` keys in ascending order and their values.".to_string(),
+            " Like a Vec this has ownership semantics, once passed over an FFI boundary".to_string(),
+            " 'the other side' is meant to own (and free) it.".to_string(),
+        ]);
+        let repr = Representation::new(Layout::C, None);
+        let meta = Meta::with_docs(doc);
+        let k = capitalize_first_letter(K::type_info().name_within_lib().as_str());
+        let v = capitalize_first_letter(V::type_info().name_within_lib().as_str());
+        let composite = Composite::with_meta_repr(format!("Map{k}{v}"), fields, meta, repr);
+        let map_type = MapType::new(composite, Box::new(K::type_info()), Box::new(V::type_info()));
+        Type::Pattern(TypePattern::Map(map_type))
+    }
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct MapType {
+    composite_type: Composite,
+    k: Box<Type>,
+    v: Box<Type>,
+}
+
+impl MapType {
+    #[must_use]
+    pub const fn new(composite_type: Composite, k: Box<Type>, v: Box<Type>) -> Self {
+        Self { composite_type, k, v }
+    }
+
+    #[must_use]
+    pub fn rust_name(&self) -> &str {
+        self.composite_type.rust_name()
+    }
+
+    #[must_use]
+    pub const fn composite_type(&self) -> &Composite {
+        &self.composite_type
+    }
+
+    #[must_use]
+    pub const fn k(&self) -> &Type {
+        &self.k
+    }
+
+    #[must_use]
+    pub const fn v(&self) -> &Type {
+        &self.v
+    }
+
+    #[must_use]
+    pub const fn meta(&self) -> &Meta {
+        self.composite_type.meta()
+    }
+
+    #[must_use]
+    pub fn to_type(&self) -> Type {
+        Type::Pattern(TypePattern::Map(self.clone()))
+    }
+}
+
+/// Emits helper functions used by [`Map`](crate::pattern::map::Map).
+///
+/// Register this once for every `ffi::Map<K, V>` used in your API, [`validate`](crate::inventory::InventoryBuilder::validate)
+/// will tell you about any you missed.
+#[macro_export]
+macro_rules! builtins_map {
+    ($k:ty, $v:ty) => {{
+        use ::interoptopus::lang::FunctionInfo;
+
+        #[$crate::ffi_function(export_unique, on_panic = "abort")]
+        pub fn interoptopus_map_create(
+            keys: *const ::std::ffi::c_void,
+            values: *const ::std::ffi::c_void,
+            len: u64,
+            rval: &mut ::std::mem::MaybeUninit<$crate::pattern::map::Map<$k, $v>>,
+        ) -> i64 {
+            let (keys, values) = if keys.is_null() || values.is_null() {
+                (&[][..], &[][..])
+            } else {
+                unsafe { (::std::slice::from_raw_parts::<$k>(keys.cast(), len as usize), ::std::slice::from_raw_parts::<$v>(values.cast(), len as usize)) }
+            };
+            let map = keys.iter().cloned().zip(values.iter().cloned()).collect();
+            rval.write(map);
+            0
+        }
+
+        #[$crate::ffi_function(export_unique, on_panic = "abort")]
+        pub fn interoptopus_map_destroy(_: $crate::ffi::Map<$k, $v>) -> i64 {
+            0
+        }
+
+        let functions = vec![interoptopus_map_create::function_info(), interoptopus_map_destroy::function_info()];
+        let builtins = $crate::pattern::builtins::Builtins::new(functions);
+        let pattern = $crate::pattern::LibraryPattern::Builtins(builtins);
+        $crate::inventory::Symbol::Pattern(pattern)
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use super::Map;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn lookup_sorted() {
+        let map: Map<u32, &str> = [(3, "c"), (1, "a"), (2, "b"), (1, "x")].into_iter().collect();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.keys(), &[1, 2, 3]);
+        assert_eq!(map.values(), &["x", "b", "c"]);
+        assert_eq!(map.get(&2), Some(&"b"));
+        assert_eq!(map.get(&4), None);
+        assert_eq!(map.clone().into_btree_map(), BTreeMap::from([(1, "x"), (2, "b"), (3, "c")]));
+    }
+}
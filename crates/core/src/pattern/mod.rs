@@ -71,13 +71,17 @@
 use crate::lang::{Composite, Primitive, Type, TypeInfo};
 use crate::pattern::builtins::Builtins;
 use crate::pattern::callback::{AsyncCallback, NamedCallback};
+use crate::pattern::iter::IterType;
+use crate::pattern::map::MapType;
 use crate::pattern::option::OptionType;
 use crate::pattern::result::ResultType;
-use crate::pattern::service::ServiceDefinition;
+use crate::pattern::service::{ForeignInterface, ServiceDefinition, ServiceInterface};
 use crate::pattern::slice::SliceType;
+use crate::pattern::tuple::TupleType;
 use crate::pattern::vec::VecType;
 use std::ffi::c_char;
 
+pub mod alloc;
 #[doc(hidden)]
 pub mod api_entry;
 pub mod api_guard;
@@ -85,13 +89,19 @@ pub mod asynk;
 pub mod builtins;
 pub mod callback;
 pub mod cstr;
+pub mod duration;
+pub mod iter;
+pub mod map;
+pub mod ndslice;
 pub mod option;
 pub mod primitive;
 pub mod result;
 pub mod service;
 pub mod slice;
+pub mod str;
 pub mod string;
 pub mod surrogate;
+pub mod tuple;
 pub mod vec;
 
 /// A pattern on a library level, usually involving both methods and types.
@@ -100,6 +110,8 @@ pub mod vec;
 #[allow(clippy::large_enum_variant)]
 pub enum LibraryPattern {
     Service(ServiceDefinition),
+    Interface(ServiceInterface),
+    Foreign(ForeignInterface),
     Builtins(Builtins),
 }
 
@@ -115,15 +127,30 @@ impl From<ServiceDefinition> for LibraryPattern {
     }
 }
 
+impl From<ServiceInterface> for LibraryPattern {
+    fn from(x: ServiceInterface) -> Self {
+        Self::Interface(x)
+    }
+}
+
+impl From<ForeignInterface> for LibraryPattern {
+    fn from(x: ForeignInterface) -> Self {
+        Self::Foreign(x)
+    }
+}
+
 /// A pattern on a type level.
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[allow(clippy::large_enum_variant)]
 pub enum TypePattern {
     CStrPointer,
     Utf8String(Composite),
+    Utf8Str(Composite),
     APIVersion,
     Slice(SliceType),
     SliceMut(SliceType),
+    NdSlice(SliceType),
+    NdSliceMut(SliceType),
     Option(OptionType),
     Result(ResultType),
     Bool,
@@ -131,6 +158,10 @@ pub enum TypePattern {
     NamedCallback(NamedCallback),
     AsyncCallback(AsyncCallback),
     Vec(VecType),
+    Map(MapType),
+    Iter(IterType),
+    Tuple(TupleType),
+    Duration(Composite),
 }
 
 impl TypePattern {
@@ -144,6 +175,8 @@ impl TypePattern {
             Self::CStrPointer => Type::ReadPointer(Box::new(Type::Pattern(Self::CChar))),
             Self::Slice(x) => Type::Composite(x.composite_type().clone()),
             Self::SliceMut(x) => Type::Composite(x.composite_type().clone()),
+            Self::NdSlice(x) => Type::Composite(x.composite_type().clone()),
+            Self::NdSliceMut(x) => Type::Composite(x.composite_type().clone()),
             Self::Option(x) => x.the_enum().to_type(),
             Self::Result(x) => x.the_enum().to_type(),
             Self::NamedCallback(x) => Type::FnPointer(x.fnpointer().clone()),
@@ -152,7 +185,12 @@ impl TypePattern {
             Self::APIVersion => Type::Primitive(Primitive::U64),
             Self::AsyncCallback(x) => Type::FnPointer(x.fnpointer().clone()),
             Self::Utf8String(x) => Type::Composite(x.clone()),
+            Self::Utf8Str(x) => Type::Composite(x.clone()),
             Self::Vec(x) => Type::Composite(x.composite_type().clone()),
+            Self::Map(x) => Type::Composite(x.composite_type().clone()),
+            Self::Iter(x) => Type::Composite(x.composite_type().clone()),
+            Self::Tuple(x) => Type::Composite(x.composite_type().clone()),
+            Self::Duration(x) => Type::Composite(x.clone()),
         }
     }
 }
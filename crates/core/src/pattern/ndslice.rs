@@ -0,0 +1,472 @@
+//! Multi-dimensional views like `&[T]` and `&mut [T]`, but with a shape and strides.
+//!
+//! An [`NdSlice`] is a pointer to the first element plus up to [`MAX_DIMS`] extents and
+//! strides, both counted in elements. This is the layout image and tensor libraries use,
+//! so a row-major image, a transposed matrix or a cropped region of interest can all be
+//! handed over without copying.
+//!
+//! # Example
+//!
+//! ```
+//! use interoptopus::{ffi_function, ffi};
+//!
+//! #[ffi_function]
+//! pub fn brightness(image: ffi::NdSlice<u8>) -> u64 {
+//!     let [h, w] = [image.shape()[0], image.shape()[1]];
+//!     let mut sum = 0;
+//!     for y in 0..h as usize {
+//!         for x in 0..w as usize {
+//!             sum += u64::from(image[[y, x]]);
+//!         }
+//!     }
+//!     sum
+//! }
+//! ```
+//!
+//! Backends supporting this pattern might generate the equivalent to the following pseudo-code:
+//!
+//! ```csharp
+//! ulong brightness(NdSliceU8 image);      // image[y, x], image.Shape(0), image.ReadOnlySpan, ...
+//! ```
+//!
+//! ```python
+//! brightness(numpy.zeros((480, 640), dtype=numpy.uint8))
+//! ```
+//!
+//! Backends not supporting this pattern, and C FFI, will see the equivalent of the following C code:
+//! ```c
+//! typedef struct NdSliceU8 { const uint8_t* data; uint64_t ndim; uint64_t shape[4]; int64_t strides[4]; } NdSliceU8;
+//!
+//! uint64_t brightness(NdSliceU8 image);
+//! ```
+use crate::Error;
+use crate::lang::util::capitalize_first_letter;
+use crate::lang::{Array, Composite, Docs, Field, Layout, Meta, Primitive, Representation, Type, TypeInfo, Visibility};
+use crate::pattern::TypePattern;
+use crate::pattern::slice::SliceType;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use std::ptr::{null, null_mut};
+
+/// Maximum number of dimensions an [`NdSlice`] can have.
+///
+/// Views received from foreign code claiming more dimensions are treated as empty.
+pub const MAX_DIMS: usize = 4;
+
+/// Returns `ndim` as an index, or `None` if it exceeds [`MAX_DIMS`].
+#[allow(clippy::cast_possible_truncation)]
+fn checked_ndim(ndim: u64) -> Option<usize> {
+    (ndim <= MAX_DIMS as u64).then_some(ndim as usize)
+}
+
+/// Returns the number of elements spanned by `shape`, or `None` if it overflows an `isize`.
+fn checked_len(ndim: u64, shape: &[u64; MAX_DIMS]) -> Option<usize> {
+    let len = shape[..checked_ndim(ndim)?].iter().try_fold(1_u64, |len, &extent| len.checked_mul(extent))?;
+    usize::try_from(isize::try_from(len).ok()?).ok()
+}
+
+/// Computes row-major (C order) strides for `shape`, or fails if it doesn't match `len` elements.
+#[allow(clippy::cast_possible_wrap)]
+fn contiguous_strides(shape: &[usize], len: usize) -> Result<([u64; MAX_DIMS], [i64; MAX_DIMS]), Error> {
+    if shape.is_empty() || shape.len() > MAX_DIMS || shape.iter().product::<usize>() != len {
+        return Err(Error::Shape);
+    }
+
+    let mut extents = [0; MAX_DIMS];
+    let mut strides = [0; MAX_DIMS];
+    let mut stride = 1;
+
+    for (i, &extent) in shape.iter().enumerate().rev() {
+        extents[i] = extent as u64;
+        strides[i] = stride as i64;
+        stride *= extent;
+    }
+
+    Ok((extents, strides))
+}
+
+/// Returns the element offset of `index`, or `None` if it is out of bounds.
+#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+fn offset_of(ndim: u64, shape: &[u64; MAX_DIMS], strides: &[i64; MAX_DIMS], index: &[usize]) -> Option<isize> {
+    if index.len() != checked_ndim(ndim)? {
+        return None;
+    }
+
+    let mut offset = 0;
+    for ((&i, &extent), &stride) in index.iter().zip(shape).zip(strides) {
+        if i as u64 >= extent {
+            return None;
+        }
+        offset += i as i64 * stride;
+    }
+
+    Some(offset as isize)
+}
+
+fn is_contiguous(ndim: u64, shape: &[u64; MAX_DIMS], strides: &[i64; MAX_DIMS]) -> bool {
+    let Some(ndim) = checked_ndim(ndim) else {
+        return false;
+    };
+    let mut expected = 1;
+
+    for i in (0..ndim).rev() {
+        if shape[i] != 1 && strides[i] != expected {
+            return false;
+        }
+        let Some(next) = i64::try_from(shape[i]).ok().and_then(|extent| expected.checked_mul(extent)) else {
+            return false;
+        };
+        expected = next;
+    }
+
+    true
+}
+
+/// A multi-dimensional view into data someone else owns which may not be modified.
+#[repr(C)]
+pub struct NdSlice<'a, T> {
+    data: *const T,
+    ndim: u64,
+    shape: [u64; MAX_DIMS],
+    strides: [i64; MAX_DIMS],
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<T> Copy for NdSlice<'_, T> {}
+
+impl<T> Clone for NdSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Default for NdSlice<'_, T> {
+    fn default() -> Self {
+        Self { data: null(), ndim: 0, shape: [0; MAX_DIMS], strides: [0; MAX_DIMS], _phantom: PhantomData }
+    }
+}
+
+impl<'a, T> NdSlice<'a, T> {
+    /// Creates a row-major view of `slice` with the given `shape`.
+    ///
+    /// # Errors
+    /// Fails with [`Error::Shape`] if `shape` has more than [`MAX_DIMS`] (or no) dimensions,
+    /// or if its elements don't add up to the length of `slice`.
+    pub fn from_slice(slice: &'a [T], shape: &[usize]) -> Result<Self, Error> {
+        let (extents, strides) = contiguous_strides(shape, slice.len())?;
+        Ok(Self { data: slice.as_ptr(), ndim: shape.len() as u64, shape: extents, strides, _phantom: PhantomData })
+    }
+
+    /// Creates a view from raw parts, with `strides` counted in elements.
+    ///
+    /// # Safety
+    /// Every element reachable through `shape` and `strides` must be valid for `'a`.
+    ///
+    /// # Panics
+    /// Panics if `shape` and `strides` differ in length or have more than [`MAX_DIMS`] entries.
+    pub unsafe fn from_raw_parts(data: *const T, shape: &[u64], strides: &[i64]) -> Self {
+        assert!(shape.len() == strides.len() && shape.len() <= MAX_DIMS, "Invalid NdSlice dimensions");
+
+        let mut rval = Self { data, ndim: shape.len() as u64, ..Self::default() };
+        rval.shape[..shape.len()].copy_from_slice(shape);
+        rval.strides[..strides.len()].copy_from_slice(strides);
+        rval
+    }
+
+    /// Returns the extent of each dimension.
+    #[must_use]
+    pub fn shape(&self) -> &[u64] {
+        &self.shape[..checked_ndim(self.ndim).unwrap_or(0)]
+    }
+
+    /// Returns the distance between neighbouring elements of each dimension, counted in elements.
+    #[must_use]
+    pub fn strides(&self) -> &[i64] {
+        &self.strides[..checked_ndim(self.ndim).unwrap_or(0)]
+    }
+
+    /// Returns the total number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        if self.data.is_null() {
+            0
+        } else {
+            checked_len(self.ndim, &self.shape).unwrap_or(0)
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: &[usize]) -> Option<&'a T> {
+        if self.data.is_null() {
+            return None;
+        }
+
+        let offset = offset_of(self.ndim, &self.shape, &self.strides, index)?;
+        unsafe { Some(&*self.data.offset(offset)) }
+    }
+
+    /// Returns all elements as a regular slice if they are laid out row-major without gaps.
+    #[must_use]
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        if self.data.is_null() || !is_contiguous(self.ndim, &self.shape, &self.strides) {
+            return None;
+        }
+
+        let len = checked_len(self.ndim, &self.shape)?;
+        unsafe { Some(std::slice::from_raw_parts(self.data, len)) }
+    }
+}
+
+impl<T, const N: usize> Index<[usize; N]> for NdSlice<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        self.get(&index).expect("NdSlice index out of bounds")
+    }
+}
+
+unsafe impl<T> TypeInfo for NdSlice<'_, T>
+where
+    T: TypeInfo,
+{
+    fn type_info() -> Type {
+        let slice =
+            nd_slice_type::<T>("NdSlice", Type::ReadPointer(Box::new(T::type_info())), "A multi-dimensional view into data someone else owns which may not be modified.");
+        Type::Pattern(TypePattern::NdSlice(slice))
+    }
+}
+
+/// A multi-dimensional view into data someone else owns which may be modified.
+#[repr(C)]
+pub struct NdSliceMut<'a, T> {
+    data: *mut T,
+    ndim: u64,
+    shape: [u64; MAX_DIMS],
+    strides: [i64; MAX_DIMS],
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<T> Default for NdSliceMut<'_, T> {
+    fn default() -> Self {
+        Self { data: null_mut(), ndim: 0, shape: [0; MAX_DIMS], strides: [0; MAX_DIMS], _phantom: PhantomData }
+    }
+}
+
+impl<'a, T> NdSliceMut<'a, T> {
+    /// Creates a row-major view of `slice` with the given `shape`.
+    ///
+    /// # Errors
+    /// Fails with [`Error::Shape`] if `shape` has more than [`MAX_DIMS`] (or no) dimensions,
+    /// or if its elements don't add up to the length of `slice`.
+    pub fn from_slice(slice: &'a mut [T], shape: &[usize]) -> Result<Self, Error> {
+        let (extents, strides) = contiguous_strides(shape, slice.len())?;
+        Ok(Self { data: slice.as_mut_ptr(), ndim: shape.len() as u64, shape: extents, strides, _phantom: PhantomData })
+    }
+
+    /// Creates a view from raw parts, with `strides` counted in elements.
+    ///
+    /// # Safety
+    /// Every element reachable through `shape` and `strides` must be valid and unaliased for `'a`.
+    ///
+    /// # Panics
+    /// Panics if `shape` and `strides` differ in length or have more than [`MAX_DIMS`] entries.
+    pub unsafe fn from_raw_parts(data: *mut T, shape: &[u64], strides: &[i64]) -> Self {
+        assert!(shape.len() == strides.len() && shape.len() <= MAX_DIMS, "Invalid NdSlice dimensions");
+
+        let mut rval = Self { data, ndim: shape.len() as u64, ..Self::default() };
+        rval.shape[..shape.len()].copy_from_slice(shape);
+        rval.strides[..strides.len()].copy_from_slice(strides);
+        rval
+    }
+
+    /// Returns the extent of each dimension.
+    #[must_use]
+    pub fn shape(&self) -> &[u64] {
+        &self.shape[..checked_ndim(self.ndim).unwrap_or(0)]
+    }
+
+    /// Returns the distance between neighbouring elements of each dimension, counted in elements.
+    #[must_use]
+    pub fn strides(&self) -> &[i64] {
+        &self.strides[..checked_ndim(self.ndim).unwrap_or(0)]
+    }
+
+    /// Returns the total number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        if self.data.is_null() {
+            0
+        } else {
+            checked_len(self.ndim, &self.shape).unwrap_or(0)
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: &[usize]) -> Option<&T> {
+        if self.data.is_null() {
+            return None;
+        }
+
+        let offset = offset_of(self.ndim, &self.shape, &self.strides, index)?;
+        unsafe { Some(&*self.data.offset(offset)) }
+    }
+
+    /// Returns the element at `index` mutably, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, index: &[usize]) -> Option<&mut T> {
+        if self.data.is_null() {
+            return None;
+        }
+
+        let offset = offset_of(self.ndim, &self.shape, &self.strides, index)?;
+        unsafe { Some(&mut *self.data.offset(offset)) }
+    }
+
+    /// Returns all elements as a regular slice if they are laid out row-major without gaps.
+    #[must_use]
+    pub fn as_slice(&self) -> Option<&[T]> {
+        if self.data.is_null() || !is_contiguous(self.ndim, &self.shape, &self.strides) {
+            return None;
+        }
+
+        let len = checked_len(self.ndim, &self.shape)?;
+        unsafe { Some(std::slice::from_raw_parts(self.data, len)) }
+    }
+
+    /// Returns all elements as a regular mutable slice if they are laid out row-major without gaps.
+    #[must_use]
+    pub fn as_slice_mut(&mut self) -> Option<&mut [T]> {
+        if self.data.is_null() || !is_contiguous(self.ndim, &self.shape, &self.strides) {
+            return None;
+        }
+
+        let len = checked_len(self.ndim, &self.shape)?;
+        unsafe { Some(std::slice::from_raw_parts_mut(self.data, len)) }
+    }
+}
+
+impl<T, const N: usize> Index<[usize; N]> for NdSliceMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        self.get(&index).expect("NdSlice index out of bounds")
+    }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for NdSliceMut<'_, T> {
+    fn index_mut(&mut self, index: [usize; N]) -> &mut Self::Output {
+        self.get_mut(&index).expect("NdSlice index out of bounds")
+    }
+}
+
+unsafe impl<T> TypeInfo for NdSliceMut<'_, T>
+where
+    T: TypeInfo,
+{
+    fn type_info() -> Type {
+        let slice = nd_slice_type::<T>(
+            "NdSliceMut",
+            Type::ReadWritePointer(Box::new(T::type_info())),
+            "A multi-dimensional view into data someone else owns which may be modified.",
+        );
+        Type::Pattern(TypePattern::NdSliceMut(slice))
+    }
+}
+
+#[rustfmt::skip]
+fn nd_slice_type<T: TypeInfo>(prefix: &str, data: Type, doc: &str) -> SliceType {
+    let doc_data = Docs::from_line("Pointer to the first element.");
+    let doc_ndim = Docs::from_line("Number of dimensions in use.");
+    let doc_shape = Docs::from_line("Extent of each dimension.");
+    let doc_strides = Docs::from_line("Distance between neighbouring elements of each dimension, in elements.");
+
+    let fields = vec![
+        Field::with_docs("data".to_string(), data, Visibility::Private, doc_data),
+        Field::with_docs("ndim".to_string(), Type::Primitive(Primitive::U64), Visibility::Private, doc_ndim),
+        Field::with_docs("shape".to_string(), Type::Array(Array::new(Type::Primitive(Primitive::U64), MAX_DIMS)), Visibility::Private, doc_shape),
+        Field::with_docs("strides".to_string(), Type::Array(Array::new(Type::Primitive(Primitive::I64), MAX_DIMS)), Visibility::Private, doc_strides),
+    ];
+
+    let repr = Representation::new(Layout::C, None);
+    let meta = Meta::with_module_docs(T::type_info().namespace().map_or_else(String::new, std::convert::Into::into), Docs::from_line(doc));
+    let name = capitalize_first_letter(T::type_info().name_within_lib().as_str());
+    let composite = Composite::with_meta_repr(format!("{prefix}{name}"), fields, meta, repr);
+    SliceType::new(composite, T::type_info())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NdSlice, NdSliceMut};
+
+    #[test]
+    fn row_major() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let slice = NdSlice::from_slice(&data, &[2, 3]).unwrap();
+
+        assert_eq!(slice.shape(), &[2, 3]);
+        assert_eq!(slice.strides(), &[3, 1]);
+        assert_eq!(slice.len(), 6);
+        assert_eq!(slice[[1, 0]], 4);
+        assert_eq!(slice.get(&[0, 3]), None);
+        assert_eq!(slice.get(&[0]), None);
+        assert_eq!(slice.as_slice(), Some(&data[..]));
+        assert!(NdSlice::from_slice(&data, &[4, 2]).is_err());
+    }
+
+    #[test]
+    fn strided() {
+        let data = [1, 2, 3, 4, 5, 6];
+        // The transpose of the 2x3 matrix above.
+        let slice = unsafe { NdSlice::from_raw_parts(data.as_ptr(), &[3, 2], &[1, 3]) };
+
+        assert_eq!(slice[[0, 1]], 4);
+        assert_eq!(slice[[2, 0]], 3);
+        assert_eq!(slice.as_slice(), None);
+        assert!(NdSlice::<u8>::default().is_empty());
+    }
+
+    #[test]
+    fn mutable() {
+        let mut data = [0; 4];
+        let mut slice = NdSliceMut::from_slice(&mut data, &[2, 2]).unwrap();
+
+        slice[[1, 1]] = 5;
+        *slice.get_mut(&[0, 1]).unwrap() = 3;
+        assert_eq!(slice.as_slice_mut().unwrap(), &[0, 3, 0, 5]);
+    }
+
+    #[test]
+    fn too_many_dims() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let mut slice = NdSlice::from_slice(&data, &[2, 3]).unwrap();
+        slice.ndim = 5;
+
+        assert!(slice.shape().is_empty());
+        assert!(slice.strides().is_empty());
+        assert!(slice.is_empty());
+        assert_eq!(slice.get(&[0, 0, 0, 0, 0]), None);
+        assert_eq!(slice.as_slice(), None);
+    }
+
+    #[test]
+    fn overflowing_shape() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let mut slice = NdSlice::from_slice(&data, &[2, 3]).unwrap();
+        slice.shape[0] = u64::MAX;
+
+        assert!(slice.is_empty());
+        assert_eq!(slice.as_slice(), None);
+    }
+}
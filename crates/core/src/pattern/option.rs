@@ -23,7 +23,7 @@ use crate::pattern::TypePattern;
 use serde::{Deserialize, Serialize};
 
 /// An option-like type at the FFI boundary where a regular [`Option`] doesn't work.
-#[repr(u32)]
+#[repr(C, u32)]
 #[cfg_attr(feature = "serde", derive(Debug, Copy, Clone, PartialEq, Eq, Default, Deserialize, Serialize))]
 #[cfg_attr(not(feature = "serde"), derive(Debug, Copy, Clone, PartialEq, Eq, Default))]
 pub enum Option<T> {
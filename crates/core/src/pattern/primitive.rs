@@ -1,10 +1,11 @@
-//! Additional support for primitives like `bool`.
+//! Additional support for primitives like `bool` or `char`.
 
-use crate::lang::Type;
 use crate::lang::TypeInfo;
+use crate::lang::{Primitive, Type};
 use crate::pattern::TypePattern;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::char::CharTryFromError;
 use std::ops::Not;
 use std::os::raw::c_char;
 
@@ -94,10 +95,69 @@ impl From<CChar> for c_char {
     }
 }
 
+/// A `u32` holding a Unicode scalar value, which is how a `char` crosses the FFI boundary.
+///
+/// Other sides can send any `u32`, so unlike a `char` this may contain surrogates or values above
+/// [`char::MAX`]. [`Char::checked_into_char`] will then return `None`, but no undefined behavior
+/// will be triggered. `#[ffi_function]` lowers `char` parameters and return values to this type
+/// automatically, `#[ffi_type]` fields must use it explicitly.
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Debug, Copy, Clone, PartialEq, Eq, Default, Deserialize, Serialize))]
+#[cfg_attr(not(feature = "serde"), derive(Debug, Copy, Clone, PartialEq, Eq, Default))]
+pub struct Char {
+    value: u32,
+}
+
+impl Char {
+    /// Converts this into a `char`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is no Unicode scalar value, which can only happen if the other side sent garbage,
+    /// see [`checked_into_char`](Self::checked_into_char).
+    #[must_use]
+    pub fn into_char(self) -> char {
+        self.checked_into_char().unwrap_or_else(|| panic!("`{:#x}` is not a valid `char`.", self.value))
+    }
+
+    /// Like [`into_char`](Self::into_char), but returns `None` if this is no Unicode scalar value.
+    #[must_use]
+    pub const fn checked_into_char(self) -> Option<char> {
+        char::from_u32(self.value)
+    }
+
+    /// The raw value, which may or may not be a Unicode scalar value.
+    #[must_use]
+    pub const fn to_u32(self) -> u32 {
+        self.value
+    }
+}
+
+unsafe impl TypeInfo for Char {
+    fn type_info() -> Type {
+        Type::Primitive(Primitive::Char)
+    }
+}
+
+impl From<char> for Char {
+    fn from(x: char) -> Self {
+        Self { value: x.into() }
+    }
+}
+
+impl TryFrom<Char> for char {
+    type Error = CharTryFromError;
+
+    fn try_from(x: Char) -> Result<Self, Self::Error> {
+        Self::try_from(x.value)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::pattern::primitive::Bool;
     use crate::pattern::primitive::CChar;
+    use crate::pattern::primitive::Char;
     use std::os::raw::c_char;
 
     #[test]
@@ -111,4 +171,11 @@ mod test {
         assert!(c_char::from(CChar::MAX) == c_char::MAX);
         assert!(CChar::from(c_char::MAX) == CChar::MAX);
     }
+
+    #[test]
+    fn char_works() {
+        assert_eq!(Char::from('🐙').into_char(), '🐙');
+        assert_eq!(Char { value: 0xD800 }.checked_into_char(), None);
+        assert!(char::try_from(Char { value: 0x11_0000 }).is_err());
+    }
 }
@@ -27,14 +27,69 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Standard Results
+//!
+//! Functions and service methods may also return a [`std::result::Result`], so their bodies can
+//! use `?`. The generated FFI shim then returns a [`Result`] instead, mapping the error via
+//! [`From`] into the type given as `error = ...`, or keeping it if none was given:
+//!
+//! ```
+//! # use interoptopus::{ffi_function, ffi_type};
+//! #[ffi_type]
+//! pub enum MyError { NotFound }
+//!
+//! pub struct LookupError;
+//!
+//! impl From<LookupError> for MyError {
+//!     fn from(_: LookupError) -> Self { Self::NotFound }
+//! }
+//!
+//! fn lookup(x: u32) -> std::result::Result<u32, LookupError> {
+//!     if x > 0 { Ok(x) } else { Err(LookupError) }
+//! }
+//!
+//! // Exported as `extern "C" fn f(x: u32) -> ffi::Result<u32, MyError>`.
+//! #[ffi_function(error = MyError)]
+//! pub fn f(x: u32) -> std::result::Result<u32, LookupError> {
+//!     Ok(lookup(x)? * 2)
+//! }
+//! ```
+//!
+//! The return type must be spelled `std::result::Result` (or `core::result::Result`) to be detected,
+//! unless an `error` is specified, in which case aliases such as `anyhow::Result<T>` work as well.
+//!
+//! # Last Error
+//!
+//! Panics caught by generated service code, as well as `Err` values returned from services
+//! (if the error type implements [`Debug`]), are recorded per thread. The details can then be
+//! retrieved via [`take_last_error`], or from foreign code via the `interoptopus_last_error`
+//! function emitted by [`builtins_string!`](crate::builtins_string). Backends supporting this
+//! include these details in the exceptions they throw.
+//!
+//! Functions and methods using `on_panic = "callback"` additionally invoke the callback
+//! registered via [`set_panic_callback`] with the recorded panic, before returning
+//! [`Default::default()`].
 
 use crate::lang::util::capitalize_first_letter;
 use crate::lang::{Docs, Enum, Layout, Meta, Primitive, Representation, Type, Variant};
 use crate::lang::{TypeInfo, VariantKind};
 use crate::pattern::TypePattern;
+use crate::pattern::service::WrongThread;
 use std::any::Any;
-use std::fmt::Debug;
-use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::RefCell;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+use std::panic::{AssertUnwindSafe, PanicHookInfo};
+use std::sync::{Once, RwLock};
+
+static PANIC_CALLBACK: RwLock<Option<fn(&LastError)>> = RwLock::new(None);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
+    static LAST_PANIC_INFO: RefCell<(Option<String>, Option<String>)> = const { RefCell::new((None, None)) };
+}
 
 /// Extracts a string message from a panic unwind.
 pub fn get_panic_message(pan: &(dyn Any + Send)) -> &str {
@@ -47,7 +102,290 @@ pub fn get_panic_message(pan: &(dyn Any + Send)) -> &str {
     }
 }
 
-#[repr(u32)]
+/// Details about the last error or panic that occurred on the current thread.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LastError {
+    message: String,
+    location: Option<String>,
+    backtrace: Option<String>,
+}
+
+impl LastError {
+    #[must_use]
+    pub const fn new(message: String) -> Self {
+        Self { message, location: None, backtrace: None }
+    }
+
+    /// What happened, e.g., `Panic in (my_function): Oh no`.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Where a panic happened, e.g., `src/lib.rs:12:5`.
+    #[must_use]
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// The backtrace of a panic, if backtraces were enabled via `RUST_BACKTRACE`.
+    #[must_use]
+    pub fn backtrace(&self) -> Option<&str> {
+        self.backtrace.as_deref()
+    }
+}
+
+impl Display for LastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        if let Some(location) = &self.location {
+            write!(f, " (at {location})")?;
+        }
+
+        if let Some(backtrace) = &self.backtrace {
+            write!(f, "\n{backtrace}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets the last error of the current thread.
+pub fn set_last_error(error: LastError) {
+    LAST_ERROR.with_borrow_mut(|x| *x = Some(error));
+}
+
+/// Returns and clears the last error of the current thread.
+#[must_use]
+pub fn take_last_error() -> Option<LastError> {
+    LAST_ERROR.with_borrow_mut(Option::take)
+}
+
+/// Registers the callback invoked by functions and methods using `on_panic = "callback"`.
+pub fn set_panic_callback(callback: fn(&LastError)) {
+    *PANIC_CALLBACK.write().unwrap_or_else(std::sync::PoisonError::into_inner) = Some(callback);
+}
+
+/// Invokes the callback registered via [`set_panic_callback`] with the last error, if any.
+#[doc(hidden)]
+pub fn invoke_panic_callback() {
+    let callback = *PANIC_CALLBACK.read().unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if let Some(callback) = callback {
+        LAST_ERROR.with_borrow(|x| {
+            if let Some(error) = x {
+                callback(error);
+            }
+        });
+    }
+}
+
+/// Installs a panic hook recording panic locations and backtraces for [`take_last_error`].
+///
+/// This is called automatically by generated code before catching panics. Any previously
+/// installed hook is still invoked.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info: &PanicHookInfo<'_>| {
+            let location = info.location().map(ToString::to_string);
+            let backtrace = Backtrace::capture();
+            let backtrace = (backtrace.status() == BacktraceStatus::Captured).then(|| backtrace.to_string());
+            LAST_PANIC_INFO.with_borrow_mut(|x| *x = (location, backtrace));
+            previous(info);
+        }));
+    });
+}
+
+/// Like [`std::panic::catch_unwind`], but makes sure panic details are recorded.
+#[doc(hidden)]
+pub fn catch_unwind<R>(f: impl FnOnce() -> R) -> std::thread::Result<R> {
+    install_panic_hook();
+    std::panic::catch_unwind(AssertUnwindSafe(f))
+}
+
+/// Logs a panic caught in `function` and records it as the last error.
+#[doc(hidden)]
+pub fn record_panic(function: &str, panic: &(dyn Any + Send)) {
+    let message = format!("Panic in ({function}): {}", get_panic_message(panic));
+    let (location, backtrace) = LAST_PANIC_INFO.with_borrow_mut(std::mem::take);
+
+    crate::ffi::log_error(|| &message);
+    set_last_error(LastError { message, location, backtrace });
+}
+
+/// Logs an error returned from `function` and records it as the last error.
+#[doc(hidden)]
+pub fn record_error(function: &str, error: &impl Debug) {
+    let message = format!("Error in ({function}): {error:?}");
+
+    crate::ffi::log_error(|| &message);
+    set_last_error(LastError::new(message));
+}
+
+/// Logs that `function` was passed a null pointer and records it as the last error.
+#[doc(hidden)]
+pub fn record_null(function: &str) {
+    let message = format!("Null pointer in ({function})");
+
+    crate::ffi::log_error(|| &message);
+    set_last_error(LastError::new(message));
+}
+
+/// Logs that a thread-affine service was called from the wrong thread and records it as the last error.
+#[doc(hidden)]
+pub fn record_wrong_thread(function: &str) {
+    let message = format!("Wrong thread in ({function})");
+
+    crate::ffi::log_error(|| &message);
+    set_last_error(LastError::new(message));
+}
+
+/// Records `Err` values for [`take_last_error`] if `E: Debug`, used by generated code.
+///
+/// Must be invoked as `(&ErrorRecorder(name, &rval)).record()` with both [`RecordError`] and
+/// [`RecordErrorFallback`] in scope, so that the former is picked whenever it applies.
+#[doc(hidden)]
+pub struct ErrorRecorder<'a, T>(pub &'a str, pub &'a T);
+
+#[doc(hidden)]
+pub trait RecordError {
+    fn record(&self);
+}
+
+impl<T, E: Debug> RecordError for ErrorRecorder<'_, Result<T, E>> {
+    fn record(&self) {
+        if let Result::Err(e) = self.1 {
+            record_error(self.0, e);
+        }
+    }
+}
+
+impl<T, E: Debug> RecordError for ErrorRecorder<'_, std::result::Result<T, E>> {
+    fn record(&self) {
+        if let std::result::Result::Err(e) = self.1 {
+            record_error(self.0, e);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait RecordErrorFallback {
+    fn record(&self);
+}
+
+impl<T> RecordErrorFallback for &ErrorRecorder<'_, T> {
+    fn record(&self) {}
+}
+
+/// Picks the value generated code returns after a panic, or when it was passed null.
+///
+/// Must be invoked as `(&&&Fallback::<T>::new()).panicked()` with [`FallbackResult`], [`FallbackDefault`]
+/// and [`FallbackAbort`] in scope. FFI results become `Panic` or `Null`, other types implementing
+/// [`Default`] their default, and anything else aborts the process. The same holds for `wrong_thread()`
+/// with [`FallbackWrongThread`] in scope, which converts [`WrongThread`] into the result's error.
+#[doc(hidden)]
+pub struct Fallback<T>(PhantomData<T>);
+
+impl<T> Fallback<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for Fallback<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[doc(hidden)]
+pub trait FallbackResult {
+    type Value;
+    fn panicked(&self) -> Self::Value;
+    fn null(&self) -> Self::Value;
+}
+
+impl<T, E> FallbackResult for &&Fallback<Result<T, E>> {
+    type Value = Result<T, E>;
+
+    fn panicked(&self) -> Self::Value {
+        Result::Panic
+    }
+
+    fn null(&self) -> Self::Value {
+        Result::Null
+    }
+}
+
+#[doc(hidden)]
+pub trait FallbackDefault {
+    type Value;
+    fn panicked(&self) -> Self::Value;
+    fn null(&self) -> Self::Value;
+    fn wrong_thread(&self) -> Self::Value;
+}
+
+impl<T: Default> FallbackDefault for &Fallback<T> {
+    type Value = T;
+
+    fn panicked(&self) -> Self::Value {
+        T::default()
+    }
+
+    fn null(&self) -> Self::Value {
+        T::default()
+    }
+
+    fn wrong_thread(&self) -> Self::Value {
+        T::default()
+    }
+}
+
+#[doc(hidden)]
+pub trait FallbackAbort {
+    type Value;
+    fn panicked(&self) -> Self::Value;
+    fn null(&self) -> Self::Value;
+    fn wrong_thread(&self) -> Self::Value;
+}
+
+impl<T> FallbackAbort for Fallback<T> {
+    type Value = T;
+
+    fn panicked(&self) -> Self::Value {
+        std::process::abort()
+    }
+
+    fn null(&self) -> Self::Value {
+        std::process::abort()
+    }
+
+    fn wrong_thread(&self) -> Self::Value {
+        std::process::abort()
+    }
+}
+
+/// Like [`FallbackResult`], for results whose error converts from [`WrongThread`].
+#[doc(hidden)]
+pub trait FallbackWrongThread {
+    type Value;
+    fn wrong_thread(&self) -> Self::Value;
+}
+
+impl<T, E: From<WrongThread>> FallbackWrongThread for &&Fallback<Result<T, E>> {
+    type Value = Result<T, E>;
+
+    fn wrong_thread(&self) -> Self::Value {
+        Result::Err(WrongThread.into())
+    }
+}
+
+#[repr(C, u32)]
 #[derive(Debug)]
 pub enum Result<T, E> {
     Ok(T),
@@ -163,9 +501,38 @@ pub async fn result_to_ffi_async<T: TypeInfo, E: TypeInfo>(f: impl std::ops::Asy
     f().await.into()
 }
 
-/// Converts a panic to a [`Result::Panic`].
+/// Names the `Ok` and `Err` types of a [`std::result::Result`], used by generated code.
+#[doc(hidden)]
+pub trait StdResult {
+    type Ok;
+    type Err;
+}
+
+impl<T, E> StdResult for std::result::Result<T, E> {
+    type Ok = T;
+    type Err = E;
+}
+
+/// Converts a [`std::result::Result`] into a [`Result`], mapping the error via [`From`], used by generated code.
+#[doc(hidden)]
+pub fn std_result_to_ffi<T, E, F: From<E>>(x: std::result::Result<T, E>) -> Result<T, F> {
+    match x {
+        std::result::Result::Ok(t) => Result::Ok(t),
+        std::result::Result::Err(e) => Result::Err(F::from(e)),
+    }
+}
+
+/// Converts a panic to a [`Result::Panic`], recording it as the [last error](take_last_error).
+///
+/// Since there is no function name to report here, the error names the source location this
+/// was called from instead.
+#[track_caller]
 pub fn panic_to_result<T: TypeInfo, E: TypeInfo>(f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
-    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| Result::Panic)
+    let caller = std::panic::Location::caller();
+    catch_unwind(f).unwrap_or_else(|e| {
+        record_panic(&caller.to_string(), e.as_ref());
+        Result::Panic
+    })
 }
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -217,3 +584,46 @@ pub trait ResultAsPtr {
 pub trait ResultAsUnitT {
     type AsUnitT;
 }
+
+#[cfg(test)]
+mod test {
+    use crate::pattern::result::{
+        ErrorRecorder, RecordError as _, RecordErrorFallback as _, Result, invoke_panic_callback, panic_to_result, set_panic_callback, take_last_error,
+    };
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    #[allow(unreachable_code)]
+    fn panics_are_recorded() {
+        let rval = panic_to_result(|| -> Result<u32, u32> { panic!("Oh no") });
+        let error = take_last_error().unwrap();
+
+        assert!(matches!(rval, Result::Panic));
+        assert!(error.message().starts_with(&format!("Panic in ({}:", file!())));
+        assert!(error.message().ends_with("Oh no"));
+        assert!(error.location().unwrap().contains("result.rs"));
+        assert!(take_last_error().is_none());
+    }
+
+    #[test]
+    fn panic_callback_is_invoked() {
+        static CALLED: AtomicBool = AtomicBool::new(false);
+
+        set_panic_callback(|e| CALLED.store(e.message().ends_with("Oh no"), Ordering::Relaxed));
+        let _ = panic_to_result(|| -> Result<u32, u32> { panic!("Oh no") });
+        invoke_panic_callback();
+
+        assert!(CALLED.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    #[allow(clippy::needless_borrow)] // The borrow selects the `RecordError` impl.
+    fn errors_are_recorded() {
+        let rval = Result::<u32, u32>::Err(123);
+        (&ErrorRecorder("f", &rval)).record();
+        assert_eq!(take_last_error().unwrap().message(), "Error in (f): 123");
+
+        (&ErrorRecorder("f", &123_u32)).record();
+        assert!(take_last_error().is_none());
+    }
+}
@@ -85,12 +85,130 @@
 //! uint32_t simple_service_just_return_value(simpleservice* context);
 //! ```
 //!
+//! # Properties
+//!
+//! Methods can be marked as property accessors via `#[ffi_service_method(getter = "Value")]`
+//! and `#[ffi_service_method(setter = "Value")]`. Backends supporting properties will then emit
+//! a `Value` property (e.g., `{ get; set; }` in C#, `@property` in Python) in addition to the
+//! regular methods. Getters must only take `&self`, setters `&self` or `&mut self` and one value.
+//!
+//! # Constructors and Static Methods
+//!
+//! By default any method without a `self` receiver is a constructor and must return
+//! `ffi::Result<Self, _>`. Other associated functions, e.g., a `fn default_config() -> Config`,
+//! must be marked `#[ffi_service_method(static)]`; backends then emit them as static methods on
+//! the service class. Constructors can also be marked explicitly with `#[ffi_service_ctor]`.
+//!
+//! # Thread Safety
+//!
+//! Nothing stops foreign code from calling a service from multiple threads at once. Services
+//! that are not safe to be used that way can opt into checks via `#[ffi_service(sync = "...")]`:
+//!
+//! - `"none"` - No checks (default), callers must synchronize themselves.
+//! - `"mutex"` - Every call takes a per-instance lock; re-entrant calls (e.g., from a callback
+//!   invoked by the same service) will deadlock.
+//! - `"thread_affine"` - Calls from any thread other than the creating one fail;
+//!   methods returning an `ffi::Result` return an `Err` converted from [`WrongThread`], so
+//!   the service's error type must implement `From<WrongThread>`. Methods using
+//!   `return_default` return their default value.
+//!
+//! Synchronization is not supported for services with `async` methods.
+//!
+//! # Service Interfaces
+//!
+//! Multiple services can share a common contract by implementing a trait annotated with
+//! [`#[ffi_service_trait]`](crate::ffi_service_trait). Each implementing service lists the trait
+//! via `#[ffi_service(implements(...))]`, and functions can then accept a `Dyn...` handle
+//! to work with any of them:
+//!
+//! ```
+//! # use std::fmt::{Display, Formatter};
+//! #
+//! # impl Display for Error {
+//! #     fn fmt(&self, _: &mut Formatter<'_>) -> std::fmt::Result {
+//! #         Ok(())
+//! #     }
+//! # }
+//! #
+//! # impl std::error::Error for Error {}
+//! #
+//! # #[ffi_type]
+//! # #[derive(PartialOrd, PartialEq, Debug, Copy, Clone)]
+//! # pub enum Error {
+//! #     Bad = 3,
+//! # }
+//! #
+//! use interoptopus::{ffi, ffi_function, ffi_type, ffi_service, ffi_service_trait};
+//!
+//! #[ffi_service_trait]
+//! pub trait Storage {
+//!     fn get(&self, key: u32) -> u32;
+//! }
+//!
+//! #[ffi_type(opaque)]
+//! pub struct MemoryStorage {
+//!     value: u32,
+//! }
+//!
+//! #[ffi_service(implements(Storage))]
+//! impl MemoryStorage {
+//!     pub fn new() -> ffi::Result<Self, Error> {
+//!         ffi::Ok(Self { value: 0 })
+//!     }
+//! }
+//!
+//! impl Storage for MemoryStorage {
+//!     fn get(&self, key: u32) -> u32 {
+//!         self.value + key
+//!     }
+//! }
+//!
+//! #[ffi_function]
+//! pub fn read_storage(storage: DynStorage) -> u32 {
+//!     storage.get(0)
+//! }
+//! ```
+//!
+//! Backends supporting this pattern emit the trait as a language-level interface (e.g., an
+//! `IStorage` in C#) and let all implementing service classes be passed where a `DynStorage`
+//! is expected. Handles can't be copied, as copies would alias services during `&mut self`
+//! methods, and invoking methods on a null handle from Rust panics.
+//!
+//! # Foreign Implementations
+//!
+//! The reverse, a trait implemented in C# or Python and called from Rust, is declared with
+//! [`#[ffi_foreign_trait]`](crate::ffi_foreign_trait). This generates a `#[repr(C)]` vtable
+//! holding one callback per method plus a `user_data` pointer, and implements the trait on
+//! that vtable:
+//!
+//! ```
+//! use interoptopus::{ffi_foreign_trait, ffi_function};
+//!
+//! #[ffi_foreign_trait]
+//! pub trait LogSink {
+//!     fn log(&self, level: u32);
+//! }
+//!
+//! #[ffi_function]
+//! pub fn log_something(sink: LogSinkVTable) {
+//!     sink.log(123);
+//! }
+//! ```
+//!
+//! Backends supporting this pattern emit a base class (e.g., `LogSinkBase` in C#) users can
+//! subclass, and which produces a matching vtable forwarding to the subclass' methods.
+//!
 
 use crate::lang::util::longest_common_prefix;
-use crate::lang::{Function, Opaque};
+use crate::lang::{Composite, Field, Function, Meta, Opaque, Type};
+use crate::pattern::TypePattern;
+use crate::pattern::callback::NamedCallback;
 use crate::pattern::result::ResultAsPtr;
+use std::cell::UnsafeCell;
 use std::fmt::Debug;
 use std::slice::from_ref;
+use std::sync::{Mutex, PoisonError};
+use std::thread::ThreadId;
 
 /// Combines a receiver, constructor, destructor and multiple methods in one entity.
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -99,6 +217,10 @@ pub struct ServiceDefinition {
     constructors: Vec<Function>,
     destructor: Function,
     methods: Vec<Function>,
+    static_methods: Vec<Function>,
+    interfaces: Vec<ServiceInterfaceImpl>,
+    properties: Vec<ServiceProperty>,
+    sync: ServiceSync,
 }
 
 impl ServiceDefinition {
@@ -120,7 +242,59 @@ impl ServiceDefinition {
             .as_opaque_type()
             .expect("Service return type must target an opaque type.");
 
-        Self { the_type: (*the_type).clone(), constructors, destructor, methods }
+        Self {
+            the_type: (*the_type).clone(),
+            constructors,
+            destructor,
+            methods,
+            static_methods: Vec::new(),
+            interfaces: Vec::new(),
+            properties: Vec::new(),
+            sync: ServiceSync::None,
+        }
+    }
+
+    /// Adds static methods to this service, i.e., methods not taking a service context.
+    #[must_use]
+    pub fn with_static_methods(mut self, static_methods: Vec<Function>) -> Self {
+        self.static_methods.extend(static_methods);
+        self
+    }
+
+    /// Adds the interfaces this service implements.
+    #[must_use]
+    pub fn with_interfaces(mut self, interfaces: Vec<ServiceInterfaceImpl>) -> Self {
+        self.interfaces.extend(interfaces);
+        self
+    }
+
+    /// Sets how calls from multiple threads are synchronized.
+    #[must_use]
+    pub const fn with_sync(mut self, sync: ServiceSync) -> Self {
+        self.sync = sync;
+        self
+    }
+
+    /// Adds properties to this service, merging getters and setters of the same name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a property ends up with more than one getter or setter.
+    #[must_use]
+    pub fn with_properties(mut self, properties: Vec<ServiceProperty>) -> Self {
+        for property in properties {
+            match self.properties.iter_mut().find(|x| x.name == property.name) {
+                Some(existing) => {
+                    assert!(existing.getter.is_none() || property.getter.is_none(), "Property `{}` has more than one getter.", property.name);
+                    assert!(existing.setter.is_none() || property.setter.is_none(), "Property `{}` has more than one setter.", property.name);
+                    existing.getter = existing.getter.take().or(property.getter);
+                    existing.setter = existing.setter.take().or(property.setter);
+                }
+                None => self.properties.push(property),
+            }
+        }
+
+        self
     }
 
     /// Checks if the signature of this service is compatible with the `Service` pattern, panic with
@@ -131,7 +305,17 @@ impl ServiceDefinition {
     ///
     /// # Panics
     /// Panics if service constraints are violated.
-    pub fn assert_valid(&self) {}
+    pub fn assert_valid(&self) {
+        for property in &self.properties {
+            if let Some(getter) = &property.getter {
+                assert_eq!(getter.signature().params().len(), 1, "Getter for property `{}` must only take `&self`.", property.name);
+            }
+
+            if let Some(setter) = &property.setter {
+                assert_eq!(setter.signature().params().len(), 2, "Setter for property `{}` must take `&self` and one value.", property.name);
+            }
+        }
+    }
 
     #[must_use]
     pub const fn the_type(&self) -> &Opaque {
@@ -153,16 +337,275 @@ impl ServiceDefinition {
         &self.methods
     }
 
+    /// Returns methods associated with this service that don't take a service context.
+    #[must_use]
+    pub fn static_methods(&self) -> &[Function] {
+        &self.static_methods
+    }
+
+    /// Returns the interfaces this service implements.
+    #[must_use]
+    pub fn interfaces(&self) -> &[ServiceInterfaceImpl] {
+        &self.interfaces
+    }
+
+    /// Returns the properties of this service, each backed by one or two of its [`methods`](Self::methods).
+    #[must_use]
+    pub fn properties(&self) -> &[ServiceProperty] {
+        &self.properties
+    }
+
+    /// Returns how calls from multiple threads are synchronized.
+    #[must_use]
+    pub const fn sync(&self) -> ServiceSync {
+        self.sync
+    }
+
     /// Returns the longest common prefix all methods of this service share.
     #[must_use]
     pub fn common_prefix(&self) -> String {
         let mut all_methods = self.methods().to_vec();
+        all_methods.extend_from_slice(self.static_methods());
         all_methods.extend_from_slice(self.constructors());
         all_methods.extend_from_slice(from_ref(&self.destructor));
         longest_common_prefix(all_methods.as_slice())
     }
 }
 
+/// How a service guards against calls from multiple threads, set via `#[ffi_service(sync = "...")]`.
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ServiceSync {
+    /// No synchronization, callers must not use the instance concurrently (default).
+    #[default]
+    None,
+    /// Calls are serialized by a per-instance lock.
+    Mutex,
+    /// Calls are only permitted from the thread that created the instance, others fail with
+    /// an error converted from [`WrongThread`].
+    ThreadAffine,
+}
+
+/// Reported when a [`ThreadAffine`](ServiceSync::ThreadAffine) service is called from another thread.
+///
+/// Error types of such services must implement `From<WrongThread>`, usually by mapping it to a
+/// dedicated variant.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WrongThread;
+
+/// Instance storage of services with a [`ServiceSync`] other than [`None`](ServiceSync::None).
+///
+/// The value is stored first, so a pointer to this type is also a valid pointer to `T` and can
+/// be handed out as the service context.
+#[doc(hidden)]
+#[repr(C)]
+pub struct SyncedService<T> {
+    value: UnsafeCell<T>,
+    lock: Mutex<()>,
+    thread: ThreadId,
+    sync: ServiceSync,
+}
+
+impl<T> SyncedService<T> {
+    pub fn new(value: T, sync: ServiceSync) -> Self {
+        Self { value: UnsafeCell::new(value), lock: Mutex::new(()), thread: std::thread::current().id(), sync }
+    }
+
+    /// Invokes `f` with the service value, or returns `None` if called from the wrong thread.
+    ///
+    /// # Safety
+    ///
+    /// The `service` pointer must have been created from a `Box<SyncedService<T>>` that is still alive.
+    pub unsafe fn with<R>(service: *const T, f: impl FnOnce(*mut T) -> R) -> Option<R> {
+        let this = unsafe { &*service.cast::<Self>() };
+
+        match this.sync {
+            ServiceSync::Mutex => {
+                let _guard = this.lock.lock().unwrap_or_else(PoisonError::into_inner);
+                Some(f(this.value.get()))
+            }
+            ServiceSync::ThreadAffine if this.thread != std::thread::current().id() => None,
+            _ => Some(f(this.value.get())),
+        }
+    }
+
+    /// Returns `true` if the service may be used from the current thread.
+    ///
+    /// # Safety
+    ///
+    /// The `service` pointer must have been created from a `Box<SyncedService<T>>` that is still alive.
+    #[must_use]
+    pub unsafe fn is_accessible(service: *const T) -> bool {
+        let this = unsafe { &*service.cast::<Self>() };
+        this.sync != ServiceSync::ThreadAffine || this.thread == std::thread::current().id()
+    }
+}
+
+/// A service property, e.g., `Value`, backed by a getter and / or a setter method.
+///
+/// Properties are created via `#[ffi_service_method(getter = "Value")]` and `setter = "Value"`
+/// and are purely a convenience for backends; the underlying methods are still exported as usual.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ServiceProperty {
+    name: String,
+    getter: Option<Function>,
+    setter: Option<Function>,
+}
+
+impl ServiceProperty {
+    /// Creates a new property.
+    #[must_use]
+    pub const fn new(name: String, getter: Option<Function>, setter: Option<Function>) -> Self {
+        Self { name, getter, setter }
+    }
+
+    /// The name of this property, e.g., `Value`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The method reading this property, taking only the service context.
+    #[must_use]
+    pub const fn getter(&self) -> Option<&Function> {
+        self.getter.as_ref()
+    }
+
+    /// The method writing this property, taking the service context and the new value.
+    #[must_use]
+    pub const fn setter(&self) -> Option<&Function> {
+        self.setter.as_ref()
+    }
+}
+
+/// A trait shared by multiple services, produced by [`#[ffi_service_trait]`](crate::ffi_service_trait).
+///
+/// On the FFI level an interface is a handle (a context pointer plus a vtable) and one dispatching
+/// function per trait method, each accepting that handle as its first parameter.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ServiceInterface {
+    name: String,
+    prefix: String,
+    handle: Composite,
+    methods: Vec<Function>,
+}
+
+impl ServiceInterface {
+    /// Creates a new interface for the given trait name, handle and dispatching functions.
+    #[must_use]
+    pub const fn new(name: String, prefix: String, handle: Composite, methods: Vec<Function>) -> Self {
+        Self { name, prefix, handle, methods }
+    }
+
+    /// The name of the Rust trait, e.g., `Storage`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The handle type accepted by all dispatching functions, e.g., `DynStorage`.
+    #[must_use]
+    pub const fn handle(&self) -> &Composite {
+        &self.handle
+    }
+
+    /// Returns the interface's meta, which is the meta of its handle.
+    #[must_use]
+    pub const fn meta(&self) -> &Meta {
+        self.handle.meta()
+    }
+
+    /// Returns one dispatching function per trait method.
+    #[must_use]
+    pub fn methods(&self) -> &[Function] {
+        &self.methods
+    }
+
+    /// Returns the prefix all dispatching functions share, e.g., `storage_`.
+    #[must_use]
+    pub fn common_prefix(&self) -> &str {
+        &self.prefix
+    }
+}
+
+/// Records that a service implements a [`ServiceInterface`].
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ServiceInterfaceImpl {
+    interface: ServiceInterface,
+    as_handle: Function,
+}
+
+impl ServiceInterfaceImpl {
+    /// Creates a new record for an interface and the function converting a service into its handle.
+    #[must_use]
+    pub const fn new(interface: ServiceInterface, as_handle: Function) -> Self {
+        Self { interface, as_handle }
+    }
+
+    /// The implemented interface.
+    #[must_use]
+    pub const fn interface(&self) -> &ServiceInterface {
+        &self.interface
+    }
+
+    /// The function converting a service context into the interface handle.
+    #[must_use]
+    pub const fn as_handle(&self) -> &Function {
+        &self.as_handle
+    }
+}
+
+/// A trait implemented in a foreign language, produced by [`#[ffi_foreign_trait]`](crate::ffi_foreign_trait).
+///
+/// On the FFI level this is a single vtable struct with one named callback per trait method and a
+/// trailing `user_data` pointer passed to each of them.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ForeignInterface {
+    name: String,
+    vtable: Composite,
+}
+
+impl ForeignInterface {
+    /// Creates a new foreign interface from the trait name and its vtable.
+    #[must_use]
+    pub const fn new(name: String, vtable: Composite) -> Self {
+        Self { name, vtable }
+    }
+
+    /// The name of the trait, e.g., `LogSink`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The vtable struct foreign code has to fill in.
+    #[must_use]
+    pub const fn vtable(&self) -> &Composite {
+        &self.vtable
+    }
+
+    /// The meta of this interface.
+    #[must_use]
+    pub const fn meta(&self) -> &Meta {
+        self.vtable.meta()
+    }
+
+    /// Returns all vtable fields holding trait methods, along with their callback types.
+    pub fn methods(&self) -> impl Iterator<Item = (&Field, &NamedCallback)> {
+        self.vtable.fields().iter().filter_map(|x| match x.the_type() {
+            Type::Pattern(TypePattern::NamedCallback(c)) => Some((x, c)),
+            _ => None,
+        })
+    }
+}
+
 pub trait ServiceInfo {
     type CtorResult: ResultAsPtr;
 }
+
+/// Implemented on `dyn Trait` for all traits annotated with [`#[ffi_service_trait]`](crate::ffi_service_trait).
+pub trait ServiceInterfaceInfo {
+    /// The FFI-safe handle type, e.g., `DynStorage`.
+    type Handle;
+
+    fn interface_info() -> ServiceInterface;
+}
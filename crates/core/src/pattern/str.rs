@@ -0,0 +1,158 @@
+//! Like a regular [`&str`](prim@str), but FFI safe.
+//!
+//! A [`Str`] is a borrowed UTF-8 `ptr` + `len` pair. Unlike [`CStrPtr`](crate::pattern::cstr::CStrPtr)
+//! it knows its length and does not need a trailing `0x0`, and unlike [`String`](crate::pattern::string::String)
+//! it never owns, allocates or frees anything on either side of the boundary.
+//!
+//! # Example
+//!
+//! ```
+//! use interoptopus::{ffi, ffi_function, ffi_type};
+//!
+//! #[ffi_type]
+//! pub struct Named<'a> {
+//!     pub name: ffi::Str<'a>,
+//! }
+//!
+//! #[ffi_function]
+//! pub fn name_len(name: ffi::Str) -> u32 {
+//!     name.as_str().map_or(0, |x| x.len() as u32)
+//! }
+//! ```
+//!
+//! Backends supporting this pattern might generate the equivalent to the following pseudo-code:
+//!
+//! ```csharp
+//! uint name_len(string name);
+//! uint name_len(ReadOnlySpan<byte> name);
+//! ```
+//!
+//! Backends not supporting this pattern, and C FFI, will see the equivalent of the following C code:
+//! ```c
+//! typedef struct Utf8Str { const uint8_t* ptr; uint64_t len; } Utf8Str;
+//!
+//! uint32_t name_len(Utf8Str name);
+//! ```
+use crate::Error;
+use crate::lang::{Composite, Docs, Field, Layout, Meta, NAMESPACE_COMMON, Primitive, Representation, Type, TypeInfo};
+use crate::pattern::TypePattern;
+use std::marker::PhantomData;
+
+/// FFI analog of a borrowed [`&str`](prim@str).
+///
+/// Since the data might come from a foreign caller, the bytes are only checked for UTF-8 when
+/// accessed via [`Str::as_str`].
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct Str<'a> {
+    ptr: *const u8,
+    len: u64,
+    _phantom: PhantomData<&'a str>,
+}
+
+// Safety: `Str` is a read-only view, just like `&str`.
+unsafe impl Send for Str<'_> {}
+unsafe impl Sync for Str<'_> {}
+
+impl Default for Str<'_> {
+    fn default() -> Self {
+        Self::from_str("")
+    }
+}
+
+impl<'a> Str<'a> {
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn from_str(s: &'a str) -> Self {
+        Self { ptr: s.as_ptr(), len: s.len() as u64, _phantom: PhantomData }
+    }
+
+    /// Returns the raw bytes, which are not guaranteed to be valid UTF-8.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        if self.ptr.is_null() {
+            return &[];
+        }
+
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len as usize) }
+    }
+
+    /// Attempts to return a Rust `str`.
+    ///
+    /// # Errors
+    /// Can fail if the bytes are not valid UTF-8.
+    pub fn as_str(&self) -> Result<&'a str, Error> {
+        Ok(std::str::from_utf8(self.as_bytes())?)
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn len(&self) -> usize {
+        if self.ptr.is_null() { 0 } else { self.len as usize }
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a> From<&'a str> for Str<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::from_str(value)
+    }
+}
+
+unsafe impl TypeInfo for Str<'_> {
+    #[rustfmt::skip]
+    fn type_info() -> Type {
+        let fields = vec![
+            Field::new("ptr".to_string(), Type::ReadPointer(Box::new(Type::Primitive(Primitive::U8)))),
+            Field::new("len".to_string(), Type::Primitive(Primitive::U64)),
+        ];
+
+        let doc = Docs::from_lines(vec![
+            " Borrowed UTF-8 string, `len` bytes starting at `ptr`, not `0x0` terminated.".to_string(),
+            " Neither side owns the data, it must outlive the call (or struct) it is passed to.".to_string(),
+        ]);
+        let repr = Representation::new(Layout::C, None);
+        let meta = Meta::with_module_docs(NAMESPACE_COMMON.to_string(), doc);
+        let composite = Composite::with_meta_repr("Utf8Str".to_string(), fields, meta, repr);
+        Type::Pattern(TypePattern::Utf8Str(composite))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Str;
+
+    #[test]
+    fn borrows() {
+        let s = String::from("hello world");
+        let str = Str::from(s.as_str());
+
+        assert_eq!(str.len(), 11);
+        assert_eq!(str.as_str().unwrap(), "hello world");
+        assert!(Str::default().is_empty());
+        assert_eq!(Str::default().as_str().unwrap(), "");
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let bytes = [0xff, 0xfe];
+        let str = Str { ptr: bytes.as_ptr(), len: 2, _phantom: std::marker::PhantomData };
+
+        assert_eq!(str.as_bytes(), &bytes);
+        assert!(str.as_str().is_err());
+    }
+
+    #[test]
+    fn null_is_empty() {
+        let str = Str { ptr: std::ptr::null(), len: 2, _phantom: std::marker::PhantomData };
+
+        assert_eq!(str.len(), 0);
+        assert!(str.is_empty());
+        assert_eq!(str.as_str().unwrap(), "");
+    }
+}
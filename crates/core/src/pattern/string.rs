@@ -3,6 +3,7 @@
 use crate::lang::{Composite, Docs, Field, Meta, NAMESPACE_COMMON, Primitive, Representation, Type};
 use crate::lang::{Layout, TypeInfo};
 use crate::pattern::TypePattern;
+use crate::pattern::alloc::{vec_drop_raw, vec_from_raw, vec_into_raw};
 use std::mem::forget;
 
 /// FFI analog of [`std::string::String`].
@@ -19,12 +20,9 @@ unsafe impl Sync for String {}
 
 impl String {
     #[must_use]
-    pub fn from_string(mut s: std::string::String) -> Self {
-        let ptr = s.as_mut_ptr();
-        let capacity = s.capacity() as u64;
-        let len = s.len() as u64;
-        forget(s);
-        Self { ptr, len, capacity }
+    pub fn from_string(s: std::string::String) -> Self {
+        let (ptr, len, capacity) = vec_into_raw(s.into_bytes());
+        Self { ptr, len: len as u64, capacity: capacity as u64 }
     }
 
     #[must_use]
@@ -40,9 +38,9 @@ impl String {
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
     pub fn into_string(self) -> std::string::String {
-        let rval = unsafe { std::string::String::from_raw_parts(self.ptr, self.len as usize, self.capacity as usize) };
+        let bytes = unsafe { vec_from_raw(self.ptr, self.len as usize, self.capacity as usize) };
         forget(self);
-        rval
+        unsafe { std::string::String::from_utf8_unchecked(bytes) }
     }
 }
 
@@ -70,9 +68,7 @@ impl Drop for String {
         if self.ptr.is_null() {
             return;
         }
-        unsafe {
-            let _ = std::string::String::from_raw_parts(self.ptr, self.len as usize, self.capacity as usize);
-        }
+        unsafe { vec_drop_raw(self.ptr, self.len as usize, self.capacity as usize) };
     }
 }
 
@@ -106,34 +102,59 @@ macro_rules! builtins_string {
     () => {{
         use ::interoptopus::lang::FunctionInfo;
 
-        #[$crate::ffi_function]
+        /// Creates a string from `len` UTF-8 bytes, returns `0` on success or `1` (and an empty string) if they are not valid UTF-8.
+        #[$crate::ffi_function(on_panic = "abort")]
         pub fn interoptopus_string_create(utf8: *const ::std::ffi::c_void, len: u64, rval: &mut ::std::mem::MaybeUninit<$crate::pattern::string::String>) -> i64 {
             let slice = if utf8.is_null() {
                 &[]
             } else {
                 unsafe { ::std::slice::from_raw_parts::<u8>(utf8.cast(), len as usize) }
             };
-            let vec = slice.to_vec();
-            let string = unsafe { ::std::string::String::from_utf8_unchecked(vec) };
+            let (string, status) = match ::std::str::from_utf8(slice) {
+                Ok(x) => (x.to_string(), 0),
+                Err(_) => (::std::string::String::new(), 1),
+            };
+            rval.write($crate::pattern::string::String::from_string(string));
+            status
+        }
+
+        /// Creates a string from `len` UTF-16 code units, replacing unpaired surrogates with `U+FFFD` like .NET does; always returns `0`.
+        #[$crate::ffi_function(on_panic = "abort")]
+        pub fn interoptopus_string_create_utf16(utf16: *const ::std::ffi::c_void, len: u64, rval: &mut ::std::mem::MaybeUninit<$crate::pattern::string::String>) -> i64 {
+            let slice = if utf16.is_null() {
+                &[]
+            } else {
+                unsafe { ::std::slice::from_raw_parts::<u16>(utf16.cast(), len as usize) }
+            };
+            let string = ::std::string::String::from_utf16_lossy(slice);
             rval.write($crate::pattern::string::String::from_string(string));
             0
         }
 
-        #[$crate::ffi_function]
+        #[$crate::ffi_function(on_panic = "abort")]
         pub fn interoptopus_string_destroy(utf8: $crate::pattern::string::String) -> i64 {
             0
         }
 
-        #[$crate::ffi_function]
+        #[$crate::ffi_function(on_panic = "abort")]
         pub fn interoptopus_string_clone(utf8: &$crate::pattern::string::String, rval: &mut ::std::mem::MaybeUninit<$crate::pattern::string::String>) -> i64 {
             rval.write(utf8.clone());
             0
         }
 
+        /// Returns and clears details about the last error or panic on this thread, or an empty string.
+        #[$crate::ffi_function(on_panic = "abort")]
+        pub fn interoptopus_last_error() -> $crate::pattern::string::String {
+            let error = $crate::pattern::result::take_last_error().map(|x| x.to_string()).unwrap_or_default();
+            $crate::pattern::string::String::from_string(error)
+        }
+
         let items = vec![
             interoptopus_string_create::function_info(),
+            interoptopus_string_create_utf16::function_info(),
             interoptopus_string_destroy::function_info(),
             interoptopus_string_clone::function_info(),
+            interoptopus_last_error::function_info(),
         ];
         let builtins = $crate::pattern::builtins::Builtins::new(items);
         let pattern = $crate::pattern::LibraryPattern::Builtins(builtins);
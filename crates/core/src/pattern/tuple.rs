@@ -0,0 +1,151 @@
+//! FFI-safe tuples with up to six elements.<sup>🚧</sup>
+//!
+//! Rust tuples have no guaranteed layout, so they can't cross an FFI boundary as-is. Instead,
+//! `#[ffi_function]` lowers any non-empty tuple `(A, B, ...)` you use as a parameter or return
+//! value to the matching `#[repr(C)]` [`Tuple2`], [`Tuple3`], ... which other languages see as a
+//! plain struct named after its arity and elements, e.g., `Tuple2_U32_F32`.
+//!
+//! # Example
+//!
+//! ```
+//! use interoptopus::{ffi, ffi_function, ffi_type};
+//!
+//! #[ffi_function]
+//! pub fn min_max(x: (u32, u32)) -> (u32, u32) {
+//!     (x.0.min(x.1), x.0.max(x.1))
+//! }
+//!
+//! #[ffi_type]
+//! pub struct Sample {
+//!     pub range: ffi::Tuple2<f32, f32>,
+//! }
+//!
+//! let sample = Sample { range: (0.0, 1.0).into() };
+//! assert_eq!(sample.range.1, 1.0);
+//! ```
+//!
+//! Tuples used as `#[ffi_type]` fields or within other types, e.g., `ffi::Option<(u32, u32)>`, are
+//! not lowered and must be spelled `ffi::Tuple2<u32, u32>` instead. Supported backends map tuples
+//! to `ValueTuple` in C# and to regular tuples in Python.
+use crate::lang::util::capitalize_first_letter;
+use crate::lang::{Composite, Docs, Field, Layout, Meta, Representation, Type, TypeInfo};
+use crate::pattern::TypePattern;
+
+/// The largest tuple `#[ffi_function]` and `#[ffi_type]` can lower.
+pub const MAX_ARITY: usize = 6;
+
+macro_rules! tuple {
+    ($name:ident, $($t:ident $i:tt),+) => {
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<$($t),+>($(pub $t),+);
+
+        impl<$($t),+> $name<$($t),+> {
+            /// Converts this back into a regular Rust tuple.
+            pub fn into_tuple(self) -> ($($t,)+) {
+                ($(self.$i,)+)
+            }
+        }
+
+        impl<$($t),+> From<($($t,)+)> for $name<$($t),+> {
+            fn from(x: ($($t,)+)) -> Self {
+                Self($(x.$i),+)
+            }
+        }
+
+        impl<$($t),+> From<$name<$($t),+>> for ($($t,)+) {
+            fn from(x: $name<$($t),+>) -> Self {
+                x.into_tuple()
+            }
+        }
+
+        unsafe impl<$($t),+> TypeInfo for $name<$($t),+>
+        where
+            $($t: TypeInfo),+
+        {
+            fn type_info() -> Type {
+                let types = vec![$($t::type_info()),+];
+                let fields = vec![$(Field::new(concat!("x", stringify!($i)).to_string(), $t::type_info())),+];
+                let names = types.iter().map(|x| capitalize_first_letter(x.name_within_lib().as_str())).collect::<Vec<_>>().join("_");
+
+                let doc = Docs::from_line(" A tuple lowered to a struct, fields are named after their position.");
+                let repr = Representation::new(Layout::C, None);
+                let meta = Meta::with_docs(doc);
+                let composite = Composite::with_meta_repr(format!("Tuple{}_{names}", types.len()), fields, meta, repr);
+                Type::Pattern(TypePattern::Tuple(TupleType::new(composite, types)))
+            }
+        }
+    };
+}
+
+tuple!(Tuple2, A 0, B 1);
+tuple!(Tuple3, A 0, B 1, C 2);
+tuple!(Tuple4, A 0, B 1, C 2, D 3);
+tuple!(Tuple5, A 0, B 1, C 2, D 3, E 4);
+tuple!(Tuple6, A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TupleType {
+    composite_type: Composite,
+    types: Vec<Type>,
+}
+
+impl TupleType {
+    #[must_use]
+    pub const fn new(composite_type: Composite, types: Vec<Type>) -> Self {
+        Self { composite_type, types }
+    }
+
+    #[must_use]
+    pub fn rust_name(&self) -> &str {
+        self.composite_type.rust_name()
+    }
+
+    #[must_use]
+    pub const fn composite_type(&self) -> &Composite {
+        &self.composite_type
+    }
+
+    /// The element types, in order.
+    #[must_use]
+    pub fn types(&self) -> &[Type] {
+        &self.types
+    }
+
+    #[must_use]
+    pub const fn meta(&self) -> &Meta {
+        self.composite_type.meta()
+    }
+
+    #[must_use]
+    pub fn to_type(&self) -> Type {
+        Type::Pattern(TypePattern::Tuple(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Tuple2, Tuple3};
+    use crate::lang::TypeInfo;
+
+    #[test]
+    fn named_after_elements() {
+        assert_eq!(Tuple2::<u32, f32>::type_info().name_within_lib(), "Tuple2_U32_F32");
+        assert_eq!(Tuple3::<u8, Tuple2<u8, u8>, i64>::type_info().name_within_lib(), "Tuple3_U8_Tuple2_U8_U8_I64");
+    }
+
+    #[test]
+    fn nested_names_dont_collide() {
+        let a = Tuple3::<u8, Tuple2<u8, u8>, i64>::type_info();
+        let b = Tuple2::<u8, Tuple3<u8, u8, i64>>::type_info();
+        assert_ne!(a.name_within_lib(), b.name_within_lib());
+    }
+
+    #[test]
+    fn converts_from_and_to_tuples() {
+        let x: Tuple3<u8, u16, u32> = (1, 2, 3).into();
+        assert_eq!(x.1, 2);
+        assert_eq!(x.into_tuple(), (1, 2, 3));
+        assert_eq!(<(u8, u16, u32)>::from(x), (1, 2, 3));
+    }
+}
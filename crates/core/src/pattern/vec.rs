@@ -2,6 +2,7 @@
 use crate::lang::util::capitalize_first_letter;
 use crate::lang::{Composite, Docs, Field, Layout, Meta, Primitive, Representation, Type, TypeInfo};
 use crate::pattern::TypePattern;
+use crate::pattern::alloc::{vec_drop_raw, vec_from_raw, vec_into_raw};
 use std::mem::forget;
 
 #[derive(Debug)]
@@ -17,12 +18,9 @@ unsafe impl<T> Sync for Vec<T> where T: Sync {}
 
 impl<T> Vec<T> {
     #[must_use]
-    pub fn from_vec(mut s: std::vec::Vec<T>) -> Self {
-        let ptr = s.as_mut_ptr();
-        let capacity = s.capacity() as u64;
-        let len = s.len() as u64;
-        forget(s);
-        Self { ptr, len, capacity }
+    pub fn from_vec(s: std::vec::Vec<T>) -> Self {
+        let (ptr, len, capacity) = vec_into_raw(s);
+        Self { ptr, len: len as u64, capacity: capacity as u64 }
     }
 
     #[must_use]
@@ -39,19 +37,16 @@ impl<T> Vec<T> {
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
     pub fn into_vec(self) -> std::vec::Vec<T> {
-        let rval = unsafe { std::vec::Vec::from_raw_parts(self.ptr, self.len as usize, self.capacity as usize) };
+        let rval = unsafe { vec_from_raw(self.ptr, self.len as usize, self.capacity as usize) };
         forget(self);
         rval
     }
 }
 
 impl<T: Clone + TypeInfo> Clone for Vec<T> {
-    #[allow(clippy::cast_possible_truncation)]
     fn clone(&self) -> Self {
-        let this = unsafe { std::vec::Vec::from_raw_parts(self.ptr, self.len as usize, self.capacity as usize) };
-        let rval = this.clone();
-        forget(this);
-        rval.into()
+        let this = unsafe { std::slice::from_raw_parts(self.ptr, self.len()) };
+        this.to_vec().into()
     }
 }
 
@@ -73,9 +68,7 @@ impl<T> Drop for Vec<T> {
         if self.ptr.is_null() {
             return;
         }
-        unsafe {
-            let _ = std::vec::Vec::from_raw_parts(self.ptr, self.len as usize, self.capacity as usize);
-        }
+        unsafe { vec_drop_raw(self.ptr, self.len as usize, self.capacity as usize) };
     }
 }
 
@@ -147,12 +140,15 @@ impl VecType {
 }
 
 /// Emits helper functions used by [`Vec`](crate::pattern::vec::Vec).
+///
+/// Register this once for every `ffi::Vec<T>` used in your API, [`validate`](crate::inventory::InventoryBuilder::validate)
+/// will tell you about any you missed.
 #[macro_export]
 macro_rules! builtins_vec {
     ($t:ty) => {{
         use ::interoptopus::lang::FunctionInfo;
 
-        #[$crate::ffi_function(export_unique)]
+        #[$crate::ffi_function(export_unique, on_panic = "abort")]
         pub fn interoptopus_vec_create(data: *const ::std::ffi::c_void, len: u64, rval: &mut ::std::mem::MaybeUninit<$crate::pattern::vec::Vec<$t>>) -> i64 {
             let slice = if data.is_null() {
                 &[]
@@ -164,7 +160,7 @@ macro_rules! builtins_vec {
             0
         }
 
-        #[$crate::ffi_function(export_unique)]
+        #[$crate::ffi_function(export_unique, on_panic = "abort")]
         pub fn interoptopus_vec_destroy(_: $crate::ffi::Vec<$t>) -> i64 {
             0
         }
@@ -1,4 +1,5 @@
 use crate::lang::{Composite, Docs, Field, Meta, Primitive, Type, TypeInfo};
+use crate::pattern::alloc::{vec_drop_raw, vec_into_raw};
 use std::io::{Read, Write};
 use std::marker::PhantomData;
 
@@ -17,16 +18,26 @@ unsafe impl Sync for WireBuffer<'_> {}
 impl<'a> WireBuffer<'a> {
     /// Create a new owned buffer from a Vec
     #[must_use]
-    pub fn from_vec(mut vec: Vec<u8>) -> WireBuffer<'static> {
-        let data = vec.as_mut_ptr();
-        let len = i32::try_from(vec.len()).expect("Too large Wire buffer!");
-        let capacity = i32::try_from(vec.capacity()).expect("Too large Wire buffer!");
-
-        std::mem::forget(vec); // LEAKS the vec here, must use interoptopus_wire_destroy() to free it
+    pub fn from_vec(vec: Vec<u8>) -> WireBuffer<'static> {
+        // LEAKS the vec here, must use interoptopus_wire_destroy() to free it
+        let (data, len, capacity) = vec_into_raw(vec);
+        let len = i32::try_from(len).expect("Too large Wire buffer!");
+        let capacity = i32::try_from(capacity).expect("Too large Wire buffer!");
 
         WireBuffer { data, len, capacity, _phantom: PhantomData }
     }
 
+    /// Frees a buffer previously created by [`WireBuffer::from_vec`].
+    ///
+    /// # Safety
+    /// The parts must come from an owned `WireBuffer` and must not be used afterwards.
+    #[doc(hidden)]
+    pub unsafe fn destroy(data: *mut u8, len: i32, capacity: i32) {
+        let len = usize::try_from(len).expect("Invalid vec length");
+        let capacity = usize::try_from(capacity).expect("Invalid vec capacity");
+        unsafe { vec_drop_raw(data, len, capacity) };
+    }
+
     /// Create a new borrowed buffer from a slice
     #[allow(clippy::use_self, reason = "We want to keep the explicit lifetime")]
     #[must_use]
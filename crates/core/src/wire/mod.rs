@@ -252,16 +252,16 @@ macro_rules! builtins_wire {
     () => {{
         use ::interoptopus::lang::FunctionInfo;
 
-        #[$crate::ffi_function]
+        #[$crate::ffi_function(on_panic = "abort")]
         pub unsafe extern "C" fn interoptopus_wire_destroy(data: *mut u8, len: i32, capacity: i32) {
             if capacity <= 0 {
                 // If the buffer was borrowed or allocated on the opposite FFI side, cannot deallocate it.
                 return;
             }
-            let _ = unsafe { Vec::from_raw_parts(data, usize::try_from(len).expect("Invalid vec length"), usize::try_from(capacity).expect("Invalid vec capacity")) };
+            unsafe { $crate::wire::WireBuffer::destroy(data, len, capacity) };
         }
 
-        // #[$crate::ffi_function]
+        // #[$crate::ffi_function(on_panic = "abort")]
         // pub fn interoptopus_string_destroy(utf8: $crate::pattern::string::String) -> i64 {
         //     0
         // }
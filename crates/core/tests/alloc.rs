@@ -0,0 +1,57 @@
+#![allow(clippy::cast_possible_truncation)]
+
+use interoptopus::ffi;
+use interoptopus::pattern::alloc::{self, Allocator};
+use std::alloc::Layout;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Hooks are process wide, so everything runs in a single test.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static FREED: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn counting_alloc(size: u64, align: u64) -> *mut c_void {
+    ALLOCATED.fetch_add(size as usize, Ordering::SeqCst);
+    let layout = Layout::from_size_align(size as usize, align as usize).unwrap();
+    unsafe { std::alloc::alloc(layout).cast() }
+}
+
+extern "C" fn counting_free(ptr: *mut c_void, size: u64, align: u64) {
+    FREED.fetch_add(size as usize, Ordering::SeqCst);
+    let layout = Layout::from_size_align(size as usize, align as usize).unwrap();
+    unsafe { std::alloc::dealloc(ptr.cast(), layout) }
+}
+
+#[test]
+fn buffers_use_installed_allocator() {
+    alloc::set_allocator(Allocator::new(counting_alloc, counting_free)).unwrap();
+    assert!(alloc::set_allocator(Allocator::new(counting_alloc, counting_free)).is_err());
+
+    let vec = ffi::Vec::from_vec(vec![1u32, 2, 3]);
+    assert_eq!(ALLOCATED.load(Ordering::SeqCst), 12);
+    assert_eq!(vec.clone().into_vec(), vec![1, 2, 3]);
+    drop(vec);
+    assert_eq!(FREED.load(Ordering::SeqCst), 24);
+
+    let string = ffi::String::from_string("hello".to_string());
+    assert_eq!(string.as_str(), "hello");
+    assert_eq!(string.into_string(), "hello");
+    assert_eq!(ALLOCATED.load(Ordering::SeqCst), 29);
+    assert_eq!(FREED.load(Ordering::SeqCst), 29);
+
+    let strings = ffi::Vec::from_vec(vec![ffi::String::from_string("a".to_string())]);
+    drop(strings);
+    assert_eq!(ALLOCATED.load(Ordering::SeqCst), FREED.load(Ordering::SeqCst));
+
+    let ptr = alloc::alloc(64, 16);
+    assert_eq!(ptr as usize % 16, 0);
+    unsafe { alloc::free(ptr, 64, 3) };
+    unsafe { alloc::free(ptr, usize::MAX, 16) };
+    assert_eq!(FREED.load(Ordering::SeqCst) + 64, ALLOCATED.load(Ordering::SeqCst));
+    unsafe { alloc::free(ptr, 64, 16) };
+    assert_eq!(ALLOCATED.load(Ordering::SeqCst), FREED.load(Ordering::SeqCst));
+
+    let empty = ffi::Vec::<u64>::from_vec(Vec::new());
+    assert!(empty.into_vec().is_empty());
+    assert_eq!(ALLOCATED.load(Ordering::SeqCst), FREED.load(Ordering::SeqCst));
+}
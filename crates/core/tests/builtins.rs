@@ -0,0 +1,44 @@
+use interoptopus::inventory::Inventory;
+use interoptopus::{builtins_iter, builtins_vec, ffi, function};
+use interoptopus_proc::ffi_function;
+
+#[ffi_function]
+fn vec_u32() -> ffi::Vec<u32> {
+    vec![1, 2, 3].into()
+}
+
+#[test]
+#[should_panic(expected = "Type `VecU32` is used but its builtins are missing, register them via `.register(builtins_vec!(T))` with `T` being the Rust type of `u32`.")]
+fn panics_on_missing_vec_builtins() {
+    _ = Inventory::builder().register(function!(vec_u32)).validate().build();
+}
+
+#[test]
+fn accepts_registered_vec_builtins() {
+    _ = Inventory::builder().register(builtins_vec!(u32)).register(function!(vec_u32)).validate().build();
+}
+
+#[ffi_function]
+fn map_u8_u32(_: ffi::Map<u8, u32>) {}
+
+#[test]
+#[should_panic(expected = "register them via `.register(builtins_map!(K, V))` with `K` and `V` being the Rust types of `u8` and `u32`.")]
+fn panics_on_missing_map_builtins() {
+    _ = Inventory::builder().register(function!(map_u8_u32)).validate().build();
+}
+
+#[ffi_function]
+fn iter_u16() -> ffi::Iter<u16> {
+    ffi::Iter::new(0..10)
+}
+
+#[test]
+#[should_panic(expected = "register them via `.register(builtins_iter!(T))` with `T` being the Rust type of `u16`.")]
+fn panics_on_missing_iter_builtins() {
+    _ = Inventory::builder().register(function!(iter_u16)).validate().build();
+}
+
+#[test]
+fn accepts_registered_iter_builtins() {
+    _ = Inventory::builder().register(builtins_iter!(u16)).register(function!(iter_u16)).validate().build();
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "catch_panics")]
+
+use interoptopus::{ffi, ffi_function, ffi_type};
+
+#[ffi_type]
+#[derive(Debug)]
+pub enum Error {
+    Fail,
+}
+
+#[ffi_function]
+fn panics_with_result() -> ffi::Result<u32, Error> {
+    panic!("oops")
+}
+
+#[ffi_function]
+fn panics_with_default() -> u32 {
+    panic!("oops")
+}
+
+#[test]
+fn panics_are_caught_without_on_panic() {
+    assert!(matches!(panics_with_result(), ffi::Result::Panic));
+    assert_eq!(panics_with_default(), 0);
+}
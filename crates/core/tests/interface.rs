@@ -0,0 +1,106 @@
+#![allow(clippy::must_use_candidate, clippy::ignored_unit_patterns)]
+
+use interoptopus::pattern::result::take_last_error;
+use interoptopus::pattern::service::WrongThread;
+use interoptopus::{ffi, ffi_service, ffi_service_trait, ffi_type};
+
+#[ffi_type]
+#[derive(Debug)]
+pub enum Error {
+    Fail,
+    WrongThread,
+}
+
+impl From<WrongThread> for Error {
+    fn from(_: WrongThread) -> Self {
+        Self::WrongThread
+    }
+}
+
+#[ffi_service_trait]
+pub trait Counter {
+    fn value(&self) -> u32;
+    fn bump(&mut self) -> ffi::Result<u32, Error>;
+}
+
+struct Panicking;
+
+impl Counter for Panicking {
+    fn value(&self) -> u32 {
+        panic!("value")
+    }
+
+    fn bump(&mut self) -> ffi::Result<u32, Error> {
+        panic!("bump")
+    }
+}
+
+#[ffi_type(opaque)]
+pub struct Affine {
+    value: u32,
+}
+
+#[ffi_service(sync = "thread_affine", implements(Counter))]
+impl Affine {
+    pub fn new() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { value: 0 })
+    }
+}
+
+impl Counter for Affine {
+    fn value(&self) -> u32 {
+        self.value
+    }
+
+    fn bump(&mut self) -> ffi::Result<u32, Error> {
+        self.value += 1;
+        ffi::Ok(self.value)
+    }
+}
+
+#[test]
+fn dispatchers_reject_null_handles() {
+    let handle = || unsafe { DynCounter::from_raw(std::ptr::null::<Panicking>()) };
+
+    assert_eq!(counter_value(handle()), 0);
+    assert!(matches!(counter_bump(handle()), ffi::Result::Null));
+    assert_eq!(take_last_error().unwrap().message(), "Null pointer in (counter_bump)");
+}
+
+#[test]
+#[should_panic(expected = "Called `value` on a null `DynCounter`.")]
+fn null_handles_panic_when_used_directly() {
+    let handle = unsafe { DynCounter::from_raw(std::ptr::null::<Panicking>()) };
+    _ = handle.value();
+}
+
+#[test]
+fn dispatchers_catch_panics() {
+    let service = Panicking;
+    let handle = || unsafe { DynCounter::from_raw(&raw const service) };
+
+    assert_eq!(counter_value(handle()), 0);
+    assert_eq!(take_last_error().unwrap().message(), "Panic in (counter_value): value");
+    assert!(matches!(counter_bump(handle()), ffi::Result::Panic));
+    assert_eq!(take_last_error().unwrap().message(), "Panic in (counter_bump): bump");
+}
+
+#[test]
+fn handles_of_synced_services_check_threads() {
+    let service = affine_new().unwrap();
+
+    assert!(matches!(counter_bump(affine_as_counter(service)), ffi::Result::Ok(1)));
+
+    let service = service as usize;
+    std::thread::spawn(move || {
+        let service = service as *const Affine;
+        assert!(matches!(counter_bump(affine_as_counter(service)), ffi::Result::Err(Error::WrongThread)));
+        assert_eq!(counter_value(affine_as_counter(service)), 0);
+    })
+    .join()
+    .unwrap();
+
+    let service = service as *const Affine;
+    assert_eq!(counter_value(affine_as_counter(service)), 1);
+    assert!(unsafe { affine_destroy(service) }.is_ok());
+}
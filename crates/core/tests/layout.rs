@@ -0,0 +1,77 @@
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi, function};
+use interoptopus_proc::{ffi_function, ffi_type};
+use std::num::NonZeroU32;
+
+#[ffi_type]
+struct Id {
+    value: u128,
+}
+
+#[ffi_function]
+fn next_id(x: Id, _: i128) -> u128 {
+    x.value + 1
+}
+
+#[ffi_function]
+fn next_char(x: char, _: Option<NonZeroU32>, _: ffi::Duration) -> char {
+    x
+}
+
+#[test]
+#[cfg_attr(
+    not(all(any(target_arch = "x86_64", target_arch = "aarch64"), not(target_os = "windows"))),
+    should_panic(expected = "128-bit integers don't have a stable C ABI on this target")
+)]
+fn accepts_128_bit_integers_where_stable() {
+    _ = Inventory::builder().register(function!(next_id)).validate().build();
+}
+
+#[test]
+fn accepts_128_bit_integers_on_stable_targets() {
+    _ = Inventory::builder().register(function!(next_id)).validate_for_target("aarch64-apple-darwin").build();
+}
+
+#[test]
+#[should_panic(expected = "128-bit integers don't have a stable C ABI on this target")]
+fn rejects_128_bit_integers_on_windows_targets() {
+    _ = Inventory::builder()
+        .register(function!(next_id))
+        .validate_for_target("x86_64-pc-windows-msvc")
+        .build();
+}
+
+#[test]
+fn accepts_chars_nonzero_and_durations() {
+    _ = Inventory::builder().register(function!(next_char)).validate().build();
+}
+
+#[ffi_type]
+pub enum Payload {
+    Small(u32),
+    Large(u64),
+}
+
+#[test]
+fn data_enums_put_all_payloads_after_the_tag() {
+    #[repr(C)]
+    union MirrorPayload {
+        small: u32,
+        _large: u64,
+    }
+
+    #[repr(C)]
+    struct Mirror {
+        tag: u32,
+        payload: MirrorPayload,
+    }
+
+    let small = Payload::Small(123);
+    let result = ffi::Result::<u64, u32>::Err(123);
+    let small = unsafe { &*(&raw const small).cast::<Mirror>() };
+    let result = unsafe { &*(&raw const result).cast::<Mirror>() };
+
+    assert_eq!(size_of::<Payload>(), size_of::<Mirror>());
+    assert_eq!((small.tag, unsafe { small.payload.small }), (0, 123));
+    assert_eq!((result.tag, unsafe { result.payload.small }), (1, 123));
+}
@@ -0,0 +1,21 @@
+use interoptopus::ffi_function;
+use interoptopus::pattern::result::take_last_error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static PREVIOUS_HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[ffi_function(on_panic = "return_default")]
+fn panics() -> u32 {
+    panic!("oops")
+}
+
+#[test]
+fn previous_panic_hook_still_runs() {
+    std::panic::set_hook(Box::new(|_| {
+        PREVIOUS_HOOK_CALLS.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    assert_eq!(panics(), 0);
+    assert_eq!(PREVIOUS_HOOK_CALLS.load(Ordering::SeqCst), 1);
+    assert!(take_last_error().unwrap().message().starts_with("Panic in (panics): oops"));
+}
@@ -0,0 +1,38 @@
+#![allow(clippy::must_use_candidate, clippy::ignored_unit_patterns)]
+
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi, ffi_service, ffi_service_method, ffi_type, pattern};
+
+#[ffi_type]
+#[derive(Debug)]
+pub enum Error {
+    Fail,
+}
+
+#[ffi_type(opaque)]
+pub struct Counter {
+    value: u32,
+}
+
+#[ffi_service]
+impl Counter {
+    pub fn new() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { value: 0 })
+    }
+
+    #[ffi_service_method(getter = "Value", on_panic = "return_default")]
+    pub fn get_value(&self) -> u32 {
+        self.value
+    }
+
+    #[ffi_service_method(getter = "Value", on_panic = "return_default")]
+    pub fn value_again(&self) -> u32 {
+        self.value
+    }
+}
+
+#[test]
+#[should_panic(expected = "Property `Value` has more than one getter.")]
+fn rejects_duplicate_getters() {
+    _ = Inventory::builder().register(pattern!(Counter)).build();
+}
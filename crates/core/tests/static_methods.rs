@@ -0,0 +1,50 @@
+#![allow(clippy::must_use_candidate, clippy::ignored_unit_patterns)]
+
+use interoptopus::pattern::result::take_last_error;
+use interoptopus::{ffi, ffi_service, ffi_service_method, ffi_type};
+
+#[ffi_type]
+#[derive(Debug)]
+pub enum Error {
+    Fail,
+}
+
+#[ffi_type(opaque)]
+pub struct Statics {}
+
+#[ffi_service]
+impl Statics {
+    pub fn new() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self {})
+    }
+
+    pub fn instance_result(&self) -> ffi::Result<u32, Error> {
+        panic!("instance")
+    }
+
+    #[ffi_service_method(static)]
+    pub fn value() -> u32 {
+        panic!("value")
+    }
+
+    #[ffi_service_method(static)]
+    pub fn result() -> ffi::Result<u32, Error> {
+        panic!("result")
+    }
+}
+
+#[test]
+fn static_methods_fall_back_by_return_type() {
+    assert_eq!(statics_value(), 0);
+    assert_eq!(take_last_error().unwrap().message(), "Panic in (statics_value): value");
+    assert!(matches!(statics_result(), ffi::Result::Panic));
+    assert_eq!(take_last_error().unwrap().message(), "Panic in (statics_result): result");
+}
+
+#[test]
+fn instance_methods_report_panics_as_results() {
+    let service = statics_new().unwrap();
+    assert!(matches!(statics_instance_result(unsafe { &*service }), ffi::Result::Panic));
+    assert_eq!(take_last_error().unwrap().message(), "Panic in (statics_instance_result): instance");
+    assert!(unsafe { statics_destroy(service) }.is_ok());
+}
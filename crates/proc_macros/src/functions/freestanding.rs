@@ -1,6 +1,7 @@
 use crate::functions::Attributes;
-use crate::util::{extract_doc_lines, purge_lifetimes_from_type};
-use proc_macro2::TokenStream;
+use crate::service::function_impl::OnPanic;
+use crate::util::{extract_doc_lines, ffi_char_type, ffi_tuple_type, purge_lifetimes_from_type, std_result_ffi_type};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{ToTokens, quote, quote_spanned};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use syn::spanned::Spanned;
@@ -38,8 +39,8 @@ pub fn rval_tokens(return_type: &ReturnType) -> TokenStream {
                 let token = x.to_token_stream();
                 quote_spanned!(span=> < #token as ::interoptopus::lang::TypeInfo>::type_info())
             }
-            Type::Tuple(_) => {
-                // TODO: Check tuple is really empty.
+            // Non-empty tuples were lowered to an `ffi::TupleN` before.
+            Type::Tuple(x) if x.elems.is_empty() => {
                 quote_spanned!(span=> ::interoptopus::lang::Type::Primitive(::interoptopus::lang::Primitive::Void))
             }
             Type::Reference(x) => {
@@ -209,11 +210,138 @@ fn ensure_ffi_attributes(item_fn: &mut ItemFn, export_name: &str) {
     item_fn.attrs.push(syn::parse_quote!(#[unsafe(export_name = #export_name)]));
 }
 
+/// Turns a function returning a `std::result::Result` into one returning the given `ffi::Result`.
+fn convert_std_result(item_fn: &mut ItemFn, ffi_rval: &TokenStream) {
+    let ReturnType::Type(_, rval) = &item_fn.sig.output else {
+        return;
+    };
+
+    let ident = &item_fn.sig.ident;
+    let block = &item_fn.block;
+
+    item_fn.block = syn::parse_quote! {{
+        #[allow(clippy::redundant_closure_call)]
+        let __rval: #rval = (move || -> #rval #block)();
+        {
+            #[allow(unused_imports)]
+            use ::interoptopus::pattern::result::{RecordError as _, RecordErrorFallback as _};
+            (&::interoptopus::pattern::result::ErrorRecorder(stringify!(#ident), &__rval)).record();
+        }
+        ::interoptopus::pattern::result::std_result_to_ffi(__rval)
+    }};
+    item_fn.sig.output = syn::parse_quote!(-> #ffi_rval);
+}
+
+/// Lowers tuple and `char` parameters and return values to their `ffi::TupleN` and `ffi::Char`,
+/// converting them back and forth so the body can keep working with regular Rust types.
+///
+/// Destructured tuple parameters are received under a fresh name and destructured in the body.
+/// Invalid `char`s panic before the body runs, and are then handled as requested by `on_panic`.
+fn lower_types(item_fn: &mut ItemFn) -> syn::Result<()> {
+    let mut conversions = Vec::new();
+
+    for (i, input) in item_fn.sig.inputs.iter_mut().enumerate() {
+        let FnArg::Typed(pat) = input else { continue };
+
+        let (lowered, convert) = if let Some(lowered) = ffi_tuple_type(&pat.ty)? {
+            (lowered, quote! { ::std::convert::Into::into })
+        } else if let Some(lowered) = ffi_char_type(&pat.ty) {
+            (lowered, quote! { ::interoptopus::ffi::Char::into_char })
+        } else {
+            continue;
+        };
+
+        let original = std::mem::replace(&mut *pat.ty, lowered);
+
+        match &mut *pat.pat {
+            Pat::Ident(x) => {
+                let binding = x.clone();
+                let ident = &x.ident;
+                conversions.push(quote! { let #binding: #original = #convert(#ident); });
+                x.mutability = None;
+            }
+            Pat::Wild(_) => {}
+            destructured => {
+                let ident = Ident::new(&format!("tuple{i}"), Span::mixed_site());
+                let destructured = std::mem::replace(destructured, syn::parse_quote!(#ident));
+                conversions.push(quote! { let #destructured: #original = #convert(#ident); });
+            }
+        }
+    }
+
+    let block = &item_fn.block;
+
+    if let ReturnType::Type(_, rval) = &item_fn.sig.output
+        && let Some(lowered) = ffi_tuple_type(rval)?.or_else(|| ffi_char_type(rval))
+    {
+        item_fn.block = syn::parse_quote! {{
+            #(#conversions)*
+            #[allow(clippy::redundant_closure_call)]
+            let __rval: #rval = (move || -> #rval #block)();
+            ::std::convert::Into::into(__rval)
+        }};
+        item_fn.sig.output = syn::parse_quote!(-> #lowered);
+    } else if !conversions.is_empty() {
+        item_fn.block = syn::parse_quote! {{
+            #(#conversions)*
+            #block
+        }};
+    }
+
+    Ok(())
+}
+
+/// Wraps the function body in a panic guard as requested by `on_panic`.
+///
+/// Without an explicit `on_panic` panics abort, unless the `catch_panics` feature is enabled, in
+/// which case they yield `Result::Panic` or `Default::default()` where the return type allows.
+fn guard_panics(item_fn: &mut ItemFn, on_panic: Option<OnPanic>) {
+    let ident = &item_fn.sig.ident;
+    let block = &item_fn.block;
+    let callback = on_panic.map(OnPanic::callback_tokens);
+
+    let rval = match &item_fn.sig.output {
+        ReturnType::Default => quote! { () },
+        ReturnType::Type(_, x) => x.to_token_stream(),
+    };
+
+    let on_panic_rval = match on_panic {
+        None if cfg!(feature = "catch_panics") => quote! {{
+            #[allow(unused_imports)]
+            use ::interoptopus::pattern::result::{FallbackAbort as _, FallbackDefault as _, FallbackResult as _};
+            (&&&::interoptopus::pattern::result::Fallback::<#rval>::new()).panicked()
+        }},
+        None | Some(OnPanic::Abort) => return,
+        Some(OnPanic::FfiError) => quote! { ::interoptopus::pattern::result::Result::Panic },
+        Some(OnPanic::ReturnDefault | OnPanic::Callback) => quote! { <#rval>::default() },
+    };
+
+    item_fn.block = syn::parse_quote! {{
+        match ::interoptopus::pattern::result::catch_unwind(move || -> #rval #block) {
+            Ok(__x) => __x,
+            Err(__e) => {
+                ::interoptopus::pattern::result::record_panic(stringify!(#ident), __e.as_ref());
+                #callback
+                #on_panic_rval
+            }
+        }
+    }};
+}
+
 pub fn ffi_function_freestanding(ffi_attributes: &Attributes, input: TokenStream) -> TokenStream {
     let namespace = ffi_attributes.namespace.clone().unwrap_or_default();
     let mut item_fn = syn::parse2::<ItemFn>(input).expect("Must be a function.");
     let docs = extract_doc_lines(&item_fn.attrs);
 
+    if let Some(ffi_rval) = std_result_ffi_type(&item_fn.sig.output, ffi_attributes.error.as_ref()) {
+        convert_std_result(&mut item_fn, &ffi_rval);
+    }
+
+    if let Err(e) = lower_types(&mut item_fn) {
+        return e.to_compile_error();
+    }
+    guard_panics(&mut item_fn, ffi_attributes.on_panic);
+
     let signature = fn_signature_type(&item_fn.sig);
     let rval = rval_tokens(&item_fn.sig.output);
 
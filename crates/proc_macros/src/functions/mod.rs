@@ -1,4 +1,5 @@
 use crate::macros::darling_parse;
+use crate::service::function_impl::OnPanic;
 use darling::FromMeta;
 use freestanding::ffi_function_freestanding;
 use proc_macro2::TokenStream;
@@ -30,6 +31,16 @@ pub struct Attributes {
 
     #[darling(default)]
     namespace: Option<String>,
+
+    /// A function returning a `std::result::Result<T, E>` is exported as returning an
+    /// `ffi::Result<T, F>`, with `E` converted via `From` into the `error` type `F` given here.
+    #[darling(default)]
+    error: Option<syn::Path>,
+
+    /// What happens on a panic, see `#[ffi_service_method]`. If not given, panics abort unless
+    /// the `catch_panics` feature is enabled.
+    #[darling(default)]
+    on_panic: Option<OnPanic>,
 }
 
 pub fn ffi_function(attr: TokenStream, input: TokenStream) -> TokenStream {
@@ -97,6 +97,14 @@ pub fn ffi_type(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// | Parameter | Explanation |
 /// | --------- | --- |
 /// | `debug`   | Print generated helper code in console. |
+/// | `error = E` | For functions returning a `std::result::Result<T, X>`, export them as returning `ffi::Result<T, E>`, converting errors via `From<X>`. |
+/// | `on_panic` | What happens on a panic (`ffi_error`, `return_default`, `callback`, `abort`), see [`ffi_service_method`](macro@crate::ffi_service_method). |
+///
+/// If no `on_panic` is given a panic aborts the process. With the `catch_panics` feature enabled it is caught instead,
+/// returning `Result::Panic` for `ffi::Result`s, [`Default::default()`] for other `T: Default`, and aborting otherwise.
+///
+/// Functions returning a `std::result::Result` (spelled out like this) are exported as returning an `ffi::Result`
+/// instead, so their bodies can use `?`.
 ///
 /// # Safety
 ///
@@ -174,6 +182,7 @@ pub fn ffi_constant(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// | --------------- | --- |
 /// | `error = "t"`   | Use `t` as the [`FFIError`](https://docs.rs/interoptopus/latest/interoptopus/patterns/result/trait.FFIError.html) type, mandatory. |
 /// | `prefix  = "p"` | Add `p` to all generated method names. If not given, the prefix will be inferred from the type. |
+/// | `implements(T, ...)` | Service implements the given [`#[ffi_service_trait]`](macro@crate::ffi_service_trait) traits; emits an `as_t` conversion per trait. |
 ///
 /// # Example
 ///
@@ -229,6 +238,123 @@ pub fn ffi_service(attr: TokenStream, item: TokenStream) -> TokenStream {
     service::ffi_service(attr, &input).into()
 }
 
+/// Turns a trait into an FFI service interface implementable by multiple services.
+///
+/// Next to the trait itself this generates a `Dyn<Trait>` handle type that can be passed
+/// to and from FFI functions, and one free-standing FFI function per trait method dispatching
+/// through the handle. Services opt in via [`#[ffi_service(implements(Trait))]`](macro@crate::ffi_service),
+/// and the interface must be registered in the inventory via `pattern!(dyn Trait)`.
+///
+/// # Requirements
+///
+/// - The trait must not be generic and all methods must take `&self` or `&mut self`.
+/// - All parameters and return types must be FFI-safe, just as with [`#[ffi_function]`](macro@crate::ffi_function).
+///
+/// # Parameters
+///
+/// | Parameter       | Explanation |
+/// | --------------- | --- |
+/// | `prefix  = "p"` | Add `p` to all generated dispatch function names. If not given, the prefix will be inferred from the trait. |
+/// | `namespace = "n"` | Emit the handle and dispatch functions into namespace `n`. |
+/// | `debug`         | Print the generated code. |
+///
+/// # Example
+///
+/// ```
+/// # use std::fmt::{Display, Formatter};
+/// #
+/// # impl Display for Error {
+/// #     fn fmt(&self, _: &mut Formatter<'_>) -> std::fmt::Result {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # impl std::error::Error for Error {}
+/// #
+/// # #[ffi_type]
+/// # #[derive(PartialOrd, PartialEq, Debug, Copy, Clone)]
+/// # pub enum Error {
+/// #     Bad = 3,
+/// # }
+/// #
+/// use interoptopus::{ffi, ffi_function, ffi_service, ffi_service_trait, ffi_type};
+///
+/// #[ffi_service_trait]
+/// pub trait Storage {
+///     fn get(&self, key: u32) -> u32;
+/// }
+///
+/// #[ffi_type(opaque)]
+/// pub struct MemoryStorage { }
+///
+/// #[ffi_service(implements(Storage))]
+/// impl MemoryStorage {
+///     pub fn new() -> ffi::Result<Self, Error> {
+///         ffi::Ok(Self { })
+///     }
+/// }
+///
+/// impl Storage for MemoryStorage {
+///     fn get(&self, key: u32) -> u32 { key }
+/// }
+///
+/// #[ffi_function]
+/// pub fn read_storage(storage: DynStorage) -> u32 {
+///     storage.get(123)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ffi_service_trait(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
+    let input = proc_macro2::TokenStream::from(item);
+    service::interface::ffi_service_trait(attr, &input).into()
+}
+
+/// Turns a trait into a vtable of callbacks, so it can be implemented in a foreign language.
+///
+/// This is the reverse of [`#[ffi_service_trait]`](macro@crate::ffi_service_trait). For a trait `T` the
+/// following items are generated:
+///
+/// - One [`callback!`](https://docs.rs/interoptopus/latest/interoptopus/macro.callback.html) type per method, named `T<Method>`.
+/// - A `#[repr(C)]` struct `TVTable` holding these callbacks plus a `user_data` pointer, which is
+///   passed to every callback instead of the callbacks' own data pointer.
+/// - An implementation of `T` for `TVTable`, forwarding each method to its callback.
+///
+/// The vtable can be accepted by [`#[ffi_function]`](macro@crate::ffi_function)s and services like
+/// any other type. To have backends emit base classes for it, register it via `pattern!(TVTable)`.
+///
+/// # Parameters
+///
+/// | Parameter       | Explanation |
+/// | --------------- | --- |
+/// | `namespace = "n"` | Emit the vtable and callbacks into namespace `n`. |
+/// | `debug`         | Print the generated code. |
+///
+/// # Example
+///
+/// ```
+/// use interoptopus::{ffi_foreign_trait, ffi_function};
+///
+/// #[ffi_foreign_trait]
+/// pub trait LogSink {
+///     fn log(&self, level: u32);
+///     fn count(&self) -> u32;
+/// }
+///
+/// #[ffi_function]
+/// pub fn log_twice(sink: LogSinkVTable) -> u32 {
+///     sink.log(1);
+///     sink.log(2);
+///     sink.count()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ffi_foreign_trait(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
+    let input = proc_macro2::TokenStream::from(item);
+    service::foreign::ffi_foreign_trait(attr, &input).into()
+}
+
 /// Inside a [`#[ffi_service]`](macro@crate::ffi_service) block, configure the generated FFI helper.
 ///
 /// This is an optional attribute that can be applied to some methods.
@@ -248,7 +374,11 @@ pub fn ffi_service(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// | Parameter  | Explanation |
 /// | ---------- | --- |
 /// | `ignore`   | Don't emit to FFI. |
-/// | `on_panic` | Determines what will happen on a panic (`ffi_error`, `return_default`, `undefined_behavior`) and, as a side effect, _also_ determine how return values will be handled. See below. |
+/// | `static`   | Method does not take `self` and is not a constructor; emitted as a static method in backends supporting the pattern. |
+/// | `on_panic` | Determines what will happen on a panic (`ffi_error`, `return_default`, `callback`, `abort`) and, as a side effect, _also_ determine how return values will be handled. See below. |
+/// | `getter = "P"` | Also expose this method as the getter of property `P` in backends supporting properties. Must only take `&self`. |
+/// | `setter = "P"` | Also expose this method as the setter of property `P` in backends supporting properties. Must take `&self` or `&mut self` and one value. |
+/// | `error = E` | For methods returning a `std::result::Result<T, X>`, export them as returning `ffi::Result<T, E>`, converting errors via `From<X>`. |
 ///
 ///
 /// ## Wrapping Behavior
@@ -257,9 +387,14 @@ pub fn ffi_service(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// | Mode                 | Explanation |
 /// | -------------------- | --- |
-/// | `ffi_error`          | Method must return `Result<(), Error>` and maps that to an `FFIError`. Default behavior. |
-/// | `return_default`     | Method can return any `T: Default`. If a panic occurs [`T::default()`](Default::default) will be returned, see below. |
-/// | `undefined_behavior` | Method can return any `T`. If a panic occurs undefined behavior happens. Slightly faster (nanoseconds) and mostly an escape hatch when running into lifetime issues in autogenerated code, e.g., when returning an `CStrPointer` from a service. In the long term our proc macro code gen should be fixed to handle this situation. |
+/// | `ffi_error`          | Method must return `Result<(), Error>` and maps that to an `FFIError`. Default for instance methods, except unmarked ones returning `()`. |
+/// | `return_default`     | Method can return any `T: Default`. If a panic occurs [`T::default()`](Default::default) will be returned, see below. Default for unmarked instance methods returning `()`. |
+/// | `callback`           | Like `return_default`, but first invokes the callback registered via `interoptopus::pattern::result::set_panic_callback`. |
+/// | `abort`              | Method can return any `T`. If a panic occurs the process aborts. Slightly faster (nanoseconds) and mostly an escape hatch when running into lifetime issues in autogenerated code, e.g., when returning an `CStrPointer` from a service. In the long term our proc macro code gen should be fixed to handle this situation. |
+///
+/// Static methods without an explicit `on_panic` pick the behavior from their return type: an
+/// `ffi::Result` becomes `Result::Panic`, other `T: Default` return [`T::default()`](Default::default),
+/// and anything else aborts.
 ///
 /// # Panic Behavior
 ///
@@ -270,8 +405,7 @@ pub fn ffi_service(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// # Safety
 ///
-/// ⚠️ You must ensure that methods marked with `on_panic = "undefined_behavior"` will never panic. Failure to do so will lead to
-/// undefined behavior.
+/// ⚠️ Methods marked with `on_panic = "abort"` should never panic, a panic aborts the process.
 ///
 /// # Example
 ///
@@ -340,3 +474,13 @@ pub fn ffi_service(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn ffi_service_method(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
+
+/// Inside a [`#[ffi_service]`](macro@crate::ffi_service) block, explicitly marks a method as constructor.
+///
+/// Methods without a `self` receiver are constructors by default, so this attribute is optional.
+/// It mostly serves as documentation next to methods marked `#[ffi_service_method(static)]`.
+/// Constructors must return an `ffi::Result<Self, _>`.
+#[proc_macro_attribute]
+pub fn ffi_service_ctor(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
@@ -0,0 +1,128 @@
+use crate::macros::darling_parse;
+use crate::service::interface::Method;
+use crate::util::{extract_doc_lines, prettyprint_tokenstream, snake_to_pascal_case};
+use darling::FromMeta;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{ItemTrait, TraitItem};
+
+#[derive(Debug, FromMeta)]
+pub struct Attributes {
+    #[darling(default)]
+    debug: bool,
+
+    #[darling(default)]
+    namespace: Option<String>,
+}
+
+pub fn ffi_foreign_trait(attr: TokenStream, input: &TokenStream) -> TokenStream {
+    let attributes = darling_parse!(Attributes, attr);
+    let item = syn::parse2::<ItemTrait>(input.clone()).expect("Must be a trait.");
+
+    assert!(item.generics.params.is_empty(), "Foreign traits must not be generic.");
+
+    let vis = &item.vis;
+    let trait_ident = &item.ident;
+    let trait_name = trait_ident.to_string();
+    let vtable = Ident::new(&format!("{trait_ident}VTable"), trait_ident.span());
+    let namespace = attributes.namespace.clone().unwrap_or_default();
+    let namespace_arg = attributes.namespace.as_ref().map(|x| quote! { , namespace = #x });
+
+    let mut vtable_docs = extract_doc_lines(&item.attrs);
+    if vtable_docs.is_empty() {
+        vtable_docs.push(format!(" Callbacks implementing [`{trait_name}`] in a foreign language."));
+    }
+
+    let methods = item
+        .items
+        .iter()
+        .filter_map(|x| match x {
+            TraitItem::Fn(x) => Some(Method::new(x)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut callbacks = Vec::new();
+    let mut fields = Vec::new();
+    let mut trait_impls = Vec::new();
+
+    for method in &methods {
+        let ident = method.ident();
+        let names = &method.names;
+        let types = &method.types;
+        let rval = method.rval();
+        let receiver = method.receiver();
+        let generics = &method.function.sig.generics;
+        let callback = Ident::new(&format!("{trait_ident}{}", snake_to_pascal_case(&ident.to_string())), ident.span());
+        let doc_lines = extract_doc_lines(&method.function.attrs);
+        let missing = format!("{vtable}::{ident}");
+
+        callbacks.push(quote! {
+            ::interoptopus::callback!(#callback(#(#names: #types),*) -> #rval #namespace_arg);
+        });
+
+        fields.push(quote! {
+            #(
+                #[doc = #doc_lines]
+            )*
+            pub #ident: #callback
+        });
+
+        // The callbacks' own data pointers are ignored, all of them receive the shared `user_data`. Unset
+        // callbacks are recorded like null pointers and yield a fallback value, e.g., `Result::Null`.
+        trait_impls.push(quote! {
+            fn #ident #generics(#receiver, #(#names: #types),*) -> #rval {
+                #[allow(unused_imports)]
+                use ::interoptopus::pattern::result::{FallbackAbort as _, FallbackDefault as _, FallbackResult as _};
+
+                let Some(__callback) = self.#ident.0 else {
+                    ::interoptopus::pattern::result::record_null(#missing);
+                    return (&&&::interoptopus::pattern::result::Fallback::<#rval>::new()).null();
+                };
+
+                __callback(#(#names,)* self.user_data)
+            }
+        });
+    }
+
+    let rval = quote! {
+        #input
+
+        #(#callbacks)*
+
+        #[::interoptopus::ffi_type(namespace = #namespace)]
+        #[derive(Clone, Copy)]
+        #(
+            #[doc = #vtable_docs]
+        )*
+        #vis struct #vtable {
+            #(#fields,)*
+            pub user_data: *const ::std::ffi::c_void,
+        }
+
+        // Safety: Same as for the individual callbacks, calling them is thread
+        //         safe as long as the foreign implementation is.
+        unsafe impl ::std::marker::Send for #vtable {}
+        unsafe impl ::std::marker::Sync for #vtable {}
+
+        impl #trait_ident for #vtable {
+            #(#trait_impls)*
+        }
+
+        impl ::interoptopus::pattern::LibraryPatternInfo for #vtable {
+            fn pattern_info() -> ::interoptopus::pattern::LibraryPattern {
+                use ::interoptopus::lang::TypeInfo;
+
+                let vtable = <#vtable as TypeInfo>::type_info().as_composite_type().expect("Foreign vtable must be a composite.").clone();
+
+                ::interoptopus::pattern::LibraryPattern::Foreign(::interoptopus::pattern::service::ForeignInterface::new(#trait_name.to_string(), vtable))
+            }
+        }
+    };
+
+    if attributes.debug {
+        prettyprint_tokenstream(&rval);
+    }
+
+    rval
+}
@@ -1,12 +1,12 @@
-use crate::service::Attributes;
-use crate::util::{ReplaceSelf, extract_doc_lines, purge_lifetimes_from_type};
+use crate::service::{Attributes, SyncMode};
+use crate::util::{ReplaceSelf, extract_doc_lines, purge_lifetimes_from_type, std_result_ffi_type};
 use darling::FromMeta;
-use proc_macro2::{Ident, TokenStream};
-use quote::quote_spanned;
+use proc_macro2::{Ident, Punct, Spacing, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
 use std::ops::Deref;
 use syn::spanned::Spanned;
 use syn::visit_mut::VisitMut;
-use syn::{FnArg, GenericParam, ImplItem, ImplItemFn, ItemImpl, Pat, ReturnType};
+use syn::{FnArg, GenericParam, ImplItem, ImplItemFn, ItemImpl, Meta, MetaList, Pat, ReturnType};
 
 pub struct Descriptor {
     pub ffi_function_tokens: TokenStream,
@@ -20,19 +20,26 @@ pub enum MethodType {
     Constructor,
     MethodAsync(AttributeMethodAsync),
     MethodSync(AttributeMethodSync),
+    Static(AttributeMethodSync),
     Destructor,
 }
 
-#[derive(Debug, FromMeta)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, FromMeta)]
 pub enum OnPanic {
+    #[default]
     FfiError,
     ReturnDefault,
     Abort,
+    Callback,
 }
 
-impl Default for OnPanic {
-    fn default() -> Self {
-        Self::FfiError
+impl OnPanic {
+    /// Invokes the user's panic callback after a panic was recorded, if requested.
+    pub fn callback_tokens(self) -> TokenStream {
+        match self {
+            Self::Callback => quote! { ::interoptopus::pattern::result::invoke_panic_callback(); },
+            _ => quote! {},
+        }
     }
 }
 
@@ -41,7 +48,15 @@ pub struct AttributeMethodSync {
     #[darling(default)]
     ignore: bool,
     #[darling(default)]
-    on_panic: OnPanic,
+    on_panic: Option<OnPanic>,
+    #[darling(skip)]
+    is_static: bool,
+    #[darling(default)]
+    pub getter: Option<String>,
+    #[darling(default)]
+    pub setter: Option<String>,
+    #[darling(default)]
+    error: Option<syn::Path>,
 }
 
 #[derive(Default, Debug, FromMeta)]
@@ -51,13 +66,76 @@ pub struct AttributeMethodAsync {
     ignore: bool,
 }
 
+/// Parses the `#[ffi_service_method]` attribute of a sync method, if present.
+fn method_sync_attributes(function: &ImplItemFn) -> Option<AttributeMethodSync> {
+    function
+        .attrs
+        .iter()
+        .filter(|x| format!("{x:?}").contains("ffi_service_method"))
+        .map(|attribute| {
+            let (meta, is_static) = extract_static(&attribute.meta);
+            AttributeMethodSync { is_static, ..AttributeMethodSync::from_meta(&meta).unwrap() }
+        })
+        .next()
+}
+
+impl AttributeMethodSync {
+    fn on_panic(&self) -> OnPanic {
+        self.on_panic.unwrap_or_default()
+    }
+
+    /// Static methods without an explicit `on_panic` return whatever fits their return type after
+    /// a panic, see [`fallback_tokens`].
+    const fn uses_fallback(&self) -> bool {
+        self.is_static && self.on_panic.is_none()
+    }
+}
+
+/// The value generated code returns after a panic, picked by the return type: FFI results become
+/// `Result::Panic`, other types implementing `Default` their default if `allow_default` is set,
+/// anything else aborts.
+fn fallback_tokens(rval: &TokenStream, allow_default: bool) -> TokenStream {
+    let fallback_default = allow_default.then(|| quote! { FallbackDefault as _, });
+    quote! {{
+        #[allow(unused_imports)]
+        use ::interoptopus::pattern::result::{FallbackAbort as _, #fallback_default FallbackResult as _};
+        (&&&::interoptopus::pattern::result::Fallback::<#rval>::new()).panicked()
+    }}
+}
+
+/// Removes a `static` flag from an attribute, since darling can't parse keywords.
+fn extract_static(meta: &Meta) -> (Meta, bool) {
+    let Meta::List(list) = meta else {
+        return (meta.clone(), false);
+    };
+
+    let mut is_static = false;
+    let mut tokens = TokenStream::new();
+    let items = list.tokens.clone().into_iter().collect::<Vec<_>>();
+
+    for item in items.split(|x| matches!(x, TokenTree::Punct(p) if p.as_char() == ',')) {
+        match item {
+            [TokenTree::Ident(x)] if x == "static" => is_static = true,
+            [] => {}
+            _ => tokens.extend(item.iter().cloned().chain(std::iter::once(TokenTree::Punct(Punct::new(',', Spacing::Alone))))),
+        }
+    }
+
+    (Meta::List(MetaList { tokens, ..list.clone() }), is_static)
+}
+
 /// Inspects all attributes and determines the method type to generate.
 #[allow(clippy::match_like_matches_macro)]
 #[allow(clippy::match_wildcard_for_single_variants)]
 fn method_type(function: &ImplItemFn) -> MethodType {
     let attrs = function.attrs.as_slice();
 
-    // To be a ctor the function must
+    // Explicitly marked constructors.
+    if attrs.iter().any(|x| format!("{x:?}").contains("ffi_service_ctor")) {
+        return MethodType::Constructor;
+    }
+
+    // To be a ctor or static method the function must
     // - not take &self
     // - and not be `async`
     let is_ctor = function.sig.inputs.iter().next().is_none_or(|x| match x {
@@ -66,7 +144,10 @@ fn method_type(function: &ImplItemFn) -> MethodType {
     });
 
     if is_ctor {
-        return MethodType::Constructor;
+        return match method_sync_attributes(function) {
+            Some(x) if x.is_static => MethodType::Static(x),
+            _ => MethodType::Constructor,
+        };
     }
 
     // Methods that have an `async fn` are always async.
@@ -82,23 +163,17 @@ fn method_type(function: &ImplItemFn) -> MethodType {
     }
 
     // Methods explicitly marked a service methods
-    if attrs.iter().any(|x| format!("{x:?}").contains("ffi_service_method")) {
-        let function_attributes = attrs
-            .iter()
-            .filter(|x| format!("{x:?}").contains("ffi_service_method"))
-            .map(|attribute| AttributeMethodSync::from_meta(&attribute.meta).unwrap())
-            .next()
-            .unwrap_or_default();
-
+    if let Some(function_attributes) = method_sync_attributes(function) {
+        assert!(!function_attributes.is_static, "Static method `{}` must not take `self`.", function.sig.ident);
         return MethodType::MethodSync(function_attributes);
     }
 
     // If the method wasn't explicitly marked ...
     match function.sig.output {
         // If it has default output type, we can get away with "return default"
-        ReturnType::Default => MethodType::MethodSync(AttributeMethodSync { ignore: false, on_panic: OnPanic::ReturnDefault }),
+        ReturnType::Default => MethodType::MethodSync(AttributeMethodSync { on_panic: Some(OnPanic::ReturnDefault), ..Default::default() }),
         // Otherwise, use FFI error conversion.
-        ReturnType::Type(_, _) => MethodType::MethodSync(AttributeMethodSync { ignore: false, on_panic: OnPanic::FfiError }),
+        ReturnType::Type(_, _) => MethodType::MethodSync(AttributeMethodSync { on_panic: Some(OnPanic::FfiError), ..Default::default() }),
     }
 }
 
@@ -117,6 +192,7 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
     let service_type = &impl_block.self_ty;
     let service_prefix = attributes.preferred_service_name(impl_block);
     let has_async = has_async_methods(impl_block);
+    let is_synced = attributes.sync != SyncMode::None;
     let mut generics = function.sig.generics.clone();
     let mut inputs = Vec::new();
     let mut arg_names = Vec::new();
@@ -135,17 +211,29 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
     let span_body = function.block.span();
     let span_service_ty = impl_block.self_ty.span();
 
-    // Determines what the return type is, `()` or `X`
-    let rval = match &function.sig.output {
-        ReturnType::Default => quote_spanned!(span_rval=> ()),
-        ReturnType::Type(_, x) => quote_spanned!(span_rval=> #x),
-    };
-
     // Type of method we process (Constructor, Async, Method, Destructor)
     let method_type = method_type(function);
 
+    // Methods returning a `std::result::Result` are exported as returning an `ffi::Result`.
+    let error = match &method_type {
+        MethodType::MethodSync(x) | MethodType::Static(x) => x.error.as_ref(),
+        _ => None,
+    };
+    let std_rval = std_result_ffi_type(&function.sig.output, error);
+    let into_ffi = |x: TokenStream| match &std_rval {
+        Some(_) => quote! { ::interoptopus::pattern::result::std_result_to_ffi(#x) },
+        None => x,
+    };
+
+    // Determines what the return type is, `()` or `X`
+    let rval = match (&function.sig.output, &std_rval) {
+        (_, Some(x)) => quote_spanned!(span_rval=> #x),
+        (ReturnType::Default, None) => quote_spanned!(span_rval=> ()),
+        (ReturnType::Type(_, x), None) => quote_spanned!(span_rval=> #x),
+    };
+
     match method_type {
-        MethodType::MethodSync(method) if method.ignore => return None,
+        MethodType::MethodSync(method) | MethodType::Static(method) if method.ignore => return None,
         _ => {}
     }
 
@@ -153,10 +241,12 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
         let span_arg = arg.span();
         match arg {
             FnArg::Receiver(receiver) => {
-                if receiver.mutability.is_some() {
-                    inputs.push(quote_spanned!(span_arg=> __context: &mut #service_type));
-                } else {
-                    inputs.push(quote_spanned!(span_arg=> __context: & #service_type));
+                // Synced services need the raw pointer to reach their `SyncedService` wrapper.
+                match (receiver.mutability.is_some(), is_synced) {
+                    (true, false) => inputs.push(quote_spanned!(span_arg=> __context: &mut #service_type)),
+                    (false, false) => inputs.push(quote_spanned!(span_arg=> __context: & #service_type)),
+                    (true, true) => inputs.push(quote_spanned!(span_arg=> __context: *mut #service_type)),
+                    (false, true) => inputs.push(quote_spanned!(span_arg=> __context: *const #service_type)),
                 }
 
                 arg_names.push(quote_spanned!(span_arg=> __context));
@@ -178,7 +268,7 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
                 // If this is the first parameter and we have `async`, the method must have
                 // requested an `Arc<T>`. In that case we generate an FFI method asking for `&T`
                 // and then convert it to `Arc<T>` internally.
-                if i == 0 && has_async && !matches!(method_type, MethodType::Constructor) {
+                if i == 0 && has_async && !matches!(method_type, MethodType::Constructor | MethodType::Static(_)) {
                     arg_names.push(quote_spanned!(span_arg=> __context));
                     inputs.push(quote_spanned!(span_arg=> __context: & #service_type));
                     continue;
@@ -202,10 +292,10 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
     }
 
     let method_attributes = quote_spanned! {span_service_ty =>
-        #[::interoptopus::ffi_function]
+        #[::interoptopus::ffi_function(on_panic = "abort")]
         #[unsafe(no_mangle)]
         #[allow(unused_mut, unsafe_op_in_unsafe_fn)]
-        #[allow(clippy::needless_lifetimes, clippy::extra_unused_lifetimes, clippy::redundant_locals, clippy::forget_non_drop, clippy::useless_conversion, clippy::let_unit_value)]
+        #[allow(clippy::needless_lifetimes, clippy::extra_unused_lifetimes, clippy::redundant_locals, clippy::forget_non_drop, clippy::useless_conversion, clippy::let_unit_value, clippy::not_unsafe_ptr_arg_deref)]
         #(
             #[doc = #doc_lines]
         )*
@@ -213,7 +303,13 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
 
     let generated_function = match &method_type {
         MethodType::Constructor => {
-            let object_construction = if has_async {
+            let object_construction = if is_synced {
+                let sync = attributes.sync.to_tokens();
+                quote_spanned! { span_service_ty =>
+                    let __boxed = ::std::boxed::Box::new(::interoptopus::pattern::service::SyncedService::new(__res.unwrap(), #sync));
+                    let __raw = ::std::boxed::Box::into_raw(__boxed).cast::<#service_type>();
+                }
+            } else if has_async {
                 quote_spanned! { span_service_ty =>
                     let __boxed = ::std::sync::Arc::new(__res.unwrap());
                     let __raw = ::std::sync::Arc::into_raw(__boxed);
@@ -226,13 +322,14 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
             };
 
             let ctor_result = quote_spanned! {span_rval => <<#service_type as ::interoptopus::pattern::service::ServiceInfo>::CtorResult as ::interoptopus::pattern::result::ResultAsPtr>::AsPtr };
+            let call = into_ffi(quote_spanned! { span_function => <#service_type>::#orig_fn_ident( #(#arg_names),* ) });
 
             quote_spanned! { span_function =>
                 #method_attributes
                 pub extern "C" fn #ffi_fn_ident #generics( #(#inputs),* ) -> #ctor_result {
-                    let __result_result = std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
-                        <#service_type>::#orig_fn_ident( #(#arg_names),* )
-                    }));
+                    let __result_result = ::interoptopus::pattern::result::catch_unwind(|| {
+                        #call
+                    });
 
                     match __result_result {
                         Ok(__res) if __res.is_ok() => {
@@ -241,67 +338,151 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
                         }
                         Ok(__res) => {
                             let __e = __res.unwrap_err();
-                            ::interoptopus::ffi::log_error(|| format!("Error in ({}): {:?}", stringify!(service_basic_new), __e));
+                            ::interoptopus::pattern::result::record_error(stringify!(#ffi_fn_ident), &__e);
                             #ctor_result::Err(__e)
                         }
                         Err(__e) => {
-                            ::interoptopus::ffi::log_error(|| format!("Panic in ({}): {}", stringify!(#ffi_fn_ident), ::interoptopus::pattern::result::get_panic_message(__e.as_ref())));
+                            ::interoptopus::pattern::result::record_panic(stringify!(#ffi_fn_ident), __e.as_ref());
                             #ctor_result::Panic
                         }
                     }
                 }
             }
         }
-        MethodType::MethodSync(x) => {
-            match x.on_panic {
-                OnPanic::ReturnDefault => {
+        MethodType::MethodSync(x) | MethodType::Static(x) => {
+            let call = quote_spanned! { span_function => <#without_lifetimes>::#orig_fn_ident( #(#arg_names),* ) };
+            let call_ffi = into_ffi(call.clone());
+
+            let body = match x.on_panic() {
+                _ if x.uses_fallback() => {
+                    let fallback = fallback_tokens(&rval, true);
                     quote_spanned! { span_function =>
-                        #method_attributes
-                        pub extern "C" fn #ffi_fn_ident #generics( #(#inputs),* ) -> #rval {
-                            let __result_result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
-                                // Make sure we only have a FnOnce closure and prevent lifetime errors.
-                                #(
-                                    let #arg_names = #arg_names;
-                                )*
-                                <#without_lifetimes>::#orig_fn_ident( #(#arg_names),* )
-                            }));
-
-                            match __result_result {
-                                Ok(__x) => __x,
-                                Err(__e) => {
-                                    ::interoptopus::ffi::log_error(|| format!("Panic in ({}): {}", stringify!(#ffi_fn_ident), ::interoptopus::pattern::result::get_panic_message(__e.as_ref())));
-                                    <#rval>::default()
-                                }
+                        let __result_result = ::interoptopus::pattern::result::catch_unwind(|| {
+                            // Make sure we only have a FnOnce closure and prevent lifetime errors.
+                            #(
+                                let #arg_names = #arg_names;
+                            )*
+                            #call_ffi
+                        });
+
+                        match __result_result {
+                            Ok(__x) => __x,
+                            Err(__e) => {
+                                ::interoptopus::pattern::result::record_panic(stringify!(#ffi_fn_ident), __e.as_ref());
+                                #fallback
+                            }
+                        }
+                    }
+                }
+                OnPanic::ReturnDefault | OnPanic::Callback => {
+                    let callback = x.on_panic().callback_tokens();
+                    quote_spanned! { span_function =>
+                        let __result_result = ::interoptopus::pattern::result::catch_unwind(|| {
+                            // Make sure we only have a FnOnce closure and prevent lifetime errors.
+                            #(
+                                let #arg_names = #arg_names;
+                            )*
+                            #call_ffi
+                        });
+
+                        match __result_result {
+                            Ok(__x) => __x,
+                            Err(__e) => {
+                                ::interoptopus::pattern::result::record_panic(stringify!(#ffi_fn_ident), __e.as_ref());
+                                #callback
+                                <#rval>::default()
                             }
                         }
                     }
                 }
                 OnPanic::Abort => {
                     quote_spanned! { span_function =>
-                        #method_attributes
-                        pub extern "C" fn #ffi_fn_ident #generics( #(#inputs),* ) -> #rval {
-                            <#without_lifetimes>::#orig_fn_ident( #(#arg_names),* )
+                        #call_ffi
+                    }
+                }
+                OnPanic::FfiError => {
+                    let rval_ffi = into_ffi(quote! { __rval });
+                    let fallback = fallback_tokens(&rval, false);
+                    quote_spanned! { span_body =>
+                        let __result_result = ::interoptopus::pattern::result::catch_unwind(|| {
+                            // Make sure we only have a FnOnce closure and prevent lifetime errors.
+                            #(
+                                let #arg_names = #arg_names;
+                            )*
+                            #call
+                        });
+
+                        let __rval = match __result_result {
+                            Ok(__x) => __x,
+                            Err(__e) => {
+                                ::interoptopus::pattern::result::record_panic(stringify!(#ffi_fn_ident), __e.as_ref());
+                                return #fallback;
+                            }
+                        };
+
+                        {
+                            #[allow(unused_imports)]
+                            use ::interoptopus::pattern::result::{RecordError as _, RecordErrorFallback as _};
+                            (&::interoptopus::pattern::result::ErrorRecorder(stringify!(#ffi_fn_ident), &__rval)).record();
                         }
+                        #rval_ffi
                     }
                 }
+            };
 
-                OnPanic::FfiError => {
-                    let block = quote_spanned! { span_body =>
-                        <#without_lifetimes>::#orig_fn_ident( #(#arg_names),* )
+            let body = match function.sig.receiver() {
+                Some(receiver) if is_synced => {
+                    let deref_context = if receiver.mutability.is_some() {
+                        quote_spanned! { span_function => &mut *__context }
+                    } else {
+                        quote_spanned! { span_function => &*__context }
+                    };
+
+                    let wrong_thread = match x.on_panic() {
+                        _ if attributes.sync != SyncMode::ThreadAffine => quote_spanned! { span_function => unreachable!("Only thread-affine services reject calls.") },
+                        OnPanic::ReturnDefault | OnPanic::Callback => quote_spanned! { span_function => <#rval>::default() },
+                        OnPanic::Abort => quote_spanned! { span_function => ::std::process::abort() },
+                        OnPanic::FfiError => {
+                            quote_spanned! { span_function => ::interoptopus::pattern::result::Result::Err(::std::convert::From::from(::interoptopus::pattern::service::WrongThread)) }
+                        }
                     };
 
                     quote_spanned! { span_function =>
-                        #method_attributes
-                        pub extern "C" fn #ffi_fn_ident #generics( #(#inputs),* ) -> #rval {
-                            #block
+                        let __synced = unsafe {
+                            ::interoptopus::pattern::service::SyncedService::with(__context, move |__context| {
+                                let __context = #deref_context;
+                                #body
+                            })
+                        };
+
+                        match __synced {
+                            Some(__x) => __x,
+                            None => {
+                                ::interoptopus::pattern::result::record_wrong_thread(stringify!(#ffi_fn_ident));
+                                #wrong_thread
+                            }
                         }
                     }
                 }
+                _ => body,
+            };
+
+            quote_spanned! { span_function =>
+                #method_attributes
+                pub extern "C" fn #ffi_fn_ident #generics( #(#inputs),* ) -> #rval {
+                    #body
+                }
             }
         }
         MethodType::Destructor => panic!("Must not happen."),
         MethodType::MethodAsync(_) => {
             let first = arg_types.first().unwrap();
+            let call = quote_spanned! { span_body => <#without_lifetimes>::#orig_fn_ident( #(#arg_names),* ).await };
+            let call_ffi = if std_rval.is_some() {
+                into_ffi(call)
+            } else {
+                quote_spanned! { span_body => #call.into() }
+            };
 
             let block = quote_spanned! { span_body =>
                 // We need &T down below to invoke spawn but override the name, so let's save
@@ -317,7 +498,7 @@ pub fn generate_service_method(attributes: &Attributes, impl_block: &ItemImpl, f
 
                 let __async_fn = async move |__tlcontext| {
                     let __context = <#first as ::interoptopus::pattern::asynk::AsyncProxy<_, _>>::new(__context, __tlcontext);
-                    let __rval = <#without_lifetimes>::#orig_fn_ident( #(#arg_names),* ).await.into();
+                    let __rval = #call_ffi;
                     __async_callback.call(&__rval);
                     // We actually want move semantics for rval for types like `Utf8Strings` that
                     // should be owned by the FFI side now. We therefore forget it here since
@@ -347,12 +528,17 @@ pub fn generate_service_dtor(attributes: &Attributes, impl_block: &ItemImpl) ->
     let ffi_fn_ident = Ident::new(&format!("{service_prefix}destroy"), impl_block.span());
     let without_lifetimes = purge_lifetimes_from_type(&impl_block.self_ty);
     let has_async = has_async_methods(impl_block);
+    let is_synced = attributes.sync != SyncMode::None;
 
     let span_service_ty = impl_block.self_ty.span();
     let ptr_type = quote_spanned!(span_service_ty => *const #without_lifetimes);
     let ctor_result = quote_spanned! {span_service_ty => <<#without_lifetimes as ::interoptopus::pattern::service::ServiceInfo>::CtorResult as ::interoptopus::pattern::result::ResultAsPtr>::AsPtr };
 
-    let object_deconstruction = if has_async {
+    let object_deconstruction = if is_synced {
+        quote_spanned! { span_service_ty =>
+            unsafe { drop(::std::boxed::Box::from_raw(__context.cast_mut().cast::<::interoptopus::pattern::service::SyncedService<#without_lifetimes>>())) };
+        }
+    } else if has_async {
         quote_spanned! { span_service_ty =>
             unsafe { drop(::std::sync::Arc::from_raw(__context)) };
         }
@@ -362,6 +548,17 @@ pub fn generate_service_dtor(attributes: &Attributes, impl_block: &ItemImpl) ->
         }
     };
 
+    let wrong_thread_check = if attributes.sync == SyncMode::ThreadAffine {
+        quote_spanned! { span_service_ty =>
+            if !unsafe { ::interoptopus::pattern::service::SyncedService::is_accessible(__context) } {
+                ::interoptopus::pattern::result::record_wrong_thread(stringify!(#ffi_fn_ident));
+                return #ctor_result::Err(::std::convert::From::from(::interoptopus::pattern::service::WrongThread));
+            }
+        }
+    } else {
+        quote_spanned! { span_service_ty => }
+    };
+
     let generated_function = quote_spanned! {span_service_ty =>
         /// Destroys the given instance.
         ///
@@ -369,9 +566,9 @@ pub fn generate_service_dtor(attributes: &Attributes, impl_block: &ItemImpl) ->
         ///
         /// The passed parameter MUST have been created with the corresponding init function;
         /// passing any other value results in undefined behavior.
-        #[::interoptopus::ffi_function]
+        #[::interoptopus::ffi_function(on_panic = "abort")]
         #[allow(unused_mut, unsafe_op_in_unsafe_fn, unused_unsafe)]
-        #[allow(clippy::needless_lifetimes, clippy::extra_unused_lifetimes, clippy::redundant_locals, clippy::let_unit_value)]
+        #[allow(clippy::needless_lifetimes, clippy::extra_unused_lifetimes, clippy::redundant_locals, clippy::let_unit_value, clippy::cast_ptr_alignment)]
         #[unsafe(no_mangle)]
         pub unsafe extern "C" fn #ffi_fn_ident(__context: #ptr_type) -> #ctor_result {
             // Checks the _contained_ pointer is not null, which usually means service was not initialized.
@@ -379,15 +576,17 @@ pub fn generate_service_dtor(attributes: &Attributes, impl_block: &ItemImpl) ->
                 return #ctor_result::Null;
             }
 
-            let __result_result = ::std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            #wrong_thread_check
+
+            let __result_result = ::interoptopus::pattern::result::catch_unwind(|| {
                 #object_deconstruction
-            }));
+            });
 
             match __result_result {
                 Ok(_) => #ctor_result::Ok(::std::ptr::null()),
                 // Ok(_) => #ctor_result::Null,
                 Err(__e) => {
-                    ::interoptopus::ffi::log_error(|| format!("Panic in ({}): {}", stringify!(#ffi_fn_ident), ::interoptopus::pattern::result::get_panic_message(__e.as_ref())));
+                    ::interoptopus::pattern::result::record_panic(stringify!(#ffi_fn_ident), __e.as_ref());
                     #ctor_result::Panic
                 }
             }
@@ -398,6 +597,6 @@ pub fn generate_service_dtor(attributes: &Attributes, impl_block: &ItemImpl) ->
 }
 
 /// Checks if the impl block as an `async fn`.
-fn has_async_methods(impl_block: &ItemImpl) -> bool {
+pub fn has_async_methods(impl_block: &ItemImpl) -> bool {
     impl_block.items.iter().any(|x| matches!(x, ImplItem::Fn(x) if x.sig.asyncness.is_some()))
 }
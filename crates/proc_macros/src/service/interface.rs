@@ -0,0 +1,378 @@
+use crate::macros::darling_parse;
+use crate::service::{Attributes as ServiceAttributes, SyncMode};
+use crate::util::{extract_doc_lines, pascal_to_snake_case, prettyprint_tokenstream, purge_lifetimes_from_type};
+use darling::FromMeta;
+use proc_macro2::{Ident, TokenStream};
+use quote::{ToTokens, quote};
+use syn::spanned::Spanned;
+use syn::{FnArg, ItemImpl, ItemTrait, Pat, Path, ReturnType, TraitItem, TraitItemFn};
+
+#[derive(Debug, FromMeta)]
+pub struct Attributes {
+    #[darling(default)]
+    debug: bool,
+
+    #[darling(default)]
+    prefix: String,
+
+    #[darling(default)]
+    namespace: Option<String>,
+}
+
+impl Attributes {
+    fn preferred_prefix(&self, item: &ItemTrait) -> String {
+        if self.prefix.is_empty() {
+            format!("{}_", pascal_to_snake_case(&item.ident.to_string()))
+        } else {
+            self.prefix.clone()
+        }
+    }
+}
+
+/// The handle type generated for a service trait, e.g., `DynStorage` for `Storage`.
+pub fn handle_ident(trait_ident: &Ident) -> Ident {
+    Ident::new(&format!("Dyn{trait_ident}"), trait_ident.span())
+}
+
+/// For a path to a service trait, e.g., `crate::x::Storage`, returns the path to its handle, `crate::x::DynStorage`.
+pub fn handle_path(trait_path: &Path) -> Path {
+    let mut rval = trait_path.clone();
+    let last = rval.segments.last_mut().expect("Trait path must not be empty.");
+    last.ident = handle_ident(&last.ident);
+    rval
+}
+
+/// For a service `implements(...)` list, generates one `<prefix>as_<trait>` FFI function per trait.
+pub fn generate_service_as_functions(attributes: &ServiceAttributes, impl_block: &ItemImpl) -> (Vec<TokenStream>, Vec<Ident>) {
+    let service_prefix = attributes.preferred_service_name(impl_block);
+    let service_type = purge_lifetimes_from_type(&impl_block.self_ty);
+    let from_raw = if attributes.sync == SyncMode::None {
+        quote! { from_raw }
+    } else {
+        quote! { from_synced }
+    };
+    let mut functions = Vec::new();
+    let mut idents = Vec::new();
+
+    for trait_path in attributes.implements.iter() {
+        let trait_ident = &trait_path.segments.last().expect("Trait path must not be empty.").ident;
+        let handle = handle_path(trait_path);
+        let as_ident = Ident::new(&format!("{service_prefix}as_{}", pascal_to_snake_case(&trait_ident.to_string())), trait_ident.span());
+        let doc = format!(" Returns a [`{trait_ident}`] handle to this service, valid until the service is destroyed.");
+        let doc_exclusive = " While a `&mut self` method runs through the handle, no other method of this service may be called.";
+
+        functions.push(quote! {
+            #[::interoptopus::ffi_function(on_panic = "abort")]
+            #[unsafe(no_mangle)]
+            #[allow(clippy::not_unsafe_ptr_arg_deref)]
+            #[doc = #doc]
+            #[doc = ""]
+            #[doc = #doc_exclusive]
+            pub extern "C" fn #as_ident(__context: *const #service_type) -> #handle {
+                if __context.is_null() {
+                    ::interoptopus::pattern::result::record_null(stringify!(#as_ident));
+                }
+
+                unsafe { #handle::#from_raw(__context) }
+            }
+        });
+
+        idents.push(as_ident);
+    }
+
+    (functions, idents)
+}
+
+/// A single trait method as seen by the generated vtable.
+pub struct Method<'a> {
+    pub function: &'a TraitItemFn,
+    pub is_mut: bool,
+    pub names: Vec<Ident>,
+    pub types: Vec<TokenStream>,
+}
+
+impl<'a> Method<'a> {
+    pub fn new(function: &'a TraitItemFn) -> Self {
+        let sig = &function.sig;
+        let mut is_mut = None;
+        let mut names = Vec::new();
+        let mut types = Vec::new();
+
+        assert!(sig.asyncness.is_none(), "Service trait method `{}` must not be `async`.", sig.ident);
+        assert!(sig.generics.type_params().next().is_none(), "Service trait method `{}` must not be generic.", sig.ident);
+
+        for (i, arg) in sig.inputs.iter().enumerate() {
+            match arg {
+                FnArg::Receiver(receiver) => {
+                    assert!(receiver.reference.is_some(), "Service trait method `{}` must take `&self` or `&mut self`.", sig.ident);
+                    is_mut = Some(receiver.mutability.is_some());
+                }
+                FnArg::Typed(pat) => {
+                    let name = match pat.pat.as_ref() {
+                        Pat::Ident(x) => x.ident.clone(),
+                        Pat::Wild(_) => Ident::new(&format!("_anon{i}"), arg.span()),
+                        _ => panic!("Unknown pattern {pat:?}"),
+                    };
+                    names.push(name);
+                    types.push(pat.ty.to_token_stream());
+                }
+            }
+        }
+
+        let is_mut = is_mut.unwrap_or_else(|| panic!("Service trait method `{}` must take `&self` or `&mut self`.", sig.ident));
+
+        Self { function, is_mut, names, types }
+    }
+
+    pub fn ident(&self) -> &Ident {
+        &self.function.sig.ident
+    }
+
+    pub fn rval(&self) -> TokenStream {
+        match &self.function.sig.output {
+            ReturnType::Default => quote! { () },
+            ReturnType::Type(_, x) => x.to_token_stream(),
+        }
+    }
+
+    pub fn receiver(&self) -> TokenStream {
+        if self.is_mut {
+            quote! { &mut self }
+        } else {
+            quote! { &self }
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn ffi_service_trait(attr: TokenStream, input: &TokenStream) -> TokenStream {
+    let attributes = darling_parse!(Attributes, attr);
+    let item = syn::parse2::<ItemTrait>(input.clone()).expect("Must be a trait.");
+
+    assert!(item.generics.params.is_empty(), "Service traits must not be generic.");
+
+    let vis = &item.vis;
+    let trait_ident = &item.ident;
+    let trait_name = trait_ident.to_string();
+    let handle = handle_ident(trait_ident);
+    let vtable = Ident::new(&format!("__{handle}VTable"), trait_ident.span());
+    let prefix = attributes.preferred_prefix(&item);
+    let namespace = attributes.namespace.clone().unwrap_or_default();
+    let mut handle_docs = extract_doc_lines(&item.attrs);
+    if handle_docs.is_empty() {
+        handle_docs.push(format!(" Handle to any service implementing [`{trait_name}`]."));
+    }
+
+    let methods = item
+        .items
+        .iter()
+        .filter_map(|x| match x {
+            TraitItem::Fn(x) => Some(Method::new(x)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut vtable_fields = Vec::new();
+    let mut vtable_shims = Vec::new();
+    let mut vtable_inits = Vec::new();
+    let mut vtable_inits_synced = Vec::new();
+    let mut trait_impls = Vec::new();
+    let mut dispatchers = Vec::new();
+    let mut dispatcher_idents = Vec::new();
+
+    for method in &methods {
+        let ident = method.ident();
+        let names = &method.names;
+        let types = &method.types;
+        let rval = method.rval();
+        let receiver = method.receiver();
+        let generics = &method.function.sig.generics;
+        let doc_lines = extract_doc_lines(&method.function.attrs);
+        let dispatcher = Ident::new(&format!("{prefix}{ident}"), ident.span());
+
+        let synced = Ident::new(&format!("{ident}_synced"), ident.span());
+
+        let deref_context = if method.is_mut {
+            quote! { &mut *__context.cast_mut().cast::<T>() }
+        } else {
+            quote! { &*__context.cast::<T>() }
+        };
+        let deref_synced = if method.is_mut {
+            quote! { &mut *__context }
+        } else {
+            quote! { &*__context }
+        };
+
+        vtable_fields.push(quote! {
+            #ident: unsafe extern "C-unwind" fn(*const ::std::ffi::c_void, #(#types),*) -> #rval
+        });
+
+        vtable_shims.push(quote! {
+            unsafe extern "C-unwind" fn #ident<T: #trait_ident>(__context: *const ::std::ffi::c_void, #(#names: #types),*) -> #rval {
+                <T as #trait_ident>::#ident(unsafe { #deref_context }, #(#names),*)
+            }
+
+            unsafe extern "C-unwind" fn #synced<T: #trait_ident>(__context: *const ::std::ffi::c_void, #(#names: #types),*) -> #rval {
+                #[allow(unused_imports)]
+                use ::interoptopus::pattern::result::{FallbackAbort as _, FallbackDefault as _, FallbackWrongThread as _};
+
+                let __synced = unsafe {
+                    ::interoptopus::pattern::service::SyncedService::with(__context.cast::<T>(), move |__context| {
+                        <T as #trait_ident>::#ident(unsafe { #deref_synced }, #(#names),*)
+                    })
+                };
+
+                __synced.unwrap_or_else(|| {
+                    ::interoptopus::pattern::result::record_wrong_thread(stringify!(#dispatcher));
+                    (&&&::interoptopus::pattern::result::Fallback::<#rval>::new()).wrong_thread()
+                })
+            }
+        });
+
+        vtable_inits.push(quote! { #ident: #vtable::#ident::<T> });
+        vtable_inits_synced.push(quote! { #ident: #vtable::#synced::<T> });
+
+        trait_impls.push(quote! {
+            fn #ident #generics(#receiver, #(#names: #types),*) -> #rval {
+                assert!(!self.context.is_null() && !self.vtable.is_null(), "Called `{}` on a null `{}`.", stringify!(#ident), stringify!(#handle));
+                let __vtable = unsafe { &*self.vtable.cast::<#vtable>() };
+                unsafe { (__vtable.#ident)(self.context, #(#names),*) }
+            }
+        });
+
+        let handle_arg = if method.is_mut {
+            quote! { &mut __handle }
+        } else {
+            quote! { &__handle }
+        };
+        let handle_param = if method.is_mut {
+            quote! { mut __handle: #handle }
+        } else {
+            quote! { __handle: #handle }
+        };
+
+        let body = quote! {
+            #[allow(unused_imports)]
+            use ::interoptopus::pattern::result::{FallbackAbort as _, FallbackDefault as _, FallbackResult as _, RecordError as _, RecordErrorFallback as _};
+
+            if __handle.context.is_null() || __handle.vtable.is_null() {
+                ::interoptopus::pattern::result::record_null(stringify!(#dispatcher));
+                return (&&&::interoptopus::pattern::result::Fallback::<#rval>::new()).null();
+            }
+
+            let __rval = match ::interoptopus::pattern::result::catch_unwind(|| <#handle as #trait_ident>::#ident(#handle_arg, #(#names),*)) {
+                Ok(__x) => __x,
+                Err(__e) => {
+                    ::interoptopus::pattern::result::record_panic(stringify!(#dispatcher), __e.as_ref());
+                    return (&&&::interoptopus::pattern::result::Fallback::<#rval>::new()).panicked();
+                }
+            };
+
+            (&::interoptopus::pattern::result::ErrorRecorder(stringify!(#dispatcher), &__rval)).record();
+            __rval
+        };
+
+        dispatchers.push(quote! {
+            #[::interoptopus::ffi_function(namespace = #namespace, on_panic = "abort")]
+            #[allow(clippy::needless_lifetimes, clippy::needless_pass_by_value, clippy::needless_pass_by_ref_mut, clippy::let_unit_value)]
+            #(
+                #[doc = #doc_lines]
+            )*
+            #vis fn #dispatcher #generics(#handle_param, #(#names: #types),*) -> #rval {
+                #body
+            }
+        });
+
+        dispatcher_idents.push(dispatcher);
+    }
+
+    let rval = quote! {
+        #input
+
+        #[::interoptopus::ffi_type(namespace = #namespace)]
+        #(
+            #[doc = #handle_docs]
+        )*
+        #vis struct #handle {
+            context: *const ::std::ffi::c_void,
+            vtable: *const ::std::ffi::c_void,
+        }
+
+        #[doc(hidden)]
+        #[repr(C)]
+        #[allow(non_camel_case_types)]
+        struct #vtable {
+            #(#vtable_fields),*
+        }
+
+        #[allow(non_camel_case_types)]
+        impl #vtable {
+            #(#vtable_shims)*
+        }
+
+        impl #handle {
+            /// Creates a handle for the given service instance.
+            ///
+            /// # Safety
+            ///
+            /// The `service` pointer must be valid for as long as the handle is used. If the handle
+            /// is used to invoke `&mut self` methods, the pointer must also be valid for writes, and
+            /// nothing else, including other handles to the same service, may access it meanwhile.
+            ///
+            /// A null `service` is allowed; the generated FFI functions then return a fallback value
+            /// (e.g., `Result::Null`), while calling trait methods on the handle directly panics.
+            #vis unsafe fn from_raw<T: #trait_ident>(service: *const T) -> Self {
+                let vtable: &'static #vtable = const { &#vtable { #(#vtable_inits),* } };
+                Self { context: service.cast(), vtable: ::std::ptr::from_ref(vtable).cast() }
+            }
+
+            /// Creates a handle for a service instance created with `#[ffi_service(sync = "...")]`,
+            /// which goes through the same synchronization as the service's own methods.
+            ///
+            /// # Safety
+            ///
+            /// The `service` pointer must point into a live `SyncedService<T>`, otherwise as [`Self::from_raw`].
+            /// If a thread-affine service is called from another thread, methods returning an `ffi::Result`
+            /// return an `Err` converted from `WrongThread`, others their default value, or abort.
+            #[doc(hidden)]
+            #vis unsafe fn from_synced<T: #trait_ident>(service: *const T) -> Self {
+                let vtable: &'static #vtable = const { &#vtable { #(#vtable_inits_synced),* } };
+                Self { context: service.cast(), vtable: ::std::ptr::from_ref(vtable).cast() }
+            }
+        }
+
+        impl #trait_ident for #handle {
+            #(#trait_impls)*
+        }
+
+        #(#dispatchers)*
+
+        impl ::interoptopus::pattern::service::ServiceInterfaceInfo for dyn #trait_ident {
+            type Handle = #handle;
+
+            fn interface_info() -> ::interoptopus::pattern::service::ServiceInterface {
+                use ::interoptopus::lang::FunctionInfo;
+                use ::interoptopus::lang::TypeInfo;
+
+                let mut methods = ::std::vec::Vec::new();
+
+                #(
+                    {
+                        use #dispatcher_idents as x;
+                        methods.push(x::function_info());
+                    }
+                )*
+
+                let handle = <#handle as TypeInfo>::type_info().as_composite_type().expect("Service handle must be a composite.").clone();
+
+                ::interoptopus::pattern::service::ServiceInterface::new(#trait_name.to_string(), #prefix.to_string(), handle, methods)
+            }
+        }
+    };
+
+    if attributes.debug {
+        prettyprint_tokenstream(&rval);
+    }
+
+    rval
+}
@@ -1,13 +1,17 @@
 use crate::macros::darling_parse;
-use crate::service::function_impl::{generate_service_dtor, generate_service_method};
-use crate::util::{get_type_name, pascal_to_snake_case, prettyprint_tokenstream};
+use crate::service::function_impl::{Descriptor, generate_service_dtor, generate_service_method, has_async_methods};
+use crate::service::interface::generate_service_as_functions;
+use crate::util::{get_type_name, pascal_to_snake_case, prettyprint_tokenstream, std_result_ffi_type};
 use darling::FromMeta;
+use darling::util::PathList;
 use function_impl::MethodType;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{ImplItem, ItemImpl, ReturnType, Visibility};
 
+pub mod foreign;
 pub mod function_impl;
+pub mod interface;
 
 #[derive(Debug, FromMeta)]
 pub struct Attributes {
@@ -16,6 +20,31 @@ pub struct Attributes {
 
     #[darling(default)]
     prefix: String,
+
+    #[darling(default)]
+    pub implements: PathList,
+
+    #[darling(default)]
+    pub sync: SyncMode,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, FromMeta)]
+pub enum SyncMode {
+    #[default]
+    None,
+    Mutex,
+    ThreadAffine,
+}
+
+impl SyncMode {
+    /// The matching `ServiceSync` variant.
+    pub fn to_tokens(self) -> TokenStream {
+        match self {
+            Self::None => quote! { ::interoptopus::pattern::service::ServiceSync::None },
+            Self::Mutex => quote! { ::interoptopus::pattern::service::ServiceSync::Mutex },
+            Self::ThreadAffine => quote! { ::interoptopus::pattern::service::ServiceSync::ThreadAffine },
+        }
+    }
 }
 
 impl Attributes {
@@ -29,11 +58,14 @@ impl Attributes {
     }
 }
 
-#[allow(clippy::match_wildcard_for_single_variants)]
+#[allow(clippy::match_wildcard_for_single_variants, clippy::too_many_lines)]
 pub fn ffi_service(attr: TokenStream, input: &TokenStream) -> TokenStream {
     let attributes = darling_parse!(Attributes, attr);
     let item = syn::parse2::<ItemImpl>(input.clone()).expect("Must be item.");
     let service_type = &item.self_ty;
+
+    assert!(attributes.sync == SyncMode::None || !has_async_methods(&item), "Services with `async` methods must not specify `sync`.");
+
     let mut function_descriptors = Vec::new();
     let mut rval = None;
 
@@ -43,9 +75,10 @@ pub fn ffi_service(attr: TokenStream, input: &TokenStream) -> TokenStream {
             && let Some(descriptor) = generate_service_method(&attributes, &item, method)
         {
             if matches!(descriptor.method_type, MethodType::Constructor) {
-                let rval_type = match &method.sig.output {
-                    ReturnType::Type(_, b) => b.to_token_stream(),
-                    _ => panic!("Must have return value"),
+                let rval_type = match (&method.sig.output, std_result_ffi_type(&method.sig.output, None)) {
+                    (_, Some(x)) => x,
+                    (ReturnType::Type(_, b), None) => b.to_token_stream(),
+                    (ReturnType::Default, None) => panic!("Must have return value"),
                 };
                 rval = Some(rval_type);
             }
@@ -61,6 +94,12 @@ pub fn ffi_service(attr: TokenStream, input: &TokenStream) -> TokenStream {
         .filter(|x| matches!(x.method_type, MethodType::MethodSync(_) | MethodType::MethodAsync(_)))
         .map(|x| x.ident.clone())
         .collect::<Vec<_>>();
+    let ffi_static_idents = function_descriptors
+        .iter()
+        .filter(|x| matches!(x.method_type, MethodType::Static(_)))
+        .map(|x| x.ident.clone())
+        .collect::<Vec<_>>();
+    let ffi_properties = generate_service_properties(&function_descriptors);
     let ffi_ctors = function_descriptors
         .iter()
         .filter(|x| matches!(x.method_type, MethodType::Constructor))
@@ -70,6 +109,11 @@ pub fn ffi_service(attr: TokenStream, input: &TokenStream) -> TokenStream {
     let ffi_dtor_quote = &ffi_dtor.ffi_function_tokens;
     let ffi_dtor_ident = &ffi_dtor.ident;
 
+    let (ffi_as_functions, ffi_as_idents) = generate_service_as_functions(&attributes, &item);
+    let ffi_as_traits = attributes.implements.iter().collect::<Vec<_>>();
+
+    let sync = attributes.sync.to_tokens();
+
     let lifetimes = item.generics.lifetimes();
     let lt = quote! { #(#lifetimes),* };
 
@@ -82,6 +126,10 @@ pub fn ffi_service(attr: TokenStream, input: &TokenStream) -> TokenStream {
 
         #ffi_dtor_quote
 
+        #(
+            #ffi_as_functions
+        )*
+
         impl <#lt> ::interoptopus::pattern::service::ServiceInfo for #service_type {
             type CtorResult = #rval;
         }
@@ -110,14 +158,37 @@ pub fn ffi_service(attr: TokenStream, input: &TokenStream) -> TokenStream {
                     }
                 )*
 
+                let mut static_methods = ::std::vec::Vec::new();
+
+                #(
+                    {
+                        use #ffi_static_idents as x;
+                        static_methods.push(x::function_info());
+                    }
+                )*
+
                 let dtor = {
                     use #ffi_dtor_ident as x;
                     x::function_info()
                 };
 
+                let mut interfaces = ::std::vec::Vec::new();
+
+                #(
+                    {
+                        use #ffi_as_idents as x;
+                        let interface = <dyn #ffi_as_traits as ::interoptopus::pattern::service::ServiceInterfaceInfo>::interface_info();
+                        interfaces.push(::interoptopus::pattern::service::ServiceInterfaceImpl::new(interface, x::function_info()));
+                    }
+                )*
+
+                let mut properties = ::std::vec::Vec::new();
+
+                #ffi_properties
+
                 let service = ::interoptopus::pattern::service::ServiceDefinition::new(
                     ctors, dtor, methods,
-                );
+                ).with_static_methods(static_methods).with_interfaces(interfaces).with_properties(properties).with_sync(#sync);
 
                 service.assert_valid();
 
@@ -132,3 +203,34 @@ pub fn ffi_service(attr: TokenStream, input: &TokenStream) -> TokenStream {
 
     rval
 }
+
+/// Generates one `properties.push(...)` per method marked as `getter` or `setter`.
+fn generate_service_properties(function_descriptors: &[Descriptor]) -> TokenStream {
+    let mut names = Vec::new();
+    let mut idents = Vec::new();
+    let mut accessors = Vec::new();
+
+    for descriptor in function_descriptors {
+        if let MethodType::MethodSync(x) = &descriptor.method_type {
+            if let Some(name) = &x.getter {
+                names.push(name.clone());
+                idents.push(descriptor.ident.clone());
+                accessors.push(quote! { Some(x::function_info()), None });
+            }
+            if let Some(name) = &x.setter {
+                names.push(name.clone());
+                idents.push(descriptor.ident.clone());
+                accessors.push(quote! { None, Some(x::function_info()) });
+            }
+        }
+    }
+
+    quote! {
+        #(
+            {
+                use #idents as x;
+                properties.push(::interoptopus::pattern::service::ServiceProperty::new(#names.to_string(), #accessors));
+            }
+        )*
+    }
+}
@@ -239,8 +239,15 @@ fn process_enum_variants(item: &ItemEnum, attributes: &Attributes, name_ident: &
 }
 
 /// Setup the repr attribute for the enum
+///
+/// With `repr(C, u32)` enums carrying data are laid out as a `u32` tag followed by a union of all
+/// payloads, which is what backends emit. Fieldless enums only take `repr(u32)`.
 fn setup_repr_attribute(item: &mut ItemEnum) {
-    let attr_align = quote! { #[repr(u32) ]};
+    let attr_align = if item.variants.iter().any(|x| !x.fields.is_empty()) {
+        quote! { #[repr(C, u32)] }
+    } else {
+        quote! { #[repr(u32)] }
+    };
 
     if item.attrs.iter().any(|attr| attr.path().is_ident("repr")) {
         panic!("Since 0.15 you must not add any `#[repr()] attributes to your enum; Interoptopus will handle that for you.");
@@ -1,6 +1,6 @@
 use crate::types::TypeRepresentation::Opaque;
 use crate::types::{Attributes, TypeRepresentation};
-use crate::util::extract_doc_lines;
+use crate::util::{extract_doc_lines, ffi_char_type, ffi_tuple_type};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::spanned::Spanned;
@@ -116,6 +116,33 @@ pub fn ffi_type_struct(attributes: &Attributes, _input: TokenStream, mut item: I
         param_where = quote! { where #(#generic_where_tokens),*  };
     }
 
+    // Tuples have no stable layout, but we must not change the declared type of a field behind the
+    // user's back, so they have to spell out the `ffi::TupleN` themselves.
+    if !attributes.wired && type_repr != Opaque {
+        for field in &item.fields {
+            let Type::Tuple(x) = &field.ty else { continue };
+
+            match ffi_tuple_type(&field.ty) {
+                Ok(Some(_)) => {
+                    let msg = format!("Tuples have no stable layout, use `ffi::Tuple{}<...>` for this field instead.", x.elems.len());
+                    return syn::Error::new_spanned(x, msg).to_compile_error();
+                }
+                Ok(None) => {}
+                Err(e) => return e.to_compile_error(),
+            }
+        }
+    }
+
+    // Other languages can store any `u32` in a `char`, so fields must use `ffi::Char` instead.
+    if !attributes.wired && type_repr != Opaque {
+        for field in &item.fields {
+            if ffi_char_type(&field.ty).is_some() {
+                let msg = "A `char` can be sent invalid values, use `ffi::Char` for this field instead.";
+                return syn::Error::new_spanned(&field.ty, msg).to_compile_error();
+            }
+        }
+    }
+
     for (i, field) in item.fields.iter().enumerate() {
         let name = field.ident.as_ref().map_or_else(|| format!("x{i}"), ToString::to_string);
 
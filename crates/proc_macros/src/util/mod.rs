@@ -5,5 +5,5 @@ mod types;
 
 pub use debug::prettyprint_tokenstream;
 pub use docs::extract_doc_lines;
-pub use strings::pascal_to_snake_case;
-pub use types::{ReplaceSelf, get_type_name, purge_lifetimes_from_type};
+pub use strings::{pascal_to_snake_case, snake_to_pascal_case};
+pub use types::{ReplaceSelf, ffi_char_type, ffi_tuple_type, get_type_name, purge_lifetimes_from_type, std_result_ffi_type};
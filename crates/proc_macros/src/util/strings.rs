@@ -19,3 +19,13 @@ pub fn pascal_to_snake_case(s: &str) -> String {
     }
     result
 }
+
+pub fn snake_to_pascal_case(s: &str) -> String {
+    s.split('_')
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            let mut chars = x.chars();
+            chars.next().map(|c| c.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+        })
+        .collect()
+}
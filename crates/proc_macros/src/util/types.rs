@@ -1,7 +1,8 @@
 use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
 use syn::punctuated::Punctuated;
 use syn::visit_mut::{VisitMut, visit_type_path_mut};
-use syn::{GenericArgument, ItemImpl, PathArguments, Type, TypePath};
+use syn::{GenericArgument, ItemImpl, Path, PathArguments, ReturnType, Type, TypePath};
 
 /// A type visitor that replaces all occurrences of `Self` in type paths with a
 /// specified replacement type.
@@ -67,10 +68,10 @@ pub fn purge_lifetimes_from_type(the_type: &Type) -> Type {
         }
         Type::Reference(x) => {
             x.lifetime = None;
-            x.elem = Box::new(purge_lifetimes_from_type(&x.elem));
+            *x.elem = purge_lifetimes_from_type(&x.elem);
         }
-        Type::Ptr(x) => x.elem = Box::new(purge_lifetimes_from_type(&x.elem)),
-        Type::Group(x) => x.elem = Box::new(purge_lifetimes_from_type(&x.elem)),
+        Type::Ptr(x) => *x.elem = purge_lifetimes_from_type(&x.elem),
+        Type::Group(x) => *x.elem = purge_lifetimes_from_type(&x.elem),
         _ => {}
     }
 
@@ -90,3 +91,62 @@ pub fn get_type_name(impl_block: &ItemImpl) -> Option<String> {
         _ => None, // Handle other types accordingly
     }
 }
+
+/// If `output` is a `std::result::Result<T, E>`, or any result if `error` is given, returns the
+/// `ffi::Result<T, F>` the generated FFI shim returns instead, with `F` being `error` or `E`.
+pub fn std_result_ffi_type(output: &ReturnType, error: Option<&Path>) -> Option<TokenStream> {
+    let ReturnType::Type(_, ty) = output else {
+        assert!(error.is_none(), "Specifying an `error` requires returning a `Result`.");
+        return None;
+    };
+
+    if error.is_none() && !is_std_result(ty) {
+        return None;
+    }
+
+    let error = error.map_or_else(|| quote! { <#ty as ::interoptopus::pattern::result::StdResult>::Err }, ToTokens::to_token_stream);
+
+    Some(quote! { ::interoptopus::pattern::result::Result<<#ty as ::interoptopus::pattern::result::StdResult>::Ok, #error> })
+}
+
+/// Checks if the type is spelled `std::result::Result<...>` or `core::result::Result<...>`.
+fn is_std_result(the_type: &Type) -> bool {
+    let Type::Path(x) = the_type else {
+        return false;
+    };
+
+    let segments = x.path.segments.iter().map(|x| x.ident.to_string()).collect::<Vec<_>>();
+
+    matches!(segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice(), ["std" | "core", "result", "Result"])
+}
+
+/// If `the_type` is a non-empty tuple `(A, B, ...)`, returns the `ffi::TupleN<A, B, ...>` it is
+/// lowered to at the FFI boundary, since Rust tuples have no stable layout.
+///
+/// Fails for tuples with an arity we have no `ffi::TupleN` for.
+pub fn ffi_tuple_type(the_type: &Type) -> syn::Result<Option<Type>> {
+    let Type::Tuple(x) = the_type else {
+        return Ok(None);
+    };
+
+    // Keep in sync with `interoptopus::pattern::tuple::MAX_ARITY`.
+    match x.elems.len() {
+        0 => Ok(None),
+        2..=6 => {
+            let ident = format_ident!("Tuple{}", x.elems.len());
+            let elems = x.elems.iter();
+            Ok(Some(syn::parse_quote!(::interoptopus::ffi::#ident<#(#elems),*>)))
+        }
+        _ => Err(syn::Error::new_spanned(x, "Only tuples of 2 to 6 elements are supported at the FFI boundary.")),
+    }
+}
+
+/// If `the_type` is a `char`, returns the `ffi::Char` it is lowered to at the FFI boundary, since
+/// the other side might send values that aren't valid Unicode scalar values.
+pub fn ffi_char_type(the_type: &Type) -> Option<Type> {
+    let Type::Path(x) = the_type else {
+        return None;
+    };
+
+    (x.qself.is_none() && x.path.is_ident("char")).then(|| syn::parse_quote!(::interoptopus::ffi::Char))
+}
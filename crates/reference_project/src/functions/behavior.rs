@@ -1,8 +1,11 @@
 use crate::patterns::result::Error;
-use interoptopus::pattern::result::{panic_to_result, result_to_ffi};
+use interoptopus::pattern::result::{LastError, panic_to_result, result_to_ffi, set_panic_callback};
 use interoptopus::{ffi, ffi_function};
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::Duration;
 
+static PANIC_CALLBACKS: AtomicU32 = AtomicU32::new(0);
+
 #[ffi_function]
 #[allow(unreachable_code)]
 pub fn behavior_panics() {
@@ -18,6 +21,32 @@ pub fn behavior_panics_via_result() -> ffi::Result<(), Error> {
     })
 }
 
+#[ffi_function(on_panic = "ffi_error")]
+#[allow(unreachable_code)]
+pub fn behavior_panics_ffi_error() -> ffi::Result<u32, Error> {
+    panic!("Oh no");
+}
+
+#[ffi_function(on_panic = "return_default")]
+#[allow(unreachable_code)]
+pub fn behavior_panics_return_default() -> u32 {
+    panic!("Oh no");
+}
+
+#[ffi_function(on_panic = "callback")]
+#[allow(unreachable_code)]
+pub fn behavior_panics_callback() -> u32 {
+    panic!("Oh no");
+}
+
+#[ffi_function]
+pub fn behavior_panic_callbacks() -> u32 {
+    set_panic_callback(|_: &LastError| {
+        PANIC_CALLBACKS.fetch_add(1, Ordering::Relaxed);
+    });
+    PANIC_CALLBACKS.load(Ordering::Relaxed)
+}
+
 #[ffi_function]
 pub fn behavior_sleep(millis: u64) {
     std::thread::sleep(Duration::from_millis(millis));
@@ -1,4 +1,4 @@
-use crate::types::aliases::{FnPtrCharArray, FnPtru8u8};
+use crate::types::aliases::{FnPtrCharArray, FnPtrSum8, FnPtru8u8};
 use crate::types::arrays::CharArray;
 use interoptopus::ffi_function;
 
@@ -11,3 +11,8 @@ pub fn fnptr_1(callback: FnPtru8u8, x: u8) -> u8 {
 pub fn fnptr_2(callback: FnPtrCharArray, x: CharArray) {
     callback(x)
 }
+
+#[ffi_function]
+pub fn fnptr_3(callback: FnPtrSum8) -> u32 {
+    callback(1, 2, 3, 4, 5, 6, 7, 8)
+}
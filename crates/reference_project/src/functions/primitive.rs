@@ -1,4 +1,5 @@
 use interoptopus::ffi_function;
+use std::num::NonZeroU32;
 
 #[ffi_function]
 pub fn primitive_void() {}
@@ -72,6 +73,26 @@ pub fn primitive_isize(x: isize) -> isize {
     -x
 }
 
+#[ffi_function]
+pub fn primitive_u128(x: u128) -> u128 {
+    u128::MAX - x
+}
+
+#[ffi_function]
+pub fn primitive_i128(x: i128) -> i128 {
+    -x
+}
+
+#[ffi_function(on_panic = "return_default")]
+pub fn primitive_char(x: char) -> char {
+    x.to_uppercase().next().unwrap_or(x)
+}
+
+#[ffi_function]
+pub fn primitive_nonzero(x: Option<NonZeroU32>) -> Option<NonZeroU32> {
+    x.and_then(|x| x.checked_add(1))
+}
+
 #[ffi_function]
 pub fn primitive_args_5(x0: i64, x1: i64, x2: i64, x3: i64, x4: i64) -> i64 {
     x0 + x1 + x2 + x3 + x4
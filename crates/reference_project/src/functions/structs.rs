@@ -1,6 +1,7 @@
 use crate::patterns::result::Error;
 use crate::types::basic::{Tupled, Vec3f32};
 use crate::types::bool::BoolField;
+use crate::types::num::WideNum;
 use interoptopus::ffi_function;
 use interoptopus::pattern::result::Result;
 
@@ -18,3 +19,13 @@ pub fn struct2(_a: Vec3f32, _b: Option<&Tupled>) -> Result<(), Error> {
 pub fn struct3(x: BoolField) -> bool {
     x.val
 }
+
+#[ffi_function]
+pub fn struct4(x: WideNum) -> WideNum {
+    WideNum {
+        id: x.id + 1,
+        delta: -x.delta,
+        initial: x.initial.checked_into_char().map_or(x.initial, |c| c.to_ascii_uppercase().into()),
+        handle: x.handle.and_then(|x| x.checked_add(1)),
+    }
+}
@@ -8,7 +8,7 @@
 //! is generated is part of the test.
 
 use interoptopus::inventory::Inventory;
-use interoptopus::{builtins_string, builtins_vec, builtins_wire, constant, extra_type, ffi, function, pattern};
+use interoptopus::{builtins_alloc, builtins_iter, builtins_map, builtins_string, builtins_vec, builtins_wire, constant, extra_type, ffi, function, pattern};
 
 pub mod constants;
 pub mod functions;
@@ -17,12 +17,17 @@ pub mod patterns {
     // pub mod api_entry;
     pub mod api_guard;
     pub mod callback;
+    pub mod duration;
+    pub mod iter;
+    pub mod map;
+    pub mod ndslice;
     pub mod option;
     pub mod primitive;
     pub mod result;
     pub mod slice;
     pub mod string;
     pub mod surrogate;
+    pub mod tuple;
     pub mod vec;
 }
 pub mod services {
@@ -38,12 +43,17 @@ pub mod services {
     pub mod basic;
     pub mod callback;
     pub mod dependent;
+    pub mod foreign;
     pub mod ignored;
+    pub mod interface;
     pub mod multiple_ctors;
     pub mod on_panic;
+    pub mod property;
     pub mod result;
     pub mod slice;
+    pub mod statics;
     pub mod string;
+    pub mod sync;
 }
 
 pub mod types;
@@ -54,10 +64,14 @@ pub fn ffi_inventory() -> Inventory {
         // Functions
         .register(builtins_string!())
         .register(builtins_wire!())
+        .register(builtins_alloc!())
         .register(builtins_vec!(u8))
         .register(builtins_vec!(ffi::String))
         .register(builtins_vec!(types::basic::Vec3f32))
         .register(builtins_vec!(types::enums::EnumPayload))
+        .register(builtins_map!(u32, types::basic::Vec3f32))
+        .register(builtins_iter!(u32))
+        .register(builtins_iter!(types::basic::Vec3f32))
         .register(function!(functions::alignment::alignment_1))
         .register(function!(functions::array::array_1))
         .register(function!(functions::array::array_2))
@@ -71,12 +85,17 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(functions::behavior::behavior_sleep))
         .register(function!(functions::behavior::behavior_panics))
         .register(function!(functions::behavior::behavior_panics_via_result))
+        .register(function!(functions::behavior::behavior_panics_ffi_error))
+        .register(function!(functions::behavior::behavior_panics_return_default))
+        .register(function!(functions::behavior::behavior_panics_callback))
+        .register(function!(functions::behavior::behavior_panic_callbacks))
         .register(function!(functions::enums::enums_1))
         .register(function!(functions::enums::enums_2))
         .register(function!(functions::enums::enums_3))
         .register(function!(functions::enums::enums_4))
         .register(function!(functions::fnptrs::fnptr_1))
         .register(function!(functions::fnptrs::fnptr_2))
+        .register(function!(functions::fnptrs::fnptr_3))
         .register(function!(functions::generic::generic_1a))
         .register(function!(functions::generic::generic_1b))
         .register(function!(functions::generic::generic_1c))
@@ -109,6 +128,10 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(functions::primitive::primitive_usize))
         .register(function!(functions::primitive::primitive_isize))
         .register(function!(functions::primitive::primitive_u8))
+        .register(function!(functions::primitive::primitive_u128))
+        .register(function!(functions::primitive::primitive_i128))
+        .register(function!(functions::primitive::primitive_char))
+        .register(function!(functions::primitive::primitive_nonzero))
         .register(function!(functions::primitive::primitive_void))
         .register(function!(functions::primitive::primitive_void2))
         .register(function!(functions::ptrs::ptr1))
@@ -124,6 +147,7 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(functions::structs::struct1))
         .register(function!(functions::structs::struct2))
         .register(function!(functions::structs::struct3))
+        .register(function!(functions::structs::struct4))
         .register(function!(patterns::string::pattern_ascii_pointer_1))
         .register(function!(patterns::string::pattern_ascii_pointer_2))
         .register(function!(patterns::string::pattern_ascii_pointer_3))
@@ -142,6 +166,11 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(patterns::string::pattern_string_9))
         .register(function!(patterns::string::pattern_string_10))
         .register(function!(patterns::string::pattern_string_11))
+        .register(function!(patterns::string::pattern_str_1))
+        .register(function!(patterns::string::pattern_str_2))
+        .register(function!(patterns::string::pattern_str_3))
+        .register(function!(patterns::string::pattern_str_4))
+        .register(function!(patterns::string::pattern_str_5))
         .register(function!(patterns::slice::pattern_ffi_slice_1))
         .register(function!(patterns::slice::pattern_ffi_slice_1b))
         .register(function!(patterns::slice::pattern_ffi_slice_2))
@@ -154,6 +183,9 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(patterns::slice::pattern_ffi_slice_9))
         .register(function!(patterns::slice::pattern_ffi_slice_delegate))
         .register(function!(patterns::slice::pattern_ffi_slice_delegate_huge))
+        .register(function!(patterns::ndslice::pattern_ndslice_1))
+        .register(function!(patterns::ndslice::pattern_ndslice_2))
+        .register(function!(patterns::ndslice::pattern_ndslice_3))
         .register(function!(patterns::option::pattern_ffi_option_1))
         .register(function!(patterns::option::pattern_ffi_option_2))
         .register(function!(patterns::option::pattern_ffi_option_3))
@@ -165,6 +197,8 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(patterns::result::pattern_result_2))
         .register(function!(patterns::result::pattern_result_3))
         .register(function!(patterns::result::pattern_result_4))
+        .register(function!(patterns::result::pattern_result_5))
+        .register(function!(patterns::result::pattern_result_6))
         .register(function!(patterns::api_guard::pattern_api_guard))
         .register(function!(patterns::callback::pattern_callback_1))
         .register(function!(patterns::callback::pattern_callback_2))
@@ -174,6 +208,12 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(patterns::callback::pattern_callback_6))
         .register(function!(patterns::callback::pattern_callback_7))
         .register(function!(patterns::callback::pattern_callback_8))
+        .register(function!(patterns::callback::pattern_callback_9))
+        .register(function!(patterns::callback::pattern_callback_10))
+        .register(function!(patterns::callback::pattern_callback_11))
+        .register(function!(patterns::callback::pattern_callback_12))
+        .register(function!(patterns::callback::pattern_callback_13))
+        .register(function!(patterns::callback::pattern_callback_14))
         .register(function!(patterns::surrogate::pattern_surrogates_1))
         .register(function!(patterns::vec::pattern_vec_1))
         .register(function!(patterns::vec::pattern_vec_2))
@@ -183,6 +223,23 @@ pub fn ffi_inventory() -> Inventory {
         .register(function!(patterns::vec::pattern_vec_6))
         .register(function!(patterns::vec::pattern_vec_7))
         .register(function!(patterns::vec::pattern_vec_8))
+        .register(function!(patterns::map::pattern_map_1))
+        .register(function!(patterns::map::pattern_map_2))
+        .register(function!(patterns::map::pattern_map_3))
+        .register(function!(patterns::iter::pattern_iter_1))
+        .register(function!(patterns::iter::pattern_iter_2))
+        .register(function!(patterns::iter::pattern_iter_3))
+        .register(function!(patterns::iter::pattern_iter_4))
+        .register(function!(patterns::tuple::pattern_tuple_1))
+        .register(function!(patterns::tuple::pattern_tuple_2))
+        .register(function!(patterns::tuple::pattern_tuple_3))
+        .register(function!(patterns::tuple::pattern_tuple_4))
+        .register(function!(patterns::tuple::pattern_tuple_5))
+        .register(function!(patterns::duration::pattern_duration_1))
+        .register(function!(patterns::duration::pattern_duration_2))
+        .register(function!(patterns::duration::pattern_duration_3))
+        .register(function!(services::interface::pattern_service_interface_1))
+        .register(function!(services::foreign::pattern_foreign_trait_1))
         // TODO
         // .register(function!(wire::basic::wire_accept_string_1))
         .register(function!(wire::basic::wire_accept_string_2))
@@ -215,6 +272,15 @@ pub fn ffi_inventory() -> Inventory {
         .register(pattern!(services::multiple_ctors::ServiceMultipleCtors))
         .register(pattern!(services::slice::ServiceVariousSlices))
         .register(pattern!(services::string::ServiceStrings))
+        .register(pattern!(services::interface::ServiceStorageArray))
+        .register(pattern!(services::interface::ServiceStorageOffset))
+        .register(pattern!(dyn services::interface::Storage))
+        .register(pattern!(services::foreign::ServiceForeignLogger))
+        .register(pattern!(services::foreign::LogSinkVTable))
+        .register(pattern!(services::property::ServiceProperties))
+        .register(pattern!(services::statics::ServiceStatics))
+        .register(pattern!(services::sync::ServiceThreadAffine))
+        .register(pattern!(services::sync::ServiceMutex))
         .validate()
         .build()
 }
@@ -1,9 +1,11 @@
 use crate::patterns::result::Error;
 use crate::types::string::UseString;
 use interoptopus::lang::NAMESPACE_COMMON;
+use interoptopus::pattern::callback::CallbackError;
 use interoptopus::{callback, ffi, ffi_function, ffi_type};
 use std::ffi::c_void;
 use std::ptr::null;
+use std::sync::Mutex;
 
 callback!(MyCallback(value: u32) -> u32);
 callback!(MyCallbackNamespaced(value: u32) -> u32, namespace = NAMESPACE_COMMON);
@@ -15,6 +17,11 @@ callback!(SumDelegateReturn(x: i32, y: i32) -> ffi::Result<(), Error>);
 callback!(SumDelegateReturn2(x: i32, y: i32));
 callback!(StringCallback(s: ffi::String));
 callback!(NestedStringCallback(s: UseString));
+callback!(owned MyOwnedCallback(value: u32) -> u32);
+callback!(MyFallibleCallback(value: u32) -> ffi::Result<u32, Error>);
+callback!(OnItem<T>(value: T) -> u8);
+
+static STORED_CALLBACK: Mutex<Option<MyOwnedCallback>> = Mutex::new(None);
 
 #[ffi_type]
 pub struct DelegateCallback<C> {
@@ -78,6 +85,43 @@ pub fn pattern_callback_8(cb: StringCallback, cb2: NestedStringCallback, s: ffi:
     cb2.call(UseString { s1: s.clone(), s2: s.clone() });
 }
 
+#[ffi_function]
+pub fn pattern_callback_9(callback: MyOwnedCallback, x: u32) -> u32 {
+    callback.call(x) // `callback` is dropped here, invoking its destructor.
+}
+
+/// Keeps the callback until replaced, dropping the previous one.
+#[ffi_function]
+pub fn pattern_callback_10(callback: MyOwnedCallback) {
+    *STORED_CALLBACK.lock().unwrap() = Some(callback);
+}
+
+/// Invokes the callback stored by `pattern_callback_10`, returns 0 if there is none.
+#[ffi_function]
+pub fn pattern_callback_11(x: u32) -> u32 {
+    STORED_CALLBACK.lock().unwrap().as_ref().map_or(0, |c| c.call(x))
+}
+
+/// Returns the callback's result plus one, passing on errors and failures of the callback.
+#[ffi_function]
+pub fn pattern_callback_12(callback: MyFallibleCallback, x: u32) -> ffi::Result<u32, Error> {
+    match callback.call_checked(x) {
+        Ok(x) => ffi::Ok(x + 1),
+        Err(CallbackError::Err(e)) => ffi::Err(e),
+        Err(_) => ffi::Result::Panic,
+    }
+}
+
+#[ffi_function]
+pub fn pattern_callback_13(callback: OnItem<u32>, x: u32) -> u8 {
+    callback.call(x)
+}
+
+#[ffi_function]
+pub fn pattern_callback_14(callback: OnItem<f32>, x: f32) -> u8 {
+    callback.call(x)
+}
+
 pub extern "C" fn exposed_sum1(x: *const c_void) {
     println!("0x{x:?}");
     eprintln!("0x{x:?}");
@@ -89,7 +133,7 @@ pub extern "C" fn exposed_sum2(x: i32, y: i32, _: *const c_void) -> i32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{MyCallback, MyCallbackNamespaced};
+    use super::{MyCallback, MyCallbackNamespaced, OnItem};
     use interoptopus::lang::{NAMESPACE_COMMON, TypeInfo};
 
     #[test]
@@ -100,4 +144,10 @@ mod tests {
         assert_eq!(ti1.namespace(), Some(""));
         assert_eq!(ti2.namespace(), Some(NAMESPACE_COMMON));
     }
+
+    #[test]
+    fn generic_callbacks_named_per_instance() {
+        assert_eq!(OnItem::<u32>::type_info().name_within_lib(), "OnItemU32");
+        assert_eq!(OnItem::<f32>::type_info().name_within_lib(), "OnItemF32");
+    }
 }
@@ -0,0 +1,24 @@
+use interoptopus::{ffi, ffi_function, ffi_type};
+use std::time::Duration;
+
+#[ffi_type]
+pub struct Timeout {
+    pub after: ffi::Duration,
+    pub retries: u32,
+}
+
+#[ffi_function]
+pub fn pattern_duration_1(x: ffi::Duration) -> ffi::Duration {
+    x.checked_into_duration().and_then(|x| x.checked_mul(2)).unwrap_or_default().into()
+}
+
+#[ffi_function]
+pub fn pattern_duration_2(x: Timeout) -> ffi::Duration {
+    let retries = x.retries.saturating_add(1);
+    x.after.checked_into_duration().and_then(|x| x.checked_mul(retries)).unwrap_or_default().into()
+}
+
+#[ffi_function]
+pub fn pattern_duration_3() -> ffi::Duration {
+    Duration::from_nanos(1_500_000_001).into()
+}
@@ -0,0 +1,25 @@
+use crate::types::basic::Vec3f32;
+use interoptopus::{ffi, ffi_function};
+
+#[ffi_function]
+pub fn pattern_iter_1(n: u32) -> ffi::Iter<u32> {
+    ffi::Iter::new(0..n)
+}
+
+#[ffi_function]
+pub fn pattern_iter_2() -> ffi::Iter<Vec3f32> {
+    ffi::Iter::new((1..=3).map(|i| {
+        let f = i as f32;
+        Vec3f32 { x: f, y: f, z: f }
+    }))
+}
+
+#[ffi_function]
+pub fn pattern_iter_3(iter: ffi::Iter<u32>) -> u32 {
+    iter.sum()
+}
+
+#[ffi_function]
+pub fn pattern_iter_4(n: u32) -> ffi::Iter<u32> {
+    ffi::Iter::new((0..=n).map(move |i| if i == n { panic!("iterator panicked") } else { i }))
+}
@@ -0,0 +1,22 @@
+use crate::types::basic::Vec3f32;
+use interoptopus::{ffi, ffi_function};
+
+#[ffi_function]
+pub fn pattern_map_1() -> ffi::Map<u32, Vec3f32> {
+    (1..=3)
+        .map(|i| {
+            let f = i as f32;
+            (i, Vec3f32 { x: f, y: f, z: f })
+        })
+        .collect()
+}
+
+#[ffi_function]
+pub fn pattern_map_2(m: ffi::Map<u32, Vec3f32>, key: u32) -> ffi::Option<Vec3f32> {
+    m.get(&key).copied().into()
+}
+
+#[ffi_function]
+pub fn pattern_map_3(m: &ffi::Map<u32, Vec3f32>) -> ffi::Map<u32, Vec3f32> {
+    m.clone()
+}
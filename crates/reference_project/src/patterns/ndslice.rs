@@ -0,0 +1,45 @@
+use interoptopus::{ffi, ffi_function};
+
+/// Sums all elements, regardless of the number of dimensions or their strides.
+#[ffi_function]
+pub fn pattern_ndslice_1(slice: ffi::NdSlice<f32>) -> f32 {
+    let mut sum = 0.0;
+    let mut index = vec![0; slice.shape().len()];
+
+    if slice.is_empty() {
+        return sum;
+    }
+
+    'outer: loop {
+        sum += slice.get(&index).copied().unwrap_or_default();
+
+        // Advance the last index, carrying over into earlier dimensions.
+        for d in (0..index.len()).rev() {
+            index[d] += 1;
+            if index[d] < slice.shape()[d] as usize {
+                continue 'outer;
+            }
+            index[d] = 0;
+        }
+
+        return sum;
+    }
+}
+
+#[ffi_function]
+pub fn pattern_ndslice_2(slice: ffi::NdSlice<i32>, row: u64, col: u64) -> i32 {
+    slice.get(&[row as usize, col as usize]).copied().unwrap_or(-1)
+}
+
+/// Sets each element of a 2D slice to `row * 100 + col`.
+#[ffi_function]
+pub fn pattern_ndslice_3(mut slice: ffi::NdSliceMut<u32>) {
+    let [rows, cols] = slice.shape() else { return };
+    let (rows, cols) = (*rows as usize, *cols as usize);
+
+    for row in 0..rows {
+        for col in 0..cols {
+            slice[[row, col]] = (row * 100 + col) as u32;
+        }
+    }
+}
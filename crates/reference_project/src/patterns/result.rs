@@ -9,6 +9,20 @@ pub enum Error {
     Fail,
 }
 
+// An internal error that is not FFI-safe, but can be converted into one.
+#[derive(Debug)]
+pub struct ParseError(pub String);
+
+impl From<ParseError> for Error {
+    fn from(_: ParseError) -> Self {
+        Self::Fail
+    }
+}
+
+pub fn parse(x: u32) -> std::result::Result<u32, ParseError> {
+    if x > 100 { Err(ParseError(format!("{x} is too large"))) } else { Ok(x) }
+}
+
 #[ffi_function]
 pub fn pattern_result_1(x: Result<u32, Error>) -> Result<u32, Error> {
     x
@@ -28,3 +42,13 @@ pub fn pattern_result_3(x: Result<(), Error>) -> Result<(), Error> {
 pub fn pattern_result_4(x: Result<(), ()>) -> Result<(), ()> {
     x
 }
+
+#[ffi_function]
+pub fn pattern_result_5(x: u32) -> std::result::Result<u32, Error> {
+    if x > 100 { Err(Error::Fail) } else { Ok(x * 2) }
+}
+
+#[ffi_function(error = Error)]
+pub fn pattern_result_6(x: u32) -> std::result::Result<u32, ParseError> {
+    Ok(parse(x)? * 2)
+}
@@ -1,5 +1,5 @@
 use crate::patterns::result::Error;
-use crate::types::string::{UseCStrPtr, UseString};
+use crate::types::string::{UseCStrPtr, UseStr, UseString};
 use interoptopus::ffi::{CStrPtr, Result, Slice};
 use interoptopus::{ffi, ffi_function};
 
@@ -104,3 +104,31 @@ pub fn pattern_string_10(_: ffi::String) {}
 
 #[ffi_function]
 pub fn pattern_string_11(_: &ffi::String) {}
+
+#[ffi_function]
+pub fn pattern_str_1(x: ffi::Str) -> u32 {
+    x.len() as u32
+}
+
+#[ffi_function]
+pub fn pattern_str_2() -> ffi::Str<'static> {
+    ffi::Str::from_str("hello world")
+}
+
+#[ffi_function]
+pub fn pattern_str_3(x: ffi::Str) -> ffi::Str {
+    x
+}
+
+#[ffi_function]
+pub fn pattern_str_4(x: UseStr) -> u32 {
+    (x.s1.len() + x.s2.len()) as u32
+}
+
+#[ffi_function]
+pub fn pattern_str_5(x: ffi::Str) -> ffi::Result<u32, Error> {
+    match x.as_str() {
+        Ok(s) => Result::Ok(s.chars().count() as u32),
+        Err(_) => Result::Err(Error::Fail),
+    }
+}
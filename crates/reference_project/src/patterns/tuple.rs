@@ -0,0 +1,37 @@
+use crate::types::basic::Vec3f32;
+use interoptopus::{ffi, ffi_function, ffi_type};
+
+#[ffi_type]
+pub struct Tuples {
+    pub pair: ffi::Tuple2<u32, f32>,
+    pub triple: ffi::Tuple3<Vec3f32, u8, i64>,
+}
+
+#[ffi_function]
+pub fn pattern_tuple_1(x: (u32, f32)) -> (f32, u32) {
+    (x.1, x.0)
+}
+
+#[ffi_function]
+pub fn pattern_tuple_2(mut x: (Vec3f32, u8, i64)) -> (Vec3f32, u8, i64) {
+    x.0.x += 1.0;
+    x.1 += 1;
+    x.2 += 1;
+    x
+}
+
+#[ffi_function]
+pub fn pattern_tuple_3(x: Tuples) -> Tuples {
+    Tuples { pair: (x.pair.0 * 2, x.pair.1 * 2.0).into(), triple: (x.triple.0, x.triple.1, x.triple.2 * 2).into() }
+}
+
+#[ffi_function]
+pub fn pattern_tuple_4(x: ffi::Option<ffi::Tuple2<u32, u32>>) -> u32 {
+    x.into_option().map_or(0, |x| x.0 + x.1)
+}
+
+#[ffi_function]
+pub fn pattern_tuple_5((a, mut b): (u32, u32)) -> u32 {
+    b += 1;
+    a * b
+}
@@ -0,0 +1,38 @@
+use crate::patterns::result::Error;
+use interoptopus::{ffi, ffi_foreign_trait, ffi_function, ffi_service, ffi_type};
+
+/// A sink implemented in a foreign language receiving log messages.
+#[ffi_foreign_trait]
+pub trait LogSink {
+    /// Called for each logged value.
+    fn log(&self, level: u32, x: u32);
+
+    /// Returns how many values the sink has seen.
+    fn count(&self) -> u32;
+}
+
+/// Logs the given values to a foreign sink and returns what the sink counted.
+#[ffi_function]
+pub fn pattern_foreign_trait_1(sink: LogSinkVTable, x: u32) -> u32 {
+    sink.log(1, x);
+    sink.log(2, x + 1);
+    sink.count()
+}
+
+/// Service holding on to a foreign implementation.
+#[ffi_type(opaque)]
+pub struct ServiceForeignLogger {
+    sink: LogSinkVTable,
+}
+
+#[ffi_service]
+impl ServiceForeignLogger {
+    pub fn new(sink: LogSinkVTable) -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { sink })
+    }
+
+    pub fn log(&self, x: u32) -> ffi::Result<(), Error> {
+        self.sink.log(0, x);
+        ffi::Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::patterns::result::Error;
+use interoptopus::{ffi, ffi_function, ffi_service, ffi_service_method, ffi_service_trait, ffi_type};
+
+/// Some key-value storage shared by multiple services.
+#[ffi_service_trait]
+pub trait Storage {
+    /// Returns the value stored at `key`.
+    fn get(&self, key: u32) -> u32;
+
+    /// Stores `val` at `key`.
+    fn set(&mut self, key: u32, val: u32);
+}
+
+/// Storage backed by a fixed size array.
+#[ffi_type(opaque)]
+pub struct ServiceStorageArray {
+    data: [u32; 16],
+}
+
+#[ffi_service(implements(Storage))]
+impl ServiceStorageArray {
+    pub fn new() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { data: [0; 16] })
+    }
+}
+
+impl Storage for ServiceStorageArray {
+    fn get(&self, key: u32) -> u32 {
+        self.data[key as usize % self.data.len()]
+    }
+
+    fn set(&mut self, key: u32, val: u32) {
+        let len = self.data.len();
+        self.data[key as usize % len] = val;
+    }
+}
+
+/// Storage adding a fixed offset to all values it returns.
+#[ffi_type(opaque)]
+pub struct ServiceStorageOffset {
+    offset: u32,
+    data: Vec<(u32, u32)>,
+}
+
+#[ffi_service(implements(Storage))]
+impl ServiceStorageOffset {
+    pub fn new(offset: u32) -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { offset, data: Vec::new() })
+    }
+
+    /// Services can provide interface methods themselves.
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn get(&self, key: u32) -> u32 {
+        Storage::get(self, key)
+    }
+
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+}
+
+impl Storage for ServiceStorageOffset {
+    fn get(&self, key: u32) -> u32 {
+        let value = self.data.iter().find(|(k, _)| *k == key).map_or(0, |(_, v)| *v);
+        value + self.offset
+    }
+
+    fn set(&mut self, key: u32, val: u32) {
+        self.data.retain(|(k, _)| *k != key);
+        self.data.push((key, val));
+    }
+}
+
+/// Works with any service implementing `Storage`.
+#[ffi_function]
+pub fn pattern_service_interface_1(mut storage: DynStorage, key: u32, val: u32) -> u32 {
+    storage.set(key, val);
+    storage.get(key)
+}
@@ -25,4 +25,8 @@ impl ServiceMultipleCtors {
     pub fn new_failing(_some_value: u8) -> ffi::Result<Self, Error> {
         ffi::Err(Error::Fail)
     }
+
+    pub fn new_std(some_value: u32) -> std::result::Result<Self, Error> {
+        if some_value > 100 { Err(Error::Fail) } else { Ok(Self { data: vec![some_value] }) }
+    }
 }
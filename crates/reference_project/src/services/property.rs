@@ -0,0 +1,33 @@
+use crate::patterns::result::Error;
+use interoptopus::{ffi, ffi_service, ffi_service_method, ffi_type};
+
+/// Service exposing some of its methods as properties.
+#[ffi_type(opaque)]
+pub struct ServiceProperties {
+    value: u32,
+    updates: u32,
+}
+
+#[ffi_service]
+impl ServiceProperties {
+    pub fn new() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { value: 0, updates: 0 })
+    }
+
+    #[ffi_service_method(getter = "Value", on_panic = "return_default")]
+    pub fn get_value(&self) -> u32 {
+        self.value
+    }
+
+    #[ffi_service_method(setter = "Value", on_panic = "return_default")]
+    pub fn set_value(&mut self, x: u32) {
+        self.value = x;
+        self.updates += 1;
+    }
+
+    /// Properties can also be read-only.
+    #[ffi_service_method(getter = "Updates", on_panic = "return_default")]
+    pub fn get_updates(&self) -> u32 {
+        self.updates
+    }
+}
@@ -1,6 +1,6 @@
-use crate::patterns::result::Error;
+use crate::patterns::result::{Error, ParseError, parse};
 use crate::types::enums::EnumPayload;
-use interoptopus::{ffi, ffi_service, ffi_type};
+use interoptopus::{ffi, ffi_service, ffi_service_method, ffi_type};
 
 #[ffi_type(opaque)]
 pub struct ServiceResult {}
@@ -30,4 +30,13 @@ impl ServiceResult {
     pub fn result_slice(&self, slice: ffi::Slice<u32>, i: u64) -> ffi::Result<u32, Error> {
         ffi::Ok(slice[i as usize])
     }
+
+    pub fn result_std(&self, x: u32) -> std::result::Result<u32, Error> {
+        if x > 100 { Err(Error::Fail) } else { Ok(x) }
+    }
+
+    #[ffi_service_method(error = Error)]
+    pub fn result_std_mapped(&self, x: u32) -> std::result::Result<u32, ParseError> {
+        Ok(parse(x)? + 1)
+    }
 }
@@ -63,4 +63,16 @@ impl ServiceVariousSlices {
     pub fn return_slice_mut(&mut self) -> ffi::SliceMut<'_, u32> {
         ffi::SliceMut::from_slice(&mut self.data)
     }
+
+    /// Hands out a copy of the data lazily, so the returned iterator stays valid even if the
+    /// service is used again.
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn return_iter(&self) -> ffi::Iter<u32> {
+        ffi::Iter::new(self.data.clone())
+    }
+
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn return_len_and_first(&self) -> (u64, u32) {
+        (self.data.len() as u64, self.data.first().copied().unwrap_or_default())
+    }
 }
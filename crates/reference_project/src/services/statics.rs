@@ -0,0 +1,32 @@
+use crate::patterns::result::Error;
+use interoptopus::{ffi, ffi_service, ffi_service_ctor, ffi_service_method, ffi_type};
+
+/// Service with static helpers next to its constructor.
+#[ffi_type(opaque)]
+pub struct ServiceStatics {
+    scale: u32,
+}
+
+#[ffi_service]
+impl ServiceStatics {
+    #[ffi_service_ctor]
+    pub fn new_with(scale: u32) -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { scale })
+    }
+
+    /// The scale used by default, not tied to any instance.
+    #[ffi_service_method(static)]
+    pub fn default_scale() -> u32 {
+        10
+    }
+
+    #[ffi_service_method(static)]
+    pub fn scaled(x: u32, scale: u32) -> u32 {
+        x * scale
+    }
+
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn scale(&self, x: u32) -> u32 {
+        Self::scaled(x, self.scale)
+    }
+}
@@ -23,6 +23,10 @@ impl ServiceStrings {
         ffi::CStrPtr::empty()
     }
 
+    pub fn pass_str(&self, s: ffi::Str) -> u32 {
+        s.len() as u32
+    }
+
     pub fn callback_string(&self, s: ffi::String, cb: StringCallback) {
         cb.call(s.clone());
     }
@@ -0,0 +1,63 @@
+use crate::patterns::result::Error;
+use interoptopus::pattern::service::WrongThread;
+use interoptopus::{ffi, ffi_service, ffi_service_method, ffi_type};
+
+/// Errors of [`ServiceThreadAffine`].
+#[ffi_type]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SyncError {
+    WrongThread,
+}
+
+impl From<WrongThread> for SyncError {
+    fn from(_: WrongThread) -> Self {
+        Self::WrongThread
+    }
+}
+
+/// Service guarded against use from other threads.
+#[ffi_type(opaque)]
+pub struct ServiceThreadAffine {
+    value: u32,
+}
+
+#[ffi_service(sync = "thread_affine")]
+impl ServiceThreadAffine {
+    pub fn new() -> ffi::Result<Self, SyncError> {
+        ffi::Ok(Self { value: 0 })
+    }
+
+    pub fn increment(&mut self) -> ffi::Result<(), SyncError> {
+        self.value += 1;
+        ffi::Ok(())
+    }
+
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+/// Service guarded against concurrent use.
+#[ffi_type(opaque)]
+pub struct ServiceMutex {
+    value: u32,
+}
+
+#[ffi_service(sync = "mutex")]
+impl ServiceMutex {
+    pub fn new() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { value: 0 })
+    }
+
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn increment(&mut self) -> u32 {
+        self.value += 1;
+        self.value
+    }
+
+    #[ffi_service_method(on_panic = "return_default")]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
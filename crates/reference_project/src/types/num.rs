@@ -1,4 +1,5 @@
-use interoptopus::ffi_type;
+use interoptopus::{ffi, ffi_type};
+use std::num::NonZeroU32;
 
 #[ffi_type(transparent)]
 pub struct TransparentNum(usize);
@@ -16,3 +17,11 @@ pub enum EnumNum {
     B,
     C(isize),
 }
+
+#[ffi_type]
+pub struct WideNum {
+    pub id: u128,
+    pub delta: i128,
+    pub initial: ffi::Char,
+    pub handle: Option<NonZeroU32>,
+}
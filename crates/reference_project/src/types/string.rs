@@ -5,6 +5,12 @@ pub struct UseCStrPtr<'a> {
     pub ascii_string: ffi::CStrPtr<'a>,
 }
 
+#[ffi_type]
+pub struct UseStr<'a> {
+    pub s1: ffi::Str<'a>,
+    pub s2: ffi::Str<'a>,
+}
+
 #[ffi_type]
 #[derive(Clone)]
 pub struct UseString {
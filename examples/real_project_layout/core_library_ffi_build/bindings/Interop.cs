@@ -3,7 +3,7 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      core_library
-// Hash:         0xacad08a5c1feb91e
+// Hash:         0xb28ade4e3ac3572b
 // Namespace:    
 // Builder:      interoptopus_backend_csharp (0.15.0-alpha.21)
 //
@@ -78,11 +78,9 @@ namespace My.Company
     public partial struct Error 
     {
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -114,20 +112,13 @@ namespace My.Company
 
         public bool IsFail => _variant == 0;
 
-        public void AsFail() { if (_variant != 0) throw ExceptionForVariant(); }
+        public void AsFail() { if (_variant != 0) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Fail";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(Error), MarshalMode.Default, typeof(Marshaller))]
@@ -161,6 +152,33 @@ namespace My.Company
         }
     }
 
+    /// Thrown when a function returns `Error` as its error.
+    public class ErrorException : InteropException
+    {
+        public Error Error { get; }
+
+        public ErrorException(Error error) : this(error, error.ToString()) { }
+
+        public ErrorException(Error error, string message) : base(message)
+        {
+            Error = error;
+        }
+
+        /// Creates the exception matching the error's variant, including details about the last error in Rust.
+        public static ErrorException FromError(Error error)
+        {
+            var lastError = Utf8String.LastError();
+            var message = string.IsNullOrEmpty(lastError) ? error.ToString() : $"{error}: {lastError}";
+            if (error.IsFail) return new ErrorFailException(error, message);
+            return new ErrorException(error, message);
+        }
+    }
+
+    public class ErrorFailException : ErrorException
+    {
+        public ErrorFailException(Error error, string message) : base(error, message) { }
+    }
+
     public partial struct Vec2
     {
         public float x;
@@ -378,6 +396,7 @@ namespace My.Company
             writer.Write(this.field);
 
 
+
         }
 
         /// <summary>
@@ -829,42 +848,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultConstPtrGameEngineError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrGameEngineError ToManaged()
             {
                 var _managed = new ResultConstPtrGameEngineError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -874,8 +880,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -884,8 +890,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -899,10 +905,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -911,17 +920,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrGameEngineError), MarshalMode.Default, typeof(Marshaller))]
@@ -955,6 +954,34 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrGameEngineError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
     public partial struct ResultError
     {
@@ -965,32 +992,26 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultError 
     {
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultError ToManaged()
             {
                 var _managed = new ResultError();
                 _managed._variant = _variant;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -1000,7 +1021,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -1009,7 +1030,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -1023,10 +1044,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public void AsOk() { if (_variant != 0) throw ExceptionForVariant(); }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public void AsOk() { if (_variant != 0) throw ToException(); }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -1035,17 +1059,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultError), MarshalMode.Default, typeof(Marshaller))]
@@ -1079,6 +1093,16 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Unwrap() => AsOk();
+
+        public TResult Match<TResult>(Func<TResult> ok, Func<Error, TResult> err) => IsOk ? ok() : IsErr ? err(_Err) : throw ToException();
+    }
+    #nullable restore
+
 
     public partial class GameEngine : IDisposable
     {
@@ -1118,15 +1142,23 @@ namespace My.Company
 
 
 
+    public class InteropException : Exception
+    {
+        public InteropException() : base()
+        {
+        }
 
-    public class EnumException() : InteropException($"Enum variant mismatch.")  { }
+        public InteropException(string message) : base(message)
+        {
+        }
 
-    public class EnumException<T>(T t) : InteropException($"Enum variant mismatch.")
-    {
-        public T Value { get; } = t;
+        /// Creates an exception for an unexpected result, including details about the last error or panic in Rust.
+        public static InteropException FromLastError(string result)
+        {
+            var lastError = Utf8String.LastError();
+            return string.IsNullOrEmpty(lastError) ? new InteropException(result) : new InteropException($"{result}: {lastError}");
+        }
     }
-
-    public class InteropException(string text) : Exception(text) { }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -1227,23 +1259,33 @@ namespace My.Company
     {
         private Utf8String() { }
 
+        // Hands the UTF-16 contents of `s` to Rust, which converts them without an intermediate copy here. Like
+        // `Encoding.UTF8`, unpaired surrogates become U+FFFD.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static unsafe Utf8String From(string s)
         {
-            var rval = new Utf8String();
-            var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+            fixed (char* p = s)
+            {
+                InteropHelper.interoptopus_string_create_utf16((IntPtr)p, (ulong)s.Length, out var native);
+                return native.IntoManaged();
+            }
+        }
 
-            fixed (byte* p = utf8Bytes)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String FromUtf8(ReadOnlySpan<byte> utf8)
+        {
+            fixed (byte* p = utf8)
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                var status = InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)utf8.Length, out var native);
+                if (status != 0) { throw new InteropException("Bytes are not valid UTF-8."); }
+                return native.IntoManaged();
             }
+        }
 
-            return rval;
+        /// Returns and clears details about the last error or panic in Rust on this thread, or an empty string.
+        public static string LastError()
+        {
+            return string.Empty;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -1341,6 +1383,11 @@ namespace My.Company
             
             public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
 
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, out Unmanaged rval);
+
             [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_destroy")]
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
@@ -38,6 +38,12 @@ typedef enum ERROR
     ERROR_FAIL = 0,
     } ERROR;
 
+///  Errors of [`ServiceThreadAffine`].
+typedef enum SYNCERROR
+    {
+    SYNCERROR_WRONGTHREAD = 0,
+    } SYNCERROR;
+
 typedef struct GENERIC2U8 GENERIC2U8;
 
 typedef struct GENERIC3 GENERIC3;
@@ -61,6 +67,9 @@ typedef struct SERVICECALLBACKS SERVICECALLBACKS;
 
 typedef struct SERVICEDEPENDENT SERVICEDEPENDENT;
 
+///  Service holding on to a foreign implementation.
+typedef struct SERVICEFOREIGNLOGGER SERVICEFOREIGNLOGGER;
+
 typedef struct SERVICEIGNORINGMETHODS SERVICEIGNORINGMETHODS;
 
 typedef struct SERVICEMAIN SERVICEMAIN;
@@ -68,14 +77,32 @@ typedef struct SERVICEMAIN SERVICEMAIN;
 ///  Some struct we want to expose as a class.
 typedef struct SERVICEMULTIPLECTORS SERVICEMULTIPLECTORS;
 
+///  Service guarded against concurrent use.
+typedef struct SERVICEMUTEX SERVICEMUTEX;
+
 ///  Some struct we want to expose as a class.
 typedef struct SERVICEONPANIC SERVICEONPANIC;
 
+///  Service exposing some of its methods as properties.
+typedef struct SERVICEPROPERTIES SERVICEPROPERTIES;
+
 typedef struct SERVICERESULT SERVICERESULT;
 
+///  Service with static helpers next to its constructor.
+typedef struct SERVICESTATICS SERVICESTATICS;
+
+///  Storage backed by a fixed size array.
+typedef struct SERVICESTORAGEARRAY SERVICESTORAGEARRAY;
+
+///  Storage adding a fixed offset to all values it returns.
+typedef struct SERVICESTORAGEOFFSET SERVICESTORAGEOFFSET;
+
 ///  Some struct we want to expose as a class.
 typedef struct SERVICESTRINGS SERVICESTRINGS;
 
+///  Service guarded against use from other threads.
+typedef struct SERVICETHREADAFFINE SERVICETHREADAFFINE;
+
 ///  Some struct we want to expose as a class.
 typedef struct SERVICEVARIOUSSLICES SERVICEVARIOUSSLICES;
 
@@ -92,11 +119,36 @@ typedef struct UTF8STRING
     uint64_t capacity;
     } UTF8STRING;
 
-typedef enum ENUMNUM
+///  Borrowed UTF-8 string, `len` bytes starting at `ptr`, not `0x0` terminated.
+///  Neither side owns the data, it must outlive the call (or struct) it is passed to.
+typedef struct UTF8STR
+    {
+    const uint8_t* ptr;
+    uint64_t len;
+    } UTF8STR;
+
+///  A span of time, `nanos` is always below one second.
+typedef struct DURATION
+    {
+    uint64_t secs;
+    uint32_t nanos;
+    } DURATION;
+
+typedef enum ENUMNUMTAG
     {
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    ENUMNUM_A = 0,
     ENUMNUM_B = 1,
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    ENUMNUM_C = 2,
+    } ENUMNUMTAG;
+
+typedef struct ENUMNUM
+    {
+    uint32_t tag; // ENUMNUMTAG
+    union
+        {
+        size_t a;
+        ptrdiff_t c;
+        } payload;
     } ENUMNUM;
 
 typedef struct BOOLFIELD
@@ -155,6 +207,12 @@ typedef struct STRUCTRENAMED
     ENUMRENAMED e;
     } STRUCTRENAMED;
 
+typedef struct TIMEOUT
+    {
+    DURATION after;
+    uint32_t retries;
+    } TIMEOUT;
+
 typedef struct TUPLED
     {
     uint8_t x0;
@@ -165,6 +223,12 @@ typedef struct USECSTRPTR
     const char* ascii_string;
     } USECSTRPTR;
 
+typedef struct USESTR
+    {
+    UTF8STR s1;
+    UTF8STR s2;
+    } USESTR;
+
 typedef struct USESTRING
     {
     UTF8STRING s1;
@@ -213,8 +277,18 @@ typedef struct WEIRD1U32
     uint32_t x;
     } WEIRD1U32;
 
+typedef struct WIDENUM
+    {
+    unsigned __int128 id;
+    __int128 delta;
+    uint32_t initial;
+    uint32_t handle;
+    } WIDENUM;
+
 typedef uint8_t (*fptr_fn_u8_rval_u8)(uint8_t x0);
 
+typedef uint32_t (*fptr_fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32)(uint32_t x0, uint32_t x1, uint32_t x2, uint32_t x3, uint32_t x4, uint32_t x5, uint32_t x6, uint32_t x7);
+
 /// A pointer to an array of data someone else owns which may not be modified.
 typedef struct SLICEBOOL
     {
@@ -278,65 +352,181 @@ typedef struct SLICEMUTU8
     uint64_t len;
     } SLICEMUTU8;
 
-/// Option that contains Some(value) or None.
-typedef enum OPTIONUTF8STRING
+/// A multi-dimensional view into data someone else owns which may not be modified.
+typedef struct NDSLICEF32
+    {
+    /// Pointer to the first element.
+    const float* data;
+    /// Number of dimensions in use.
+    uint64_t ndim;
+    /// Extent of each dimension.
+    uint64_t shape[4];
+    /// Distance between neighbouring elements of each dimension, in elements.
+    int64_t strides[4];
+    } NDSLICEF32;
+
+/// A multi-dimensional view into data someone else owns which may not be modified.
+typedef struct NDSLICEI32
+    {
+    /// Pointer to the first element.
+    const int32_t* data;
+    /// Number of dimensions in use.
+    uint64_t ndim;
+    /// Extent of each dimension.
+    uint64_t shape[4];
+    /// Distance between neighbouring elements of each dimension, in elements.
+    int64_t strides[4];
+    } NDSLICEI32;
+
+/// A multi-dimensional view into data someone else owns which may be modified.
+typedef struct NDSLICEMUTU32
+    {
+    /// Pointer to the first element.
+    uint32_t* data;
+    /// Number of dimensions in use.
+    uint64_t ndim;
+    /// Extent of each dimension.
+    uint64_t shape[4];
+    /// Distance between neighbouring elements of each dimension, in elements.
+    int64_t strides[4];
+    } NDSLICEMUTU32;
+
+typedef enum OPTIONUTF8STRINGTAG
     {
     /// Element if Some().
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    OPTIONUTF8STRING_SOME = 0,
     OPTIONUTF8STRING_NONE = 1,
+    } OPTIONUTF8STRINGTAG;
+
+/// Option that contains Some(value) or None.
+typedef struct OPTIONUTF8STRING
+    {
+    uint32_t tag; // OPTIONUTF8STRINGTAG
+    union
+        {
+        /// Element if Some().
+        UTF8STRING some;
+        } payload;
     } OPTIONUTF8STRING;
 
-/// Result that contains value or an error.
-typedef enum RESULTERROR
+typedef enum RESULTERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTERROR_ERR = 1,
     RESULTERROR_PANIC = 2,
     RESULTERROR_NULL = 3,
+    } RESULTERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTERROR
+    {
+    uint32_t tag; // RESULTERRORTAG
+    union
+        {
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTERROR;
 
+typedef enum RESULTSYNCERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTSYNCERROR_OK = 0,
+    /// Error value.
+    RESULTSYNCERROR_ERR = 1,
+    RESULTSYNCERROR_PANIC = 2,
+    RESULTSYNCERROR_NULL = 3,
+    } RESULTSYNCERRORTAG;
+
 /// Result that contains value or an error.
-typedef enum RESULTU32ERROR
+typedef struct RESULTSYNCERROR
+    {
+    uint32_t tag; // RESULTSYNCERRORTAG
+    union
+        {
+        /// Error value.
+        SYNCERROR err;
+        } payload;
+    } RESULTSYNCERROR;
+
+typedef enum RESULTU32ERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTU32ERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTU32ERROR_ERR = 1,
     RESULTU32ERROR_PANIC = 2,
     RESULTU32ERROR_NULL = 3,
-    } RESULTU32ERROR;
+    } RESULTU32ERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTU64ERROR
+typedef struct RESULTU32ERROR
+    {
+    uint32_t tag; // RESULTU32ERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        uint32_t ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTU32ERROR;
+
+typedef enum RESULTU64ERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTU64ERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTU64ERROR_ERR = 1,
     RESULTU64ERROR_PANIC = 2,
     RESULTU64ERROR_NULL = 3,
-    } RESULTU64ERROR;
+    } RESULTU64ERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTUTF8STRINGERROR
+typedef struct RESULTU64ERROR
+    {
+    uint32_t tag; // RESULTU64ERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        uint64_t ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTU64ERROR;
+
+typedef enum RESULTUTF8STRINGERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTUTF8STRINGERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTUTF8STRINGERROR_ERR = 1,
     RESULTUTF8STRINGERROR_PANIC = 2,
     RESULTUTF8STRINGERROR_NULL = 3,
+    } RESULTUTF8STRINGERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTUTF8STRINGERROR
+    {
+    uint32_t tag; // RESULTUTF8STRINGERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        UTF8STRING ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTUTF8STRINGERROR;
 
 /// Result that contains value or an error.
 typedef enum RESULTVOID
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTVOID_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTVOID_ERR = 1,
     RESULTVOID_PANIC = 2,
     RESULTVOID_NULL = 3,
     } RESULTVOID;
@@ -367,11 +557,58 @@ typedef struct VECUTF8STRING
     uint64_t capacity;
     } VECUTF8STRING;
 
-typedef enum ENUMPAYLOAD
+///  Iterator marshalling helper.
+///  An opaque, Rust-owned handle that produces items on demand. Like
+///  a Vec it must be freed exactly once, either by the other side or
+///  by passing it back to Rust.
+typedef struct ITERU32
+    {
+    void* ptr;
+    } ITERU32;
+
+///  A tuple lowered to a struct, fields are named after their position.
+typedef struct TUPLE2_F32_U32
+    {
+    float x0;
+    uint32_t x1;
+    } TUPLE2_F32_U32;
+
+///  A tuple lowered to a struct, fields are named after their position.
+typedef struct TUPLE2_U32_F32
+    {
+    uint32_t x0;
+    float x1;
+    } TUPLE2_U32_F32;
+
+///  A tuple lowered to a struct, fields are named after their position.
+typedef struct TUPLE2_U32_U32
+    {
+    uint32_t x0;
+    uint32_t x1;
+    } TUPLE2_U32_U32;
+
+///  A tuple lowered to a struct, fields are named after their position.
+typedef struct TUPLE2_U64_U32
+    {
+    uint64_t x0;
+    uint32_t x1;
+    } TUPLE2_U64_U32;
+
+typedef enum ENUMPAYLOADTAG
     {
     ENUMPAYLOAD_A = 0,
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    ENUMPAYLOAD_B = 1,
+    ENUMPAYLOAD_C = 2,
+    } ENUMPAYLOADTAG;
+
+typedef struct ENUMPAYLOAD
+    {
+    uint32_t tag; // ENUMPAYLOADTAG
+    union
+        {
+        VEC3F32 b;
+        uint32_t c;
+        } payload;
     } ENUMPAYLOAD;
 
 typedef struct ARRAY
@@ -384,6 +621,13 @@ typedef struct CONTAINER
     LOCAL foreign;
     } CONTAINER;
 
+///  Some key-value storage shared by multiple services.
+typedef struct DYNSTORAGE
+    {
+    const void* context;
+    const void* vtable;
+    } DYNSTORAGE;
+
 typedef struct FIXEDSTRING
     {
     uint8_t data[32];
@@ -427,6 +671,10 @@ typedef struct WIREBUFFER
     int32_t capacity;
     } WIREBUFFER;
 
+typedef void* (*fptr_fn_u64_u64_rval_MutPtrVoid)(uint64_t x0, uint64_t x1);
+
+typedef void (*fptr_fn_MutPtrVoid_u64_u64_rval_void)(void* x0, uint64_t x1, uint64_t x2);
+
 /// A pointer to an array of data someone else owns which may not be modified.
 typedef struct SLICEUSECSTRPTR
     {
@@ -472,218 +720,651 @@ typedef struct SLICEMUTVEC
     uint64_t len;
     } SLICEMUTVEC;
 
-/// Option that contains Some(value) or None.
-typedef enum OPTIONINNER
+typedef enum OPTIONINNERTAG
     {
     /// Element if Some().
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    OPTIONINNER_SOME = 0,
     OPTIONINNER_NONE = 1,
+    } OPTIONINNERTAG;
+
+/// Option that contains Some(value) or None.
+typedef struct OPTIONINNER
+    {
+    uint32_t tag; // OPTIONINNERTAG
+    union
+        {
+        /// Element if Some().
+        INNER some;
+        } payload;
     } OPTIONINNER;
 
+typedef enum OPTIONTUPLE2_U32_U32TAG
+    {
+    /// Element if Some().
+    OPTIONTUPLE2_U32_U32_SOME = 0,
+    OPTIONTUPLE2_U32_U32_NONE = 1,
+    } OPTIONTUPLE2_U32_U32TAG;
+
 /// Option that contains Some(value) or None.
-typedef enum OPTIONVEC
+typedef struct OPTIONTUPLE2_U32_U32
+    {
+    uint32_t tag; // OPTIONTUPLE2_U32_U32TAG
+    union
+        {
+        /// Element if Some().
+        TUPLE2_U32_U32 some;
+        } payload;
+    } OPTIONTUPLE2_U32_U32;
+
+typedef enum OPTIONVECTAG
     {
     /// Element if Some().
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    OPTIONVEC_SOME = 0,
     OPTIONVEC_NONE = 1,
+    } OPTIONVECTAG;
+
+/// Option that contains Some(value) or None.
+typedef struct OPTIONVEC
+    {
+    uint32_t tag; // OPTIONVECTAG
+    union
+        {
+        /// Element if Some().
+        VEC some;
+        } payload;
     } OPTIONVEC;
 
-/// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEASYNCBASICERROR
+typedef enum OPTIONVEC3F32TAG
+    {
+    /// Element if Some().
+    OPTIONVEC3F32_SOME = 0,
+    OPTIONVEC3F32_NONE = 1,
+    } OPTIONVEC3F32TAG;
+
+/// Option that contains Some(value) or None.
+typedef struct OPTIONVEC3F32
+    {
+    uint32_t tag; // OPTIONVEC3F32TAG
+    union
+        {
+        /// Element if Some().
+        VEC3F32 some;
+        } payload;
+    } OPTIONVEC3F32;
+
+typedef enum RESULTCONSTPTRSERVICEASYNCBASICERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCBASICERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCBASICERROR_ERR = 1,
     RESULTCONSTPTRSERVICEASYNCBASICERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEASYNCBASICERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEASYNCBASICERROR;
+    } RESULTCONSTPTRSERVICEASYNCBASICERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEASYNCRESULTERROR
+typedef struct RESULTCONSTPTRSERVICEASYNCBASICERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEASYNCBASICERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEASYNCBASIC* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEASYNCBASICERROR;
+
+typedef enum RESULTCONSTPTRSERVICEASYNCRESULTERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCRESULTERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCRESULTERROR_ERR = 1,
     RESULTCONSTPTRSERVICEASYNCRESULTERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEASYNCRESULTERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEASYNCRESULTERROR;
+    } RESULTCONSTPTRSERVICEASYNCRESULTERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEASYNCSLEEPERROR
+typedef struct RESULTCONSTPTRSERVICEASYNCRESULTERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEASYNCRESULTERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEASYNCRESULT* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEASYNCRESULTERROR;
+
+typedef enum RESULTCONSTPTRSERVICEASYNCSLEEPERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCSLEEPERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCSLEEPERROR_ERR = 1,
     RESULTCONSTPTRSERVICEASYNCSLEEPERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEASYNCSLEEPERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEASYNCSLEEPERROR;
+    } RESULTCONSTPTRSERVICEASYNCSLEEPERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR
+typedef struct RESULTCONSTPTRSERVICEASYNCSLEEPERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEASYNCSLEEPERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEASYNCSLEEP* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEASYNCSLEEPERROR;
+
+typedef enum RESULTCONSTPTRSERVICEASYNCSTRUCTSERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR_ERR = 1,
     RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR;
+    } RESULTCONSTPTRSERVICEASYNCSTRUCTSERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR
+typedef struct RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEASYNCSTRUCTSERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEASYNCSTRUCTS* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEASYNCSTRUCTSERROR;
+
+typedef enum RESULTCONSTPTRSERVICEASYNCVECSTRINGERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR_ERR = 1,
     RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR;
+    } RESULTCONSTPTRSERVICEASYNCVECSTRINGERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEBASICERROR
+typedef struct RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEASYNCVECSTRINGERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEASYNCVECSTRING* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEASYNCVECSTRINGERROR;
+
+typedef enum RESULTCONSTPTRSERVICEBASICERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEBASICERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEBASICERROR_ERR = 1,
     RESULTCONSTPTRSERVICEBASICERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEBASICERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEBASICERROR;
+    } RESULTCONSTPTRSERVICEBASICERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICECALLBACKSERROR
+typedef struct RESULTCONSTPTRSERVICEBASICERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEBASICERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEBASIC* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEBASICERROR;
+
+typedef enum RESULTCONSTPTRSERVICECALLBACKSERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICECALLBACKSERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICECALLBACKSERROR_ERR = 1,
     RESULTCONSTPTRSERVICECALLBACKSERROR_PANIC = 2,
     RESULTCONSTPTRSERVICECALLBACKSERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICECALLBACKSERROR;
+    } RESULTCONSTPTRSERVICECALLBACKSERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEDEPENDENTERROR
+typedef struct RESULTCONSTPTRSERVICECALLBACKSERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICECALLBACKSERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICECALLBACKS* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICECALLBACKSERROR;
+
+typedef enum RESULTCONSTPTRSERVICEDEPENDENTERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEDEPENDENTERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEDEPENDENTERROR_ERR = 1,
     RESULTCONSTPTRSERVICEDEPENDENTERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEDEPENDENTERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICEDEPENDENTERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTCONSTPTRSERVICEDEPENDENTERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEDEPENDENTERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEDEPENDENT* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTCONSTPTRSERVICEDEPENDENTERROR;
 
+typedef enum RESULTCONSTPTRSERVICEFOREIGNLOGGERERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR_OK = 0,
+    /// Error value.
+    RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR_ERR = 1,
+    RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR_PANIC = 2,
+    RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICEFOREIGNLOGGERERRORTAG;
+
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR
+typedef struct RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEFOREIGNLOGGERERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEFOREIGNLOGGER* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR;
+
+typedef enum RESULTCONSTPTRSERVICEIGNORINGMETHODSERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR_ERR = 1,
     RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR;
+    } RESULTCONSTPTRSERVICEIGNORINGMETHODSERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEMAINERROR
+typedef struct RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEIGNORINGMETHODSERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEIGNORINGMETHODS* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEIGNORINGMETHODSERROR;
+
+typedef enum RESULTCONSTPTRSERVICEMAINERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEMAINERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEMAINERROR_ERR = 1,
     RESULTCONSTPTRSERVICEMAINERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEMAINERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEMAINERROR;
+    } RESULTCONSTPTRSERVICEMAINERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEMULTIPLECTORSERROR
+typedef struct RESULTCONSTPTRSERVICEMAINERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEMAINERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEMAIN* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEMAINERROR;
+
+typedef enum RESULTCONSTPTRSERVICEMULTIPLECTORSERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEMULTIPLECTORSERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEMULTIPLECTORSERROR_ERR = 1,
     RESULTCONSTPTRSERVICEMULTIPLECTORSERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEMULTIPLECTORSERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICEMULTIPLECTORSERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTCONSTPTRSERVICEMULTIPLECTORSERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEMULTIPLECTORSERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEMULTIPLECTORS* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTCONSTPTRSERVICEMULTIPLECTORSERROR;
 
+typedef enum RESULTCONSTPTRSERVICEMUTEXERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTCONSTPTRSERVICEMUTEXERROR_OK = 0,
+    /// Error value.
+    RESULTCONSTPTRSERVICEMUTEXERROR_ERR = 1,
+    RESULTCONSTPTRSERVICEMUTEXERROR_PANIC = 2,
+    RESULTCONSTPTRSERVICEMUTEXERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICEMUTEXERRORTAG;
+
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEONPANICERROR
+typedef struct RESULTCONSTPTRSERVICEMUTEXERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEMUTEXERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEMUTEX* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEMUTEXERROR;
+
+typedef enum RESULTCONSTPTRSERVICEONPANICERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEONPANICERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEONPANICERROR_ERR = 1,
     RESULTCONSTPTRSERVICEONPANICERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEONPANICERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICEONPANICERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTCONSTPTRSERVICEONPANICERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEONPANICERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEONPANIC* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTCONSTPTRSERVICEONPANICERROR;
 
+typedef enum RESULTCONSTPTRSERVICEPROPERTIESERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTCONSTPTRSERVICEPROPERTIESERROR_OK = 0,
+    /// Error value.
+    RESULTCONSTPTRSERVICEPROPERTIESERROR_ERR = 1,
+    RESULTCONSTPTRSERVICEPROPERTIESERROR_PANIC = 2,
+    RESULTCONSTPTRSERVICEPROPERTIESERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICEPROPERTIESERRORTAG;
+
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICERESULTERROR
+typedef struct RESULTCONSTPTRSERVICEPROPERTIESERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEPROPERTIESERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEPROPERTIES* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEPROPERTIESERROR;
+
+typedef enum RESULTCONSTPTRSERVICERESULTERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICERESULTERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICERESULTERROR_ERR = 1,
     RESULTCONSTPTRSERVICERESULTERROR_PANIC = 2,
     RESULTCONSTPTRSERVICERESULTERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICERESULTERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTCONSTPTRSERVICERESULTERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICERESULTERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICERESULT* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTCONSTPTRSERVICERESULTERROR;
 
+typedef enum RESULTCONSTPTRSERVICESTATICSERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTCONSTPTRSERVICESTATICSERROR_OK = 0,
+    /// Error value.
+    RESULTCONSTPTRSERVICESTATICSERROR_ERR = 1,
+    RESULTCONSTPTRSERVICESTATICSERROR_PANIC = 2,
+    RESULTCONSTPTRSERVICESTATICSERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICESTATICSERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTCONSTPTRSERVICESTATICSERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICESTATICSERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICESTATICS* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICESTATICSERROR;
+
+typedef enum RESULTCONSTPTRSERVICESTORAGEARRAYERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTCONSTPTRSERVICESTORAGEARRAYERROR_OK = 0,
+    /// Error value.
+    RESULTCONSTPTRSERVICESTORAGEARRAYERROR_ERR = 1,
+    RESULTCONSTPTRSERVICESTORAGEARRAYERROR_PANIC = 2,
+    RESULTCONSTPTRSERVICESTORAGEARRAYERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICESTORAGEARRAYERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTCONSTPTRSERVICESTORAGEARRAYERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICESTORAGEARRAYERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICESTORAGEARRAY* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICESTORAGEARRAYERROR;
+
+typedef enum RESULTCONSTPTRSERVICESTORAGEOFFSETERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTCONSTPTRSERVICESTORAGEOFFSETERROR_OK = 0,
+    /// Error value.
+    RESULTCONSTPTRSERVICESTORAGEOFFSETERROR_ERR = 1,
+    RESULTCONSTPTRSERVICESTORAGEOFFSETERROR_PANIC = 2,
+    RESULTCONSTPTRSERVICESTORAGEOFFSETERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICESTORAGEOFFSETERRORTAG;
+
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICESTRINGSERROR
+typedef struct RESULTCONSTPTRSERVICESTORAGEOFFSETERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICESTORAGEOFFSETERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICESTORAGEOFFSET* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICESTORAGEOFFSETERROR;
+
+typedef enum RESULTCONSTPTRSERVICESTRINGSERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICESTRINGSERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICESTRINGSERROR_ERR = 1,
     RESULTCONSTPTRSERVICESTRINGSERROR_PANIC = 2,
     RESULTCONSTPTRSERVICESTRINGSERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICESTRINGSERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTCONSTPTRSERVICESTRINGSERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICESTRINGSERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICESTRINGS* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTCONSTPTRSERVICESTRINGSERROR;
 
+typedef enum RESULTCONSTPTRSERVICETHREADAFFINESYNCERRORTAG
+    {
+    /// Element if err is `Ok`.
+    RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR_OK = 0,
+    /// Error value.
+    RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR_ERR = 1,
+    RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR_PANIC = 2,
+    RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR_NULL = 3,
+    } RESULTCONSTPTRSERVICETHREADAFFINESYNCERRORTAG;
+
 /// Result that contains value or an error.
-typedef enum RESULTCONSTPTRSERVICEVARIOUSSLICESERROR
+typedef struct RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICETHREADAFFINESYNCERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICETHREADAFFINE* ok;
+        /// Error value.
+        SYNCERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR;
+
+typedef enum RESULTCONSTPTRSERVICEVARIOUSSLICESERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEVARIOUSSLICESERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTCONSTPTRSERVICEVARIOUSSLICESERROR_ERR = 1,
     RESULTCONSTPTRSERVICEVARIOUSSLICESERROR_PANIC = 2,
     RESULTCONSTPTRSERVICEVARIOUSSLICESERROR_NULL = 3,
-    } RESULTCONSTPTRSERVICEVARIOUSSLICESERROR;
+    } RESULTCONSTPTRSERVICEVARIOUSSLICESERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTOPTIONUTF8STRINGERROR
+typedef struct RESULTCONSTPTRSERVICEVARIOUSSLICESERROR
+    {
+    uint32_t tag; // RESULTCONSTPTRSERVICEVARIOUSSLICESERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        const SERVICEVARIOUSSLICES* ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTCONSTPTRSERVICEVARIOUSSLICESERROR;
+
+typedef enum RESULTOPTIONUTF8STRINGERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTOPTIONUTF8STRINGERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTOPTIONUTF8STRINGERROR_ERR = 1,
     RESULTOPTIONUTF8STRINGERROR_PANIC = 2,
     RESULTOPTIONUTF8STRINGERROR_NULL = 3,
-    } RESULTOPTIONUTF8STRINGERROR;
+    } RESULTOPTIONUTF8STRINGERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTUSESTRINGERROR
+typedef struct RESULTOPTIONUTF8STRINGERROR
+    {
+    uint32_t tag; // RESULTOPTIONUTF8STRINGERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        OPTIONUTF8STRING ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTOPTIONUTF8STRINGERROR;
+
+typedef enum RESULTUSESTRINGERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTUSESTRINGERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTUSESTRINGERROR_ERR = 1,
     RESULTUSESTRINGERROR_PANIC = 2,
     RESULTUSESTRINGERROR_NULL = 3,
-    } RESULTUSESTRINGERROR;
+    } RESULTUSESTRINGERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTVECUTF8STRINGERROR
+typedef struct RESULTUSESTRINGERROR
+    {
+    uint32_t tag; // RESULTUSESTRINGERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        USESTRING ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTUSESTRINGERROR;
+
+typedef enum RESULTVECUTF8STRINGERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTVECUTF8STRINGERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTVECUTF8STRINGERROR_ERR = 1,
     RESULTVECUTF8STRINGERROR_PANIC = 2,
     RESULTVECUTF8STRINGERROR_NULL = 3,
+    } RESULTVECUTF8STRINGERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTVECUTF8STRINGERROR
+    {
+    uint32_t tag; // RESULTVECUTF8STRINGERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        VECUTF8STRING ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTVECUTF8STRINGERROR;
 
 typedef uint8_t (*CALLBACKFFISLICE)(SLICEU8 SLICE, const void* CALLBACK_DATA);
@@ -692,6 +1373,10 @@ typedef void (*CALLBACKSLICEMUT)(SLICEMUTU8 SLICE, const void* CALLBACK_DATA);
 
 typedef uint8_t (*CALLBACKU8)(uint8_t VALUE, const void* CALLBACK_DATA);
 
+typedef uint32_t (*LOGSINKCOUNT)(const void* CALLBACK_DATA);
+
+typedef void (*LOGSINKLOG)(uint32_t LEVEL, uint32_t X, const void* CALLBACK_DATA);
+
 typedef uint32_t (*MYCALLBACK)(uint32_t VALUE, const void* CALLBACK_DATA);
 
 typedef void (*MYCALLBACKCONTEXTUAL)(const void* CONTEXT, uint32_t VALUE, const void* CALLBACK_DATA);
@@ -700,13 +1385,22 @@ typedef uint32_t (*MYCALLBACKNAMESPACED)(uint32_t VALUE, const void* CALLBACK_DA
 
 typedef void (*MYCALLBACKVOID)(const void* PTR, const void* CALLBACK_DATA);
 
+typedef RESULTU32ERROR (*MYFALLIBLECALLBACK)(uint32_t VALUE, const void* CALLBACK_DATA);
+
+typedef struct MYOWNEDCALLBACK
+    {
+    uint32_t (*callback)(uint32_t VALUE, const void* CALLBACK_DATA);
+    const void* data;
+    void (*destructor)(const void* data);
+    } MYOWNEDCALLBACK;
+
 typedef void (*NESTEDSTRINGCALLBACK)(USESTRING S, const void* CALLBACK_DATA);
 
-typedef void (*POINTERS)(const int32_t* X, int32_t* Y, const void* CALLBACK_DATA);
+typedef uint8_t (*ONITEMF32)(float VALUE, const void* CALLBACK_DATA);
 
-typedef void (*STRINGCALLBACK)(UTF8STRING S, const void* CALLBACK_DATA);
+typedef uint8_t (*ONITEMU32)(uint32_t VALUE, const void* CALLBACK_DATA);
 
-typedef UTF8STRING (*STRINGCALLBACKSTRING)(UTF8STRING S, const void* CALLBACK_DATA);
+typedef void (*STRINGCALLBACK)(UTF8STRING S, const void* CALLBACK_DATA);
 
 typedef void (*SUMDELEGATE1)(const void* CALLBACK_DATA);
 
@@ -729,6 +1423,33 @@ typedef struct VECVEC3F32
     uint64_t capacity;
     } VECVEC3F32;
 
+///  Map marshalling helper, holding `len` keys in ascending order and their values.
+///  Like a Vec this has ownership semantics, once passed over an FFI boundary
+///  'the other side' is meant to own (and free) it.
+typedef struct MAPU32VEC3F32
+    {
+    uint32_t* keys_ptr;
+    VEC3F32* values_ptr;
+    uint64_t len;
+    } MAPU32VEC3F32;
+
+///  Iterator marshalling helper.
+///  An opaque, Rust-owned handle that produces items on demand. Like
+///  a Vec it must be freed exactly once, either by the other side or
+///  by passing it back to Rust.
+typedef struct ITERVEC3F32
+    {
+    void* ptr;
+    } ITERVEC3F32;
+
+///  A tuple lowered to a struct, fields are named after their position.
+typedef struct TUPLE3_VEC3F32_U8_I64
+    {
+    VEC3F32 x0;
+    uint8_t x1;
+    int64_t x2;
+    } TUPLE3_VEC3F32_U8_I64;
+
 typedef struct CALLBACKTABLE
     {
     MYCALLBACK my_callback;
@@ -755,6 +1476,16 @@ typedef struct LAYER2UTF8STRING
     VECUTF8STRING strings;
     } LAYER2UTF8STRING;
 
+///  A sink implemented in a foreign language receiving log messages.
+typedef struct LOGSINKVTABLE
+    {
+    ///  Called for each logged value.
+    LOGSINKLOG log;
+    ///  Returns how many values the sink has seen.
+    LOGSINKCOUNT count;
+    const void* user_data;
+    } LOGSINKVTABLE;
+
 typedef struct NESTEDARRAY
     {
     ENUMRENAMED field_enum;
@@ -766,20 +1497,46 @@ typedef struct NESTEDARRAY
     ARRAY field_struct;
     } NESTEDARRAY;
 
-/// Option that contains Some(value) or None.
-typedef enum OPTIONENUMPAYLOAD
+typedef struct TUPLES
+    {
+    TUPLE2_U32_F32 pair;
+    TUPLE3_VEC3F32_U8_I64 triple;
+    } TUPLES;
+
+typedef enum OPTIONENUMPAYLOADTAG
     {
     /// Element if Some().
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    OPTIONENUMPAYLOAD_SOME = 0,
     OPTIONENUMPAYLOAD_NONE = 1,
-    } OPTIONENUMPAYLOAD;
+    } OPTIONENUMPAYLOADTAG;
 
 /// Option that contains Some(value) or None.
-typedef enum OPTIONRESULTOPTIONUTF8STRINGERROR
+typedef struct OPTIONENUMPAYLOAD
+    {
+    uint32_t tag; // OPTIONENUMPAYLOADTAG
+    union
+        {
+        /// Element if Some().
+        ENUMPAYLOAD some;
+        } payload;
+    } OPTIONENUMPAYLOAD;
+
+typedef enum OPTIONRESULTOPTIONUTF8STRINGERRORTAG
     {
     /// Element if Some().
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    OPTIONRESULTOPTIONUTF8STRINGERROR_SOME = 0,
     OPTIONRESULTOPTIONUTF8STRINGERROR_NONE = 1,
+    } OPTIONRESULTOPTIONUTF8STRINGERRORTAG;
+
+/// Option that contains Some(value) or None.
+typedef struct OPTIONRESULTOPTIONUTF8STRINGERROR
+    {
+    uint32_t tag; // OPTIONRESULTOPTIONUTF8STRINGERRORTAG
+    union
+        {
+        /// Element if Some().
+        RESULTOPTIONUTF8STRINGERROR some;
+        } payload;
     } OPTIONRESULTOPTIONUTF8STRINGERROR;
 
 typedef VEC3F32 (*CALLBACKHUGEVECSLICE)(SLICEVEC3F32 SLICE, const void* CALLBACK_DATA);
@@ -803,10 +1560,20 @@ typedef struct VECENUMPAYLOAD
     uint64_t capacity;
     } VECENUMPAYLOAD;
 
-typedef enum LAYER3
+typedef enum LAYER3TAG
     {
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    LAYER3_A = 0,
+    LAYER3_B = 1,
+    } LAYER3TAG;
+
+typedef struct LAYER3
+    {
+    uint32_t tag; // LAYER3TAG
+    union
+        {
+        LAYER1UTF8STRING a;
+        LAYER2UTF8STRING b;
+        } payload;
     } LAYER3;
 
 typedef void (*fptr_fn_CharArray_rval_void)(CHARARRAY x0);
@@ -820,34 +1587,68 @@ typedef struct SLICEMUTCHARARRAY
     uint64_t len;
     } SLICEMUTCHARARRAY;
 
-/// Option that contains Some(value) or None.
-typedef enum OPTIONOPTIONRESULTOPTIONUTF8STRINGERROR
+typedef enum OPTIONOPTIONRESULTOPTIONUTF8STRINGERRORTAG
     {
     /// Element if Some().
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    OPTIONOPTIONRESULTOPTIONUTF8STRINGERROR_SOME = 0,
     OPTIONOPTIONRESULTOPTIONUTF8STRINGERROR_NONE = 1,
+    } OPTIONOPTIONRESULTOPTIONUTF8STRINGERRORTAG;
+
+/// Option that contains Some(value) or None.
+typedef struct OPTIONOPTIONRESULTOPTIONUTF8STRINGERROR
+    {
+    uint32_t tag; // OPTIONOPTIONRESULTOPTIONUTF8STRINGERRORTAG
+    union
+        {
+        /// Element if Some().
+        OPTIONRESULTOPTIONUTF8STRINGERROR some;
+        } payload;
     } OPTIONOPTIONRESULTOPTIONUTF8STRINGERROR;
 
-/// Result that contains value or an error.
-typedef enum RESULTNESTEDARRAYERROR
+typedef enum RESULTNESTEDARRAYERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTNESTEDARRAYERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTNESTEDARRAYERROR_ERR = 1,
     RESULTNESTEDARRAYERROR_PANIC = 2,
     RESULTNESTEDARRAYERROR_NULL = 3,
-    } RESULTNESTEDARRAYERROR;
+    } RESULTNESTEDARRAYERRORTAG;
 
 /// Result that contains value or an error.
-typedef enum RESULTOPTIONENUMPAYLOADERROR
+typedef struct RESULTNESTEDARRAYERROR
+    {
+    uint32_t tag; // RESULTNESTEDARRAYERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        NESTEDARRAY ok;
+        /// Error value.
+        ERROR err;
+        } payload;
+    } RESULTNESTEDARRAYERROR;
+
+typedef enum RESULTOPTIONENUMPAYLOADERRORTAG
     {
     /// Element if err is `Ok`.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTOPTIONENUMPAYLOADERROR_OK = 0,
     /// Error value.
-    // TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    RESULTOPTIONENUMPAYLOADERROR_ERR = 1,
     RESULTOPTIONENUMPAYLOADERROR_PANIC = 2,
     RESULTOPTIONENUMPAYLOADERROR_NULL = 3,
+    } RESULTOPTIONENUMPAYLOADERRORTAG;
+
+/// Result that contains value or an error.
+typedef struct RESULTOPTIONENUMPAYLOADERROR
+    {
+    uint32_t tag; // RESULTOPTIONENUMPAYLOADERRORTAG
+    union
+        {
+        /// Element if err is `Ok`.
+        OPTIONENUMPAYLOAD ok;
+        /// Error value.
+        ERROR err;
+        } payload;
     } RESULTOPTIONENUMPAYLOADERROR;
 
 typedef void (*CALLBACKCHARARRAY2)(CHARARRAY VALUE, const void* CALLBACK_DATA);
@@ -859,14 +1660,30 @@ typedef void (*fptr_fn_ConstPtrResultVecUtf8StringError_ConstPtrVoid_rval_void)(
 typedef void (*fptr_fn_ConstPtrResultNestedArrayError_ConstPtrVoid_rval_void)(const RESULTNESTEDARRAYERROR* x0, const void* x1);
 
 
+///  Creates a string from `len` UTF-8 bytes, returns `0` on success or `1` (and an empty string) if they are not valid UTF-8.
 int64_t interoptopus_string_create(const void* UTF8, uint64_t LEN, UTF8STRING* RVAL);
 
+///  Creates a string from `len` UTF-16 code units, replacing unpaired surrogates with `U+FFFD` like .NET does; always returns `0`.
+int64_t interoptopus_string_create_utf16(const void* UTF16, uint64_t LEN, UTF8STRING* RVAL);
+
 int64_t interoptopus_string_destroy(UTF8STRING UTF8);
 
 int64_t interoptopus_string_clone(const UTF8STRING* UTF8, UTF8STRING* RVAL);
 
+///  Returns and clears details about the last error or panic on this thread, or an empty string.
+UTF8STRING interoptopus_last_error();
+
 void interoptopus_wire_destroy(uint8_t* DATA, int32_t LEN, int32_t CAPACITY);
 
+///  Installs allocation hooks for all FFI buffers, returns `0` on success or `1` if it is too late to do so.
+int64_t interoptopus_allocator_set(fptr_fn_u64_u64_rval_MutPtrVoid ALLOC, fptr_fn_MutPtrVoid_u64_u64_rval_void FREE);
+
+///  Allocates `size` bytes aligned to `align` that Rust may later free, returns null on failure.
+void* interoptopus_alloc(uint64_t SIZE, uint64_t ALIGN);
+
+///  Frees memory allocated by `interoptopus_alloc`, or owned by a buffer Rust handed out; invalid `size` and `align` values are ignored.
+void interoptopus_free(void* PTR, uint64_t SIZE, uint64_t ALIGN);
+
 int64_t interoptopus_vec_create_6849152863081469284(const void* DATA, uint64_t LEN, VECU8* RVAL);
 
 int64_t interoptopus_vec_destroy_9353420175730321243(VECU8 IGNORED);
@@ -883,6 +1700,20 @@ int64_t interoptopus_vec_create_12058709700293828778(const void* DATA, uint64_t
 
 int64_t interoptopus_vec_destroy_4854562680055612543(VECENUMPAYLOAD IGNORED);
 
+int64_t interoptopus_map_create_6262311165280223196(const void* KEYS, const void* VALUES, uint64_t LEN, MAPU32VEC3F32* RVAL);
+
+int64_t interoptopus_map_destroy_9095550523068598099(MAPU32VEC3F32 IGNORED);
+
+///  Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked.
+int64_t interoptopus_iter_next_14415398661037898230(ITERU32* ITER, uint32_t* RVAL);
+
+int64_t interoptopus_iter_destroy_11887687051161408401(ITERU32 IGNORED);
+
+///  Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked.
+int64_t interoptopus_iter_next_12283536038193100041(ITERVEC3F32* ITER, VEC3F32* RVAL);
+
+int64_t interoptopus_iter_destroy_14403488365818035019(ITERVEC3F32 IGNORED);
+
 PACKED2 alignment_1(PACKED1 A);
 
 uint8_t array_1(ARRAY X);
@@ -909,6 +1740,14 @@ void behavior_panics();
 
 RESULTERROR behavior_panics_via_result();
 
+RESULTU32ERROR behavior_panics_ffi_error();
+
+uint32_t behavior_panics_return_default();
+
+uint32_t behavior_panics_callback();
+
+uint32_t behavior_panic_callbacks();
+
 void enums_1(ENUMPAYLOAD IGNORED);
 
 ENUMPAYLOAD enums_2(ENUMPAYLOAD X);
@@ -921,6 +1760,8 @@ uint8_t fnptr_1(fptr_fn_u8_rval_u8 CALLBACK, uint8_t X);
 
 void fnptr_2(fptr_fn_CharArray_rval_void CALLBACK, CHARARRAY X);
 
+uint32_t fnptr_3(fptr_fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32 CALLBACK);
+
 uint32_t generic_1a(GENERICU32 X, PHANTOMU8 Y);
 
 uint8_t generic_1b(GENERICU8 X, PHANTOMU8 Y);
@@ -986,6 +1827,14 @@ ptrdiff_t primitive_isize(ptrdiff_t X);
 
 uint8_t primitive_u8(uint8_t X);
 
+unsigned __int128 primitive_u128(unsigned __int128 X);
+
+__int128 primitive_i128(__int128 X);
+
+uint32_t primitive_char(uint32_t X);
+
+uint32_t primitive_nonzero(uint32_t X);
+
 void primitive_void();
 
 void primitive_void2();
@@ -1019,6 +1868,8 @@ RESULTERROR struct2(VEC3F32 A, const TUPLED* B);
 
 bool struct3(BOOLFIELD X);
 
+WIDENUM struct4(WIDENUM X);
+
 uint32_t pattern_ascii_pointer_1(const char* X);
 
 const char* pattern_ascii_pointer_2();
@@ -1055,6 +1906,16 @@ void pattern_string_10(UTF8STRING IGNORED);
 
 void pattern_string_11(const UTF8STRING* IGNORED);
 
+uint32_t pattern_str_1(UTF8STR X);
+
+UTF8STR pattern_str_2();
+
+UTF8STR pattern_str_3(UTF8STR X);
+
+uint32_t pattern_str_4(USESTR X);
+
+RESULTU32ERROR pattern_str_5(UTF8STR X);
+
 uint32_t pattern_ffi_slice_1(SLICEU32 FFI_SLICE);
 
 uint32_t pattern_ffi_slice_1b(SLICEMUTU32 FFI_SLICE);
@@ -1078,6 +1939,14 @@ uint8_t pattern_ffi_slice_delegate(CALLBACKFFISLICE CALLBACK);
 
 VEC3F32 pattern_ffi_slice_delegate_huge(CALLBACKHUGEVECSLICE CALLBACK);
 
+///  Sums all elements, regardless of the number of dimensions or their strides.
+float pattern_ndslice_1(NDSLICEF32 SLICE);
+
+int32_t pattern_ndslice_2(NDSLICEI32 SLICE, uint64_t ROW, uint64_t COL);
+
+///  Sets each element of a 2D slice to `row * 100 + col`.
+void pattern_ndslice_3(NDSLICEMUTU32 SLICE);
+
 OPTIONINNER pattern_ffi_option_1(OPTIONINNER X);
 
 INNER pattern_ffi_option_2(OPTIONINNER X);
@@ -1100,6 +1969,10 @@ RESULTERROR pattern_result_3(RESULTERROR X);
 
 RESULTVOID pattern_result_4(RESULTVOID X);
 
+RESULTU32ERROR pattern_result_5(uint32_t X);
+
+RESULTU32ERROR pattern_result_6(uint32_t X);
+
 uint64_t pattern_api_guard();
 
 uint32_t pattern_callback_1(MYCALLBACK CALLBACK, uint32_t X);
@@ -1116,9 +1989,20 @@ RESULTERROR pattern_callback_7(SUMDELEGATERETURN C1, SUMDELEGATERETURN2 C2, int3
 
 void pattern_callback_8(STRINGCALLBACK CB, NESTEDSTRINGCALLBACK CB2, UTF8STRING S);
 
-int32_t pattern_callback_9(POINTERS X);
+uint32_t pattern_callback_9(MYOWNEDCALLBACK CALLBACK, uint32_t X);
+
+///  Keeps the callback until replaced, dropping the previous one.
+void pattern_callback_10(MYOWNEDCALLBACK CALLBACK);
+
+///  Invokes the callback stored by `pattern_callback_10`, returns 0 if there is none.
+uint32_t pattern_callback_11(uint32_t X);
+
+///  Returns the callback's result plus one, passing on errors and failures of the callback.
+RESULTU32ERROR pattern_callback_12(MYFALLIBLECALLBACK CALLBACK, uint32_t X);
 
-void pattern_callback_10(STRINGCALLBACKSTRING IGNORED);
+uint8_t pattern_callback_13(ONITEMU32 CALLBACK, uint32_t X);
+
+uint8_t pattern_callback_14(ONITEMF32 CALLBACK, float X);
 
 void pattern_surrogates_1(LOCAL S, CONTAINER* C);
 
@@ -1138,6 +2022,42 @@ void pattern_vec_7(USESLICEANDVEC IGNORED);
 
 USESLICEANDVEC pattern_vec_8(USESLICEANDVEC V);
 
+MAPU32VEC3F32 pattern_map_1();
+
+OPTIONVEC3F32 pattern_map_2(MAPU32VEC3F32 M, uint32_t KEY);
+
+MAPU32VEC3F32 pattern_map_3(const MAPU32VEC3F32* M);
+
+ITERU32 pattern_iter_1(uint32_t N);
+
+ITERVEC3F32 pattern_iter_2();
+
+uint32_t pattern_iter_3(ITERU32 ITER);
+
+ITERU32 pattern_iter_4(uint32_t N);
+
+TUPLE2_F32_U32 pattern_tuple_1(TUPLE2_U32_F32 X);
+
+TUPLE3_VEC3F32_U8_I64 pattern_tuple_2(TUPLE3_VEC3F32_U8_I64 X);
+
+TUPLES pattern_tuple_3(TUPLES X);
+
+uint32_t pattern_tuple_4(OPTIONTUPLE2_U32_U32 X);
+
+uint32_t pattern_tuple_5(TUPLE2_U32_U32 TUPLE0);
+
+DURATION pattern_duration_1(DURATION X);
+
+DURATION pattern_duration_2(TIMEOUT X);
+
+DURATION pattern_duration_3();
+
+///  Works with any service implementing `Storage`.
+uint32_t pattern_service_interface_1(DYNSTORAGE STORAGE, uint32_t KEY, uint32_t VAL);
+
+///  Logs the given values to a foreign sink and returns what the sink counted.
+uint32_t pattern_foreign_trait_1(LOGSINKVTABLE SINK, uint32_t X);
+
 void wire_accept_string_2(void * INPUT);
 
 ///  Destroys the given instance.
@@ -1258,6 +2178,10 @@ RESULTOPTIONENUMPAYLOADERROR service_result_result_option_enum(const SERVICERESU
 
 RESULTU32ERROR service_result_result_slice(const SERVICERESULT* _CONTEXT, SLICEU32 SLICE, uint64_t I);
 
+RESULTU32ERROR service_result_result_std(const SERVICERESULT* _CONTEXT, uint32_t X);
+
+RESULTU32ERROR service_result_result_std_mapped(const SERVICERESULT* _CONTEXT, uint32_t X);
+
 ///  Destroys the given instance.
 /// 
 ///  # Safety
@@ -1325,6 +2249,8 @@ RESULTCONSTPTRSERVICEMULTIPLECTORSERROR service_multiple_ctors_new_with_string(c
 
 RESULTCONSTPTRSERVICEMULTIPLECTORSERROR service_multiple_ctors_new_failing(uint8_t SOME_VALUE);
 
+RESULTCONSTPTRSERVICEMULTIPLECTORSERROR service_multiple_ctors_new_std(uint32_t SOME_VALUE);
+
 ///  Destroys the given instance.
 /// 
 ///  # Safety
@@ -1358,6 +2284,12 @@ SLICEU32 service_various_slices_return_slice(SERVICEVARIOUSSLICES* _CONTEXT);
 ///  again, as otherwise undefined behavior might happen.
 SLICEMUTU32 service_various_slices_return_slice_mut(SERVICEVARIOUSSLICES* _CONTEXT);
 
+///  Hands out a copy of the data lazily, so the returned iterator stays valid even if the
+///  service is used again.
+ITERU32 service_various_slices_return_iter(const SERVICEVARIOUSSLICES* _CONTEXT);
+
+TUPLE2_U64_U32 service_various_slices_return_len_and_first(const SERVICEVARIOUSSLICES* _CONTEXT);
+
 ///  Destroys the given instance.
 /// 
 ///  # Safety
@@ -1374,8 +2306,125 @@ void service_strings_pass_cstr(SERVICESTRINGS* _CONTEXT, const char* ANON1);
 
 const char* service_strings_return_cstr(SERVICESTRINGS* _CONTEXT);
 
+uint32_t service_strings_pass_str(const SERVICESTRINGS* _CONTEXT, UTF8STR S);
+
 void service_strings_callback_string(const SERVICESTRINGS* _CONTEXT, UTF8STRING S, STRINGCALLBACK CB);
 
+///  Destroys the given instance.
+/// 
+///  # Safety
+/// 
+///  The passed parameter MUST have been created with the corresponding init function;
+///  passing any other value results in undefined behavior.
+RESULTCONSTPTRSERVICESTORAGEARRAYERROR service_storage_array_destroy(const SERVICESTORAGEARRAY* _CONTEXT);
+
+RESULTCONSTPTRSERVICESTORAGEARRAYERROR service_storage_array_new();
+
+///  Returns a [`Storage`] handle to this service, valid until the service is destroyed.
+/// 
+///  While a `&mut self` method runs through the handle, no other method of this service may be called.
+DYNSTORAGE service_storage_array_as_storage(const SERVICESTORAGEARRAY* _CONTEXT);
+
+///  Returns the value stored at `key`.
+uint32_t storage_get(DYNSTORAGE _HANDLE, uint32_t KEY);
+
+///  Stores `val` at `key`.
+void storage_set(DYNSTORAGE _HANDLE, uint32_t KEY, uint32_t VAL);
+
+///  Destroys the given instance.
+/// 
+///  # Safety
+/// 
+///  The passed parameter MUST have been created with the corresponding init function;
+///  passing any other value results in undefined behavior.
+RESULTCONSTPTRSERVICESTORAGEOFFSETERROR service_storage_offset_destroy(const SERVICESTORAGEOFFSET* _CONTEXT);
+
+RESULTCONSTPTRSERVICESTORAGEOFFSETERROR service_storage_offset_new(uint32_t OFFSET);
+
+///  Services can provide interface methods themselves.
+uint32_t service_storage_offset_get(const SERVICESTORAGEOFFSET* _CONTEXT, uint32_t KEY);
+
+uint32_t service_storage_offset_offset(const SERVICESTORAGEOFFSET* _CONTEXT);
+
+///  Returns a [`Storage`] handle to this service, valid until the service is destroyed.
+/// 
+///  While a `&mut self` method runs through the handle, no other method of this service may be called.
+DYNSTORAGE service_storage_offset_as_storage(const SERVICESTORAGEOFFSET* _CONTEXT);
+
+///  Destroys the given instance.
+/// 
+///  # Safety
+/// 
+///  The passed parameter MUST have been created with the corresponding init function;
+///  passing any other value results in undefined behavior.
+RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR service_foreign_logger_destroy(const SERVICEFOREIGNLOGGER* _CONTEXT);
+
+RESULTCONSTPTRSERVICEFOREIGNLOGGERERROR service_foreign_logger_new(LOGSINKVTABLE SINK);
+
+RESULTERROR service_foreign_logger_log(const SERVICEFOREIGNLOGGER* _CONTEXT, uint32_t X);
+
+///  Destroys the given instance.
+/// 
+///  # Safety
+/// 
+///  The passed parameter MUST have been created with the corresponding init function;
+///  passing any other value results in undefined behavior.
+RESULTCONSTPTRSERVICEPROPERTIESERROR service_properties_destroy(const SERVICEPROPERTIES* _CONTEXT);
+
+RESULTCONSTPTRSERVICEPROPERTIESERROR service_properties_new();
+
+uint32_t service_properties_get_value(const SERVICEPROPERTIES* _CONTEXT);
+
+void service_properties_set_value(SERVICEPROPERTIES* _CONTEXT, uint32_t X);
+
+///  Properties can also be read-only.
+uint32_t service_properties_get_updates(const SERVICEPROPERTIES* _CONTEXT);
+
+///  Destroys the given instance.
+/// 
+///  # Safety
+/// 
+///  The passed parameter MUST have been created with the corresponding init function;
+///  passing any other value results in undefined behavior.
+RESULTCONSTPTRSERVICESTATICSERROR service_statics_destroy(const SERVICESTATICS* _CONTEXT);
+
+RESULTCONSTPTRSERVICESTATICSERROR service_statics_new_with(uint32_t SCALE);
+
+uint32_t service_statics_scale(const SERVICESTATICS* _CONTEXT, uint32_t X);
+
+///  The scale used by default, not tied to any instance.
+uint32_t service_statics_default_scale();
+
+uint32_t service_statics_scaled(uint32_t X, uint32_t SCALE);
+
+///  Destroys the given instance.
+/// 
+///  # Safety
+/// 
+///  The passed parameter MUST have been created with the corresponding init function;
+///  passing any other value results in undefined behavior.
+RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR service_thread_affine_destroy(const SERVICETHREADAFFINE* _CONTEXT);
+
+RESULTCONSTPTRSERVICETHREADAFFINESYNCERROR service_thread_affine_new();
+
+RESULTSYNCERROR service_thread_affine_increment(SERVICETHREADAFFINE* _CONTEXT);
+
+uint32_t service_thread_affine_value(const SERVICETHREADAFFINE* _CONTEXT);
+
+///  Destroys the given instance.
+/// 
+///  # Safety
+/// 
+///  The passed parameter MUST have been created with the corresponding init function;
+///  passing any other value results in undefined behavior.
+RESULTCONSTPTRSERVICEMUTEXERROR service_mutex_destroy(const SERVICEMUTEX* _CONTEXT);
+
+RESULTCONSTPTRSERVICEMUTEXERROR service_mutex_new();
+
+uint32_t service_mutex_increment(SERVICEMUTEX* _CONTEXT);
+
+uint32_t service_mutex_value(const SERVICEMUTEX* _CONTEXT);
+
 
 #ifdef __cplusplus
 }
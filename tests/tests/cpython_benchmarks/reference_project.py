@@ -1,5 +1,9 @@
 from __future__ import annotations
+import collections.abc
 import ctypes
+import datetime
+import dataclasses
+import math
 import typing
 
 T = typing.TypeVar("T")
@@ -13,18 +17,23 @@ def init_lib(path):
     c_lib.array_1.argtypes = [Array]
     c_lib.array_2.argtypes = []
     c_lib.array_3.argtypes = [ctypes.POINTER(Array)]
+    c_lib.behavior_panic_callbacks.argtypes = []
     c_lib.behavior_panics.argtypes = []
+    c_lib.behavior_panics_callback.argtypes = []
+    c_lib.behavior_panics_ffi_error.argtypes = []
+    c_lib.behavior_panics_return_default.argtypes = []
     c_lib.behavior_panics_via_result.argtypes = []
     c_lib.behavior_sleep.argtypes = [ctypes.c_uint64]
     c_lib.char_array_1.argtypes = []
     c_lib.char_array_2.argtypes = [CharArray]
     c_lib.char_array_3.argtypes = [ctypes.POINTER(CharArray)]
-    c_lib.enums_1.argtypes = [ctypes.c_int]
-    c_lib.enums_2.argtypes = [ctypes.c_int]
-    c_lib.enums_3.argtypes = [ctypes.POINTER(ctypes.c_int)]
-    c_lib.enums_4.argtypes = [ctypes.c_int]
+    c_lib.enums_1.argtypes = [EnumPayload]
+    c_lib.enums_2.argtypes = [EnumPayload]
+    c_lib.enums_3.argtypes = [ctypes.POINTER(EnumPayload)]
+    c_lib.enums_4.argtypes = [Layer3]
     c_lib.fnptr_1.argtypes = [ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint8), ctypes.c_uint8]
     c_lib.fnptr_2.argtypes = [ctypes.CFUNCTYPE(None, CharArray), CharArray]
+    c_lib.fnptr_3.argtypes = [ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32)]
     c_lib.generic_1a.argtypes = [Genericu32, Phantomu8]
     c_lib.generic_1b.argtypes = [Genericu8, Phantomu8]
     c_lib.generic_1c.argtypes = [ctypes.POINTER(Genericu8), ctypes.POINTER(Genericu8)]
@@ -32,8 +41,19 @@ def init_lib(path):
     c_lib.generic_3.argtypes = [ctypes.c_void_p]
     c_lib.generic_4.argtypes = [ctypes.c_void_p]
     c_lib.generic_5.argtypes = [Weird1u32, Weird2u8]
+    c_lib.interoptopus_alloc.argtypes = [ctypes.c_uint64, ctypes.c_uint64]
+    c_lib.interoptopus_allocator_set.argtypes = [ctypes.CFUNCTYPE(ctypes.c_void_p, ctypes.c_uint64, ctypes.c_uint64), ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_uint64, ctypes.c_uint64)]
+    c_lib.interoptopus_free.argtypes = [ctypes.c_void_p, ctypes.c_uint64, ctypes.c_uint64]
+    c_lib.interoptopus_iter_destroy_11887687051161408401.argtypes = [IterU32]
+    c_lib.interoptopus_iter_destroy_14403488365818035019.argtypes = [IterVec3f32]
+    c_lib.interoptopus_iter_next_12283536038193100041.argtypes = [ctypes.POINTER(IterVec3f32), ctypes.POINTER(Vec3f32)]
+    c_lib.interoptopus_iter_next_14415398661037898230.argtypes = [ctypes.POINTER(IterU32), ctypes.POINTER(ctypes.c_uint32)]
+    c_lib.interoptopus_last_error.argtypes = []
+    c_lib.interoptopus_map_create_6262311165280223196.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_uint64, ctypes.POINTER(MapU32Vec3f32)]
+    c_lib.interoptopus_map_destroy_9095550523068598099.argtypes = [MapU32Vec3f32]
     c_lib.interoptopus_string_clone.argtypes = [ctypes.POINTER(Utf8String), ctypes.POINTER(Utf8String)]
     c_lib.interoptopus_string_create.argtypes = [ctypes.c_void_p, ctypes.c_uint64, ctypes.POINTER(Utf8String)]
+    c_lib.interoptopus_string_create_utf16.argtypes = [ctypes.c_void_p, ctypes.c_uint64, ctypes.POINTER(Utf8String)]
     c_lib.interoptopus_string_destroy.argtypes = [Utf8String]
     c_lib.interoptopus_vec_create_12058709700293828778.argtypes = [ctypes.c_void_p, ctypes.c_uint64, ctypes.POINTER(VecEnumPayload)]
     c_lib.interoptopus_vec_create_17163327104750939811.argtypes = [ctypes.c_void_p, ctypes.c_uint64, ctypes.POINTER(VecUtf8String)]
@@ -50,7 +70,7 @@ def init_lib(path):
     c_lib.meta_documented.argtypes = [StructDocumented]
     c_lib.meta_renamed.argtypes = [StructRenamed]
     c_lib.meta_visibility1.argtypes = [Visibility1, Visibility2]
-    c_lib.namespaced_inner_option.argtypes = [TODO]
+    c_lib.namespaced_inner_option.argtypes = [OptionVec]
     c_lib.namespaced_inner_slice.argtypes = [SliceVec]
     c_lib.namespaced_inner_slice_mut.argtypes = [SliceMutVec]
     c_lib.namespaced_type.argtypes = [Vec]
@@ -64,37 +84,63 @@ def init_lib(path):
     c_lib.pattern_ascii_pointer_4.argtypes = [ctypes.POINTER(ctypes.c_char), ctypes.c_uint32]
     c_lib.pattern_ascii_pointer_5.argtypes = [ctypes.POINTER(ctypes.c_char), ctypes.c_uint32]
     c_lib.pattern_ascii_pointer_return_slice.argtypes = []
-    c_lib.pattern_callback_1.argtypes = [ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p), ctypes.c_uint32]
-    c_lib.pattern_callback_10.argtypes = [ctypes.CFUNCTYPE(Utf8String, Utf8String, ctypes.c_void_p)]
-    c_lib.pattern_callback_2.argtypes = [ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_void_p)]
-    c_lib.pattern_callback_4.argtypes = [ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p), ctypes.c_uint32]
+    c_lib.pattern_callback_1.argtypes = [MyCallback, ctypes.c_uint32]
+    c_lib.pattern_callback_10.argtypes = [MyOwnedCallback]
+    c_lib.pattern_callback_11.argtypes = [ctypes.c_uint32]
+    c_lib.pattern_callback_12.argtypes = [MyFallibleCallback, ctypes.c_uint32]
+    c_lib.pattern_callback_13.argtypes = [OnItemU32, ctypes.c_uint32]
+    c_lib.pattern_callback_14.argtypes = [OnItemF32, ctypes.c_float]
+    c_lib.pattern_callback_2.argtypes = [MyCallbackVoid]
+    c_lib.pattern_callback_4.argtypes = [MyCallbackNamespaced, ctypes.c_uint32]
     c_lib.pattern_callback_5.argtypes = []
     c_lib.pattern_callback_6.argtypes = []
-    c_lib.pattern_callback_7.argtypes = [ctypes.CFUNCTYPE(ResultError, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p), ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p), ctypes.c_int32, ctypes.c_int32, ctypes.POINTER(ctypes.c_int32)]
-    c_lib.pattern_callback_8.argtypes = [ctypes.CFUNCTYPE(None, Utf8String, ctypes.c_void_p), ctypes.CFUNCTYPE(None, UseString, ctypes.c_void_p), Utf8String]
-    c_lib.pattern_callback_9.argtypes = [ctypes.CFUNCTYPE(None, ctypes.POINTER(ctypes.c_int32), ctypes.POINTER(ctypes.c_int32), ctypes.c_void_p)]
+    c_lib.pattern_callback_7.argtypes = [SumDelegateReturn, SumDelegateReturn2, ctypes.c_int32, ctypes.c_int32, ctypes.POINTER(ctypes.c_int32)]
+    c_lib.pattern_callback_8.argtypes = [StringCallback, NestedStringCallback, Utf8String]
+    c_lib.pattern_callback_9.argtypes = [MyOwnedCallback, ctypes.c_uint32]
+    c_lib.pattern_duration_1.argtypes = [Duration]
+    c_lib.pattern_duration_2.argtypes = [Timeout]
+    c_lib.pattern_duration_3.argtypes = []
     c_lib.pattern_ffi_bool.argtypes = [ctypes.c_uint8]
     c_lib.pattern_ffi_cchar.argtypes = [ctypes.c_char]
     c_lib.pattern_ffi_cchar_const_pointer.argtypes = [ctypes.POINTER(ctypes.c_char)]
     c_lib.pattern_ffi_cchar_mut_pointer.argtypes = [ctypes.POINTER(ctypes.c_char)]
-    c_lib.pattern_ffi_option_1.argtypes = [TODO]
-    c_lib.pattern_ffi_option_2.argtypes = [TODO]
-    c_lib.pattern_ffi_option_3.argtypes = [TODO]
+    c_lib.pattern_ffi_option_1.argtypes = [OptionInner]
+    c_lib.pattern_ffi_option_2.argtypes = [OptionInner]
+    c_lib.pattern_ffi_option_3.argtypes = [OptionOptionResultOptionUtf8StringError]
     c_lib.pattern_ffi_slice_1.argtypes = [SliceU32]
     c_lib.pattern_ffi_slice_1b.argtypes = [SliceMutU32]
     c_lib.pattern_ffi_slice_2.argtypes = [SliceVec3f32, ctypes.c_int32]
-    c_lib.pattern_ffi_slice_3.argtypes = [SliceMutU8, ctypes.CFUNCTYPE(None, SliceMutU8, ctypes.c_void_p)]
+    c_lib.pattern_ffi_slice_3.argtypes = [SliceMutU8, CallbackSliceMut]
     c_lib.pattern_ffi_slice_4.argtypes = [SliceU8, SliceMutU8]
     c_lib.pattern_ffi_slice_5.argtypes = [ctypes.POINTER(SliceU8), ctypes.POINTER(SliceMutU8)]
-    c_lib.pattern_ffi_slice_6.argtypes = [ctypes.POINTER(SliceMutU8), ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint8, ctypes.c_void_p)]
-    c_lib.pattern_ffi_slice_8.argtypes = [ctypes.POINTER(SliceMutCharArray), ctypes.CFUNCTYPE(None, CharArray, ctypes.c_void_p)]
+    c_lib.pattern_ffi_slice_6.argtypes = [ctypes.POINTER(SliceMutU8), CallbackU8]
+    c_lib.pattern_ffi_slice_8.argtypes = [ctypes.POINTER(SliceMutCharArray), CallbackCharArray2]
     c_lib.pattern_ffi_slice_9.argtypes = [SliceUseString]
-    c_lib.pattern_ffi_slice_delegate.argtypes = [ctypes.CFUNCTYPE(ctypes.c_uint8, SliceU8, ctypes.c_void_p)]
-    c_lib.pattern_ffi_slice_delegate_huge.argtypes = [ctypes.CFUNCTYPE(Vec3f32, SliceVec3f32, ctypes.c_void_p)]
+    c_lib.pattern_ffi_slice_delegate.argtypes = [CallbackFFISlice]
+    c_lib.pattern_ffi_slice_delegate_huge.argtypes = [CallbackHugeVecSlice]
+    c_lib.pattern_foreign_trait_1.argtypes = [LogSinkVTable, ctypes.c_uint32]
+    c_lib.pattern_iter_1.argtypes = [ctypes.c_uint32]
+    c_lib.pattern_iter_2.argtypes = []
+    c_lib.pattern_iter_3.argtypes = [IterU32]
+    c_lib.pattern_iter_4.argtypes = [ctypes.c_uint32]
+    c_lib.pattern_map_1.argtypes = []
+    c_lib.pattern_map_2.argtypes = [MapU32Vec3f32, ctypes.c_uint32]
+    c_lib.pattern_map_3.argtypes = [ctypes.POINTER(MapU32Vec3f32)]
+    c_lib.pattern_ndslice_1.argtypes = [NdSliceF32]
+    c_lib.pattern_ndslice_2.argtypes = [NdSliceI32, ctypes.c_uint64, ctypes.c_uint64]
+    c_lib.pattern_ndslice_3.argtypes = [NdSliceMutU32]
     c_lib.pattern_result_1.argtypes = [ResultU32Error]
     c_lib.pattern_result_2.argtypes = []
     c_lib.pattern_result_3.argtypes = [ResultError]
     c_lib.pattern_result_4.argtypes = [ResultVoid]
+    c_lib.pattern_result_5.argtypes = [ctypes.c_uint32]
+    c_lib.pattern_result_6.argtypes = [ctypes.c_uint32]
+    c_lib.pattern_service_interface_1.argtypes = [DynStorage, ctypes.c_uint32, ctypes.c_uint32]
+    c_lib.pattern_str_1.argtypes = [Utf8Str]
+    c_lib.pattern_str_2.argtypes = []
+    c_lib.pattern_str_3.argtypes = [Utf8Str]
+    c_lib.pattern_str_4.argtypes = [UseStr]
+    c_lib.pattern_str_5.argtypes = [Utf8Str]
     c_lib.pattern_string_1.argtypes = [Utf8String]
     c_lib.pattern_string_10.argtypes = [Utf8String]
     c_lib.pattern_string_11.argtypes = [ctypes.POINTER(Utf8String)]
@@ -108,6 +154,11 @@ def init_lib(path):
     c_lib.pattern_string_8.argtypes = [SliceUseString, ctypes.c_uint64]
     c_lib.pattern_string_9.argtypes = []
     c_lib.pattern_surrogates_1.argtypes = [Local, ctypes.POINTER(Container)]
+    c_lib.pattern_tuple_1.argtypes = [Tuple2_U32_F32]
+    c_lib.pattern_tuple_2.argtypes = [Tuple3_Vec3f32_U8_I64]
+    c_lib.pattern_tuple_3.argtypes = [Tuples]
+    c_lib.pattern_tuple_4.argtypes = [OptionTuple2_U32_U32]
+    c_lib.pattern_tuple_5.argtypes = [Tuple2_U32_U32]
     c_lib.pattern_vec_1.argtypes = []
     c_lib.pattern_vec_2.argtypes = [VecU8]
     c_lib.pattern_vec_3.argtypes = [VecU8]
@@ -119,13 +170,17 @@ def init_lib(path):
     c_lib.primitive_args_10.argtypes = [ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64]
     c_lib.primitive_args_5.argtypes = [ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64, ctypes.c_int64]
     c_lib.primitive_bool.argtypes = [ctypes.c_bool]
+    c_lib.primitive_char.argtypes = [ctypes.c_uint32]
     c_lib.primitive_f32.argtypes = [ctypes.c_float]
     c_lib.primitive_f64.argtypes = [ctypes.c_double]
+    c_lib.primitive_i128.argtypes = [_I128]
     c_lib.primitive_i16.argtypes = [ctypes.c_int16]
     c_lib.primitive_i32.argtypes = [ctypes.c_int32]
     c_lib.primitive_i64.argtypes = [ctypes.c_int64]
     c_lib.primitive_i8.argtypes = [ctypes.c_int8]
     c_lib.primitive_isize.argtypes = [ctypes.c_ssize_t]
+    c_lib.primitive_nonzero.argtypes = [ctypes.c_uint32]
+    c_lib.primitive_u128.argtypes = [_U128]
     c_lib.primitive_u16.argtypes = [ctypes.c_uint16]
     c_lib.primitive_u32.argtypes = [ctypes.c_uint32]
     c_lib.primitive_u64.argtypes = [ctypes.c_uint64]
@@ -140,8 +195,8 @@ def init_lib(path):
     c_lib.ref2.argtypes = [ctypes.POINTER(ctypes.c_int64)]
     c_lib.ref3.argtypes = [ctypes.POINTER(ctypes.c_int64)]
     c_lib.ref4.argtypes = [ctypes.POINTER(ctypes.c_int64)]
-    c_lib.ref5.argtypes = [ctypes.POINTER(ctypes.c_int)]
-    c_lib.ref6.argtypes = [ctypes.POINTER(TODO)]
+    c_lib.ref5.argtypes = [ctypes.POINTER(EnumPayload)]
+    c_lib.ref6.argtypes = [ctypes.POINTER(OptionEnumPayload)]
     c_lib.ref7.argtypes = [ctypes.POINTER(VecUtf8String)]
     c_lib.service_async_basic_call.argtypes = [ctypes.c_void_p, ctypes.CFUNCTYPE(None, ctypes.POINTER(ResultError), ctypes.c_void_p)]
     c_lib.service_async_basic_destroy.argtypes = [ctypes.c_void_p]
@@ -163,9 +218,9 @@ def init_lib(path):
     c_lib.service_async_vec_string_new.argtypes = []
     c_lib.service_basic_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_basic_new.argtypes = []
-    c_lib.service_callbacks_callback_ffi_return.argtypes = [ctypes.c_void_p, ctypes.CFUNCTYPE(ResultError, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)]
-    c_lib.service_callbacks_callback_simple.argtypes = [ctypes.c_void_p, ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)]
-    c_lib.service_callbacks_callback_with_slice.argtypes = [ctypes.c_void_p, ctypes.CFUNCTYPE(ResultError, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p), SliceI32]
+    c_lib.service_callbacks_callback_ffi_return.argtypes = [ctypes.c_void_p, SumDelegateReturn]
+    c_lib.service_callbacks_callback_simple.argtypes = [ctypes.c_void_p, MyCallback]
+    c_lib.service_callbacks_callback_with_slice.argtypes = [ctypes.c_void_p, SumDelegateReturn, SliceI32]
     c_lib.service_callbacks_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_callbacks_invoke_delegates.argtypes = [ctypes.c_void_p]
     c_lib.service_callbacks_new.argtypes = []
@@ -173,33 +228,66 @@ def init_lib(path):
     c_lib.service_dependent_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_dependent_from_main.argtypes = [ctypes.c_void_p]
     c_lib.service_dependent_get.argtypes = [ctypes.c_void_p]
+    c_lib.service_foreign_logger_destroy.argtypes = [ctypes.c_void_p]
+    c_lib.service_foreign_logger_log.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
+    c_lib.service_foreign_logger_new.argtypes = [LogSinkVTable]
     c_lib.service_ignoring_methods_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_ignoring_methods_new.argtypes = []
     c_lib.service_main_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_main_new.argtypes = [ctypes.c_uint32]
     c_lib.service_multiple_ctors_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_multiple_ctors_new_failing.argtypes = [ctypes.c_uint8]
+    c_lib.service_multiple_ctors_new_std.argtypes = [ctypes.c_uint32]
     c_lib.service_multiple_ctors_new_with.argtypes = [ctypes.c_uint32]
     c_lib.service_multiple_ctors_new_with_string.argtypes = [ctypes.POINTER(ctypes.c_char)]
     c_lib.service_multiple_ctors_new_without.argtypes = []
+    c_lib.service_mutex_destroy.argtypes = [ctypes.c_void_p]
+    c_lib.service_mutex_increment.argtypes = [ctypes.c_void_p]
+    c_lib.service_mutex_new.argtypes = []
+    c_lib.service_mutex_value.argtypes = [ctypes.c_void_p]
     c_lib.service_on_panic_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_on_panic_new.argtypes = []
     c_lib.service_on_panic_return_default_value.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
     c_lib.service_on_panic_return_result.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
     c_lib.service_on_panic_return_ub_on_panic.argtypes = [ctypes.c_void_p]
+    c_lib.service_properties_destroy.argtypes = [ctypes.c_void_p]
+    c_lib.service_properties_get_updates.argtypes = [ctypes.c_void_p]
+    c_lib.service_properties_get_value.argtypes = [ctypes.c_void_p]
+    c_lib.service_properties_new.argtypes = []
+    c_lib.service_properties_set_value.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
     c_lib.service_result_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_result_new.argtypes = []
     c_lib.service_result_result_option_enum.argtypes = [ctypes.c_void_p]
     c_lib.service_result_result_slice.argtypes = [ctypes.c_void_p, SliceU32, ctypes.c_uint64]
+    c_lib.service_result_result_std.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
+    c_lib.service_result_result_std_mapped.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
     c_lib.service_result_result_string.argtypes = [ctypes.c_void_p]
     c_lib.service_result_result_u32.argtypes = [ctypes.c_void_p]
     c_lib.service_result_test.argtypes = [ctypes.c_void_p]
-    c_lib.service_strings_callback_string.argtypes = [ctypes.c_void_p, Utf8String, ctypes.CFUNCTYPE(None, Utf8String, ctypes.c_void_p)]
+    c_lib.service_statics_default_scale.argtypes = []
+    c_lib.service_statics_destroy.argtypes = [ctypes.c_void_p]
+    c_lib.service_statics_new_with.argtypes = [ctypes.c_uint32]
+    c_lib.service_statics_scale.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
+    c_lib.service_statics_scaled.argtypes = [ctypes.c_uint32, ctypes.c_uint32]
+    c_lib.service_storage_array_as_storage.argtypes = [ctypes.c_void_p]
+    c_lib.service_storage_array_destroy.argtypes = [ctypes.c_void_p]
+    c_lib.service_storage_array_new.argtypes = []
+    c_lib.service_storage_offset_as_storage.argtypes = [ctypes.c_void_p]
+    c_lib.service_storage_offset_destroy.argtypes = [ctypes.c_void_p]
+    c_lib.service_storage_offset_get.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
+    c_lib.service_storage_offset_new.argtypes = [ctypes.c_uint32]
+    c_lib.service_storage_offset_offset.argtypes = [ctypes.c_void_p]
+    c_lib.service_strings_callback_string.argtypes = [ctypes.c_void_p, Utf8String, StringCallback]
     c_lib.service_strings_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_strings_new.argtypes = []
     c_lib.service_strings_new_string.argtypes = [Utf8String]
     c_lib.service_strings_pass_cstr.argtypes = [ctypes.c_void_p, ctypes.POINTER(ctypes.c_char)]
+    c_lib.service_strings_pass_str.argtypes = [ctypes.c_void_p, Utf8Str]
     c_lib.service_strings_return_cstr.argtypes = [ctypes.c_void_p]
+    c_lib.service_thread_affine_destroy.argtypes = [ctypes.c_void_p]
+    c_lib.service_thread_affine_increment.argtypes = [ctypes.c_void_p]
+    c_lib.service_thread_affine_new.argtypes = []
+    c_lib.service_thread_affine_value.argtypes = [ctypes.c_void_p]
     c_lib.service_various_slices_destroy.argtypes = [ctypes.c_void_p]
     c_lib.service_various_slices_mut_self.argtypes = [ctypes.c_void_p, SliceU8]
     c_lib.service_various_slices_mut_self_ffi_error.argtypes = [ctypes.c_void_p, SliceMutU8]
@@ -209,28 +297,38 @@ def init_lib(path):
     c_lib.service_various_slices_mut_self_ref_slice_limited.argtypes = [ctypes.c_void_p, ctypes.POINTER(ctypes.c_uint8), ctypes.POINTER(ctypes.c_uint8), SliceU8, SliceU8]
     c_lib.service_various_slices_mut_self_void.argtypes = [ctypes.c_void_p, SliceBool]
     c_lib.service_various_slices_new.argtypes = []
+    c_lib.service_various_slices_return_iter.argtypes = [ctypes.c_void_p]
+    c_lib.service_various_slices_return_len_and_first.argtypes = [ctypes.c_void_p]
     c_lib.service_various_slices_return_slice.argtypes = [ctypes.c_void_p]
     c_lib.service_various_slices_return_slice_mut.argtypes = [ctypes.c_void_p]
+    c_lib.storage_get.argtypes = [DynStorage, ctypes.c_uint32]
+    c_lib.storage_set.argtypes = [DynStorage, ctypes.c_uint32, ctypes.c_uint32]
     c_lib.struct1.argtypes = [Tupled]
     c_lib.struct2.argtypes = [Vec3f32, ctypes.POINTER(Tupled)]
     c_lib.struct3.argtypes = [BoolField]
+    c_lib.struct4.argtypes = [WideNum]
     c_lib.wire_accept_string_2.argtypes = [todo]
     c_lib.alignment_1.restype = Packed2
     c_lib.array_1.restype = ctypes.c_uint8
     c_lib.array_2.restype = Array
-    c_lib.array_3.restype = 
-    c_lib.behavior_panics.restype = 
+    c_lib.array_3.restype = None
+    c_lib.behavior_panic_callbacks.restype = ctypes.c_uint32
+    c_lib.behavior_panics.restype = None
+    c_lib.behavior_panics_callback.restype = ctypes.c_uint32
+    c_lib.behavior_panics_ffi_error.restype = ResultU32Error
+    c_lib.behavior_panics_return_default.restype = ctypes.c_uint32
     c_lib.behavior_panics_via_result.restype = ResultError
-    c_lib.behavior_sleep.restype = 
+    c_lib.behavior_sleep.restype = None
     c_lib.char_array_1.restype = CharArray
     c_lib.char_array_2.restype = CharArray
     c_lib.char_array_3.restype = ctypes.c_uint8
-    c_lib.enums_1.restype = 
-    c_lib.enums_2.restype = ctypes.c_int
-    c_lib.enums_3.restype = ctypes.POINTER(ctypes.c_int)
+    c_lib.enums_1.restype = None
+    c_lib.enums_2.restype = EnumPayload
+    c_lib.enums_3.restype = ctypes.POINTER(EnumPayload)
     c_lib.enums_4.restype = Utf8String
     c_lib.fnptr_1.restype = ctypes.c_uint8
-    c_lib.fnptr_2.restype = 
+    c_lib.fnptr_2.restype = None
+    c_lib.fnptr_3.restype = ctypes.c_uint32
     c_lib.generic_1a.restype = ctypes.c_uint32
     c_lib.generic_1b.restype = ctypes.c_uint8
     c_lib.generic_1c.restype = ctypes.c_uint8
@@ -238,8 +336,19 @@ def init_lib(path):
     c_lib.generic_3.restype = ctypes.c_uint8
     c_lib.generic_4.restype = ctypes.c_uint8
     c_lib.generic_5.restype = ctypes.c_bool
+    c_lib.interoptopus_alloc.restype = ctypes.c_void_p
+    c_lib.interoptopus_allocator_set.restype = ctypes.c_int64
+    c_lib.interoptopus_free.restype = None
+    c_lib.interoptopus_iter_destroy_11887687051161408401.restype = ctypes.c_int64
+    c_lib.interoptopus_iter_destroy_14403488365818035019.restype = ctypes.c_int64
+    c_lib.interoptopus_iter_next_12283536038193100041.restype = ctypes.c_int64
+    c_lib.interoptopus_iter_next_14415398661037898230.restype = ctypes.c_int64
+    c_lib.interoptopus_last_error.restype = Utf8String
+    c_lib.interoptopus_map_create_6262311165280223196.restype = ctypes.c_int64
+    c_lib.interoptopus_map_destroy_9095550523068598099.restype = ctypes.c_int64
     c_lib.interoptopus_string_clone.restype = ctypes.c_int64
     c_lib.interoptopus_string_create.restype = ctypes.c_int64
+    c_lib.interoptopus_string_create_utf16.restype = ctypes.c_int64
     c_lib.interoptopus_string_destroy.restype = ctypes.c_int64
     c_lib.interoptopus_vec_create_12058709700293828778.restype = ctypes.c_int64
     c_lib.interoptopus_vec_create_17163327104750939811.restype = ctypes.c_int64
@@ -249,19 +358,19 @@ def init_lib(path):
     c_lib.interoptopus_vec_destroy_13145557392013674812.restype = ctypes.c_int64
     c_lib.interoptopus_vec_destroy_4854562680055612543.restype = ctypes.c_int64
     c_lib.interoptopus_vec_destroy_9353420175730321243.restype = ctypes.c_int64
-    c_lib.interoptopus_wire_destroy.restype = 
+    c_lib.interoptopus_wire_destroy.restype = None
     c_lib.meta_ambiguous_1.restype = Vec1
     c_lib.meta_ambiguous_2.restype = Vec2
     c_lib.meta_ambiguous_3.restype = ctypes.c_bool
     c_lib.meta_documented.restype = ctypes.c_int
     c_lib.meta_renamed.restype = ctypes.c_int
-    c_lib.meta_visibility1.restype = 
-    c_lib.namespaced_inner_option.restype = TODO
+    c_lib.meta_visibility1.restype = None
+    c_lib.namespaced_inner_option.restype = OptionVec
     c_lib.namespaced_inner_slice.restype = SliceVec
     c_lib.namespaced_inner_slice_mut.restype = SliceMutVec
     c_lib.namespaced_type.restype = Vec
     c_lib.nested_array_1.restype = NestedArray
-    c_lib.nested_array_2.restype = 
+    c_lib.nested_array_2.restype = None
     c_lib.nested_array_3.restype = ctypes.c_uint8
     c_lib.pattern_api_guard.restype = ctypes.c_uint64
     c_lib.pattern_ascii_pointer_1.restype = ctypes.c_uint32
@@ -271,39 +380,65 @@ def init_lib(path):
     c_lib.pattern_ascii_pointer_5.restype = ctypes.c_uint8
     c_lib.pattern_ascii_pointer_return_slice.restype = SliceUseCStrPtr
     c_lib.pattern_callback_1.restype = ctypes.c_uint32
-    c_lib.pattern_callback_10.restype = 
-    c_lib.pattern_callback_2.restype = ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_void_p)
+    c_lib.pattern_callback_10.restype = None
+    c_lib.pattern_callback_11.restype = ctypes.c_uint32
+    c_lib.pattern_callback_12.restype = ResultU32Error
+    c_lib.pattern_callback_13.restype = ctypes.c_uint8
+    c_lib.pattern_callback_14.restype = ctypes.c_uint8
+    c_lib.pattern_callback_2.restype = MyCallbackVoid
     c_lib.pattern_callback_4.restype = ctypes.c_uint32
-    c_lib.pattern_callback_5.restype = ctypes.CFUNCTYPE(None, ctypes.c_void_p)
-    c_lib.pattern_callback_6.restype = ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)
+    c_lib.pattern_callback_5.restype = SumDelegate1
+    c_lib.pattern_callback_6.restype = SumDelegate2
     c_lib.pattern_callback_7.restype = ResultError
-    c_lib.pattern_callback_8.restype = 
-    c_lib.pattern_callback_9.restype = ctypes.c_int32
+    c_lib.pattern_callback_8.restype = None
+    c_lib.pattern_callback_9.restype = ctypes.c_uint32
+    c_lib.pattern_duration_1.restype = Duration
+    c_lib.pattern_duration_2.restype = Duration
+    c_lib.pattern_duration_3.restype = Duration
     c_lib.pattern_ffi_bool.restype = ctypes.c_uint8
     c_lib.pattern_ffi_cchar.restype = ctypes.c_char
     c_lib.pattern_ffi_cchar_const_pointer.restype = ctypes.POINTER(ctypes.c_char)
     c_lib.pattern_ffi_cchar_mut_pointer.restype = ctypes.POINTER(ctypes.c_char)
-    c_lib.pattern_ffi_option_1.restype = TODO
+    c_lib.pattern_ffi_option_1.restype = OptionInner
     c_lib.pattern_ffi_option_2.restype = Inner
-    c_lib.pattern_ffi_option_3.restype = TODO
+    c_lib.pattern_ffi_option_3.restype = OptionOptionResultOptionUtf8StringError
     c_lib.pattern_ffi_slice_1.restype = ctypes.c_uint32
     c_lib.pattern_ffi_slice_1b.restype = ctypes.c_uint32
     c_lib.pattern_ffi_slice_2.restype = Vec3f32
-    c_lib.pattern_ffi_slice_3.restype = 
-    c_lib.pattern_ffi_slice_4.restype = 
-    c_lib.pattern_ffi_slice_5.restype = 
-    c_lib.pattern_ffi_slice_6.restype = 
-    c_lib.pattern_ffi_slice_8.restype = 
+    c_lib.pattern_ffi_slice_3.restype = None
+    c_lib.pattern_ffi_slice_4.restype = None
+    c_lib.pattern_ffi_slice_5.restype = None
+    c_lib.pattern_ffi_slice_6.restype = None
+    c_lib.pattern_ffi_slice_8.restype = None
     c_lib.pattern_ffi_slice_9.restype = Utf8String
     c_lib.pattern_ffi_slice_delegate.restype = ctypes.c_uint8
     c_lib.pattern_ffi_slice_delegate_huge.restype = Vec3f32
+    c_lib.pattern_foreign_trait_1.restype = ctypes.c_uint32
+    c_lib.pattern_iter_1.restype = IterU32
+    c_lib.pattern_iter_2.restype = IterVec3f32
+    c_lib.pattern_iter_3.restype = ctypes.c_uint32
+    c_lib.pattern_iter_4.restype = IterU32
+    c_lib.pattern_map_1.restype = MapU32Vec3f32
+    c_lib.pattern_map_2.restype = OptionVec3f32
+    c_lib.pattern_map_3.restype = MapU32Vec3f32
+    c_lib.pattern_ndslice_1.restype = ctypes.c_float
+    c_lib.pattern_ndslice_2.restype = ctypes.c_int32
+    c_lib.pattern_ndslice_3.restype = None
     c_lib.pattern_result_1.restype = ResultU32Error
     c_lib.pattern_result_2.restype = ResultError
     c_lib.pattern_result_3.restype = ResultError
     c_lib.pattern_result_4.restype = ResultVoid
+    c_lib.pattern_result_5.restype = ResultU32Error
+    c_lib.pattern_result_6.restype = ResultU32Error
+    c_lib.pattern_service_interface_1.restype = ctypes.c_uint32
+    c_lib.pattern_str_1.restype = ctypes.c_uint32
+    c_lib.pattern_str_2.restype = Utf8Str
+    c_lib.pattern_str_3.restype = Utf8Str
+    c_lib.pattern_str_4.restype = ctypes.c_uint32
+    c_lib.pattern_str_5.restype = ResultU32Error
     c_lib.pattern_string_1.restype = Utf8String
-    c_lib.pattern_string_10.restype = 
-    c_lib.pattern_string_11.restype = 
+    c_lib.pattern_string_10.restype = None
+    c_lib.pattern_string_11.restype = None
     c_lib.pattern_string_2.restype = ctypes.c_uint32
     c_lib.pattern_string_3.restype = Utf8String
     c_lib.pattern_string_4.restype = UseString
@@ -313,32 +448,41 @@ def init_lib(path):
     c_lib.pattern_string_7.restype = ResultUtf8StringError
     c_lib.pattern_string_8.restype = ResultUseStringError
     c_lib.pattern_string_9.restype = ResultUtf8StringError
-    c_lib.pattern_surrogates_1.restype = 
+    c_lib.pattern_surrogates_1.restype = None
+    c_lib.pattern_tuple_1.restype = Tuple2_F32_U32
+    c_lib.pattern_tuple_2.restype = Tuple3_Vec3f32_U8_I64
+    c_lib.pattern_tuple_3.restype = Tuples
+    c_lib.pattern_tuple_4.restype = ctypes.c_uint32
+    c_lib.pattern_tuple_5.restype = ctypes.c_uint32
     c_lib.pattern_vec_1.restype = VecU8
-    c_lib.pattern_vec_2.restype = 
+    c_lib.pattern_vec_2.restype = None
     c_lib.pattern_vec_3.restype = VecU8
     c_lib.pattern_vec_4.restype = VecU8
     c_lib.pattern_vec_5.restype = VecUtf8String
     c_lib.pattern_vec_6.restype = VecVec3f32
-    c_lib.pattern_vec_7.restype = 
+    c_lib.pattern_vec_7.restype = None
     c_lib.pattern_vec_8.restype = UseSliceAndVec
     c_lib.primitive_args_10.restype = ctypes.c_int64
     c_lib.primitive_args_5.restype = ctypes.c_int64
     c_lib.primitive_bool.restype = ctypes.c_bool
+    c_lib.primitive_char.restype = ctypes.c_uint32
     c_lib.primitive_f32.restype = ctypes.c_float
     c_lib.primitive_f64.restype = ctypes.c_double
+    c_lib.primitive_i128.restype = _I128
     c_lib.primitive_i16.restype = ctypes.c_int16
     c_lib.primitive_i32.restype = ctypes.c_int32
     c_lib.primitive_i64.restype = ctypes.c_int64
     c_lib.primitive_i8.restype = ctypes.c_int8
     c_lib.primitive_isize.restype = ctypes.c_ssize_t
+    c_lib.primitive_nonzero.restype = ctypes.c_uint32
+    c_lib.primitive_u128.restype = _U128
     c_lib.primitive_u16.restype = ctypes.c_uint16
     c_lib.primitive_u32.restype = ctypes.c_uint32
     c_lib.primitive_u64.restype = ctypes.c_uint64
     c_lib.primitive_u8.restype = ctypes.c_uint8
     c_lib.primitive_usize.restype = ctypes.c_size_t
-    c_lib.primitive_void.restype = 
-    c_lib.primitive_void2.restype = 
+    c_lib.primitive_void.restype = None
+    c_lib.primitive_void2.restype = None
     c_lib.ptr1.restype = ctypes.POINTER(ctypes.c_int64)
     c_lib.ptr2.restype = ctypes.POINTER(ctypes.POINTER(ctypes.c_int64))
     c_lib.ptr3.restype = ctypes.POINTER(ctypes.c_int64)
@@ -346,9 +490,9 @@ def init_lib(path):
     c_lib.ref2.restype = ctypes.POINTER(ctypes.c_int64)
     c_lib.ref3.restype = ctypes.c_bool
     c_lib.ref4.restype = ctypes.c_bool
-    c_lib.ref5.restype = 
-    c_lib.ref6.restype = 
-    c_lib.ref7.restype = 
+    c_lib.ref5.restype = None
+    c_lib.ref6.restype = None
+    c_lib.ref7.restype = None
     c_lib.service_async_basic_call.restype = ResultError
     c_lib.service_async_basic_destroy.restype = ResultConstPtrServiceAsyncBasicError
     c_lib.service_async_basic_new.restype = ResultConstPtrServiceAsyncBasicError
@@ -375,37 +519,70 @@ def init_lib(path):
     c_lib.service_callbacks_destroy.restype = ResultConstPtrServiceCallbacksError
     c_lib.service_callbacks_invoke_delegates.restype = ResultError
     c_lib.service_callbacks_new.restype = ResultConstPtrServiceCallbacksError
-    c_lib.service_callbacks_set_delegate_table.restype = 
+    c_lib.service_callbacks_set_delegate_table.restype = None
     c_lib.service_dependent_destroy.restype = ResultConstPtrServiceDependentError
     c_lib.service_dependent_from_main.restype = ResultConstPtrServiceDependentError
     c_lib.service_dependent_get.restype = ctypes.c_uint32
+    c_lib.service_foreign_logger_destroy.restype = ResultConstPtrServiceForeignLoggerError
+    c_lib.service_foreign_logger_log.restype = ResultError
+    c_lib.service_foreign_logger_new.restype = ResultConstPtrServiceForeignLoggerError
     c_lib.service_ignoring_methods_destroy.restype = ResultConstPtrServiceIgnoringMethodsError
     c_lib.service_ignoring_methods_new.restype = ResultConstPtrServiceIgnoringMethodsError
     c_lib.service_main_destroy.restype = ResultConstPtrServiceMainError
     c_lib.service_main_new.restype = ResultConstPtrServiceMainError
     c_lib.service_multiple_ctors_destroy.restype = ResultConstPtrServiceMultipleCtorsError
     c_lib.service_multiple_ctors_new_failing.restype = ResultConstPtrServiceMultipleCtorsError
+    c_lib.service_multiple_ctors_new_std.restype = ResultConstPtrServiceMultipleCtorsError
     c_lib.service_multiple_ctors_new_with.restype = ResultConstPtrServiceMultipleCtorsError
     c_lib.service_multiple_ctors_new_with_string.restype = ResultConstPtrServiceMultipleCtorsError
     c_lib.service_multiple_ctors_new_without.restype = ResultConstPtrServiceMultipleCtorsError
+    c_lib.service_mutex_destroy.restype = ResultConstPtrServiceMutexError
+    c_lib.service_mutex_increment.restype = ctypes.c_uint32
+    c_lib.service_mutex_new.restype = ResultConstPtrServiceMutexError
+    c_lib.service_mutex_value.restype = ctypes.c_uint32
     c_lib.service_on_panic_destroy.restype = ResultConstPtrServiceOnPanicError
     c_lib.service_on_panic_new.restype = ResultConstPtrServiceOnPanicError
     c_lib.service_on_panic_return_default_value.restype = ctypes.c_uint32
     c_lib.service_on_panic_return_result.restype = ResultError
     c_lib.service_on_panic_return_ub_on_panic.restype = ctypes.POINTER(ctypes.c_char)
+    c_lib.service_properties_destroy.restype = ResultConstPtrServicePropertiesError
+    c_lib.service_properties_get_updates.restype = ctypes.c_uint32
+    c_lib.service_properties_get_value.restype = ctypes.c_uint32
+    c_lib.service_properties_new.restype = ResultConstPtrServicePropertiesError
+    c_lib.service_properties_set_value.restype = None
     c_lib.service_result_destroy.restype = ResultConstPtrServiceResultError
     c_lib.service_result_new.restype = ResultConstPtrServiceResultError
     c_lib.service_result_result_option_enum.restype = ResultOptionEnumPayloadError
     c_lib.service_result_result_slice.restype = ResultU32Error
+    c_lib.service_result_result_std.restype = ResultU32Error
+    c_lib.service_result_result_std_mapped.restype = ResultU32Error
     c_lib.service_result_result_string.restype = ResultUtf8StringError
     c_lib.service_result_result_u32.restype = ResultU32Error
     c_lib.service_result_test.restype = ResultError
-    c_lib.service_strings_callback_string.restype = 
+    c_lib.service_statics_default_scale.restype = ctypes.c_uint32
+    c_lib.service_statics_destroy.restype = ResultConstPtrServiceStaticsError
+    c_lib.service_statics_new_with.restype = ResultConstPtrServiceStaticsError
+    c_lib.service_statics_scale.restype = ctypes.c_uint32
+    c_lib.service_statics_scaled.restype = ctypes.c_uint32
+    c_lib.service_storage_array_as_storage.restype = DynStorage
+    c_lib.service_storage_array_destroy.restype = ResultConstPtrServiceStorageArrayError
+    c_lib.service_storage_array_new.restype = ResultConstPtrServiceStorageArrayError
+    c_lib.service_storage_offset_as_storage.restype = DynStorage
+    c_lib.service_storage_offset_destroy.restype = ResultConstPtrServiceStorageOffsetError
+    c_lib.service_storage_offset_get.restype = ctypes.c_uint32
+    c_lib.service_storage_offset_new.restype = ResultConstPtrServiceStorageOffsetError
+    c_lib.service_storage_offset_offset.restype = ctypes.c_uint32
+    c_lib.service_strings_callback_string.restype = None
     c_lib.service_strings_destroy.restype = ResultConstPtrServiceStringsError
     c_lib.service_strings_new.restype = ResultConstPtrServiceStringsError
     c_lib.service_strings_new_string.restype = ResultConstPtrServiceStringsError
-    c_lib.service_strings_pass_cstr.restype = 
+    c_lib.service_strings_pass_cstr.restype = None
+    c_lib.service_strings_pass_str.restype = ctypes.c_uint32
     c_lib.service_strings_return_cstr.restype = ctypes.POINTER(ctypes.c_char)
+    c_lib.service_thread_affine_destroy.restype = ResultConstPtrServiceThreadAffineSyncError
+    c_lib.service_thread_affine_increment.restype = ResultSyncError
+    c_lib.service_thread_affine_new.restype = ResultConstPtrServiceThreadAffineSyncError
+    c_lib.service_thread_affine_value.restype = ctypes.c_uint32
     c_lib.service_various_slices_destroy.restype = ResultConstPtrServiceVariousSlicesError
     c_lib.service_various_slices_mut_self.restype = ctypes.c_uint8
     c_lib.service_various_slices_mut_self_ffi_error.restype = ResultError
@@ -413,52 +590,111 @@ def init_lib(path):
     c_lib.service_various_slices_mut_self_ref.restype = ctypes.c_uint8
     c_lib.service_various_slices_mut_self_ref_slice.restype = ctypes.c_uint8
     c_lib.service_various_slices_mut_self_ref_slice_limited.restype = ctypes.c_uint8
-    c_lib.service_various_slices_mut_self_void.restype = 
+    c_lib.service_various_slices_mut_self_void.restype = None
     c_lib.service_various_slices_new.restype = ResultConstPtrServiceVariousSlicesError
+    c_lib.service_various_slices_return_iter.restype = IterU32
+    c_lib.service_various_slices_return_len_and_first.restype = Tuple2_U64_U32
     c_lib.service_various_slices_return_slice.restype = SliceU32
     c_lib.service_various_slices_return_slice_mut.restype = SliceMutU32
+    c_lib.storage_get.restype = ctypes.c_uint32
+    c_lib.storage_set.restype = None
     c_lib.struct1.restype = Tupled
     c_lib.struct2.restype = ResultError
     c_lib.struct3.restype = ctypes.c_bool
-    c_lib.wire_accept_string_2.restype = 
+    c_lib.struct4.restype = WideNum
+    c_lib.wire_accept_string_2.restype = None
 
 
 def interoptopus_string_create(utf8: ctypes.c_void_p, len: int, rval: ctypes.POINTER(Utf8String)) -> int:
+    """ Creates a string from `len` UTF-8 bytes, returns `0` on success or `1` (and an empty string) if they are not valid UTF-8."""
     return c_lib.interoptopus_string_create(utf8, len, rval)
 
+def interoptopus_string_create_utf16(utf16: ctypes.c_void_p, len: int, rval: ctypes.POINTER(Utf8String)) -> int:
+    """ Creates a string from `len` UTF-16 code units, replacing unpaired surrogates with `U+FFFD` like .NET does; always returns `0`."""
+    return c_lib.interoptopus_string_create_utf16(utf16, len, rval)
+
 def interoptopus_string_destroy(utf8) -> int:
     return c_lib.interoptopus_string_destroy(utf8)
 
 def interoptopus_string_clone(utf8: ctypes.POINTER(Utf8String), rval: ctypes.POINTER(Utf8String)) -> int:
     return c_lib.interoptopus_string_clone(utf8, rval)
 
+def interoptopus_last_error():
+    """ Returns and clears details about the last error or panic on this thread, or an empty string."""
+    return c_lib.interoptopus_last_error()
+
 def interoptopus_wire_destroy(data: ctypes.POINTER(ctypes.c_uint8), len: int, capacity: int):
     return c_lib.interoptopus_wire_destroy(data, len, capacity)
 
+def interoptopus_allocator_set(alloc, free) -> int:
+    """ Installs allocation hooks for all FFI buffers, returns `0` on success or `1` if it is too late to do so."""
+    if not hasattr(alloc, "__ctypes_from_outparam__"):
+        alloc = callbacks.fn_u64_u64_rval_MutPtrVoid(alloc)
+
+    if not hasattr(free, "__ctypes_from_outparam__"):
+        free = callbacks.fn_MutPtrVoid_u64_u64_rval_void(free)
+
+    return c_lib.interoptopus_allocator_set(alloc, free)
+
+def interoptopus_alloc(size: int, align: int) -> ctypes.c_void_p:
+    """ Allocates `size` bytes aligned to `align` that Rust may later free, returns null on failure."""
+    return c_lib.interoptopus_alloc(size, align)
+
+def interoptopus_free(ptr: ctypes.c_void_p, size: int, align: int):
+    """ Frees memory allocated by `interoptopus_alloc`, or owned by a buffer Rust handed out; invalid `size` and `align` values are ignored."""
+    return c_lib.interoptopus_free(ptr, size, align)
+
 def interoptopus_vec_create_6849152863081469284(data: ctypes.c_void_p, len: int, rval: ctypes.POINTER(VecU8)) -> int:
     return c_lib.interoptopus_vec_create_6849152863081469284(data, len, rval)
 
-def interoptopus_vec_destroy_9353420175730321243(ignored) -> int:
+def interoptopus_vec_destroy_9353420175730321243(ignored: VecU8 | collections.abc.Buffer) -> int:
+    if not isinstance(ignored, VecU8):
+        ignored = VecU8.from_buffer(ignored)
+
     return c_lib.interoptopus_vec_destroy_9353420175730321243(ignored)
 
 def interoptopus_vec_create_17163327104750939811(data: ctypes.c_void_p, len: int, rval: ctypes.POINTER(VecUtf8String)) -> int:
     return c_lib.interoptopus_vec_create_17163327104750939811(data, len, rval)
 
-def interoptopus_vec_destroy_13145557392013674812(ignored) -> int:
+def interoptopus_vec_destroy_13145557392013674812(ignored: VecUtf8String) -> int:
     return c_lib.interoptopus_vec_destroy_13145557392013674812(ignored)
 
 def interoptopus_vec_create_1781563852271199874(data: ctypes.c_void_p, len: int, rval: ctypes.POINTER(VecVec3f32)) -> int:
     return c_lib.interoptopus_vec_create_1781563852271199874(data, len, rval)
 
-def interoptopus_vec_destroy_12107015372467426116(ignored) -> int:
+def interoptopus_vec_destroy_12107015372467426116(ignored: VecVec3f32) -> int:
     return c_lib.interoptopus_vec_destroy_12107015372467426116(ignored)
 
 def interoptopus_vec_create_12058709700293828778(data: ctypes.c_void_p, len: int, rval: ctypes.POINTER(VecEnumPayload)) -> int:
     return c_lib.interoptopus_vec_create_12058709700293828778(data, len, rval)
 
-def interoptopus_vec_destroy_4854562680055612543(ignored) -> int:
+def interoptopus_vec_destroy_4854562680055612543(ignored: VecEnumPayload) -> int:
     return c_lib.interoptopus_vec_destroy_4854562680055612543(ignored)
 
+def interoptopus_map_create_6262311165280223196(keys: ctypes.c_void_p, values: ctypes.c_void_p, len: int, rval: ctypes.POINTER(MapU32Vec3f32)) -> int:
+    return c_lib.interoptopus_map_create_6262311165280223196(keys, values, len, rval)
+
+def interoptopus_map_destroy_9095550523068598099(ignored: MapU32Vec3f32) -> int:
+    return c_lib.interoptopus_map_destroy_9095550523068598099(ignored)
+
+def interoptopus_iter_next_14415398661037898230(iter: ctypes.POINTER(IterU32), rval: ctypes.POINTER(ctypes.c_uint32)) -> int:
+    """ Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked."""
+    return c_lib.interoptopus_iter_next_14415398661037898230(iter, rval)
+
+def interoptopus_iter_destroy_11887687051161408401(ignored: IterU32) -> int:
+    ignored = ignored._move()
+
+    return c_lib.interoptopus_iter_destroy_11887687051161408401(ignored)
+
+def interoptopus_iter_next_12283536038193100041(iter: ctypes.POINTER(IterVec3f32), rval: ctypes.POINTER(Vec3f32)) -> int:
+    """ Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked."""
+    return c_lib.interoptopus_iter_next_12283536038193100041(iter, rval)
+
+def interoptopus_iter_destroy_14403488365818035019(ignored: IterVec3f32) -> int:
+    ignored = ignored._move()
+
+    return c_lib.interoptopus_iter_destroy_14403488365818035019(ignored)
+
 def alignment_1(a: Packed1) -> Packed2:
     return c_lib.alignment_1(a)
 
@@ -495,19 +731,31 @@ def behavior_sleep(millis: int):
 def behavior_panics():
     return c_lib.behavior_panics()
 
-def behavior_panics_via_result():
+def behavior_panics_via_result() -> ResultError:
     return c_lib.behavior_panics_via_result()
 
-def enums_1(ignored: TODO):
+def behavior_panics_ffi_error() -> ResultU32Error:
+    return c_lib.behavior_panics_ffi_error()
+
+def behavior_panics_return_default() -> int:
+    return c_lib.behavior_panics_return_default()
+
+def behavior_panics_callback() -> int:
+    return c_lib.behavior_panics_callback()
+
+def behavior_panic_callbacks() -> int:
+    return c_lib.behavior_panic_callbacks()
+
+def enums_1(ignored: EnumPayload):
     return c_lib.enums_1(ignored)
 
-def enums_2(x: TODO) -> TODO:
+def enums_2(x: EnumPayload) -> EnumPayload:
     return c_lib.enums_2(x)
 
-def enums_3(x: ctypes.POINTER(ctypes.c_int)) -> ctypes.POINTER(ctypes.c_int):
+def enums_3(x: ctypes.POINTER(EnumPayload)) -> ctypes.POINTER(EnumPayload):
     return c_lib.enums_3(x)
 
-def enums_4(x: TODO):
+def enums_4(x: Layer3):
     return c_lib.enums_4(x)
 
 def fnptr_1(callback, x: int) -> int:
@@ -522,6 +770,12 @@ def fnptr_2(callback, x: CharArray):
 
     return c_lib.fnptr_2(callback, x)
 
+def fnptr_3(callback) -> int:
+    if not hasattr(callback, "__ctypes_from_outparam__"):
+        callback = callbacks.fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32(callback)
+
+    return c_lib.fnptr_3(callback)
+
 def generic_1a(x: Genericu32, y: Phantomu8) -> int:
     return c_lib.generic_1a(x, y)
 
@@ -552,17 +806,17 @@ def meta_ambiguous_2(x: Vec2) -> Vec2:
 def meta_ambiguous_3(x: Vec1, y: Vec2) -> bool:
     return c_lib.meta_ambiguous_3(x, y)
 
-def meta_documented(x: StructDocumented) -> TODO:
+def meta_documented(x: StructDocumented) -> int:
     """ This function has documentation."""
     return c_lib.meta_documented(x)
 
 def meta_visibility1(x: Visibility1, y: Visibility2):
     return c_lib.meta_visibility1(x, y)
 
-def meta_renamed(x: StructRenamed) -> TODO:
+def meta_renamed(x: StructRenamed) -> int:
     return c_lib.meta_renamed(x)
 
-def namespaced_inner_option(x: TODO) -> TODO:
+def namespaced_inner_option(x: OptionVec) -> OptionVec:
     return c_lib.namespaced_inner_option(x)
 
 def namespaced_inner_slice(x: SliceVec | ctypes.Array[Vec]) -> SliceVec:
@@ -625,6 +879,24 @@ def primitive_isize(x: int) -> int:
 def primitive_u8(x: int) -> int:
     return c_lib.primitive_u8(x)
 
+def primitive_u128(x: int) -> int:
+    if not isinstance(x, _U128):
+        x = _U128.from_int(x)
+
+    return c_lib.primitive_u128(x).value
+
+def primitive_i128(x: int) -> int:
+    if not isinstance(x, _I128):
+        x = _I128.from_int(x)
+
+    return c_lib.primitive_i128(x).value
+
+def primitive_char(x: int) -> int:
+    return c_lib.primitive_char(x)
+
+def primitive_nonzero(x: int) -> int:
+    return c_lib.primitive_nonzero(x)
+
 def primitive_void():
     return c_lib.primitive_void()
 
@@ -655,10 +927,10 @@ def ref3(x: ctypes.POINTER(ctypes.c_int64)) -> bool:
 def ref4(x: ctypes.POINTER(ctypes.c_int64)) -> bool:
     return c_lib.ref4(x)
 
-def ref5(x: ctypes.POINTER(ctypes.c_int)):
+def ref5(x: ctypes.POINTER(EnumPayload)):
     return c_lib.ref5(x)
 
-def ref6(x: ctypes.POINTER(TODO)):
+def ref6(x: ctypes.POINTER(OptionEnumPayload)):
     return c_lib.ref6(x)
 
 def ref7(x: ctypes.POINTER(VecUtf8String)):
@@ -667,12 +939,15 @@ def ref7(x: ctypes.POINTER(VecUtf8String)):
 def struct1(x: Tupled) -> Tupled:
     return c_lib.struct1(x)
 
-def struct2(a: Vec3f32, b: ctypes.POINTER(Tupled)):
+def struct2(a: Vec3f32, b: ctypes.POINTER(Tupled)) -> ResultError:
     return c_lib.struct2(a, b)
 
 def struct3(x: BoolField) -> bool:
     return c_lib.struct3(x)
 
+def struct4(x: WideNum) -> WideNum:
+    return c_lib.struct4(x)
+
 def pattern_ascii_pointer_1(x: bytes) -> int:
     if not hasattr(x, "__ctypes_from_outparam__"):
         x = ctypes.cast(x, ctypes.POINTER(ctypes.c_char))
@@ -714,28 +989,28 @@ def pattern_string_3():
 def pattern_string_4(x: UseString) -> UseString:
     return c_lib.pattern_string_4(x)
 
-def pattern_string_5(x: UseString):
+def pattern_string_5(x: UseString) -> ResultUseStringError:
     return c_lib.pattern_string_5(x)
 
-def pattern_string_6a(ignored: ctypes.POINTER(UseString)):
+def pattern_string_6a(ignored: ctypes.POINTER(UseString)) -> ResultError:
     return c_lib.pattern_string_6a(ignored)
 
-def pattern_string_6b(y: ctypes.POINTER(UseString)):
+def pattern_string_6b(y: ctypes.POINTER(UseString)) -> ResultError:
     return c_lib.pattern_string_6b(y)
 
-def pattern_string_7(x: SliceUtf8String | ctypes.Array[Utf8String], i: int):
+def pattern_string_7(x: SliceUtf8String | ctypes.Array[Utf8String], i: int) -> ResultUtf8StringError:
     if hasattr(x, "_length_") and getattr(x, "_type_", "") == Utf8String:
         x = SliceUtf8String(data=ctypes.cast(x, ctypes.POINTER(Utf8String)), len=len(x))
 
     return c_lib.pattern_string_7(x, i)
 
-def pattern_string_8(x: SliceUseString | ctypes.Array[UseString], i: int):
+def pattern_string_8(x: SliceUseString | ctypes.Array[UseString], i: int) -> ResultUseStringError:
     if hasattr(x, "_length_") and getattr(x, "_type_", "") == UseString:
         x = SliceUseString(data=ctypes.cast(x, ctypes.POINTER(UseString)), len=len(x))
 
     return c_lib.pattern_string_8(x, i)
 
-def pattern_string_9():
+def pattern_string_9() -> ResultUtf8StringError:
     return c_lib.pattern_string_9()
 
 def pattern_string_10(ignored):
@@ -744,15 +1019,36 @@ def pattern_string_10(ignored):
 def pattern_string_11(ignored: ctypes.POINTER(Utf8String)):
     return c_lib.pattern_string_11(ignored)
 
-def pattern_ffi_slice_1(ffi_slice: SliceU32 | ctypes.Array[ctypes.c_uint32]) -> int:
-    if hasattr(ffi_slice, "_length_") and getattr(ffi_slice, "_type_", "") == ctypes.c_uint32:
-        ffi_slice = SliceU32(data=ctypes.cast(ffi_slice, ctypes.POINTER(ctypes.c_uint32)), len=len(ffi_slice))
+def pattern_str_1(x: Utf8Str | str | bytes) -> int:
+    if not isinstance(x, Utf8Str):
+        x = Utf8Str.from_bytes(x)
+
+    return c_lib.pattern_str_1(x)
+
+def pattern_str_2() -> Utf8Str:
+    return c_lib.pattern_str_2()
+
+def pattern_str_3(x: Utf8Str) -> Utf8Str:
+    return c_lib.pattern_str_3(x)
+
+def pattern_str_4(x: UseStr) -> int:
+    return c_lib.pattern_str_4(x)
+
+def pattern_str_5(x: Utf8Str | str | bytes) -> ResultU32Error:
+    if not isinstance(x, Utf8Str):
+        x = Utf8Str.from_bytes(x)
+
+    return c_lib.pattern_str_5(x)
+
+def pattern_ffi_slice_1(ffi_slice: SliceU32 | collections.abc.Buffer) -> int:
+    if not isinstance(ffi_slice, SliceU32):
+        ffi_slice = SliceU32.from_buffer(ffi_slice)
 
     return c_lib.pattern_ffi_slice_1(ffi_slice)
 
-def pattern_ffi_slice_1b(ffi_slice: SliceMutU32 | ctypes.Array[ctypes.c_uint32]) -> int:
-    if hasattr(ffi_slice, "_length_") and getattr(ffi_slice, "_type_", "") == ctypes.c_uint32:
-        ffi_slice = SliceMutU32(data=ctypes.cast(ffi_slice, ctypes.POINTER(ctypes.c_uint32)), len=len(ffi_slice))
+def pattern_ffi_slice_1b(ffi_slice: SliceMutU32 | collections.abc.Buffer) -> int:
+    if not isinstance(ffi_slice, SliceMutU32):
+        ffi_slice = SliceMutU32.from_buffer(ffi_slice)
 
     return c_lib.pattern_ffi_slice_1b(ffi_slice)
 
@@ -762,21 +1058,21 @@ def pattern_ffi_slice_2(ffi_slice: SliceVec3f32 | ctypes.Array[Vec3f32], i: int)
 
     return c_lib.pattern_ffi_slice_2(ffi_slice, i)
 
-def pattern_ffi_slice_3(slice: SliceMutU8 | ctypes.Array[ctypes.c_uint8], callback):
-    if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint8:
-        slice = SliceMutU8(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint8)), len=len(slice))
+def pattern_ffi_slice_3(slice: SliceMutU8 | collections.abc.Buffer, callback):
+    if not isinstance(slice, SliceMutU8):
+        slice = SliceMutU8.from_buffer(slice)
 
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_SliceMutU8_ConstPtrVoid_rval_void(callback)
+    if not isinstance(callback, CallbackSliceMut):
+        callback = CallbackSliceMut(callback)
 
     return c_lib.pattern_ffi_slice_3(slice, callback)
 
-def pattern_ffi_slice_4(slice: SliceU8 | ctypes.Array[ctypes.c_uint8], slice2: SliceMutU8 | ctypes.Array[ctypes.c_uint8]):
-    if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint8:
-        slice = SliceU8(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint8)), len=len(slice))
+def pattern_ffi_slice_4(slice: SliceU8 | collections.abc.Buffer, slice2: SliceMutU8 | collections.abc.Buffer):
+    if not isinstance(slice, SliceU8):
+        slice = SliceU8.from_buffer(slice)
 
-    if hasattr(slice2, "_length_") and getattr(slice2, "_type_", "") == ctypes.c_uint8:
-        slice2 = SliceMutU8(data=ctypes.cast(slice2, ctypes.POINTER(ctypes.c_uint8)), len=len(slice2))
+    if not isinstance(slice2, SliceMutU8):
+        slice2 = SliceMutU8.from_buffer(slice2)
 
     return c_lib.pattern_ffi_slice_4(slice, slice2)
 
@@ -785,14 +1081,14 @@ def pattern_ffi_slice_5(slice: ctypes.POINTER(SliceU8), slice2: ctypes.POINTER(S
     return c_lib.pattern_ffi_slice_5(slice, slice2)
 
 def pattern_ffi_slice_6(slice: ctypes.POINTER(SliceMutU8), callback):
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_u8_ConstPtrVoid_rval_u8(callback)
+    if not isinstance(callback, CallbackU8):
+        callback = CallbackU8(callback)
 
     return c_lib.pattern_ffi_slice_6(slice, callback)
 
 def pattern_ffi_slice_8(slice: ctypes.POINTER(SliceMutCharArray), callback):
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_CharArray_ConstPtrVoid_rval_void(callback)
+    if not isinstance(callback, CallbackCharArray2):
+        callback = CallbackCharArray2(callback)
 
     return c_lib.pattern_ffi_slice_8(slice, callback)
 
@@ -803,24 +1099,44 @@ def pattern_ffi_slice_9(slice: SliceUseString | ctypes.Array[UseString]):
     return c_lib.pattern_ffi_slice_9(slice)
 
 def pattern_ffi_slice_delegate(callback) -> int:
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_SliceU8_ConstPtrVoid_rval_u8(callback)
+    if not isinstance(callback, CallbackFFISlice):
+        callback = CallbackFFISlice(callback)
 
     return c_lib.pattern_ffi_slice_delegate(callback)
 
 def pattern_ffi_slice_delegate_huge(callback) -> Vec3f32:
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_SliceVec3f32_ConstPtrVoid_rval_Vec3f32(callback)
+    if not isinstance(callback, CallbackHugeVecSlice):
+        callback = CallbackHugeVecSlice(callback)
 
     return c_lib.pattern_ffi_slice_delegate_huge(callback)
 
-def pattern_ffi_option_1(x: TODO) -> TODO:
+def pattern_ndslice_1(slice: NdSliceF32 | collections.abc.Buffer) -> float:
+    """ Sums all elements, regardless of the number of dimensions or their strides."""
+    if not isinstance(slice, NdSliceF32):
+        slice = NdSliceF32.from_buffer(slice)
+
+    return c_lib.pattern_ndslice_1(slice)
+
+def pattern_ndslice_2(slice: NdSliceI32 | collections.abc.Buffer, row: int, col: int) -> int:
+    if not isinstance(slice, NdSliceI32):
+        slice = NdSliceI32.from_buffer(slice)
+
+    return c_lib.pattern_ndslice_2(slice, row, col)
+
+def pattern_ndslice_3(slice: NdSliceMutU32 | collections.abc.Buffer):
+    """ Sets each element of a 2D slice to `row * 100 + col`."""
+    if not isinstance(slice, NdSliceMutU32):
+        slice = NdSliceMutU32.from_buffer(slice)
+
+    return c_lib.pattern_ndslice_3(slice)
+
+def pattern_ffi_option_1(x: OptionInner) -> OptionInner:
     return c_lib.pattern_ffi_option_1(x)
 
-def pattern_ffi_option_2(x: TODO) -> Inner:
+def pattern_ffi_option_2(x: OptionInner) -> Inner:
     return c_lib.pattern_ffi_option_2(x)
 
-def pattern_ffi_option_3(x: TODO) -> TODO:
+def pattern_ffi_option_3(x: OptionOptionResultOptionUtf8StringError) -> OptionOptionResultOptionUtf8StringError:
     return c_lib.pattern_ffi_option_3(x)
 
 def pattern_ffi_bool(ffi_bool):
@@ -835,36 +1151,42 @@ def pattern_ffi_cchar_const_pointer(ffi_cchar: ctypes.POINTER(ctypes.c_char)) ->
 def pattern_ffi_cchar_mut_pointer(ffi_cchar: ctypes.POINTER(ctypes.c_char)) -> ctypes.POINTER(ctypes.c_char):
     return c_lib.pattern_ffi_cchar_mut_pointer(ffi_cchar)
 
-def pattern_result_1(x):
+def pattern_result_1(x: ResultU32Error) -> ResultU32Error:
     return c_lib.pattern_result_1(x)
 
-def pattern_result_2():
+def pattern_result_2() -> ResultError:
     return c_lib.pattern_result_2()
 
-def pattern_result_3(x):
+def pattern_result_3(x: ResultError) -> ResultError:
     return c_lib.pattern_result_3(x)
 
-def pattern_result_4(x):
+def pattern_result_4(x: ResultVoid) -> ResultVoid:
     return c_lib.pattern_result_4(x)
 
+def pattern_result_5(x: int) -> ResultU32Error:
+    return c_lib.pattern_result_5(x)
+
+def pattern_result_6(x: int) -> ResultU32Error:
+    return c_lib.pattern_result_6(x)
+
 def pattern_api_guard():
     return c_lib.pattern_api_guard()
 
 def pattern_callback_1(callback, x: int) -> int:
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_u32_ConstPtrVoid_rval_u32(callback)
+    if not isinstance(callback, MyCallback):
+        callback = MyCallback(callback)
 
     return c_lib.pattern_callback_1(callback, x)
 
 def pattern_callback_2(callback):
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_ConstPtrVoid_ConstPtrVoid_rval_void(callback)
+    if not isinstance(callback, MyCallbackVoid):
+        callback = MyCallbackVoid(callback)
 
     return c_lib.pattern_callback_2(callback)
 
 def pattern_callback_4(callback, x: int) -> int:
-    if not hasattr(callback, "__ctypes_from_outparam__"):
-        callback = callbacks.fn_u32_ConstPtrVoid_rval_u32(callback)
+    if not isinstance(callback, MyCallbackNamespaced):
+        callback = MyCallbackNamespaced(callback)
 
     return c_lib.pattern_callback_4(callback, x)
 
@@ -874,55 +1196,85 @@ def pattern_callback_5():
 def pattern_callback_6():
     return c_lib.pattern_callback_6()
 
-def pattern_callback_7(c1, c2, x: int, i: int, o: ctypes.POINTER(ctypes.c_int32)):
-    if not hasattr(c1, "__ctypes_from_outparam__"):
-        c1 = callbacks.fn_i32_i32_ConstPtrVoid_rval_ResultError(c1)
+def pattern_callback_7(c1, c2, x: int, i: int, o: ctypes.POINTER(ctypes.c_int32)) -> ResultError:
+    if not isinstance(c1, SumDelegateReturn):
+        c1 = SumDelegateReturn(c1)
 
-    if not hasattr(c2, "__ctypes_from_outparam__"):
-        c2 = callbacks.fn_i32_i32_ConstPtrVoid_rval_void(c2)
+    if not isinstance(c2, SumDelegateReturn2):
+        c2 = SumDelegateReturn2(c2)
 
     return c_lib.pattern_callback_7(c1, c2, x, i, o)
 
 def pattern_callback_8(cb, cb2, s):
-    if not hasattr(cb, "__ctypes_from_outparam__"):
-        cb = callbacks.fn_Utf8String_ConstPtrVoid_rval_void(cb)
+    if not isinstance(cb, StringCallback):
+        cb = StringCallback(cb)
 
-    if not hasattr(cb2, "__ctypes_from_outparam__"):
-        cb2 = callbacks.fn_UseString_ConstPtrVoid_rval_void(cb2)
+    if not isinstance(cb2, NestedStringCallback):
+        cb2 = NestedStringCallback(cb2)
 
     return c_lib.pattern_callback_8(cb, cb2, s)
 
-def pattern_callback_9(x) -> int:
-    if not hasattr(x, "__ctypes_from_outparam__"):
-        x = callbacks.fn_ConstPtrI32_MutPtrI32_ConstPtrVoid_rval_void(x)
+def pattern_callback_9(callback, x: int) -> int:
+    if not isinstance(callback, MyOwnedCallback):
+        callback = MyOwnedCallback(callback)
+
+    return c_lib.pattern_callback_9(callback, x)
+
+def pattern_callback_10(callback):
+    """ Keeps the callback until replaced, dropping the previous one."""
+    if not isinstance(callback, MyOwnedCallback):
+        callback = MyOwnedCallback(callback)
 
-    return c_lib.pattern_callback_9(x)
+    return c_lib.pattern_callback_10(callback)
 
-def pattern_callback_10(ignored):
-    if not hasattr(ignored, "__ctypes_from_outparam__"):
-        ignored = callbacks.fn_Utf8String_ConstPtrVoid_rval_Utf8String(ignored)
+def pattern_callback_11(x: int) -> int:
+    """ Invokes the callback stored by `pattern_callback_10`, returns 0 if there is none."""
+    return c_lib.pattern_callback_11(x)
 
-    return c_lib.pattern_callback_10(ignored)
+def pattern_callback_12(callback, x: int) -> ResultU32Error:
+    """ Returns the callback's result plus one, passing on errors and failures of the callback."""
+    if not isinstance(callback, MyFallibleCallback):
+        callback = MyFallibleCallback(callback)
+
+    return c_lib.pattern_callback_12(callback, x)
+
+def pattern_callback_13(callback, x: int) -> int:
+    if not isinstance(callback, OnItemU32):
+        callback = OnItemU32(callback)
+
+    return c_lib.pattern_callback_13(callback, x)
+
+def pattern_callback_14(callback, x: float) -> int:
+    if not isinstance(callback, OnItemF32):
+        callback = OnItemF32(callback)
+
+    return c_lib.pattern_callback_14(callback, x)
 
 def pattern_surrogates_1(s: Local, c: ctypes.POINTER(Container)):
     return c_lib.pattern_surrogates_1(s, c)
 
-def pattern_vec_1():
+def pattern_vec_1() -> VecU8:
     return c_lib.pattern_vec_1()
 
-def pattern_vec_2(ignored):
+def pattern_vec_2(ignored: VecU8 | collections.abc.Buffer):
+    if not isinstance(ignored, VecU8):
+        ignored = VecU8.from_buffer(ignored)
+
     return c_lib.pattern_vec_2(ignored)
 
-def pattern_vec_3(v):
+def pattern_vec_3(v: VecU8 | collections.abc.Buffer) -> VecU8:
+    if not isinstance(v, VecU8):
+        v = VecU8.from_buffer(v)
+
     return c_lib.pattern_vec_3(v)
 
-def pattern_vec_4(v: ctypes.POINTER(VecU8)):
+def pattern_vec_4(v: ctypes.POINTER(VecU8)) -> VecU8:
     return c_lib.pattern_vec_4(v)
 
-def pattern_vec_5(v):
+def pattern_vec_5(v: VecUtf8String) -> VecUtf8String:
     return c_lib.pattern_vec_5(v)
 
-def pattern_vec_6(v):
+def pattern_vec_6(v: VecVec3f32) -> VecVec3f32:
     return c_lib.pattern_vec_6(v)
 
 def pattern_vec_7(ignored: UseSliceAndVec):
@@ -931,6 +1283,73 @@ def pattern_vec_7(ignored: UseSliceAndVec):
 def pattern_vec_8(v: UseSliceAndVec) -> UseSliceAndVec:
     return c_lib.pattern_vec_8(v)
 
+def pattern_map_1() -> MapU32Vec3f32:
+    return c_lib.pattern_map_1()
+
+def pattern_map_2(m: MapU32Vec3f32, key: int) -> OptionVec3f32:
+    return c_lib.pattern_map_2(m, key)
+
+def pattern_map_3(m: ctypes.POINTER(MapU32Vec3f32)) -> MapU32Vec3f32:
+    return c_lib.pattern_map_3(m)
+
+def pattern_iter_1(n: int) -> IterU32:
+    return c_lib.pattern_iter_1(n)
+
+def pattern_iter_2() -> IterVec3f32:
+    return c_lib.pattern_iter_2()
+
+def pattern_iter_3(iter: IterU32) -> int:
+    iter = iter._move()
+
+    return c_lib.pattern_iter_3(iter)
+
+def pattern_iter_4(n: int) -> IterU32:
+    return c_lib.pattern_iter_4(n)
+
+def pattern_tuple_1(x: Tuple2_U32_F32 | typing.Tuple[int, float]) -> typing.Tuple[float, int]:
+    if not isinstance(x, Tuple2_U32_F32):
+        x = Tuple2_U32_F32(*x)
+
+    return c_lib.pattern_tuple_1(x).to_tuple()
+
+def pattern_tuple_2(x: Tuple3_Vec3f32_U8_I64 | typing.Tuple[Vec3f32, int, int]) -> typing.Tuple[Vec3f32, int, int]:
+    if not isinstance(x, Tuple3_Vec3f32_U8_I64):
+        x = Tuple3_Vec3f32_U8_I64(*x)
+
+    return c_lib.pattern_tuple_2(x).to_tuple()
+
+def pattern_tuple_3(x: Tuples) -> Tuples:
+    return c_lib.pattern_tuple_3(x)
+
+def pattern_tuple_4(x: OptionTuple2_U32_U32) -> int:
+    return c_lib.pattern_tuple_4(x)
+
+def pattern_tuple_5(tuple0: Tuple2_U32_U32 | typing.Tuple[int, int]) -> int:
+    if not isinstance(tuple0, Tuple2_U32_U32):
+        tuple0 = Tuple2_U32_U32(*tuple0)
+
+    return c_lib.pattern_tuple_5(tuple0)
+
+def pattern_duration_1(x: Duration | datetime.timedelta) -> datetime.timedelta:
+    if isinstance(x, datetime.timedelta):
+        x = Duration.from_timedelta(x)
+
+    return c_lib.pattern_duration_1(x).to_timedelta()
+
+def pattern_duration_2(x: Timeout) -> datetime.timedelta:
+    return c_lib.pattern_duration_2(x).to_timedelta()
+
+def pattern_duration_3() -> datetime.timedelta:
+    return c_lib.pattern_duration_3().to_timedelta()
+
+def pattern_service_interface_1(storage: DynStorage, key: int, val: int) -> int:
+    """ Works with any service implementing `Storage`."""
+    return c_lib.pattern_service_interface_1(storage, key, val)
+
+def pattern_foreign_trait_1(sink: LogSinkVTable, x: int) -> int:
+    """ Logs the given values to a foreign sink and returns what the sink counted."""
+    return c_lib.pattern_foreign_trait_1(sink, x)
+
 def wire_accept_string_2(input):
     return c_lib.wire_accept_string_2(input)
 
@@ -946,10 +1365,18 @@ TRUE = ctypes.c_uint8(1)
 FALSE = ctypes.c_uint8(0)
 
 
+def _last_error():
+    """Returns and clears details about the last error or panic in Rust on this thread, or an empty string."""
+    error = c_lib.interoptopus_last_error()
+    rval = ctypes.string_at(error.ptr, error.len).decode("utf-8") if error.len else ""
+    c_lib.interoptopus_string_destroy(error)
+    return rval
+
+
 def _errcheck(returned, success):
     """Checks for FFIErrors and converts them to an exception."""
     if returned == success: return
-    else: raise Exception(f"Function returned error: {returned}")
+    else: raise Exception(f"Function returned error: {returned} {_last_error()}")
 
 
 class CallbackVars(object):
@@ -979,6 +1406,93 @@ class _Iter(object):
         return rval
 
 
+def _buffer_address(value, ctype, writable: bool):
+    """Returns `(address, view, owned)` for a buffer of `ctype` elements (e.g., a numpy array,
+    memoryview, bytes or bytearray), where `owned` must be kept alive while `address` is used.
+
+    Nothing is copied, except for read-only buffers that don't expose their address."""
+    view = memoryview(value)
+    kind = getattr(ctype, "_type_", None)
+    if view.itemsize != ctypes.sizeof(ctype):
+        raise ValueError(f"Buffer items have {view.itemsize} bytes, expected {ctypes.sizeof(ctype)}")
+    if isinstance(kind, str) and (view.format[-1] in "efd") != (kind in "fd"):
+        raise ValueError(f"Buffer format {view.format} does not match {ctype.__name__}")
+    if view.nbytes == 0:
+        return None, view, value
+    interface = getattr(value, "__array_interface__", None)
+    if interface is not None and not (writable and interface["data"][1]):
+        return interface["data"][0], view, value
+    if not view.c_contiguous:
+        raise ValueError("Buffer is not contiguous, strided data must expose `__array_interface__`")
+    if not view.readonly:
+        return ctypes.addressof(ctypes.c_char.from_buffer(view)), view, value
+    if writable:
+        raise ValueError("Buffer is read-only")
+    if isinstance(view.obj, bytes) and view.nbytes == len(view.obj):
+        return ctypes.cast(ctypes.c_char_p(view.obj), ctypes.c_void_p).value, view, view.obj
+    owned = (ctypes.c_char * view.nbytes).from_buffer_copy(view)
+    return ctypes.addressof(owned), view, owned
+
+
+def _as_memoryview(pointer, shape, ctype, owner, readonly: bool) -> memoryview:
+    """Returns a zero-copy view of contiguous data, which keeps `owner` alive while in use."""
+    array_type = ctype
+    for extent in reversed(shape):
+        array_type = array_type * extent
+    address = ctypes.cast(pointer, ctypes.c_void_p).value
+    array = array_type.from_address(address) if address else array_type()
+    array.owner = owner
+    view = memoryview(array)
+    # ctypes reports explicit byte orders (e.g., `<I`) which memoryview itself can't index.
+    format = view.format.lstrip("<>=@!")
+    if len(format) == 1:
+        view = view.cast("B").cast(format, shape)
+    return view.toreadonly() if readonly else view
+
+
+class _U128(ctypes.Structure):
+    """Helper for `u128`, converted from and to `int` when crossing the FFI boundary."""
+    _align_ = 16
+    _fields_ = [("lo", ctypes.c_uint64), ("hi", ctypes.c_uint64)]
+    _range = (0, 1 << 128)
+
+    @classmethod
+    def from_int(cls, value: int):
+        if not cls._range[0] <= value < cls._range[1]:
+            raise OverflowError(f"{value} does not fit into {cls.__name__}")
+        return cls(value & 0xFFFFFFFFFFFFFFFF, (value >> 64) & 0xFFFFFFFFFFFFFFFF)
+
+    @property
+    def value(self) -> int:
+        return self.lo | (self.hi << 64)
+
+
+class _I128(_U128):
+    """Helper for `i128`, like `_U128` but in two's complement."""
+    _range = (-(1 << 127), 1 << 127)
+
+    @property
+    def value(self) -> int:
+        rval = self.lo | (self.hi << 64)
+        return rval - (1 << 128) if rval >> 127 else rval
+
+
+def _align_fields(fields):
+    """Pads `_fields_` holding 128-bit integers like Rust does, as ctypes only honors `_align_` since Python 3.13."""
+    rval, offset, max_align = [], 0, 1
+    for name, ctype in fields:
+        align = max(ctypes.alignment(ctype), getattr(ctype, "_align_", 1))
+        if -offset % align:
+            rval.append((f"_pad{len(rval)}", ctypes.c_uint8 * (-offset % align)))
+            offset += -offset % align
+        rval.append((name, ctype))
+        offset += ctypes.sizeof(ctype)
+        max_align = max(max_align, align)
+    if -offset % max_align:
+        rval.append(("_pad_end", ctypes.c_uint8 * (-offset % max_align)))
+    return rval
+
+
 class EnumDocumented:
     """ Documented enum."""
     #  Variant A.
@@ -997,6 +1511,49 @@ class Error:
     Fail = 0
 
 
+class ErrorException(Exception):
+    """Raised when a function returns `Error` as its error."""
+    def __init__(self, error, message=""):
+        super().__init__(message or f"Error({error})")
+        self.error = error
+
+    @staticmethod
+    def from_error(error):
+        """Creates the exception matching the error's variant, including details about the last error in Rust."""
+        message = f"Error({error}) {_last_error()}".strip()
+        for cls in ErrorException.__subclasses__():
+            if cls.ERROR == error: return cls(error, message)
+        return ErrorException(error, message)
+
+
+class ErrorFailException(ErrorException):
+    ERROR = Error.Fail
+
+
+class SyncError:
+    """ Errors of [`ServiceThreadAffine`]."""
+    WrongThread = 0
+
+
+class SyncErrorException(Exception):
+    """Raised when a function returns `SyncError` as its error."""
+    def __init__(self, error, message=""):
+        super().__init__(message or f"SyncError({error})")
+        self.error = error
+
+    @staticmethod
+    def from_error(error):
+        """Creates the exception matching the error's variant, including details about the last error in Rust."""
+        message = f"SyncError({error}) {_last_error()}".strip()
+        for cls in SyncErrorException.__subclasses__():
+            if cls.ERROR == error: return cls(error, message)
+        return SyncErrorException(error, message)
+
+
+class SyncErrorWrongThreadException(SyncErrorException):
+    ERROR = SyncError.WrongThread
+
+
 class Utf8String(ctypes.Structure):
     """ UTF-8 string marshalling helper.
  A highly dangerous 'use once type' that has ownership semantics!
@@ -1045,10 +1602,162 @@ class Utf8String(ctypes.Structure):
         return ctypes.Structure.__set__(self, "capacity", value)
 
 
-class EnumNum:
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    B = 1
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+class Utf8Str(ctypes.Structure):
+    """ Borrowed UTF-8 string, `len` bytes starting at `ptr`, not `0x0` terminated.
+ Neither side owns the data, it must outlive the call (or struct) it is passed to."""
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("ptr", ctypes.POINTER(ctypes.c_uint8)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    @staticmethod
+    def from_bytes(value: str | bytes) -> "Utf8Str":
+        """Borrows the given bytes (or the UTF-8 encoding of a str) without copying them.
+
+        The returned object keeps the bytes alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        if isinstance(value, str):
+            value = value.encode("utf-8")
+        rval = Utf8Str(ptr=ctypes.cast(ctypes.c_char_p(value), ctypes.POINTER(ctypes.c_uint8)), len=len(value))
+        rval.owned = value  # Store bytes in returned object to prevent memory deallocation
+        return rval
+
+    def __len__(self):
+        return self.len
+
+    def bytes(self) -> bytes:
+        """Returns a copy of the underlying bytes."""
+        if not self.ptr:
+            return b""
+        return ctypes.string_at(self.ptr, self.len)
+
+    def __str__(self) -> str:
+        return self.bytes().decode("utf-8")
+
+
+class Duration(ctypes.Structure):
+    """ A span of time, `nanos` is always below one second."""
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("secs", ctypes.c_uint64),
+        ("nanos", ctypes.c_uint32),
+    ]
+
+    def __init__(self, secs: int = None, nanos: int = None):
+        if secs is not None:
+            self.secs = secs
+        if nanos is not None:
+            self.nanos = nanos
+
+    @property
+    def secs(self) -> int:
+        return ctypes.Structure.__get__(self, "secs")
+
+    @secs.setter
+    def secs(self, value: int):
+        return ctypes.Structure.__set__(self, "secs", value)
+
+    @property
+    def nanos(self) -> int:
+        return ctypes.Structure.__get__(self, "nanos")
+
+    @nanos.setter
+    def nanos(self, value: int):
+        return ctypes.Structure.__set__(self, "nanos", value)
+
+    @staticmethod
+    def from_timedelta(value: datetime.timedelta) -> "Duration":
+        """Converts a non-negative `timedelta`, which has a resolution of one microsecond."""
+        if value < datetime.timedelta(0):
+            raise ValueError("Durations can't be negative")
+        return Duration(value.days * 86400 + value.seconds, value.microseconds * 1000)
+
+    def to_timedelta(self) -> datetime.timedelta:
+        """Converts this into a `timedelta`, nanoseconds are truncated to microseconds."""
+        return datetime.timedelta(seconds=self.secs, microseconds=self.nanos // 1000)
+
+
+class EnumNum(ctypes.Structure):
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("A", ctypes.c_size_t),
+            ("C", ctypes.c_ssize_t),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class AVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class BVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class CVariant:
+        value: int
+
+    @staticmethod
+    def A(value: int) -> EnumNum:
+        rval = EnumNum()
+        rval._payload.A = value
+        rval._variant = 0
+        return rval
+
+    def is_a(self) -> bool:
+        return self._variant == 0
+
+    def as_a(self) -> int:
+        """Returns the `A` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `A`, got {self!r}")
+        return self._payload.A
+
+    @staticmethod
+    def B() -> EnumNum:
+        rval = EnumNum()
+        rval._variant = 1
+        return rval
+
+    def is_b(self) -> bool:
+        return self._variant == 1
+
+    @staticmethod
+    def C(value: int) -> EnumNum:
+        rval = EnumNum()
+        rval._payload.C = value
+        rval._variant = 2
+        return rval
+
+    def is_c(self) -> bool:
+        return self._variant == 2
+
+    def as_c(self) -> int:
+        """Returns the `C` value, raises if this is another variant."""
+        if self._variant != 2: raise Exception(f"Expected `C`, got {self!r}")
+        return self._payload.C
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return EnumNum.AVariant(self._payload.A)
+        if self._variant == 1: return EnumNum.BVariant()
+        if self._variant == 2: return EnumNum.CVariant(self._payload.C)
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, EnumNum) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
 
 
 class BoolField(ctypes.Structure):
@@ -1287,19 +1996,52 @@ class StructRenamed(ctypes.Structure):
         ("e", ctypes.c_int),
     ]
 
-    def __init__(self, e: TODO = None):
+    def __init__(self, e: int = None):
         if e is not None:
             self.e = e
 
     @property
-    def e(self) -> TODO:
+    def e(self) -> int:
         return ctypes.Structure.__get__(self, "e")
 
     @e.setter
-    def e(self, value: TODO):
+    def e(self, value: int):
         return ctypes.Structure.__set__(self, "e", value)
 
 
+class Timeout(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_after", Duration),
+        ("retries", ctypes.c_uint32),
+    ]
+
+    def __init__(self, after: datetime.timedelta = None, retries: int = None):
+        if after is not None:
+            self.after = after
+        if retries is not None:
+            self.retries = retries
+
+    @property
+    def after(self) -> datetime.timedelta:
+        return self._after.to_timedelta()
+
+    @after.setter
+    def after(self, value: datetime.timedelta):
+        if isinstance(value, datetime.timedelta):
+            value = Duration.from_timedelta(value)
+        self._after = value
+
+    @property
+    def retries(self) -> int:
+        return ctypes.Structure.__get__(self, "retries")
+
+    @retries.setter
+    def retries(self, value: int):
+        return ctypes.Structure.__set__(self, "retries", value)
+
+
 class Tupled(ctypes.Structure):
 
     # These fields represent the underlying C data layout
@@ -1340,6 +2082,37 @@ class UseCStrPtr(ctypes.Structure):
         return ctypes.Structure.__set__(self, "ascii_string", value)
 
 
+class UseStr(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("s1", Utf8Str),
+        ("s2", Utf8Str),
+    ]
+
+    def __init__(self, s1: Utf8Str = None, s2: Utf8Str = None):
+        if s1 is not None:
+            self.s1 = s1
+        if s2 is not None:
+            self.s2 = s2
+
+    @property
+    def s1(self) -> Utf8Str:
+        return ctypes.Structure.__get__(self, "s1")
+
+    @s1.setter
+    def s1(self, value: Utf8Str):
+        return ctypes.Structure.__set__(self, "s1", value)
+
+    @property
+    def s2(self) -> Utf8Str:
+        return ctypes.Structure.__get__(self, "s2")
+
+    @s2.setter
+    def s2(self, value: Utf8Str):
+        return ctypes.Structure.__set__(self, "s2", value)
+
+
 class UseString(ctypes.Structure):
 
     # These fields represent the underlying C data layout
@@ -1588,6 +2361,64 @@ class Weird1u32(ctypes.Structure):
         return ctypes.Structure.__set__(self, "x", value)
 
 
+class WideNum(ctypes.Structure):
+    _align_ = 16
+
+    # These fields represent the underlying C data layout
+    _fields_ = _align_fields([
+        ("_id", _U128),
+        ("_delta", _I128),
+        ("initial", ctypes.c_uint32),
+        ("handle", ctypes.c_uint32),
+    ])
+
+    def __init__(self, id: int = None, delta: int = None, initial: int = None, handle: int = None):
+        if id is not None:
+            self.id = id
+        if delta is not None:
+            self.delta = delta
+        if initial is not None:
+            self.initial = initial
+        if handle is not None:
+            self.handle = handle
+
+    @property
+    def id(self) -> int:
+        return self._id.value
+
+    @id.setter
+    def id(self, value: int):
+        if not isinstance(value, _U128):
+            value = _U128.from_int(value)
+        self._id = value
+
+    @property
+    def delta(self) -> int:
+        return self._delta.value
+
+    @delta.setter
+    def delta(self, value: int):
+        if not isinstance(value, _I128):
+            value = _I128.from_int(value)
+        self._delta = value
+
+    @property
+    def initial(self) -> int:
+        return ctypes.Structure.__get__(self, "initial")
+
+    @initial.setter
+    def initial(self, value: int):
+        return ctypes.Structure.__set__(self, "initial", value)
+
+    @property
+    def handle(self) -> int:
+        return ctypes.Structure.__get__(self, "handle")
+
+    @handle.setter
+    def handle(self, value: int):
+        return ctypes.Structure.__set__(self, "handle", value)
+
+
 class SliceBool(ctypes.Structure):
     # These fields represent the underlying C data layout
     _fields_ = [
@@ -1645,6 +2476,19 @@ class SliceI32(ctypes.Structure):
         ("len", ctypes.c_uint64),
     ]
 
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "SliceI32":
+        """Borrows a contiguous buffer (e.g., a numpy array, bytearray or array.array) without copying it.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_int32, False)
+        if not view.c_contiguous:
+            raise ValueError("Buffer is not contiguous")
+        rval = SliceI32(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_int32)), len=view.nbytes // view.itemsize)
+        rval.owned = owned  # Store buffer in returned slice to prevent memory deallocation
+        return rval
+
     def __len__(self):
         return self.len
 
@@ -1687,6 +2531,13 @@ class SliceI32(ctypes.Structure):
         """Returns the last element of this slice."""
         return self[len(self)-1]
 
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of this slice, pass it to `numpy.asarray` to get a numpy array.
+
+        The view keeps this object alive, but can't outlive the data it points to. For slices
+        received from Rust that usually means the duration of the call or callback."""
+        return _as_memoryview(self.data, (len(self),), ctypes.c_int32, self, True)
+
 
 class SliceU32(ctypes.Structure):
     # These fields represent the underlying C data layout
@@ -1695,6 +2546,19 @@ class SliceU32(ctypes.Structure):
         ("len", ctypes.c_uint64),
     ]
 
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "SliceU32":
+        """Borrows a contiguous buffer (e.g., a numpy array, bytearray or array.array) without copying it.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_uint32, False)
+        if not view.c_contiguous:
+            raise ValueError("Buffer is not contiguous")
+        rval = SliceU32(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_uint32)), len=view.nbytes // view.itemsize)
+        rval.owned = owned  # Store buffer in returned slice to prevent memory deallocation
+        return rval
+
     def __len__(self):
         return self.len
 
@@ -1737,6 +2601,13 @@ class SliceU32(ctypes.Structure):
         """Returns the last element of this slice."""
         return self[len(self)-1]
 
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of this slice, pass it to `numpy.asarray` to get a numpy array.
+
+        The view keeps this object alive, but can't outlive the data it points to. For slices
+        received from Rust that usually means the duration of the call or callback."""
+        return _as_memoryview(self.data, (len(self),), ctypes.c_uint32, self, True)
+
 
 class SliceU8(ctypes.Structure):
     # These fields represent the underlying C data layout
@@ -1745,6 +2616,19 @@ class SliceU8(ctypes.Structure):
         ("len", ctypes.c_uint64),
     ]
 
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "SliceU8":
+        """Borrows a contiguous buffer (e.g., a numpy array, bytearray or array.array) without copying it.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_uint8, False)
+        if not view.c_contiguous:
+            raise ValueError("Buffer is not contiguous")
+        rval = SliceU8(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_uint8)), len=view.nbytes // view.itemsize)
+        rval.owned = owned  # Store buffer in returned slice to prevent memory deallocation
+        return rval
+
     def __len__(self):
         return self.len
 
@@ -1787,12 +2671,16 @@ class SliceU8(ctypes.Structure):
         """Returns the last element of this slice."""
         return self[len(self)-1]
 
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of this slice, pass it to `numpy.asarray` to get a numpy array.
+
+        The view keeps this object alive, but can't outlive the data it points to. For slices
+        received from Rust that usually means the duration of the call or callback."""
+        return _as_memoryview(self.data, (len(self),), ctypes.c_uint8, self, True)
+
     def bytearray(self):
         """Returns a bytearray with the content of this slice."""
-        rval = bytearray(len(self))
-        for i in range(len(self)):
-            rval[i] = self[i]
-        return rval
+        return bytearray(self.memoryview())
 
 
 class SliceUtf8String(ctypes.Structure):
@@ -1852,6 +2740,19 @@ class SliceMutU32(ctypes.Structure):
         ("len", ctypes.c_uint64),
     ]
 
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "SliceMutU32":
+        """Borrows a contiguous buffer (e.g., a numpy array, bytearray or array.array) without copying it.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_uint32, True)
+        if not view.c_contiguous:
+            raise ValueError("Buffer is not contiguous")
+        rval = SliceMutU32(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_uint32)), len=view.nbytes // view.itemsize)
+        rval.owned = owned  # Store buffer in returned slice to prevent memory deallocation
+        return rval
+
     def __len__(self):
         return self.len
 
@@ -1905,6 +2806,13 @@ class SliceMutU32(ctypes.Structure):
         """Returns the last element of this slice."""
         return self[len(self)-1]
 
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of this slice, pass it to `numpy.asarray` to get a numpy array.
+
+        The view keeps this object alive, but can't outlive the data it points to. For slices
+        received from Rust that usually means the duration of the call or callback."""
+        return _as_memoryview(self.data, (len(self),), ctypes.c_uint32, self, False)
+
 
 class SliceMutU8(ctypes.Structure):
     # These fields represent the underlying C data layout
@@ -1913,6 +2821,19 @@ class SliceMutU8(ctypes.Structure):
         ("len", ctypes.c_uint64),
     ]
 
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "SliceMutU8":
+        """Borrows a contiguous buffer (e.g., a numpy array, bytearray or array.array) without copying it.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_uint8, True)
+        if not view.c_contiguous:
+            raise ValueError("Buffer is not contiguous")
+        rval = SliceMutU8(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_uint8)), len=view.nbytes // view.itemsize)
+        rval.owned = owned  # Store buffer in returned slice to prevent memory deallocation
+        return rval
+
     def __len__(self):
         return self.len
 
@@ -1966,1162 +2887,6178 @@ class SliceMutU8(ctypes.Structure):
         """Returns the last element of this slice."""
         return self[len(self)-1]
 
-    def bytearray(self):
-        """Returns a bytearray with the content of this slice."""
-        rval = bytearray(len(self))
-        for i in range(len(self)):
-            rval[i] = self[i]
-        return rval
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of this slice, pass it to `numpy.asarray` to get a numpy array.
 
+        The view keeps this object alive, but can't outlive the data it points to. For slices
+        received from Rust that usually means the duration of the call or callback."""
+        return _as_memoryview(self.data, (len(self),), ctypes.c_uint8, self, False)
 
-class OptionUtf8String:
-    """Option that contains Some(value) or None."""
-    # Element if Some().
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    None = 1
+    def bytearray(self):
+        """Returns a bytearray with the content of this slice."""
+        return bytearray(self.memoryview())
 
 
-class ResultError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+class NdSliceF32(ctypes.Structure):
+    """A multi-dimensional view into data someone else owns which may not be modified."""
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(ctypes.c_float)),
+        ("ndim", ctypes.c_uint64),
+        ("shape", ctypes.c_uint64 * 4),
+        ("strides", ctypes.c_int64 * 4),
+    ]
 
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "NdSliceF32":
+        """Borrows a buffer (e.g., a numpy array or memoryview) without copying it, see `_buffer_address`.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_float, False)
+        itemsize = view.itemsize
+        if not 0 < view.ndim <= 4 or any(x % itemsize for x in view.strides):
+            raise ValueError("Buffer layout does not match NdSliceF32")
+        rval = NdSliceF32(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_float)), ndim=view.ndim)
+        rval.shape[:view.ndim] = view.shape
+        rval.strides[:view.ndim] = [x // itemsize for x in view.strides]
+        rval.owned = owned  # Store buffer in returned object to prevent memory deallocation
+        return rval
 
-class ResultU32Error:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
-
-
-class ResultU64Error:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    def dims(self) -> tuple[int, ...]:
+        """Returns the extent of each dimension, like numpy's `shape`."""
+        return tuple(self.shape[:self.ndim])
 
+    def __len__(self):
+        """Returns the total number of elements."""
+        return math.prod(self.dims()) if self.data else 0
+
+    def is_contiguous(self) -> bool:
+        """Whether all elements are laid out row-major without gaps."""
+        expected = 1
+        for d in reversed(range(self.ndim)):
+            if self.shape[d] != 1 and self.strides[d] != expected:
+                return False
+            expected *= self.shape[d]
+        return True
+
+    def _offset(self, index) -> int:
+        if not isinstance(index, tuple):
+            index = (index,)
+        if len(index) != self.ndim:
+            raise IndexError("Expected {} indices, got {}".format(self.ndim, len(index)))
+        offset = 0
+        for i, extent, stride in zip(index, self.shape, self.strides):
+            if not 0 <= i < extent:
+                raise IndexError("Index out of range")
+            offset += i * stride
+        return offset
+
+    def __getitem__(self, index) -> float:
+        return self.data[self._offset(index)]
+
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of the data, which must be contiguous.
+
+        Pass the result to `numpy.asarray` to get a numpy array. Neither may be used longer
+        than the data this object points to."""
+        if not self.is_contiguous():
+            raise ValueError("NdSliceF32 is not contiguous")
+        return _as_memoryview(self.data, self.dims(), ctypes.c_float, self, True)
+
+
+class NdSliceI32(ctypes.Structure):
+    """A multi-dimensional view into data someone else owns which may not be modified."""
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(ctypes.c_int32)),
+        ("ndim", ctypes.c_uint64),
+        ("shape", ctypes.c_uint64 * 4),
+        ("strides", ctypes.c_int64 * 4),
+    ]
 
-class ResultUtf8StringError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "NdSliceI32":
+        """Borrows a buffer (e.g., a numpy array or memoryview) without copying it, see `_buffer_address`.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_int32, False)
+        itemsize = view.itemsize
+        if not 0 < view.ndim <= 4 or any(x % itemsize for x in view.strides):
+            raise ValueError("Buffer layout does not match NdSliceI32")
+        rval = NdSliceI32(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_int32)), ndim=view.ndim)
+        rval.shape[:view.ndim] = view.shape
+        rval.strides[:view.ndim] = [x // itemsize for x in view.strides]
+        rval.owned = owned  # Store buffer in returned object to prevent memory deallocation
+        return rval
 
+    def dims(self) -> tuple[int, ...]:
+        """Returns the extent of each dimension, like numpy's `shape`."""
+        return tuple(self.shape[:self.ndim])
 
-class ResultVoid:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    def __len__(self):
+        """Returns the total number of elements."""
+        return math.prod(self.dims()) if self.data else 0
+
+    def is_contiguous(self) -> bool:
+        """Whether all elements are laid out row-major without gaps."""
+        expected = 1
+        for d in reversed(range(self.ndim)):
+            if self.shape[d] != 1 and self.strides[d] != expected:
+                return False
+            expected *= self.shape[d]
+        return True
+
+    def _offset(self, index) -> int:
+        if not isinstance(index, tuple):
+            index = (index,)
+        if len(index) != self.ndim:
+            raise IndexError("Expected {} indices, got {}".format(self.ndim, len(index)))
+        offset = 0
+        for i, extent, stride in zip(index, self.shape, self.strides):
+            if not 0 <= i < extent:
+                raise IndexError("Index out of range")
+            offset += i * stride
+        return offset
+
+    def __getitem__(self, index) -> int:
+        return self.data[self._offset(index)]
+
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of the data, which must be contiguous.
+
+        Pass the result to `numpy.asarray` to get a numpy array. Neither may be used longer
+        than the data this object points to."""
+        if not self.is_contiguous():
+            raise ValueError("NdSliceI32 is not contiguous")
+        return _as_memoryview(self.data, self.dims(), ctypes.c_int32, self, True)
+
+
+class NdSliceMutU32(ctypes.Structure):
+    """A multi-dimensional view into data someone else owns which may be modified."""
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(ctypes.c_uint32)),
+        ("ndim", ctypes.c_uint64),
+        ("shape", ctypes.c_uint64 * 4),
+        ("strides", ctypes.c_int64 * 4),
+    ]
 
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "NdSliceMutU32":
+        """Borrows a buffer (e.g., a numpy array or memoryview) without copying it, see `_buffer_address`.
+
+        The returned object keeps the buffer alive, but once its fields are copied elsewhere
+        (e.g., into a struct) the original object must be kept around for as long as they are used."""
+        address, view, owned = _buffer_address(value, ctypes.c_uint32, True)
+        itemsize = view.itemsize
+        if not 0 < view.ndim <= 4 or any(x % itemsize for x in view.strides):
+            raise ValueError("Buffer layout does not match NdSliceMutU32")
+        rval = NdSliceMutU32(data=ctypes.cast(address, ctypes.POINTER(ctypes.c_uint32)), ndim=view.ndim)
+        rval.shape[:view.ndim] = view.shape
+        rval.strides[:view.ndim] = [x // itemsize for x in view.strides]
+        rval.owned = owned  # Store buffer in returned object to prevent memory deallocation
+        return rval
 
-class EnumPayload:
-    A = 0
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+    def dims(self) -> tuple[int, ...]:
+        """Returns the extent of each dimension, like numpy's `shape`."""
+        return tuple(self.shape[:self.ndim])
 
+    def __len__(self):
+        """Returns the total number of elements."""
+        return math.prod(self.dims()) if self.data else 0
+
+    def is_contiguous(self) -> bool:
+        """Whether all elements are laid out row-major without gaps."""
+        expected = 1
+        for d in reversed(range(self.ndim)):
+            if self.shape[d] != 1 and self.strides[d] != expected:
+                return False
+            expected *= self.shape[d]
+        return True
+
+    def _offset(self, index) -> int:
+        if not isinstance(index, tuple):
+            index = (index,)
+        if len(index) != self.ndim:
+            raise IndexError("Expected {} indices, got {}".format(self.ndim, len(index)))
+        offset = 0
+        for i, extent, stride in zip(index, self.shape, self.strides):
+            if not 0 <= i < extent:
+                raise IndexError("Index out of range")
+            offset += i * stride
+        return offset
+
+    def __getitem__(self, index) -> int:
+        return self.data[self._offset(index)]
+
+    def __setitem__(self, index, v: int):
+        self.data[self._offset(index)] = v
+
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of the data, which must be contiguous.
+
+        Pass the result to `numpy.asarray` to get a numpy array. Neither may be used longer
+        than the data this object points to."""
+        if not self.is_contiguous():
+            raise ValueError("NdSliceMutU32 is not contiguous")
+        return _as_memoryview(self.data, self.dims(), ctypes.c_uint32, self, False)
+
+
+class OptionUtf8String(ctypes.Structure):
+    """Option that contains Some(value) or None."""
 
-class Array(ctypes.Structure):
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", Utf8String),
+        ]
 
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("data", ctypes.c_uint8 * 16),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __init__(self, data = None):
-        if data is not None:
-            self.data = data
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    @property
-    def data(self):
-        return ctypes.Structure.__get__(self, "data")
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: typing.Any
 
-    @data.setter
-    def data(self, value):
-        return ctypes.Structure.__set__(self, "data", value)
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
 
+    @staticmethod
+    def Some(value: typing.Any) -> OptionUtf8String:
+        # Element if Some().
+        rval = OptionUtf8String()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
 
-class Container(ctypes.Structure):
+    def is_some(self) -> bool:
+        return self._variant == 0
 
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("foreign", Local),
-    ]
+    def as_some(self) -> typing.Any:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
 
-    def __init__(self, foreign: Local = None):
-        if foreign is not None:
-            self.foreign = foreign
+    @staticmethod
+    def None_() -> OptionUtf8String:
+        rval = OptionUtf8String()
+        rval._variant = 1
+        return rval
 
-    @property
-    def foreign(self) -> Local:
-        return ctypes.Structure.__get__(self, "foreign")
+    def is_none(self) -> bool:
+        return self._variant == 1
 
-    @foreign.setter
-    def foreign(self, value: Local):
-        return ctypes.Structure.__set__(self, "foreign", value)
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionUtf8String.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionUtf8String.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
+    def __eq__(self, other):
+        return isinstance(other, OptionUtf8String) and self.variant() == other.variant()
 
-class FixedString(ctypes.Structure):
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> typing.Any:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class ResultError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Err", ctypes.c_int),
+        ]
 
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("data", ctypes.c_uint8 * 32),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __init__(self, data = None):
-        if data is not None:
-            self.data = data
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    @property
-    def data(self):
-        return ctypes.Structure.__get__(self, "data")
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        pass
 
-    @data.setter
-    def data(self, value):
-        return ctypes.Structure.__set__(self, "data", value)
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
 
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
 
-class Genericu32(ctypes.Structure):
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
 
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("x", ctypes.POINTER(ctypes.c_uint32)),
-    ]
+    @staticmethod
+    def Ok() -> ResultError:
+        # Element if err is `Ok`.
+        rval = ResultError()
+        rval._variant = 0
+        return rval
 
-    def __init__(self, x: ctypes.POINTER(ctypes.c_uint32) = None):
-        if x is not None:
-            self.x = x
+    def is_ok(self) -> bool:
+        return self._variant == 0
 
-    @property
-    def x(self) -> ctypes.POINTER(ctypes.c_uint32):
-        return ctypes.Structure.__get__(self, "x")
+    @staticmethod
+    def Err(value: int) -> ResultError:
+        # Error value.
+        rval = ResultError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
 
-    @x.setter
-    def x(self, value: ctypes.POINTER(ctypes.c_uint32)):
-        return ctypes.Structure.__set__(self, "x", value)
+    def is_err(self) -> bool:
+        return self._variant == 1
 
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
 
-class Genericu8(ctypes.Structure):
+    @staticmethod
+    def Panic() -> ResultError:
+        rval = ResultError()
+        rval._variant = 2
+        return rval
 
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("x", ctypes.POINTER(ctypes.c_uint8)),
-    ]
+    def is_panic(self) -> bool:
+        return self._variant == 2
 
-    def __init__(self, x: ctypes.POINTER(ctypes.c_uint8) = None):
-        if x is not None:
-            self.x = x
+    @staticmethod
+    def Null() -> ResultError:
+        rval = ResultError()
+        rval._variant = 3
+        return rval
 
-    @property
-    def x(self) -> ctypes.POINTER(ctypes.c_uint8):
-        return ctypes.Structure.__get__(self, "x")
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-    @x.setter
-    def x(self, value: ctypes.POINTER(ctypes.c_uint8)):
-        return ctypes.Structure.__set__(self, "x", value)
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultError.OkVariant()
+        if self._variant == 1: return ResultError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultError.PanicVariant()
+        if self._variant == 3: return ResultError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
+    def __eq__(self, other):
+        return isinstance(other, ResultError) and self.variant() == other.variant()
 
-class Layer1Utf8String(ctypes.Structure):
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> None:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultSyncError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Err", ctypes.c_int),
+        ]
 
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("maybe_1", TODO),
-        ("maybe_2", VecUtf8String),
-        ("maybe_3", Utf8String),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __init__(self, maybe_1: TODO = None, maybe_2 = None, maybe_3 = None):
-        if maybe_1 is not None:
-            self.maybe_1 = maybe_1
-        if maybe_2 is not None:
-            self.maybe_2 = maybe_2
-        if maybe_3 is not None:
-            self.maybe_3 = maybe_3
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    @property
-    def maybe_1(self) -> TODO:
-        return ctypes.Structure.__get__(self, "maybe_1")
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        pass
 
-    @maybe_1.setter
-    def maybe_1(self, value: TODO):
-        return ctypes.Structure.__set__(self, "maybe_1", value)
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
 
-    @property
-    def maybe_2(self):
-        return ctypes.Structure.__get__(self, "maybe_2")
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
 
-    @maybe_2.setter
-    def maybe_2(self, value):
-        return ctypes.Structure.__set__(self, "maybe_2", value)
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
 
-    @property
-    def maybe_3(self):
-        return ctypes.Structure.__get__(self, "maybe_3")
+    @staticmethod
+    def Ok() -> ResultSyncError:
+        # Element if err is `Ok`.
+        rval = ResultSyncError()
+        rval._variant = 0
+        return rval
 
-    @maybe_3.setter
-    def maybe_3(self, value):
-        return ctypes.Structure.__set__(self, "maybe_3", value)
+    def is_ok(self) -> bool:
+        return self._variant == 0
 
+    @staticmethod
+    def Err(value: int) -> ResultSyncError:
+        # Error value.
+        rval = ResultSyncError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
 
-class UseSliceAndVec(ctypes.Structure):
+    def is_err(self) -> bool:
+        return self._variant == 1
 
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("s1", SliceUtf8String),
-        ("s2", VecUtf8String),
-    ]
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
 
-    def __init__(self, s1: SliceUtf8String = None, s2 = None):
-        if s1 is not None:
-            self.s1 = s1
-        if s2 is not None:
-            self.s2 = s2
+    @staticmethod
+    def Panic() -> ResultSyncError:
+        rval = ResultSyncError()
+        rval._variant = 2
+        return rval
 
-    @property
-    def s1(self) -> SliceUtf8String:
-        return ctypes.Structure.__get__(self, "s1")
+    def is_panic(self) -> bool:
+        return self._variant == 2
 
-    @s1.setter
-    def s1(self, value: SliceUtf8String):
-        return ctypes.Structure.__set__(self, "s1", value)
+    @staticmethod
+    def Null() -> ResultSyncError:
+        rval = ResultSyncError()
+        rval._variant = 3
+        return rval
 
-    @property
-    def s2(self):
-        return ctypes.Structure.__get__(self, "s2")
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-    @s2.setter
-    def s2(self, value):
-        return ctypes.Structure.__set__(self, "s2", value)
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultSyncError.OkVariant()
+        if self._variant == 1: return ResultSyncError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultSyncError.PanicVariant()
+        if self._variant == 3: return ResultSyncError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
+    def __eq__(self, other):
+        return isinstance(other, ResultSyncError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> None:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return
+        if self._variant == 1: raise SyncErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
 
-class Weird2u8(ctypes.Structure):
+
+class ResultU32Error(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_uint32),
+            ("Err", ctypes.c_int),
+        ]
 
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("t", ctypes.c_uint8),
-        ("a", ctypes.c_uint8 * 5),
-        ("r", ctypes.POINTER(ctypes.c_uint8)),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __init__(self, t: int = None, a = None, r: ctypes.POINTER(ctypes.c_uint8) = None):
-        if t is not None:
-            self.t = t
-        if a is not None:
-            self.a = a
-        if r is not None:
-            self.r = r
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    @property
-    def t(self) -> int:
-        return ctypes.Structure.__get__(self, "t")
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: int
 
-    @t.setter
-    def t(self, value: int):
-        return ctypes.Structure.__set__(self, "t", value)
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
 
-    @property
-    def a(self):
-        return ctypes.Structure.__get__(self, "a")
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
 
-    @a.setter
-    def a(self, value):
-        return ctypes.Structure.__set__(self, "a", value)
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
 
-    @property
-    def r(self) -> ctypes.POINTER(ctypes.c_uint8):
-        return ctypes.Structure.__get__(self, "r")
+    @staticmethod
+    def Ok(value: int) -> ResultU32Error:
+        # Element if err is `Ok`.
+        rval = ResultU32Error()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
 
-    @r.setter
-    def r(self, value: ctypes.POINTER(ctypes.c_uint8)):
-        return ctypes.Structure.__set__(self, "r", value)
+    def is_ok(self) -> bool:
+        return self._variant == 0
 
+    def as_ok(self) -> int:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
 
-class WireBuffer(ctypes.Structure):
-    """FFI buffer for Wire data transfer"""
+    @staticmethod
+    def Err(value: int) -> ResultU32Error:
+        # Error value.
+        rval = ResultU32Error()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
 
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("data", ctypes.POINTER(ctypes.c_uint8)),
-        ("len", ctypes.c_int32),
-        ("capacity", ctypes.c_int32),
-    ]
+    def is_err(self) -> bool:
+        return self._variant == 1
 
-    def __init__(self, data: ctypes.POINTER(ctypes.c_uint8) = None, len: int = None, capacity: int = None):
-        if data is not None:
-            self.data = data
-        if len is not None:
-            self.len = len
-        if capacity is not None:
-            self.capacity = capacity
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
 
-    @property
-    def data(self) -> ctypes.POINTER(ctypes.c_uint8):
-        return ctypes.Structure.__get__(self, "data")
+    @staticmethod
+    def Panic() -> ResultU32Error:
+        rval = ResultU32Error()
+        rval._variant = 2
+        return rval
 
-    @data.setter
-    def data(self, value: ctypes.POINTER(ctypes.c_uint8)):
-        return ctypes.Structure.__set__(self, "data", value)
+    def is_panic(self) -> bool:
+        return self._variant == 2
 
-    @property
-    def len(self) -> int:
-        return ctypes.Structure.__get__(self, "len")
+    @staticmethod
+    def Null() -> ResultU32Error:
+        rval = ResultU32Error()
+        rval._variant = 3
+        return rval
 
-    @len.setter
-    def len(self, value: int):
-        return ctypes.Structure.__set__(self, "len", value)
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-    @property
-    def capacity(self) -> int:
-        return ctypes.Structure.__get__(self, "capacity")
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultU32Error.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultU32Error.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultU32Error.PanicVariant()
+        if self._variant == 3: return ResultU32Error.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
-    @capacity.setter
-    def capacity(self, value: int):
-        return ctypes.Structure.__set__(self, "capacity", value)
+    def __eq__(self, other):
+        return isinstance(other, ResultU32Error) and self.variant() == other.variant()
 
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> int:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultU64Error(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_uint64),
+            ("Err", ctypes.c_int),
+        ]
 
-class SliceUseCStrPtr(ctypes.Structure):
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("data", ctypes.POINTER(UseCStrPtr)),
-        ("len", ctypes.c_uint64),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __len__(self):
-        return self.len
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    def __getitem__(self, i) -> UseCStrPtr:
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: int
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
 
-        return self.data[index]
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
 
-    def copied(self) -> SliceUseCStrPtr:
-        """Returns a shallow, owned copy of the underlying slice.
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
 
-        The returned object owns the immediate data, but not the targets of any contained
-        pointers. In other words, if your struct contains any pointers the returned object
-        may only be used as long as these pointers are valid. If the struct did not contain
-        any pointers the returned object is valid indefinitely."""
-        array = (UseCStrPtr * len(self))()
-        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(UseCStrPtr))
-        rval = SliceUseCStrPtr(data=ctypes.cast(array, ctypes.POINTER(UseCStrPtr)), len=len(self))
-        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+    @staticmethod
+    def Ok(value: int) -> ResultU64Error:
+        # Element if err is `Ok`.
+        rval = ResultU64Error()
+        rval._payload.Ok = value
+        rval._variant = 0
         return rval
 
-    def __iter__(self) -> typing.Iterable[UseCStrPtr]:
-        return _Iter(self)
-
-    def iter(self) -> typing.Iterable[UseCStrPtr]:
-        """Convenience method returning a value iterator."""
-        return iter(self)
+    def is_ok(self) -> bool:
+        return self._variant == 0
 
-    def first(self) -> UseCStrPtr:
-        """Returns the first element of this slice."""
-        return self[0]
+    def as_ok(self) -> int:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
 
-    def last(self) -> UseCStrPtr:
-        """Returns the last element of this slice."""
-        return self[len(self)-1]
+    @staticmethod
+    def Err(value: int) -> ResultU64Error:
+        # Error value.
+        rval = ResultU64Error()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
 
+    def is_err(self) -> bool:
+        return self._variant == 1
 
-class SliceUseString(ctypes.Structure):
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("data", ctypes.POINTER(UseString)),
-        ("len", ctypes.c_uint64),
-    ]
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
 
-    def __len__(self):
-        return self.len
+    @staticmethod
+    def Panic() -> ResultU64Error:
+        rval = ResultU64Error()
+        rval._variant = 2
+        return rval
 
-    def __getitem__(self, i) -> UseString:
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    def is_panic(self) -> bool:
+        return self._variant == 2
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    @staticmethod
+    def Null() -> ResultU64Error:
+        rval = ResultU64Error()
+        rval._variant = 3
+        return rval
 
-        return self.data[index]
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-    def copied(self) -> SliceUseString:
-        """Returns a shallow, owned copy of the underlying slice.
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultU64Error.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultU64Error.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultU64Error.PanicVariant()
+        if self._variant == 3: return ResultU64Error.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
-        The returned object owns the immediate data, but not the targets of any contained
-        pointers. In other words, if your struct contains any pointers the returned object
-        may only be used as long as these pointers are valid. If the struct did not contain
-        any pointers the returned object is valid indefinitely."""
-        array = (UseString * len(self))()
-        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(UseString))
-        rval = SliceUseString(data=ctypes.cast(array, ctypes.POINTER(UseString)), len=len(self))
-        rval.owned = array  # Store array in returned slice to prevent memory deallocation
-        return rval
+    def __eq__(self, other):
+        return isinstance(other, ResultU64Error) and self.variant() == other.variant()
 
-    def __iter__(self) -> typing.Iterable[UseString]:
-        return _Iter(self)
+    def __repr__(self):
+        return repr(self.variant())
 
-    def iter(self) -> typing.Iterable[UseString]:
-        """Convenience method returning a value iterator."""
-        return iter(self)
+    def unwrap(self) -> int:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
 
-    def first(self) -> UseString:
-        """Returns the first element of this slice."""
-        return self[0]
 
-    def last(self) -> UseString:
-        """Returns the last element of this slice."""
-        return self[len(self)-1]
+class ResultUtf8StringError(ctypes.Structure):
+    """Result that contains value or an error."""
 
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", Utf8String),
+            ("Err", ctypes.c_int),
+        ]
 
-class SliceVec(ctypes.Structure):
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("data", ctypes.POINTER(Vec)),
-        ("len", ctypes.c_uint64),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __len__(self):
-        return self.len
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    def __getitem__(self, i) -> Vec:
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: typing.Any
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
 
-        return self.data[index]
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
 
-    def copied(self) -> SliceVec:
-        """Returns a shallow, owned copy of the underlying slice.
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
 
-        The returned object owns the immediate data, but not the targets of any contained
-        pointers. In other words, if your struct contains any pointers the returned object
-        may only be used as long as these pointers are valid. If the struct did not contain
-        any pointers the returned object is valid indefinitely."""
-        array = (Vec * len(self))()
-        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(Vec))
-        rval = SliceVec(data=ctypes.cast(array, ctypes.POINTER(Vec)), len=len(self))
-        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+    @staticmethod
+    def Ok(value: typing.Any) -> ResultUtf8StringError:
+        # Element if err is `Ok`.
+        rval = ResultUtf8StringError()
+        rval._payload.Ok = value
+        rval._variant = 0
         return rval
 
-    def __iter__(self) -> typing.Iterable[Vec]:
-        return _Iter(self)
-
-    def iter(self) -> typing.Iterable[Vec]:
-        """Convenience method returning a value iterator."""
-        return iter(self)
-
-    def first(self) -> Vec:
-        """Returns the first element of this slice."""
-        return self[0]
+    def is_ok(self) -> bool:
+        return self._variant == 0
 
-    def last(self) -> Vec:
-        """Returns the last element of this slice."""
-        return self[len(self)-1]
+    def as_ok(self) -> typing.Any:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
 
+    @staticmethod
+    def Err(value: int) -> ResultUtf8StringError:
+        # Error value.
+        rval = ResultUtf8StringError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
 
-class SliceVec3f32(ctypes.Structure):
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("data", ctypes.POINTER(Vec3f32)),
-        ("len", ctypes.c_uint64),
-    ]
+    def is_err(self) -> bool:
+        return self._variant == 1
 
-    def __len__(self):
-        return self.len
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
 
-    def __getitem__(self, i) -> Vec3f32:
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    @staticmethod
+    def Panic() -> ResultUtf8StringError:
+        rval = ResultUtf8StringError()
+        rval._variant = 2
+        return rval
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    def is_panic(self) -> bool:
+        return self._variant == 2
 
-        return self.data[index]
+    @staticmethod
+    def Null() -> ResultUtf8StringError:
+        rval = ResultUtf8StringError()
+        rval._variant = 3
+        return rval
 
-    def copied(self) -> SliceVec3f32:
-        """Returns a shallow, owned copy of the underlying slice.
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-        The returned object owns the immediate data, but not the targets of any contained
-        pointers. In other words, if your struct contains any pointers the returned object
-        may only be used as long as these pointers are valid. If the struct did not contain
-        any pointers the returned object is valid indefinitely."""
-        array = (Vec3f32 * len(self))()
-        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(Vec3f32))
-        rval = SliceVec3f32(data=ctypes.cast(array, ctypes.POINTER(Vec3f32)), len=len(self))
-        rval.owned = array  # Store array in returned slice to prevent memory deallocation
-        return rval
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultUtf8StringError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultUtf8StringError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultUtf8StringError.PanicVariant()
+        if self._variant == 3: return ResultUtf8StringError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
-    def __iter__(self) -> typing.Iterable[Vec3f32]:
-        return _Iter(self)
+    def __eq__(self, other):
+        return isinstance(other, ResultUtf8StringError) and self.variant() == other.variant()
 
-    def iter(self) -> typing.Iterable[Vec3f32]:
-        """Convenience method returning a value iterator."""
-        return iter(self)
+    def __repr__(self):
+        return repr(self.variant())
 
-    def first(self) -> Vec3f32:
-        """Returns the first element of this slice."""
-        return self[0]
+    def unwrap(self) -> typing.Any:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
 
-    def last(self) -> Vec3f32:
-        """Returns the last element of this slice."""
-        return self[len(self)-1]
 
+class ResultVoid(ctypes.Structure):
+    """Result that contains value or an error."""
 
-class SliceMutVec(ctypes.Structure):
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("data", ctypes.POINTER(Vec)),
-        ("len", ctypes.c_uint64),
+        ("_variant", ctypes.c_uint32),
     ]
 
-    def __len__(self):
-        return self.len
-
-    def __getitem__(self, i) -> Vec:
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    # Variants as dataclasses, e.g., for use with `match`.
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        pass
 
-        return self.data[index]
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        pass
 
-    def __setitem__(self, i, v: Vec):
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
 
-        self.data[index] = v
+    @staticmethod
+    def Ok() -> ResultVoid:
+        # Element if err is `Ok`.
+        rval = ResultVoid()
+        rval._variant = 0
+        return rval
 
-    def copied(self) -> SliceMutVec:
-        """Returns a shallow, owned copy of the underlying slice.
+    def is_ok(self) -> bool:
+        return self._variant == 0
 
-        The returned object owns the immediate data, but not the targets of any contained
-        pointers. In other words, if your struct contains any pointers the returned object
-        may only be used as long as these pointers are valid. If the struct did not contain
-        any pointers the returned object is valid indefinitely."""
-        array = (Vec * len(self))()
-        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(Vec))
-        rval = SliceMutVec(data=ctypes.cast(array, ctypes.POINTER(Vec)), len=len(self))
-        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+    @staticmethod
+    def Err() -> ResultVoid:
+        # Error value.
+        rval = ResultVoid()
+        rval._variant = 1
         return rval
 
-    def __iter__(self) -> typing.Iterable[Vec]:
-        return _Iter(self)
+    def is_err(self) -> bool:
+        return self._variant == 1
 
-    def iter(self) -> typing.Iterable[Vec]:
-        """Convenience method returning a value iterator."""
-        return iter(self)
+    @staticmethod
+    def Panic() -> ResultVoid:
+        rval = ResultVoid()
+        rval._variant = 2
+        return rval
 
-    def first(self) -> Vec:
-        """Returns the first element of this slice."""
-        return self[0]
+    def is_panic(self) -> bool:
+        return self._variant == 2
 
-    def last(self) -> Vec:
-        """Returns the last element of this slice."""
-        return self[len(self)-1]
+    @staticmethod
+    def Null() -> ResultVoid:
+        rval = ResultVoid()
+        rval._variant = 3
+        return rval
 
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-class OptionInner:
-    """Option that contains Some(value) or None."""
-    # Element if Some().
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    None = 1
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultVoid.OkVariant()
+        if self._variant == 1: return ResultVoid.ErrVariant()
+        if self._variant == 2: return ResultVoid.PanicVariant()
+        if self._variant == 3: return ResultVoid.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
+    def __eq__(self, other):
+        return isinstance(other, ResultVoid) and self.variant() == other.variant()
 
-class OptionVec:
-    """Option that contains Some(value) or None."""
-    # Element if Some().
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    None = 1
+    def __repr__(self):
+        return repr(self.variant())
 
+    def unwrap(self) -> None:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return
+        raise Exception(f"{self!r} {_last_error()}".strip())
 
-class ResultConstPtrServiceAsyncBasicError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
 
+class VecU8(ctypes.Structure):
+    """ Vec marshalling helper.
+ A highly dangerous 'use once type' that has ownership semantics!
+ Once passed over an FFI boundary 'the other side' is meant to own
+ (and free) it. Rust handles that fine, but if in C# you put this
+ in a struct and then call Rust multiple times with that struct 
+ you'll free the same pointer multiple times, and get UB!"""
 
-class ResultConstPtrServiceAsyncResultError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("ptr", ctypes.POINTER(ctypes.c_uint8)),
+        ("len", ctypes.c_uint64),
+        ("capacity", ctypes.c_uint64),
+    ]
 
+    def __init__(self, ptr: ctypes.POINTER(ctypes.c_uint8) = None, len: int = None, capacity: int = None):
+        if ptr is not None:
+            self.ptr = ptr
+        if len is not None:
+            self.len = len
+        if capacity is not None:
+            self.capacity = capacity
 
-class ResultConstPtrServiceAsyncSleepError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @property
+    def ptr(self) -> ctypes.POINTER(ctypes.c_uint8):
+        return ctypes.Structure.__get__(self, "ptr")
 
+    @ptr.setter
+    def ptr(self, value: ctypes.POINTER(ctypes.c_uint8)):
+        return ctypes.Structure.__set__(self, "ptr", value)
 
-class ResultConstPtrServiceAsyncStructsError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @property
+    def len(self) -> int:
+        return ctypes.Structure.__get__(self, "len")
 
+    @len.setter
+    def len(self, value: int):
+        return ctypes.Structure.__set__(self, "len", value)
 
-class ResultConstPtrServiceAsyncVecStringError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @property
+    def capacity(self) -> int:
+        return ctypes.Structure.__get__(self, "capacity")
 
+    @capacity.setter
+    def capacity(self, value: int):
+        return ctypes.Structure.__set__(self, "capacity", value)
 
-class ResultConstPtrServiceBasicError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @staticmethod
+    def from_buffer(value: collections.abc.Buffer) -> "VecU8":
+        """Copies a contiguous buffer (e.g., bytes, a numpy array or array.array) into a new, Rust-owned Vec.
+
+        The data is copied once, in bulk. Once passed to Rust the returned object must not be used anymore."""
+        address, view, _ = _buffer_address(value, ctypes.c_uint8, False)
+        if not view.c_contiguous:
+            raise ValueError("Buffer is not contiguous")
+        rval = VecU8()
+        c_lib.interoptopus_vec_create_6849152863081469284(address, view.nbytes // view.itemsize, ctypes.byref(rval))
+        return rval
 
+    def memoryview(self) -> memoryview:
+        """Returns a zero-copy view of this Vec, pass it to `numpy.asarray` to get a numpy array.
 
-class ResultConstPtrServiceCallbacksError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+        The view keeps this object alive, but must not be used once the Vec was passed back to Rust."""
+        return _as_memoryview(self.ptr, (self.len,), ctypes.c_uint8, self, False)
 
 
-class ResultConstPtrServiceDependentError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+class VecUtf8String(ctypes.Structure):
+    """ Vec marshalling helper.
+ A highly dangerous 'use once type' that has ownership semantics!
+ Once passed over an FFI boundary 'the other side' is meant to own
+ (and free) it. Rust handles that fine, but if in C# you put this
+ in a struct and then call Rust multiple times with that struct 
+ you'll free the same pointer multiple times, and get UB!"""
 
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("ptr", ctypes.POINTER(Utf8String)),
+        ("len", ctypes.c_uint64),
+        ("capacity", ctypes.c_uint64),
+    ]
 
-class ResultConstPtrServiceIgnoringMethodsError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    def __init__(self, ptr: ctypes.POINTER(Utf8String) = None, len: int = None, capacity: int = None):
+        if ptr is not None:
+            self.ptr = ptr
+        if len is not None:
+            self.len = len
+        if capacity is not None:
+            self.capacity = capacity
 
+    @property
+    def ptr(self) -> ctypes.POINTER(Utf8String):
+        return ctypes.Structure.__get__(self, "ptr")
 
-class ResultConstPtrServiceMainError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @ptr.setter
+    def ptr(self, value: ctypes.POINTER(Utf8String)):
+        return ctypes.Structure.__set__(self, "ptr", value)
 
+    @property
+    def len(self) -> int:
+        return ctypes.Structure.__get__(self, "len")
 
-class ResultConstPtrServiceMultipleCtorsError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @len.setter
+    def len(self, value: int):
+        return ctypes.Structure.__set__(self, "len", value)
 
+    @property
+    def capacity(self) -> int:
+        return ctypes.Structure.__get__(self, "capacity")
 
-class ResultConstPtrServiceOnPanicError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @capacity.setter
+    def capacity(self, value: int):
+        return ctypes.Structure.__set__(self, "capacity", value)
 
 
-class ResultConstPtrServiceResultError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+class IterU32(ctypes.Structure):
+    """ Iterator marshalling helper.
+ An opaque, Rust-owned handle that produces items on demand. Like
+ a Vec it must be freed exactly once, either by the other side or
+ by passing it back to Rust."""
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("ptr", ctypes.c_void_p),
+    ]
 
+    def _move(self) -> "IterU32":
+        """Hands the handle over to Rust, which then owns it, leaving this object empty."""
+        rval = IterU32(self.ptr)
+        self.ptr = None
+        return rval
 
-class ResultConstPtrServiceStringsError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    def __iter__(self):
+        return self
 
+    def __next__(self) -> int:
+        if not self.ptr:
+            raise StopIteration
+        rval = ctypes.c_uint32()
+        if c_lib.interoptopus_iter_next_14415398661037898230(ctypes.byref(self), ctypes.byref(rval)) == 0:
+            self.close()
+            raise StopIteration
+        return rval.value
+
+    def close(self):
+        """Frees the iterator early, which otherwise happens once it is exhausted."""
+        if self.ptr:
+            c_lib.interoptopus_iter_destroy_11887687051161408401(self)
+            self.ptr = None
+
+    def __enter__(self):
+        return self
 
-class ResultConstPtrServiceVariousSlicesError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    def __exit__(self, *args):
+        self.close()
 
 
-class ResultOptionUtf8StringError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+class Tuple2_F32_U32(ctypes.Structure):
+    """ A tuple lowered to a struct, fields are named after their position."""
 
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("x0", ctypes.c_float),
+        ("x1", ctypes.c_uint32),
+    ]
 
-class ResultUseStringError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    def __init__(self, x0: float = None, x1: int = None):
+        if x0 is not None:
+            self.x0 = x0
+        if x1 is not None:
+            self.x1 = x1
 
+    @property
+    def x0(self) -> float:
+        return ctypes.Structure.__get__(self, "x0")
 
-class ResultVecUtf8StringError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    @x0.setter
+    def x0(self, value: float):
+        return ctypes.Structure.__set__(self, "x0", value)
 
+    @property
+    def x1(self) -> int:
+        return ctypes.Structure.__get__(self, "x1")
 
-class CallbackTable(ctypes.Structure):
+    @x1.setter
+    def x1(self, value: int):
+        return ctypes.Structure.__set__(self, "x1", value)
 
-    # These fields represent the underlying C data layout
-    _fields_ = [
-        ("my_callback", ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)),
-        ("my_callback_namespaced", ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)),
-        ("my_callback_void", ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_void_p)),
-        ("my_callback_contextual", ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_uint32, ctypes.c_void_p)),
-        ("sum_delegate_1", ctypes.CFUNCTYPE(None, ctypes.c_void_p)),
-        ("sum_delegate_2", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)),
-        ("sum_delegate_return", ctypes.CFUNCTYPE(ResultError, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)),
-        ("sum_delegate_return_2", ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)),
-    ]
+    def to_tuple(self) -> typing.Tuple[float, int]:
+        return (self.x0, self.x1,)
 
-    def __init__(self, my_callback = None, my_callback_namespaced = None, my_callback_void = None, my_callback_contextual = None, sum_delegate_1 = None, sum_delegate_2 = None, sum_delegate_return = None, sum_delegate_return_2 = None):
-        if my_callback is not None:
-            self.my_callback = my_callback
-        if my_callback_namespaced is not None:
-            self.my_callback_namespaced = my_callback_namespaced
-        if my_callback_void is not None:
-            self.my_callback_void = my_callback_void
-        if my_callback_contextual is not None:
-            self.my_callback_contextual = my_callback_contextual
-        if sum_delegate_1 is not None:
-            self.sum_delegate_1 = sum_delegate_1
-        if sum_delegate_2 is not None:
-            self.sum_delegate_2 = sum_delegate_2
-        if sum_delegate_return is not None:
-            self.sum_delegate_return = sum_delegate_return
-        if sum_delegate_return_2 is not None:
-            self.sum_delegate_return_2 = sum_delegate_return_2
+    def __len__(self) -> int:
+        return 2
 
-    @property
-    def my_callback(self):
-        return ctypes.Structure.__get__(self, "my_callback")
+    def __iter__(self):
+        return iter(self.to_tuple())
 
-    @my_callback.setter
-    def my_callback(self, value):
-        return ctypes.Structure.__set__(self, "my_callback", value)
+    def __getitem__(self, index: int):
+        return self.to_tuple()[index]
 
-    @property
-    def my_callback_namespaced(self):
-        return ctypes.Structure.__get__(self, "my_callback_namespaced")
+    def __eq__(self, other) -> bool:
+        return isinstance(other, (Tuple2_F32_U32, tuple)) and self.to_tuple() == tuple(other)
 
-    @my_callback_namespaced.setter
-    def my_callback_namespaced(self, value):
-        return ctypes.Structure.__set__(self, "my_callback_namespaced", value)
+    def __repr__(self) -> str:
+        return repr(self.to_tuple())
 
-    @property
-    def my_callback_void(self):
-        return ctypes.Structure.__get__(self, "my_callback_void")
 
-    @my_callback_void.setter
-    def my_callback_void(self, value):
-        return ctypes.Structure.__set__(self, "my_callback_void", value)
+class Tuple2_U32_F32(ctypes.Structure):
+    """ A tuple lowered to a struct, fields are named after their position."""
 
-    @property
-    def my_callback_contextual(self):
-        return ctypes.Structure.__get__(self, "my_callback_contextual")
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("x0", ctypes.c_uint32),
+        ("x1", ctypes.c_float),
+    ]
 
-    @my_callback_contextual.setter
-    def my_callback_contextual(self, value):
-        return ctypes.Structure.__set__(self, "my_callback_contextual", value)
+    def __init__(self, x0: int = None, x1: float = None):
+        if x0 is not None:
+            self.x0 = x0
+        if x1 is not None:
+            self.x1 = x1
 
     @property
-    def sum_delegate_1(self):
-        return ctypes.Structure.__get__(self, "sum_delegate_1")
+    def x0(self) -> int:
+        return ctypes.Structure.__get__(self, "x0")
 
-    @sum_delegate_1.setter
-    def sum_delegate_1(self, value):
-        return ctypes.Structure.__set__(self, "sum_delegate_1", value)
+    @x0.setter
+    def x0(self, value: int):
+        return ctypes.Structure.__set__(self, "x0", value)
 
     @property
-    def sum_delegate_2(self):
-        return ctypes.Structure.__get__(self, "sum_delegate_2")
+    def x1(self) -> float:
+        return ctypes.Structure.__get__(self, "x1")
 
-    @sum_delegate_2.setter
-    def sum_delegate_2(self, value):
-        return ctypes.Structure.__set__(self, "sum_delegate_2", value)
+    @x1.setter
+    def x1(self, value: float):
+        return ctypes.Structure.__set__(self, "x1", value)
 
-    @property
-    def sum_delegate_return(self):
-        return ctypes.Structure.__get__(self, "sum_delegate_return")
+    def to_tuple(self) -> typing.Tuple[int, float]:
+        return (self.x0, self.x1,)
 
-    @sum_delegate_return.setter
-    def sum_delegate_return(self, value):
-        return ctypes.Structure.__set__(self, "sum_delegate_return", value)
+    def __len__(self) -> int:
+        return 2
 
-    @property
-    def sum_delegate_return_2(self):
-        return ctypes.Structure.__get__(self, "sum_delegate_return_2")
+    def __iter__(self):
+        return iter(self.to_tuple())
 
-    @sum_delegate_return_2.setter
-    def sum_delegate_return_2(self, value):
-        return ctypes.Structure.__set__(self, "sum_delegate_return_2", value)
+    def __getitem__(self, index: int):
+        return self.to_tuple()[index]
 
+    def __eq__(self, other) -> bool:
+        return isinstance(other, (Tuple2_U32_F32, tuple)) and self.to_tuple() == tuple(other)
+
+    def __repr__(self) -> str:
+        return repr(self.to_tuple())
 
-class CharArray(ctypes.Structure):
+
+class Tuple2_U32_U32(ctypes.Structure):
+    """ A tuple lowered to a struct, fields are named after their position."""
 
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("str", FixedString),
-        ("str_2", FixedString),
+        ("x0", ctypes.c_uint32),
+        ("x1", ctypes.c_uint32),
     ]
 
-    def __init__(self, str: FixedString = None, str_2: FixedString = None):
-        if str is not None:
-            self.str = str
-        if str_2 is not None:
-            self.str_2 = str_2
+    def __init__(self, x0: int = None, x1: int = None):
+        if x0 is not None:
+            self.x0 = x0
+        if x1 is not None:
+            self.x1 = x1
 
     @property
-    def str(self) -> FixedString:
-        return ctypes.Structure.__get__(self, "str")
+    def x0(self) -> int:
+        return ctypes.Structure.__get__(self, "x0")
 
-    @str.setter
-    def str(self, value: FixedString):
-        return ctypes.Structure.__set__(self, "str", value)
+    @x0.setter
+    def x0(self, value: int):
+        return ctypes.Structure.__set__(self, "x0", value)
 
     @property
-    def str_2(self) -> FixedString:
-        return ctypes.Structure.__get__(self, "str_2")
+    def x1(self) -> int:
+        return ctypes.Structure.__get__(self, "x1")
 
-    @str_2.setter
-    def str_2(self, value: FixedString):
-        return ctypes.Structure.__set__(self, "str_2", value)
+    @x1.setter
+    def x1(self, value: int):
+        return ctypes.Structure.__set__(self, "x1", value)
 
+    def to_tuple(self) -> typing.Tuple[int, int]:
+        return (self.x0, self.x1,)
 
-class Layer2Utf8String(ctypes.Structure):
+    def __len__(self) -> int:
+        return 2
+
+    def __iter__(self):
+        return iter(self.to_tuple())
+
+    def __getitem__(self, index: int):
+        return self.to_tuple()[index]
+
+    def __eq__(self, other) -> bool:
+        return isinstance(other, (Tuple2_U32_U32, tuple)) and self.to_tuple() == tuple(other)
+
+    def __repr__(self) -> str:
+        return repr(self.to_tuple())
+
+
+class Tuple2_U64_U32(ctypes.Structure):
+    """ A tuple lowered to a struct, fields are named after their position."""
 
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("layer_1", Layer1Utf8String),
-        ("vec", Vec3f32),
-        ("the_enum", ctypes.c_int),
-        ("strings", VecUtf8String),
+        ("x0", ctypes.c_uint64),
+        ("x1", ctypes.c_uint32),
     ]
 
-    def __init__(self, layer_1: Layer1Utf8String = None, vec: Vec3f32 = None, the_enum: TODO = None, strings = None):
-        if layer_1 is not None:
-            self.layer_1 = layer_1
-        if vec is not None:
-            self.vec = vec
-        if the_enum is not None:
-            self.the_enum = the_enum
-        if strings is not None:
-            self.strings = strings
+    def __init__(self, x0: int = None, x1: int = None):
+        if x0 is not None:
+            self.x0 = x0
+        if x1 is not None:
+            self.x1 = x1
 
     @property
-    def layer_1(self) -> Layer1Utf8String:
-        return ctypes.Structure.__get__(self, "layer_1")
+    def x0(self) -> int:
+        return ctypes.Structure.__get__(self, "x0")
 
-    @layer_1.setter
-    def layer_1(self, value: Layer1Utf8String):
-        return ctypes.Structure.__set__(self, "layer_1", value)
+    @x0.setter
+    def x0(self, value: int):
+        return ctypes.Structure.__set__(self, "x0", value)
 
     @property
-    def vec(self) -> Vec3f32:
-        return ctypes.Structure.__get__(self, "vec")
+    def x1(self) -> int:
+        return ctypes.Structure.__get__(self, "x1")
 
-    @vec.setter
-    def vec(self, value: Vec3f32):
-        return ctypes.Structure.__set__(self, "vec", value)
+    @x1.setter
+    def x1(self, value: int):
+        return ctypes.Structure.__set__(self, "x1", value)
 
-    @property
-    def the_enum(self) -> TODO:
-        return ctypes.Structure.__get__(self, "the_enum")
+    def to_tuple(self) -> typing.Tuple[int, int]:
+        return (self.x0, self.x1,)
 
-    @the_enum.setter
-    def the_enum(self, value: TODO):
-        return ctypes.Structure.__set__(self, "the_enum", value)
+    def __len__(self) -> int:
+        return 2
 
-    @property
-    def strings(self):
-        return ctypes.Structure.__get__(self, "strings")
+    def __iter__(self):
+        return iter(self.to_tuple())
 
-    @strings.setter
-    def strings(self, value):
-        return ctypes.Structure.__set__(self, "strings", value)
+    def __getitem__(self, index: int):
+        return self.to_tuple()[index]
 
+    def __eq__(self, other) -> bool:
+        return isinstance(other, (Tuple2_U64_U32, tuple)) and self.to_tuple() == tuple(other)
 
-class NestedArray(ctypes.Structure):
+    def __repr__(self) -> str:
+        return repr(self.to_tuple())
+
+
+class EnumPayload(ctypes.Structure):
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("B", Vec3f32),
+            ("C", ctypes.c_uint32),
+        ]
 
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("field_enum", ctypes.c_int),
-        ("field_vec", Vec3f32),
-        ("field_bool", ctypes.c_bool),
-        ("field_int", ctypes.c_int32),
-        ("field_array", ctypes.c_uint16 * 5),
-        ("field_array_2", ctypes.c_uint16 * 5),
-        ("field_struct", Array),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __init__(self, field_enum: TODO = None, field_vec: Vec3f32 = None, field_bool: bool = None, field_int: int = None, field_array = None, field_array_2 = None, field_struct: Array = None):
-        if field_enum is not None:
-            self.field_enum = field_enum
-        if field_vec is not None:
-            self.field_vec = field_vec
-        if field_bool is not None:
-            self.field_bool = field_bool
-        if field_int is not None:
-            self.field_int = field_int
-        if field_array is not None:
-            self.field_array = field_array
-        if field_array_2 is not None:
-            self.field_array_2 = field_array_2
-        if field_struct is not None:
-            self.field_struct = field_struct
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    @property
-    def field_enum(self) -> TODO:
-        return ctypes.Structure.__get__(self, "field_enum")
+    @dataclasses.dataclass(frozen=True)
+    class AVariant:
+        pass
 
-    @field_enum.setter
-    def field_enum(self, value: TODO):
-        return ctypes.Structure.__set__(self, "field_enum", value)
+    @dataclasses.dataclass(frozen=True)
+    class BVariant:
+        value: Vec3f32
 
-    @property
-    def field_vec(self) -> Vec3f32:
-        return ctypes.Structure.__get__(self, "field_vec")
+    @dataclasses.dataclass(frozen=True)
+    class CVariant:
+        value: int
 
-    @field_vec.setter
-    def field_vec(self, value: Vec3f32):
-        return ctypes.Structure.__set__(self, "field_vec", value)
+    @staticmethod
+    def A() -> EnumPayload:
+        rval = EnumPayload()
+        rval._variant = 0
+        return rval
 
-    @property
-    def field_bool(self) -> bool:
-        return ctypes.Structure.__get__(self, "field_bool")
+    def is_a(self) -> bool:
+        return self._variant == 0
 
-    @field_bool.setter
-    def field_bool(self, value: bool):
-        return ctypes.Structure.__set__(self, "field_bool", value)
+    @staticmethod
+    def B(value: Vec3f32) -> EnumPayload:
+        rval = EnumPayload()
+        rval._payload.B = value
+        rval._variant = 1
+        return rval
 
-    @property
-    def field_int(self) -> int:
-        return ctypes.Structure.__get__(self, "field_int")
+    def is_b(self) -> bool:
+        return self._variant == 1
 
-    @field_int.setter
-    def field_int(self, value: int):
-        return ctypes.Structure.__set__(self, "field_int", value)
+    def as_b(self) -> Vec3f32:
+        """Returns the `B` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `B`, got {self!r}")
+        return self._payload.B
 
-    @property
-    def field_array(self):
-        return ctypes.Structure.__get__(self, "field_array")
+    @staticmethod
+    def C(value: int) -> EnumPayload:
+        rval = EnumPayload()
+        rval._payload.C = value
+        rval._variant = 2
+        return rval
+
+    def is_c(self) -> bool:
+        return self._variant == 2
+
+    def as_c(self) -> int:
+        """Returns the `C` value, raises if this is another variant."""
+        if self._variant != 2: raise Exception(f"Expected `C`, got {self!r}")
+        return self._payload.C
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return EnumPayload.AVariant()
+        if self._variant == 1: return EnumPayload.BVariant(self._payload.B)
+        if self._variant == 2: return EnumPayload.CVariant(self._payload.C)
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, EnumPayload) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+
+class Array(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.c_uint8 * 16),
+    ]
+
+    def __init__(self, data = None):
+        if data is not None:
+            self.data = data
+
+    @property
+    def data(self):
+        return ctypes.Structure.__get__(self, "data")
+
+    @data.setter
+    def data(self, value):
+        return ctypes.Structure.__set__(self, "data", value)
+
+
+class Container(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("foreign", Local),
+    ]
+
+    def __init__(self, foreign: Local = None):
+        if foreign is not None:
+            self.foreign = foreign
+
+    @property
+    def foreign(self) -> Local:
+        return ctypes.Structure.__get__(self, "foreign")
+
+    @foreign.setter
+    def foreign(self, value: Local):
+        return ctypes.Structure.__set__(self, "foreign", value)
+
+
+class DynStorage(ctypes.Structure):
+    """ Some key-value storage shared by multiple services."""
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("context", ctypes.c_void_p),
+        ("vtable", ctypes.c_void_p),
+    ]
+
+    def __init__(self, context: ctypes.c_void_p = None, vtable: ctypes.c_void_p = None):
+        if context is not None:
+            self.context = context
+        if vtable is not None:
+            self.vtable = vtable
+
+    @property
+    def context(self) -> ctypes.c_void_p:
+        return ctypes.Structure.__get__(self, "context")
+
+    @context.setter
+    def context(self, value: ctypes.c_void_p):
+        return ctypes.Structure.__set__(self, "context", value)
+
+    @property
+    def vtable(self) -> ctypes.c_void_p:
+        return ctypes.Structure.__get__(self, "vtable")
+
+    @vtable.setter
+    def vtable(self, value: ctypes.c_void_p):
+        return ctypes.Structure.__set__(self, "vtable", value)
+
+
+class FixedString(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.c_uint8 * 32),
+    ]
+
+    def __init__(self, data = None):
+        if data is not None:
+            self.data = data
+
+    @property
+    def data(self):
+        return ctypes.Structure.__get__(self, "data")
+
+    @data.setter
+    def data(self, value):
+        return ctypes.Structure.__set__(self, "data", value)
+
+
+class Genericu32(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("x", ctypes.POINTER(ctypes.c_uint32)),
+    ]
+
+    def __init__(self, x: ctypes.POINTER(ctypes.c_uint32) = None):
+        if x is not None:
+            self.x = x
+
+    @property
+    def x(self) -> ctypes.POINTER(ctypes.c_uint32):
+        return ctypes.Structure.__get__(self, "x")
+
+    @x.setter
+    def x(self, value: ctypes.POINTER(ctypes.c_uint32)):
+        return ctypes.Structure.__set__(self, "x", value)
+
+
+class Genericu8(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("x", ctypes.POINTER(ctypes.c_uint8)),
+    ]
+
+    def __init__(self, x: ctypes.POINTER(ctypes.c_uint8) = None):
+        if x is not None:
+            self.x = x
+
+    @property
+    def x(self) -> ctypes.POINTER(ctypes.c_uint8):
+        return ctypes.Structure.__get__(self, "x")
+
+    @x.setter
+    def x(self, value: ctypes.POINTER(ctypes.c_uint8)):
+        return ctypes.Structure.__set__(self, "x", value)
+
+
+class Layer1Utf8String(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("maybe_1", OptionUtf8String),
+        ("maybe_2", VecUtf8String),
+        ("maybe_3", Utf8String),
+    ]
+
+    def __init__(self, maybe_1: OptionUtf8String = None, maybe_2: VecUtf8String = None, maybe_3 = None):
+        if maybe_1 is not None:
+            self.maybe_1 = maybe_1
+        if maybe_2 is not None:
+            self.maybe_2 = maybe_2
+        if maybe_3 is not None:
+            self.maybe_3 = maybe_3
+
+    @property
+    def maybe_1(self) -> OptionUtf8String:
+        return ctypes.Structure.__get__(self, "maybe_1")
+
+    @maybe_1.setter
+    def maybe_1(self, value: OptionUtf8String):
+        return ctypes.Structure.__set__(self, "maybe_1", value)
+
+    @property
+    def maybe_2(self) -> VecUtf8String:
+        return ctypes.Structure.__get__(self, "maybe_2")
+
+    @maybe_2.setter
+    def maybe_2(self, value: VecUtf8String):
+        return ctypes.Structure.__set__(self, "maybe_2", value)
+
+    @property
+    def maybe_3(self):
+        return ctypes.Structure.__get__(self, "maybe_3")
+
+    @maybe_3.setter
+    def maybe_3(self, value):
+        return ctypes.Structure.__set__(self, "maybe_3", value)
+
+
+class UseSliceAndVec(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("s1", SliceUtf8String),
+        ("s2", VecUtf8String),
+    ]
+
+    def __init__(self, s1: SliceUtf8String = None, s2: VecUtf8String = None):
+        if s1 is not None:
+            self.s1 = s1
+        if s2 is not None:
+            self.s2 = s2
+
+    @property
+    def s1(self) -> SliceUtf8String:
+        return ctypes.Structure.__get__(self, "s1")
+
+    @s1.setter
+    def s1(self, value: SliceUtf8String):
+        return ctypes.Structure.__set__(self, "s1", value)
+
+    @property
+    def s2(self) -> VecUtf8String:
+        return ctypes.Structure.__get__(self, "s2")
+
+    @s2.setter
+    def s2(self, value: VecUtf8String):
+        return ctypes.Structure.__set__(self, "s2", value)
+
+
+class Weird2u8(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("t", ctypes.c_uint8),
+        ("a", ctypes.c_uint8 * 5),
+        ("r", ctypes.POINTER(ctypes.c_uint8)),
+    ]
+
+    def __init__(self, t: int = None, a = None, r: ctypes.POINTER(ctypes.c_uint8) = None):
+        if t is not None:
+            self.t = t
+        if a is not None:
+            self.a = a
+        if r is not None:
+            self.r = r
+
+    @property
+    def t(self) -> int:
+        return ctypes.Structure.__get__(self, "t")
+
+    @t.setter
+    def t(self, value: int):
+        return ctypes.Structure.__set__(self, "t", value)
+
+    @property
+    def a(self):
+        return ctypes.Structure.__get__(self, "a")
+
+    @a.setter
+    def a(self, value):
+        return ctypes.Structure.__set__(self, "a", value)
+
+    @property
+    def r(self) -> ctypes.POINTER(ctypes.c_uint8):
+        return ctypes.Structure.__get__(self, "r")
+
+    @r.setter
+    def r(self, value: ctypes.POINTER(ctypes.c_uint8)):
+        return ctypes.Structure.__set__(self, "r", value)
+
+
+class WireBuffer(ctypes.Structure):
+    """FFI buffer for Wire data transfer"""
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(ctypes.c_uint8)),
+        ("len", ctypes.c_int32),
+        ("capacity", ctypes.c_int32),
+    ]
+
+    def __init__(self, data: ctypes.POINTER(ctypes.c_uint8) = None, len: int = None, capacity: int = None):
+        if data is not None:
+            self.data = data
+        if len is not None:
+            self.len = len
+        if capacity is not None:
+            self.capacity = capacity
+
+    @property
+    def data(self) -> ctypes.POINTER(ctypes.c_uint8):
+        return ctypes.Structure.__get__(self, "data")
+
+    @data.setter
+    def data(self, value: ctypes.POINTER(ctypes.c_uint8)):
+        return ctypes.Structure.__set__(self, "data", value)
+
+    @property
+    def len(self) -> int:
+        return ctypes.Structure.__get__(self, "len")
+
+    @len.setter
+    def len(self, value: int):
+        return ctypes.Structure.__set__(self, "len", value)
+
+    @property
+    def capacity(self) -> int:
+        return ctypes.Structure.__get__(self, "capacity")
+
+    @capacity.setter
+    def capacity(self, value: int):
+        return ctypes.Structure.__set__(self, "capacity", value)
+
+
+class SliceUseCStrPtr(ctypes.Structure):
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(UseCStrPtr)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    def __len__(self):
+        return self.len
+
+    def __getitem__(self, i) -> UseCStrPtr:
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        return self.data[index]
+
+    def copied(self) -> SliceUseCStrPtr:
+        """Returns a shallow, owned copy of the underlying slice.
+
+        The returned object owns the immediate data, but not the targets of any contained
+        pointers. In other words, if your struct contains any pointers the returned object
+        may only be used as long as these pointers are valid. If the struct did not contain
+        any pointers the returned object is valid indefinitely."""
+        array = (UseCStrPtr * len(self))()
+        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(UseCStrPtr))
+        rval = SliceUseCStrPtr(data=ctypes.cast(array, ctypes.POINTER(UseCStrPtr)), len=len(self))
+        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+        return rval
+
+    def __iter__(self) -> typing.Iterable[UseCStrPtr]:
+        return _Iter(self)
+
+    def iter(self) -> typing.Iterable[UseCStrPtr]:
+        """Convenience method returning a value iterator."""
+        return iter(self)
+
+    def first(self) -> UseCStrPtr:
+        """Returns the first element of this slice."""
+        return self[0]
+
+    def last(self) -> UseCStrPtr:
+        """Returns the last element of this slice."""
+        return self[len(self)-1]
+
+
+class SliceUseString(ctypes.Structure):
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(UseString)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    def __len__(self):
+        return self.len
+
+    def __getitem__(self, i) -> UseString:
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        return self.data[index]
+
+    def copied(self) -> SliceUseString:
+        """Returns a shallow, owned copy of the underlying slice.
+
+        The returned object owns the immediate data, but not the targets of any contained
+        pointers. In other words, if your struct contains any pointers the returned object
+        may only be used as long as these pointers are valid. If the struct did not contain
+        any pointers the returned object is valid indefinitely."""
+        array = (UseString * len(self))()
+        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(UseString))
+        rval = SliceUseString(data=ctypes.cast(array, ctypes.POINTER(UseString)), len=len(self))
+        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+        return rval
+
+    def __iter__(self) -> typing.Iterable[UseString]:
+        return _Iter(self)
+
+    def iter(self) -> typing.Iterable[UseString]:
+        """Convenience method returning a value iterator."""
+        return iter(self)
+
+    def first(self) -> UseString:
+        """Returns the first element of this slice."""
+        return self[0]
+
+    def last(self) -> UseString:
+        """Returns the last element of this slice."""
+        return self[len(self)-1]
+
+
+class SliceVec(ctypes.Structure):
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(Vec)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    def __len__(self):
+        return self.len
+
+    def __getitem__(self, i) -> Vec:
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        return self.data[index]
+
+    def copied(self) -> SliceVec:
+        """Returns a shallow, owned copy of the underlying slice.
+
+        The returned object owns the immediate data, but not the targets of any contained
+        pointers. In other words, if your struct contains any pointers the returned object
+        may only be used as long as these pointers are valid. If the struct did not contain
+        any pointers the returned object is valid indefinitely."""
+        array = (Vec * len(self))()
+        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(Vec))
+        rval = SliceVec(data=ctypes.cast(array, ctypes.POINTER(Vec)), len=len(self))
+        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+        return rval
+
+    def __iter__(self) -> typing.Iterable[Vec]:
+        return _Iter(self)
+
+    def iter(self) -> typing.Iterable[Vec]:
+        """Convenience method returning a value iterator."""
+        return iter(self)
+
+    def first(self) -> Vec:
+        """Returns the first element of this slice."""
+        return self[0]
+
+    def last(self) -> Vec:
+        """Returns the last element of this slice."""
+        return self[len(self)-1]
+
+
+class SliceVec3f32(ctypes.Structure):
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(Vec3f32)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    def __len__(self):
+        return self.len
+
+    def __getitem__(self, i) -> Vec3f32:
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        return self.data[index]
+
+    def copied(self) -> SliceVec3f32:
+        """Returns a shallow, owned copy of the underlying slice.
+
+        The returned object owns the immediate data, but not the targets of any contained
+        pointers. In other words, if your struct contains any pointers the returned object
+        may only be used as long as these pointers are valid. If the struct did not contain
+        any pointers the returned object is valid indefinitely."""
+        array = (Vec3f32 * len(self))()
+        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(Vec3f32))
+        rval = SliceVec3f32(data=ctypes.cast(array, ctypes.POINTER(Vec3f32)), len=len(self))
+        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+        return rval
+
+    def __iter__(self) -> typing.Iterable[Vec3f32]:
+        return _Iter(self)
+
+    def iter(self) -> typing.Iterable[Vec3f32]:
+        """Convenience method returning a value iterator."""
+        return iter(self)
+
+    def first(self) -> Vec3f32:
+        """Returns the first element of this slice."""
+        return self[0]
+
+    def last(self) -> Vec3f32:
+        """Returns the last element of this slice."""
+        return self[len(self)-1]
+
+
+class SliceMutVec(ctypes.Structure):
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(Vec)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    def __len__(self):
+        return self.len
+
+    def __getitem__(self, i) -> Vec:
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        return self.data[index]
+
+    def __setitem__(self, i, v: Vec):
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        self.data[index] = v
+
+    def copied(self) -> SliceMutVec:
+        """Returns a shallow, owned copy of the underlying slice.
+
+        The returned object owns the immediate data, but not the targets of any contained
+        pointers. In other words, if your struct contains any pointers the returned object
+        may only be used as long as these pointers are valid. If the struct did not contain
+        any pointers the returned object is valid indefinitely."""
+        array = (Vec * len(self))()
+        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(Vec))
+        rval = SliceMutVec(data=ctypes.cast(array, ctypes.POINTER(Vec)), len=len(self))
+        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+        return rval
+
+    def __iter__(self) -> typing.Iterable[Vec]:
+        return _Iter(self)
+
+    def iter(self) -> typing.Iterable[Vec]:
+        """Convenience method returning a value iterator."""
+        return iter(self)
+
+    def first(self) -> Vec:
+        """Returns the first element of this slice."""
+        return self[0]
+
+    def last(self) -> Vec:
+        """Returns the last element of this slice."""
+        return self[len(self)-1]
+
+
+class OptionInner(ctypes.Structure):
+    """Option that contains Some(value) or None."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", Inner),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: Inner
+
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
+
+    @staticmethod
+    def Some(value: Inner) -> OptionInner:
+        # Element if Some().
+        rval = OptionInner()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
+
+    def is_some(self) -> bool:
+        return self._variant == 0
+
+    def as_some(self) -> Inner:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
+
+    @staticmethod
+    def None_() -> OptionInner:
+        rval = OptionInner()
+        rval._variant = 1
+        return rval
+
+    def is_none(self) -> bool:
+        return self._variant == 1
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionInner.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionInner.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, OptionInner) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> Inner:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class OptionTuple2_U32_U32(ctypes.Structure):
+    """Option that contains Some(value) or None."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", Tuple2_U32_U32),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: typing.Tuple[int, int]
+
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
+
+    @staticmethod
+    def Some(value: typing.Tuple[int, int]) -> OptionTuple2_U32_U32:
+        # Element if Some().
+        rval = OptionTuple2_U32_U32()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
+
+    def is_some(self) -> bool:
+        return self._variant == 0
+
+    def as_some(self) -> typing.Tuple[int, int]:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
+
+    @staticmethod
+    def None_() -> OptionTuple2_U32_U32:
+        rval = OptionTuple2_U32_U32()
+        rval._variant = 1
+        return rval
+
+    def is_none(self) -> bool:
+        return self._variant == 1
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionTuple2_U32_U32.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionTuple2_U32_U32.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, OptionTuple2_U32_U32) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> typing.Tuple[int, int]:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class OptionVec(ctypes.Structure):
+    """Option that contains Some(value) or None."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", Vec),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: Vec
+
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
+
+    @staticmethod
+    def Some(value: Vec) -> OptionVec:
+        # Element if Some().
+        rval = OptionVec()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
+
+    def is_some(self) -> bool:
+        return self._variant == 0
+
+    def as_some(self) -> Vec:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
+
+    @staticmethod
+    def None_() -> OptionVec:
+        rval = OptionVec()
+        rval._variant = 1
+        return rval
+
+    def is_none(self) -> bool:
+        return self._variant == 1
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionVec.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionVec.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, OptionVec) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> Vec:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class OptionVec3f32(ctypes.Structure):
+    """Option that contains Some(value) or None."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", Vec3f32),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: Vec3f32
+
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
+
+    @staticmethod
+    def Some(value: Vec3f32) -> OptionVec3f32:
+        # Element if Some().
+        rval = OptionVec3f32()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
+
+    def is_some(self) -> bool:
+        return self._variant == 0
+
+    def as_some(self) -> Vec3f32:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
+
+    @staticmethod
+    def None_() -> OptionVec3f32:
+        rval = OptionVec3f32()
+        rval._variant = 1
+        return rval
+
+    def is_none(self) -> bool:
+        return self._variant == 1
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionVec3f32.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionVec3f32.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, OptionVec3f32) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> Vec3f32:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class ResultConstPtrServiceAsyncBasicError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceAsyncBasicError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceAsyncBasicError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceAsyncBasicError:
+        # Error value.
+        rval = ResultConstPtrServiceAsyncBasicError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceAsyncBasicError:
+        rval = ResultConstPtrServiceAsyncBasicError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceAsyncBasicError:
+        rval = ResultConstPtrServiceAsyncBasicError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceAsyncBasicError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceAsyncBasicError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceAsyncBasicError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceAsyncBasicError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceAsyncBasicError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceAsyncResultError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceAsyncResultError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceAsyncResultError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceAsyncResultError:
+        # Error value.
+        rval = ResultConstPtrServiceAsyncResultError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceAsyncResultError:
+        rval = ResultConstPtrServiceAsyncResultError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceAsyncResultError:
+        rval = ResultConstPtrServiceAsyncResultError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceAsyncResultError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceAsyncResultError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceAsyncResultError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceAsyncResultError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceAsyncResultError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceAsyncSleepError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceAsyncSleepError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceAsyncSleepError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceAsyncSleepError:
+        # Error value.
+        rval = ResultConstPtrServiceAsyncSleepError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceAsyncSleepError:
+        rval = ResultConstPtrServiceAsyncSleepError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceAsyncSleepError:
+        rval = ResultConstPtrServiceAsyncSleepError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceAsyncSleepError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceAsyncSleepError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceAsyncSleepError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceAsyncSleepError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceAsyncSleepError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceAsyncStructsError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceAsyncStructsError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceAsyncStructsError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceAsyncStructsError:
+        # Error value.
+        rval = ResultConstPtrServiceAsyncStructsError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceAsyncStructsError:
+        rval = ResultConstPtrServiceAsyncStructsError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceAsyncStructsError:
+        rval = ResultConstPtrServiceAsyncStructsError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceAsyncStructsError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceAsyncStructsError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceAsyncStructsError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceAsyncStructsError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceAsyncStructsError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceAsyncVecStringError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceAsyncVecStringError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceAsyncVecStringError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceAsyncVecStringError:
+        # Error value.
+        rval = ResultConstPtrServiceAsyncVecStringError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceAsyncVecStringError:
+        rval = ResultConstPtrServiceAsyncVecStringError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceAsyncVecStringError:
+        rval = ResultConstPtrServiceAsyncVecStringError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceAsyncVecStringError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceAsyncVecStringError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceAsyncVecStringError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceAsyncVecStringError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceAsyncVecStringError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceBasicError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceBasicError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceBasicError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceBasicError:
+        # Error value.
+        rval = ResultConstPtrServiceBasicError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceBasicError:
+        rval = ResultConstPtrServiceBasicError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceBasicError:
+        rval = ResultConstPtrServiceBasicError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceBasicError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceBasicError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceBasicError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceBasicError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceBasicError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceCallbacksError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceCallbacksError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceCallbacksError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceCallbacksError:
+        # Error value.
+        rval = ResultConstPtrServiceCallbacksError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceCallbacksError:
+        rval = ResultConstPtrServiceCallbacksError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceCallbacksError:
+        rval = ResultConstPtrServiceCallbacksError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceCallbacksError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceCallbacksError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceCallbacksError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceCallbacksError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceCallbacksError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceDependentError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceDependentError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceDependentError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceDependentError:
+        # Error value.
+        rval = ResultConstPtrServiceDependentError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceDependentError:
+        rval = ResultConstPtrServiceDependentError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceDependentError:
+        rval = ResultConstPtrServiceDependentError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceDependentError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceDependentError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceDependentError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceDependentError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceDependentError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceForeignLoggerError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceForeignLoggerError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceForeignLoggerError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceForeignLoggerError:
+        # Error value.
+        rval = ResultConstPtrServiceForeignLoggerError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceForeignLoggerError:
+        rval = ResultConstPtrServiceForeignLoggerError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceForeignLoggerError:
+        rval = ResultConstPtrServiceForeignLoggerError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceForeignLoggerError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceForeignLoggerError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceForeignLoggerError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceForeignLoggerError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceForeignLoggerError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceIgnoringMethodsError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceIgnoringMethodsError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceIgnoringMethodsError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceIgnoringMethodsError:
+        # Error value.
+        rval = ResultConstPtrServiceIgnoringMethodsError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceIgnoringMethodsError:
+        rval = ResultConstPtrServiceIgnoringMethodsError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceIgnoringMethodsError:
+        rval = ResultConstPtrServiceIgnoringMethodsError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceIgnoringMethodsError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceIgnoringMethodsError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceIgnoringMethodsError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceIgnoringMethodsError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceIgnoringMethodsError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceMainError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceMainError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceMainError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceMainError:
+        # Error value.
+        rval = ResultConstPtrServiceMainError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceMainError:
+        rval = ResultConstPtrServiceMainError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceMainError:
+        rval = ResultConstPtrServiceMainError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceMainError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceMainError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceMainError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceMainError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceMainError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceMultipleCtorsError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceMultipleCtorsError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceMultipleCtorsError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceMultipleCtorsError:
+        # Error value.
+        rval = ResultConstPtrServiceMultipleCtorsError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceMultipleCtorsError:
+        rval = ResultConstPtrServiceMultipleCtorsError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceMultipleCtorsError:
+        rval = ResultConstPtrServiceMultipleCtorsError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceMultipleCtorsError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceMultipleCtorsError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceMultipleCtorsError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceMultipleCtorsError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceMultipleCtorsError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceMutexError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceMutexError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceMutexError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceMutexError:
+        # Error value.
+        rval = ResultConstPtrServiceMutexError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceMutexError:
+        rval = ResultConstPtrServiceMutexError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceMutexError:
+        rval = ResultConstPtrServiceMutexError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceMutexError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceMutexError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceMutexError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceMutexError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceMutexError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceOnPanicError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceOnPanicError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceOnPanicError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceOnPanicError:
+        # Error value.
+        rval = ResultConstPtrServiceOnPanicError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceOnPanicError:
+        rval = ResultConstPtrServiceOnPanicError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceOnPanicError:
+        rval = ResultConstPtrServiceOnPanicError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceOnPanicError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceOnPanicError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceOnPanicError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceOnPanicError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceOnPanicError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServicePropertiesError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServicePropertiesError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServicePropertiesError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServicePropertiesError:
+        # Error value.
+        rval = ResultConstPtrServicePropertiesError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServicePropertiesError:
+        rval = ResultConstPtrServicePropertiesError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServicePropertiesError:
+        rval = ResultConstPtrServicePropertiesError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServicePropertiesError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServicePropertiesError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServicePropertiesError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServicePropertiesError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServicePropertiesError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceResultError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceResultError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceResultError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceResultError:
+        # Error value.
+        rval = ResultConstPtrServiceResultError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceResultError:
+        rval = ResultConstPtrServiceResultError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceResultError:
+        rval = ResultConstPtrServiceResultError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceResultError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceResultError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceResultError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceResultError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceResultError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceStaticsError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceStaticsError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceStaticsError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceStaticsError:
+        # Error value.
+        rval = ResultConstPtrServiceStaticsError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceStaticsError:
+        rval = ResultConstPtrServiceStaticsError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceStaticsError:
+        rval = ResultConstPtrServiceStaticsError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceStaticsError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceStaticsError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceStaticsError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceStaticsError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceStaticsError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceStorageArrayError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceStorageArrayError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceStorageArrayError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceStorageArrayError:
+        # Error value.
+        rval = ResultConstPtrServiceStorageArrayError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceStorageArrayError:
+        rval = ResultConstPtrServiceStorageArrayError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceStorageArrayError:
+        rval = ResultConstPtrServiceStorageArrayError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceStorageArrayError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceStorageArrayError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceStorageArrayError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceStorageArrayError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceStorageArrayError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceStorageOffsetError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceStorageOffsetError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceStorageOffsetError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceStorageOffsetError:
+        # Error value.
+        rval = ResultConstPtrServiceStorageOffsetError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceStorageOffsetError:
+        rval = ResultConstPtrServiceStorageOffsetError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceStorageOffsetError:
+        rval = ResultConstPtrServiceStorageOffsetError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceStorageOffsetError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceStorageOffsetError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceStorageOffsetError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceStorageOffsetError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceStorageOffsetError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceStringsError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceStringsError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceStringsError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceStringsError:
+        # Error value.
+        rval = ResultConstPtrServiceStringsError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceStringsError:
+        rval = ResultConstPtrServiceStringsError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceStringsError:
+        rval = ResultConstPtrServiceStringsError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceStringsError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceStringsError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceStringsError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceStringsError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceStringsError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceThreadAffineSyncError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceThreadAffineSyncError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceThreadAffineSyncError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceThreadAffineSyncError:
+        # Error value.
+        rval = ResultConstPtrServiceThreadAffineSyncError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceThreadAffineSyncError:
+        rval = ResultConstPtrServiceThreadAffineSyncError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceThreadAffineSyncError:
+        rval = ResultConstPtrServiceThreadAffineSyncError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceThreadAffineSyncError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceThreadAffineSyncError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceThreadAffineSyncError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceThreadAffineSyncError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceThreadAffineSyncError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise SyncErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultConstPtrServiceVariousSlicesError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", ctypes.c_void_p),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: ctypes.c_void_p
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: ctypes.c_void_p) -> ResultConstPtrServiceVariousSlicesError:
+        # Element if err is `Ok`.
+        rval = ResultConstPtrServiceVariousSlicesError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultConstPtrServiceVariousSlicesError:
+        # Error value.
+        rval = ResultConstPtrServiceVariousSlicesError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultConstPtrServiceVariousSlicesError:
+        rval = ResultConstPtrServiceVariousSlicesError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultConstPtrServiceVariousSlicesError:
+        rval = ResultConstPtrServiceVariousSlicesError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultConstPtrServiceVariousSlicesError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultConstPtrServiceVariousSlicesError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultConstPtrServiceVariousSlicesError.PanicVariant()
+        if self._variant == 3: return ResultConstPtrServiceVariousSlicesError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultConstPtrServiceVariousSlicesError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ctypes.c_void_p:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultOptionUtf8StringError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", OptionUtf8String),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: OptionUtf8String
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: OptionUtf8String) -> ResultOptionUtf8StringError:
+        # Element if err is `Ok`.
+        rval = ResultOptionUtf8StringError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> OptionUtf8String:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultOptionUtf8StringError:
+        # Error value.
+        rval = ResultOptionUtf8StringError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultOptionUtf8StringError:
+        rval = ResultOptionUtf8StringError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultOptionUtf8StringError:
+        rval = ResultOptionUtf8StringError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultOptionUtf8StringError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultOptionUtf8StringError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultOptionUtf8StringError.PanicVariant()
+        if self._variant == 3: return ResultOptionUtf8StringError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultOptionUtf8StringError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> OptionUtf8String:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultUseStringError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", UseString),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: UseString
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: UseString) -> ResultUseStringError:
+        # Element if err is `Ok`.
+        rval = ResultUseStringError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> UseString:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultUseStringError:
+        # Error value.
+        rval = ResultUseStringError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultUseStringError:
+        rval = ResultUseStringError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultUseStringError:
+        rval = ResultUseStringError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultUseStringError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultUseStringError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultUseStringError.PanicVariant()
+        if self._variant == 3: return ResultUseStringError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultUseStringError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> UseString:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class ResultVecUtf8StringError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", VecUtf8String),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: VecUtf8String
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: VecUtf8String) -> ResultVecUtf8StringError:
+        # Element if err is `Ok`.
+        rval = ResultVecUtf8StringError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> VecUtf8String:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultVecUtf8StringError:
+        # Error value.
+        rval = ResultVecUtf8StringError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
+
+    def is_err(self) -> bool:
+        return self._variant == 1
+
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
+
+    @staticmethod
+    def Panic() -> ResultVecUtf8StringError:
+        rval = ResultVecUtf8StringError()
+        rval._variant = 2
+        return rval
+
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultVecUtf8StringError:
+        rval = ResultVecUtf8StringError()
+        rval._variant = 3
+        return rval
+
+    def is_null(self) -> bool:
+        return self._variant == 3
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultVecUtf8StringError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultVecUtf8StringError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultVecUtf8StringError.PanicVariant()
+        if self._variant == 3: return ResultVecUtf8StringError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, ResultVecUtf8StringError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> VecUtf8String:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
+
+
+class CallbackFFISlice(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint8, SliceU8, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_uint8, SliceU8, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class CallbackSliceMut(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, SliceMutU8, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, SliceMutU8, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class CallbackU8(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint8, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint8, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class LogSinkCount(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class LogSinkLog(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class MyCallback(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class MyCallbackContextual(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class MyCallbackNamespaced(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class MyCallbackVoid(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class MyFallibleCallback(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ResultU32Error, ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ResultU32Error, ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class MyOwnedCallback(ctypes.Structure):
+    """Owned callback, kept alive until Rust drops it and invokes the destructor."""
+
+    # Native callbacks Rust still holds on to, keyed by their callback data.
+    _alive = {}
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+        ("destructor", ctypes.CFUNCTYPE(None, ctypes.c_void_p)),
+    ]
+
+    def __init__(self, f):
+        self._f = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(self._f, None, MyOwnedCallback._release)
+
+    @classmethod
+    def from_param(cls, obj):
+        """Registers a new reference every time this is passed to Rust, each released by its own destructor call."""
+        if not isinstance(obj, cls):
+            obj = cls(obj)
+        entry = (obj._f,)
+        key = id(entry)
+        cls._alive[key] = entry
+        rval = cls.__new__(cls)
+        ctypes.Structure.__init__(rval, obj._f, key, cls._release)
+        rval._f = obj._f
+        return rval
+
+    # Invoked by Rust once it dropped the callback.
+    _release = ctypes.CFUNCTYPE(None, ctypes.c_void_p)(lambda data: MyOwnedCallback._alive.pop(data, None))
+
+
+class NestedStringCallback(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, UseString, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, UseString, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class OnItemF32(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_float, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_float, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class OnItemU32(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class StringCallback(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, Utf8String, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, Utf8String, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class SumDelegate1(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class SumDelegate2(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class SumDelegateReturn(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(ResultError, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(ResultError, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class SumDelegateReturn2(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class VecVec3f32(ctypes.Structure):
+    """ Vec marshalling helper.
+ A highly dangerous 'use once type' that has ownership semantics!
+ Once passed over an FFI boundary 'the other side' is meant to own
+ (and free) it. Rust handles that fine, but if in C# you put this
+ in a struct and then call Rust multiple times with that struct 
+ you'll free the same pointer multiple times, and get UB!"""
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("ptr", ctypes.POINTER(Vec3f32)),
+        ("len", ctypes.c_uint64),
+        ("capacity", ctypes.c_uint64),
+    ]
+
+    def __init__(self, ptr: ctypes.POINTER(Vec3f32) = None, len: int = None, capacity: int = None):
+        if ptr is not None:
+            self.ptr = ptr
+        if len is not None:
+            self.len = len
+        if capacity is not None:
+            self.capacity = capacity
+
+    @property
+    def ptr(self) -> ctypes.POINTER(Vec3f32):
+        return ctypes.Structure.__get__(self, "ptr")
+
+    @ptr.setter
+    def ptr(self, value: ctypes.POINTER(Vec3f32)):
+        return ctypes.Structure.__set__(self, "ptr", value)
+
+    @property
+    def len(self) -> int:
+        return ctypes.Structure.__get__(self, "len")
+
+    @len.setter
+    def len(self, value: int):
+        return ctypes.Structure.__set__(self, "len", value)
+
+    @property
+    def capacity(self) -> int:
+        return ctypes.Structure.__get__(self, "capacity")
+
+    @capacity.setter
+    def capacity(self, value: int):
+        return ctypes.Structure.__set__(self, "capacity", value)
+
+
+class MapU32Vec3f32(ctypes.Structure):
+    """ Map marshalling helper, holding `len` keys in ascending order and their values.
+ Like a Vec this has ownership semantics, once passed over an FFI boundary
+ 'the other side' is meant to own (and free) it."""
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("keys_ptr", ctypes.POINTER(ctypes.c_uint32)),
+        ("values_ptr", ctypes.POINTER(Vec3f32)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    def __len__(self):
+        return self.len
+
+    def _index(self, key) -> int:
+        # Keys are sorted by their Rust ordering, which might not match Python's, so we search linearly.
+        for i in range(self.len):
+            if self.keys_ptr[i] == key:
+                return i
+        return -1
+
+    def __getitem__(self, key: int) -> Vec3f32:
+        index = self._index(key)
+        if index < 0:
+            raise KeyError(key)
+        return self.values_ptr[index]
+
+    def __contains__(self, key) -> bool:
+        return self._index(key) >= 0
+
+    def __iter__(self) -> typing.Iterator[ctypes.c_uint32]:
+        return (self.keys_ptr[i] for i in range(self.len))
+
+    def get(self, key, default=None):
+        index = self._index(key)
+        return default if index < 0 else self.values_ptr[index]
+
+    def keys(self) -> typing.List[ctypes.c_uint32]:
+        return [self.keys_ptr[i] for i in range(self.len)]
+
+    def values(self) -> typing.List[Vec3f32]:
+        return [self.values_ptr[i] for i in range(self.len)]
+
+    def items(self) -> typing.List[typing.Tuple[ctypes.c_uint32, Vec3f32]]:
+        return [(self.keys_ptr[i], self.values_ptr[i]) for i in range(self.len)]
+
+
+collections.abc.Mapping.register(MapU32Vec3f32)
+
+
+class IterVec3f32(ctypes.Structure):
+    """ Iterator marshalling helper.
+ An opaque, Rust-owned handle that produces items on demand. Like
+ a Vec it must be freed exactly once, either by the other side or
+ by passing it back to Rust."""
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("ptr", ctypes.c_void_p),
+    ]
+
+    def _move(self) -> "IterVec3f32":
+        """Hands the handle over to Rust, which then owns it, leaving this object empty."""
+        rval = IterVec3f32(self.ptr)
+        self.ptr = None
+        return rval
+
+    def __iter__(self):
+        return self
+
+    def __next__(self) -> Vec3f32:
+        if not self.ptr:
+            raise StopIteration
+        rval = Vec3f32()
+        if c_lib.interoptopus_iter_next_12283536038193100041(ctypes.byref(self), ctypes.byref(rval)) == 0:
+            self.close()
+            raise StopIteration
+        return rval
+
+    def close(self):
+        """Frees the iterator early, which otherwise happens once it is exhausted."""
+        if self.ptr:
+            c_lib.interoptopus_iter_destroy_14403488365818035019(self)
+            self.ptr = None
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, *args):
+        self.close()
+
+
+class Tuple3_Vec3f32_U8_I64(ctypes.Structure):
+    """ A tuple lowered to a struct, fields are named after their position."""
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("x0", Vec3f32),
+        ("x1", ctypes.c_uint8),
+        ("x2", ctypes.c_int64),
+    ]
+
+    def __init__(self, x0: Vec3f32 = None, x1: int = None, x2: int = None):
+        if x0 is not None:
+            self.x0 = x0
+        if x1 is not None:
+            self.x1 = x1
+        if x2 is not None:
+            self.x2 = x2
+
+    @property
+    def x0(self) -> Vec3f32:
+        return ctypes.Structure.__get__(self, "x0")
+
+    @x0.setter
+    def x0(self, value: Vec3f32):
+        return ctypes.Structure.__set__(self, "x0", value)
+
+    @property
+    def x1(self) -> int:
+        return ctypes.Structure.__get__(self, "x1")
+
+    @x1.setter
+    def x1(self, value: int):
+        return ctypes.Structure.__set__(self, "x1", value)
+
+    @property
+    def x2(self) -> int:
+        return ctypes.Structure.__get__(self, "x2")
+
+    @x2.setter
+    def x2(self, value: int):
+        return ctypes.Structure.__set__(self, "x2", value)
+
+    def to_tuple(self) -> typing.Tuple[Vec3f32, int, int]:
+        return (self.x0, self.x1, self.x2,)
+
+    def __len__(self) -> int:
+        return 3
+
+    def __iter__(self):
+        return iter(self.to_tuple())
+
+    def __getitem__(self, index: int):
+        return self.to_tuple()[index]
+
+    def __eq__(self, other) -> bool:
+        return isinstance(other, (Tuple3_Vec3f32_U8_I64, tuple)) and self.to_tuple() == tuple(other)
+
+    def __repr__(self) -> str:
+        return repr(self.to_tuple())
+
+
+class CallbackTable(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("my_callback", MyCallback),
+        ("my_callback_namespaced", MyCallbackNamespaced),
+        ("my_callback_void", MyCallbackVoid),
+        ("my_callback_contextual", MyCallbackContextual),
+        ("sum_delegate_1", SumDelegate1),
+        ("sum_delegate_2", SumDelegate2),
+        ("sum_delegate_return", SumDelegateReturn),
+        ("sum_delegate_return_2", SumDelegateReturn2),
+    ]
+
+    def __init__(self, my_callback = None, my_callback_namespaced = None, my_callback_void = None, my_callback_contextual = None, sum_delegate_1 = None, sum_delegate_2 = None, sum_delegate_return = None, sum_delegate_return_2 = None):
+        if my_callback is not None:
+            self.my_callback = my_callback
+        if my_callback_namespaced is not None:
+            self.my_callback_namespaced = my_callback_namespaced
+        if my_callback_void is not None:
+            self.my_callback_void = my_callback_void
+        if my_callback_contextual is not None:
+            self.my_callback_contextual = my_callback_contextual
+        if sum_delegate_1 is not None:
+            self.sum_delegate_1 = sum_delegate_1
+        if sum_delegate_2 is not None:
+            self.sum_delegate_2 = sum_delegate_2
+        if sum_delegate_return is not None:
+            self.sum_delegate_return = sum_delegate_return
+        if sum_delegate_return_2 is not None:
+            self.sum_delegate_return_2 = sum_delegate_return_2
+
+    @property
+    def my_callback(self):
+        return ctypes.Structure.__get__(self, "my_callback")
+
+    @my_callback.setter
+    def my_callback(self, value):
+        return ctypes.Structure.__set__(self, "my_callback", value)
+
+    @property
+    def my_callback_namespaced(self):
+        return ctypes.Structure.__get__(self, "my_callback_namespaced")
+
+    @my_callback_namespaced.setter
+    def my_callback_namespaced(self, value):
+        return ctypes.Structure.__set__(self, "my_callback_namespaced", value)
+
+    @property
+    def my_callback_void(self):
+        return ctypes.Structure.__get__(self, "my_callback_void")
+
+    @my_callback_void.setter
+    def my_callback_void(self, value):
+        return ctypes.Structure.__set__(self, "my_callback_void", value)
+
+    @property
+    def my_callback_contextual(self):
+        return ctypes.Structure.__get__(self, "my_callback_contextual")
+
+    @my_callback_contextual.setter
+    def my_callback_contextual(self, value):
+        return ctypes.Structure.__set__(self, "my_callback_contextual", value)
+
+    @property
+    def sum_delegate_1(self):
+        return ctypes.Structure.__get__(self, "sum_delegate_1")
+
+    @sum_delegate_1.setter
+    def sum_delegate_1(self, value):
+        return ctypes.Structure.__set__(self, "sum_delegate_1", value)
+
+    @property
+    def sum_delegate_2(self):
+        return ctypes.Structure.__get__(self, "sum_delegate_2")
+
+    @sum_delegate_2.setter
+    def sum_delegate_2(self, value):
+        return ctypes.Structure.__set__(self, "sum_delegate_2", value)
+
+    @property
+    def sum_delegate_return(self):
+        return ctypes.Structure.__get__(self, "sum_delegate_return")
+
+    @sum_delegate_return.setter
+    def sum_delegate_return(self, value):
+        return ctypes.Structure.__set__(self, "sum_delegate_return", value)
+
+    @property
+    def sum_delegate_return_2(self):
+        return ctypes.Structure.__get__(self, "sum_delegate_return_2")
+
+    @sum_delegate_return_2.setter
+    def sum_delegate_return_2(self, value):
+        return ctypes.Structure.__set__(self, "sum_delegate_return_2", value)
+
+
+class CharArray(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("str", FixedString),
+        ("str_2", FixedString),
+    ]
+
+    def __init__(self, str: FixedString = None, str_2: FixedString = None):
+        if str is not None:
+            self.str = str
+        if str_2 is not None:
+            self.str_2 = str_2
+
+    @property
+    def str(self) -> FixedString:
+        return ctypes.Structure.__get__(self, "str")
+
+    @str.setter
+    def str(self, value: FixedString):
+        return ctypes.Structure.__set__(self, "str", value)
+
+    @property
+    def str_2(self) -> FixedString:
+        return ctypes.Structure.__get__(self, "str_2")
+
+    @str_2.setter
+    def str_2(self, value: FixedString):
+        return ctypes.Structure.__set__(self, "str_2", value)
+
+
+class Layer2Utf8String(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("layer_1", Layer1Utf8String),
+        ("vec", Vec3f32),
+        ("the_enum", EnumPayload),
+        ("strings", VecUtf8String),
+    ]
+
+    def __init__(self, layer_1: Layer1Utf8String = None, vec: Vec3f32 = None, the_enum: EnumPayload = None, strings: VecUtf8String = None):
+        if layer_1 is not None:
+            self.layer_1 = layer_1
+        if vec is not None:
+            self.vec = vec
+        if the_enum is not None:
+            self.the_enum = the_enum
+        if strings is not None:
+            self.strings = strings
+
+    @property
+    def layer_1(self) -> Layer1Utf8String:
+        return ctypes.Structure.__get__(self, "layer_1")
+
+    @layer_1.setter
+    def layer_1(self, value: Layer1Utf8String):
+        return ctypes.Structure.__set__(self, "layer_1", value)
+
+    @property
+    def vec(self) -> Vec3f32:
+        return ctypes.Structure.__get__(self, "vec")
+
+    @vec.setter
+    def vec(self, value: Vec3f32):
+        return ctypes.Structure.__set__(self, "vec", value)
+
+    @property
+    def the_enum(self) -> EnumPayload:
+        return ctypes.Structure.__get__(self, "the_enum")
+
+    @the_enum.setter
+    def the_enum(self, value: EnumPayload):
+        return ctypes.Structure.__set__(self, "the_enum", value)
+
+    @property
+    def strings(self) -> VecUtf8String:
+        return ctypes.Structure.__get__(self, "strings")
+
+    @strings.setter
+    def strings(self, value: VecUtf8String):
+        return ctypes.Structure.__set__(self, "strings", value)
+
+
+class LogSinkVTable(ctypes.Structure):
+    """ A sink implemented in a foreign language receiving log messages."""
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("log", LogSinkLog),
+        ("count", LogSinkCount),
+        ("user_data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, log = None, count = None, user_data: ctypes.c_void_p = None):
+        if log is not None:
+            self.log = log
+        if count is not None:
+            self.count = count
+        if user_data is not None:
+            self.user_data = user_data
+
+    @property
+    def log(self):
+        """ Called for each logged value."""
+        return ctypes.Structure.__get__(self, "log")
+
+    @log.setter
+    def log(self, value):
+        """ Called for each logged value."""
+        return ctypes.Structure.__set__(self, "log", value)
+
+    @property
+    def count(self):
+        """ Returns how many values the sink has seen."""
+        return ctypes.Structure.__get__(self, "count")
+
+    @count.setter
+    def count(self, value):
+        """ Returns how many values the sink has seen."""
+        return ctypes.Structure.__set__(self, "count", value)
+
+    @property
+    def user_data(self) -> ctypes.c_void_p:
+        return ctypes.Structure.__get__(self, "user_data")
+
+    @user_data.setter
+    def user_data(self, value: ctypes.c_void_p):
+        return ctypes.Structure.__set__(self, "user_data", value)
+
+
+class NestedArray(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("field_enum", ctypes.c_int),
+        ("field_vec", Vec3f32),
+        ("field_bool", ctypes.c_bool),
+        ("field_int", ctypes.c_int32),
+        ("field_array", ctypes.c_uint16 * 5),
+        ("field_array_2", ctypes.c_uint16 * 5),
+        ("field_struct", Array),
+    ]
+
+    def __init__(self, field_enum: int = None, field_vec: Vec3f32 = None, field_bool: bool = None, field_int: int = None, field_array = None, field_array_2 = None, field_struct: Array = None):
+        if field_enum is not None:
+            self.field_enum = field_enum
+        if field_vec is not None:
+            self.field_vec = field_vec
+        if field_bool is not None:
+            self.field_bool = field_bool
+        if field_int is not None:
+            self.field_int = field_int
+        if field_array is not None:
+            self.field_array = field_array
+        if field_array_2 is not None:
+            self.field_array_2 = field_array_2
+        if field_struct is not None:
+            self.field_struct = field_struct
+
+    @property
+    def field_enum(self) -> int:
+        return ctypes.Structure.__get__(self, "field_enum")
+
+    @field_enum.setter
+    def field_enum(self, value: int):
+        return ctypes.Structure.__set__(self, "field_enum", value)
+
+    @property
+    def field_vec(self) -> Vec3f32:
+        return ctypes.Structure.__get__(self, "field_vec")
+
+    @field_vec.setter
+    def field_vec(self, value: Vec3f32):
+        return ctypes.Structure.__set__(self, "field_vec", value)
+
+    @property
+    def field_bool(self) -> bool:
+        return ctypes.Structure.__get__(self, "field_bool")
+
+    @field_bool.setter
+    def field_bool(self, value: bool):
+        return ctypes.Structure.__set__(self, "field_bool", value)
+
+    @property
+    def field_int(self) -> int:
+        return ctypes.Structure.__get__(self, "field_int")
+
+    @field_int.setter
+    def field_int(self, value: int):
+        return ctypes.Structure.__set__(self, "field_int", value)
+
+    @property
+    def field_array(self):
+        return ctypes.Structure.__get__(self, "field_array")
+
+    @field_array.setter
+    def field_array(self, value):
+        return ctypes.Structure.__set__(self, "field_array", value)
+
+    @property
+    def field_array_2(self):
+        return ctypes.Structure.__get__(self, "field_array_2")
+
+    @field_array_2.setter
+    def field_array_2(self, value):
+        return ctypes.Structure.__set__(self, "field_array_2", value)
+
+    @property
+    def field_struct(self) -> Array:
+        return ctypes.Structure.__get__(self, "field_struct")
+
+    @field_struct.setter
+    def field_struct(self, value: Array):
+        return ctypes.Structure.__set__(self, "field_struct", value)
+
+
+class Tuples(ctypes.Structure):
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("pair", Tuple2_U32_F32),
+        ("triple", Tuple3_Vec3f32_U8_I64),
+    ]
+
+    def __init__(self, pair: typing.Tuple[int, float] = None, triple: typing.Tuple[Vec3f32, int, int] = None):
+        if pair is not None:
+            self.pair = pair
+        if triple is not None:
+            self.triple = triple
+
+    @property
+    def pair(self) -> typing.Tuple[int, float]:
+        return ctypes.Structure.__get__(self, "pair").to_tuple()
+
+    @pair.setter
+    def pair(self, value: typing.Tuple[int, float]):
+        return ctypes.Structure.__set__(self, "pair", value)
+
+    @property
+    def triple(self) -> typing.Tuple[Vec3f32, int, int]:
+        return ctypes.Structure.__get__(self, "triple").to_tuple()
+
+    @triple.setter
+    def triple(self, value: typing.Tuple[Vec3f32, int, int]):
+        return ctypes.Structure.__set__(self, "triple", value)
+
+
+class OptionEnumPayload(ctypes.Structure):
+    """Option that contains Some(value) or None."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", EnumPayload),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: EnumPayload
+
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
+
+    @staticmethod
+    def Some(value: EnumPayload) -> OptionEnumPayload:
+        # Element if Some().
+        rval = OptionEnumPayload()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
+
+    def is_some(self) -> bool:
+        return self._variant == 0
+
+    def as_some(self) -> EnumPayload:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
+
+    @staticmethod
+    def None_() -> OptionEnumPayload:
+        rval = OptionEnumPayload()
+        rval._variant = 1
+        return rval
+
+    def is_none(self) -> bool:
+        return self._variant == 1
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionEnumPayload.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionEnumPayload.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, OptionEnumPayload) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> EnumPayload:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class OptionResultOptionUtf8StringError(ctypes.Structure):
+    """Option that contains Some(value) or None."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", ResultOptionUtf8StringError),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: ResultOptionUtf8StringError
+
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
+
+    @staticmethod
+    def Some(value: ResultOptionUtf8StringError) -> OptionResultOptionUtf8StringError:
+        # Element if Some().
+        rval = OptionResultOptionUtf8StringError()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
+
+    def is_some(self) -> bool:
+        return self._variant == 0
+
+    def as_some(self) -> ResultOptionUtf8StringError:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
+
+    @staticmethod
+    def None_() -> OptionResultOptionUtf8StringError:
+        rval = OptionResultOptionUtf8StringError()
+        rval._variant = 1
+        return rval
+
+    def is_none(self) -> bool:
+        return self._variant == 1
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionResultOptionUtf8StringError.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionResultOptionUtf8StringError.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, OptionResultOptionUtf8StringError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> ResultOptionUtf8StringError:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class CallbackHugeVecSlice(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(Vec3f32, SliceVec3f32, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(Vec3f32, SliceVec3f32, ctypes.c_void_p)(f)
+        super().__init__(f, None)
+
+
+class VecEnumPayload(ctypes.Structure):
+    """ Vec marshalling helper.
+ A highly dangerous 'use once type' that has ownership semantics!
+ Once passed over an FFI boundary 'the other side' is meant to own
+ (and free) it. Rust handles that fine, but if in C# you put this
+ in a struct and then call Rust multiple times with that struct 
+ you'll free the same pointer multiple times, and get UB!"""
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("ptr", ctypes.POINTER(EnumPayload)),
+        ("len", ctypes.c_uint64),
+        ("capacity", ctypes.c_uint64),
+    ]
+
+    def __init__(self, ptr: ctypes.POINTER(EnumPayload) = None, len: int = None, capacity: int = None):
+        if ptr is not None:
+            self.ptr = ptr
+        if len is not None:
+            self.len = len
+        if capacity is not None:
+            self.capacity = capacity
+
+    @property
+    def ptr(self) -> ctypes.POINTER(EnumPayload):
+        return ctypes.Structure.__get__(self, "ptr")
+
+    @ptr.setter
+    def ptr(self, value: ctypes.POINTER(EnumPayload)):
+        return ctypes.Structure.__set__(self, "ptr", value)
+
+    @property
+    def len(self) -> int:
+        return ctypes.Structure.__get__(self, "len")
+
+    @len.setter
+    def len(self, value: int):
+        return ctypes.Structure.__set__(self, "len", value)
+
+    @property
+    def capacity(self) -> int:
+        return ctypes.Structure.__get__(self, "capacity")
+
+    @capacity.setter
+    def capacity(self, value: int):
+        return ctypes.Structure.__set__(self, "capacity", value)
+
+
+class Layer3(ctypes.Structure):
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("A", Layer1Utf8String),
+            ("B", Layer2Utf8String),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class AVariant:
+        value: Layer1Utf8String
+
+    @dataclasses.dataclass(frozen=True)
+    class BVariant:
+        value: Layer2Utf8String
+
+    @staticmethod
+    def A(value: Layer1Utf8String) -> Layer3:
+        rval = Layer3()
+        rval._payload.A = value
+        rval._variant = 0
+        return rval
+
+    def is_a(self) -> bool:
+        return self._variant == 0
+
+    def as_a(self) -> Layer1Utf8String:
+        """Returns the `A` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `A`, got {self!r}")
+        return self._payload.A
+
+    @staticmethod
+    def B(value: Layer2Utf8String) -> Layer3:
+        rval = Layer3()
+        rval._payload.B = value
+        rval._variant = 1
+        return rval
+
+    def is_b(self) -> bool:
+        return self._variant == 1
+
+    def as_b(self) -> Layer2Utf8String:
+        """Returns the `B` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `B`, got {self!r}")
+        return self._payload.B
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return Layer3.AVariant(self._payload.A)
+        if self._variant == 1: return Layer3.BVariant(self._payload.B)
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, Layer3) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+
+class SliceMutCharArray(ctypes.Structure):
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("data", ctypes.POINTER(CharArray)),
+        ("len", ctypes.c_uint64),
+    ]
+
+    def __len__(self):
+        return self.len
+
+    def __getitem__(self, i) -> CharArray:
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        return self.data[index]
+
+    def __setitem__(self, i, v: CharArray):
+        if i < 0:
+            index = self.len+i
+        else:
+            index = i
+
+        if index >= self.len:
+            raise IndexError("Index out of range")
+
+        self.data[index] = v
+
+    def copied(self) -> SliceMutCharArray:
+        """Returns a shallow, owned copy of the underlying slice.
+
+        The returned object owns the immediate data, but not the targets of any contained
+        pointers. In other words, if your struct contains any pointers the returned object
+        may only be used as long as these pointers are valid. If the struct did not contain
+        any pointers the returned object is valid indefinitely."""
+        array = (CharArray * len(self))()
+        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(CharArray))
+        rval = SliceMutCharArray(data=ctypes.cast(array, ctypes.POINTER(CharArray)), len=len(self))
+        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+        return rval
+
+    def __iter__(self) -> typing.Iterable[CharArray]:
+        return _Iter(self)
+
+    def iter(self) -> typing.Iterable[CharArray]:
+        """Convenience method returning a value iterator."""
+        return iter(self)
+
+    def first(self) -> CharArray:
+        """Returns the first element of this slice."""
+        return self[0]
+
+    def last(self) -> CharArray:
+        """Returns the last element of this slice."""
+        return self[len(self)-1]
+
+
+class OptionOptionResultOptionUtf8StringError(ctypes.Structure):
+    """Option that contains Some(value) or None."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Some", OptionResultOptionUtf8StringError),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class SomeVariant:
+        value: OptionResultOptionUtf8StringError
+
+    @dataclasses.dataclass(frozen=True)
+    class NoneVariant:
+        pass
+
+    @staticmethod
+    def Some(value: OptionResultOptionUtf8StringError) -> OptionOptionResultOptionUtf8StringError:
+        # Element if Some().
+        rval = OptionOptionResultOptionUtf8StringError()
+        rval._payload.Some = value
+        rval._variant = 0
+        return rval
+
+    def is_some(self) -> bool:
+        return self._variant == 0
+
+    def as_some(self) -> OptionResultOptionUtf8StringError:
+        """Returns the `Some` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Some`, got {self!r}")
+        return self._payload.Some
+
+    @staticmethod
+    def None_() -> OptionOptionResultOptionUtf8StringError:
+        rval = OptionOptionResultOptionUtf8StringError()
+        rval._variant = 1
+        return rval
+
+    def is_none(self) -> bool:
+        return self._variant == 1
+
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return OptionOptionResultOptionUtf8StringError.SomeVariant(self._payload.Some)
+        if self._variant == 1: return OptionOptionResultOptionUtf8StringError.NoneVariant()
+        raise Exception(f"Invalid variant {self._variant}")
+
+    def __eq__(self, other):
+        return isinstance(other, OptionOptionResultOptionUtf8StringError) and self.variant() == other.variant()
+
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> OptionResultOptionUtf8StringError:
+        """Returns the `Some` value, raises if this is `None`."""
+        if self._variant != 0: raise Exception("Called `unwrap()` on `None`")
+        return self._payload.Some
+
+
+class ResultNestedArrayError(ctypes.Structure):
+    """Result that contains value or an error."""
+
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", NestedArray),
+            ("Err", ctypes.c_int),
+        ]
+
+    # These fields represent the underlying C data layout
+    _fields_ = [
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
+    ]
+
+    # Variants as dataclasses, e.g., for use with `match`.
+
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: NestedArray
+
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
+
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
+
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
+
+    @staticmethod
+    def Ok(value: NestedArray) -> ResultNestedArrayError:
+        # Element if err is `Ok`.
+        rval = ResultNestedArrayError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
+
+    def is_ok(self) -> bool:
+        return self._variant == 0
+
+    def as_ok(self) -> NestedArray:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
+
+    @staticmethod
+    def Err(value: int) -> ResultNestedArrayError:
+        # Error value.
+        rval = ResultNestedArrayError()
+        rval._payload.Err = value
+        rval._variant = 1
+        return rval
 
-    @field_array.setter
-    def field_array(self, value):
-        return ctypes.Structure.__set__(self, "field_array", value)
+    def is_err(self) -> bool:
+        return self._variant == 1
 
-    @property
-    def field_array_2(self):
-        return ctypes.Structure.__get__(self, "field_array_2")
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
 
-    @field_array_2.setter
-    def field_array_2(self, value):
-        return ctypes.Structure.__set__(self, "field_array_2", value)
+    @staticmethod
+    def Panic() -> ResultNestedArrayError:
+        rval = ResultNestedArrayError()
+        rval._variant = 2
+        return rval
 
-    @property
-    def field_struct(self) -> Array:
-        return ctypes.Structure.__get__(self, "field_struct")
+    def is_panic(self) -> bool:
+        return self._variant == 2
 
-    @field_struct.setter
-    def field_struct(self, value: Array):
-        return ctypes.Structure.__set__(self, "field_struct", value)
+    @staticmethod
+    def Null() -> ResultNestedArrayError:
+        rval = ResultNestedArrayError()
+        rval._variant = 3
+        return rval
 
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-class OptionEnumPayload:
-    """Option that contains Some(value) or None."""
-    # Element if Some().
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    None = 1
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultNestedArrayError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultNestedArrayError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultNestedArrayError.PanicVariant()
+        if self._variant == 3: return ResultNestedArrayError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
+    def __eq__(self, other):
+        return isinstance(other, ResultNestedArrayError) and self.variant() == other.variant()
 
-class OptionResultOptionUtf8StringError:
-    """Option that contains Some(value) or None."""
-    # Element if Some().
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    None = 1
+    def __repr__(self):
+        return repr(self.variant())
+
+    def unwrap(self) -> NestedArray:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
 
 
-class Layer3:
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
+class ResultOptionEnumPayloadError(ctypes.Structure):
+    """Result that contains value or an error."""
 
+    class _Payload(ctypes.Union):
+        _fields_ = [
+            ("Ok", OptionEnumPayload),
+            ("Err", ctypes.c_int),
+        ]
 
-class SliceMutCharArray(ctypes.Structure):
     # These fields represent the underlying C data layout
     _fields_ = [
-        ("data", ctypes.POINTER(CharArray)),
-        ("len", ctypes.c_uint64),
+        ("_variant", ctypes.c_uint32),
+        ("_payload", _Payload),
     ]
 
-    def __len__(self):
-        return self.len
+    # Variants as dataclasses, e.g., for use with `match`.
 
-    def __getitem__(self, i) -> CharArray:
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    @dataclasses.dataclass(frozen=True)
+    class OkVariant:
+        value: OptionEnumPayload
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    @dataclasses.dataclass(frozen=True)
+    class ErrVariant:
+        value: int
 
-        return self.data[index]
+    @dataclasses.dataclass(frozen=True)
+    class PanicVariant:
+        pass
 
-    def __setitem__(self, i, v: CharArray):
-        if i < 0:
-            index = self.len+i
-        else:
-            index = i
+    @dataclasses.dataclass(frozen=True)
+    class NullVariant:
+        pass
 
-        if index >= self.len:
-            raise IndexError("Index out of range")
+    @staticmethod
+    def Ok(value: OptionEnumPayload) -> ResultOptionEnumPayloadError:
+        # Element if err is `Ok`.
+        rval = ResultOptionEnumPayloadError()
+        rval._payload.Ok = value
+        rval._variant = 0
+        return rval
 
-        self.data[index] = v
+    def is_ok(self) -> bool:
+        return self._variant == 0
 
-    def copied(self) -> SliceMutCharArray:
-        """Returns a shallow, owned copy of the underlying slice.
+    def as_ok(self) -> OptionEnumPayload:
+        """Returns the `Ok` value, raises if this is another variant."""
+        if self._variant != 0: raise Exception(f"Expected `Ok`, got {self!r}")
+        return self._payload.Ok
 
-        The returned object owns the immediate data, but not the targets of any contained
-        pointers. In other words, if your struct contains any pointers the returned object
-        may only be used as long as these pointers are valid. If the struct did not contain
-        any pointers the returned object is valid indefinitely."""
-        array = (CharArray * len(self))()
-        ctypes.memmove(array, self.data, len(self) * ctypes.sizeof(CharArray))
-        rval = SliceMutCharArray(data=ctypes.cast(array, ctypes.POINTER(CharArray)), len=len(self))
-        rval.owned = array  # Store array in returned slice to prevent memory deallocation
+    @staticmethod
+    def Err(value: int) -> ResultOptionEnumPayloadError:
+        # Error value.
+        rval = ResultOptionEnumPayloadError()
+        rval._payload.Err = value
+        rval._variant = 1
         return rval
 
-    def __iter__(self) -> typing.Iterable[CharArray]:
-        return _Iter(self)
+    def is_err(self) -> bool:
+        return self._variant == 1
 
-    def iter(self) -> typing.Iterable[CharArray]:
-        """Convenience method returning a value iterator."""
-        return iter(self)
+    def as_err(self) -> int:
+        """Returns the `Err` value, raises if this is another variant."""
+        if self._variant != 1: raise Exception(f"Expected `Err`, got {self!r}")
+        return self._payload.Err
 
-    def first(self) -> CharArray:
-        """Returns the first element of this slice."""
-        return self[0]
+    @staticmethod
+    def Panic() -> ResultOptionEnumPayloadError:
+        rval = ResultOptionEnumPayloadError()
+        rval._variant = 2
+        return rval
 
-    def last(self) -> CharArray:
-        """Returns the last element of this slice."""
-        return self[len(self)-1]
+    def is_panic(self) -> bool:
+        return self._variant == 2
+
+    @staticmethod
+    def Null() -> ResultOptionEnumPayloadError:
+        rval = ResultOptionEnumPayloadError()
+        rval._variant = 3
+        return rval
 
+    def is_null(self) -> bool:
+        return self._variant == 3
 
-class OptionOptionResultOptionUtf8StringError:
-    """Option that contains Some(value) or None."""
-    # Element if Some().
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    None = 1
+    def variant(self):
+        """Returns the active variant as one of the `...Variant` dataclasses."""
+        if self._variant == 0: return ResultOptionEnumPayloadError.OkVariant(self._payload.Ok)
+        if self._variant == 1: return ResultOptionEnumPayloadError.ErrVariant(self._payload.Err)
+        if self._variant == 2: return ResultOptionEnumPayloadError.PanicVariant()
+        if self._variant == 3: return ResultOptionEnumPayloadError.NullVariant()
+        raise Exception(f"Invalid variant {self._variant}")
 
+    def __eq__(self, other):
+        return isinstance(other, ResultOptionEnumPayloadError) and self.variant() == other.variant()
 
-class ResultNestedArrayError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+    def __repr__(self):
+        return repr(self.variant())
 
+    def unwrap(self) -> OptionEnumPayload:
+        """Returns the `Ok` value, raises an exception with details about the last error in Rust otherwise."""
+        if self._variant == 0: return self._payload.Ok
+        if self._variant == 1: raise ErrorException.from_error(self._payload.Err)
+        raise Exception(f"{self!r} {_last_error()}".strip())
 
-class ResultOptionEnumPayloadError:
-    """Result that contains value or an error."""
-    # Element if err is `Ok`.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    # Error value.
-# TODO - OMITTED DATA VARIANT - BINDINGS ARE BROKEN
-    Panic = 2
-    Null = 3
+
+class CallbackCharArray2(ctypes.Structure):
+    """Callback and its data, Python callbacks ignore the data."""
+
+    _fields_ = [
+        ("callback", ctypes.CFUNCTYPE(None, CharArray, ctypes.c_void_p)),
+        ("data", ctypes.c_void_p),
+    ]
+
+    def __init__(self, f):
+        if not hasattr(f, "__ctypes_from_outparam__"):
+            f = ctypes.CFUNCTYPE(None, CharArray, ctypes.c_void_p)(f)
+        super().__init__(f, None)
 
 
 
@@ -3129,20 +9066,27 @@ class ResultOptionEnumPayloadError:
 class callbacks:
     """Helpers to define callbacks."""
     fn_u8_rval_u8 = ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint8)
+    fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32 = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_uint32)
+    fn_u64_u64_rval_MutPtrVoid = ctypes.CFUNCTYPE(ctypes.c_void_p, ctypes.c_uint64, ctypes.c_uint64)
     fn_CharArray_rval_void = ctypes.CFUNCTYPE(None, CharArray)
+    fn_MutPtrVoid_u64_u64_rval_void = ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_uint64, ctypes.c_uint64)
     fn_CharArray_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, CharArray, ctypes.c_void_p)
     fn_SliceU8_ConstPtrVoid_rval_u8 = ctypes.CFUNCTYPE(ctypes.c_uint8, SliceU8, ctypes.c_void_p)
     fn_SliceVec3f32_ConstPtrVoid_rval_Vec3f32 = ctypes.CFUNCTYPE(Vec3f32, SliceVec3f32, ctypes.c_void_p)
     fn_SliceMutU8_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, SliceMutU8, ctypes.c_void_p)
     fn_u8_ConstPtrVoid_rval_u8 = ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint8, ctypes.c_void_p)
+    fn_ConstPtrVoid_rval_u32 = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_void_p)
+    fn_u32_u32_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)
     fn_u32_ConstPtrVoid_rval_u32 = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)
     fn_ConstPtrVoid_u32_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_uint32, ctypes.c_void_p)
     fn_u32_ConstPtrVoid_rval_u32 = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)
     fn_ConstPtrVoid_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, ctypes.c_void_p, ctypes.c_void_p)
+    fn_u32_ConstPtrVoid_rval_ResultU32Error = ctypes.CFUNCTYPE(ResultU32Error, ctypes.c_uint32, ctypes.c_void_p)
+    fn_u32_ConstPtrVoid_rval_u32 = ctypes.CFUNCTYPE(ctypes.c_uint32, ctypes.c_uint32, ctypes.c_void_p)
     fn_UseString_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, UseString, ctypes.c_void_p)
-    fn_ConstPtrI32_MutPtrI32_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, ctypes.POINTER(ctypes.c_int32), ctypes.POINTER(ctypes.c_int32), ctypes.c_void_p)
+    fn_f32_ConstPtrVoid_rval_u8 = ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_float, ctypes.c_void_p)
+    fn_u32_ConstPtrVoid_rval_u8 = ctypes.CFUNCTYPE(ctypes.c_uint8, ctypes.c_uint32, ctypes.c_void_p)
     fn_Utf8String_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, Utf8String, ctypes.c_void_p)
-    fn_Utf8String_ConstPtrVoid_rval_Utf8String = ctypes.CFUNCTYPE(Utf8String, Utf8String, ctypes.c_void_p)
     fn_ConstPtrVoid_rval_void = ctypes.CFUNCTYPE(None, ctypes.c_void_p)
     fn_i32_i32_ConstPtrVoid_rval_i32 = ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)
     fn_i32_i32_ConstPtrVoid_rval_ResultError = ctypes.CFUNCTYPE(ResultError, ctypes.c_int32, ctypes.c_int32, ctypes.c_void_p)
@@ -3163,13 +9107,13 @@ class ServiceAsyncBasic:
     @staticmethod
     def new() -> ServiceAsyncBasic:
         """"""
-        ctx = c_lib.service_async_basic_new().t
+        ctx = c_lib.service_async_basic_new().unwrap()
         self = ServiceAsyncBasic(ServiceAsyncBasic.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_async_basic_destroy(self._ctx, )
-    def call(self, _async_callback):
+    def call(self, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_basic_call(self._ctx, _async_callback)
 
@@ -3189,13 +9133,13 @@ class ServiceAsyncSleep:
     @staticmethod
     def new() -> ServiceAsyncSleep:
         """"""
-        ctx = c_lib.service_async_sleep_new().t
+        ctx = c_lib.service_async_sleep_new().unwrap()
         self = ServiceAsyncSleep(ServiceAsyncSleep.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_async_sleep_destroy(self._ctx, )
-    def return_after_ms(self, x: int, ms: int, _async_callback):
+    def return_after_ms(self, x: int, ms: int, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_sleep_return_after_ms(self._ctx, x, ms, _async_callback)
 
@@ -3215,21 +9159,21 @@ class ServiceAsyncVecString:
     @staticmethod
     def new() -> ServiceAsyncVecString:
         """"""
-        ctx = c_lib.service_async_vec_string_new().t
+        ctx = c_lib.service_async_vec_string_new().unwrap()
         self = ServiceAsyncVecString(ServiceAsyncVecString.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_async_vec_string_destroy(self._ctx, )
-    def handle_string(self, s, _async_callback):
+    def handle_string(self, s, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_vec_string_handle_string(self._ctx, s, _async_callback)
 
-    def handle_vec_string(self, s, _async_callback):
+    def handle_vec_string(self, s: VecUtf8String, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_vec_string_handle_vec_string(self._ctx, s, _async_callback)
 
-    def handle_nested_string(self, s, _async_callback):
+    def handle_nested_string(self, s, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_vec_string_handle_nested_string(self._ctx, s, _async_callback)
 
@@ -3249,17 +9193,17 @@ class ServiceAsyncResult:
     @staticmethod
     def new() -> ServiceAsyncResult:
         """"""
-        ctx = c_lib.service_async_result_new().t
+        ctx = c_lib.service_async_result_new().unwrap()
         self = ServiceAsyncResult(ServiceAsyncResult.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_async_result_destroy(self._ctx, )
-    def success(self, _async_callback):
+    def success(self, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_result_success(self._ctx, _async_callback)
 
-    def fail(self, _async_callback):
+    def fail(self, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_result_fail(self._ctx, _async_callback)
 
@@ -3279,13 +9223,13 @@ class ServiceAsyncStructs:
     @staticmethod
     def new() -> ServiceAsyncStructs:
         """"""
-        ctx = c_lib.service_async_structs_new().t
+        ctx = c_lib.service_async_structs_new().unwrap()
         self = ServiceAsyncStructs(ServiceAsyncStructs.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_async_structs_destroy(self._ctx, )
-    def process_struct(self, x: NestedArray, _async_callback):
+    def process_struct(self, x: NestedArray, _async_callback) -> ResultError:
         """"""
         return c_lib.service_async_structs_process_struct(self._ctx, x, _async_callback)
 
@@ -3305,7 +9249,7 @@ class ServiceBasic:
     @staticmethod
     def new() -> ServiceBasic:
         """"""
-        ctx = c_lib.service_basic_new().t
+        ctx = c_lib.service_basic_new().unwrap()
         self = ServiceBasic(ServiceBasic.__api_lock, ctx)
         return self
 
@@ -3325,9 +9269,9 @@ class ServiceMain:
         return self._ctx
 
     @staticmethod
-    def new() -> ServiceMain:
+    def new(x: int) -> ServiceMain:
         """"""
-        ctx = c_lib.service_main_new().t
+        ctx = c_lib.service_main_new(x).unwrap()
         self = ServiceMain(ServiceMain.__api_lock, ctx)
         return self
 
@@ -3347,9 +9291,9 @@ class ServiceDependent:
         return self._ctx
 
     @staticmethod
-    def from_main() -> ServiceDependent:
+    def from_main(main: ctypes.c_void_p) -> ServiceDependent:
         """"""
-        ctx = c_lib.service_dependent_from_main().t
+        ctx = c_lib.service_dependent_from_main(main).unwrap()
         self = ServiceDependent(ServiceDependent.__api_lock, ctx)
         return self
 
@@ -3375,35 +9319,43 @@ class ServiceResult:
     @staticmethod
     def new() -> ServiceResult:
         """"""
-        ctx = c_lib.service_result_new().t
+        ctx = c_lib.service_result_new().unwrap()
         self = ServiceResult(ServiceResult.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_result_destroy(self._ctx, )
-    def test(self, ):
+    def test(self, ) -> ResultError:
         """"""
         return c_lib.service_result_test(self._ctx, )
 
-    def result_u32(self, ):
+    def result_u32(self, ) -> ResultU32Error:
         """"""
         return c_lib.service_result_result_u32(self._ctx, )
 
-    def result_string(self, ):
+    def result_string(self, ) -> ResultUtf8StringError:
         """"""
         return c_lib.service_result_result_string(self._ctx, )
 
-    def result_option_enum(self, ):
+    def result_option_enum(self, ) -> ResultOptionEnumPayloadError:
         """"""
         return c_lib.service_result_result_option_enum(self._ctx, )
 
-    def result_slice(self, slice: SliceU32 | ctypes.Array[ctypes.c_uint32], i: int):
+    def result_slice(self, slice: SliceU32 | collections.abc.Buffer, i: int) -> ResultU32Error:
         """"""
-        if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint32:
-            slice = SliceU32(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint32)), len=len(slice))
+        if not isinstance(slice, SliceU32):
+            slice = SliceU32.from_buffer(slice)
 
         return c_lib.service_result_result_slice(self._ctx, slice, i)
 
+    def result_std(self, x: int) -> ResultU32Error:
+        """"""
+        return c_lib.service_result_result_std(self._ctx, x)
+
+    def result_std_mapped(self, x: int) -> ResultU32Error:
+        """"""
+        return c_lib.service_result_result_std_mapped(self._ctx, x)
+
 
 
 class ServiceOnPanic:
@@ -3421,13 +9373,13 @@ class ServiceOnPanic:
     @staticmethod
     def new() -> ServiceOnPanic:
         """"""
-        ctx = c_lib.service_on_panic_new().t
+        ctx = c_lib.service_on_panic_new().unwrap()
         self = ServiceOnPanic(ServiceOnPanic.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_on_panic_destroy(self._ctx, )
-    def return_result(self, anon1: int):
+    def return_result(self, anon1: int) -> ResultError:
         """ Methods returning a Result<(), _> are the default and do not
  need annotations."""
         return c_lib.service_on_panic_return_result(self._ctx, anon1)
@@ -3459,33 +9411,33 @@ class ServiceCallbacks:
     @staticmethod
     def new() -> ServiceCallbacks:
         """"""
-        ctx = c_lib.service_callbacks_new().t
+        ctx = c_lib.service_callbacks_new().unwrap()
         self = ServiceCallbacks(ServiceCallbacks.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_callbacks_destroy(self._ctx, )
-    def callback_simple(self, callback):
+    def callback_simple(self, callback) -> ResultError:
         """"""
-        if not hasattr(callback, "__ctypes_from_outparam__"):
-            callback = callbacks.fn_u32_ConstPtrVoid_rval_u32(callback)
+        if not isinstance(callback, MyCallback):
+            callback = MyCallback(callback)
 
         return c_lib.service_callbacks_callback_simple(self._ctx, callback)
 
-    def callback_ffi_return(self, callback):
+    def callback_ffi_return(self, callback) -> ResultError:
         """"""
-        if not hasattr(callback, "__ctypes_from_outparam__"):
-            callback = callbacks.fn_i32_i32_ConstPtrVoid_rval_ResultError(callback)
+        if not isinstance(callback, SumDelegateReturn):
+            callback = SumDelegateReturn(callback)
 
         return c_lib.service_callbacks_callback_ffi_return(self._ctx, callback)
 
-    def callback_with_slice(self, callback, input: SliceI32 | ctypes.Array[ctypes.c_int32]):
+    def callback_with_slice(self, callback, input: SliceI32 | collections.abc.Buffer) -> ResultError:
         """"""
-        if not hasattr(callback, "__ctypes_from_outparam__"):
-            callback = callbacks.fn_i32_i32_ConstPtrVoid_rval_ResultError(callback)
+        if not isinstance(callback, SumDelegateReturn):
+            callback = SumDelegateReturn(callback)
 
-        if hasattr(input, "_length_") and getattr(input, "_type_", "") == ctypes.c_int32:
-            input = SliceI32(data=ctypes.cast(input, ctypes.POINTER(ctypes.c_int32)), len=len(input))
+        if not isinstance(input, SliceI32):
+            input = SliceI32.from_buffer(input)
 
         return c_lib.service_callbacks_callback_with_slice(self._ctx, callback, input)
 
@@ -3493,7 +9445,7 @@ class ServiceCallbacks:
         """"""
         return c_lib.service_callbacks_set_delegate_table(self._ctx, table)
 
-    def invoke_delegates(self, ):
+    def invoke_delegates(self, ) -> ResultError:
         """"""
         return c_lib.service_callbacks_invoke_delegates(self._ctx, )
 
@@ -3513,7 +9465,7 @@ class ServiceIgnoringMethods:
     @staticmethod
     def new() -> ServiceIgnoringMethods:
         """"""
-        ctx = c_lib.service_ignoring_methods_new().t
+        ctx = c_lib.service_ignoring_methods_new().unwrap()
         self = ServiceIgnoringMethods(ServiceIgnoringMethods.__api_lock, ctx)
         return self
 
@@ -3534,32 +9486,39 @@ class ServiceMultipleCtors:
         return self._ctx
 
     @staticmethod
-    def new_with() -> ServiceMultipleCtors:
+    def new_with(some_value: int) -> ServiceMultipleCtors:
         """"""
-        ctx = c_lib.service_multiple_ctors_new_with().t
+        ctx = c_lib.service_multiple_ctors_new_with(some_value).unwrap()
         self = ServiceMultipleCtors(ServiceMultipleCtors.__api_lock, ctx)
         return self
 
     @staticmethod
     def new_without() -> ServiceMultipleCtors:
         """"""
-        ctx = c_lib.service_multiple_ctors_new_without().t
+        ctx = c_lib.service_multiple_ctors_new_without().unwrap()
         self = ServiceMultipleCtors(ServiceMultipleCtors.__api_lock, ctx)
         return self
 
     @staticmethod
-    def new_with_string() -> ServiceMultipleCtors:
+    def new_with_string(anon0: bytes) -> ServiceMultipleCtors:
         """"""
         if not hasattr(anon0, "__ctypes_from_outparam__"):
             anon0 = ctypes.cast(anon0, ctypes.POINTER(ctypes.c_char))
-        ctx = c_lib.service_multiple_ctors_new_with_string().t
+        ctx = c_lib.service_multiple_ctors_new_with_string(anon0).unwrap()
+        self = ServiceMultipleCtors(ServiceMultipleCtors.__api_lock, ctx)
+        return self
+
+    @staticmethod
+    def new_failing(some_value: int) -> ServiceMultipleCtors:
+        """"""
+        ctx = c_lib.service_multiple_ctors_new_failing(some_value).unwrap()
         self = ServiceMultipleCtors(ServiceMultipleCtors.__api_lock, ctx)
         return self
 
     @staticmethod
-    def new_failing() -> ServiceMultipleCtors:
+    def new_std(some_value: int) -> ServiceMultipleCtors:
         """"""
-        ctx = c_lib.service_multiple_ctors_new_failing().t
+        ctx = c_lib.service_multiple_ctors_new_std(some_value).unwrap()
         self = ServiceMultipleCtors(ServiceMultipleCtors.__api_lock, ctx)
         return self
 
@@ -3582,16 +9541,16 @@ class ServiceVariousSlices:
     @staticmethod
     def new() -> ServiceVariousSlices:
         """"""
-        ctx = c_lib.service_various_slices_new().t
+        ctx = c_lib.service_various_slices_new().unwrap()
         self = ServiceVariousSlices(ServiceVariousSlices.__api_lock, ctx)
         return self
 
     def __del__(self):
         c_lib.service_various_slices_destroy(self._ctx, )
-    def mut_self(self, slice: SliceU8 | ctypes.Array[ctypes.c_uint8]) -> int:
+    def mut_self(self, slice: SliceU8 | collections.abc.Buffer) -> int:
         """"""
-        if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint8:
-            slice = SliceU8(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint8)), len=len(slice))
+        if not isinstance(slice, SliceU8):
+            slice = SliceU8.from_buffer(slice)
 
         return c_lib.service_various_slices_mut_self(self._ctx, slice)
 
@@ -3606,34 +9565,34 @@ class ServiceVariousSlices:
         """"""
         return c_lib.service_various_slices_mut_self_ref(self._ctx, x, y)
 
-    def mut_self_ref_slice(self, x: ctypes.POINTER(ctypes.c_uint8), y: ctypes.POINTER(ctypes.c_uint8), slice: SliceU8 | ctypes.Array[ctypes.c_uint8]) -> int:
+    def mut_self_ref_slice(self, x: ctypes.POINTER(ctypes.c_uint8), y: ctypes.POINTER(ctypes.c_uint8), slice: SliceU8 | collections.abc.Buffer) -> int:
         """"""
-        if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint8:
-            slice = SliceU8(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint8)), len=len(slice))
+        if not isinstance(slice, SliceU8):
+            slice = SliceU8.from_buffer(slice)
 
         return c_lib.service_various_slices_mut_self_ref_slice(self._ctx, x, y, slice)
 
-    def mut_self_ref_slice_limited(self, x: ctypes.POINTER(ctypes.c_uint8), y: ctypes.POINTER(ctypes.c_uint8), slice: SliceU8 | ctypes.Array[ctypes.c_uint8], slice2: SliceU8 | ctypes.Array[ctypes.c_uint8]) -> int:
+    def mut_self_ref_slice_limited(self, x: ctypes.POINTER(ctypes.c_uint8), y: ctypes.POINTER(ctypes.c_uint8), slice: SliceU8 | collections.abc.Buffer, slice2: SliceU8 | collections.abc.Buffer) -> int:
         """"""
-        if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint8:
-            slice = SliceU8(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint8)), len=len(slice))
+        if not isinstance(slice, SliceU8):
+            slice = SliceU8.from_buffer(slice)
 
-        if hasattr(slice2, "_length_") and getattr(slice2, "_type_", "") == ctypes.c_uint8:
-            slice2 = SliceU8(data=ctypes.cast(slice2, ctypes.POINTER(ctypes.c_uint8)), len=len(slice2))
+        if not isinstance(slice2, SliceU8):
+            slice2 = SliceU8.from_buffer(slice2)
 
         return c_lib.service_various_slices_mut_self_ref_slice_limited(self._ctx, x, y, slice, slice2)
 
-    def mut_self_ffi_error(self, slice: SliceMutU8 | ctypes.Array[ctypes.c_uint8]):
+    def mut_self_ffi_error(self, slice: SliceMutU8 | collections.abc.Buffer) -> ResultError:
         """"""
-        if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint8:
-            slice = SliceMutU8(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint8)), len=len(slice))
+        if not isinstance(slice, SliceMutU8):
+            slice = SliceMutU8.from_buffer(slice)
 
         return c_lib.service_various_slices_mut_self_ffi_error(self._ctx, slice)
 
-    def mut_self_no_error(self, slice: SliceMutU8 | ctypes.Array[ctypes.c_uint8]):
+    def mut_self_no_error(self, slice: SliceMutU8 | collections.abc.Buffer) -> ResultError:
         """"""
-        if hasattr(slice, "_length_") and getattr(slice, "_type_", "") == ctypes.c_uint8:
-            slice = SliceMutU8(data=ctypes.cast(slice, ctypes.POINTER(ctypes.c_uint8)), len=len(slice))
+        if not isinstance(slice, SliceMutU8):
+            slice = SliceMutU8.from_buffer(slice)
 
         return c_lib.service_various_slices_mut_self_no_error(self._ctx, slice)
 
@@ -3647,6 +9606,15 @@ class ServiceVariousSlices:
  again, as otherwise undefined behavior might happen."""
         return c_lib.service_various_slices_return_slice_mut(self._ctx, )
 
+    def return_iter(self, ) -> IterU32:
+        """ Hands out a copy of the data lazily, so the returned iterator stays valid even if the
+ service is used again."""
+        return c_lib.service_various_slices_return_iter(self._ctx, )
+
+    def return_len_and_first(self, ) -> typing.Tuple[int, int]:
+        """"""
+        return c_lib.service_various_slices_return_len_and_first(self._ctx, ).to_tuple()
+
 
 
 class ServiceStrings:
@@ -3664,14 +9632,14 @@ class ServiceStrings:
     @staticmethod
     def new() -> ServiceStrings:
         """"""
-        ctx = c_lib.service_strings_new().t
+        ctx = c_lib.service_strings_new().unwrap()
         self = ServiceStrings(ServiceStrings.__api_lock, ctx)
         return self
 
     @staticmethod
-    def new_string() -> ServiceStrings:
+    def new_string(x) -> ServiceStrings:
         """"""
-        ctx = c_lib.service_strings_new_string().t
+        ctx = c_lib.service_strings_new_string(x).unwrap()
         self = ServiceStrings(ServiceStrings.__api_lock, ctx)
         return self
 
@@ -3688,12 +9656,292 @@ class ServiceStrings:
         rval = c_lib.service_strings_return_cstr(self._ctx, )
         return ctypes.string_at(rval)
 
+    def pass_str(self, s: Utf8Str | str | bytes) -> int:
+        """"""
+        if not isinstance(s, Utf8Str):
+            s = Utf8Str.from_bytes(s)
+
+        return c_lib.service_strings_pass_str(self._ctx, s)
+
     def callback_string(self, s, cb):
         """"""
-        if not hasattr(cb, "__ctypes_from_outparam__"):
-            cb = callbacks.fn_Utf8String_ConstPtrVoid_rval_void(cb)
+        if not isinstance(cb, StringCallback):
+            cb = StringCallback(cb)
 
         return c_lib.service_strings_callback_string(self._ctx, s, cb)
 
 
 
+class Storage(typing.Protocol):
+    """ Some key-value storage shared by multiple services."""
+    def get(self, key: int) -> int: ...
+    def set(self, key: int, val: int): ...
+
+
+class ServiceStorageArray:
+    """ Storage backed by a fixed size array."""
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert(api_lock == ServiceStorageArray.__api_lock), "You must create this with a static constructor." 
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    @staticmethod
+    def new() -> ServiceStorageArray:
+        """"""
+        ctx = c_lib.service_storage_array_new().unwrap()
+        self = ServiceStorageArray(ServiceStorageArray.__api_lock, ctx)
+        return self
+
+    def __del__(self):
+        c_lib.service_storage_array_destroy(self._ctx, )
+    def as_storage(self) -> DynStorage:
+        return c_lib.service_storage_array_as_storage(self._ctx)
+
+    def get(self, key: int) -> int:
+        """ Returns the value stored at `key`."""
+        return c_lib.storage_get(self.as_storage(), key)
+
+    def set(self, key: int, val: int):
+        """ Stores `val` at `key`."""
+        return c_lib.storage_set(self.as_storage(), key, val)
+
+
+
+class ServiceStorageOffset:
+    """ Storage adding a fixed offset to all values it returns."""
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert(api_lock == ServiceStorageOffset.__api_lock), "You must create this with a static constructor." 
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    @staticmethod
+    def new(offset: int) -> ServiceStorageOffset:
+        """"""
+        ctx = c_lib.service_storage_offset_new(offset).unwrap()
+        self = ServiceStorageOffset(ServiceStorageOffset.__api_lock, ctx)
+        return self
+
+    def __del__(self):
+        c_lib.service_storage_offset_destroy(self._ctx, )
+    def get(self, key: int) -> int:
+        """ Services can provide interface methods themselves."""
+        return c_lib.service_storage_offset_get(self._ctx, key)
+
+    def offset(self, ) -> int:
+        """"""
+        return c_lib.service_storage_offset_offset(self._ctx, )
+
+    def as_storage(self) -> DynStorage:
+        return c_lib.service_storage_offset_as_storage(self._ctx)
+
+    def set(self, key: int, val: int):
+        """ Stores `val` at `key`."""
+        return c_lib.storage_set(self.as_storage(), key, val)
+
+
+
+class ServiceForeignLogger:
+    """ Service holding on to a foreign implementation."""
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert(api_lock == ServiceForeignLogger.__api_lock), "You must create this with a static constructor." 
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    @staticmethod
+    def new(sink: LogSinkVTable) -> ServiceForeignLogger:
+        """"""
+        ctx = c_lib.service_foreign_logger_new(sink).unwrap()
+        self = ServiceForeignLogger(ServiceForeignLogger.__api_lock, ctx)
+        return self
+
+    def __del__(self):
+        c_lib.service_foreign_logger_destroy(self._ctx, )
+    def log(self, x: int) -> ResultError:
+        """"""
+        return c_lib.service_foreign_logger_log(self._ctx, x)
+
+
+
+class LogSinkBase:
+    """ A sink implemented in a foreign language receiving log messages."""
+    def __init__(self):
+        self._vtable = LogSinkVTable()
+        self._vtable.log = LogSinkLog(lambda level, x, _: self.log(level, x))
+        self._vtable.count = LogSinkCount(lambda _: self.count())
+
+    def vtable(self) -> LogSinkVTable:
+        """Returns a vtable forwarding to this instance, valid as long as this instance is alive."""
+        return self._vtable
+
+    def log(self, level: int, x: int):
+        """ Called for each logged value."""
+        raise NotImplementedError()
+
+    def count(self) -> int:
+        """ Returns how many values the sink has seen."""
+        raise NotImplementedError()
+
+
+class ServiceProperties:
+    """ Service exposing some of its methods as properties."""
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert(api_lock == ServiceProperties.__api_lock), "You must create this with a static constructor." 
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    @staticmethod
+    def new() -> ServiceProperties:
+        """"""
+        ctx = c_lib.service_properties_new().unwrap()
+        self = ServiceProperties(ServiceProperties.__api_lock, ctx)
+        return self
+
+    def __del__(self):
+        c_lib.service_properties_destroy(self._ctx, )
+    def get_value(self, ) -> int:
+        """"""
+        return c_lib.service_properties_get_value(self._ctx, )
+
+    def set_value(self, x: int):
+        """"""
+        return c_lib.service_properties_set_value(self._ctx, x)
+
+    def get_updates(self, ) -> int:
+        """ Properties can also be read-only."""
+        return c_lib.service_properties_get_updates(self._ctx, )
+
+    @property
+    def value(self) -> int:
+        return self.get_value()
+
+    @value.setter
+    def value(self, x: int):
+        self.set_value(x)
+
+    @property
+    def updates(self) -> int:
+        return self.get_updates()
+
+
+
+class ServiceStatics:
+    """ Service with static helpers next to its constructor."""
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert(api_lock == ServiceStatics.__api_lock), "You must create this with a static constructor." 
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    @staticmethod
+    def new_with(scale: int) -> ServiceStatics:
+        """"""
+        ctx = c_lib.service_statics_new_with(scale).unwrap()
+        self = ServiceStatics(ServiceStatics.__api_lock, ctx)
+        return self
+
+    def __del__(self):
+        c_lib.service_statics_destroy(self._ctx, )
+    def scale(self, x: int) -> int:
+        """"""
+        return c_lib.service_statics_scale(self._ctx, x)
+
+    @staticmethod
+    def default_scale() -> int:
+        """ The scale used by default, not tied to any instance."""
+        return c_lib.service_statics_default_scale()
+
+    @staticmethod
+    def scaled(x: int, scale: int) -> int:
+        """"""
+        return c_lib.service_statics_scaled(x, scale)
+
+
+
+class ServiceThreadAffine:
+    """ Service guarded against use from other threads.
+ This service must only be used from the thread that created it."""
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert(api_lock == ServiceThreadAffine.__api_lock), "You must create this with a static constructor." 
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    @staticmethod
+    def new() -> ServiceThreadAffine:
+        """"""
+        ctx = c_lib.service_thread_affine_new().unwrap()
+        self = ServiceThreadAffine(ServiceThreadAffine.__api_lock, ctx)
+        return self
+
+    def __del__(self):
+        c_lib.service_thread_affine_destroy(self._ctx, )
+    def increment(self, ) -> ResultSyncError:
+        """"""
+        return c_lib.service_thread_affine_increment(self._ctx, )
+
+    def value(self, ) -> int:
+        """"""
+        return c_lib.service_thread_affine_value(self._ctx, )
+
+
+
+class ServiceMutex:
+    """ Service guarded against concurrent use.
+ Calls into this service are serialized by a lock."""
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert(api_lock == ServiceMutex.__api_lock), "You must create this with a static constructor." 
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    @staticmethod
+    def new() -> ServiceMutex:
+        """"""
+        ctx = c_lib.service_mutex_new().unwrap()
+        self = ServiceMutex(ServiceMutex.__api_lock, ctx)
+        return self
+
+    def __del__(self):
+        c_lib.service_mutex_destroy(self._ctx, )
+    def increment(self, ) -> int:
+        """"""
+        return c_lib.service_mutex_increment(self._ctx, )
+
+    def value(self, ) -> int:
+        """"""
+        return c_lib.service_mutex_value(self._ctx, )
+
+
+
@@ -15,10 +15,17 @@ fn reference_tests_work() -> Result<(), Error> {
         "test_core_apis.py",
         "test_core_namespaces.py",
         "test_core_panics.py",
+        "test_core_primitives.py",
         "test_core_slices.py",
         "test_pattern_callbacks.py",
+        "test_pattern_durations.py",
+        "test_pattern_enums.py",
+        "test_pattern_iters.py",
+        "test_pattern_maps.py",
+        "test_pattern_ndslices.py",
         "test_pattern_services.py",
         "test_pattern_strings.py",
+        "test_pattern_tuples.py",
     ];
 
     for file in files {
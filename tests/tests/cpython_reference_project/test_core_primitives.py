@@ -0,0 +1,52 @@
+import common
+import reference_project as r
+import unittest
+
+r.init_lib(common.DLL)
+
+
+class TestPrimitives(unittest.TestCase):
+    def test_char(self):
+        self.assertEqual(ord("Ä"), r.primitive_char(ord("ä")))
+        self.assertEqual(ord("🐙"), r.primitive_char(ord("🐙")))
+
+    def test_invalid_char(self):
+        self.assertEqual(0, r.primitive_char(0xD800))
+        self.assertEqual(0, r.primitive_char(0x110000))
+        self.assertEqual(0xD800, r.struct4(r.WideNum(initial=0xD800)).initial)
+
+    def test_128_bit(self):
+        u128_max = 2 ** 128 - 1
+        self.assertEqual(u128_max, r.primitive_u128(0))
+        self.assertEqual(2 ** 64, r.primitive_u128(u128_max - 2 ** 64))
+        self.assertEqual(-(2 ** 100), r.primitive_i128(2 ** 100))
+        self.assertEqual(2 ** 127 - 1, r.primitive_i128(-(2 ** 127 - 1)))
+
+    def test_128_bit_halves(self):
+        x = 0x0123456789ABCDEF_FEDCBA9876543210
+        self.assertEqual(2 ** 128 - 1 - x, r.primitive_u128(x))
+        self.assertEqual(x, r.primitive_u128(r.primitive_u128(x)))
+        self.assertEqual(-x, r.primitive_i128(x))
+        self.assertEqual(x, r.primitive_i128(-x))
+
+    def test_128_bit_out_of_range(self):
+        with self.assertRaises(OverflowError):
+            r.primitive_u128(-1)
+        with self.assertRaises(OverflowError):
+            r.primitive_i128(2 ** 127)
+
+    def test_nonzero(self):
+        self.assertEqual(2, r.primitive_nonzero(1))
+        self.assertEqual(0, r.primitive_nonzero(0))
+        self.assertEqual(0, r.primitive_nonzero(2 ** 32 - 1))
+
+    def test_struct(self):
+        rval = r.struct4(r.WideNum(id=2 ** 120, delta=-(2 ** 90), initial=ord("x"), handle=41))
+        self.assertEqual(2 ** 120 + 1, rval.id)
+        self.assertEqual(2 ** 90, rval.delta)
+        self.assertEqual(ord("X"), rval.initial)
+        self.assertEqual(42, rval.handle)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -3,6 +3,7 @@ import reference_project as r
 import unittest
 import sys
 import ctypes
+import array
 
 r.init_lib(common.DLL)
 
@@ -20,6 +21,49 @@ class TestFunctions(unittest.TestCase):
         returned_length = r.pattern_ffi_slice_1(slice)
         self.assertEqual(len(slice), returned_length)
 
+    def test_slice_from_buffer(self):
+        self.assertEqual(5, r.pattern_ffi_slice_1(array.array("I", range(5))))
+        self.assertEqual(3, r.pattern_ffi_slice_1(memoryview(bytearray(12)).cast("I")))
+        self.assertEqual(0, r.pattern_ffi_slice_1(array.array("I")))
+        self.assertEqual(4, r.pattern_ffi_slice_1b(array.array("I", range(4))))
+
+        with self.assertRaises(ValueError):
+            r.pattern_ffi_slice_1(array.array("d", range(5)))
+
+        with self.assertRaises(ValueError):
+            r.pattern_ffi_slice_1(memoryview(bytearray(32)).cast("I")[::2])
+
+    def test_slice_mut_from_read_only_buffer(self):
+        with self.assertRaises(ValueError):
+            r.pattern_ffi_slice_1b(memoryview(array.array("I", range(4))).toreadonly())
+
+    def test_slice_memoryview(self):
+        values = array.array("I", [1, 2, 3])
+        slice = r.SliceMutU32.from_buffer(values)
+        view = slice.memoryview()
+        view[1] = 20
+        self.assertEqual([1, 20, 3], values.tolist())
+        self.assertFalse(view.readonly)
+        self.assertTrue(r.SliceU32.from_buffer(values).memoryview().readonly)
+
+    def test_slice_bytes(self):
+        slice = r.SliceU8.from_buffer(b"hello")
+        self.assertEqual(b"hello", slice.memoryview().tobytes())
+        self.assertEqual(bytearray(b"hello"), slice.bytearray())
+
+    def test_vec_from_buffer(self):
+        vec = r.VecU8.from_buffer(b"abc")
+        self.assertEqual(3, vec.len)
+        self.assertEqual(b"abc", r.pattern_vec_3(vec).memoryview().tobytes())
+        self.assertEqual(b"xyz", r.pattern_vec_3(bytearray(b"xyz")).memoryview().tobytes())
+        self.assertEqual(0, r.pattern_vec_3(b"").len)
+
+    def test_vec_memoryview(self):
+        vec = r.pattern_vec_1()
+        view = vec.memoryview()
+        del vec
+        self.assertEqual([1, 2, 3], view.tolist())
+
     # Callbacks are (ptr_fn, ptr_data) now, code gen needs to be reworked
     # def test_slice_from_ctypes_array_callback(self):
     #     array = (ctypes.c_uint8 * 10)()
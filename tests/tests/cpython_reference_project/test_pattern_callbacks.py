@@ -2,6 +2,7 @@ import common
 import reference_project as r
 import unittest
 import sys
+import gc
 
 r.init_lib(common.DLL)
 
@@ -25,6 +26,18 @@ class TestFunctions(unittest.TestCase):
         #
         # r.pattern_ffi_slice_delegate(callback)
 
+    def test_owned_callback_shared_by_two_holders(self):
+        callback = r.MyOwnedCallback(lambda x, _: x * 2)
+        r.pattern_callback_10(callback)
+        self.assertEqual(6, r.pattern_callback_9(callback, 3))  # Rust drops this reference again.
+
+        del callback
+        gc.collect()
+        self.assertEqual(8, r.pattern_callback_11(4))
+
+        r.pattern_callback_10(r.MyOwnedCallback(lambda x, _: x))
+        self.assertEqual(1, len(r.MyOwnedCallback._alive))
+
 
 if __name__ == '__main__':
     unittest.main()
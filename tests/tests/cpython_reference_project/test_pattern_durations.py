@@ -0,0 +1,32 @@
+import common
+import reference_project as r
+import unittest
+from datetime import timedelta
+
+r.init_lib(common.DLL)
+
+
+class TestDurations(unittest.TestCase):
+    def test_timedelta(self):
+        self.assertEqual(timedelta(seconds=3, microseconds=2), r.pattern_duration_1(timedelta(seconds=1.5, microseconds=1)))
+        self.assertEqual(timedelta(days=4), r.pattern_duration_1(timedelta(days=2)))
+        self.assertEqual(timedelta(seconds=1), r.pattern_duration_1(r.Duration(0, 500_000_000)))
+
+    def test_negative(self):
+        with self.assertRaises(ValueError):
+            r.pattern_duration_1(timedelta(seconds=-1))
+
+    def test_overflow(self):
+        self.assertEqual(timedelta(0), r.pattern_duration_1(r.Duration(2**64 - 1, 1_000_000_000)))
+
+    def test_fields(self):
+        timeout = r.Timeout(after=timedelta(milliseconds=250), retries=3)
+        self.assertEqual(timedelta(milliseconds=250), timeout.after)
+        self.assertEqual(timedelta(seconds=1), r.pattern_duration_2(timeout))
+
+    def test_truncates_nanos(self):
+        self.assertEqual(timedelta(seconds=1, microseconds=500_000), r.pattern_duration_3())
+
+
+if __name__ == '__main__':
+    unittest.main()
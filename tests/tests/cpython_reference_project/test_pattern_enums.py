@@ -0,0 +1,47 @@
+import common
+import reference_project as r
+import unittest
+
+r.init_lib(common.DLL)
+
+
+class TestEnums(unittest.TestCase):
+    def test_data_enums(self):
+        self.assertEqual(r.EnumPayload.C(246), r.enums_2(r.EnumPayload.C(123)))
+        self.assertTrue(r.enums_2(r.EnumPayload.A()).is_a())
+
+        b = r.enums_2(r.EnumPayload.B(r.Vec3f32(1.0, 2.0, 3.0)))
+        self.assertTrue(b.is_b())
+        self.assertEqual(6.0, b.as_b().z)
+
+        match r.enums_2(r.EnumPayload.C(1)).variant():
+            case r.EnumPayload.CVariant(value=x):
+                self.assertEqual(2, x)
+            case _:
+                self.fail()
+
+    def test_options(self):
+        some = r.pattern_ffi_option_1(r.OptionInner.Some(r.Inner(1.0)))
+        self.assertTrue(some.is_some())
+        self.assertEqual(1.0, some.unwrap().x)
+        self.assertEqual(1.0, r.pattern_ffi_option_2(r.OptionInner.Some(r.Inner(1.0))).x)
+
+        none = r.pattern_ffi_option_1(r.OptionInner.None_())
+        self.assertTrue(none.is_none())
+        self.assertRaises(Exception, none.unwrap)
+
+    def test_results(self):
+        self.assertEqual(123, r.pattern_result_1(r.ResultU32Error.Ok(123)).unwrap())
+        self.assertEqual(20, r.pattern_result_5(10).unwrap())
+        self.assertRaises(r.ErrorFailException, r.pattern_result_5(1000).unwrap)
+        self.assertIsNone(r.pattern_result_2().unwrap())
+        self.assertTrue(r.pattern_result_4(r.ResultVoid.Ok()).is_ok())
+
+    def test_panics(self):
+        result = r.behavior_panics_ffi_error()
+        self.assertTrue(result.is_panic())
+        self.assertRaises(Exception, r.behavior_panics_ffi_error().unwrap)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,45 @@
+import common
+import reference_project as r
+import unittest
+
+r.init_lib(common.DLL)
+
+
+class TestIters(unittest.TestCase):
+    def test_primitives(self):
+        self.assertEqual([0, 1, 2, 3], list(r.pattern_iter_1(4)))
+        self.assertEqual([], list(r.pattern_iter_1(0)))
+
+    def test_composites(self):
+        self.assertEqual([1.0, 2.0, 3.0], [v.y for v in r.pattern_iter_2()])
+
+    def test_exhausted(self):
+        it = r.pattern_iter_1(2)
+        self.assertEqual(0, next(it))
+        self.assertEqual([1], list(it))
+        self.assertIsNone(it.ptr)
+        self.assertEqual([], list(it))
+
+    def test_close(self):
+        with r.pattern_iter_1(100) as it:
+            self.assertEqual(0, next(it))
+        self.assertIsNone(it.ptr)
+        self.assertRaises(StopIteration, lambda: next(it))
+
+    def test_roundtrip(self):
+        it = r.pattern_iter_1(5)
+        self.assertEqual(10, r.pattern_iter_3(it))
+        self.assertIsNone(it.ptr)
+
+    def test_panic_ends_iteration(self):
+        it = r.pattern_iter_4(3)
+        self.assertEqual([0, 1, 2], list(it))
+        self.assertIsNone(it.ptr)
+
+    def test_service(self):
+        service = r.ServiceVariousSlices.new()
+        self.assertEqual([123] * 64, list(service.return_iter()))
+
+
+if __name__ == '__main__':
+    unittest.main()
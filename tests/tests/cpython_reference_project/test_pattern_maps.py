@@ -0,0 +1,30 @@
+import collections.abc
+import common
+import reference_project as r
+import unittest
+
+r.init_lib(common.DLL)
+
+
+class TestMaps(unittest.TestCase):
+    def test_mapping(self):
+        m = r.pattern_map_1()
+        self.assertIsInstance(m, collections.abc.Mapping)
+        self.assertEqual(3, len(m))
+        self.assertEqual([1, 2, 3], list(m))
+        self.assertEqual(2.0, m[2].x)
+        self.assertIn(3, m)
+        self.assertNotIn(4, m)
+        self.assertIsNone(m.get(4))
+        self.assertEqual([1.0, 2.0, 3.0], [v.z for v in m.values()])
+        self.assertRaises(KeyError, lambda: m[4])
+
+    def test_roundtrip(self):
+        m = r.pattern_map_1()
+        clone = r.pattern_map_3(m)
+        self.assertEqual(3.0, r.pattern_map_2(clone, 3).unwrap().y)
+        self.assertTrue(r.pattern_map_2(m, 4).is_none())
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,40 @@
+import array
+import common
+import reference_project as r
+import unittest
+
+r.init_lib(common.DLL)
+
+
+class TestNdSlices(unittest.TestCase):
+    def test_buffer_protocol(self):
+        data = memoryview(array.array("f", [1, 2, 3, 4, 5, 6])).cast("B").cast("f", (2, 3))
+        slice = r.NdSliceF32.from_buffer(data)
+        self.assertEqual((2, 3), slice.dims())
+        self.assertEqual(6, len(slice))
+        self.assertEqual(4.0, slice[1, 0])
+        self.assertEqual([[1, 2, 3], [4, 5, 6]], slice.memoryview().tolist())
+        self.assertTrue(slice.memoryview().readonly)
+        self.assertRaises(IndexError, lambda: slice[2, 0])
+        self.assertEqual(21.0, r.pattern_ndslice_1(data))
+        self.assertEqual(21.0, r.pattern_ndslice_1(slice))
+
+    def test_read_only_and_mismatched_buffers(self):
+        data = memoryview(bytes(array.array("i", [1, 2, 3, 4]))).cast("i", (2, 2))
+        self.assertEqual(3, r.pattern_ndslice_2(data, 1, 0))
+        self.assertEqual(-1, r.pattern_ndslice_2(data, 2, 0))
+        self.assertRaises(ValueError, lambda: r.NdSliceF32.from_buffer(data))
+        self.assertRaises(ValueError, lambda: r.NdSliceMutU32.from_buffer(array.array("I", [0]).tobytes()))
+
+    def test_mut_writes_through(self):
+        data = array.array("I", [0] * 6)
+        view = memoryview(data).cast("B").cast("I", (2, 3))
+        slice = r.NdSliceMutU32.from_buffer(view)
+        r.pattern_ndslice_3(slice)
+        slice[0, 0] = 7
+        self.assertEqual([7, 1, 2, 100, 101, 102], list(data))
+        self.assertEqual([[7, 1, 2], [100, 101, 102]], slice.memoryview().tolist())
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -11,6 +11,44 @@ class TestFunctions(unittest.TestCase):
         slice = service.return_slice_mut()
         self.assertEqual(123, slice[0])
 
+    def test_service_interfaces(self):
+        array = r.ServiceStorageArray.new()
+        offset = r.ServiceStorageOffset.new(100)
+        array.set(1, 5)
+        offset.set(1, 5)
+        self.assertEqual(5, array.get(1))
+        self.assertEqual(105, offset.get(1))
+        self.assertEqual(107, r.pattern_service_interface_1(offset.as_storage(), 2, 7))
+
+    def test_foreign_trait(self):
+        class CountingSink(r.LogSinkBase):
+            def __init__(self):
+                super().__init__()
+                self.seen = 0
+
+            def log(self, level, x):
+                self.seen += 1
+
+            def count(self):
+                return self.seen
+
+        sink = CountingSink()
+        self.assertEqual(2, r.pattern_foreign_trait_1(sink.vtable(), 10))
+
+    def test_service_properties(self):
+        service = r.ServiceProperties.new()
+        service.value = 123
+        self.assertEqual(123, service.value)
+        self.assertEqual(1, service.updates)
+
+    def test_service_static_methods(self):
+        self.assertEqual(10, r.ServiceStatics.default_scale())
+        self.assertEqual(6, r.ServiceStatics.scaled(2, 3))
+
+    def test_service_std_result_ctor(self):
+        r.ServiceMultipleCtors.new_std(10)
+        self.assertRaises(r.ErrorFailException, r.ServiceMultipleCtors.new_std, 1000)
+
 
 if __name__ == '__main__':
     unittest.main()
@@ -16,6 +16,32 @@ class TestFunctions(unittest.TestCase):
     def test_c_char(self):
         self.assertEqual(b'X', r.pattern_ffi_cchar(b'X'))
 
+    def test_str(self):
+        self.assertEqual(11, r.pattern_str_1("hello world"))
+        self.assertEqual(5, r.pattern_str_1(b"hello"))
+        self.assertEqual(0, r.pattern_str_1(""))
+        self.assertEqual("hello world", str(r.pattern_str_2()))
+        s1 = r.Utf8Str.from_bytes("héllo")
+        s2 = r.Utf8Str.from_bytes(b"abc")
+        self.assertEqual("héllo", str(r.pattern_str_3(s1)))
+        self.assertEqual(b"abc", r.pattern_str_3(s2).bytes())
+
+    def test_str_validates_utf8(self):
+        self.assertEqual(5, r.pattern_str_5("héllo").unwrap())
+        self.assertRaises(r.ErrorFailException, r.pattern_str_5(b"\xc3\x28").unwrap)
+
+    def test_result_with_string(self):
+        self.assertEqual(r.Error.Fail, r.pattern_string_9().as_err())
+
+    def test_str_in_struct(self):
+        s1 = r.Utf8Str.from_bytes("hello")
+        s2 = r.Utf8Str.from_bytes(b"world!")
+        self.assertEqual(11, r.pattern_str_4(r.UseStr(s1, s2)))
+
+    def test_service_str(self):
+        service = r.ServiceStrings.new()
+        self.assertEqual(3, service.pass_str("abc"))
+
 
 if __name__ == '__main__':
     unittest.main()
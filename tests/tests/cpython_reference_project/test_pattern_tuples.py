@@ -0,0 +1,47 @@
+import common
+import reference_project as r
+import unittest
+
+r.init_lib(common.DLL)
+
+
+class TestTuples(unittest.TestCase):
+    def test_primitives(self):
+        self.assertEqual((2.0, 1), r.pattern_tuple_1((1, 2.0)))
+        self.assertEqual((2.0, 1), r.pattern_tuple_1(r.Tuple2_U32_F32(1, 2.0)))
+
+    def test_composites(self):
+        v, b, i = r.pattern_tuple_2((r.Vec3f32(1.0, 2.0, 3.0), 10, -5))
+        self.assertEqual(2.0, v.x)
+        self.assertEqual(3.0, v.z)
+        self.assertEqual(11, b)
+        self.assertEqual(-4, i)
+
+    def test_fields(self):
+        t = r.Tuples(pair=(3, 1.5), triple=(r.Vec3f32(), 1, 21))
+        rval = r.pattern_tuple_3(t)
+        self.assertEqual((6, 3.0), rval.pair)
+        self.assertEqual(42, rval.triple[2])
+
+    def test_behaves_like_tuple(self):
+        t = r.Tuple2_U32_F32(1, 2.0)
+        a, b = t
+        self.assertEqual((1, 2.0), (a, b))
+        self.assertEqual(2, len(t))
+        self.assertEqual(2.0, t[1])
+        self.assertEqual((1, 2.0), t)
+
+    def test_nested(self):
+        self.assertEqual(5, r.pattern_tuple_4(r.OptionTuple2_U32_U32.Some(r.Tuple2_U32_U32(2, 3))))
+        self.assertEqual(0, r.pattern_tuple_4(r.OptionTuple2_U32_U32.None_()))
+
+    def test_destructured(self):
+        self.assertEqual(12, r.pattern_tuple_5((3, 3)))
+
+    def test_service(self):
+        service = r.ServiceVariousSlices.new()
+        self.assertEqual((64, 123), service.return_len_and_first())
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -3,9 +3,9 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      library
-// Hash:         0x1e0f49052a4ff635
+// Hash:         0xb9de4e521a083aaf
 // Namespace:    
-// Builder:      interoptopus_backend_csharp (0.15.0-alpha.20)
+// Builder:      interoptopus_backend_csharp (0.15.0-alpha.21)
 //
 // Do not edit this file manually.
 //
@@ -36,11 +36,9 @@ namespace My.Company
     public partial struct MyEnum 
     {
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -72,20 +70,13 @@ namespace My.Company
 
         public bool IsEnumerator => _variant == 0;
 
-        public void AsEnumerator() { if (_variant != 0) throw ExceptionForVariant(); }
+        public void AsEnumerator() { if (_variant != 0) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Enumerator";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(MyEnum), MarshalMode.Default, typeof(Marshaller))]
@@ -163,10 +154,11 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(MyEnum arg)
+        internal void Call(MyEnum arg)
         {
             var __target = Marshal.GetDelegateForFunctionPointer<EnumArgumentNative>(_ptr);
-            __target(arg.ToUnmanaged(), IntPtr.Zero);
+            // TODO
+            // __target(arg.ToManaged());
             return;
         }
 
@@ -278,10 +270,12 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyEnum Call()
+        internal MyEnum Call()
         {
             var __target = Marshal.GetDelegateForFunctionPointer<EnumReturnNative>(_ptr);
-            return __target(IntPtr.Zero).ToManaged();
+            // TODO
+            // return __target();
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -350,15 +344,23 @@ namespace My.Company
 
 
 
+    public class InteropException : Exception
+    {
+        public InteropException() : base()
+        {
+        }
 
-    public class EnumException() : InteropException($"Enum variant mismatch.")  { }
+        public InteropException(string message) : base(message)
+        {
+        }
 
-    public class EnumException<T>(T t) : InteropException($"Enum variant mismatch.")
-    {
-        public T Value { get; } = t;
+        /// Creates an exception for an unexpected result, including details about the last error or panic in Rust.
+        public static InteropException FromLastError(string result)
+        {
+            var lastError = Utf8String.LastError();
+            return string.IsNullOrEmpty(lastError) ? new InteropException(result) : new InteropException($"{result}: {lastError}");
+        }
     }
-
-    public class InteropException(string text) : Exception(text) { }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -459,23 +461,33 @@ namespace My.Company
     {
         private Utf8String() { }
 
+        // Hands the UTF-16 contents of `s` to Rust, which converts them without an intermediate copy here. Like
+        // `Encoding.UTF8`, unpaired surrogates become U+FFFD.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static unsafe Utf8String From(string s)
         {
-            var rval = new Utf8String();
-            var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+            fixed (char* p = s)
+            {
+                InteropHelper.interoptopus_string_create_utf16((IntPtr)p, (ulong)s.Length, out var native);
+                return native.IntoManaged();
+            }
+        }
 
-            fixed (byte* p = utf8Bytes)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String FromUtf8(ReadOnlySpan<byte> utf8)
+        {
+            fixed (byte* p = utf8)
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                var status = InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)utf8.Length, out var native);
+                if (status != 0) { throw new InteropException("Bytes are not valid UTF-8."); }
+                return native.IntoManaged();
             }
+        }
 
-            return rval;
+        /// Returns and clears details about the last error or panic in Rust on this thread, or an empty string.
+        public static string LastError()
+        {
+            return string.Empty;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -573,6 +585,11 @@ namespace My.Company
             
             public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
 
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, out Unmanaged rval);
+
             [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_destroy")]
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
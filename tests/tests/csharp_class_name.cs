@@ -3,9 +3,9 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      library
-// Hash:         0x437f3e6baccd6fbd
+// Hash:         0xd6cebceea6574708
 // Namespace:    
-// Builder:      interoptopus_backend_csharp (0.15.0-alpha.20)
+// Builder:      interoptopus_backend_csharp (0.15.0-alpha.21)
 //
 // Do not edit this file manually.
 //
@@ -41,11 +41,18 @@ namespace My.Company
         public static partial void sample_function();
 
 
+        /// Creates a string from `len` UTF-8 bytes, returns `0` on success or `1` (and an empty string) if they are not valid UTF-8.
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_create")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_string_create(IntPtr utf8, ulong len, ref Utf8String rval);
 
 
+        /// Creates a string from `len` UTF-16 code units, replacing unpaired surrogates with `U+FFFD` like .NET does; always returns `0`.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, ref Utf8String rval);
+
+
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_destroy")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_string_destroy(Utf8String utf8);
@@ -56,19 +63,33 @@ namespace My.Company
         public static partial long interoptopus_string_clone(ref Utf8String utf8, ref Utf8String rval);
 
 
-    }
+        /// Returns and clears details about the last error or panic on this thread, or an empty string.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_last_error")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Utf8String interoptopus_last_error();
 
 
+    }
 
 
-    public class EnumException() : InteropException($"Enum variant mismatch.")  { }
 
-    public class EnumException<T>(T t) : InteropException($"Enum variant mismatch.")
+    public class InteropException : Exception
     {
-        public T Value { get; } = t;
-    }
+        public InteropException() : base()
+        {
+        }
+
+        public InteropException(string message) : base(message)
+        {
+        }
 
-    public class InteropException(string text) : Exception(text) { }
+        /// Creates an exception for an unexpected result, including details about the last error or panic in Rust.
+        public static InteropException FromLastError(string result)
+        {
+            var lastError = Utf8String.LastError();
+            return string.IsNullOrEmpty(lastError) ? new InteropException(result) : new InteropException($"{result}: {lastError}");
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -169,23 +190,33 @@ namespace My.Company
     {
         private Utf8String() { }
 
+        // Hands the UTF-16 contents of `s` to Rust, which converts them without an intermediate copy here. Like
+        // `Encoding.UTF8`, unpaired surrogates become U+FFFD.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static unsafe Utf8String From(string s)
         {
-            var rval = new Utf8String();
-            var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+            fixed (char* p = s)
+            {
+                InteropHelper.interoptopus_string_create_utf16((IntPtr)p, (ulong)s.Length, out var native);
+                return native.IntoManaged();
+            }
+        }
 
-            fixed (byte* p = utf8Bytes)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String FromUtf8(ReadOnlySpan<byte> utf8)
+        {
+            fixed (byte* p = utf8)
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                var status = InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)utf8.Length, out var native);
+                if (status != 0) { throw new InteropException("Bytes are not valid UTF-8."); }
+                return native.IntoManaged();
             }
+        }
 
-            return rval;
+        /// Returns and clears details about the last error or panic in Rust on this thread, or an empty string.
+        public static string LastError()
+        {
+            return InteropHelper.interoptopus_last_error().IntoManaged().IntoString();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -283,6 +314,11 @@ namespace My.Company
             
             public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
 
+            [LibraryImport(MyClass.NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, out Unmanaged rval);
+
             [LibraryImport(MyClass.NativeLib, EntryPoint = "interoptopus_string_destroy")]
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
@@ -292,6 +328,11 @@ namespace My.Company
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
             public static partial long interoptopus_string_clone(ref Unmanaged orig, ref Unmanaged cloned);
+
+            [LibraryImport(MyClass.NativeLib, EntryPoint = "interoptopus_last_error")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial Unmanaged interoptopus_last_error();
         }
 
         [CustomMarshaller(typeof(Utf8String), MarshalMode.Default, typeof(Marshaller))]
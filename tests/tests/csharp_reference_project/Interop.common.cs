@@ -3,9 +3,9 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      interoptopus_reference_project
-// Hash:         0x8047a8b1c89b1a85
+// Hash:         0x697e3c498b89abcb
 // Namespace:    _common
-// Builder:      interoptopus_backend_csharp (0.15.0-alpha.20)
+// Builder:      interoptopus_backend_csharp (0.15.0-alpha.21)
 //
 // Do not edit this file manually.
 //
@@ -37,13 +37,9 @@ namespace My.Company.Common
     public partial struct EnumDocumented 
     {
 
-
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -79,9 +75,9 @@ namespace My.Company.Common
         public bool IsB => _variant == 1;
         public bool IsC => _variant == 2;
 
-        public void AsA() { if (_variant != 0) throw ExceptionForVariant(); }
-        public void AsB() { if (_variant != 1) throw ExceptionForVariant(); }
-        public void AsC() { if (_variant != 2) throw ExceptionForVariant(); }
+        public void AsA() { if (_variant != 0) throw new InteropException(); }
+        public void AsB() { if (_variant != 1) throw new InteropException(); }
+        public void AsC() { if (_variant != 2) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -89,16 +85,7 @@ namespace My.Company.Common
             if (_variant == 0) return "A";
             if (_variant == 1) return "B";
             if (_variant == 2) return "C";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException();
-            if (_variant == 2) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumDocumented), MarshalMode.Default, typeof(Marshaller))]
@@ -142,41 +129,29 @@ namespace My.Company.Common
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct EnumNum 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedA
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal nuint _A;
-        }
-
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedC
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal nint _C;
         }
 
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedA _A;
-
-            [FieldOffset(0)]
-            internal UnmanagedC _C;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal EnumNum ToManaged()
             {
                 var _managed = new EnumNum();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._A = _A._A;
-                if (_variant == 2) _managed._C = _C._C;
+                if (_variant == 0) _managed._A = _payload._A;
+                if (_variant == 2) _managed._C = _payload._C;
                 return _managed;
             }
         }
@@ -186,8 +161,8 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A;
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 0) _unmanaged._payload._A = _A;
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -196,8 +171,8 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A;
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 0) _unmanaged._payload._A = _A;
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -209,9 +184,9 @@ namespace My.Company.Common
         public bool IsB => _variant == 1;
         public bool IsC => _variant == 2;
 
-        public nuint AsA() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _A; } }
-        public void AsB() { if (_variant != 1) throw ExceptionForVariant(); }
-        public nint AsC() { if (_variant != 2) { throw ExceptionForVariant(); } else { return _C; } }
+        public nuint AsA() { if (_variant != 0) { throw new InteropException(); } else { return _A; } }
+        public void AsB() { if (_variant != 1) throw new InteropException(); }
+        public nint AsC() { if (_variant != 2) { throw new InteropException(); } else { return _C; } }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -219,16 +194,7 @@ namespace My.Company.Common
             if (_variant == 0) return "A(...)";
             if (_variant == 1) return "B";
             if (_variant == 2) return "C(...)";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<nuint>(_A);
-            if (_variant == 1) return new EnumException();
-            if (_variant == 2) return new EnumException<nint>(_C);
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumNum), MarshalMode.Default, typeof(Marshaller))]
@@ -271,11 +237,9 @@ namespace My.Company.Common
     public partial struct EnumRenamed 
     {
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -307,20 +271,13 @@ namespace My.Company.Common
 
         public bool IsX => _variant == 0;
 
-        public void AsX() { if (_variant != 0) throw ExceptionForVariant(); }
+        public void AsX() { if (_variant != 0) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "X";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumRenamed), MarshalMode.Default, typeof(Marshaller))]
@@ -363,11 +320,9 @@ namespace My.Company.Common
     public partial struct Error 
     {
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -399,20 +354,13 @@ namespace My.Company.Common
 
         public bool IsFail => _variant == 0;
 
-        public void AsFail() { if (_variant != 0) throw ExceptionForVariant(); }
+        public void AsFail() { if (_variant != 0) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Fail";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(Error), MarshalMode.Default, typeof(Marshaller))]
@@ -446,6 +394,144 @@ namespace My.Company.Common
         }
     }
 
+    /// Thrown when a function returns `Error` as its error.
+    public class ErrorException : InteropException
+    {
+        public Error Error { get; }
+
+        public ErrorException(Error error) : this(error, error.ToString()) { }
+
+        public ErrorException(Error error, string message) : base(message)
+        {
+            Error = error;
+        }
+
+        /// Creates the exception matching the error's variant, including details about the last error in Rust.
+        public static ErrorException FromError(Error error)
+        {
+            var lastError = Utf8String.LastError();
+            var message = string.IsNullOrEmpty(lastError) ? error.ToString() : $"{error}: {lastError}";
+            if (error.IsFail) return new ErrorFailException(error, message);
+            return new ErrorException(error, message);
+        }
+    }
+
+    public class ErrorFailException : ErrorException
+    {
+        public ErrorFailException(Error error, string message) : base(error, message) { }
+    }
+
+    /// Errors of [`ServiceThreadAffine`].
+    public partial struct SyncError
+    {
+        uint _variant;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct SyncError 
+    {
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal SyncError ToManaged()
+            {
+                var _managed = new SyncError();
+                _managed._variant = _variant;
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        public static SyncError WrongThread => new() { _variant = 0 };
+
+        public bool IsWrongThread => _variant == 0;
+
+        public void AsWrongThread() { if (_variant != 0) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "WrongThread";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(SyncError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private SyncError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SyncError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SyncError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SyncError ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    /// Thrown when a function returns `SyncError` as its error.
+    public class SyncErrorException : InteropException
+    {
+        public SyncError Error { get; }
+
+        public SyncErrorException(SyncError error) : this(error, error.ToString()) { }
+
+        public SyncErrorException(SyncError error, string message) : base(message)
+        {
+            Error = error;
+        }
+
+        /// Creates the exception matching the error's variant, including details about the last error in Rust.
+        public static SyncErrorException FromError(SyncError error)
+        {
+            var lastError = Utf8String.LastError();
+            var message = string.IsNullOrEmpty(lastError) ? error.ToString() : $"{error}: {lastError}";
+            if (error.IsWrongThread) return new SyncErrorWrongThreadException(error, message);
+            return new SyncErrorException(error, message);
+        }
+    }
+
+    public class SyncErrorWrongThreadException : SyncErrorException
+    {
+        public SyncErrorWrongThreadException(SyncError error, string message) : base(error, message) { }
+    }
+
     public partial struct Vec
     {
         public double x;
@@ -531,10 +617,69 @@ namespace My.Company.Common
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate byte InteropDelegate_fn_u8_rval_u8(byte x0);
 
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint InteropDelegate_fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32(uint x0, uint x1, uint x2, uint x3, uint x4, uint x5, uint x6, uint x7);
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate IntPtr InteropDelegate_fn_u64_u64_rval_MutPtrVoid(ulong x0, ulong x1);
+
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void InteropDelegate_fn_CharArray_rval_void(CharArray x0);
     public delegate void InteropDelegate_fn_CharArray_rval_void_native(CharArray.Unmanaged x0);
 
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void InteropDelegate_fn_MutPtrVoid_u64_u64_rval_void(IntPtr x0, ulong x1, ulong x2);
+
+    [StructLayout(LayoutKind.Sequential)]
+    public partial struct Utf8Str
+    {
+        IntPtr _ptr;
+        ulong _len;
+
+        public int Length => (int) _len;
+
+        public unsafe ReadOnlySpan<byte> ReadOnlySpan
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => _ptr == IntPtr.Zero ? ReadOnlySpan<byte>.Empty : new(_ptr.ToPointer(), (int) _len);
+        }
+
+        // The bytes must stay pinned for as long as this value is used.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static Utf8Str From(IntPtr ptr, ulong len)
+        {
+            var rval = new Utf8Str();
+            rval._ptr = ptr;
+            rval._len = len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString() => Encoding.UTF8.GetString(ReadOnlySpan);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._ptr = _ptr;
+            unmanaged._len = _len;
+            return unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _ptr;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal Utf8Str ToManaged()
+            {
+                return Utf8Str.From(_ptr, _len);
+            }
+        }
+    }
+
     public partial class SliceBool
     {
         GCHandle _handle;
@@ -1666,276 +1811,449 @@ namespace My.Company.Common
         }
 
 
-    ///Option that contains Some(value) or None.
-    public partial class OptionOptionResultOptionUtf8StringError
-    {
-        uint _variant;
-        OptionResultOptionUtf8StringError _Some;
-    }
-
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class OptionOptionResultOptionUtf8StringError : IDisposable
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct NdSliceF32
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
-        {
-            internal uint _variant;
-            internal OptionResultOptionUtf8StringError.Unmanaged _Some;
-        }
+        IntPtr _data;
+        ulong _ndim;
+        fixed ulong _shape[4];
+        fixed long _strides[4];
 
+        public int Rank => (int) _ndim;
 
-        public void Dispose()
+        public int Count
         {
-            if (_variant == 0) { _Some.Dispose(); }
+            get
+            {
+                if (_data == IntPtr.Zero) return 0;
+                long count = 1;
+                for (var d = 0; d < Rank; ++d) count *= (long) _shape[d];
+                return (int) count;
+            }
         }
 
-        [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        // True if all elements are laid out row-major without gaps.
+        public bool IsContiguous
         {
-            [FieldOffset(0)]
-            internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
-
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionOptionResultOptionUtf8StringError IntoManaged()
+            get
             {
-                var _managed = new OptionOptionResultOptionUtf8StringError();
-                _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.IntoManaged();
-                return _managed;
+                long expected = 1;
+                for (var d = Rank - 1; d >= 0; --d)
+                {
+                    if (_shape[d] != 1 && _strides[d] != expected) return false;
+                    expected *= (long) _shape[d];
+                }
+                return true;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
-        {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.IntoUnmanaged();
-            return _unmanaged;
-        }
+        public int GetLength(int dimension) => dimension >= 0 && dimension < Rank ? (int) _shape[dimension] : throw new IndexOutOfRangeException();
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged AsUnmanaged()
+        public long GetStride(int dimension) => dimension >= 0 && dimension < Rank ? _strides[dimension] : throw new IndexOutOfRangeException();
+
+        public ReadOnlySpan<float> ReadOnlySpan
         {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.AsUnmanaged();
-            return _unmanaged;
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
         }
 
-        public static OptionOptionResultOptionUtf8StringError Some(OptionResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
-        public static OptionOptionResultOptionUtf8StringError None => new() { _variant = 1 };
-
-        public bool IsSome => _variant == 0;
-        public bool IsNone => _variant == 1;
-
-        public OptionResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        public float this[int i0]
         {
-            if (_variant == 0) return "Some(...)";
-            if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<float>(At(1, i0, 0, 0, 0));
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
+        public float this[int i0, int i1]
         {
-            if (_variant == 0) return new EnumException<OptionResultOptionUtf8StringError>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<float>(At(2, i0, i1, 0, 0));
         }
 
-        [CustomMarshaller(typeof(OptionOptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
-
-        public ref struct Marshaller
+        public float this[int i0, int i1, int i2]
         {
-            private OptionOptionResultOptionUtf8StringError _managed;
-            private Unmanaged _unmanaged;
-
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
+            get => Unsafe.Read<float>(At(3, i0, i1, i2, 0));
+        }
 
+        public float this[int i0, int i1, int i2, int i3]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+            get => Unsafe.Read<float>(At(4, i0, i1, i2, i3));
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
+        void* At(int rank, int i0, int i1, int i2, int i3)
+        {
+            if (rank != Rank) throw new IndexOutOfRangeException();
+            Span<int> index = stackalloc int[] { i0, i1, i2, i3 };
+            long offset = 0;
+            for (var d = 0; d < rank; ++d)
+            {
+                if ((ulong) index[d] >= _shape[d]) throw new IndexOutOfRangeException();
+                offset += index[d] * _strides[d];
+            }
+            return (byte*) _data + offset * Unsafe.SizeOf<float>();
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+        // The data must stay pinned for as long as this value is used.
+        public static NdSliceF32 From(IntPtr data, ReadOnlySpan<ulong> shape)
+        {
+            if (shape.Length > 4) throw new ArgumentException(Dimensions);
+            Span<long> strides = stackalloc long[shape.Length];
+            long stride = 1;
+            for (var d = shape.Length - 1; d >= 0; --d)
+            {
+                strides[d] = stride;
+                stride *= (long) shape[d];
+            }
+            return From(data, shape, strides);
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+        // Strides are counted in elements, not bytes.
+        public static NdSliceF32 From(IntPtr data, ReadOnlySpan<ulong> shape, ReadOnlySpan<long> strides)
+        {
+            if (shape.Length > 4 || shape.Length != strides.Length) throw new ArgumentException(Dimensions);
+            var rval = new NdSliceF32();
+            rval._data = data;
+            rval._ndim = (ulong) shape.Length;
+            for (var d = 0; d < shape.Length; ++d)
+            {
+                rval._shape[d] = shape[d];
+                rval._strides[d] = strides[d];
+            }
+            return rval;
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionOptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+        const string Noncontiguous = "NdSlice is not contiguous.";
+        const string Dimensions = "NdSlice supports up to 4 dimensions, with one stride per dimension.";
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged() => Unsafe.As<NdSliceF32, Unmanaged>(ref this);
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _ndim;
+            public fixed ulong _shape[4];
+            public fixed long _strides[4];
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void Free() {}
+            internal NdSliceF32 ToManaged() => Unsafe.As<Unmanaged, NdSliceF32>(ref this);
         }
     }
 
-    ///Option that contains Some(value) or None.
-    public partial class OptionResultOptionUtf8StringError
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct NdSliceI32
     {
-        uint _variant;
-        ResultOptionUtf8StringError _Some;
-    }
+        IntPtr _data;
+        ulong _ndim;
+        fixed ulong _shape[4];
+        fixed long _strides[4];
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class OptionResultOptionUtf8StringError : IDisposable
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        public int Rank => (int) _ndim;
+
+        public int Count
         {
-            internal uint _variant;
-            internal ResultOptionUtf8StringError.Unmanaged _Some;
+            get
+            {
+                if (_data == IntPtr.Zero) return 0;
+                long count = 1;
+                for (var d = 0; d < Rank; ++d) count *= (long) _shape[d];
+                return (int) count;
+            }
+        }
+
+        // True if all elements are laid out row-major without gaps.
+        public bool IsContiguous
+        {
+            get
+            {
+                long expected = 1;
+                for (var d = Rank - 1; d >= 0; --d)
+                {
+                    if (_shape[d] != 1 && _strides[d] != expected) return false;
+                    expected *= (long) _shape[d];
+                }
+                return true;
+            }
         }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public int GetLength(int dimension) => dimension >= 0 && dimension < Rank ? (int) _shape[dimension] : throw new IndexOutOfRangeException();
 
-        public void Dispose()
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public long GetStride(int dimension) => dimension >= 0 && dimension < Rank ? _strides[dimension] : throw new IndexOutOfRangeException();
+
+        public ReadOnlySpan<int> ReadOnlySpan
         {
-            if (_variant == 0) { _Some.Dispose(); }
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
         }
 
-        [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        public int this[int i0]
         {
-            [FieldOffset(0)]
-            internal uint _variant;
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<int>(At(1, i0, 0, 0, 0));
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+        public int this[int i0, int i1]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<int>(At(2, i0, i1, 0, 0));
+        }
 
+        public int this[int i0, int i1, int i2]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionResultOptionUtf8StringError IntoManaged()
+            get => Unsafe.Read<int>(At(3, i0, i1, i2, 0));
+        }
+
+        public int this[int i0, int i1, int i2, int i3]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<int>(At(4, i0, i1, i2, i3));
+        }
+
+        void* At(int rank, int i0, int i1, int i2, int i3)
+        {
+            if (rank != Rank) throw new IndexOutOfRangeException();
+            Span<int> index = stackalloc int[] { i0, i1, i2, i3 };
+            long offset = 0;
+            for (var d = 0; d < rank; ++d)
             {
-                var _managed = new OptionResultOptionUtf8StringError();
-                _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.IntoManaged();
-                return _managed;
+                if ((ulong) index[d] >= _shape[d]) throw new IndexOutOfRangeException();
+                offset += index[d] * _strides[d];
             }
+            return (byte*) _data + offset * Unsafe.SizeOf<int>();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        // The data must stay pinned for as long as this value is used.
+        public static NdSliceI32 From(IntPtr data, ReadOnlySpan<ulong> shape)
         {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.IntoUnmanaged();
-            return _unmanaged;
+            if (shape.Length > 4) throw new ArgumentException(Dimensions);
+            Span<long> strides = stackalloc long[shape.Length];
+            long stride = 1;
+            for (var d = shape.Length - 1; d >= 0; --d)
+            {
+                strides[d] = stride;
+                stride *= (long) shape[d];
+            }
+            return From(data, shape, strides);
+        }
+
+        // Strides are counted in elements, not bytes.
+        public static NdSliceI32 From(IntPtr data, ReadOnlySpan<ulong> shape, ReadOnlySpan<long> strides)
+        {
+            if (shape.Length > 4 || shape.Length != strides.Length) throw new ArgumentException(Dimensions);
+            var rval = new NdSliceI32();
+            rval._data = data;
+            rval._ndim = (ulong) shape.Length;
+            for (var d = 0; d < shape.Length; ++d)
+            {
+                rval._shape[d] = shape[d];
+                rval._strides[d] = strides[d];
+            }
+            return rval;
         }
 
+        const string Noncontiguous = "NdSlice is not contiguous.";
+        const string Dimensions = "NdSlice supports up to 4 dimensions, with one stride per dimension.";
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged AsUnmanaged()
+        internal Unmanaged ToUnmanaged() => Unsafe.As<NdSliceI32, Unmanaged>(ref this);
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
         {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.AsUnmanaged();
-            return _unmanaged;
+            public IntPtr _data;
+            public ulong _ndim;
+            public fixed ulong _shape[4];
+            public fixed long _strides[4];
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal NdSliceI32 ToManaged() => Unsafe.As<Unmanaged, NdSliceI32>(ref this);
         }
+    }
 
-        public static OptionResultOptionUtf8StringError Some(ResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
-        public static OptionResultOptionUtf8StringError None => new() { _variant = 1 };
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct NdSliceMutU32
+    {
+        IntPtr _data;
+        ulong _ndim;
+        fixed ulong _shape[4];
+        fixed long _strides[4];
 
-        public bool IsSome => _variant == 0;
-        public bool IsNone => _variant == 1;
+        public int Rank => (int) _ndim;
 
-        public ResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public int Count
+        {
+            get
+            {
+                if (_data == IntPtr.Zero) return 0;
+                long count = 1;
+                for (var d = 0; d < Rank; ++d) count *= (long) _shape[d];
+                return (int) count;
+            }
+        }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        // True if all elements are laid out row-major without gaps.
+        public bool IsContiguous
         {
-            if (_variant == 0) return "Some(...)";
-            if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            get
+            {
+                long expected = 1;
+                for (var d = Rank - 1; d >= 0; --d)
+                {
+                    if (_shape[d] != 1 && _strides[d] != expected) return false;
+                    expected *= (long) _shape[d];
+                }
+                return true;
+            }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
+        public int GetLength(int dimension) => dimension >= 0 && dimension < Rank ? (int) _shape[dimension] : throw new IndexOutOfRangeException();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public long GetStride(int dimension) => dimension >= 0 && dimension < Rank ? _strides[dimension] : throw new IndexOutOfRangeException();
+
+        public ReadOnlySpan<uint> ReadOnlySpan
         {
-            if (_variant == 0) return new EnumException<ResultOptionUtf8StringError>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
         }
 
-        [CustomMarshaller(typeof(OptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
-
-        public ref struct Marshaller
+        public Span<uint> Span
         {
-            private OptionResultOptionUtf8StringError _managed;
-            private Unmanaged _unmanaged;
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
+        }
 
+        public uint this[int i0]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionResultOptionUtf8StringError managed) { _managed = managed; }
-
+            get => Unsafe.Read<uint>(At(1, i0, 0, 0, 0));
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+            set => Unsafe.Write(At(1, i0, 0, 0, 0), value);
+        }
 
+        public uint this[int i0, int i1]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionResultOptionUtf8StringError managed) { _managed = managed; }
-
+            get => Unsafe.Read<uint>(At(2, i0, i1, 0, 0));
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+            set => Unsafe.Write(At(2, i0, i1, 0, 0), value);
+        }
 
+        public uint this[int i0, int i1, int i2]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            get => Unsafe.Read<uint>(At(3, i0, i1, i2, 0));
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            set => Unsafe.Write(At(3, i0, i1, i2, 0), value);
+        }
 
+        public uint this[int i0, int i1, int i2, int i3]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            get => Unsafe.Read<uint>(At(4, i0, i1, i2, i3));
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            set => Unsafe.Write(At(4, i0, i1, i2, i3), value);
+        }
+
+        void* At(int rank, int i0, int i1, int i2, int i3)
+        {
+            if (rank != Rank) throw new IndexOutOfRangeException();
+            Span<int> index = stackalloc int[] { i0, i1, i2, i3 };
+            long offset = 0;
+            for (var d = 0; d < rank; ++d)
+            {
+                if ((ulong) index[d] >= _shape[d]) throw new IndexOutOfRangeException();
+                offset += index[d] * _strides[d];
+            }
+            return (byte*) _data + offset * Unsafe.SizeOf<uint>();
+        }
+
+        // The data must stay pinned for as long as this value is used.
+        public static NdSliceMutU32 From(IntPtr data, ReadOnlySpan<ulong> shape)
+        {
+            if (shape.Length > 4) throw new ArgumentException(Dimensions);
+            Span<long> strides = stackalloc long[shape.Length];
+            long stride = 1;
+            for (var d = shape.Length - 1; d >= 0; --d)
+            {
+                strides[d] = stride;
+                stride *= (long) shape[d];
+            }
+            return From(data, shape, strides);
+        }
+
+        // Strides are counted in elements, not bytes.
+        public static NdSliceMutU32 From(IntPtr data, ReadOnlySpan<ulong> shape, ReadOnlySpan<long> strides)
+        {
+            if (shape.Length > 4 || shape.Length != strides.Length) throw new ArgumentException(Dimensions);
+            var rval = new NdSliceMutU32();
+            rval._data = data;
+            rval._ndim = (ulong) shape.Length;
+            for (var d = 0; d < shape.Length; ++d)
+            {
+                rval._shape[d] = shape[d];
+                rval._strides[d] = strides[d];
+            }
+            return rval;
+        }
+
+        const string Noncontiguous = "NdSlice is not contiguous.";
+        const string Dimensions = "NdSlice supports up to 4 dimensions, with one stride per dimension.";
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged() => Unsafe.As<NdSliceMutU32, Unmanaged>(ref this);
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _ndim;
+            public fixed ulong _shape[4];
+            public fixed long _strides[4];
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void Free() {}
+            internal NdSliceMutU32 ToManaged() => Unsafe.As<Unmanaged, NdSliceMutU32>(ref this);
         }
     }
 
     ///Option that contains Some(value) or None.
-    public partial class OptionUtf8String
+    public partial class OptionOptionResultOptionUtf8StringError
     {
         uint _variant;
-        Utf8String _Some;
+        OptionResultOptionUtf8StringError _Some;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class OptionUtf8String : IDisposable
+    public partial class OptionOptionResultOptionUtf8StringError : IDisposable
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
-            internal Utf8String.Unmanaged _Some;
+            [FieldOffset(0)]
+            internal OptionResultOptionUtf8StringError.Unmanaged _Some;
         }
 
-
         public void Dispose()
         {
             if (_variant == 0) { _Some.Dispose(); }
         }
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionUtf8String IntoManaged()
+            internal OptionOptionResultOptionUtf8StringError IntoManaged()
             {
-                var _managed = new OptionUtf8String();
+                var _managed = new OptionOptionResultOptionUtf8StringError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.IntoManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.IntoManaged();
                 return _managed;
             }
         }
@@ -1945,7 +2263,7 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.IntoUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -1954,51 +2272,43 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.AsUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.AsUnmanaged();
             return _unmanaged;
         }
 
-        public static OptionUtf8String Some(Utf8String value) => new() { _variant = 0, _Some = value };
-        public static OptionUtf8String None => new() { _variant = 1 };
+        public static OptionOptionResultOptionUtf8StringError Some(OptionResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
+        public static OptionOptionResultOptionUtf8StringError None => new() { _variant = 1 };
 
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public Utf8String AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public OptionResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Some(...)";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Utf8String>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(OptionUtf8String), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionOptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private OptionUtf8String _managed;
+            private OptionOptionResultOptionUtf8StringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionUtf8String managed) { _managed = managed; }
+            public Marshaller(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionUtf8String managed) { _managed = managed; }
+            public void FromManaged(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -2007,57 +2317,96 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
+            public OptionOptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Option that contains Some(value) or None.
-    public partial struct OptionVec
+    #nullable enable
+    public partial class OptionOptionResultOptionUtf8StringError
     {
-        uint _variant;
-        Vec _Some;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionOptionResultOptionUtf8StringError(OptionResultOptionUtf8StringError? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct OptionVec 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionResultOptionUtf8StringError?(OptionOptionResultOptionUtf8StringError value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionOptionResultOptionUtf8StringError FromNullable(OptionResultOptionUtf8StringError? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionResultOptionUtf8StringError? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionResultOptionUtf8StringError GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionResultOptionUtf8StringError GetValueOrDefault(OptionResultOptionUtf8StringError defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out OptionResultOptionUtf8StringError value)
         {
-            internal uint _variant;
-            internal Vec.Unmanaged _Some;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
+        public TResult Match<TResult>(Func<OptionResultOptionUtf8StringError, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
 
-
-        [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        public void Match(Action<OptionResultOptionUtf8StringError> some, Action none)
         {
-            [FieldOffset(0)]
-            internal uint _variant;
+            if (IsSome) { some(_Some); } else { none(); }
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+        public OptionOptionResultOptionUtf8StringError Map(Func<OptionResultOptionUtf8StringError, OptionResultOptionUtf8StringError> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
+    ///Option that contains Some(value) or None.
+    public partial class OptionResultOptionUtf8StringError
+    {
+        uint _variant;
+        ResultOptionUtf8StringError _Some;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OptionResultOptionUtf8StringError : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal ResultOptionUtf8StringError.Unmanaged _Some;
+        }
+
+        public void Dispose()
+        {
+            if (_variant == 0) { _Some.Dispose(); }
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionVec ToManaged()
+            internal OptionResultOptionUtf8StringError IntoManaged()
             {
-                var _managed = new OptionVec();
+                var _managed = new OptionResultOptionUtf8StringError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.IntoManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        internal Unmanaged IntoUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -2066,102 +2415,127 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.AsUnmanaged();
             return _unmanaged;
         }
 
-        public static OptionVec Some(Vec value) => new() { _variant = 0, _Some = value };
-        public static OptionVec None => new() { _variant = 1 };
+        public static OptionResultOptionUtf8StringError Some(ResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
+        public static OptionResultOptionUtf8StringError None => new() { _variant = 1 };
 
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public Vec AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public ResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Some(...)";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Vec>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(OptionVec), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private OptionVec _managed;
+            private OptionResultOptionUtf8StringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionVec managed) { _managed = managed; }
+            public Marshaller(OptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionVec managed) { _managed = managed; }
+            public void FromManaged(OptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionVec ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial struct ResultError
+    #nullable enable
+    public partial class OptionResultOptionUtf8StringError
     {
-        uint _variant;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionResultOptionUtf8StringError(ResultOptionUtf8StringError? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultError 
-    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator ResultOptionUtf8StringError?(OptionResultOptionUtf8StringError value) => value.IsSome ? value._Some : null;
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionResultOptionUtf8StringError FromNullable(ResultOptionUtf8StringError? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ResultOptionUtf8StringError? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ResultOptionUtf8StringError GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ResultOptionUtf8StringError GetValueOrDefault(ResultOptionUtf8StringError defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out ResultOptionUtf8StringError value)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
+        public TResult Match<TResult>(Func<ResultOptionUtf8StringError, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<ResultOptionUtf8StringError> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
+        }
 
+        public OptionResultOptionUtf8StringError Map(Func<ResultOptionUtf8StringError, ResultOptionUtf8StringError> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
 
+    ///Option that contains Some(value) or None.
+    public partial struct OptionTuple2_U32_U32
+    {
+        uint _variant;
+        Tuple2_U32_U32 _Some;
+    }
 
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionTuple2_U32_U32 
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal Tuple2_U32_U32.Unmanaged _Some;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultError ToManaged()
+            internal OptionTuple2_U32_U32 ToManaged()
             {
-                var _managed = new ResultError();
+                var _managed = new OptionTuple2_U32_U32();
                 _managed._variant = _variant;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -2171,7 +2545,7 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -2180,61 +2554,43 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultError Ok => new() { _variant = 0 };
-        public static ResultError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultError Panic => new() { _variant = 2 };
-        public static ResultError Null => new() { _variant = 3 };
+        public static OptionTuple2_U32_U32 Some(Tuple2_U32_U32 value) => new() { _variant = 0, _Some = value };
+        public static OptionTuple2_U32_U32 None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public void AsOk() { if (_variant != 0) throw ExceptionForVariant(); }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Tuple2_U32_U32 AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionTuple2_U32_U32), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultError _managed;
+            private OptionTuple2_U32_U32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultError managed) { _managed = managed; }
+            public Marshaller(OptionTuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultError managed) { _managed = managed; }
+            public void FromManaged(OptionTuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -2243,64 +2599,86 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultError ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionTuple2_U32_U32 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial class ResultOptionUtf8StringError
+    #nullable enable
+    public partial struct OptionTuple2_U32_U32
     {
-        uint _variant;
-        OptionUtf8String _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionTuple2_U32_U32(Tuple2_U32_U32? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultOptionUtf8StringError : IDisposable
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple2_U32_U32?(OptionTuple2_U32_U32 value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionTuple2_U32_U32 FromNullable(Tuple2_U32_U32? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Tuple2_U32_U32? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Tuple2_U32_U32 GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Tuple2_U32_U32 GetValueOrDefault(Tuple2_U32_U32 defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Tuple2_U32_U32 value)
         {
-            internal uint _variant;
-            internal OptionUtf8String.Unmanaged _Ok;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        public TResult Match<TResult>(Func<Tuple2_U32_U32, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Tuple2_U32_U32> some, Action none)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionTuple2_U32_U32 Map(Func<Tuple2_U32_U32, Tuple2_U32_U32> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
+    ///Option that contains Some(value) or None.
+    public partial class OptionUtf8String
+    {
+        uint _variant;
+        Utf8String _Some;
+    }
 
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OptionUtf8String : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal Utf8String.Unmanaged _Some;
+        }
 
         public void Dispose()
         {
-            if (_variant == 0) { _Ok.Dispose(); }
+            if (_variant == 0) { _Some.Dispose(); }
         }
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultOptionUtf8StringError IntoManaged()
+            internal OptionUtf8String IntoManaged()
             {
-                var _managed = new ResultOptionUtf8StringError();
+                var _managed = new OptionUtf8String();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.IntoManaged();
                 return _managed;
             }
         }
@@ -2310,8 +2688,7 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -2320,62 +2697,43 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.AsUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultOptionUtf8StringError Ok(OptionUtf8String value) => new() { _variant = 0, _Ok = value };
-        public static ResultOptionUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultOptionUtf8StringError Panic => new() { _variant = 2 };
-        public static ResultOptionUtf8StringError Null => new() { _variant = 3 };
+        public static OptionUtf8String Some(Utf8String value) => new() { _variant = 0, _Some = value };
+        public static OptionUtf8String None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public OptionUtf8String AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Utf8String AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<OptionUtf8String>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionUtf8String), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultOptionUtf8StringError _managed;
+            private OptionUtf8String _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultOptionUtf8StringError managed) { _managed = managed; }
+            public Marshaller(OptionUtf8String managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultOptionUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(OptionUtf8String managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -2384,60 +2742,82 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public OptionUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial struct ResultU32Error
+    #nullable enable
+    public partial class OptionUtf8String
     {
-        uint _variant;
-        uint _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionUtf8String(Utf8String? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultU32Error 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Utf8String?(OptionUtf8String value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionUtf8String FromNullable(Utf8String? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String GetValueOrDefault(Utf8String defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Utf8String value)
         {
-            internal uint _variant;
-            internal uint _Ok;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        public TResult Match<TResult>(Func<Utf8String, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Utf8String> some, Action none)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionUtf8String Map(Func<Utf8String, Utf8String> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
 
+    ///Option that contains Some(value) or None.
+    public partial struct OptionVec
+    {
+        uint _variant;
+        Vec _Some;
+    }
 
-
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionVec 
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal Vec.Unmanaged _Some;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultU32Error ToManaged()
+            internal OptionVec ToManaged()
             {
-                var _managed = new ResultU32Error();
+                var _managed = new OptionVec();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -2447,8 +2827,7 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -2457,62 +2836,43 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultU32Error Ok(uint value) => new() { _variant = 0, _Ok = value };
-        public static ResultU32Error Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultU32Error Panic => new() { _variant = 2 };
-        public static ResultU32Error Null => new() { _variant = 3 };
+        public static OptionVec Some(Vec value) => new() { _variant = 0, _Some = value };
+        public static OptionVec None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public uint AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Vec AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<uint>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultU32Error), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionVec), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultU32Error _managed;
+            private OptionVec _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultU32Error managed) { _managed = managed; }
+            public Marshaller(OptionVec managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultU32Error managed) { _managed = managed; }
+            public void FromManaged(OptionVec managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -2521,60 +2881,82 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultU32Error ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionVec ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct OptionVec
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionVec(Vec? value) => value is { } x ? Some(x) : None;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Vec?(OptionVec value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionVec FromNullable(Vec? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec GetValueOrDefault(Vec defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Vec value)
+        {
+            value = GetValueOrDefault();
+            return IsSome;
+        }
+
+        public TResult Match<TResult>(Func<Vec, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Vec> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
+        }
+
+        public OptionVec Map(Func<Vec, Vec> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultU64Error
+    public partial struct ResultError
     {
         uint _variant;
-        ulong _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultU64Error 
+    public partial struct ResultError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal ulong _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultU64Error ToManaged()
+            internal ResultError ToManaged()
             {
-                var _managed = new ResultU64Error();
+                var _managed = new ResultError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -2584,8 +2966,7 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -2594,25 +2975,27 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultU64Error Ok(ulong value) => new() { _variant = 0, _Ok = value };
-        public static ResultU64Error Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultU64Error Panic => new() { _variant = 2 };
-        public static ResultU64Error Null => new() { _variant = 3 };
+        public static ResultError Ok => new() { _variant = 0 };
+        public static ResultError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultError Panic => new() { _variant = 2 };
+        public static ResultError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public ulong AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public void AsOk() { if (_variant != 0) throw ToException(); }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -2621,35 +3004,25 @@ namespace My.Company.Common
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<ulong>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultU64Error), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultU64Error _managed;
+            private ResultError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultU64Error managed) { _managed = managed; }
+            public Marshaller(ResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultU64Error managed) { _managed = managed; }
+            public void FromManaged(ResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -2658,64 +3031,61 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultU64Error ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Unwrap() => AsOk();
+
+        public TResult Match<TResult>(Func<TResult> ok, Func<Error, TResult> err) => IsOk ? ok() : IsErr ? err(_Err) : throw ToException();
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial class ResultUtf8StringError
+    public partial class ResultOptionUtf8StringError
     {
         uint _variant;
-        Utf8String _Ok;
+        OptionUtf8String _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultUtf8StringError : IDisposable
+    public partial class ResultOptionUtf8StringError : IDisposable
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal Utf8String.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal OptionUtf8String.Unmanaged _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
-
-
         public void Dispose()
         {
             if (_variant == 0) { _Ok.Dispose(); }
         }
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultUtf8StringError IntoManaged()
+            internal ResultOptionUtf8StringError IntoManaged()
             {
-                var _managed = new ResultUtf8StringError();
+                var _managed = new ResultOptionUtf8StringError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -2725,8 +3095,8 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -2735,25 +3105,28 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultUtf8StringError Ok(Utf8String value) => new() { _variant = 0, _Ok = value };
-        public static ResultUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultUtf8StringError Panic => new() { _variant = 2 };
-        public static ResultUtf8StringError Null => new() { _variant = 3 };
+        public static ResultOptionUtf8StringError Ok(OptionUtf8String value) => new() { _variant = 0, _Ok = value };
+        public static ResultOptionUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultOptionUtf8StringError Panic => new() { _variant = 2 };
+        public static ResultOptionUtf8StringError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public Utf8String AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public OptionUtf8String AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -2762,35 +3135,25 @@ namespace My.Company.Common
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Utf8String>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultUtf8StringError _managed;
+            private ResultOptionUtf8StringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultUtf8StringError managed) { _managed = managed; }
+            public Marshaller(ResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(ResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -2799,75 +3162,81 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public ResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial class ResultVecUtf8StringError
+    #nullable enable
+    public partial class ResultOptionUtf8StringError
     {
-        uint _variant;
-        VecUtf8String _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionUtf8String Unwrap() => AsOk();
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultVecUtf8StringError : IDisposable
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal VecUtf8String.Unmanaged _Ok;
-        }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionUtf8String UnwrapOr(OptionUtf8String fallback) => IsOk ? _Ok : fallback;
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out OptionUtf8String value)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
 
+        public TResult Match<TResult>(Func<OptionUtf8String, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
 
-
-        public void Dispose()
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out OptionUtf8String? value, out Error? error)
         {
-            if (_variant == 0) { _Ok.Dispose(); }
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
         }
+    }
+    #nullable restore
 
+    ///Result that contains value or an error.
+    public partial struct ResultSyncError
+    {
+        uint _variant;
+        SyncError _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultSyncError 
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal SyncError.Unmanaged _Err;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultVecUtf8StringError IntoManaged()
+            internal ResultSyncError ToManaged()
             {
-                var _managed = new ResultVecUtf8StringError();
+                var _managed = new ResultSyncError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -2876,25 +3245,27 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultVecUtf8StringError Ok(VecUtf8String value) => new() { _variant = 0, _Ok = value };
-        public static ResultVecUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultVecUtf8StringError Panic => new() { _variant = 2 };
-        public static ResultVecUtf8StringError Null => new() { _variant = 3 };
+        public static ResultSyncError Ok => new() { _variant = 0 };
+        public static ResultSyncError Err(SyncError value) => new() { _variant = 1, _Err = value };
+        public static ResultSyncError Panic => new() { _variant = 2 };
+        public static ResultSyncError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public VecUtf8String AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? SyncErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public void AsOk() { if (_variant != 0) throw ToException(); }
+        public SyncError AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -2903,75 +3274,84 @@ namespace My.Company.Common
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<VecUtf8String>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultVecUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultSyncError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultVecUtf8StringError _managed;
+            private ResultSyncError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultVecUtf8StringError managed) { _managed = managed; }
+            public Marshaller(ResultSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultVecUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(ResultSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultVecUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public ResultSyncError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultSyncError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Unwrap() => AsOk();
+
+        public TResult Match<TResult>(Func<TResult> ok, Func<SyncError, TResult> err) => IsOk ? ok() : IsErr ? err(_Err) : throw ToException();
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultVoid
+    public partial struct ResultU32Error
     {
         uint _variant;
+        uint _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultVoid 
+    public partial struct ResultU32Error 
     {
-
-
-
-
-
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
-
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultVoid ToManaged()
+            internal uint _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
+        }
+
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultU32Error ToManaged()
             {
-                var _managed = new ResultVoid();
+                var _managed = new ResultU32Error();
                 _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -2981,6 +3361,8 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -2989,60 +3371,55 @@ namespace My.Company.Common
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultVoid Ok => new() { _variant = 0 };
-        public static ResultVoid Err => new() { _variant = 1 };
-        public static ResultVoid Panic => new() { _variant = 2 };
-        public static ResultVoid Null => new() { _variant = 3 };
+        public static ResultU32Error Ok(uint value) => new() { _variant = 0, _Ok = value };
+        public static ResultU32Error Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultU32Error Panic => new() { _variant = 2 };
+        public static ResultU32Error Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public void AsOk() { if (_variant != 0) throw ExceptionForVariant(); }
-        public void AsErr() { if (_variant != 1) throw ExceptionForVariant(); }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public uint AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return "Err(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException();
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultVoid), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultU32Error), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultVoid _managed;
+            private ResultU32Error _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultVoid managed) { _managed = managed; }
+            public Marshaller(ResultU32Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultVoid managed) { _managed = managed; }
+            public void FromManaged(ResultU32Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -3051,131 +3428,143 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultVoid ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultU32Error ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [Serializable]
-    [StructLayout(LayoutKind.Sequential)]
-    public partial struct Bool
+    #nullable enable
+    public partial struct ResultU32Error
     {
-        byte value;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Unwrap() => AsOk();
 
-    public partial struct Bool
-    {
-        public static readonly Bool True = new Bool { value =  1 };
-        public static readonly Bool False = new Bool { value =  0 };
-        public Bool(bool b)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint UnwrapOr(uint fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out uint value)
         {
-            value = (byte) (b ? 1 : 0);
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
-        public bool Is => value == 1;
-    }
 
+        public TResult Match<TResult>(Func<uint, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate uint MyCallbackNamespacedNative(uint value, IntPtr callback_data); // 'True' native callback signature
-    public delegate uint MyCallbackNamespacedDelegate(uint value); // Our C# signature
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out uint? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
 
-    public partial class MyCallbackNamespaced
+    ///Result that contains value or an error.
+    public partial struct ResultU64Error
     {
-        private MyCallbackNamespacedDelegate _managed; // C# callback
-        private MyCallbackNamespacedNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        ulong _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallbackNamespaced : IDisposable
+    public partial struct ResultU64Error 
     {
-
-        internal MyCallbackNamespaced() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallbackNamespaced(MyCallbackNamespacedDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal ulong _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private uint CallTrampoline(uint value, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(value);
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultU64Error ToManaged()
             {
-                _exception = e;
-                return default;
+                var _managed = new ResultU64Error();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint Call(uint value)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNamespacedNative>(_ptr);
-            return __target(value, IntPtr.Zero);
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultU64Error Ok(ulong value) => new() { _variant = 0, _Ok = value };
+        public static ResultU64Error Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultU64Error Panic => new() { _variant = 2 };
+        public static ResultU64Error Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(MyCallbackNamespaced), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public MyCallbackNamespaced ToManaged()
-            {
-                var rval = new MyCallbackNamespaced();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public ulong AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultU64Error), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private MyCallbackNamespaced _managed;
+            private ResultU64Error _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallbackNamespaced managed) { _managed = managed; }
+            public Marshaller(ResultU64Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallbackNamespaced managed) { _managed = managed; }
+            public void FromManaged(ResultU64Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -3184,354 +3573,1578 @@ namespace My.Company.Common
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallbackNamespaced ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultU64Error ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    // This must be a class because we only ever want to hold on to the
-    // same instance, as we overwrite fields when this is sent over the FFI
-    // boundary
-    public partial class VecU8
+    #nullable enable
+    public partial struct ResultU64Error
     {
-        internal IntPtr _ptr;
-        internal ulong _len;
-        internal ulong _capacity;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ulong Unwrap() => AsOk();
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecU8 : IDisposable
-    {
-        // An internal helper to create an empty object.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecU8() { }
+        public ulong UnwrapOr(ulong fallback) => IsOk ? _Ok : fallback;
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecU8 From(Span<byte> _data)
+        public bool TryGet(out ulong value)
         {
-            var rval = new VecU8();
-            fixed (void* _data_ptr = _data)
-            {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                rval._len = _out._len;
-                rval._capacity = _out._capacity;
-                rval._ptr = _out._ptr;
-            }
-            return rval;
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecU8 Empty()
+        public TResult Match<TResult>(Func<ulong, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out ulong? value, out Error? error)
         {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
-            return _out.IntoManaged();
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
         }
+    }
+    #nullable restore
 
-        public int Count
+    ///Result that contains value or an error.
+    public partial class ResultUtf8StringError
+    {
+        uint _variant;
+        Utf8String _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class ResultUtf8StringError : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
+            [FieldOffset(0)]
+            internal Utf8String.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        public unsafe byte this[int i]
+        public void Dispose()
         {
+            if (_variant == 0) { _Ok.Dispose(); }
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
+            internal ResultUtf8StringError IntoManaged()
             {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                return Marshal.PtrToStructure<byte>(new IntPtr(_ptr.ToInt64() + i * sizeof(byte)));
+                var _managed = new ResultUtf8StringError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged IntoUnmanaged()
+        internal Unmanaged IntoUnmanaged()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            _ptr = IntPtr.Zero; // Mark this instance as moved.
-            return rval;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged AsUnmanaged()
+        internal Unmanaged AsUnmanaged()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultUtf8StringError Ok(Utf8String value) => new() { _variant = 0, _Ok = value };
+        public static ResultUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultUtf8StringError Panic => new() { _variant = 2 };
+        public static ResultUtf8StringError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public Utf8String AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultUtf8StringError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultUtf8StringError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultUtf8StringError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    #nullable enable
+    public partial class ResultUtf8StringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String UnwrapOr(Utf8String fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out Utf8String value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<Utf8String, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out Utf8String? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial class ResultVecUtf8StringError
+    {
+        uint _variant;
+        VecUtf8String _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class ResultVecUtf8StringError : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal VecUtf8String.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
+        }
+
+        public void Dispose()
+        {
+            if (_variant == 0) { _Ok.Dispose(); }
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultVecUtf8StringError IntoManaged()
+            {
+                var _managed = new ResultVecUtf8StringError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged IntoUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultVecUtf8StringError Ok(VecUtf8String value) => new() { _variant = 0, _Ok = value };
+        public static ResultVecUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultVecUtf8StringError Panic => new() { _variant = 2 };
+        public static ResultVecUtf8StringError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public VecUtf8String AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultVecUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultVecUtf8StringError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultVecUtf8StringError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultVecUtf8StringError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultVecUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    #nullable enable
+    public partial class ResultVecUtf8StringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public VecUtf8String Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public VecUtf8String UnwrapOr(VecUtf8String fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out VecUtf8String value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<VecUtf8String, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out VecUtf8String? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultVoid
+    {
+        uint _variant;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultVoid 
+    {
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultVoid ToManaged()
+            {
+                var _managed = new ResultVoid();
+                _managed._variant = _variant;
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        public static ResultVoid Ok => new() { _variant = 0 };
+        public static ResultVoid Err => new() { _variant = 1 };
+        public static ResultVoid Panic => new() { _variant = 2 };
+        public static ResultVoid Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => InteropException.FromLastError(ToString());
+
+        public void AsOk() { if (_variant != 0) throw ToException(); }
+        public void AsErr() { if (_variant != 1) throw new InteropException(); }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return "Err(...)";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultVoid), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultVoid _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultVoid managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultVoid managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultVoid ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+
+    [Serializable]
+    [StructLayout(LayoutKind.Sequential)]
+    public partial struct Bool
+    {
+        byte value;
+    }
+
+    public partial struct Bool
+    {
+        public static readonly Bool True = new Bool { value =  1 };
+        public static readonly Bool False = new Bool { value =  0 };
+        public Bool(bool b)
+        {
+            value = (byte) (b ? 1 : 0);
+        }
+        public bool Is => value == 1;
+    }
+
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint MyCallbackNamespacedNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate uint MyCallbackNamespacedDelegate(uint value); // Our C# signature
+
+    public partial class MyCallbackNamespaced
+    {
+        private MyCallbackNamespacedDelegate _managed; // C# callback
+        private MyCallbackNamespacedNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyCallbackNamespaced : IDisposable
+    {
+
+        internal MyCallbackNamespaced() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public MyCallbackNamespaced(MyCallbackNamespacedDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private uint CallTrampoline(uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal uint Call(uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNamespacedNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(MyCallbackNamespaced), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public MyCallbackNamespaced ToManaged()
+            {
+                var rval = new MyCallbackNamespaced();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyCallbackNamespaced _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyCallbackNamespaced managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyCallbackNamespaced managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyCallbackNamespaced ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecU8
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecU8 : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecU8() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecU8 From(Span<byte> _data)
+        {
+            var rval = new VecU8();
+            fixed (void* _data_ptr = _data)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                rval._len = _out._len;
+                rval._capacity = _out._capacity;
+                rval._ptr = _out._ptr;
+            }
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecU8 Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe byte this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                return Marshal.PtrToStructure<byte>(new IntPtr(_ptr.ToInt64() + i * sizeof(byte)));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecU8 { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_6849152863081469284")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_9353420175730321243")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecU8), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecU8 IntoManaged()
+            {
+                var rval = new VecU8();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecU8 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecU8 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public static class VecU8Extensions
+    {
+        public static VecU8 Vec(this byte[] s) { return VecU8.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecUtf8String
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecUtf8String : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecUtf8String() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecUtf8String From(Span<Utf8String> _data)
+        {
+            var _temp = new Utf8String.Unmanaged[_data.Length];
+            for (var i = 0; i < _data.Length; ++i)
+            {
+                _temp[i] = _data[i].IntoUnmanaged();
+            }
+            fixed (void* _data_ptr = _temp)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                return _out.IntoManaged();
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecUtf8String Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe Utf8String this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                var _element = Marshal.PtrToStructure<Utf8String.Unmanaged>(new IntPtr(_ptr.ToInt64() + i * sizeof(Utf8String.Unmanaged)));
+                return _element.IntoManaged();
+            }
+        }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecUtf8String { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_17163327104750939811")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_13145557392013674812")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecUtf8String), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecUtf8String IntoManaged()
+            {
+                var rval = new VecUtf8String();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecUtf8String _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecUtf8String managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecUtf8String managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+
+    }
+
+    public static class VecUtf8StringExtensions
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static VecUtf8String IntoVec(this Utf8String[] s) { return VecUtf8String.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class IterU32
+    {
+        internal IntPtr _ptr;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class IterU32 : IEnumerable<uint>, IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private IterU32() { }
+
+        // Advances the iterator, returns false once it is exhausted.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryNext(out uint item)
+        {
+            var _unmanaged = AsUnmanaged();
+            return InteropHelper.interoptopus_iter_next(ref _unmanaged, out item) != 0;
+        }
+
+        // Items can only be enumerated once, the handle is disposed afterwards.
+        public IEnumerator<uint> GetEnumerator()
+        {
+            try
+            {
+                while (TryNext(out var item)) { yield return item; }
+            }
+            finally
+            {
+                Dispose();
+            }
+        }
+
+        IEnumerator IEnumerable.GetEnumerator() { return GetEnumerator(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
             return rval;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
-        {
-            if (_ptr == IntPtr.Zero) return;
-            var _unmanaged = new Unmanaged();
-            _unmanaged._ptr = _ptr;
-            _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
-            _ptr = IntPtr.Zero;
-            _len = 0;
-            _capacity = 0;
-        }
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            InteropHelper.interoptopus_iter_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "IterU32 { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_next_14415398661037898230")]
+            internal static partial long interoptopus_iter_next(ref Unmanaged iter, out uint rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_destroy_11887687051161408401")]
+            internal static partial long interoptopus_iter_destroy(Unmanaged iter);
+        }
+
+        [CustomMarshaller(typeof(IterU32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public IterU32 IntoManaged()
+            {
+                var rval = new IterU32();
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private IterU32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(IterU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(IterU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public IterU32 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_F32_U32
+    {
+        public float x0;
+        public uint x1;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuple2_F32_U32 
+    {
+        public Tuple2_F32_U32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public float x0;
+            public uint x1;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Tuple2_F32_U32 ToManaged()
+            {
+                var _managed = new Tuple2_F32_U32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tuple2_F32_U32 { ... }";
+        }
+
+        [CustomMarshaller(typeof(Tuple2_F32_U32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Tuple2_F32_U32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuple2_F32_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuple2_F32_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuple2_F32_U32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct Tuple2_F32_U32
+    {
+        public Tuple2_F32_U32(float x0, uint x1) { this.x0 = x0; this.x1 = x1; }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple2_F32_U32((float, uint) value) => new(value.Item1, value.Item2);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (float, uint)(Tuple2_F32_U32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (float, uint) ToValueTuple() => (x0, x1);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out float _x0, out uint _x1)
+        {
+            _x0 = x0;
+            _x1 = x1;
+        }
+    }
+
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_U32_F32
+    {
+        public uint x0;
+        public float x1;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuple2_U32_F32 
+    {
+        public Tuple2_U32_F32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public uint x0;
+            public float x1;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Tuple2_U32_F32 ToManaged()
+            {
+                var _managed = new Tuple2_U32_F32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tuple2_U32_F32 { ... }";
+        }
+
+        [CustomMarshaller(typeof(Tuple2_U32_F32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Tuple2_U32_F32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuple2_U32_F32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuple2_U32_F32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuple2_U32_F32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct Tuple2_U32_F32
+    {
+        public Tuple2_U32_F32(uint x0, float x1) { this.x0 = x0; this.x1 = x1; }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple2_U32_F32((uint, float) value) => new(value.Item1, value.Item2);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (uint, float)(Tuple2_U32_F32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (uint, float) ToValueTuple() => (x0, x1);
 
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        public void Deconstruct(out uint _x0, out float _x1)
         {
-            return "VecU8 { ... }";
+            _x0 = x0;
+            _x1 = x1;
         }
+    }
 
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_U32_U32
+    {
+        public uint x0;
+        public uint x1;
+    }
 
-        public partial class InteropHelper
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuple2_U32_U32 
+    {
+        public Tuple2_U32_U32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_6849152863081469284")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_9353420175730321243")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
-        [CustomMarshaller(typeof(VecU8), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
+        }
 
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _ptr;
-            internal ulong _len;
-            internal ulong _capacity;
+            public uint x0;
+            public uint x1;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecU8 IntoManaged()
+            internal unsafe Tuple2_U32_U32 ToManaged()
             {
-                var rval = new VecU8();
-                rval._len = _len;
-                rval._capacity = _capacity;
-                rval._ptr = _ptr;
-                return rval;
+                var _managed = new Tuple2_U32_U32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
             }
+        }
 
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tuple2_U32_U32 { ... }";
         }
 
+        [CustomMarshaller(typeof(Tuple2_U32_U32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private VecU8 _managed;
+            private Tuple2_U32_U32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecU8 managed) { _managed = managed; }
+            public Marshaller(Tuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecU8 managed) { _managed = managed; }
+            public void FromManaged(Tuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecU8 ToManaged() { return _unmanaged.IntoManaged(); }
+            public Tuple2_U32_U32 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public static class VecU8Extensions
+    public partial struct Tuple2_U32_U32
     {
-        public static VecU8 Vec(this byte[] s) { return VecU8.From(s); }
-    }
+        public Tuple2_U32_U32(uint x0, uint x1) { this.x0 = x0; this.x1 = x1; }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple2_U32_U32((uint, uint) value) => new(value.Item1, value.Item2);
 
-    // This must be a class because we only ever want to hold on to the
-    // same instance, as we overwrite fields when this is sent over the FFI
-    // boundary
-    public partial class VecUtf8String
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (uint, uint)(Tuple2_U32_U32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (uint, uint) ToValueTuple() => (x0, x1);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out uint _x0, out uint _x1)
+        {
+            _x0 = x0;
+            _x1 = x1;
+        }
+    }
+
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_U64_U32
     {
-        internal IntPtr _ptr;
-        internal ulong _len;
-        internal ulong _capacity;
+        public ulong x0;
+        public uint x1;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecUtf8String : IDisposable
+    public partial struct Tuple2_U64_U32 
     {
-        // An internal helper to create an empty object.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecUtf8String() { }
+        public Tuple2_U64_U32() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecUtf8String From(Span<Utf8String> _data)
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            var _temp = new Utf8String.Unmanaged[_data.Length];
-            for (var i = 0; i < _data.Length; ++i)
-            {
-                _temp[i] = _data[i].IntoUnmanaged();
-            }
-            fixed (void* _data_ptr = _temp)
-            {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                return _out.IntoManaged();
-            }
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecUtf8String Empty()
+        internal unsafe Unmanaged AsUnmanaged()
         {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
-            return _out.IntoManaged();
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
-        public int Count
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
-        }
+            public ulong x0;
+            public uint x1;
 
-        public unsafe Utf8String this[int i]
-        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
+            internal unsafe Tuple2_U64_U32 ToManaged()
             {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                var _element = Marshal.PtrToStructure<Utf8String.Unmanaged>(new IntPtr(_ptr.ToInt64() + i * sizeof(Utf8String.Unmanaged)));
-                return _element.IntoManaged();
+                var _managed = new Tuple2_U64_U32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
             }
         }
+
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged IntoUnmanaged()
+        public override string ToString()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            _ptr = IntPtr.Zero; // Mark this instance as moved.
-            return rval;
+            return "Tuple2_U64_U32 { ... }";
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged AsUnmanaged()
+        [CustomMarshaller(typeof(Tuple2_U64_U32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            return rval;
+            private Tuple2_U64_U32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuple2_U64_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuple2_U64_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuple2_U64_U32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
+    }
+
+    public partial struct Tuple2_U64_U32
+    {
+        public Tuple2_U64_U32(ulong x0, uint x1) { this.x0 = x0; this.x1 = x1; }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
-        {
-            if (_ptr == IntPtr.Zero) return;
-            var _unmanaged = new Unmanaged();
-            _unmanaged._ptr = _ptr;
-            _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
-            _ptr = IntPtr.Zero;
-            _len = 0;
-            _capacity = 0;
-        }
+        public static implicit operator Tuple2_U64_U32((ulong, uint) value) => new(value.Item1, value.Item2);
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        public static implicit operator (ulong, uint)(Tuple2_U64_U32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (ulong, uint) ToValueTuple() => (x0, x1);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out ulong _x0, out uint _x1)
         {
-            return "VecUtf8String { ... }";
+            _x0 = x0;
+            _x1 = x1;
         }
+    }
 
+    /// A span of time, `nanos` is always below one second.
+    public partial struct Duration
+    {
+        public ulong secs;
+        public uint nanos;
+    }
 
-        public partial class InteropHelper
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Duration 
+    {
+        public Duration() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_17163327104750939811")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_13145557392013674812")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+            var _unmanaged = new Unmanaged();
+            _unmanaged.secs = secs;
+            _unmanaged.nanos = nanos;
+            return _unmanaged;
         }
 
-        [CustomMarshaller(typeof(VecUtf8String), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.secs = secs;
+            _unmanaged.nanos = nanos;
+            return _unmanaged;
+        }
 
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _ptr;
-            internal ulong _len;
-            internal ulong _capacity;
+            public ulong secs;
+            public uint nanos;
+
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecUtf8String IntoManaged()
+            internal unsafe Duration ToManaged()
             {
-                var rval = new VecUtf8String();
-                rval._len = _len;
-                rval._capacity = _capacity;
-                rval._ptr = _ptr;
-                return rval;
+                var _managed = new Duration();
+                _managed.secs = secs;
+                _managed.nanos = nanos;
+                return _managed;
             }
+        }
 
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Duration { ... }";
         }
 
+        [CustomMarshaller(typeof(Duration), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private VecUtf8String _managed;
+            private Duration _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecUtf8String managed) { _managed = managed; }
+            public Marshaller(Duration managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecUtf8String managed) { _managed = managed; }
+            public void FromManaged(Duration managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
+            public Duration ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
-
     }
 
-    public static class VecUtf8StringExtensions
+    public partial struct Duration
     {
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static VecUtf8String IntoVec(this Utf8String[] s) { return VecUtf8String.From(s); }
-    }
+        public static implicit operator Duration(TimeSpan value) => FromTimeSpan(value);
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator TimeSpan(Duration value) => value.ToTimeSpan();
 
+        // Rust durations can't be negative, and sub-tick nanoseconds get truncated on the way back.
+        public static Duration FromTimeSpan(TimeSpan value)
+        {
+            if (value < TimeSpan.Zero) throw new ArgumentOutOfRangeException(nameof(value), value, null);
+            var rval = new Duration();
+            rval.secs = (ulong) (value.Ticks / TimeSpan.TicksPerSecond);
+            rval.nanos = (uint) (value.Ticks % TimeSpan.TicksPerSecond * 100);
+            return rval;
+        }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public TimeSpan ToTimeSpan() => TimeSpan.FromTicks(checked((long) secs * TimeSpan.TicksPerSecond + nanos / 100));
+    }
 
 
-    public class EnumException() : InteropException($"Enum variant mismatch.")  { }
 
-    public class EnumException<T>(T t) : InteropException($"Enum variant mismatch.")
+    public class InteropException : Exception
     {
-        public T Value { get; } = t;
-    }
+        public InteropException() : base()
+        {
+        }
 
-    public class InteropException(string text) : Exception(text) { }
+        public InteropException(string message) : base(message)
+        {
+        }
+
+        /// Creates an exception for an unexpected result, including details about the last error or panic in Rust.
+        public static InteropException FromLastError(string result)
+        {
+            var lastError = Utf8String.LastError();
+            return string.IsNullOrEmpty(lastError) ? new InteropException(result) : new InteropException($"{result}: {lastError}");
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -3632,23 +5245,33 @@ namespace My.Company.Common
     {
         private Utf8String() { }
 
+        // Hands the UTF-16 contents of `s` to Rust, which converts them without an intermediate copy here. Like
+        // `Encoding.UTF8`, unpaired surrogates become U+FFFD.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static unsafe Utf8String From(string s)
         {
-            var rval = new Utf8String();
-            var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+            fixed (char* p = s)
+            {
+                InteropHelper.interoptopus_string_create_utf16((IntPtr)p, (ulong)s.Length, out var native);
+                return native.IntoManaged();
+            }
+        }
 
-            fixed (byte* p = utf8Bytes)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String FromUtf8(ReadOnlySpan<byte> utf8)
+        {
+            fixed (byte* p = utf8)
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                var status = InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)utf8.Length, out var native);
+                if (status != 0) { throw new InteropException("Bytes are not valid UTF-8."); }
+                return native.IntoManaged();
             }
+        }
 
-            return rval;
+        /// Returns and clears details about the last error or panic in Rust on this thread, or an empty string.
+        public static string LastError()
+        {
+            return InteropHelper.interoptopus_last_error().IntoManaged().IntoString();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -3746,6 +5369,11 @@ namespace My.Company.Common
             
             public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
 
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, out Unmanaged rval);
+
             [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_destroy")]
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
@@ -3755,6 +5383,11 @@ namespace My.Company.Common
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
             public static partial long interoptopus_string_clone(ref Unmanaged orig, ref Unmanaged cloned);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_last_error")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial Unmanaged interoptopus_last_error();
         }
 
         [CustomMarshaller(typeof(Utf8String), MarshalMode.Default, typeof(Marshaller))]
@@ -11,6 +11,45 @@ public class TestBehavior
         Assert.Equal(ResultError.Panic, v);
     }
 
+    [Fact]
+    public void behavior_panics_via_result_last_error()
+    {
+        Interop.behavior_panics_via_result();
+        var error = Utf8String.LastError();
+        Assert.Contains("Oh no", error);
+        Assert.Contains("behavior.rs", error);
+        Assert.Equal("", Utf8String.LastError());
+    }
+
+    [Fact]
+    public void behavior_panics_via_result_exception()
+    {
+        var e = Assert.Throws<InteropException>(() => Interop.behavior_panics_via_result().AsOk());
+        Assert.Contains("Oh no", e.Message);
+    }
+
+    [Fact]
+    public void behavior_panics_ffi_error()
+    {
+        Assert.Equal(ResultU32Error.Panic, Interop.behavior_panics_ffi_error());
+        Assert.Contains("Oh no", Utf8String.LastError());
+    }
+
+    [Fact]
+    public void behavior_panics_return_default()
+    {
+        Assert.Equal(0u, Interop.behavior_panics_return_default());
+        Assert.Contains("Oh no", Utf8String.LastError());
+    }
+
+    [Fact]
+    public void behavior_panics_callback()
+    {
+        var before = Interop.behavior_panic_callbacks();
+        Assert.Equal(0u, Interop.behavior_panics_callback());
+        Assert.Equal(before + 1, Interop.behavior_panic_callbacks());
+    }
+
     [Fact]
     public void behavior_sleep()
     {
@@ -16,6 +16,13 @@ public class TestFnPtr
         Assert.Equal(43, result);
     }
 
+    [Fact]
+    public void fnptr_3()
+    {
+        var result = Interop.fnptr_3((x0, x1, x2, x3, x4, x5, x6, x7) => x0 + x1 + x2 + x3 + x4 + x5 + x6 + x7);
+        Assert.Equal(36u, result);
+    }
+
     // [Fact]
     // public void callback_marshalled()
     // {
@@ -1,3 +1,5 @@
+using System;
+using System.Text;
 using My.Company;
 using Xunit;
 
@@ -27,4 +29,20 @@ public class TestBasics
 
 	[Fact]
 	public void primitive_isize() { Assert.Equal(-123, Interop.primitive_isize(123)); }
+
+    [Fact]
+    public void primitive_u128() { Assert.Equal(UInt128.MaxValue, Interop.primitive_u128(0)); }
+
+    [Fact]
+    public void primitive_i128() { Assert.Equal(-(Int128) ulong.MaxValue, Interop.primitive_i128(ulong.MaxValue)); }
+
+    [Fact]
+    public void primitive_char() { Assert.Equal(new Rune('Ä'), Interop.primitive_char(new Rune('ä'))); }
+
+    [Fact]
+    public void primitive_nonzero()
+    {
+        Assert.Equal(2u, Interop.primitive_nonzero(1));
+        Assert.Equal(0u, Interop.primitive_nonzero(0));
+    }
 }
@@ -1,3 +1,5 @@
+using System;
+using System.Text;
 using My.Company;
 using Xunit;
 
@@ -13,4 +15,21 @@ public class TestStructs
         
         Assert.True(rval);
     }
-}
\ No newline at end of file
+
+    [Fact]
+    public void struct4()
+    {
+        var rval = Interop.struct4(new WideNum
+        {
+            id = UInt128.MaxValue - 1,
+            delta = Int128.MinValue + 1,
+            initial = new Rune('x'),
+            handle = 41
+        });
+
+        Assert.Equal(UInt128.MaxValue, rval.id);
+        Assert.Equal(Int128.MaxValue, rval.delta);
+        Assert.Equal(new Rune('X'), rval.initial);
+        Assert.Equal(42u, rval.handle);
+    }
+}
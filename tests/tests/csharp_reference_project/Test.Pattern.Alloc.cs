@@ -0,0 +1,23 @@
+using System;
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternAlloc
+{
+    [Fact]
+    public void alloc_and_free()
+    {
+        var ptr = Interop.interoptopus_alloc(64, 16);
+        Assert.NotEqual(IntPtr.Zero, ptr);
+        Assert.Equal(0, ptr.ToInt64() % 16);
+        Interop.interoptopus_free(ptr, 64, 16);
+    }
+
+    [Fact]
+    public void allocator_set_after_use_fails()
+    {
+        Interop.pattern_vec_1().Dispose();
+        Assert.Equal(1, Interop.interoptopus_allocator_set((size, align) => IntPtr.Zero, (ptr, size, align) => { }));
+    }
+}
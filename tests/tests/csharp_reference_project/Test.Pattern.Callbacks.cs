@@ -170,4 +170,38 @@ public class TestPatternDelegates
         Assert.Equal(cc1.Call(0, 1), ResultError.Ok);
     }
 
+    [Fact]
+    public void pattern_callback_9()
+    {
+        Assert.Equal(2u, Interop.pattern_callback_9(value => value * 2, 1));
+    }
+
+    [Fact]
+    public void pattern_callback_10()
+    {
+        // Rust holds on to the callback after the call returned.
+        Interop.pattern_callback_10(value => value + 10);
+        GC.Collect();
+        GC.WaitForPendingFinalizers();
+        Assert.Equal(11u, Interop.pattern_callback_11(1));
+
+        // Replacing it drops (and releases) the previous one.
+        Interop.pattern_callback_10(value => value + 20);
+        Assert.Equal(21u, Interop.pattern_callback_11(1));
+    }
+
+    [Fact]
+    public void pattern_callback_12()
+    {
+        Assert.Equal(ResultU32Error.Ok(2), Interop.pattern_callback_12(value => ResultU32Error.Ok(value + 1), 0));
+        Assert.Equal(ResultU32Error.Err(Error.Fail), Interop.pattern_callback_12(value => throw new ErrorFailException(Error.Fail, "Oh no"), 0));
+        Assert.Equal(ResultU32Error.Panic, Interop.pattern_callback_12(value => throw new Exception("Oh no"), 0));
+    }
+
+    [Fact]
+    public void pattern_callback_13_14()
+    {
+        Assert.Equal(3, Interop.pattern_callback_13(value => (byte) (value + 1), 2));
+        Assert.Equal(5, Interop.pattern_callback_14(value => (byte) (value * 2), 2.5f));
+    }
 }
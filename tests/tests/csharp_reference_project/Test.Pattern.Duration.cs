@@ -0,0 +1,43 @@
+using System;
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternDuration
+{
+    [Fact]
+    public void pattern_duration_1()
+    {
+        TimeSpan rval = Interop.pattern_duration_1(TimeSpan.FromMilliseconds(1500));
+        Assert.Equal(TimeSpan.FromSeconds(3), rval);
+    }
+
+    [Fact]
+    public void pattern_duration_1_overflow()
+    {
+        var rval = Interop.pattern_duration_1(new Duration { secs = ulong.MaxValue, nanos = 1_000_000_000 });
+        Assert.Equal(TimeSpan.Zero, rval.ToTimeSpan());
+    }
+
+    [Fact]
+    public void pattern_duration_2()
+    {
+        var timeout = new Timeout { after = TimeSpan.FromMilliseconds(250), retries = 3 };
+        Assert.Equal(TimeSpan.FromSeconds(1), Interop.pattern_duration_2(timeout).ToTimeSpan());
+    }
+
+    [Fact]
+    public void pattern_duration_3()
+    {
+        var rval = Interop.pattern_duration_3();
+        Assert.Equal(1ul, rval.secs);
+        Assert.Equal(500_000_001u, rval.nanos);
+        Assert.Equal(TimeSpan.FromMilliseconds(1500), rval.ToTimeSpan());
+    }
+
+    [Fact]
+    public void negative_timespans_throw()
+    {
+        Assert.Throws<ArgumentOutOfRangeException>(() => Interop.pattern_duration_1(TimeSpan.FromSeconds(-1)));
+    }
+}
@@ -0,0 +1,69 @@
+using System;
+using System.Linq;
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternIter
+{
+    [Fact]
+    public void pattern_iter_1()
+    {
+        Assert.Equal(new uint[] { 0, 1, 2, 3 }, Interop.pattern_iter_1(4).ToArray());
+        Assert.Empty(Interop.pattern_iter_1(0));
+    }
+
+    [Fact]
+    public void pattern_iter_2()
+    {
+        var sum = 0.0f;
+
+        foreach (var v in Interop.pattern_iter_2())
+        {
+            sum += v.y;
+        }
+
+        Assert.Equal(6.0f, sum);
+    }
+
+    [Fact]
+    public void pattern_iter_3()
+    {
+        Assert.Equal(10u, Interop.pattern_iter_3(Interop.pattern_iter_1(5)));
+    }
+
+    [Fact]
+    public void pattern_iter_4()
+    {
+        Assert.Equal(new uint[] { 0, 1, 2 }, Interop.pattern_iter_4(3).ToArray());
+    }
+
+    [Fact]
+    public void pattern_iter_try_next()
+    {
+        using var iter = Interop.pattern_iter_1(100);
+
+        Assert.True(iter.TryNext(out var first));
+        Assert.True(iter.TryNext(out var second));
+        Assert.Equal(0u, first);
+        Assert.Equal(1u, second);
+    }
+
+    [Fact]
+    public void pattern_iter_only_once()
+    {
+        var iter = Interop.pattern_iter_1(3);
+
+        Assert.Equal(3, iter.Count());
+        Assert.Throws<InteropException>(() => iter.Count());
+    }
+
+    [Fact]
+    public void service_return_iter()
+    {
+        var service = ServiceVariousSlices.New();
+
+        Assert.All(service.ReturnIter(), x => Assert.Equal(123u, x));
+        service.Dispose();
+    }
+}
@@ -0,0 +1,68 @@
+using System;
+using System.Collections.Generic;
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternMap
+{
+    [Fact]
+    public void pattern_map_1()
+    {
+        var map = Interop.pattern_map_1();
+
+        Assert.Equal(3, map.Count);
+        Assert.Equal(2.0f, map[2].x);
+        Assert.True(map.ContainsKey(3));
+        Assert.False(map.ContainsKey(4));
+        Assert.Equal(new uint[] { 1, 2, 3 }, map.Keys);
+
+        map.Dispose();
+    }
+
+    [Fact]
+    public void pattern_map_2()
+    {
+        var data = new Dictionary<uint, Vec3f32> { { 10, new Vec3f32 { x = 1, y = 2, z = 3 } } };
+
+        Assert.Equal(3.0f, Interop.pattern_map_2(data.IntoMap(), 10).AsSome().z);
+        Assert.True(Interop.pattern_map_2(data.IntoMap(), 11).IsNone);
+    }
+
+    [Fact]
+    public void pattern_map_2_no_reuse_after_move()
+    {
+        var map = Interop.pattern_map_1();
+        Interop.pattern_map_2(map, 1);
+
+        Assert.Throws<NullReferenceException>(() => Interop.pattern_map_2(map, 1));
+    }
+
+    [Fact]
+    public void pattern_map_3()
+    {
+        var map = Interop.pattern_map_1();
+        var clone = Interop.pattern_map_3(ref map);
+
+        Assert.Equal(map.Count, clone.Count);
+        Assert.Equal(map[1].y, clone[1].y);
+
+        map.Dispose();
+        clone.Dispose();
+    }
+
+    [Fact]
+    public void pattern_map_iterate()
+    {
+        var map = Interop.pattern_map_1();
+        var sum = 0.0f;
+
+        foreach (var (key, value) in map)
+        {
+            sum += key * value.x;
+        }
+
+        Assert.Equal(14.0f, sum);
+        map.Dispose();
+    }
+}
@@ -0,0 +1,59 @@
+using System;
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternNdSlices
+{
+    [Fact]
+    public unsafe void ndslice_sums_all_dimensions()
+    {
+        var data = new float[] { 1, 2, 3, 4, 5, 6 };
+
+        fixed (float* p = data)
+        {
+            var slice = NdSliceF32.From((IntPtr) p, new ulong[] { 2, 3 });
+            Assert.Equal(2, slice.Rank);
+            Assert.Equal(3, slice.GetLength(1));
+            Assert.Equal(6, slice.Count);
+            Assert.Equal(4.0f, slice[1, 0]);
+            Assert.Equal(21.0f, Interop.pattern_ndslice_1(slice));
+            Assert.Equal(21.0f, Interop.pattern_ndslice_1(NdSliceF32.From((IntPtr) p, new ulong[] { 1, 2, 3 })));
+        }
+    }
+
+    [Fact]
+    public unsafe void ndslice_respects_strides()
+    {
+        var data = new int[] { 1, 2, 3, 4, 5, 6 };
+
+        fixed (int* p = data)
+        {
+            // The transpose of the 2x3 matrix above.
+            var transposed = NdSliceI32.From((IntPtr) p, new ulong[] { 3, 2 }, new long[] { 1, 3 });
+            Assert.False(transposed.IsContiguous);
+            Assert.Equal(4, transposed[0, 1]);
+            Assert.Equal(4, Interop.pattern_ndslice_2(transposed, 0, 1));
+            Assert.Equal(3, Interop.pattern_ndslice_2(transposed, 2, 0));
+            Assert.Equal(-1, Interop.pattern_ndslice_2(transposed, 3, 0));
+            Assert.Throws<IndexOutOfRangeException>(() => transposed[3, 0]);
+            Assert.Throws<InvalidOperationException>(() => transposed.ReadOnlySpan.Length);
+        }
+    }
+
+    [Fact]
+    public unsafe void ndslice_mut_writes_through()
+    {
+        var data = new uint[6];
+
+        fixed (uint* p = data)
+        {
+            var slice = NdSliceMutU32.From((IntPtr) p, new ulong[] { 2, 3 });
+            Interop.pattern_ndslice_3(slice);
+            slice[0, 0] = 7;
+            Assert.Equal(102u, slice.Span[5]);
+        }
+
+        Assert.Equal(new uint[] { 7, 1, 2, 100, 101, 102 }, data);
+    }
+}
@@ -31,4 +31,33 @@ public class TestPatternOptions
         Assert.Equal("hello world", rval.IntoString());
     }
 
+    [Fact]
+    public void pattern_ffi_option_nullable()
+    {
+        Assert.Equal(123.0f, Interop.pattern_ffi_option_1((Inner?) new Inner { x = 123.0f }).AsSome().x);
+        Assert.False(Interop.pattern_ffi_option_1((Inner?) null).IsSome);
+        Assert.True(float.IsNaN(Interop.pattern_ffi_option_2((Inner?) null).x));
+
+        Inner? some = Interop.pattern_ffi_option_1(OptionInner.Some(new Inner { x = 1.0f }));
+        Inner? none = Interop.pattern_ffi_option_1(OptionInner.None);
+        Assert.Equal(1.0f, some?.x);
+        Assert.Null(none);
+    }
+
+    [Fact]
+    public void pattern_ffi_option_helpers()
+    {
+        OptionInner some = new Inner { x = 2.0f };
+        var none = OptionInner.None;
+
+        Assert.Equal(2.0f, some.GetValueOrDefault().x);
+        Assert.Equal(3.0f, none.GetValueOrDefault(new Inner { x = 3.0f }).x);
+        Assert.True(some.TryGetValue(out var value));
+        Assert.Equal(2.0f, value.x);
+        Assert.False(none.TryGetValue(out _));
+        Assert.Equal(4.0f, some.Map(x => new Inner { x = x.x * 2 }).AsSome().x);
+        Assert.False(none.Map(x => new Inner { x = x.x * 2 }).IsSome);
+        Assert.Equal("some", some.Match(_ => "some", () => "none"));
+        Assert.Equal("none", none.Match(_ => "some", () => "none"));
+    }
 }
@@ -11,6 +11,14 @@ public class TestPatternResult
         Interop.pattern_result_1(x).AsOk();
     }
 
+    [Fact]
+    public void pattern_result_1_err()
+    {
+        var e = Assert.Throws<ErrorFailException>(() => Interop.pattern_result_1(ResultU32Error.Err(Error.Fail)).AsOk());
+        Assert.True(e.Error.IsFail);
+        Assert.IsAssignableFrom<ErrorException>(e);
+    }
+
     [Fact]
     public void pattern_result_3()
     {
@@ -25,4 +33,43 @@ public class TestPatternResult
         Assert.True(Interop.pattern_result_4(ResultVoid.Ok).IsOk);
     }
 
+    [Fact]
+    public void pattern_result_5()
+    {
+        Assert.Equal(20u, Interop.pattern_result_5(10).AsOk());
+        Assert.Throws<ErrorFailException>(() => Interop.pattern_result_5(1000).AsOk());
+    }
+
+    [Fact]
+    public void pattern_result_6()
+    {
+        Assert.Equal(20u, Interop.pattern_result_6(10).AsOk());
+        var e = Assert.Throws<ErrorFailException>(() => Interop.pattern_result_6(1000).AsOk());
+        Assert.Contains("too large", e.Message);
+    }
+
+    [Fact]
+    public void pattern_result_helpers()
+    {
+        Assert.Equal(20u, Interop.pattern_result_5(10).Unwrap());
+        Assert.Equal(7u, Interop.pattern_result_5(1000).UnwrapOr(7));
+        Assert.True(Interop.pattern_result_5(10).TryGet(out var value));
+        Assert.Equal(20u, value);
+        Assert.False(Interop.pattern_result_5(1000).TryGet(out _));
+        Assert.Equal("ok", Interop.pattern_result_5(10).Match(_ => "ok", _ => "err"));
+        Assert.Equal("err", Interop.pattern_result_5(1000).Match(_ => "ok", _ => "err"));
+        Assert.ThrowsAny<InteropException>(() => ResultU32Error.Null.Match(_ => "ok", _ => "err"));
+    }
+
+    [Fact]
+    public void pattern_result_deconstruct()
+    {
+        var (ok, none) = Interop.pattern_result_5(10);
+        Assert.Equal(20u, ok);
+        Assert.Null(none);
+
+        var (nothing, err) = Interop.pattern_result_5(1000);
+        Assert.Null(nothing);
+        Assert.True(err?.IsFail);
+    }
 }
@@ -24,9 +24,9 @@ public class TestPatternServicesAsyncResult
         var s = ServiceAsyncResult.New();
 
         try { await s.Fail(); }
-        catch (EnumException<Error> e)
+        catch (ErrorFailException e)
         {
-            Assert.True(e.Value.IsFail);
+            Assert.True(e.Error.IsFail);
             exceptionThrown = true;
         }
         s.Dispose();
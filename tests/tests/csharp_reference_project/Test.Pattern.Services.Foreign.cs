@@ -0,0 +1,43 @@
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternServicesForeign
+{
+
+    class CountingSink : LogSinkBase
+    {
+        public uint Seen;
+
+        public override void Log(uint level, uint x)
+        {
+            Seen += 1;
+        }
+
+        public override uint Count()
+        {
+            return Seen;
+        }
+    }
+
+    [Fact]
+    public void CallFromRust()
+    {
+        var sink = new CountingSink();
+
+        Assert.Equal(2u, Interop.pattern_foreign_trait_1(sink.VTable, 10));
+    }
+
+    [Fact]
+    public void StoredInService()
+    {
+        var sink = new CountingSink();
+
+        using var logger = ServiceForeignLogger.New(sink.VTable);
+        logger.Log(1);
+        logger.Log(2);
+
+        Assert.Equal(2u, sink.Seen);
+    }
+
+}
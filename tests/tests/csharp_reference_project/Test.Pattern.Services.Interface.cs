@@ -0,0 +1,33 @@
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternServicesInterface
+{
+
+    static uint SetAndGet(IStorage storage, uint key, uint val)
+    {
+        storage.Set(key, val);
+        return storage.Get(key);
+    }
+
+    [Fact]
+    public void ImplementsInterface()
+    {
+        using var array = ServiceStorageArray.New();
+        using var offset = ServiceStorageOffset.New(100);
+
+        Assert.Equal(5u, SetAndGet(array, 1, 5));
+        Assert.Equal(105u, SetAndGet(offset, 1, 5));
+    }
+
+    [Fact]
+    public void PassHandleToFunction()
+    {
+        using var offset = ServiceStorageOffset.New(100);
+
+        Assert.Equal(107u, Interop.pattern_service_interface_1(offset.AsStorage(), 2, 7));
+        Assert.Equal(107u, offset.Get(2));
+    }
+
+}
@@ -24,4 +24,11 @@ public class TestPatternServicesMultipleCtors
         catch (InteropException) { }
     }
 
+    [Fact]
+    public void NewStd()
+    {
+        using (ServiceMultipleCtors.NewStd(10)) { };
+        Assert.Throws<ErrorFailException>(() => ServiceMultipleCtors.NewStd(1000));
+    }
+
 }
@@ -0,0 +1,18 @@
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternServicesProperties
+{
+    [Fact]
+    public void GetAndSet()
+    {
+        using var service = ServiceProperties.New();
+
+        service.Value = 123;
+
+        Assert.Equal(123u, service.Value);
+        Assert.Equal(123u, service.GetValue());
+        Assert.Equal(1u, service.Updates);
+    }
+}
@@ -16,7 +16,7 @@ public class TestPatternServicesResult
     public void Test()
     {
         var service = ServiceResult.New();
-        Assert.Throws<EnumException<Error>>(() => service.Test());
+        Assert.Throws<ErrorFailException>(() => service.Test());
         service.Dispose();
     }
 
@@ -53,4 +53,15 @@ public class TestPatternServicesResult
         slice.Dispose();
         service.Dispose();
     }
+
+    [Fact]
+    public void ResultStd()
+    {
+        var service = ServiceResult.New();
+        Assert.Equal(10u, service.ResultStd(10));
+        Assert.Throws<ErrorFailException>(() => service.ResultStd(1000));
+        Assert.Equal(11u, service.ResultStdMapped(10));
+        Assert.Throws<ErrorFailException>(() => service.ResultStdMapped(1000));
+        service.Dispose();
+    }
 }
@@ -0,0 +1,21 @@
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternServicesStatics
+{
+    [Fact]
+    public void StaticMethods()
+    {
+        Assert.Equal(10u, ServiceStatics.DefaultScale());
+        Assert.Equal(6u, ServiceStatics.Scaled(2, 3));
+    }
+
+    [Fact]
+    public void InstanceMethods()
+    {
+        using var service = ServiceStatics.NewWith(ServiceStatics.DefaultScale());
+
+        Assert.Equal(20u, service.Scale(2));
+    }
+}
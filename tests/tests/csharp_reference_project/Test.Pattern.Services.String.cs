@@ -22,4 +22,13 @@ public class TestPatternServicesString
 
         Assert.Equal(str, rval);
     }
+
+    [Fact]
+    public void PassStr()
+    {
+        var s = ServiceStrings.New();
+        Assert.Equal(3u, s.PassStr("abc"));
+        Assert.Equal(3u, s.PassStr("abc"u8));
+        s.Dispose();
+    }
 }
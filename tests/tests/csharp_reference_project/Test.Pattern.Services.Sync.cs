@@ -0,0 +1,43 @@
+using System.Threading;
+using System.Threading.Tasks;
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternServicesSync
+{
+    [Fact]
+    public void ThreadAffineSameThread()
+    {
+        using var service = ServiceThreadAffine.New();
+        service.Increment();
+
+        Assert.Equal(1u, service.Value());
+    }
+
+    [Fact]
+    public void ThreadAffineOtherThread()
+    {
+        using var service = ServiceThreadAffine.New();
+        var thread = new Thread(() =>
+        {
+            Assert.Equal(SyncError.WrongThread, Interop.service_thread_affine_increment(service.Context).AsErr());
+            Assert.Equal(0u, service.Value());
+        });
+
+        thread.Start();
+        thread.Join();
+
+        Assert.Equal(0u, service.Value());
+    }
+
+    [Fact]
+    public void MutexSerializesCalls()
+    {
+        using var service = ServiceMutex.New();
+
+        Parallel.For(0, 1000, _ => service.Increment());
+
+        Assert.Equal(1000u, service.Value());
+    }
+}
@@ -160,4 +160,79 @@ public class TestPatternStrings
         s1.Dispose();
     }
 
+    [Fact]
+    public void string_from_utf8()
+    {
+        var s1 = Utf8String.FromUtf8("hello"u8);
+        Assert.Equal("hello", s1.IntoString());
+        Assert.Throws<InteropException>(() => Utf8String.FromUtf8(new byte[] { 0xc3, 0x28 }));
+    }
+
+    [Fact]
+    public void string_from_utf16()
+    {
+        Assert.Equal("héllo 🐙", "héllo 🐙".Utf8().IntoString());
+        Assert.Equal("", "".Utf8().IntoString());
+        Assert.Equal("\ufffd", "\ud800".Utf8().IntoString());
+    }
+
+    [Fact]
+    public void str_from_string_and_span()
+    {
+        Assert.Equal(11u, Interop.pattern_str_1("hello world"));
+        Assert.Equal(5u, Interop.pattern_str_1("hello"u8));
+        Assert.Equal(0u, Interop.pattern_str_1(""));
+        Assert.Equal(6u, Interop.pattern_str_1("héllo"));
+    }
+
+    [Fact]
+    public void str_returned()
+    {
+        Assert.Equal("hello world", Interop.pattern_str_2().ToString());
+        Assert.True(Interop.pattern_str_2().ReadOnlySpan.SequenceEqual("hello world"u8));
+    }
+
+    [Fact]
+    public unsafe void str_borrows_span()
+    {
+        var input = "héllo"u8;
+
+        // The returned string borrows from the input, so the input must stay pinned while it is used.
+        fixed (byte* p = input)
+        {
+            var echo = Interop.pattern_str_3(Utf8Str.From((IntPtr) p, (ulong) input.Length));
+            Assert.Equal("héllo", echo.ToString());
+
+            // Nothing was copied, Rust handed back the very bytes we passed in.
+            fixed (byte* q = echo.ReadOnlySpan)
+            {
+                Assert.Equal((IntPtr) p, (IntPtr) q);
+            }
+        }
+    }
+
+    [Fact]
+    public void str_validates_utf8()
+    {
+        Assert.Equal(5u, Interop.pattern_str_5("héllo").AsOk());
+        Assert.Throws<ErrorFailException>(() => Interop.pattern_str_5(new byte[] { 0xc3, 0x28 }).AsOk());
+    }
+
+    [Fact]
+    public unsafe void str_in_struct()
+    {
+        var s1 = "hello"u8;
+        var s2 = "world!"u8;
+
+        fixed (byte* p1 = s1)
+        fixed (byte* p2 = s2)
+        {
+            var x = new UseStr
+            {
+                s1 = Utf8Str.From((IntPtr) p1, (ulong) s1.Length),
+                s2 = Utf8Str.From((IntPtr) p2, (ulong) s2.Length),
+            };
+            Assert.Equal(11u, Interop.pattern_str_4(x));
+        }
+    }
 }
@@ -0,0 +1,65 @@
+using My.Company;
+using My.Company.Common;
+using Xunit;
+
+public class TestPatternTuple
+{
+    [Fact]
+    public void pattern_tuple_1()
+    {
+        var (f, u) = Interop.pattern_tuple_1((1u, 2.0f));
+        Assert.Equal(2.0f, f);
+        Assert.Equal(1u, u);
+    }
+
+    [Fact]
+    public void pattern_tuple_2()
+    {
+        Tuple3_Vec3f32_U8_I64 x = (new Vec3f32 { x = 1.0f }, (byte) 10, -5L);
+        (Vec3f32 v, byte b, long i) = Interop.pattern_tuple_2(x);
+        Assert.Equal(2.0f, v.x);
+        Assert.Equal(11, b);
+        Assert.Equal(-4L, i);
+    }
+
+    [Fact]
+    public void pattern_tuple_3()
+    {
+        var x = new Tuples { pair = (3u, 1.5f), triple = (new Vec3f32(), (byte) 1, 21L) };
+        var rval = Interop.pattern_tuple_3(x);
+        Assert.Equal((6u, 3.0f), rval.pair.ToValueTuple());
+        Assert.Equal(42L, rval.triple.x2);
+    }
+
+    [Fact]
+    public void pattern_tuple_4()
+    {
+        Assert.Equal(5u, Interop.pattern_tuple_4((Tuple2_U32_U32?) new Tuple2_U32_U32(2, 3)));
+        Assert.Equal(0u, Interop.pattern_tuple_4((Tuple2_U32_U32?) null));
+    }
+
+    [Fact]
+    public void pattern_tuple_5()
+    {
+        Assert.Equal(12u, Interop.pattern_tuple_5((3u, 3u)));
+    }
+
+    [Fact]
+    public void value_tuple_conversions()
+    {
+        (uint, float) vt = new Tuple2_U32_F32(1, 2.0f);
+        Tuple2_U32_F32 t = vt;
+        Assert.Equal(1u, t.x0);
+        Assert.Equal(vt, t.ToValueTuple());
+    }
+
+    [Fact]
+    public void service_return_tuple()
+    {
+        var service = ServiceVariousSlices.New();
+        var (len, first) = service.ReturnLenAndFirst();
+        Assert.Equal(64ul, len);
+        Assert.Equal(123u, first);
+        service.Dispose();
+    }
+}
@@ -3,9 +3,9 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      library
-// Hash:         0x8047a8b1c89b1a85
+// Hash:         0x697e3c498b89abcb
 // Namespace:    
-// Builder:      interoptopus_backend_csharp (0.15.0-alpha.20)
+// Builder:      interoptopus_backend_csharp (0.15.0-alpha.21)
 //
 // Do not edit this file manually.
 //
@@ -34,9 +34,9 @@ namespace My.Company
         static Interop()
         {
             var api_version = Interop.pattern_api_guard();
-            if (api_version != 0x8047a8b1c89b1a85)
+            if (api_version != 0x697e3c498b89abcb)
             {
-                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0x8047a8b1c89b1a85). You probably forgot to update / copy either the bindings or the library.");
+                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0x697e3c498b89abcb). You probably forgot to update / copy either the bindings or the library.");
             }
         }
 
@@ -54,11 +54,18 @@ namespace My.Company
         public const int COMPUTED_I32 = (int) -2147483647;
 
 
+        /// Creates a string from `len` UTF-8 bytes, returns `0` on success or `1` (and an empty string) if they are not valid UTF-8.
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_create")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_string_create(IntPtr utf8, ulong len, ref Utf8String rval);
 
 
+        /// Creates a string from `len` UTF-16 code units, replacing unpaired surrogates with `U+FFFD` like .NET does; always returns `0`.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, ref Utf8String rval);
+
+
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_destroy")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_string_destroy(Utf8String utf8);
@@ -69,11 +76,35 @@ namespace My.Company
         public static partial long interoptopus_string_clone(ref Utf8String utf8, ref Utf8String rval);
 
 
+        /// Returns and clears details about the last error or panic on this thread, or an empty string.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_last_error")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Utf8String interoptopus_last_error();
+
+
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_wire_destroy")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void interoptopus_wire_destroy(ref byte data, int len, int capacity);
 
 
+        /// Installs allocation hooks for all FFI buffers, returns `0` on success or `1` if it is too late to do so.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_allocator_set")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_allocator_set(InteropDelegate_fn_u64_u64_rval_MutPtrVoid alloc, InteropDelegate_fn_MutPtrVoid_u64_u64_rval_void free);
+
+
+        /// Allocates `size` bytes aligned to `align` that Rust may later free, returns null on failure.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_alloc")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IntPtr interoptopus_alloc(ulong size, ulong align);
+
+
+        /// Frees memory allocated by `interoptopus_alloc`, or owned by a buffer Rust handed out; invalid `size` and `align` values are ignored.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_free")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void interoptopus_free(IntPtr ptr, ulong size, ulong align);
+
+
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_vec_create_6849152863081469284")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_vec_create_6849152863081469284(IntPtr data, ulong len, ref VecU8 rval);
@@ -114,6 +145,38 @@ namespace My.Company
         public static partial long interoptopus_vec_destroy_4854562680055612543(VecEnumPayload ignored);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_map_create_6262311165280223196")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_map_create_6262311165280223196(IntPtr keys, IntPtr values, ulong len, ref MapU32Vec3f32 rval);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_map_destroy_9095550523068598099")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_map_destroy_9095550523068598099(MapU32Vec3f32 ignored);
+
+
+        /// Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_next_14415398661037898230")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_next_14415398661037898230(ref IterU32 iter, ref uint rval);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_destroy_11887687051161408401")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_destroy_11887687051161408401(IterU32 ignored);
+
+
+        /// Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_next_12283536038193100041")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_next_12283536038193100041(ref IterVec3f32 iter, ref Vec3f32 rval);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_destroy_14403488365818035019")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_destroy_14403488365818035019(IterVec3f32 ignored);
+
+
         [LibraryImport(NativeLib, EntryPoint = "alignment_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial Packed2 alignment_1(Packed1 a);
@@ -179,6 +242,26 @@ namespace My.Company
         public static partial ResultError behavior_panics_via_result();
 
 
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panics_ffi_error")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error behavior_panics_ffi_error();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panics_return_default")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint behavior_panics_return_default();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panics_callback")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint behavior_panics_callback();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panic_callbacks")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint behavior_panic_callbacks();
+
+
         [LibraryImport(NativeLib, EntryPoint = "enums_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void enums_1(EnumPayload ignored);
@@ -209,6 +292,11 @@ namespace My.Company
         public static partial void fnptr_2(InteropDelegate_fn_CharArray_rval_void_native callback, CharArray x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "fnptr_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint fnptr_3(InteropDelegate_fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32 callback);
+
+
         [LibraryImport(NativeLib, EntryPoint = "generic_1a")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial uint generic_1a(Genericu32 x, Phantomu8 y);
@@ -281,6 +369,11 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial OptionVec namespaced_inner_option(OptionVec x);
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionVec namespaced_inner_option(Vec? x)
+        {
+            return namespaced_inner_option(OptionVec.FromNullable(x));
+        }
 
         [LibraryImport(NativeLib, EntryPoint = "namespaced_inner_slice")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -373,6 +466,26 @@ namespace My.Company
         public static partial byte primitive_u8(byte x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "primitive_u128")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial UInt128 primitive_u128(UInt128 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "primitive_i128")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Int128 primitive_i128(Int128 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "primitive_char")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Rune primitive_char(Rune x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "primitive_nonzero")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint primitive_nonzero(uint x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "primitive_void")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void primitive_void();
@@ -454,6 +567,11 @@ namespace My.Company
         public static partial bool struct3(BoolField x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "struct4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial WideNum struct4(WideNum x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_ascii_pointer_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial uint pattern_ascii_pointer_1([MarshalAs(UnmanagedType.LPStr)] string x);
@@ -544,6 +662,59 @@ namespace My.Company
         public static partial void pattern_string_11(ref Utf8String ignored);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_str_1(Utf8Str x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe uint pattern_str_1(ReadOnlySpan<byte> x)
+        {
+            fixed (byte* x_ptr = x)
+            {
+                return pattern_str_1(Utf8Str.From((IntPtr) x_ptr, (ulong) x.Length));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint pattern_str_1(string x)
+        {
+            return pattern_str_1(Encoding.UTF8.GetBytes(x));
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Utf8Str pattern_str_2();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Utf8Str pattern_str_3(Utf8Str x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_str_4(UseStr x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_5")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_str_5(Utf8Str x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe ResultU32Error pattern_str_5(ReadOnlySpan<byte> x)
+        {
+            fixed (byte* x_ptr = x)
+            {
+                return pattern_str_5(Utf8Str.From((IntPtr) x_ptr, (ulong) x.Length));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ResultU32Error pattern_str_5(string x)
+        {
+            return pattern_str_5(Encoding.UTF8.GetBytes(x));
+        }
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_slice_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial uint pattern_ffi_slice_1(SliceU32 ffi_slice);
@@ -665,15 +836,42 @@ namespace My.Company
             }
         }
 
+        /// Sums all elements, regardless of the number of dimensions or their strides.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_ndslice_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial float pattern_ndslice_1(NdSliceF32 slice);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_ndslice_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial int pattern_ndslice_2(NdSliceI32 slice, ulong row, ulong col);
+
+
+        /// Sets each element of a 2D slice to `row * 100 + col`.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_ndslice_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void pattern_ndslice_3(NdSliceMutU32 slice);
+
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_option_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial OptionInner pattern_ffi_option_1(OptionInner x);
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionInner pattern_ffi_option_1(Inner? x)
+        {
+            return pattern_ffi_option_1(OptionInner.FromNullable(x));
+        }
 
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_option_2")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial Inner pattern_ffi_option_2(OptionInner x);
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static Inner pattern_ffi_option_2(Inner? x)
+        {
+            return pattern_ffi_option_2(OptionInner.FromNullable(x));
+        }
 
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_option_3")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -720,6 +918,16 @@ namespace My.Company
         public static partial ResultVoid pattern_result_4(ResultVoid x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "pattern_result_5")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_result_5(uint x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_result_6")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_result_6(uint x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_api_guard")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial ulong pattern_api_guard();
@@ -831,37 +1039,101 @@ namespace My.Company
 
         [LibraryImport(NativeLib, EntryPoint = "pattern_callback_9")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static partial int pattern_callback_9(Pointers x);
+        public static partial uint pattern_callback_9(MyOwnedCallback callback, uint x);
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe int pattern_callback_9(PointersDelegate x)
+        public static unsafe uint pattern_callback_9(MyOwnedCallbackDelegate callback, uint x)
         {
-            var x_wrapped = new Pointers(x);
+            var callback_wrapped = new MyOwnedCallback(callback);
             try
             {
-                return pattern_callback_9(x_wrapped);
+                return pattern_callback_9(callback_wrapped, x);
             }
             finally
             {
-                x_wrapped.Dispose();
+                callback_wrapped.Dispose();
             }
         }
 
+        /// Keeps the callback until replaced, dropping the previous one.
         [LibraryImport(NativeLib, EntryPoint = "pattern_callback_10")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static partial void pattern_callback_10(StringCallbackString ignored);
+        public static partial void pattern_callback_10(MyOwnedCallback callback);
+
+        /// Keeps the callback until replaced, dropping the previous one.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe void pattern_callback_10(MyOwnedCallbackDelegate callback)
+        {
+            var callback_wrapped = new MyOwnedCallback(callback);
+            try
+            {
+                pattern_callback_10(callback_wrapped);
+            }
+            finally
+            {
+                callback_wrapped.Dispose();
+            }
+        }
+
+        /// Invokes the callback stored by `pattern_callback_10`, returns 0 if there is none.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_11")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_callback_11(uint x);
+
+
+        /// Returns the callback's result plus one, passing on errors and failures of the callback.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_12")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_callback_12(MyFallibleCallback callback, uint x);
+
+        /// Returns the callback's result plus one, passing on errors and failures of the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe ResultU32Error pattern_callback_12(MyFallibleCallbackDelegate callback, uint x)
+        {
+            var callback_wrapped = new MyFallibleCallback(callback);
+            try
+            {
+                return pattern_callback_12(callback_wrapped, x);
+            }
+            finally
+            {
+                callback_wrapped.Dispose();
+            }
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_13")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial byte pattern_callback_13(OnItemU32 callback, uint x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe byte pattern_callback_13(OnItemU32Delegate callback, uint x)
+        {
+            var callback_wrapped = new OnItemU32(callback);
+            try
+            {
+                return pattern_callback_13(callback_wrapped, x);
+            }
+            finally
+            {
+                callback_wrapped.Dispose();
+            }
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_14")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial byte pattern_callback_14(OnItemF32 callback, float x);
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe void pattern_callback_10(StringCallbackStringDelegate ignored)
+        public static unsafe byte pattern_callback_14(OnItemF32Delegate callback, float x)
         {
-            var ignored_wrapped = new StringCallbackString(ignored);
+            var callback_wrapped = new OnItemF32(callback);
             try
             {
-                pattern_callback_10(ignored_wrapped);
+                return pattern_callback_14(callback_wrapped, x);
             }
             finally
             {
-                ignored_wrapped.Dispose();
+                callback_wrapped.Dispose();
             }
         }
 
@@ -910,6 +1182,98 @@ namespace My.Company
         public static partial UseSliceAndVec pattern_vec_8(UseSliceAndVec v);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "pattern_map_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial MapU32Vec3f32 pattern_map_1();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_map_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial OptionVec3f32 pattern_map_2(MapU32Vec3f32 m, uint key);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_map_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial MapU32Vec3f32 pattern_map_3(ref MapU32Vec3f32 m);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterU32 pattern_iter_1(uint n);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterVec3f32 pattern_iter_2();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_iter_3(IterU32 iter);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterU32 pattern_iter_4(uint n);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuple2_F32_U32 pattern_tuple_1(Tuple2_U32_F32 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuple3_Vec3f32_U8_I64 pattern_tuple_2(Tuple3_Vec3f32_U8_I64 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuples pattern_tuple_3(Tuples x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_tuple_4(OptionTuple2_U32_U32 x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint pattern_tuple_4(Tuple2_U32_U32? x)
+        {
+            return pattern_tuple_4(OptionTuple2_U32_U32.FromNullable(x));
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_5")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_tuple_5(Tuple2_U32_U32 tuple0);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_duration_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Duration pattern_duration_1(Duration x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_duration_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Duration pattern_duration_2(Timeout x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_duration_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Duration pattern_duration_3();
+
+
+        /// Works with any service implementing `Storage`.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_service_interface_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_service_interface_1(DynStorage storage, uint key, uint val);
+
+
+        /// Logs the given values to a foreign sink and returns what the sink counted.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_foreign_trait_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_foreign_trait_1(LogSinkVTable sink, uint x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "wire_accept_string_2")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void wire_accept_string_2(WireOfMyString input);
@@ -939,14 +1303,7 @@ namespace My.Company
         public static unsafe Task service_async_basic_call(IntPtr _context)
         {
             var (_cb, _cs) = _trampolineResultError.NewCall();
-            try
-            {
-                service_async_basic_call(_context, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_basic_call(_context, _cb).AsOk();
             return _cs;
         }
 
@@ -974,14 +1331,7 @@ namespace My.Company
         public static unsafe Task<ulong> service_async_sleep_return_after_ms(IntPtr _context, ulong x, ulong ms)
         {
             var (_cb, _cs) = _trampolineResultU64Error.NewCall();
-            try
-            {
-                service_async_sleep_return_after_ms(_context, x, ms, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_sleep_return_after_ms(_context, x, ms, _cb).AsOk();
             return _cs;
         }
 
@@ -1009,14 +1359,7 @@ namespace My.Company
         public static unsafe Task<Utf8String> service_async_vec_string_handle_string(IntPtr _context, Utf8String s)
         {
             var (_cb, _cs) = _trampolineResultUtf8StringError.NewCall();
-            try
-            {
-                service_async_vec_string_handle_string(_context, s, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_vec_string_handle_string(_context, s, _cb).AsOk();
             return _cs;
         }
 
@@ -1028,14 +1371,7 @@ namespace My.Company
         public static unsafe Task<VecUtf8String> service_async_vec_string_handle_vec_string(IntPtr _context, VecUtf8String s)
         {
             var (_cb, _cs) = _trampolineResultVecUtf8StringError.NewCall();
-            try
-            {
-                service_async_vec_string_handle_vec_string(_context, s, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_vec_string_handle_vec_string(_context, s, _cb).AsOk();
             return _cs;
         }
 
@@ -1047,14 +1383,7 @@ namespace My.Company
         public static unsafe Task<UseString> service_async_vec_string_handle_nested_string(IntPtr _context, Utf8String s)
         {
             var (_cb, _cs) = _trampolineResultUseStringError.NewCall();
-            try
-            {
-                service_async_vec_string_handle_nested_string(_context, s, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_vec_string_handle_nested_string(_context, s, _cb).AsOk();
             return _cs;
         }
 
@@ -1082,14 +1411,7 @@ namespace My.Company
         public static unsafe Task service_async_result_success(IntPtr _context)
         {
             var (_cb, _cs) = _trampolineResultError.NewCall();
-            try
-            {
-                service_async_result_success(_context, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_result_success(_context, _cb).AsOk();
             return _cs;
         }
 
@@ -1101,14 +1423,7 @@ namespace My.Company
         public static unsafe Task service_async_result_fail(IntPtr _context)
         {
             var (_cb, _cs) = _trampolineResultError.NewCall();
-            try
-            {
-                service_async_result_fail(_context, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_result_fail(_context, _cb).AsOk();
             return _cs;
         }
 
@@ -1136,14 +1451,7 @@ namespace My.Company
         public static unsafe Task<NestedArray> service_async_structs_process_struct(IntPtr _context, NestedArray x)
         {
             var (_cb, _cs) = _trampolineResultNestedArrayError.NewCall();
-            try
-            {
-                service_async_structs_process_struct(_context, x, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_structs_process_struct(_context, x, _cb).AsOk();
             return _cs;
         }
 
@@ -1241,6 +1549,16 @@ namespace My.Company
         public static partial ResultU32Error service_result_result_slice(IntPtr _context, SliceU32 slice, ulong i);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "service_result_result_std")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error service_result_result_std(IntPtr _context, uint x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_result_result_std_mapped")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error service_result_result_std_mapped(IntPtr _context, uint x);
+
+
         /// Destroys the given instance.
         ///
         /// # Safety
@@ -1404,6 +1722,11 @@ namespace My.Company
         public static partial ResultConstPtrServiceMultipleCtorsError service_multiple_ctors_new_failing(byte some_value);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "service_multiple_ctors_new_std")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceMultipleCtorsError service_multiple_ctors_new_std(uint some_value);
+
+
         /// Destroys the given instance.
         ///
         /// # Safety
@@ -1470,6 +1793,18 @@ namespace My.Company
         public static partial SliceMutU32 service_various_slices_return_slice_mut(IntPtr _context);
 
 
+        /// Hands out a copy of the data lazily, so the returned iterator stays valid even if the
+        /// service is used again.
+        [LibraryImport(NativeLib, EntryPoint = "service_various_slices_return_iter")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterU32 service_various_slices_return_iter(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_various_slices_return_len_and_first")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuple2_U64_U32 service_various_slices_return_len_and_first(IntPtr _context);
+
+
         /// Destroys the given instance.
         ///
         /// # Safety
@@ -1501,6 +1836,25 @@ namespace My.Company
         public static partial IntPtr service_strings_return_cstr(IntPtr _context);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "service_strings_pass_str")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_strings_pass_str(IntPtr _context, Utf8Str s);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe uint service_strings_pass_str(IntPtr _context, ReadOnlySpan<byte> s)
+        {
+            fixed (byte* s_ptr = s)
+            {
+                return service_strings_pass_str(_context, Utf8Str.From((IntPtr) s_ptr, (ulong) s.Length));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint service_strings_pass_str(IntPtr _context, string s)
+        {
+            return service_strings_pass_str(_context, Encoding.UTF8.GetBytes(s));
+        }
+
         [LibraryImport(NativeLib, EntryPoint = "service_strings_callback_string")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void service_strings_callback_string(IntPtr _context, Utf8String s, StringCallback cb);
@@ -1519,6 +1873,214 @@ namespace My.Company
             }
         }
 
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_array_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageArrayError service_storage_array_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_array_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageArrayError service_storage_array_new();
+
+
+        /// Returns a [`Storage`] handle to this service, valid until the service is destroyed.
+        ///
+        /// While a `&mut self` method runs through the handle, no other method of this service may be called.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_array_as_storage")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial DynStorage service_storage_array_as_storage(IntPtr _context);
+
+
+        /// Returns the value stored at `key`.
+        [LibraryImport(NativeLib, EntryPoint = "storage_get")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint storage_get(DynStorage _handle, uint key);
+
+
+        /// Stores `val` at `key`.
+        [LibraryImport(NativeLib, EntryPoint = "storage_set")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void storage_set(DynStorage _handle, uint key, uint val);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageOffsetError service_storage_offset_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageOffsetError service_storage_offset_new(uint offset);
+
+
+        /// Services can provide interface methods themselves.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_get")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_storage_offset_get(IntPtr _context, uint key);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_offset")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_storage_offset_offset(IntPtr _context);
+
+
+        /// Returns a [`Storage`] handle to this service, valid until the service is destroyed.
+        ///
+        /// While a `&mut self` method runs through the handle, no other method of this service may be called.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_as_storage")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial DynStorage service_storage_offset_as_storage(IntPtr _context);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_foreign_logger_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceForeignLoggerError service_foreign_logger_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_foreign_logger_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceForeignLoggerError service_foreign_logger_new(LogSinkVTable sink);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_foreign_logger_log")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultError service_foreign_logger_log(IntPtr _context, uint x);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServicePropertiesError service_properties_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServicePropertiesError service_properties_new();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_get_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_properties_get_value(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_set_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void service_properties_set_value(IntPtr _context, uint x);
+
+
+        /// Properties can also be read-only.
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_get_updates")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_properties_get_updates(IntPtr _context);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStaticsError service_statics_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_new_with")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStaticsError service_statics_new_with(uint scale);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_scale")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_statics_scale(IntPtr _context, uint x);
+
+
+        /// The scale used by default, not tied to any instance.
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_default_scale")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_statics_default_scale();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_scaled")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_statics_scaled(uint x, uint scale);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceThreadAffineSyncError service_thread_affine_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceThreadAffineSyncError service_thread_affine_new();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_increment")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultSyncError service_thread_affine_increment(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_thread_affine_value(IntPtr _context);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceMutexError service_mutex_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceMutexError service_mutex_new();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_increment")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_mutex_increment(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_mutex_value(IntPtr _context);
+
+
     }
 
     /// Documented enum.
@@ -1531,13 +2093,9 @@ namespace My.Company
     public partial struct EnumDocumented 
     {
 
-
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -1573,9 +2131,9 @@ namespace My.Company
         public bool IsB => _variant == 1;
         public bool IsC => _variant == 2;
 
-        public void AsA() { if (_variant != 0) throw ExceptionForVariant(); }
-        public void AsB() { if (_variant != 1) throw ExceptionForVariant(); }
-        public void AsC() { if (_variant != 2) throw ExceptionForVariant(); }
+        public void AsA() { if (_variant != 0) throw new InteropException(); }
+        public void AsB() { if (_variant != 1) throw new InteropException(); }
+        public void AsC() { if (_variant != 2) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -1583,16 +2141,7 @@ namespace My.Company
             if (_variant == 0) return "A";
             if (_variant == 1) return "B";
             if (_variant == 2) return "C";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException();
-            if (_variant == 2) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumDocumented), MarshalMode.Default, typeof(Marshaller))]
@@ -1636,41 +2185,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct EnumNum 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedA
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal nuint _A;
-        }
-
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedC
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal nint _C;
         }
 
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedA _A;
-
-            [FieldOffset(0)]
-            internal UnmanagedC _C;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal EnumNum ToManaged()
             {
                 var _managed = new EnumNum();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._A = _A._A;
-                if (_variant == 2) _managed._C = _C._C;
+                if (_variant == 0) _managed._A = _payload._A;
+                if (_variant == 2) _managed._C = _payload._C;
                 return _managed;
             }
         }
@@ -1680,8 +2217,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A;
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 0) _unmanaged._payload._A = _A;
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -1690,8 +2227,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A;
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 0) _unmanaged._payload._A = _A;
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -1703,9 +2240,9 @@ namespace My.Company
         public bool IsB => _variant == 1;
         public bool IsC => _variant == 2;
 
-        public nuint AsA() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _A; } }
-        public void AsB() { if (_variant != 1) throw ExceptionForVariant(); }
-        public nint AsC() { if (_variant != 2) { throw ExceptionForVariant(); } else { return _C; } }
+        public nuint AsA() { if (_variant != 0) { throw new InteropException(); } else { return _A; } }
+        public void AsB() { if (_variant != 1) throw new InteropException(); }
+        public nint AsC() { if (_variant != 2) { throw new InteropException(); } else { return _C; } }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -1713,16 +2250,7 @@ namespace My.Company
             if (_variant == 0) return "A(...)";
             if (_variant == 1) return "B";
             if (_variant == 2) return "C(...)";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<nuint>(_A);
-            if (_variant == 1) return new EnumException();
-            if (_variant == 2) return new EnumException<nint>(_C);
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumNum), MarshalMode.Default, typeof(Marshaller))]
@@ -1766,41 +2294,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct EnumPayload 
     {
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedB
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Vec3f32.Unmanaged _B;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedC
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal uint _C;
         }
 
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedB _B;
-
-            [FieldOffset(0)]
-            internal UnmanagedC _C;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal EnumPayload ToManaged()
             {
                 var _managed = new EnumPayload();
                 _managed._variant = _variant;
-                if (_variant == 1) _managed._B = _B._B.ToManaged();
-                if (_variant == 2) _managed._C = _C._C;
+                if (_variant == 1) _managed._B = _payload._B.ToManaged();
+                if (_variant == 2) _managed._C = _payload._C;
                 return _managed;
             }
         }
@@ -1810,8 +2326,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._B._B = _B.ToUnmanaged();
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 1) _unmanaged._payload._B = _B.ToUnmanaged();
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -1820,8 +2336,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._B._B = _B.ToUnmanaged();
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 1) _unmanaged._payload._B = _B.ToUnmanaged();
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -1833,9 +2349,9 @@ namespace My.Company
         public bool IsB => _variant == 1;
         public bool IsC => _variant == 2;
 
-        public void AsA() { if (_variant != 0) throw ExceptionForVariant(); }
-        public Vec3f32 AsB() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _B; } }
-        public uint AsC() { if (_variant != 2) { throw ExceptionForVariant(); } else { return _C; } }
+        public void AsA() { if (_variant != 0) throw new InteropException(); }
+        public Vec3f32 AsB() { if (_variant != 1) { throw new InteropException(); } else { return _B; } }
+        public uint AsC() { if (_variant != 2) { throw new InteropException(); } else { return _C; } }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -1843,16 +2359,7 @@ namespace My.Company
             if (_variant == 0) return "A";
             if (_variant == 1) return "B(...)";
             if (_variant == 2) return "C(...)";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException<Vec3f32>(_B);
-            if (_variant == 2) return new EnumException<uint>(_C);
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumPayload), MarshalMode.Default, typeof(Marshaller))]
@@ -1895,11 +2402,9 @@ namespace My.Company
     public partial struct EnumRenamed 
     {
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -1931,20 +2436,13 @@ namespace My.Company
 
         public bool IsX => _variant == 0;
 
-        public void AsX() { if (_variant != 0) throw ExceptionForVariant(); }
+        public void AsX() { if (_variant != 0) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "X";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumRenamed), MarshalMode.Default, typeof(Marshaller))]
@@ -1987,11 +2485,9 @@ namespace My.Company
     public partial struct Error 
     {
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -2023,20 +2519,13 @@ namespace My.Company
 
         public bool IsFail => _variant == 0;
 
-        public void AsFail() { if (_variant != 0) throw ExceptionForVariant(); }
+        public void AsFail() { if (_variant != 0) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Fail";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(Error), MarshalMode.Default, typeof(Marshaller))]
@@ -2070,27 +2559,49 @@ namespace My.Company
         }
     }
 
-    public partial class Layer3
+    /// Thrown when a function returns `Error` as its error.
+    public class ErrorException : InteropException
     {
-        uint _variant;
-        Layer1Utf8String _A;
-        Layer2Utf8String _B;
-    }
+        public Error Error { get; }
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class Layer3 : IDisposable
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedA
+        public ErrorException(Error error) : this(error, error.ToString()) { }
+
+        public ErrorException(Error error, string message) : base(message)
         {
-            internal uint _variant;
-            internal Layer1Utf8String.Unmanaged _A;
+            Error = error;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedB
+        /// Creates the exception matching the error's variant, including details about the last error in Rust.
+        public static ErrorException FromError(Error error)
         {
-            internal uint _variant;
+            var lastError = Utf8String.LastError();
+            var message = string.IsNullOrEmpty(lastError) ? error.ToString() : $"{error}: {lastError}";
+            if (error.IsFail) return new ErrorFailException(error, message);
+            return new ErrorException(error, message);
+        }
+    }
+
+    public class ErrorFailException : ErrorException
+    {
+        public ErrorFailException(Error error, string message) : base(error, message) { }
+    }
+
+    public partial class Layer3
+    {
+        uint _variant;
+        Layer1Utf8String _A;
+        Layer2Utf8String _B;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class Layer3 : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal Layer1Utf8String.Unmanaged _A;
+            [FieldOffset(0)]
             internal Layer2Utf8String.Unmanaged _B;
         }
 
@@ -2100,25 +2611,19 @@ namespace My.Company
             if (_variant == 1) { _B.Dispose(); }
         }
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedA _A;
-
-            [FieldOffset(0)]
-            internal UnmanagedB _B;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal Layer3 IntoManaged()
             {
                 var _managed = new Layer3();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._A = _A._A.IntoManaged();
-                if (_variant == 1) _managed._B = _B._B.IntoManaged();
+                if (_variant == 0) _managed._A = _payload._A.IntoManaged();
+                if (_variant == 1) _managed._B = _payload._B.IntoManaged();
                 return _managed;
             }
         }
@@ -2128,8 +2633,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._B._B = _B.IntoUnmanaged();
+            if (_variant == 0) _unmanaged._payload._A = _A.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._B = _B.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -2138,8 +2643,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A.AsUnmanaged();
-            if (_variant == 1) _unmanaged._B._B = _B.AsUnmanaged();
+            if (_variant == 0) _unmanaged._payload._A = _A.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._B = _B.AsUnmanaged();
             return _unmanaged;
         }
 
@@ -2149,23 +2654,15 @@ namespace My.Company
         public bool IsA => _variant == 0;
         public bool IsB => _variant == 1;
 
-        public Layer1Utf8String AsA() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _A; } }
-        public Layer2Utf8String AsB() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _B; } }
+        public Layer1Utf8String AsA() { if (_variant != 0) { throw new InteropException(); } else { return _A; } }
+        public Layer2Utf8String AsB() { if (_variant != 1) { throw new InteropException(); } else { return _B; } }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "A(...)";
             if (_variant == 1) return "B(...)";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Layer1Utf8String>(_A);
-            if (_variant == 1) return new EnumException<Layer2Utf8String>(_B);
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(Layer3), MarshalMode.Default, typeof(Marshaller))]
@@ -2199,6 +2696,117 @@ namespace My.Company
         }
     }
 
+    /// Errors of [`ServiceThreadAffine`].
+    public partial struct SyncError
+    {
+        uint _variant;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct SyncError 
+    {
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal SyncError ToManaged()
+            {
+                var _managed = new SyncError();
+                _managed._variant = _variant;
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        public static SyncError WrongThread => new() { _variant = 0 };
+
+        public bool IsWrongThread => _variant == 0;
+
+        public void AsWrongThread() { if (_variant != 0) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "WrongThread";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(SyncError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private SyncError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SyncError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SyncError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SyncError ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    /// Thrown when a function returns `SyncError` as its error.
+    public class SyncErrorException : InteropException
+    {
+        public SyncError Error { get; }
+
+        public SyncErrorException(SyncError error) : this(error, error.ToString()) { }
+
+        public SyncErrorException(SyncError error, string message) : base(message)
+        {
+            Error = error;
+        }
+
+        /// Creates the exception matching the error's variant, including details about the last error in Rust.
+        public static SyncErrorException FromError(SyncError error)
+        {
+            var lastError = Utf8String.LastError();
+            var message = string.IsNullOrEmpty(lastError) ? error.ToString() : $"{error}: {lastError}";
+            if (error.IsWrongThread) return new SyncErrorWrongThreadException(error, message);
+            return new SyncErrorException(error, message);
+        }
+    }
+
+    public class SyncErrorWrongThreadException : SyncErrorException
+    {
+        public SyncErrorWrongThreadException(SyncError error, string message) : base(error, message) { }
+    }
+
     public partial struct Array
     {
         public byte[] data;
@@ -2653,6 +3261,89 @@ namespace My.Company
         }
     }
 
+    /// Some key-value storage shared by multiple services.
+    public partial struct DynStorage
+    {
+        IntPtr context;
+        IntPtr vtable;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct DynStorage 
+    {
+        public DynStorage() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.context = context;
+            _unmanaged.vtable = vtable;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.context = context;
+            _unmanaged.vtable = vtable;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public IntPtr context;
+            public IntPtr vtable;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe DynStorage ToManaged()
+            {
+                var _managed = new DynStorage();
+                _managed.context = context;
+                _managed.vtable = vtable;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "DynStorage { ... }";
+        }
+
+        [CustomMarshaller(typeof(DynStorage), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private DynStorage _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(DynStorage managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(DynStorage managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public DynStorage ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
     public partial struct ExtraTypef32
     {
         public float x;
@@ -3410,6 +4101,101 @@ namespace My.Company
         }
     }
 
+    /// A sink implemented in a foreign language receiving log messages.
+    public partial struct LogSinkVTable
+    {
+        /// Called for each logged value.
+        public LogSinkLog log;
+        /// Returns how many values the sink has seen.
+        public LogSinkCount count;
+        public IntPtr user_data;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct LogSinkVTable : IDisposable
+    {
+        public LogSinkVTable() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.log = log?.ToUnmanaged() ?? default;
+            _unmanaged.count = count?.ToUnmanaged() ?? default;
+            _unmanaged.user_data = user_data;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.log = log?.ToUnmanaged() ?? default;
+            _unmanaged.count = count?.ToUnmanaged() ?? default;
+            _unmanaged.user_data = user_data;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public LogSinkLog.Unmanaged log;
+            public LogSinkCount.Unmanaged count;
+            public IntPtr user_data;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe LogSinkVTable ToManaged()
+            {
+                var _managed = new LogSinkVTable();
+                _managed.log = log.ToManaged();
+                _managed.count = count.ToManaged();
+                _managed.user_data = user_data;
+                return _managed;
+            }
+        }
+
+        public void Dispose()
+        {
+            log.Dispose();
+            count.Dispose();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "LogSinkVTable { ... }";
+        }
+
+        [CustomMarshaller(typeof(LogSinkVTable), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private LogSinkVTable _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(LogSinkVTable managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(LogSinkVTable managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public LogSinkVTable ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
     public partial struct NestedArray
     {
         public EnumRenamed field_enum;
@@ -3950,21 +4736,23 @@ namespace My.Company
         }
     }
 
-    public partial struct Tupled
+    public partial struct Timeout
     {
-        public byte x0;
+        public Duration after;
+        public uint retries;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct Tupled 
+    public partial struct Timeout 
     {
-        public Tupled() { }
+        public Timeout() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.x0 = x0;
+            _unmanaged.after = after.ToUnmanaged();
+            _unmanaged.retries = retries;
             return _unmanaged;
         }
 
@@ -3972,20 +4760,23 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.x0 = x0;
+            _unmanaged.after = after.ToUnmanaged();
+            _unmanaged.retries = retries;
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public byte x0;
+            public Duration.Unmanaged after;
+            public uint retries;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe Tupled ToManaged()
+            internal unsafe Timeout ToManaged()
             {
-                var _managed = new Tupled();
-                _managed.x0 = x0;
+                var _managed = new Timeout();
+                _managed.after = after.ToManaged();
+                _managed.retries = retries;
                 return _managed;
             }
         }
@@ -3994,24 +4785,101 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "Tupled { ... }";
+            return "Timeout { ... }";
         }
 
-        [CustomMarshaller(typeof(Tupled), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(Timeout), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private Tupled _managed;
+            private Timeout _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Tupled managed) { _managed = managed; }
+            public Marshaller(Timeout managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(Tupled managed) { _managed = managed; }
+            public void FromManaged(Timeout managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Timeout ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct Tupled
+    {
+        public byte x0;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tupled 
+    {
+        public Tupled() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public byte x0;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Tupled ToManaged()
+            {
+                var _managed = new Tupled();
+                _managed.x0 = x0;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tupled { ... }";
+        }
+
+        [CustomMarshaller(typeof(Tupled), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Tupled _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tupled managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tupled managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -4027,6 +4895,88 @@ namespace My.Company
         }
     }
 
+    public partial struct Tuples
+    {
+        public Tuple2_U32_F32 pair;
+        public Tuple3_Vec3f32_U8_I64 triple;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuples 
+    {
+        public Tuples() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.pair = pair.ToUnmanaged();
+            _unmanaged.triple = triple.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.pair = pair.ToUnmanaged();
+            _unmanaged.triple = triple.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public Tuple2_U32_F32.Unmanaged pair;
+            public Tuple3_Vec3f32_U8_I64.Unmanaged triple;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Tuples ToManaged()
+            {
+                var _managed = new Tuples();
+                _managed.pair = pair.ToManaged();
+                _managed.triple = triple.ToManaged();
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tuples { ... }";
+        }
+
+        [CustomMarshaller(typeof(Tuples), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Tuples _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuples managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuples managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuples ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
     public partial struct UseCStrPtr
     {
         public string ascii_string;
@@ -4191,6 +5141,88 @@ namespace My.Company
         }
     }
 
+    public partial struct UseStr
+    {
+        public Utf8Str s1;
+        public Utf8Str s2;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct UseStr 
+    {
+        public UseStr() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.ToUnmanaged();
+            _unmanaged.s2 = s2.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.ToUnmanaged();
+            _unmanaged.s2 = s2.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public Utf8Str.Unmanaged s1;
+            public Utf8Str.Unmanaged s2;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe UseStr ToManaged()
+            {
+                var _managed = new UseStr();
+                _managed.s1 = s1.ToManaged();
+                _managed.s2 = s2.ToManaged();
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "UseStr { ... }";
+        }
+
+        [CustomMarshaller(typeof(UseStr), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private UseStr _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(UseStr managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(UseStr managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public UseStr ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
     public partial class UseString
     {
         public Utf8String s1;
@@ -4957,26 +5989,27 @@ namespace My.Company
         }
     }
 
-    ///FFI buffer for Wire data transfer
-    public partial struct WireBuffer
+    public partial struct WideNum
     {
-        public IntPtr data;
-        public int len;
-        public int capacity;
+        public UInt128 id;
+        public Int128 delta;
+        public Rune initial;
+        public uint handle;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct WireBuffer 
+    public partial struct WideNum 
     {
-        public WireBuffer() { }
+        public WideNum() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.data = data;
-            _unmanaged.len = len;
-            _unmanaged.capacity = capacity;
+            _unmanaged.id = id;
+            _unmanaged.delta = delta;
+            _unmanaged.initial = initial;
+            _unmanaged.handle = handle;
             return _unmanaged;
         }
 
@@ -4984,26 +6017,29 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.data = data;
-            _unmanaged.len = len;
-            _unmanaged.capacity = capacity;
+            _unmanaged.id = id;
+            _unmanaged.delta = delta;
+            _unmanaged.initial = initial;
+            _unmanaged.handle = handle;
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public IntPtr data;
-            public int len;
-            public int capacity;
+            public UInt128 id;
+            public Int128 delta;
+            public Rune initial;
+            public uint handle;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe WireBuffer ToManaged()
+            internal unsafe WideNum ToManaged()
             {
-                var _managed = new WireBuffer();
-                _managed.data = data;
-                _managed.len = len;
-                _managed.capacity = capacity;
+                var _managed = new WideNum();
+                _managed.id = id;
+                _managed.delta = delta;
+                _managed.initial = initial;
+                _managed.handle = handle;
                 return _managed;
             }
         }
@@ -5012,24 +6048,24 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "WireBuffer { ... }";
+            return "WideNum { ... }";
         }
 
-        [CustomMarshaller(typeof(WireBuffer), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(WideNum), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private WireBuffer _managed;
+            private WideNum _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(WireBuffer managed) { _managed = managed; }
+            public Marshaller(WideNum managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(WireBuffer managed) { _managed = managed; }
+            public void FromManaged(WideNum managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -5038,32 +6074,120 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public WireBuffer ToManaged() { return _unmanaged.ToManaged(); }
+            public WideNum ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-
-    public partial class MyString
+    ///FFI buffer for Wire data transfer
+    public partial struct WireBuffer
     {
-        /// 
-        public String x;
+        public IntPtr data;
+        public int len;
+        public int capacity;
     }
 
-    public partial class MyString
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct WireBuffer 
     {
-        /// <summary>Empty constructor</summary>
-        public MyString() { }
+        public WireBuffer() { }
 
-        /// <summary>Member-wise initializing constructor</summary>
-        public MyString(String x)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            this.x = x;
+            var _unmanaged = new Unmanaged();
+            _unmanaged.data = data;
+            _unmanaged.len = len;
+            _unmanaged.capacity = capacity;
+            return _unmanaged;
         }
 
-        public override string ToString()
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.data = data;
+            _unmanaged.len = len;
+            _unmanaged.capacity = capacity;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public IntPtr data;
+            public int len;
+            public int capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe WireBuffer ToManaged()
+            {
+                var _managed = new WireBuffer();
+                _managed.data = data;
+                _managed.len = len;
+                _managed.capacity = capacity;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "WireBuffer { ... }";
+        }
+
+        [CustomMarshaller(typeof(WireBuffer), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private WireBuffer _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(WireBuffer managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(WireBuffer managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public WireBuffer ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+
+    public partial class MyString
+    {
+        /// 
+        public String x;
+    }
+
+    public partial class MyString
+    {
+        /// <summary>Empty constructor</summary>
+        public MyString() { }
+
+        /// <summary>Member-wise initializing constructor</summary>
+        public MyString(String x)
+        {
+            this.x = x;
+        }
+
+        public override string ToString()
         {
             return "MyString { x = " + x + " }";
         }
@@ -5282,10 +6406,69 @@ namespace My.Company
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate byte InteropDelegate_fn_u8_rval_u8(byte x0);
 
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint InteropDelegate_fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32(uint x0, uint x1, uint x2, uint x3, uint x4, uint x5, uint x6, uint x7);
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate IntPtr InteropDelegate_fn_u64_u64_rval_MutPtrVoid(ulong x0, ulong x1);
+
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void InteropDelegate_fn_CharArray_rval_void(CharArray x0);
     public delegate void InteropDelegate_fn_CharArray_rval_void_native(CharArray.Unmanaged x0);
 
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void InteropDelegate_fn_MutPtrVoid_u64_u64_rval_void(IntPtr x0, ulong x1, ulong x2);
+
+    [StructLayout(LayoutKind.Sequential)]
+    public partial struct Utf8Str
+    {
+        IntPtr _ptr;
+        ulong _len;
+
+        public int Length => (int) _len;
+
+        public unsafe ReadOnlySpan<byte> ReadOnlySpan
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => _ptr == IntPtr.Zero ? ReadOnlySpan<byte>.Empty : new(_ptr.ToPointer(), (int) _len);
+        }
+
+        // The bytes must stay pinned for as long as this value is used.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static Utf8Str From(IntPtr ptr, ulong len)
+        {
+            var rval = new Utf8Str();
+            rval._ptr = ptr;
+            rval._len = len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString() => Encoding.UTF8.GetString(ReadOnlySpan);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._ptr = _ptr;
+            unmanaged._len = _len;
+            return unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _ptr;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal Utf8Str ToManaged()
+            {
+                return Utf8Str.From(_ptr, _len);
+            }
+        }
+    }
+
     public partial class SliceBool
     {
         GCHandle _handle;
@@ -6911,278 +8094,455 @@ namespace My.Company
         }
 
 
-    ///Option that contains Some(value) or None.
-    public partial struct OptionEnumPayload
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct NdSliceF32
     {
-        uint _variant;
-        EnumPayload _Some;
-    }
+        IntPtr _data;
+        ulong _ndim;
+        fixed ulong _shape[4];
+        fixed long _strides[4];
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct OptionEnumPayload 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        public int Rank => (int) _ndim;
+
+        public int Count
         {
-            internal uint _variant;
-            internal EnumPayload.Unmanaged _Some;
+            get
+            {
+                if (_data == IntPtr.Zero) return 0;
+                long count = 1;
+                for (var d = 0; d < Rank; ++d) count *= (long) _shape[d];
+                return (int) count;
+            }
         }
 
+        // True if all elements are laid out row-major without gaps.
+        public bool IsContiguous
+        {
+            get
+            {
+                long expected = 1;
+                for (var d = Rank - 1; d >= 0; --d)
+                {
+                    if (_shape[d] != 1 && _strides[d] != expected) return false;
+                    expected *= (long) _shape[d];
+                }
+                return true;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public int GetLength(int dimension) => dimension >= 0 && dimension < Rank ? (int) _shape[dimension] : throw new IndexOutOfRangeException();
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public long GetStride(int dimension) => dimension >= 0 && dimension < Rank ? _strides[dimension] : throw new IndexOutOfRangeException();
 
-        [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        public ReadOnlySpan<float> ReadOnlySpan
         {
-            [FieldOffset(0)]
-            internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
+        }
 
+        public float this[int i0]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionEnumPayload ToManaged()
-            {
-                var _managed = new OptionEnumPayload();
-                _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.ToManaged();
-                return _managed;
-            }
+            get => Unsafe.Read<float>(At(1, i0, 0, 0, 0));
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        public float this[int i0, int i1]
         {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
-            return _unmanaged;
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<float>(At(2, i0, i1, 0, 0));
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged AsUnmanaged()
+        public float this[int i0, int i1, int i2]
         {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
-            return _unmanaged;
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<float>(At(3, i0, i1, i2, 0));
         }
 
-        public static OptionEnumPayload Some(EnumPayload value) => new() { _variant = 0, _Some = value };
-        public static OptionEnumPayload None => new() { _variant = 1 };
+        public float this[int i0, int i1, int i2, int i3]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => Unsafe.Read<float>(At(4, i0, i1, i2, i3));
+        }
 
-        public bool IsSome => _variant == 0;
-        public bool IsNone => _variant == 1;
+        void* At(int rank, int i0, int i1, int i2, int i3)
+        {
+            if (rank != Rank) throw new IndexOutOfRangeException();
+            Span<int> index = stackalloc int[] { i0, i1, i2, i3 };
+            long offset = 0;
+            for (var d = 0; d < rank; ++d)
+            {
+                if ((ulong) index[d] >= _shape[d]) throw new IndexOutOfRangeException();
+                offset += index[d] * _strides[d];
+            }
+            return (byte*) _data + offset * Unsafe.SizeOf<float>();
+        }
 
-        public EnumPayload AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        // The data must stay pinned for as long as this value is used.
+        public static NdSliceF32 From(IntPtr data, ReadOnlySpan<ulong> shape)
+        {
+            if (shape.Length > 4) throw new ArgumentException(Dimensions);
+            Span<long> strides = stackalloc long[shape.Length];
+            long stride = 1;
+            for (var d = shape.Length - 1; d >= 0; --d)
+            {
+                strides[d] = stride;
+                stride *= (long) shape[d];
+            }
+            return From(data, shape, strides);
+        }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        // Strides are counted in elements, not bytes.
+        public static NdSliceF32 From(IntPtr data, ReadOnlySpan<ulong> shape, ReadOnlySpan<long> strides)
         {
-            if (_variant == 0) return $"Some({AsSome().ToString()})";
-            if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (shape.Length > 4 || shape.Length != strides.Length) throw new ArgumentException(Dimensions);
+            var rval = new NdSliceF32();
+            rval._data = data;
+            rval._ndim = (ulong) shape.Length;
+            for (var d = 0; d < shape.Length; ++d)
+            {
+                rval._shape[d] = shape[d];
+                rval._strides[d] = strides[d];
+            }
+            return rval;
         }
 
+        const string Noncontiguous = "NdSlice is not contiguous.";
+        const string Dimensions = "NdSlice supports up to 4 dimensions, with one stride per dimension.";
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
+        internal Unmanaged ToUnmanaged() => Unsafe.As<NdSliceF32, Unmanaged>(ref this);
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
         {
-            if (_variant == 0) return new EnumException<EnumPayload>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            public IntPtr _data;
+            public ulong _ndim;
+            public fixed ulong _shape[4];
+            public fixed long _strides[4];
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal NdSliceF32 ToManaged() => Unsafe.As<Unmanaged, NdSliceF32>(ref this);
         }
+    }
 
-        [CustomMarshaller(typeof(OptionEnumPayload), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct NdSliceI32
+    {
+        IntPtr _data;
+        ulong _ndim;
+        fixed ulong _shape[4];
+        fixed long _strides[4];
 
-        public ref struct Marshaller
+        public int Rank => (int) _ndim;
+
+        public int Count
         {
-            private OptionEnumPayload _managed;
-            private Unmanaged _unmanaged;
+            get
+            {
+                if (_data == IntPtr.Zero) return 0;
+                long count = 1;
+                for (var d = 0; d < Rank; ++d) count *= (long) _shape[d];
+                return (int) count;
+            }
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionEnumPayload managed) { _managed = managed; }
+        // True if all elements are laid out row-major without gaps.
+        public bool IsContiguous
+        {
+            get
+            {
+                long expected = 1;
+                for (var d = Rank - 1; d >= 0; --d)
+                {
+                    if (_shape[d] != 1 && _strides[d] != expected) return false;
+                    expected *= (long) _shape[d];
+                }
+                return true;
+            }
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public int GetLength(int dimension) => dimension >= 0 && dimension < Rank ? (int) _shape[dimension] : throw new IndexOutOfRangeException();
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionEnumPayload managed) { _managed = managed; }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public long GetStride(int dimension) => dimension >= 0 && dimension < Rank ? _strides[dimension] : throw new IndexOutOfRangeException();
 
+        public ReadOnlySpan<int> ReadOnlySpan
+        {
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
+        }
+
+        public int this[int i0]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+            get => Unsafe.Read<int>(At(1, i0, 0, 0, 0));
+        }
 
+        public int this[int i0, int i1]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            get => Unsafe.Read<int>(At(2, i0, i1, 0, 0));
+        }
 
+        public int this[int i0, int i1, int i2]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionEnumPayload ToManaged() { return _unmanaged.ToManaged(); }
+            get => Unsafe.Read<int>(At(3, i0, i1, i2, 0));
+        }
 
+        public int this[int i0, int i1, int i2, int i3]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void Free() {}
+            get => Unsafe.Read<int>(At(4, i0, i1, i2, i3));
         }
-    }
 
-    ///Option that contains Some(value) or None.
-    public partial struct OptionInner
-    {
-        uint _variant;
-        Inner _Some;
-    }
-
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct OptionInner 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        void* At(int rank, int i0, int i1, int i2, int i3)
         {
-            internal uint _variant;
-            internal Inner.Unmanaged _Some;
+            if (rank != Rank) throw new IndexOutOfRangeException();
+            Span<int> index = stackalloc int[] { i0, i1, i2, i3 };
+            long offset = 0;
+            for (var d = 0; d < rank; ++d)
+            {
+                if ((ulong) index[d] >= _shape[d]) throw new IndexOutOfRangeException();
+                offset += index[d] * _strides[d];
+            }
+            return (byte*) _data + offset * Unsafe.SizeOf<int>();
         }
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        // The data must stay pinned for as long as this value is used.
+        public static NdSliceI32 From(IntPtr data, ReadOnlySpan<ulong> shape)
         {
-            [FieldOffset(0)]
-            internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
-
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionInner ToManaged()
+            if (shape.Length > 4) throw new ArgumentException(Dimensions);
+            Span<long> strides = stackalloc long[shape.Length];
+            long stride = 1;
+            for (var d = shape.Length - 1; d >= 0; --d)
             {
-                var _managed = new OptionInner();
-                _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.ToManaged();
-                return _managed;
+                strides[d] = stride;
+                stride *= (long) shape[d];
             }
+            return From(data, shape, strides);
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        // Strides are counted in elements, not bytes.
+        public static NdSliceI32 From(IntPtr data, ReadOnlySpan<ulong> shape, ReadOnlySpan<long> strides)
         {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
-            return _unmanaged;
+            if (shape.Length > 4 || shape.Length != strides.Length) throw new ArgumentException(Dimensions);
+            var rval = new NdSliceI32();
+            rval._data = data;
+            rval._ndim = (ulong) shape.Length;
+            for (var d = 0; d < shape.Length; ++d)
+            {
+                rval._shape[d] = shape[d];
+                rval._strides[d] = strides[d];
+            }
+            return rval;
         }
 
+        const string Noncontiguous = "NdSlice is not contiguous.";
+        const string Dimensions = "NdSlice supports up to 4 dimensions, with one stride per dimension.";
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged AsUnmanaged()
+        internal Unmanaged ToUnmanaged() => Unsafe.As<NdSliceI32, Unmanaged>(ref this);
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
         {
-            var _unmanaged = new Unmanaged();
-            _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
-            return _unmanaged;
+            public IntPtr _data;
+            public ulong _ndim;
+            public fixed ulong _shape[4];
+            public fixed long _strides[4];
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal NdSliceI32 ToManaged() => Unsafe.As<Unmanaged, NdSliceI32>(ref this);
         }
+    }
 
-        public static OptionInner Some(Inner value) => new() { _variant = 0, _Some = value };
-        public static OptionInner None => new() { _variant = 1 };
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct NdSliceMutU32
+    {
+        IntPtr _data;
+        ulong _ndim;
+        fixed ulong _shape[4];
+        fixed long _strides[4];
 
-        public bool IsSome => _variant == 0;
-        public bool IsNone => _variant == 1;
+        public int Rank => (int) _ndim;
 
-        public Inner AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public int Count
+        {
+            get
+            {
+                if (_data == IntPtr.Zero) return 0;
+                long count = 1;
+                for (var d = 0; d < Rank; ++d) count *= (long) _shape[d];
+                return (int) count;
+            }
+        }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        // True if all elements are laid out row-major without gaps.
+        public bool IsContiguous
         {
-            if (_variant == 0) return "Some(...)";
-            if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            get
+            {
+                long expected = 1;
+                for (var d = Rank - 1; d >= 0; --d)
+                {
+                    if (_shape[d] != 1 && _strides[d] != expected) return false;
+                    expected *= (long) _shape[d];
+                }
+                return true;
+            }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
+        public int GetLength(int dimension) => dimension >= 0 && dimension < Rank ? (int) _shape[dimension] : throw new IndexOutOfRangeException();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public long GetStride(int dimension) => dimension >= 0 && dimension < Rank ? _strides[dimension] : throw new IndexOutOfRangeException();
+
+        public ReadOnlySpan<uint> ReadOnlySpan
         {
-            if (_variant == 0) return new EnumException<Inner>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
         }
 
-        [CustomMarshaller(typeof(OptionInner), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
-
-        public ref struct Marshaller
+        public Span<uint> Span
         {
-            private OptionInner _managed;
-            private Unmanaged _unmanaged;
+            get => IsContiguous ? new(_data.ToPointer(), Count) : throw new InvalidOperationException(Noncontiguous);
+        }
 
+        public uint this[int i0]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionInner managed) { _managed = managed; }
-
+            get => Unsafe.Read<uint>(At(1, i0, 0, 0, 0));
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+            set => Unsafe.Write(At(1, i0, 0, 0, 0), value);
+        }
 
+        public uint this[int i0, int i1]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionInner managed) { _managed = managed; }
-
+            get => Unsafe.Read<uint>(At(2, i0, i1, 0, 0));
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+            set => Unsafe.Write(At(2, i0, i1, 0, 0), value);
+        }
 
+        public uint this[int i0, int i1, int i2]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            get => Unsafe.Read<uint>(At(3, i0, i1, i2, 0));
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            set => Unsafe.Write(At(3, i0, i1, i2, 0), value);
+        }
 
+        public uint this[int i0, int i1, int i2, int i3]
+        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionInner ToManaged() { return _unmanaged.ToManaged(); }
+            get => Unsafe.Read<uint>(At(4, i0, i1, i2, i3));
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            set => Unsafe.Write(At(4, i0, i1, i2, i3), value);
+        }
+
+        void* At(int rank, int i0, int i1, int i2, int i3)
+        {
+            if (rank != Rank) throw new IndexOutOfRangeException();
+            Span<int> index = stackalloc int[] { i0, i1, i2, i3 };
+            long offset = 0;
+            for (var d = 0; d < rank; ++d)
+            {
+                if ((ulong) index[d] >= _shape[d]) throw new IndexOutOfRangeException();
+                offset += index[d] * _strides[d];
+            }
+            return (byte*) _data + offset * Unsafe.SizeOf<uint>();
+        }
+
+        // The data must stay pinned for as long as this value is used.
+        public static NdSliceMutU32 From(IntPtr data, ReadOnlySpan<ulong> shape)
+        {
+            if (shape.Length > 4) throw new ArgumentException(Dimensions);
+            Span<long> strides = stackalloc long[shape.Length];
+            long stride = 1;
+            for (var d = shape.Length - 1; d >= 0; --d)
+            {
+                strides[d] = stride;
+                stride *= (long) shape[d];
+            }
+            return From(data, shape, strides);
+        }
+
+        // Strides are counted in elements, not bytes.
+        public static NdSliceMutU32 From(IntPtr data, ReadOnlySpan<ulong> shape, ReadOnlySpan<long> strides)
+        {
+            if (shape.Length > 4 || shape.Length != strides.Length) throw new ArgumentException(Dimensions);
+            var rval = new NdSliceMutU32();
+            rval._data = data;
+            rval._ndim = (ulong) shape.Length;
+            for (var d = 0; d < shape.Length; ++d)
+            {
+                rval._shape[d] = shape[d];
+                rval._strides[d] = strides[d];
+            }
+            return rval;
+        }
+
+        const string Noncontiguous = "NdSlice is not contiguous.";
+        const string Dimensions = "NdSlice supports up to 4 dimensions, with one stride per dimension.";
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged() => Unsafe.As<NdSliceMutU32, Unmanaged>(ref this);
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _ndim;
+            public fixed ulong _shape[4];
+            public fixed long _strides[4];
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void Free() {}
+            internal NdSliceMutU32 ToManaged() => Unsafe.As<Unmanaged, NdSliceMutU32>(ref this);
         }
     }
 
     ///Option that contains Some(value) or None.
-    public partial class OptionOptionResultOptionUtf8StringError
+    public partial struct OptionEnumPayload
     {
         uint _variant;
-        OptionResultOptionUtf8StringError _Some;
+        EnumPayload _Some;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class OptionOptionResultOptionUtf8StringError : IDisposable
+    public partial struct OptionEnumPayload 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
-            internal OptionResultOptionUtf8StringError.Unmanaged _Some;
+            [FieldOffset(0)]
+            internal EnumPayload.Unmanaged _Some;
         }
 
 
-        public void Dispose()
-        {
-            if (_variant == 0) { _Some.Dispose(); }
-        }
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionOptionResultOptionUtf8StringError IntoManaged()
+            internal OptionEnumPayload ToManaged()
             {
-                var _managed = new OptionOptionResultOptionUtf8StringError();
+                var _managed = new OptionEnumPayload();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.IntoManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.IntoUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7191,114 +8551,137 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.AsUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static OptionOptionResultOptionUtf8StringError Some(OptionResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
-        public static OptionOptionResultOptionUtf8StringError None => new() { _variant = 1 };
+        public static OptionEnumPayload Some(EnumPayload value) => new() { _variant = 0, _Some = value };
+        public static OptionEnumPayload None => new() { _variant = 1 };
 
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public OptionResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public EnumPayload AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Some(...)";
+            if (_variant == 0) return $"Some({AsSome().ToString()})";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<OptionResultOptionUtf8StringError>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(OptionOptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionEnumPayload), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private OptionOptionResultOptionUtf8StringError _managed;
+            private OptionEnumPayload _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
+            public Marshaller(OptionEnumPayload managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(OptionEnumPayload managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionOptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public OptionEnumPayload ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct OptionEnumPayload
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionEnumPayload(EnumPayload? value) => value is { } x ? Some(x) : None;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator EnumPayload?(OptionEnumPayload value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionEnumPayload FromNullable(EnumPayload? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public EnumPayload? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public EnumPayload GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public EnumPayload GetValueOrDefault(EnumPayload defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out EnumPayload value)
+        {
+            value = GetValueOrDefault();
+            return IsSome;
+        }
+
+        public TResult Match<TResult>(Func<EnumPayload, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<EnumPayload> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
+        }
+
+        public OptionEnumPayload Map(Func<EnumPayload, EnumPayload> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
     ///Option that contains Some(value) or None.
-    public partial class OptionResultOptionUtf8StringError
+    public partial struct OptionInner
     {
         uint _variant;
-        ResultOptionUtf8StringError _Some;
+        Inner _Some;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class OptionResultOptionUtf8StringError : IDisposable
+    public partial struct OptionInner 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
-            internal ResultOptionUtf8StringError.Unmanaged _Some;
+            [FieldOffset(0)]
+            internal Inner.Unmanaged _Some;
         }
 
 
-        public void Dispose()
-        {
-            if (_variant == 0) { _Some.Dispose(); }
-        }
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionResultOptionUtf8StringError IntoManaged()
+            internal OptionInner ToManaged()
             {
-                var _managed = new OptionResultOptionUtf8StringError();
+                var _managed = new OptionInner();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.IntoManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.IntoUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7307,104 +8690,131 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.AsUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static OptionResultOptionUtf8StringError Some(ResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
-        public static OptionResultOptionUtf8StringError None => new() { _variant = 1 };
+        public static OptionInner Some(Inner value) => new() { _variant = 0, _Some = value };
+        public static OptionInner None => new() { _variant = 1 };
 
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public ResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public Inner AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Some(...)";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<ResultOptionUtf8StringError>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(OptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionInner), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private OptionResultOptionUtf8StringError _managed;
+            private OptionInner _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionResultOptionUtf8StringError managed) { _managed = managed; }
+            public Marshaller(OptionInner managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionResultOptionUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(OptionInner managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public OptionInner ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Option that contains Some(value) or None.
-    public partial class OptionUtf8String
+    #nullable enable
+    public partial struct OptionInner
     {
-        uint _variant;
-        Utf8String _Some;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionInner(Inner? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class OptionUtf8String : IDisposable
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Inner?(OptionInner value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionInner FromNullable(Inner? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Inner? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Inner GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Inner GetValueOrDefault(Inner defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Inner value)
         {
-            internal uint _variant;
-            internal Utf8String.Unmanaged _Some;
+            value = GetValueOrDefault();
+            return IsSome;
+        }
+
+        public TResult Match<TResult>(Func<Inner, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Inner> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionInner Map(Func<Inner, Inner> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
+    ///Option that contains Some(value) or None.
+    public partial class OptionOptionResultOptionUtf8StringError
+    {
+        uint _variant;
+        OptionResultOptionUtf8StringError _Some;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OptionOptionResultOptionUtf8StringError : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal OptionResultOptionUtf8StringError.Unmanaged _Some;
+        }
 
         public void Dispose()
         {
             if (_variant == 0) { _Some.Dispose(); }
         }
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionUtf8String IntoManaged()
+            internal OptionOptionResultOptionUtf8StringError IntoManaged()
             {
-                var _managed = new OptionUtf8String();
+                var _managed = new OptionOptionResultOptionUtf8StringError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.IntoManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.IntoManaged();
                 return _managed;
             }
         }
@@ -7414,7 +8824,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.IntoUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -7423,51 +8833,43 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.AsUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.AsUnmanaged();
             return _unmanaged;
         }
 
-        public static OptionUtf8String Some(Utf8String value) => new() { _variant = 0, _Some = value };
-        public static OptionUtf8String None => new() { _variant = 1 };
+        public static OptionOptionResultOptionUtf8StringError Some(OptionResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
+        public static OptionOptionResultOptionUtf8StringError None => new() { _variant = 1 };
 
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public Utf8String AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public OptionResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Some(...)";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Utf8String>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(OptionUtf8String), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionOptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private OptionUtf8String _managed;
+            private OptionOptionResultOptionUtf8StringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionUtf8String managed) { _managed = managed; }
+            public Marshaller(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionUtf8String managed) { _managed = managed; }
+            public void FromManaged(OptionOptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7476,57 +8878,96 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
+            public OptionOptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial class OptionOptionResultOptionUtf8StringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionOptionResultOptionUtf8StringError(OptionResultOptionUtf8StringError? value) => value is { } x ? Some(x) : None;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionResultOptionUtf8StringError?(OptionOptionResultOptionUtf8StringError value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionOptionResultOptionUtf8StringError FromNullable(OptionResultOptionUtf8StringError? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionResultOptionUtf8StringError? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionResultOptionUtf8StringError GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionResultOptionUtf8StringError GetValueOrDefault(OptionResultOptionUtf8StringError defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out OptionResultOptionUtf8StringError value)
+        {
+            value = GetValueOrDefault();
+            return IsSome;
+        }
+
+        public TResult Match<TResult>(Func<OptionResultOptionUtf8StringError, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<OptionResultOptionUtf8StringError> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
+        }
+
+        public OptionOptionResultOptionUtf8StringError Map(Func<OptionResultOptionUtf8StringError, OptionResultOptionUtf8StringError> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
     ///Option that contains Some(value) or None.
-    public partial struct OptionVec
+    public partial class OptionResultOptionUtf8StringError
     {
         uint _variant;
-        Vec _Some;
+        ResultOptionUtf8StringError _Some;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct OptionVec 
+    public partial class OptionResultOptionUtf8StringError : IDisposable
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
-            internal Vec.Unmanaged _Some;
+            [FieldOffset(0)]
+            internal ResultOptionUtf8StringError.Unmanaged _Some;
         }
 
+        public void Dispose()
+        {
+            if (_variant == 0) { _Some.Dispose(); }
+        }
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal OptionVec ToManaged()
+            internal OptionResultOptionUtf8StringError IntoManaged()
             {
-                var _managed = new OptionVec();
+                var _managed = new OptionResultOptionUtf8StringError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.IntoManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        internal Unmanaged IntoUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -7535,113 +8976,127 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.AsUnmanaged();
             return _unmanaged;
         }
 
-        public static OptionVec Some(Vec value) => new() { _variant = 0, _Some = value };
-        public static OptionVec None => new() { _variant = 1 };
+        public static OptionResultOptionUtf8StringError Some(ResultOptionUtf8StringError value) => new() { _variant = 0, _Some = value };
+        public static OptionResultOptionUtf8StringError None => new() { _variant = 1 };
 
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public Vec AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public ResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Some(...)";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Vec>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(OptionVec), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private OptionVec _managed;
+            private OptionResultOptionUtf8StringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(OptionVec managed) { _managed = managed; }
+            public Marshaller(OptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(OptionVec managed) { _managed = managed; }
+            public void FromManaged(OptionResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public OptionVec ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceAsyncBasicError
+    #nullable enable
+    public partial class OptionResultOptionUtf8StringError
     {
-        uint _variant;
-        IntPtr _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionResultOptionUtf8StringError(ResultOptionUtf8StringError? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceAsyncBasicError 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator ResultOptionUtf8StringError?(OptionResultOptionUtf8StringError value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionResultOptionUtf8StringError FromNullable(ResultOptionUtf8StringError? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ResultOptionUtf8StringError? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ResultOptionUtf8StringError GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ResultOptionUtf8StringError GetValueOrDefault(ResultOptionUtf8StringError defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out ResultOptionUtf8StringError value)
         {
-            internal uint _variant;
-            internal IntPtr _Ok;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        public TResult Match<TResult>(Func<ResultOptionUtf8StringError, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<ResultOptionUtf8StringError> some, Action none)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionResultOptionUtf8StringError Map(Func<ResultOptionUtf8StringError, ResultOptionUtf8StringError> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
 
+    ///Option that contains Some(value) or None.
+    public partial struct OptionTuple2_U32_U32
+    {
+        uint _variant;
+        Tuple2_U32_U32 _Some;
+    }
 
-
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionTuple2_U32_U32 
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal Tuple2_U32_U32.Unmanaged _Some;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceAsyncBasicError ToManaged()
+            internal OptionTuple2_U32_U32 ToManaged()
             {
-                var _managed = new ResultConstPtrServiceAsyncBasicError();
+                var _managed = new OptionTuple2_U32_U32();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -7651,8 +9106,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7661,62 +9115,43 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceAsyncBasicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceAsyncBasicError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceAsyncBasicError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceAsyncBasicError Null => new() { _variant = 3 };
+        public static OptionTuple2_U32_U32 Some(Tuple2_U32_U32 value) => new() { _variant = 0, _Some = value };
+        public static OptionTuple2_U32_U32 None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Tuple2_U32_U32 AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncBasicError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionTuple2_U32_U32), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceAsyncBasicError _managed;
+            private OptionTuple2_U32_U32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
+            public Marshaller(OptionTuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
+            public void FromManaged(OptionTuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7725,71 +9160,96 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceAsyncBasicError ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionTuple2_U32_U32 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceAsyncResultError
+    #nullable enable
+    public partial struct OptionTuple2_U32_U32
     {
-        uint _variant;
-        IntPtr _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionTuple2_U32_U32(Tuple2_U32_U32? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceAsyncResultError 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple2_U32_U32?(OptionTuple2_U32_U32 value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionTuple2_U32_U32 FromNullable(Tuple2_U32_U32? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Tuple2_U32_U32? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Tuple2_U32_U32 GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Tuple2_U32_U32 GetValueOrDefault(Tuple2_U32_U32 defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Tuple2_U32_U32 value)
         {
-            internal uint _variant;
-            internal IntPtr _Ok;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        public TResult Match<TResult>(Func<Tuple2_U32_U32, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Tuple2_U32_U32> some, Action none)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionTuple2_U32_U32 Map(Func<Tuple2_U32_U32, Tuple2_U32_U32> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
 
+    ///Option that contains Some(value) or None.
+    public partial class OptionUtf8String
+    {
+        uint _variant;
+        Utf8String _Some;
+    }
 
-
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OptionUtf8String : IDisposable
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal Utf8String.Unmanaged _Some;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
+        public void Dispose()
+        {
+            if (_variant == 0) { _Some.Dispose(); }
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceAsyncResultError ToManaged()
+            internal OptionUtf8String IntoManaged()
             {
-                var _managed = new ResultConstPtrServiceAsyncResultError();
+                var _managed = new OptionUtf8String();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.IntoManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        internal Unmanaged IntoUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -7798,124 +9258,127 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.AsUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceAsyncResultError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceAsyncResultError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceAsyncResultError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceAsyncResultError Null => new() { _variant = 3 };
+        public static OptionUtf8String Some(Utf8String value) => new() { _variant = 0, _Some = value };
+        public static OptionUtf8String None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Utf8String AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncResultError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionUtf8String), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceAsyncResultError _managed;
+            private OptionUtf8String _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceAsyncResultError managed) { _managed = managed; }
+            public Marshaller(OptionUtf8String managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceAsyncResultError managed) { _managed = managed; }
+            public void FromManaged(OptionUtf8String managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceAsyncResultError ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceAsyncSleepError
+    #nullable enable
+    public partial class OptionUtf8String
     {
-        uint _variant;
-        IntPtr _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionUtf8String(Utf8String? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceAsyncSleepError 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Utf8String?(OptionUtf8String value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionUtf8String FromNullable(Utf8String? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String GetValueOrDefault(Utf8String defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Utf8String value)
         {
-            internal uint _variant;
-            internal IntPtr _Ok;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        public TResult Match<TResult>(Func<Utf8String, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Utf8String> some, Action none)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionUtf8String Map(Func<Utf8String, Utf8String> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
 
+    ///Option that contains Some(value) or None.
+    public partial struct OptionVec
+    {
+        uint _variant;
+        Vec _Some;
+    }
 
-
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionVec 
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal Vec.Unmanaged _Some;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceAsyncSleepError ToManaged()
+            internal OptionVec ToManaged()
             {
-                var _managed = new ResultConstPtrServiceAsyncSleepError();
+                var _managed = new OptionVec();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -7925,8 +9388,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7935,62 +9397,43 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceAsyncSleepError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceAsyncSleepError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceAsyncSleepError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceAsyncSleepError Null => new() { _variant = 3 };
+        public static OptionVec Some(Vec value) => new() { _variant = 0, _Some = value };
+        public static OptionVec None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Vec AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncSleepError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionVec), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceAsyncSleepError _managed;
+            private OptionVec _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceAsyncSleepError managed) { _managed = managed; }
+            public Marshaller(OptionVec managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceAsyncSleepError managed) { _managed = managed; }
+            public void FromManaged(OptionVec managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7999,60 +9442,82 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceAsyncSleepError ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionVec ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceAsyncStructsError
+    #nullable enable
+    public partial struct OptionVec
     {
-        uint _variant;
-        IntPtr _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionVec(Vec? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceAsyncStructsError 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Vec?(OptionVec value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionVec FromNullable(Vec? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec GetValueOrDefault(Vec defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Vec value)
         {
-            internal uint _variant;
-            internal IntPtr _Ok;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        public TResult Match<TResult>(Func<Vec, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Vec> some, Action none)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionVec Map(Func<Vec, Vec> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
 
+    ///Option that contains Some(value) or None.
+    public partial struct OptionVec3f32
+    {
+        uint _variant;
+        Vec3f32 _Some;
+    }
 
-
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionVec3f32 
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal Vec3f32.Unmanaged _Some;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceAsyncStructsError ToManaged()
+            internal OptionVec3f32 ToManaged()
             {
-                var _managed = new ResultConstPtrServiceAsyncStructsError();
+                var _managed = new OptionVec3f32();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -8062,8 +9527,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -8072,62 +9536,43 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceAsyncStructsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceAsyncStructsError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceAsyncStructsError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceAsyncStructsError Null => new() { _variant = 3 };
+        public static OptionVec3f32 Some(Vec3f32 value) => new() { _variant = 0, _Some = value };
+        public static OptionVec3f32 None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Vec3f32 AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncStructsError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionVec3f32), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceAsyncStructsError _managed;
+            private OptionVec3f32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceAsyncStructsError managed) { _managed = managed; }
+            public Marshaller(OptionVec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceAsyncStructsError managed) { _managed = managed; }
+            public void FromManaged(OptionVec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8136,15 +9581,54 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceAsyncStructsError ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionVec3f32 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct OptionVec3f32
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionVec3f32(Vec3f32? value) => value is { } x ? Some(x) : None;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Vec3f32?(OptionVec3f32 value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionVec3f32 FromNullable(Vec3f32? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec3f32? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec3f32 GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec3f32 GetValueOrDefault(Vec3f32 defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Vec3f32 value)
+        {
+            value = GetValueOrDefault();
+            return IsSome;
+        }
+
+        public TResult Match<TResult>(Func<Vec3f32, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Vec3f32> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
+        }
+
+        public OptionVec3f32 Map(Func<Vec3f32, Vec3f32> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceAsyncVecStringError
+    public partial struct ResultConstPtrServiceAsyncBasicError
     {
         uint _variant;
         IntPtr _Ok;
@@ -8152,44 +9636,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceAsyncVecStringError 
+    public partial struct ResultConstPtrServiceAsyncBasicError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceAsyncVecStringError ToManaged()
+            internal ResultConstPtrServiceAsyncBasicError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceAsyncVecStringError();
+                var _managed = new ResultConstPtrServiceAsyncBasicError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -8199,8 +9670,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -8209,25 +9680,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceAsyncVecStringError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceAsyncVecStringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceAsyncVecStringError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceAsyncVecStringError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceAsyncBasicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceAsyncBasicError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceAsyncBasicError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceAsyncBasicError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -8236,35 +9710,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncVecStringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncBasicError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceAsyncVecStringError _managed;
+            private ResultConstPtrServiceAsyncBasicError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceAsyncVecStringError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceAsyncVecStringError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8273,15 +9737,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceAsyncVecStringError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceAsyncBasicError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncBasicError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceBasicError
+    public partial struct ResultConstPtrServiceAsyncResultError
     {
         uint _variant;
         IntPtr _Ok;
@@ -8289,44 +9781,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceBasicError 
+    public partial struct ResultConstPtrServiceAsyncResultError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceBasicError ToManaged()
+            internal ResultConstPtrServiceAsyncResultError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceBasicError();
+                var _managed = new ResultConstPtrServiceAsyncResultError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -8336,8 +9815,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -8346,25 +9825,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceBasicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceBasicError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceBasicError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceBasicError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceAsyncResultError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceAsyncResultError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceAsyncResultError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceAsyncResultError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -8373,35 +9855,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultConstPtrServiceBasicError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncResultError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceBasicError _managed;
+            private ResultConstPtrServiceAsyncResultError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceBasicError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceAsyncResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceBasicError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceAsyncResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8410,15 +9882,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceBasicError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceAsyncResultError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncResultError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceCallbacksError
+    public partial struct ResultConstPtrServiceAsyncSleepError
     {
         uint _variant;
         IntPtr _Ok;
@@ -8426,44 +9926,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceCallbacksError 
+    public partial struct ResultConstPtrServiceAsyncSleepError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceCallbacksError ToManaged()
+            internal ResultConstPtrServiceAsyncSleepError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceCallbacksError();
+                var _managed = new ResultConstPtrServiceAsyncSleepError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -8473,8 +9960,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -8483,25 +9970,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceCallbacksError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceCallbacksError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceCallbacksError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceCallbacksError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceAsyncSleepError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceAsyncSleepError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceAsyncSleepError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceAsyncSleepError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -8510,35 +10000,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceCallbacksError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncSleepError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceCallbacksError _managed;
+            private ResultConstPtrServiceAsyncSleepError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceCallbacksError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceAsyncSleepError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceCallbacksError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceAsyncSleepError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8547,15 +10027,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceCallbacksError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceAsyncSleepError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncSleepError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceDependentError
+    public partial struct ResultConstPtrServiceAsyncStructsError
     {
         uint _variant;
         IntPtr _Ok;
@@ -8563,44 +10071,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceDependentError 
+    public partial struct ResultConstPtrServiceAsyncStructsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceDependentError ToManaged()
+            internal ResultConstPtrServiceAsyncStructsError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceDependentError();
+                var _managed = new ResultConstPtrServiceAsyncStructsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -8610,8 +10105,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -8620,25 +10115,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceDependentError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceDependentError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceDependentError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceDependentError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceAsyncStructsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceAsyncStructsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceAsyncStructsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceAsyncStructsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -8647,35 +10145,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceDependentError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncStructsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceDependentError _managed;
+            private ResultConstPtrServiceAsyncStructsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceDependentError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceAsyncStructsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceDependentError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceAsyncStructsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8684,15 +10172,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceDependentError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceAsyncStructsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncStructsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceIgnoringMethodsError
+    public partial struct ResultConstPtrServiceAsyncVecStringError
     {
         uint _variant;
         IntPtr _Ok;
@@ -8700,44 +10216,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceIgnoringMethodsError 
+    public partial struct ResultConstPtrServiceAsyncVecStringError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceIgnoringMethodsError ToManaged()
+            internal ResultConstPtrServiceAsyncVecStringError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceIgnoringMethodsError();
+                var _managed = new ResultConstPtrServiceAsyncVecStringError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -8747,8 +10250,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -8757,25 +10260,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceIgnoringMethodsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceIgnoringMethodsError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceIgnoringMethodsError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceIgnoringMethodsError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceAsyncVecStringError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceAsyncVecStringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceAsyncVecStringError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceAsyncVecStringError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -8784,35 +10290,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceIgnoringMethodsError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncVecStringError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceIgnoringMethodsError _managed;
+            private ResultConstPtrServiceAsyncVecStringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceAsyncVecStringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceAsyncVecStringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8821,15 +10317,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceIgnoringMethodsError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceAsyncVecStringError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncVecStringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceMainError
+    public partial struct ResultConstPtrServiceBasicError
     {
         uint _variant;
         IntPtr _Ok;
@@ -8837,44 +10361,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceMainError 
+    public partial struct ResultConstPtrServiceBasicError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceMainError ToManaged()
+            internal ResultConstPtrServiceBasicError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceMainError();
+                var _managed = new ResultConstPtrServiceBasicError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -8884,8 +10395,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -8894,25 +10405,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceMainError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceMainError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceMainError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceMainError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceBasicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceBasicError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceBasicError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceBasicError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -8921,35 +10435,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceMainError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceBasicError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceMainError _managed;
+            private ResultConstPtrServiceBasicError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceMainError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceBasicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceMainError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceBasicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8958,15 +10462,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceMainError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceBasicError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceBasicError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceMultipleCtorsError
+    public partial struct ResultConstPtrServiceCallbacksError
     {
         uint _variant;
         IntPtr _Ok;
@@ -8974,44 +10506,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceMultipleCtorsError 
+    public partial struct ResultConstPtrServiceCallbacksError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceMultipleCtorsError ToManaged()
+            internal ResultConstPtrServiceCallbacksError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceMultipleCtorsError();
+                var _managed = new ResultConstPtrServiceCallbacksError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9021,8 +10540,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9031,25 +10550,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceMultipleCtorsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceMultipleCtorsError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceMultipleCtorsError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceMultipleCtorsError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceCallbacksError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceCallbacksError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceCallbacksError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceCallbacksError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -9058,35 +10580,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceMultipleCtorsError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceCallbacksError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceMultipleCtorsError _managed;
+            private ResultConstPtrServiceCallbacksError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceCallbacksError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceCallbacksError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9095,15 +10607,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceMultipleCtorsError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceCallbacksError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceCallbacksError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceOnPanicError
+    public partial struct ResultConstPtrServiceDependentError
     {
         uint _variant;
         IntPtr _Ok;
@@ -9111,44 +10651,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceOnPanicError 
+    public partial struct ResultConstPtrServiceDependentError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceOnPanicError ToManaged()
+            internal ResultConstPtrServiceDependentError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceOnPanicError();
+                var _managed = new ResultConstPtrServiceDependentError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9158,8 +10685,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9168,25 +10695,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceOnPanicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceOnPanicError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceOnPanicError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceOnPanicError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceDependentError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceDependentError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceDependentError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceDependentError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -9195,35 +10725,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceOnPanicError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceDependentError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceOnPanicError _managed;
+            private ResultConstPtrServiceDependentError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceDependentError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceDependentError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9232,15 +10752,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceOnPanicError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceDependentError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceDependentError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceResultError
+    public partial struct ResultConstPtrServiceForeignLoggerError
     {
         uint _variant;
         IntPtr _Ok;
@@ -9248,44 +10796,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceResultError 
+    public partial struct ResultConstPtrServiceForeignLoggerError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceResultError ToManaged()
+            internal ResultConstPtrServiceForeignLoggerError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceResultError();
+                var _managed = new ResultConstPtrServiceForeignLoggerError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9295,8 +10830,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9305,25 +10840,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceResultError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceResultError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceResultError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceResultError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceForeignLoggerError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceForeignLoggerError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceForeignLoggerError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceForeignLoggerError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -9332,35 +10870,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceResultError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceForeignLoggerError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceResultError _managed;
+            private ResultConstPtrServiceForeignLoggerError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceResultError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceForeignLoggerError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceResultError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceForeignLoggerError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9369,15 +10897,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceResultError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceForeignLoggerError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceForeignLoggerError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceStringsError
+    public partial struct ResultConstPtrServiceIgnoringMethodsError
     {
         uint _variant;
         IntPtr _Ok;
@@ -9385,44 +10941,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceStringsError 
+    public partial struct ResultConstPtrServiceIgnoringMethodsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceStringsError ToManaged()
+            internal ResultConstPtrServiceIgnoringMethodsError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceStringsError();
+                var _managed = new ResultConstPtrServiceIgnoringMethodsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9432,8 +10975,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9442,25 +10985,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceStringsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceStringsError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceStringsError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceStringsError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceIgnoringMethodsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceIgnoringMethodsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceIgnoringMethodsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceIgnoringMethodsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -9469,35 +11015,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceStringsError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceIgnoringMethodsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceStringsError _managed;
+            private ResultConstPtrServiceIgnoringMethodsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceStringsError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceStringsError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9506,15 +11042,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceStringsError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceIgnoringMethodsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceIgnoringMethodsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceVariousSlicesError
+    public partial struct ResultConstPtrServiceMainError
     {
         uint _variant;
         IntPtr _Ok;
@@ -9522,44 +11086,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceVariousSlicesError 
+    public partial struct ResultConstPtrServiceMainError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceVariousSlicesError ToManaged()
+            internal ResultConstPtrServiceMainError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceVariousSlicesError();
+                var _managed = new ResultConstPtrServiceMainError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9569,8 +11120,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9579,25 +11130,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceVariousSlicesError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceVariousSlicesError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceVariousSlicesError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceVariousSlicesError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceMainError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceMainError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceMainError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceMainError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -9606,35 +11160,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceVariousSlicesError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceMainError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceVariousSlicesError _managed;
+            private ResultConstPtrServiceMainError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceMainError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceMainError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9643,49 +11187,75 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceVariousSlicesError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceMainError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceMainError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultError
+    public partial struct ResultConstPtrServiceMultipleCtorsError
     {
         uint _variant;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultError 
+    public partial struct ResultConstPtrServiceMultipleCtorsError 
     {
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultError ToManaged()
+            internal ResultConstPtrServiceMultipleCtorsError ToManaged()
             {
-                var _managed = new ResultError();
+                var _managed = new ResultConstPtrServiceMultipleCtorsError();
                 _managed._variant = _variant;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9695,7 +11265,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9704,24 +11275,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultError Ok => new() { _variant = 0 };
-        public static ResultError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultError Panic => new() { _variant = 2 };
-        public static ResultError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceMultipleCtorsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceMultipleCtorsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceMultipleCtorsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceMultipleCtorsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public void AsOk() { if (_variant != 0) throw ExceptionForVariant(); }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -9730,35 +11305,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceMultipleCtorsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultError _managed;
+            private ResultConstPtrServiceMultipleCtorsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9767,60 +11332,75 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceMultipleCtorsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceMultipleCtorsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultNestedArrayError
+    public partial struct ResultConstPtrServiceMutexError
     {
         uint _variant;
-        NestedArray _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultNestedArrayError 
+    public partial struct ResultConstPtrServiceMutexError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal NestedArray.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultNestedArrayError ToManaged()
+            internal ResultConstPtrServiceMutexError ToManaged()
             {
-                var _managed = new ResultNestedArrayError();
+                var _managed = new ResultConstPtrServiceMutexError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.ToManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9830,8 +11410,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9840,25 +11420,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultNestedArrayError Ok(NestedArray value) => new() { _variant = 0, _Ok = value };
-        public static ResultNestedArrayError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultNestedArrayError Panic => new() { _variant = 2 };
-        public static ResultNestedArrayError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceMutexError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceMutexError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceMutexError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceMutexError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public NestedArray AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -9867,35 +11450,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<NestedArray>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultNestedArrayError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceMutexError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultNestedArrayError _managed;
+            private ResultConstPtrServiceMutexError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultNestedArrayError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceMutexError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultNestedArrayError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceMutexError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9904,60 +11477,75 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultNestedArrayError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceMutexError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceMutexError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultOptionEnumPayloadError
+    public partial struct ResultConstPtrServiceOnPanicError
     {
         uint _variant;
-        OptionEnumPayload _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultOptionEnumPayloadError 
+    public partial struct ResultConstPtrServiceOnPanicError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal OptionEnumPayload.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultOptionEnumPayloadError ToManaged()
+            internal ResultConstPtrServiceOnPanicError ToManaged()
             {
-                var _managed = new ResultOptionEnumPayloadError();
+                var _managed = new ResultConstPtrServiceOnPanicError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.ToManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -9967,8 +11555,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -9977,25 +11565,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultOptionEnumPayloadError Ok(OptionEnumPayload value) => new() { _variant = 0, _Ok = value };
-        public static ResultOptionEnumPayloadError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultOptionEnumPayloadError Panic => new() { _variant = 2 };
-        public static ResultOptionEnumPayloadError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceOnPanicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceOnPanicError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceOnPanicError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceOnPanicError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public OptionEnumPayload AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -10004,35 +11595,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<OptionEnumPayload>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultOptionEnumPayloadError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceOnPanicError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultOptionEnumPayloadError _managed;
+            private ResultConstPtrServiceOnPanicError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultOptionEnumPayloadError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultOptionEnumPayloadError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -10041,75 +11622,86 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultOptionEnumPayloadError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceOnPanicError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceOnPanicError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial class ResultOptionUtf8StringError
+    public partial struct ResultConstPtrServicePropertiesError
     {
         uint _variant;
-        OptionUtf8String _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultOptionUtf8StringError : IDisposable
+    public partial struct ResultConstPtrServicePropertiesError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal OptionUtf8String.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-        public void Dispose()
-        {
-            if (_variant == 0) { _Ok.Dispose(); }
-        }
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultOptionUtf8StringError IntoManaged()
+            internal ResultConstPtrServicePropertiesError ToManaged()
             {
-                var _managed = new ResultOptionUtf8StringError();
+                var _managed = new ResultConstPtrServicePropertiesError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -10118,25 +11710,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultOptionUtf8StringError Ok(OptionUtf8String value) => new() { _variant = 0, _Ok = value };
-        public static ResultOptionUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultOptionUtf8StringError Panic => new() { _variant = 2 };
-        public static ResultOptionUtf8StringError Null => new() { _variant = 3 };
+        public static ResultConstPtrServicePropertiesError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServicePropertiesError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServicePropertiesError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServicePropertiesError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public OptionUtf8String AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -10145,97 +11740,102 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<OptionUtf8String>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServicePropertiesError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultOptionUtf8StringError _managed;
+            private ResultConstPtrServicePropertiesError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultOptionUtf8StringError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServicePropertiesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultOptionUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServicePropertiesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public ResultConstPtrServicePropertiesError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServicePropertiesError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultU32Error
+    public partial struct ResultConstPtrServiceResultError
     {
         uint _variant;
-        uint _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultU32Error 
+    public partial struct ResultConstPtrServiceResultError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal uint _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultU32Error ToManaged()
+            internal ResultConstPtrServiceResultError ToManaged()
             {
-                var _managed = new ResultU32Error();
+                var _managed = new ResultConstPtrServiceResultError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -10245,8 +11845,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -10255,25 +11855,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultU32Error Ok(uint value) => new() { _variant = 0, _Ok = value };
-        public static ResultU32Error Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultU32Error Panic => new() { _variant = 2 };
-        public static ResultU32Error Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceResultError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceResultError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceResultError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceResultError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public uint AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -10282,35 +11885,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<uint>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultU32Error), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceResultError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultU32Error _managed;
+            private ResultConstPtrServiceResultError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultU32Error managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultU32Error managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -10319,60 +11912,75 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultU32Error ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceResultError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceResultError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultU64Error
+    public partial struct ResultConstPtrServiceStaticsError
     {
         uint _variant;
-        ulong _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultU64Error 
+    public partial struct ResultConstPtrServiceStaticsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal ulong _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultU64Error ToManaged()
+            internal ResultConstPtrServiceStaticsError ToManaged()
             {
-                var _managed = new ResultU64Error();
+                var _managed = new ResultConstPtrServiceStaticsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -10382,8 +11990,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -10392,25 +12000,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultU64Error Ok(ulong value) => new() { _variant = 0, _Ok = value };
-        public static ResultU64Error Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultU64Error Panic => new() { _variant = 2 };
-        public static ResultU64Error Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceStaticsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStaticsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStaticsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStaticsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public ulong AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -10419,35 +12030,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<ulong>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultU64Error), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceStaticsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultU64Error _managed;
+            private ResultConstPtrServiceStaticsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultU64Error managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStaticsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultU64Error managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStaticsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -10456,75 +12057,86 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultU64Error ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceStaticsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceStaticsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial class ResultUseStringError
+    public partial struct ResultConstPtrServiceStorageArrayError
     {
         uint _variant;
-        UseString _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultUseStringError : IDisposable
+    public partial struct ResultConstPtrServiceStorageArrayError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal UseString.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-        public void Dispose()
-        {
-            if (_variant == 0) { _Ok.Dispose(); }
-        }
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultUseStringError IntoManaged()
+            internal ResultConstPtrServiceStorageArrayError ToManaged()
             {
-                var _managed = new ResultUseStringError();
+                var _managed = new ResultConstPtrServiceStorageArrayError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -10533,25 +12145,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultUseStringError Ok(UseString value) => new() { _variant = 0, _Ok = value };
-        public static ResultUseStringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultUseStringError Panic => new() { _variant = 2 };
-        public static ResultUseStringError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceStorageArrayError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStorageArrayError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStorageArrayError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStorageArrayError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public UseString AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -10560,112 +12175,113 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<UseString>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultUseStringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceStorageArrayError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultUseStringError _managed;
+            private ResultConstPtrServiceStorageArrayError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultUseStringError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStorageArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultUseStringError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStorageArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultUseStringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public ResultConstPtrServiceStorageArrayError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceStorageArrayError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial class ResultUtf8StringError
+    public partial struct ResultConstPtrServiceStorageOffsetError
     {
         uint _variant;
-        Utf8String _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultUtf8StringError : IDisposable
+    public partial struct ResultConstPtrServiceStorageOffsetError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal Utf8String.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-        public void Dispose()
-        {
-            if (_variant == 0) { _Ok.Dispose(); }
-        }
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultUtf8StringError IntoManaged()
+            internal ResultConstPtrServiceStorageOffsetError ToManaged()
             {
-                var _managed = new ResultUtf8StringError();
+                var _managed = new ResultConstPtrServiceStorageOffsetError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -10674,25 +12290,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultUtf8StringError Ok(Utf8String value) => new() { _variant = 0, _Ok = value };
-        public static ResultUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultUtf8StringError Panic => new() { _variant = 2 };
-        public static ResultUtf8StringError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceStorageOffsetError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStorageOffsetError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStorageOffsetError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStorageOffsetError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public Utf8String AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -10701,112 +12320,113 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Utf8String>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceStorageOffsetError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultUtf8StringError _managed;
+            private ResultConstPtrServiceStorageOffsetError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultUtf8StringError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStorageOffsetError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStorageOffsetError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public ResultConstPtrServiceStorageOffsetError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceStorageOffsetError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial class ResultVecUtf8StringError
+    public partial struct ResultConstPtrServiceStringsError
     {
         uint _variant;
-        VecUtf8String _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultVecUtf8StringError : IDisposable
+    public partial struct ResultConstPtrServiceStringsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal VecUtf8String.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-        public void Dispose()
-        {
-            if (_variant == 0) { _Ok.Dispose(); }
-        }
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultVecUtf8StringError IntoManaged()
+            internal ResultConstPtrServiceStringsError ToManaged()
             {
-                var _managed = new ResultVecUtf8StringError();
+                var _managed = new ResultConstPtrServiceStringsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -10815,25 +12435,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultVecUtf8StringError Ok(VecUtf8String value) => new() { _variant = 0, _Ok = value };
-        public static ResultVecUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultVecUtf8StringError Panic => new() { _variant = 2 };
-        public static ResultVecUtf8StringError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceStringsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStringsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStringsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStringsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public VecUtf8String AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -10842,75 +12465,102 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<VecUtf8String>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultVecUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceStringsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultVecUtf8StringError _managed;
+            private ResultConstPtrServiceStringsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultVecUtf8StringError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStringsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultVecUtf8StringError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStringsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultVecUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public ResultConstPtrServiceStringsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceStringsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultVoid
+    public partial struct ResultConstPtrServiceThreadAffineSyncError
     {
         uint _variant;
+        IntPtr _Ok;
+        SyncError _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultVoid 
+    public partial struct ResultConstPtrServiceThreadAffineSyncError 
     {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
+            internal SyncError.Unmanaged _Err;
+        }
 
 
-
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultVoid ToManaged()
+            internal ResultConstPtrServiceThreadAffineSyncError ToManaged()
             {
-                var _managed = new ResultVoid();
+                var _managed = new ResultConstPtrServiceThreadAffineSyncError();
                 _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -10920,6 +12570,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -10928,60 +12580,55 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultVoid Ok => new() { _variant = 0 };
-        public static ResultVoid Err => new() { _variant = 1 };
-        public static ResultVoid Panic => new() { _variant = 2 };
-        public static ResultVoid Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceThreadAffineSyncError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceThreadAffineSyncError Err(SyncError value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceThreadAffineSyncError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceThreadAffineSyncError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public void AsOk() { if (_variant != 0) throw ExceptionForVariant(); }
-        public void AsErr() { if (_variant != 1) throw ExceptionForVariant(); }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? SyncErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public SyncError AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return "Err(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException();
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultVoid), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceThreadAffineSyncError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultVoid _managed;
+            private ResultConstPtrServiceThreadAffineSyncError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultVoid managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceThreadAffineSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultVoid managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceThreadAffineSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -10990,132 +12637,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultVoid ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceThreadAffineSyncError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [Serializable]
-    [StructLayout(LayoutKind.Sequential)]
-    public partial struct Bool
+    #nullable enable
+    public partial struct ResultConstPtrServiceThreadAffineSyncError
     {
-        byte value;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
 
-    public partial struct Bool
-    {
-        public static readonly Bool True = new Bool { value =  1 };
-        public static readonly Bool False = new Bool { value =  0 };
-        public Bool(bool b)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
         {
-            value = (byte) (b ? 1 : 0);
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
-        public bool Is => value == 1;
-    }
 
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<SyncError, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void CallbackCharArray2Native(CharArray.Unmanaged value, IntPtr callback_data); // 'True' native callback signature
-    public delegate void CallbackCharArray2Delegate(CharArray value); // Our C# signature
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out SyncError? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
 
-    public partial class CallbackCharArray2
+    ///Result that contains value or an error.
+    public partial struct ResultConstPtrServiceVariousSlicesError
     {
-        private CallbackCharArray2Delegate _managed; // C# callback
-        private CallbackCharArray2Native _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        IntPtr _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackCharArray2 : IDisposable
+    public partial struct ResultConstPtrServiceVariousSlicesError 
     {
-
-        internal CallbackCharArray2() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackCharArray2(CallbackCharArray2Delegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(CharArray.Unmanaged value, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(value.ToManaged());
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultConstPtrServiceVariousSlicesError ToManaged()
             {
-                _exception = e;
-                return;
+                var _managed = new ResultConstPtrServiceVariousSlicesError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(CharArray value)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackCharArray2Native>(_ptr);
-            __target(value.ToUnmanaged(), IntPtr.Zero);
-            return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultConstPtrServiceVariousSlicesError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceVariousSlicesError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceVariousSlicesError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceVariousSlicesError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(CallbackCharArray2), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public CallbackCharArray2 ToManaged()
-            {
-                var rval = new CallbackCharArray2();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultConstPtrServiceVariousSlicesError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackCharArray2 _managed;
+            private ResultConstPtrServiceVariousSlicesError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackCharArray2 managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackCharArray2 managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -11124,112 +12782,137 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackCharArray2 ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceVariousSlicesError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate byte CallbackFFISliceNative(SliceU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
-    public delegate byte CallbackFFISliceDelegate(SliceU8 slice); // Our C# signature
+    #nullable enable
+    public partial struct ResultConstPtrServiceVariousSlicesError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
 
-    public partial class CallbackFFISlice
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultError
     {
-        private CallbackFFISliceDelegate _managed; // C# callback
-        private CallbackFFISliceNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackFFISlice : IDisposable
+    public partial struct ResultError 
     {
-
-        internal CallbackFFISlice() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackFFISlice(CallbackFFISliceDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private byte CallTrampoline(SliceU8.Unmanaged slice, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(slice.ToManaged());
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultError ToManaged()
             {
-                _exception = e;
-                return default;
+                var _managed = new ResultError();
+                _managed._variant = _variant;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte Call(SliceU8 slice)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackFFISliceNative>(_ptr);
-            return __target(slice.ToUnmanaged(), IntPtr.Zero);
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultError Ok => new() { _variant = 0 };
+        public static ResultError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultError Panic => new() { _variant = 2 };
+        public static ResultError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(CallbackFFISlice), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public CallbackFFISlice ToManaged()
-            {
-                var rval = new CallbackFFISlice();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public void AsOk() { if (_variant != 0) throw ToException(); }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackFFISlice _managed;
+            private ResultError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackFFISlice managed) { _managed = managed; }
+            public Marshaller(ResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackFFISlice managed) { _managed = managed; }
+            public void FromManaged(ResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -11238,112 +12921,125 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackFFISlice ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate Vec3f32.Unmanaged CallbackHugeVecSliceNative(SliceVec3f32.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
-    public delegate Vec3f32 CallbackHugeVecSliceDelegate(SliceVec3f32 slice); // Our C# signature
+    #nullable enable
+    public partial struct ResultError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Unwrap() => AsOk();
 
-    public partial class CallbackHugeVecSlice
+        public TResult Match<TResult>(Func<TResult> ok, Func<Error, TResult> err) => IsOk ? ok() : IsErr ? err(_Err) : throw ToException();
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultNestedArrayError
     {
-        private CallbackHugeVecSliceDelegate _managed; // C# callback
-        private CallbackHugeVecSliceNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        NestedArray _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackHugeVecSlice : IDisposable
+    public partial struct ResultNestedArrayError 
     {
-
-        internal CallbackHugeVecSlice() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackHugeVecSlice(CallbackHugeVecSliceDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal NestedArray.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private Vec3f32.Unmanaged CallTrampoline(SliceVec3f32.Unmanaged slice, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(slice.ToManaged()).ToUnmanaged();
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultNestedArrayError ToManaged()
             {
-                _exception = e;
-                return default;
+                var _managed = new ResultNestedArrayError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.ToManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Vec3f32 Call(SliceVec3f32 slice)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackHugeVecSliceNative>(_ptr);
-            return __target(slice.ToUnmanaged(), IntPtr.Zero).ToManaged();
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultNestedArrayError Ok(NestedArray value) => new() { _variant = 0, _Ok = value };
+        public static ResultNestedArrayError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultNestedArrayError Panic => new() { _variant = 2 };
+        public static ResultNestedArrayError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(CallbackHugeVecSlice), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public CallbackHugeVecSlice ToManaged()
-            {
-                var rval = new CallbackHugeVecSlice();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public NestedArray AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultNestedArrayError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackHugeVecSlice _managed;
+            private ResultNestedArrayError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackHugeVecSlice managed) { _managed = managed; }
+            public Marshaller(ResultNestedArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackHugeVecSlice managed) { _managed = managed; }
+            public void FromManaged(ResultNestedArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -11352,113 +13048,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackHugeVecSlice ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultNestedArrayError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void CallbackSliceMutNative(SliceMutU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
-    public delegate void CallbackSliceMutDelegate(SliceMutU8 slice); // Our C# signature
+    #nullable enable
+    public partial struct ResultNestedArrayError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public NestedArray Unwrap() => AsOk();
 
-    public partial class CallbackSliceMut
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public NestedArray UnwrapOr(NestedArray fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out NestedArray value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<NestedArray, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out NestedArray? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultOptionEnumPayloadError
     {
-        private CallbackSliceMutDelegate _managed; // C# callback
-        private CallbackSliceMutNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        OptionEnumPayload _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackSliceMut : IDisposable
+    public partial struct ResultOptionEnumPayloadError 
     {
-
-        internal CallbackSliceMut() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackSliceMut(CallbackSliceMutDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal OptionEnumPayload.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(SliceMutU8.Unmanaged slice, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(slice.ToManaged());
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultOptionEnumPayloadError ToManaged()
             {
-                _exception = e;
-                return;
+                var _managed = new ResultOptionEnumPayloadError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.ToManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(SliceMutU8 slice)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackSliceMutNative>(_ptr);
-            __target(slice.ToUnmanaged(), IntPtr.Zero);
-            return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultOptionEnumPayloadError Ok(OptionEnumPayload value) => new() { _variant = 0, _Ok = value };
+        public static ResultOptionEnumPayloadError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultOptionEnumPayloadError Panic => new() { _variant = 2 };
+        public static ResultOptionEnumPayloadError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(CallbackSliceMut), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public CallbackSliceMut ToManaged()
-            {
-                var rval = new CallbackSliceMut();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public OptionEnumPayload AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultOptionEnumPayloadError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackSliceMut _managed;
+            private ResultOptionEnumPayloadError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackSliceMut managed) { _managed = managed; }
+            public Marshaller(ResultOptionEnumPayloadError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackSliceMut managed) { _managed = managed; }
+            public void FromManaged(ResultOptionEnumPayloadError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -11467,226 +13193,286 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackSliceMut ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultOptionEnumPayloadError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate byte CallbackU8Native(byte value, IntPtr callback_data); // 'True' native callback signature
-    public delegate byte CallbackU8Delegate(byte value); // Our C# signature
-
-    public partial class CallbackU8
+    #nullable enable
+    public partial struct ResultOptionEnumPayloadError
     {
-        private CallbackU8Delegate _managed; // C# callback
-        private CallbackU8Native _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionEnumPayload Unwrap() => AsOk();
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackU8 : IDisposable
-    {
-
-        internal CallbackU8() { }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionEnumPayload UnwrapOr(OptionEnumPayload fallback) => IsOk ? _Ok : fallback;
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackU8(CallbackU8Delegate managed)
+        public bool TryGet(out OptionEnumPayload value)
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private byte CallTrampoline(byte value, IntPtr callback_data)
+        public TResult Match<TResult>(Func<OptionEnumPayload, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out OptionEnumPayload? value, out Error? error)
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(value);
-            }
-            catch (Exception e)
-            {
-                _exception = e;
-                return default;
-            }
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
         }
+    }
+    #nullable restore
 
-        // Invokes the callback.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte Call(byte value)
+    ///Result that contains value or an error.
+    public partial class ResultOptionUtf8StringError
+    {
+        uint _variant;
+        OptionUtf8String _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class ResultOptionUtf8StringError : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackU8Native>(_ptr);
-            return __target(value, IntPtr.Zero);
+            [FieldOffset(0)]
+            internal OptionUtf8String.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            if (_variant == 0) { _Ok.Dispose(); }
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultOptionUtf8StringError IntoManaged()
+            {
+                var _managed = new ResultOptionUtf8StringError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
+            }
         }
 
-        [CustomMarshaller(typeof(CallbackU8), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged IntoUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
         {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
 
-            public CallbackU8 ToManaged()
-            {
-                var rval = new CallbackU8();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public static ResultOptionUtf8StringError Ok(OptionUtf8String value) => new() { _variant = 0, _Ok = value };
+        public static ResultOptionUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultOptionUtf8StringError Panic => new() { _variant = 2 };
+        public static ResultOptionUtf8StringError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
+        public OptionUtf8String AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultOptionUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackU8 _managed;
+            private ResultOptionUtf8StringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackU8 managed) { _managed = managed; }
+            public Marshaller(ResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackU8 managed) { _managed = managed; }
+            public void FromManaged(ResultOptionUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackU8 ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultOptionUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate uint MyCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
-    public delegate uint MyCallbackDelegate(uint value); // Our C# signature
+    #nullable enable
+    public partial class ResultOptionUtf8StringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionUtf8String Unwrap() => AsOk();
 
-    public partial class MyCallback
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionUtf8String UnwrapOr(OptionUtf8String fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out OptionUtf8String value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<OptionUtf8String, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out OptionUtf8String? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultSyncError
     {
-        private MyCallbackDelegate _managed; // C# callback
-        private MyCallbackNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        SyncError _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallback : IDisposable
+    public partial struct ResultSyncError 
     {
-
-        internal MyCallback() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallback(MyCallbackDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal SyncError.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private uint CallTrampoline(uint value, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(value);
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultSyncError ToManaged()
             {
-                _exception = e;
-                return default;
+                var _managed = new ResultSyncError();
+                _managed._variant = _variant;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint Call(uint value)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNative>(_ptr);
-            return __target(value, IntPtr.Zero);
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultSyncError Ok => new() { _variant = 0 };
+        public static ResultSyncError Err(SyncError value) => new() { _variant = 1, _Err = value };
+        public static ResultSyncError Panic => new() { _variant = 2 };
+        public static ResultSyncError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(MyCallback), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? SyncErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public MyCallback ToManaged()
-            {
-                var rval = new MyCallback();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public void AsOk() { if (_variant != 0) throw ToException(); }
+        public SyncError AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultSyncError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private MyCallback _managed;
+            private ResultSyncError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallback managed) { _managed = managed; }
+            public Marshaller(ResultSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallback managed) { _managed = managed; }
+            public void FromManaged(ResultSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -11695,113 +13481,125 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallback ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultSyncError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void MyCallbackContextualNative(IntPtr context, uint value, IntPtr callback_data); // 'True' native callback signature
-    public delegate void MyCallbackContextualDelegate(IntPtr context, uint value); // Our C# signature
+    #nullable enable
+    public partial struct ResultSyncError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Unwrap() => AsOk();
 
-    public partial class MyCallbackContextual
+        public TResult Match<TResult>(Func<TResult> ok, Func<SyncError, TResult> err) => IsOk ? ok() : IsErr ? err(_Err) : throw ToException();
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultU32Error
     {
-        private MyCallbackContextualDelegate _managed; // C# callback
-        private MyCallbackContextualNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        uint _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallbackContextual : IDisposable
+    public partial struct ResultU32Error 
     {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal uint _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
+        }
 
-        internal MyCallbackContextual() { }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallbackContextual(MyCallbackContextualDelegate managed)
-        {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
-        }
-
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(IntPtr context, uint value, IntPtr callback_data)
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(context, value);
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultU32Error ToManaged()
             {
-                _exception = e;
-                return;
+                var _managed = new ResultU32Error();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(IntPtr context, uint value)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackContextualNative>(_ptr);
-            __target(context, value, IntPtr.Zero);
-            return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultU32Error Ok(uint value) => new() { _variant = 0, _Ok = value };
+        public static ResultU32Error Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultU32Error Panic => new() { _variant = 2 };
+        public static ResultU32Error Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(MyCallbackContextual), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public MyCallbackContextual ToManaged()
-            {
-                var rval = new MyCallbackContextual();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public uint AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultU32Error), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private MyCallbackContextual _managed;
+            private ResultU32Error _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallbackContextual managed) { _managed = managed; }
+            public Marshaller(ResultU32Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallbackContextual managed) { _managed = managed; }
+            public void FromManaged(ResultU32Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -11810,112 +13608,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallbackContextual ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultU32Error ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate uint MyCallbackNamespacedNative(uint value, IntPtr callback_data); // 'True' native callback signature
-    public delegate uint MyCallbackNamespacedDelegate(uint value); // Our C# signature
+    #nullable enable
+    public partial struct ResultU32Error
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Unwrap() => AsOk();
 
-    public partial class MyCallbackNamespaced
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint UnwrapOr(uint fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out uint value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<uint, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out uint? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultU64Error
     {
-        private MyCallbackNamespacedDelegate _managed; // C# callback
-        private MyCallbackNamespacedNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        ulong _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallbackNamespaced : IDisposable
+    public partial struct ResultU64Error 
     {
-
-        internal MyCallbackNamespaced() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallbackNamespaced(MyCallbackNamespacedDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal ulong _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private uint CallTrampoline(uint value, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(value);
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultU64Error ToManaged()
             {
-                _exception = e;
-                return default;
+                var _managed = new ResultU64Error();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint Call(uint value)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNamespacedNative>(_ptr);
-            return __target(value, IntPtr.Zero);
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultU64Error Ok(ulong value) => new() { _variant = 0, _Ok = value };
+        public static ResultU64Error Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultU64Error Panic => new() { _variant = 2 };
+        public static ResultU64Error Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(MyCallbackNamespaced), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public MyCallbackNamespaced ToManaged()
-            {
-                var rval = new MyCallbackNamespaced();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public ulong AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultU64Error), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private MyCallbackNamespaced _managed;
+            private ResultU64Error _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallbackNamespaced managed) { _managed = managed; }
+            public Marshaller(ResultU64Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallbackNamespaced managed) { _managed = managed; }
+            public void FromManaged(ResultU64Error managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -11924,343 +13753,572 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallbackNamespaced ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultU64Error ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void MyCallbackVoidNative(IntPtr ptr, IntPtr callback_data); // 'True' native callback signature
-    public delegate void MyCallbackVoidDelegate(IntPtr ptr); // Our C# signature
-
-    public partial class MyCallbackVoid
-    {
-        private MyCallbackVoidDelegate _managed; // C# callback
-        private MyCallbackVoidNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
-    }
-
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallbackVoid : IDisposable
+    #nullable enable
+    public partial struct ResultU64Error
     {
-
-        internal MyCallbackVoid() { }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public ulong Unwrap() => AsOk();
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallbackVoid(MyCallbackVoidDelegate managed)
-        {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
-        }
+        public ulong UnwrapOr(ulong fallback) => IsOk ? _Ok : fallback;
 
-        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(IntPtr ptr, IntPtr callback_data)
+        public bool TryGet(out ulong value)
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(ptr);
-            }
-            catch (Exception e)
-            {
-                _exception = e;
-                return;
-            }
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
 
-        // Invokes the callback.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(IntPtr ptr)
+        public TResult Match<TResult>(Func<ulong, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out ulong? value, out Error? error)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackVoidNative>(_ptr);
-            __target(ptr, IntPtr.Zero);
-            return;
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
         }
+    }
+    #nullable restore
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+    ///Result that contains value or an error.
+    public partial class ResultUseStringError
+    {
+        uint _variant;
+        UseString _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class ResultUseStringError : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            [FieldOffset(0)]
+            internal UseString.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        public void Dispose()
         {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
+            if (_variant == 0) { _Ok.Dispose(); }
         }
 
-        [CustomMarshaller(typeof(MyCallbackVoid), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
-
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
-            public MyCallbackVoid ToManaged()
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultUseStringError IntoManaged()
             {
-                var rval = new MyCallbackVoid();
-                rval._ptr = _callback;
-                return rval;
+                var _managed = new ResultUseStringError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged IntoUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultUseStringError Ok(UseString value) => new() { _variant = 0, _Ok = value };
+        public static ResultUseStringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultUseStringError Panic => new() { _variant = 2 };
+        public static ResultUseStringError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
+        public UseString AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultUseStringError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private MyCallbackVoid _managed;
+            private ResultUseStringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallbackVoid managed) { _managed = managed; }
+            public Marshaller(ResultUseStringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallbackVoid managed) { _managed = managed; }
+            public void FromManaged(ResultUseStringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallbackVoid ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultUseStringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void NestedStringCallbackNative(UseString.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
-    public delegate void NestedStringCallbackDelegate(UseString s); // Our C# signature
+    #nullable enable
+    public partial class ResultUseStringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public UseString Unwrap() => AsOk();
 
-    public partial class NestedStringCallback
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public UseString UnwrapOr(UseString fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out UseString value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<UseString, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out UseString? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial class ResultUtf8StringError
     {
-        private NestedStringCallbackDelegate _managed; // C# callback
-        private NestedStringCallbackNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        Utf8String _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class NestedStringCallback : IDisposable
+    public partial class ResultUtf8StringError : IDisposable
     {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal Utf8String.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
+        }
 
-        internal NestedStringCallback() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public NestedStringCallback(NestedStringCallbackDelegate managed)
+        public void Dispose()
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            if (_variant == 0) { _Ok.Dispose(); }
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(UseString.Unmanaged s, IntPtr callback_data)
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(s.IntoManaged());
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultUtf8StringError IntoManaged()
             {
-                _exception = e;
-                return;
+                var _managed = new ResultUtf8StringError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(UseString s)
+        internal Unmanaged IntoUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<NestedStringCallbackNative>(_ptr);
-            __target(s.IntoUnmanaged(), IntPtr.Zero);
-            return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultUtf8StringError Ok(Utf8String value) => new() { _variant = 0, _Ok = value };
+        public static ResultUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultUtf8StringError Panic => new() { _variant = 2 };
+        public static ResultUtf8StringError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(NestedStringCallback), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public NestedStringCallback ToManaged()
-            {
-                var rval = new NestedStringCallback();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public Utf8String AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private NestedStringCallback _managed;
+            private ResultUtf8StringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(NestedStringCallback managed) { _managed = managed; }
+            public Marshaller(ResultUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(NestedStringCallback managed) { _managed = managed; }
+            public void FromManaged(ResultUtf8StringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public NestedStringCallback ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void PointersNative(ref int x, ref int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate void PointersDelegate(ref int x, ref int y); // Our C# signature
-
-    public partial class Pointers
-    {
-        private PointersDelegate _managed; // C# callback
-        private PointersNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
-    }
-
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class Pointers : IDisposable
+    #nullable enable
+    public partial class ResultUtf8StringError
     {
-
-        internal Pointers() { }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String Unwrap() => AsOk();
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Pointers(PointersDelegate managed)
-        {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
-        }
+        public Utf8String UnwrapOr(Utf8String fallback) => IsOk ? _Ok : fallback;
 
-        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(ref int x, ref int y, IntPtr callback_data)
+        public bool TryGet(out Utf8String value)
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(ref x, ref y);
-            }
-            catch (Exception e)
-            {
-                _exception = e;
-                return;
-            }
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
 
-        // Invokes the callback.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(ref int x, ref int y)
+        public TResult Match<TResult>(Func<Utf8String, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out Utf8String? value, out Error? error)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<PointersNative>(_ptr);
-            __target(ref x, ref y, IntPtr.Zero);
-            return;
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial class ResultVecUtf8StringError
+    {
+        uint _variant;
+        VecUtf8String _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class ResultVecUtf8StringError : IDisposable
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal VecUtf8String.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            if (_variant == 0) { _Ok.Dispose(); }
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultVecUtf8StringError IntoManaged()
+            {
+                var _managed = new ResultVecUtf8StringError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
+            }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+        internal Unmanaged IntoUnmanaged()
         {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [CustomMarshaller(typeof(Pointers), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultVecUtf8StringError Ok(VecUtf8String value) => new() { _variant = 0, _Ok = value };
+        public static ResultVecUtf8StringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultVecUtf8StringError Panic => new() { _variant = 2 };
+        public static ResultVecUtf8StringError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public VecUtf8String AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultVecUtf8StringError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultVecUtf8StringError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultVecUtf8StringError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultVecUtf8StringError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultVecUtf8StringError ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    #nullable enable
+    public partial class ResultVecUtf8StringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public VecUtf8String Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public VecUtf8String UnwrapOr(VecUtf8String fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out VecUtf8String value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<VecUtf8String, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out VecUtf8String? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultVoid
+    {
+        uint _variant;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultVoid 
+    {
 
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+            internal uint _variant;
 
-            public Pointers ToManaged()
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultVoid ToManaged()
             {
-                var rval = new Pointers();
-                rval._ptr = _callback;
-                return rval;
+                var _managed = new ResultVoid();
+                _managed._variant = _variant;
+                return _managed;
             }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        public static ResultVoid Ok => new() { _variant = 0 };
+        public static ResultVoid Err => new() { _variant = 1 };
+        public static ResultVoid Panic => new() { _variant = 2 };
+        public static ResultVoid Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => InteropException.FromLastError(ToString());
+
+        public void AsOk() { if (_variant != 0) throw ToException(); }
+        public void AsErr() { if (_variant != 1) throw new InteropException(); }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return "Err(...)";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultVoid), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private Pointers _managed;
+            private ResultVoid _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Pointers managed) { _managed = managed; }
+            public Marshaller(ResultVoid managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(Pointers managed) { _managed = managed; }
+            public void FromManaged(ResultVoid managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -12269,33 +14327,53 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Pointers ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultVoid ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+
+    [Serializable]
+    [StructLayout(LayoutKind.Sequential)]
+    public partial struct Bool
+    {
+        byte value;
+    }
+
+    public partial struct Bool
+    {
+        public static readonly Bool True = new Bool { value =  1 };
+        public static readonly Bool False = new Bool { value =  0 };
+        public Bool(bool b)
+        {
+            value = (byte) (b ? 1 : 0);
+        }
+        public bool Is => value == 1;
+    }
+
+
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void StringCallbackNative(Utf8String.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
-    public delegate void StringCallbackDelegate(Utf8String s); // Our C# signature
+    public delegate void CallbackCharArray2Native(CharArray.Unmanaged value, IntPtr callback_data); // 'True' native callback signature
+    public delegate void CallbackCharArray2Delegate(CharArray value); // Our C# signature
 
-    public partial class StringCallback
+    public partial class CallbackCharArray2
     {
-        private StringCallbackDelegate _managed; // C# callback
-        private StringCallbackNative _native; // Native callback 
+        private CallbackCharArray2Delegate _managed; // C# callback
+        private CallbackCharArray2Native _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class StringCallback : IDisposable
+    public partial class CallbackCharArray2 : IDisposable
     {
 
-        internal StringCallback() { }
+        internal CallbackCharArray2() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public StringCallback(StringCallbackDelegate managed)
+        public CallbackCharArray2(CallbackCharArray2Delegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -12304,12 +14382,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(Utf8String.Unmanaged s, IntPtr callback_data)
+        private void CallTrampoline(CharArray.Unmanaged value, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed(s.IntoManaged());
+                _managed(value.ToManaged());
             }
             catch (Exception e)
             {
@@ -12320,10 +14398,11 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(Utf8String s)
+        internal void Call(CharArray value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<StringCallbackNative>(_ptr);
-            __target(s.IntoUnmanaged(), IntPtr.Zero);
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackCharArray2Native>(_ptr);
+            // TODO
+            // __target(value.ToManaged());
             return;
         }
 
@@ -12345,7 +14424,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(StringCallback), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackCharArray2), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -12354,9 +14433,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public StringCallback ToManaged()
+            public CallbackCharArray2 ToManaged()
             {
-                var rval = new StringCallback();
+                var rval = new CallbackCharArray2();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -12365,17 +14444,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private StringCallback _managed;
+            private CallbackCharArray2 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(StringCallback managed) { _managed = managed; }
+            public Marshaller(CallbackCharArray2 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(StringCallback managed) { _managed = managed; }
+            public void FromManaged(CallbackCharArray2 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -12384,7 +14463,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public StringCallback ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackCharArray2 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -12392,25 +14471,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate Utf8String.Unmanaged StringCallbackStringNative(Utf8String.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
-    public delegate Utf8String StringCallbackStringDelegate(Utf8String s); // Our C# signature
+    public delegate byte CallbackFFISliceNative(SliceU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte CallbackFFISliceDelegate(SliceU8 slice); // Our C# signature
 
-    public partial class StringCallbackString
+    public partial class CallbackFFISlice
     {
-        private StringCallbackStringDelegate _managed; // C# callback
-        private StringCallbackStringNative _native; // Native callback 
+        private CallbackFFISliceDelegate _managed; // C# callback
+        private CallbackFFISliceNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class StringCallbackString : IDisposable
+    public partial class CallbackFFISlice : IDisposable
     {
 
-        internal StringCallbackString() { }
+        internal CallbackFFISlice() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public StringCallbackString(StringCallbackStringDelegate managed)
+        public CallbackFFISlice(CallbackFFISliceDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -12419,12 +14498,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private Utf8String.Unmanaged CallTrampoline(Utf8String.Unmanaged s, IntPtr callback_data)
+        private byte CallTrampoline(SliceU8.Unmanaged slice, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                return _managed(s.IntoManaged()).IntoUnmanaged();
+                return _managed(slice.ToManaged());
             }
             catch (Exception e)
             {
@@ -12435,10 +14514,12 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Utf8String Call(Utf8String s)
+        internal byte Call(SliceU8 slice)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<StringCallbackStringNative>(_ptr);
-            return __target(s.IntoUnmanaged(), IntPtr.Zero).IntoManaged();
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackFFISliceNative>(_ptr);
+            // TODO
+            // return __target(slice.ToManaged());
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -12459,7 +14540,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(StringCallbackString), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackFFISlice), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -12468,9 +14549,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public StringCallbackString ToManaged()
+            public CallbackFFISlice ToManaged()
             {
-                var rval = new StringCallbackString();
+                var rval = new CallbackFFISlice();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -12479,17 +14560,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private StringCallbackString _managed;
+            private CallbackFFISlice _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(StringCallbackString managed) { _managed = managed; }
+            public Marshaller(CallbackFFISlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(StringCallbackString managed) { _managed = managed; }
+            public void FromManaged(CallbackFFISlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -12498,7 +14579,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public StringCallbackString ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackFFISlice ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -12506,25 +14587,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void SumDelegate1Native(IntPtr callback_data); // 'True' native callback signature
-    public delegate void SumDelegate1Delegate(); // Our C# signature
+    public delegate Vec3f32.Unmanaged CallbackHugeVecSliceNative(SliceVec3f32.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
+    public delegate Vec3f32 CallbackHugeVecSliceDelegate(SliceVec3f32 slice); // Our C# signature
 
-    public partial class SumDelegate1
+    public partial class CallbackHugeVecSlice
     {
-        private SumDelegate1Delegate _managed; // C# callback
-        private SumDelegate1Native _native; // Native callback 
+        private CallbackHugeVecSliceDelegate _managed; // C# callback
+        private CallbackHugeVecSliceNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegate1 : IDisposable
+    public partial class CallbackHugeVecSlice : IDisposable
     {
 
-        internal SumDelegate1() { }
+        internal CallbackHugeVecSlice() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegate1(SumDelegate1Delegate managed)
+        public CallbackHugeVecSlice(CallbackHugeVecSliceDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -12533,27 +14614,28 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(IntPtr callback_data)
+        private Vec3f32.Unmanaged CallTrampoline(SliceVec3f32.Unmanaged slice, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed();
+                return _managed(slice.ToManaged()).ToUnmanaged();
             }
             catch (Exception e)
             {
                 _exception = e;
-                return;
+                return default;
             }
         }
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call()
+        internal Vec3f32 Call(SliceVec3f32 slice)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate1Native>(_ptr);
-            __target(IntPtr.Zero);
-            return;
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackHugeVecSliceNative>(_ptr);
+            // TODO
+            // return __target(slice.ToManaged());
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -12574,7 +14656,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegate1), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackHugeVecSlice), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -12583,9 +14665,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegate1 ToManaged()
+            public CallbackHugeVecSlice ToManaged()
             {
-                var rval = new SumDelegate1();
+                var rval = new CallbackHugeVecSlice();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -12594,17 +14676,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegate1 _managed;
+            private CallbackHugeVecSlice _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegate1 managed) { _managed = managed; }
+            public Marshaller(CallbackHugeVecSlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegate1 managed) { _managed = managed; }
+            public void FromManaged(CallbackHugeVecSlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -12613,7 +14695,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegate1 ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackHugeVecSlice ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -12621,25 +14703,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate int SumDelegate2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate int SumDelegate2Delegate(int x, int y); // Our C# signature
+    public delegate void CallbackSliceMutNative(SliceMutU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
+    public delegate void CallbackSliceMutDelegate(SliceMutU8 slice); // Our C# signature
 
-    public partial class SumDelegate2
+    public partial class CallbackSliceMut
     {
-        private SumDelegate2Delegate _managed; // C# callback
-        private SumDelegate2Native _native; // Native callback 
+        private CallbackSliceMutDelegate _managed; // C# callback
+        private CallbackSliceMutNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegate2 : IDisposable
+    public partial class CallbackSliceMut : IDisposable
     {
 
-        internal SumDelegate2() { }
+        internal CallbackSliceMut() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegate2(SumDelegate2Delegate managed)
+        public CallbackSliceMut(CallbackSliceMutDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -12648,26 +14730,28 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private int CallTrampoline(int x, int y, IntPtr callback_data)
+        private void CallTrampoline(SliceMutU8.Unmanaged slice, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                return _managed(x, y);
+                _managed(slice.ToManaged());
             }
             catch (Exception e)
             {
                 _exception = e;
-                return default;
+                return;
             }
         }
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public int Call(int x, int y)
+        internal void Call(SliceMutU8 slice)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate2Native>(_ptr);
-            return __target(x, y, IntPtr.Zero);
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackSliceMutNative>(_ptr);
+            // TODO
+            // __target(slice.ToManaged());
+            return;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -12688,7 +14772,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegate2), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackSliceMut), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -12697,9 +14781,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegate2 ToManaged()
+            public CallbackSliceMut ToManaged()
             {
-                var rval = new SumDelegate2();
+                var rval = new CallbackSliceMut();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -12708,17 +14792,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegate2 _managed;
+            private CallbackSliceMut _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegate2 managed) { _managed = managed; }
+            public Marshaller(CallbackSliceMut managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegate2 managed) { _managed = managed; }
+            public void FromManaged(CallbackSliceMut managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -12727,7 +14811,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegate2 ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackSliceMut ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -12735,25 +14819,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate ResultError.Unmanaged SumDelegateReturnNative(int x, int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate ResultError SumDelegateReturnDelegate(int x, int y); // Our C# signature
+    public delegate byte CallbackU8Native(byte value, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte CallbackU8Delegate(byte value); // Our C# signature
 
-    public partial class SumDelegateReturn
+    public partial class CallbackU8
     {
-        private SumDelegateReturnDelegate _managed; // C# callback
-        private SumDelegateReturnNative _native; // Native callback 
+        private CallbackU8Delegate _managed; // C# callback
+        private CallbackU8Native _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegateReturn : IDisposable
+    public partial class CallbackU8 : IDisposable
     {
 
-        internal SumDelegateReturn() { }
+        internal CallbackU8() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegateReturn(SumDelegateReturnDelegate managed)
+        public CallbackU8(CallbackU8Delegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -12762,12 +14846,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private ResultError.Unmanaged CallTrampoline(int x, int y, IntPtr callback_data)
+        private byte CallTrampoline(byte value, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                return _managed(x, y).ToUnmanaged();
+                return _managed(value);
             }
             catch (Exception e)
             {
@@ -12778,10 +14862,12 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public ResultError Call(int x, int y)
+        internal byte Call(byte value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturnNative>(_ptr);
-            return __target(x, y, IntPtr.Zero).ToManaged();
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackU8Native>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -12802,7 +14888,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegateReturn), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackU8), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -12811,9 +14897,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegateReturn ToManaged()
+            public CallbackU8 ToManaged()
             {
-                var rval = new SumDelegateReturn();
+                var rval = new CallbackU8();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -12822,17 +14908,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegateReturn _managed;
+            private CallbackU8 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegateReturn managed) { _managed = managed; }
+            public Marshaller(CallbackU8 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegateReturn managed) { _managed = managed; }
+            public void FromManaged(CallbackU8 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -12841,7 +14927,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegateReturn ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackU8 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -12849,25 +14935,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void SumDelegateReturn2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate void SumDelegateReturn2Delegate(int x, int y); // Our C# signature
+    public delegate uint LogSinkCountNative(IntPtr callback_data); // 'True' native callback signature
+    public delegate uint LogSinkCountDelegate(); // Our C# signature
 
-    public partial class SumDelegateReturn2
+    public partial class LogSinkCount
     {
-        private SumDelegateReturn2Delegate _managed; // C# callback
-        private SumDelegateReturn2Native _native; // Native callback 
+        private LogSinkCountDelegate _managed; // C# callback
+        private LogSinkCountNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegateReturn2 : IDisposable
+    public partial class LogSinkCount : IDisposable
     {
 
-        internal SumDelegateReturn2() { }
+        internal LogSinkCount() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegateReturn2(SumDelegateReturn2Delegate managed)
+        public LogSinkCount(LogSinkCountDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -12876,27 +14962,28 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(int x, int y, IntPtr callback_data)
+        private uint CallTrampoline(IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed(x, y);
+                return _managed();
             }
             catch (Exception e)
             {
                 _exception = e;
-                return;
+                return default;
             }
         }
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(int x, int y)
+        internal uint Call()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturn2Native>(_ptr);
-            __target(x, y, IntPtr.Zero);
-            return;
+            var __target = Marshal.GetDelegateForFunctionPointer<LogSinkCountNative>(_ptr);
+            // TODO
+            // return __target();
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -12917,7 +15004,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegateReturn2), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(LogSinkCount), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -12926,9 +15013,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegateReturn2 ToManaged()
+            public LogSinkCount ToManaged()
             {
-                var rval = new SumDelegateReturn2();
+                var rval = new LogSinkCount();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -12937,17 +15024,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegateReturn2 _managed;
+            private LogSinkCount _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegateReturn2 managed) { _managed = managed; }
+            public Marshaller(LogSinkCount managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegateReturn2 managed) { _managed = managed; }
+            public void FromManaged(LogSinkCount managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -12956,1127 +15043,4340 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegateReturn2 ToManaged() { return _unmanaged.ToManaged(); }
+            public LogSinkCount ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public class AsyncTrampolineResultError
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void LogSinkLogNative(uint level, uint x, IntPtr callback_data); // 'True' native callback signature
+    public delegate void LogSinkLogDelegate(uint level, uint x); // Our C# signature
+
+    public partial class LogSinkLog
+    {
+        private LogSinkLogDelegate _managed; // C# callback
+        private LogSinkLogNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class LogSinkLog : IDisposable
     {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+
+        internal LogSinkLog() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultError()
+        public LogSinkLog(LogSinkLogDelegate managed)
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
         }
 
+        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        private void CallTrampoline(uint level, uint x, IntPtr callback_data)
         {
-            TaskCompletionSource tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultError.Unmanaged>(data);
-            var managed = unmanaged.ToManaged();
-            if (managed.IsOk) { tcs.SetResult(); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(level, x);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
         }
 
+        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task) NewCall()
+        internal void Call(uint level, uint x)
         {
-            var tcs = new TaskCompletionSource();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
-
-            return (ac, tcs.Task);
+            var __target = Marshal.GetDelegateForFunctionPointer<LogSinkLogNative>(_ptr);
+            // TODO
+            // __target(level, x);
+            return;
         }
-    }
-
-    public class AsyncTrampolineResultNestedArrayError
-    {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<NestedArray>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultNestedArrayError()
+        public void Dispose()
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        internal Unmanaged ToUnmanaged()
         {
-            TaskCompletionSource<NestedArray> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultNestedArrayError.Unmanaged>(data);
-            var managed = unmanaged.ToManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<NestedArray>) NewCall()
+        [CustomMarshaller(typeof(LogSinkLog), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
         {
-            var tcs = new TaskCompletionSource<NestedArray>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public LogSinkLog ToManaged()
+            {
+                var rval = new LogSinkLog();
+                rval._ptr = _callback;
+                return rval;
+            }
 
-            return (ac, tcs.Task);
         }
-    }
 
-    public class AsyncTrampolineResultU64Error
-    {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<ulong>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+        public ref struct Marshaller
+        {
+            private LogSinkLog _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(LogSinkLog managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(LogSinkLog managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public LogSinkLog ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint MyCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate uint MyCallbackDelegate(uint value); // Our C# signature
+
+    public partial class MyCallback
+    {
+        private MyCallbackDelegate _managed; // C# callback
+        private MyCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyCallback : IDisposable
+    {
+
+        internal MyCallback() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public MyCallback(MyCallbackDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private uint CallTrampoline(uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal uint Call(uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(MyCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public MyCallback ToManaged()
+            {
+                var rval = new MyCallback();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void MyCallbackContextualNative(IntPtr context, uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate void MyCallbackContextualDelegate(IntPtr context, uint value); // Our C# signature
+
+    public partial class MyCallbackContextual
+    {
+        private MyCallbackContextualDelegate _managed; // C# callback
+        private MyCallbackContextualNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyCallbackContextual : IDisposable
+    {
+
+        internal MyCallbackContextual() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public MyCallbackContextual(MyCallbackContextualDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(IntPtr context, uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(context, value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(IntPtr context, uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackContextualNative>(_ptr);
+            // TODO
+            // __target(context, value);
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(MyCallbackContextual), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public MyCallbackContextual ToManaged()
+            {
+                var rval = new MyCallbackContextual();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyCallbackContextual _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyCallbackContextual managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyCallbackContextual managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyCallbackContextual ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint MyCallbackNamespacedNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate uint MyCallbackNamespacedDelegate(uint value); // Our C# signature
+
+    public partial class MyCallbackNamespaced
+    {
+        private MyCallbackNamespacedDelegate _managed; // C# callback
+        private MyCallbackNamespacedNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyCallbackNamespaced : IDisposable
+    {
+
+        internal MyCallbackNamespaced() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public MyCallbackNamespaced(MyCallbackNamespacedDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private uint CallTrampoline(uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal uint Call(uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNamespacedNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(MyCallbackNamespaced), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public MyCallbackNamespaced ToManaged()
+            {
+                var rval = new MyCallbackNamespaced();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyCallbackNamespaced _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyCallbackNamespaced managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyCallbackNamespaced managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyCallbackNamespaced ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void MyCallbackVoidNative(IntPtr ptr, IntPtr callback_data); // 'True' native callback signature
+    public delegate void MyCallbackVoidDelegate(IntPtr ptr); // Our C# signature
+
+    public partial class MyCallbackVoid
+    {
+        private MyCallbackVoidDelegate _managed; // C# callback
+        private MyCallbackVoidNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyCallbackVoid : IDisposable
+    {
+
+        internal MyCallbackVoid() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public MyCallbackVoid(MyCallbackVoidDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(IntPtr ptr, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(ptr);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(IntPtr ptr)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackVoidNative>(_ptr);
+            // TODO
+            // __target(ptr);
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(MyCallbackVoid), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public MyCallbackVoid ToManaged()
+            {
+                var rval = new MyCallbackVoid();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyCallbackVoid _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyCallbackVoid managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyCallbackVoid managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyCallbackVoid ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate ResultU32Error.Unmanaged MyFallibleCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate ResultU32Error MyFallibleCallbackDelegate(uint value); // Our C# signature
+
+    public partial class MyFallibleCallback
+    {
+        private MyFallibleCallbackDelegate _managed; // C# callback
+        private MyFallibleCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyFallibleCallback : IDisposable
+    {
+
+        internal MyFallibleCallback() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public MyFallibleCallback(MyFallibleCallbackDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private ResultU32Error.Unmanaged CallTrampoline(uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value).ToUnmanaged();
+            }
+            catch (ErrorException e)
+            {
+                return ResultU32Error.Err(e.Error).ToUnmanaged();
+            }
+            catch (Exception)
+            {
+                return ResultU32Error.Panic.ToUnmanaged();
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal ResultU32Error Call(uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<MyFallibleCallbackNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(MyFallibleCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public MyFallibleCallback ToManaged()
+            {
+                var rval = new MyFallibleCallback();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyFallibleCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyFallibleCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyFallibleCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyFallibleCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint MyOwnedCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate uint MyOwnedCallbackDelegate(uint value); // Our C# signature
+
+    public partial class MyOwnedCallback
+    {
+        private MyOwnedCallbackDelegate _managed; // C# callback
+        private MyOwnedCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+        private IntPtr _data; // Callback data of an owned callback received from Rust
+        private IntPtr _destructor; // Destructor of an owned callback received from Rust
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyOwnedCallback : IDisposable
+    {
+
+        internal MyOwnedCallback() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public MyOwnedCallback(MyOwnedCallbackDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private uint CallTrampoline(uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal uint Call(uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<MyOwnedCallbackNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // If Rust handed us an owned callback we are now responsible for releasing it.
+            if (_destructor != IntPtr.Zero)
+            {
+                Marshal.GetDelegateForFunctionPointer<DestructorNative>(_destructor)(_data);
+                _destructor = IntPtr.Zero;
+            }
+
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            // Keeps this callback alive until Rust drops it and invokes the destructor.
+            rval._data = GCHandle.ToIntPtr(GCHandle.Alloc(this));
+            rval._destructor = DestructorPtr;
+            return rval;
+        }
+
+        private delegate void DestructorNative(IntPtr data);
+        private static readonly DestructorNative Destructor = ReleaseHandle;
+        private static readonly IntPtr DestructorPtr = Marshal.GetFunctionPointerForDelegate(Destructor);
+
+        // Invoked by Rust once it dropped the callback.
+        private static void ReleaseHandle(IntPtr data)
+        {
+            GCHandle.FromIntPtr(data).Free();
+        }
+
+        [CustomMarshaller(typeof(MyOwnedCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+            internal IntPtr _destructor;
+
+            public MyOwnedCallback ToManaged()
+            {
+                var rval = new MyOwnedCallback();
+                rval._ptr = _callback;
+                rval._data = _data;
+                rval._destructor = _destructor;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyOwnedCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyOwnedCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyOwnedCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyOwnedCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void NestedStringCallbackNative(UseString.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
+    public delegate void NestedStringCallbackDelegate(UseString s); // Our C# signature
+
+    public partial class NestedStringCallback
+    {
+        private NestedStringCallbackDelegate _managed; // C# callback
+        private NestedStringCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class NestedStringCallback : IDisposable
+    {
+
+        internal NestedStringCallback() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public NestedStringCallback(NestedStringCallbackDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(UseString.Unmanaged s, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(s.IntoManaged());
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(UseString s)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<NestedStringCallbackNative>(_ptr);
+            // TODO
+            // __target(s.IntoManaged());
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(NestedStringCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public NestedStringCallback ToManaged()
+            {
+                var rval = new NestedStringCallback();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private NestedStringCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(NestedStringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(NestedStringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public NestedStringCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate byte OnItemF32Native(float value, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte OnItemF32Delegate(float value); // Our C# signature
+
+    public partial class OnItemF32
+    {
+        private OnItemF32Delegate _managed; // C# callback
+        private OnItemF32Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OnItemF32 : IDisposable
+    {
+
+        internal OnItemF32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OnItemF32(OnItemF32Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private byte CallTrampoline(float value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal byte Call(float value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<OnItemF32Native>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(OnItemF32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public OnItemF32 ToManaged()
+            {
+                var rval = new OnItemF32();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private OnItemF32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(OnItemF32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(OnItemF32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public OnItemF32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate byte OnItemU32Native(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte OnItemU32Delegate(uint value); // Our C# signature
+
+    public partial class OnItemU32
+    {
+        private OnItemU32Delegate _managed; // C# callback
+        private OnItemU32Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OnItemU32 : IDisposable
+    {
+
+        internal OnItemU32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OnItemU32(OnItemU32Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private byte CallTrampoline(uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal byte Call(uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<OnItemU32Native>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(OnItemU32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public OnItemU32 ToManaged()
+            {
+                var rval = new OnItemU32();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private OnItemU32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(OnItemU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(OnItemU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public OnItemU32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void StringCallbackNative(Utf8String.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
+    public delegate void StringCallbackDelegate(Utf8String s); // Our C# signature
+
+    public partial class StringCallback
+    {
+        private StringCallbackDelegate _managed; // C# callback
+        private StringCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class StringCallback : IDisposable
+    {
+
+        internal StringCallback() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public StringCallback(StringCallbackDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(Utf8String.Unmanaged s, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(s.IntoManaged());
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(Utf8String s)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<StringCallbackNative>(_ptr);
+            // TODO
+            // __target(s.IntoManaged());
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(StringCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public StringCallback ToManaged()
+            {
+                var rval = new StringCallback();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private StringCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(StringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(StringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public StringCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void SumDelegate1Native(IntPtr callback_data); // 'True' native callback signature
+    public delegate void SumDelegate1Delegate(); // Our C# signature
+
+    public partial class SumDelegate1
+    {
+        private SumDelegate1Delegate _managed; // C# callback
+        private SumDelegate1Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegate1 : IDisposable
+    {
+
+        internal SumDelegate1() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegate1(SumDelegate1Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed();
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call()
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate1Native>(_ptr);
+            // TODO
+            // __target();
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegate1), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegate1 ToManaged()
+            {
+                var rval = new SumDelegate1();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegate1 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegate1 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegate1 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegate1 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate int SumDelegate2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
+    public delegate int SumDelegate2Delegate(int x, int y); // Our C# signature
+
+    public partial class SumDelegate2
+    {
+        private SumDelegate2Delegate _managed; // C# callback
+        private SumDelegate2Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegate2 : IDisposable
+    {
+
+        internal SumDelegate2() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegate2(SumDelegate2Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private int CallTrampoline(int x, int y, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(x, y);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal int Call(int x, int y)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate2Native>(_ptr);
+            // TODO
+            // return __target(x, y);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegate2), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegate2 ToManaged()
+            {
+                var rval = new SumDelegate2();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegate2 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegate2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegate2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegate2 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate ResultError.Unmanaged SumDelegateReturnNative(int x, int y, IntPtr callback_data); // 'True' native callback signature
+    public delegate ResultError SumDelegateReturnDelegate(int x, int y); // Our C# signature
+
+    public partial class SumDelegateReturn
+    {
+        private SumDelegateReturnDelegate _managed; // C# callback
+        private SumDelegateReturnNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegateReturn : IDisposable
+    {
+
+        internal SumDelegateReturn() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegateReturn(SumDelegateReturnDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private ResultError.Unmanaged CallTrampoline(int x, int y, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(x, y).ToUnmanaged();
+            }
+            catch (ErrorException e)
+            {
+                return ResultError.Err(e.Error).ToUnmanaged();
+            }
+            catch (Exception)
+            {
+                return ResultError.Panic.ToUnmanaged();
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal ResultError Call(int x, int y)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturnNative>(_ptr);
+            // TODO
+            // return __target(x, y);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegateReturn), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegateReturn ToManaged()
+            {
+                var rval = new SumDelegateReturn();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegateReturn _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegateReturn managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegateReturn managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegateReturn ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void SumDelegateReturn2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
+    public delegate void SumDelegateReturn2Delegate(int x, int y); // Our C# signature
+
+    public partial class SumDelegateReturn2
+    {
+        private SumDelegateReturn2Delegate _managed; // C# callback
+        private SumDelegateReturn2Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegateReturn2 : IDisposable
+    {
+
+        internal SumDelegateReturn2() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegateReturn2(SumDelegateReturn2Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(int x, int y, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(x, y);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(int x, int y)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturn2Native>(_ptr);
+            // TODO
+            // __target(x, y);
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegateReturn2), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegateReturn2 ToManaged()
+            {
+                var rval = new SumDelegateReturn2();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegateReturn2 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegateReturn2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegateReturn2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegateReturn2 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public class AsyncTrampolineResultError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultError.Unmanaged>(data);
+            var managed = unmanaged.ToManaged();
+            if (managed.IsOk) { tcs.SetResult(); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task) NewCall()
+        {
+            var tcs = new TaskCompletionSource();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultNestedArrayError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<NestedArray>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultNestedArrayError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<NestedArray> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultNestedArrayError.Unmanaged>(data);
+            var managed = unmanaged.ToManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<NestedArray>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<NestedArray>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultU64Error
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<ulong>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultU64Error()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<ulong> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultU64Error.Unmanaged>(data);
+            var managed = unmanaged.ToManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<ulong>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<ulong>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultUseStringError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<UseString>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultUseStringError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<UseString> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultUseStringError.Unmanaged>(data);
+            var managed = unmanaged.IntoManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<UseString>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<UseString>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultUtf8StringError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<Utf8String>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultUtf8StringError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<Utf8String> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultUtf8StringError.Unmanaged>(data);
+            var managed = unmanaged.IntoManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<Utf8String>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<Utf8String>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultVecUtf8StringError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<VecUtf8String>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultVecUtf8StringError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<VecUtf8String> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultVecUtf8StringError.Unmanaged>(data);
+            var managed = unmanaged.IntoManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<VecUtf8String>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<VecUtf8String>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecEnumPayload
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecEnumPayload : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecEnumPayload() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecEnumPayload From(Span<EnumPayload> _data)
+        {
+            var rval = new VecEnumPayload();
+            fixed (void* _data_ptr = _data)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                rval._len = _out._len;
+                rval._capacity = _out._capacity;
+                rval._ptr = _out._ptr;
+            }
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecEnumPayload Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe EnumPayload this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                return Marshal.PtrToStructure<EnumPayload>(new IntPtr(_ptr.ToInt64() + i * sizeof(EnumPayload)));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecEnumPayload { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_12058709700293828778")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_4854562680055612543")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecEnumPayload), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecEnumPayload IntoManaged()
+            {
+                var rval = new VecEnumPayload();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecEnumPayload _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecEnumPayload managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecEnumPayload managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecEnumPayload ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public static class VecEnumPayloadExtensions
+    {
+        public static VecEnumPayload Vec(this EnumPayload[] s) { return VecEnumPayload.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecU8
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecU8 : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecU8() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecU8 From(Span<byte> _data)
+        {
+            var rval = new VecU8();
+            fixed (void* _data_ptr = _data)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                rval._len = _out._len;
+                rval._capacity = _out._capacity;
+                rval._ptr = _out._ptr;
+            }
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecU8 Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe byte this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                return Marshal.PtrToStructure<byte>(new IntPtr(_ptr.ToInt64() + i * sizeof(byte)));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecU8 { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_6849152863081469284")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_9353420175730321243")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecU8), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecU8 IntoManaged()
+            {
+                var rval = new VecU8();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecU8 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecU8 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public static class VecU8Extensions
+    {
+        public static VecU8 Vec(this byte[] s) { return VecU8.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecUtf8String
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecUtf8String : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecUtf8String() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecUtf8String From(Span<Utf8String> _data)
+        {
+            var _temp = new Utf8String.Unmanaged[_data.Length];
+            for (var i = 0; i < _data.Length; ++i)
+            {
+                _temp[i] = _data[i].IntoUnmanaged();
+            }
+            fixed (void* _data_ptr = _temp)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                return _out.IntoManaged();
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecUtf8String Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe Utf8String this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                var _element = Marshal.PtrToStructure<Utf8String.Unmanaged>(new IntPtr(_ptr.ToInt64() + i * sizeof(Utf8String.Unmanaged)));
+                return _element.IntoManaged();
+            }
+        }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecUtf8String { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_17163327104750939811")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_13145557392013674812")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecUtf8String), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecUtf8String IntoManaged()
+            {
+                var rval = new VecUtf8String();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecUtf8String _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecUtf8String managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecUtf8String managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+
+    }
+
+    public static class VecUtf8StringExtensions
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static VecUtf8String IntoVec(this Utf8String[] s) { return VecUtf8String.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecVec3f32
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecVec3f32 : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecVec3f32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecVec3f32 From(Span<Vec3f32> _data)
+        {
+            var rval = new VecVec3f32();
+            fixed (void* _data_ptr = _data)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                rval._len = _out._len;
+                rval._capacity = _out._capacity;
+                rval._ptr = _out._ptr;
+            }
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecVec3f32 Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe Vec3f32 this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                return Marshal.PtrToStructure<Vec3f32>(new IntPtr(_ptr.ToInt64() + i * sizeof(Vec3f32)));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecVec3f32 { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_1781563852271199874")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_12107015372467426116")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecVec3f32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecVec3f32 IntoManaged()
+            {
+                var rval = new VecVec3f32();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecVec3f32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecVec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecVec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecVec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public static class VecVec3f32Extensions
+    {
+        public static VecVec3f32 Vec(this Vec3f32[] s) { return VecVec3f32.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class MapU32Vec3f32
+    {
+        internal IntPtr _keys;
+        internal IntPtr _values;
+        internal ulong _len;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MapU32Vec3f32 : IReadOnlyDictionary<uint, Vec3f32>, IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private MapU32Vec3f32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe MapU32Vec3f32 From(IReadOnlyDictionary<uint, Vec3f32> _data)
+        {
+            var _keys = new uint[_data.Count];
+            var _values = new Vec3f32[_data.Count];
+            var i = 0;
+            foreach (var _entry in _data)
+            {
+                _keys[i] = _entry.Key;
+                _values[i] = _entry.Value;
+                ++i;
+            }
+            fixed (void* _keys_ptr = _keys)
+            fixed (void* _values_ptr = _values)
+            {
+                InteropHelper.interoptopus_map_create((IntPtr) _keys_ptr, (IntPtr) _values_ptr, (ulong) _keys.Length, out var _out);
+                return _out.IntoManaged();
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe MapU32Vec3f32 Empty()
+        {
+            InteropHelper.interoptopus_map_create(IntPtr.Zero, IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_keys == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private unsafe uint KeyAt(int i)
+        {
+            if (i >= Count) throw new IndexOutOfRangeException();
+            return Marshal.PtrToStructure<uint>(new IntPtr(_keys.ToInt64() + i * sizeof(uint)));
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private unsafe Vec3f32 ValueAt(int i)
+        {
+            if (i >= Count) throw new IndexOutOfRangeException();
+            return Marshal.PtrToStructure<Vec3f32>(new IntPtr(_values.ToInt64() + i * sizeof(Vec3f32)));
+        }
+
+        // Keys are sorted by their Rust ordering, which might differ from C#, so we search linearly.
+        private int IndexOf(uint key)
+        {
+            for (var i = 0; i < Count; ++i)
+            {
+                if (EqualityComparer<uint>.Default.Equals(KeyAt(i), key)) return i;
+            }
+            return -1;
+        }
+
+        public Vec3f32 this[uint key]
+        {
+            get { if (TryGetValue(key, out var value)) { return value; } else { throw new KeyNotFoundException(); } }
+        }
+
+        public bool ContainsKey(uint key) { return IndexOf(key) >= 0; }
+
+        public bool TryGetValue(uint key, out Vec3f32 value)
+        {
+            var i = IndexOf(key);
+            value = i >= 0 ? ValueAt(i) : default;
+            return i >= 0;
+        }
+
+        public IEnumerable<uint> Keys
+        {
+            get { for (var i = 0; i < Count; ++i) { yield return KeyAt(i); } }
+        }
+
+        public IEnumerable<Vec3f32> Values
+        {
+            get { for (var i = 0; i < Count; ++i) { yield return ValueAt(i); } }
+        }
+
+        public IEnumerator<KeyValuePair<uint, Vec3f32>> GetEnumerator()
+        {
+            for (var i = 0; i < Count; ++i)
+            {
+                yield return new KeyValuePair<uint, Vec3f32>(KeyAt(i), ValueAt(i));
+            }
+        }
+
+        IEnumerator IEnumerable.GetEnumerator() { return GetEnumerator(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_keys == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._keys = _keys;
+            rval._values = _values;
+            rval._len = _len;
+            _keys = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_keys == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._keys = _keys;
+            rval._values = _values;
+            rval._len = _len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_keys == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._keys = _keys;
+            _unmanaged._values = _values;
+            _unmanaged._len = _len;
+            InteropHelper.interoptopus_map_destroy(_unmanaged);
+            _keys = IntPtr.Zero;
+            _values = IntPtr.Zero;
+            _len = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "MapU32Vec3f32 { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_map_create_6262311165280223196")]
+            internal static partial long interoptopus_map_create(IntPtr keys, IntPtr values, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_map_destroy_9095550523068598099")]
+            internal static partial long interoptopus_map_destroy(Unmanaged map);
+        }
+
+        [CustomMarshaller(typeof(MapU32Vec3f32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _keys;
+            internal IntPtr _values;
+            internal ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MapU32Vec3f32 IntoManaged()
+            {
+                var rval = new MapU32Vec3f32();
+                rval._keys = _keys;
+                rval._values = _values;
+                rval._len = _len;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MapU32Vec3f32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MapU32Vec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MapU32Vec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MapU32Vec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public static class MapU32Vec3f32Extensions
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static MapU32Vec3f32 IntoMap(this IReadOnlyDictionary<uint, Vec3f32> s) { return MapU32Vec3f32.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class IterU32
+    {
+        internal IntPtr _ptr;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class IterU32 : IEnumerable<uint>, IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private IterU32() { }
+
+        // Advances the iterator, returns false once it is exhausted.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryNext(out uint item)
+        {
+            var _unmanaged = AsUnmanaged();
+            return InteropHelper.interoptopus_iter_next(ref _unmanaged, out item) != 0;
+        }
+
+        // Items can only be enumerated once, the handle is disposed afterwards.
+        public IEnumerator<uint> GetEnumerator()
+        {
+            try
+            {
+                while (TryNext(out var item)) { yield return item; }
+            }
+            finally
+            {
+                Dispose();
+            }
+        }
+
+        IEnumerator IEnumerable.GetEnumerator() { return GetEnumerator(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            InteropHelper.interoptopus_iter_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "IterU32 { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_next_14415398661037898230")]
+            internal static partial long interoptopus_iter_next(ref Unmanaged iter, out uint rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_destroy_11887687051161408401")]
+            internal static partial long interoptopus_iter_destroy(Unmanaged iter);
+        }
+
+        [CustomMarshaller(typeof(IterU32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public IterU32 IntoManaged()
+            {
+                var rval = new IterU32();
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private IterU32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(IterU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(IterU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultU64Error()
-        {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public IterU32 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class IterVec3f32
+    {
+        internal IntPtr _ptr;
+    }
 
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class IterVec3f32 : IEnumerable<Vec3f32>, IDisposable
+    {
+        // An internal helper to create an empty object.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
-        {
-            TaskCompletionSource<ulong> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultU64Error.Unmanaged>(data);
-            var managed = unmanaged.ToManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
-        }
+        private IterVec3f32() { }
 
+        // Advances the iterator, returns false once it is exhausted.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<ulong>) NewCall()
+        public bool TryNext(out Vec3f32 item)
         {
-            var tcs = new TaskCompletionSource<ulong>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            var _unmanaged = AsUnmanaged();
+            return InteropHelper.interoptopus_iter_next(ref _unmanaged, out item) != 0;
+        }
 
-            return (ac, tcs.Task);
+        // Items can only be enumerated once, the handle is disposed afterwards.
+        public IEnumerator<Vec3f32> GetEnumerator()
+        {
+            try
+            {
+                while (TryNext(out var item)) { yield return item; }
+            }
+            finally
+            {
+                Dispose();
+            }
         }
-    }
 
-    public class AsyncTrampolineResultUseStringError
-    {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<UseString>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+        IEnumerator IEnumerable.GetEnumerator() { return GetEnumerator(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultUseStringError()
+        public Unmanaged IntoUnmanaged()
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        public Unmanaged AsUnmanaged()
         {
-            TaskCompletionSource<UseString> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultUseStringError.Unmanaged>(data);
-            var managed = unmanaged.IntoManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._ptr = _ptr;
+            return rval;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<UseString>) NewCall()
+        public void Dispose()
         {
-            var tcs = new TaskCompletionSource<UseString>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            InteropHelper.interoptopus_iter_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+        }
 
-            return (ac, tcs.Task);
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "IterVec3f32 { ... }";
         }
-    }
 
-    public class AsyncTrampolineResultUtf8StringError
-    {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<Utf8String>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultUtf8StringError()
+        public partial class InteropHelper
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_next_12283536038193100041")]
+            internal static partial long interoptopus_iter_next(ref Unmanaged iter, out Vec3f32 rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_destroy_14403488365818035019")]
+            internal static partial long interoptopus_iter_destroy(Unmanaged iter);
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        [CustomMarshaller(typeof(IterVec3f32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
         {
-            TaskCompletionSource<Utf8String> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultUtf8StringError.Unmanaged>(data);
-            var managed = unmanaged.IntoManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            internal IntPtr _ptr;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public IterVec3f32 IntoManaged()
+            {
+                var rval = new IterVec3f32();
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<Utf8String>) NewCall()
+        public ref struct Marshaller
         {
-            var tcs = new TaskCompletionSource<Utf8String>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            private IterVec3f32 _managed;
+            private Unmanaged _unmanaged;
 
-            return (ac, tcs.Task);
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(IterVec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(IterVec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public IterVec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
     }
 
-    public class AsyncTrampolineResultVecUtf8StringError
+
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_F32_U32
     {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<VecUtf8String>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+        public float x0;
+        public uint x1;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuple2_F32_U32 
+    {
+        public Tuple2_F32_U32() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultVecUtf8StringError()
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        internal unsafe Unmanaged AsUnmanaged()
         {
-            TaskCompletionSource<VecUtf8String> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultVecUtf8StringError.Unmanaged>(data);
-            var managed = unmanaged.IntoManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public float x0;
+            public uint x1;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Tuple2_F32_U32 ToManaged()
+            {
+                var _managed = new Tuple2_F32_U32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
+            }
         }
 
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<VecUtf8String>) NewCall()
+        public override string ToString()
         {
-            var tcs = new TaskCompletionSource<VecUtf8String>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            return "Tuple2_F32_U32 { ... }";
+        }
 
-            return (ac, tcs.Task);
+        [CustomMarshaller(typeof(Tuple2_F32_U32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Tuple2_F32_U32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuple2_F32_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuple2_F32_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuple2_F32_U32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
     }
 
-    // This must be a class because we only ever want to hold on to the
-    // same instance, as we overwrite fields when this is sent over the FFI
-    // boundary
-    public partial class VecEnumPayload
+    public partial struct Tuple2_F32_U32
+    {
+        public Tuple2_F32_U32(float x0, uint x1) { this.x0 = x0; this.x1 = x1; }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple2_F32_U32((float, uint) value) => new(value.Item1, value.Item2);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (float, uint)(Tuple2_F32_U32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (float, uint) ToValueTuple() => (x0, x1);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out float _x0, out uint _x1)
+        {
+            _x0 = x0;
+            _x1 = x1;
+        }
+    }
+
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_U32_F32
     {
-        internal IntPtr _ptr;
-        internal ulong _len;
-        internal ulong _capacity;
+        public uint x0;
+        public float x1;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecEnumPayload : IDisposable
+    public partial struct Tuple2_U32_F32 
     {
-        // An internal helper to create an empty object.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecEnumPayload() { }
+        public Tuple2_U32_F32() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecEnumPayload From(Span<EnumPayload> _data)
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            var rval = new VecEnumPayload();
-            fixed (void* _data_ptr = _data)
-            {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                rval._len = _out._len;
-                rval._capacity = _out._capacity;
-                rval._ptr = _out._ptr;
-            }
-            return rval;
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecEnumPayload Empty()
+        internal unsafe Unmanaged AsUnmanaged()
         {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
-            return _out.IntoManaged();
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
-        public int Count
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
-        }
+            public uint x0;
+            public float x1;
 
-        public unsafe EnumPayload this[int i]
-        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
+            internal unsafe Tuple2_U32_F32 ToManaged()
             {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                return Marshal.PtrToStructure<EnumPayload>(new IntPtr(_ptr.ToInt64() + i * sizeof(EnumPayload)));
+                var _managed = new Tuple2_U32_F32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
             }
         }
 
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged IntoUnmanaged()
+        public override string ToString()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            _ptr = IntPtr.Zero; // Mark this instance as moved.
-            return rval;
+            return "Tuple2_U32_F32 { ... }";
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged AsUnmanaged()
+        [CustomMarshaller(typeof(Tuple2_U32_F32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            return rval;
+            private Tuple2_U32_F32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuple2_U32_F32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuple2_U32_F32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuple2_U32_F32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
+    }
+
+    public partial struct Tuple2_U32_F32
+    {
+        public Tuple2_U32_F32(uint x0, float x1) { this.x0 = x0; this.x1 = x1; }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
-        {
-            if (_ptr == IntPtr.Zero) return;
-            var _unmanaged = new Unmanaged();
-            _unmanaged._ptr = _ptr;
-            _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
-            _ptr = IntPtr.Zero;
-            _len = 0;
-            _capacity = 0;
-        }
+        public static implicit operator Tuple2_U32_F32((uint, float) value) => new(value.Item1, value.Item2);
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        public static implicit operator (uint, float)(Tuple2_U32_F32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (uint, float) ToValueTuple() => (x0, x1);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out uint _x0, out float _x1)
         {
-            return "VecEnumPayload { ... }";
+            _x0 = x0;
+            _x1 = x1;
         }
+    }
 
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_U32_U32
+    {
+        public uint x0;
+        public uint x1;
+    }
 
-        public partial class InteropHelper
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuple2_U32_U32 
+    {
+        public Tuple2_U32_U32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_12058709700293828778")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_4854562680055612543")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
-        [CustomMarshaller(typeof(VecEnumPayload), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
+        }
 
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _ptr;
-            internal ulong _len;
-            internal ulong _capacity;
+            public uint x0;
+            public uint x1;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecEnumPayload IntoManaged()
+            internal unsafe Tuple2_U32_U32 ToManaged()
             {
-                var rval = new VecEnumPayload();
-                rval._len = _len;
-                rval._capacity = _capacity;
-                rval._ptr = _ptr;
-                return rval;
+                var _managed = new Tuple2_U32_U32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
             }
+        }
 
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tuple2_U32_U32 { ... }";
         }
 
+        [CustomMarshaller(typeof(Tuple2_U32_U32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private VecEnumPayload _managed;
+            private Tuple2_U32_U32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecEnumPayload managed) { _managed = managed; }
+            public Marshaller(Tuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecEnumPayload managed) { _managed = managed; }
+            public void FromManaged(Tuple2_U32_U32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecEnumPayload ToManaged() { return _unmanaged.IntoManaged(); }
+            public Tuple2_U32_U32 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public static class VecEnumPayloadExtensions
+    public partial struct Tuple2_U32_U32
     {
-        public static VecEnumPayload Vec(this EnumPayload[] s) { return VecEnumPayload.From(s); }
-    }
+        public Tuple2_U32_U32(uint x0, uint x1) { this.x0 = x0; this.x1 = x1; }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple2_U32_U32((uint, uint) value) => new(value.Item1, value.Item2);
 
-    // This must be a class because we only ever want to hold on to the
-    // same instance, as we overwrite fields when this is sent over the FFI
-    // boundary
-    public partial class VecU8
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (uint, uint)(Tuple2_U32_U32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (uint, uint) ToValueTuple() => (x0, x1);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out uint _x0, out uint _x1)
+        {
+            _x0 = x0;
+            _x1 = x1;
+        }
+    }
+
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple2_U64_U32
     {
-        internal IntPtr _ptr;
-        internal ulong _len;
-        internal ulong _capacity;
+        public ulong x0;
+        public uint x1;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecU8 : IDisposable
+    public partial struct Tuple2_U64_U32 
     {
-        // An internal helper to create an empty object.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecU8() { }
+        public Tuple2_U64_U32() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecU8 From(Span<byte> _data)
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            var rval = new VecU8();
-            fixed (void* _data_ptr = _data)
-            {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                rval._len = _out._len;
-                rval._capacity = _out._capacity;
-                rval._ptr = _out._ptr;
-            }
-            return rval;
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecU8 Empty()
+        internal unsafe Unmanaged AsUnmanaged()
         {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
-            return _out.IntoManaged();
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0;
+            _unmanaged.x1 = x1;
+            return _unmanaged;
         }
 
-        public int Count
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
-        }
+            public ulong x0;
+            public uint x1;
 
-        public unsafe byte this[int i]
-        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
+            internal unsafe Tuple2_U64_U32 ToManaged()
             {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                return Marshal.PtrToStructure<byte>(new IntPtr(_ptr.ToInt64() + i * sizeof(byte)));
+                var _managed = new Tuple2_U64_U32();
+                _managed.x0 = x0;
+                _managed.x1 = x1;
+                return _managed;
             }
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged IntoUnmanaged()
-        {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            _ptr = IntPtr.Zero; // Mark this instance as moved.
-            return rval;
-        }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged AsUnmanaged()
+        public override string ToString()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            return rval;
+            return "Tuple2_U64_U32 { ... }";
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        [CustomMarshaller(typeof(Tuple2_U64_U32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
         {
-            if (_ptr == IntPtr.Zero) return;
-            var _unmanaged = new Unmanaged();
-            _unmanaged._ptr = _ptr;
-            _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
-            _ptr = IntPtr.Zero;
-            _len = 0;
-            _capacity = 0;
+            private Tuple2_U64_U32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuple2_U64_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuple2_U64_U32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuple2_U64_U32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
+    }
+
+    public partial struct Tuple2_U64_U32
+    {
+        public Tuple2_U64_U32(ulong x0, uint x1) { this.x0 = x0; this.x1 = x1; }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        public static implicit operator Tuple2_U64_U32((ulong, uint) value) => new(value.Item1, value.Item2);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (ulong, uint)(Tuple2_U64_U32 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (ulong, uint) ToValueTuple() => (x0, x1);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out ulong _x0, out uint _x1)
         {
-            return "VecU8 { ... }";
+            _x0 = x0;
+            _x1 = x1;
         }
+    }
 
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple3_Vec3f32_U8_I64
+    {
+        public Vec3f32 x0;
+        public byte x1;
+        public long x2;
+    }
 
-        public partial class InteropHelper
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuple3_Vec3f32_U8_I64 
+    {
+        public Tuple3_Vec3f32_U8_I64() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
         {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_6849152863081469284")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_9353420175730321243")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0.ToUnmanaged();
+            _unmanaged.x1 = x1;
+            _unmanaged.x2 = x2;
+            return _unmanaged;
         }
 
-        [CustomMarshaller(typeof(VecU8), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0.ToUnmanaged();
+            _unmanaged.x1 = x1;
+            _unmanaged.x2 = x2;
+            return _unmanaged;
+        }
 
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _ptr;
-            internal ulong _len;
-            internal ulong _capacity;
+            public Vec3f32.Unmanaged x0;
+            public byte x1;
+            public long x2;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecU8 IntoManaged()
+            internal unsafe Tuple3_Vec3f32_U8_I64 ToManaged()
             {
-                var rval = new VecU8();
-                rval._len = _len;
-                rval._capacity = _capacity;
-                rval._ptr = _ptr;
-                return rval;
+                var _managed = new Tuple3_Vec3f32_U8_I64();
+                _managed.x0 = x0.ToManaged();
+                _managed.x1 = x1;
+                _managed.x2 = x2;
+                return _managed;
             }
+        }
 
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tuple3_Vec3f32_U8_I64 { ... }";
         }
 
+        [CustomMarshaller(typeof(Tuple3_Vec3f32_U8_I64), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private VecU8 _managed;
+            private Tuple3_Vec3f32_U8_I64 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecU8 managed) { _managed = managed; }
+            public Marshaller(Tuple3_Vec3f32_U8_I64 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecU8 managed) { _managed = managed; }
+            public void FromManaged(Tuple3_Vec3f32_U8_I64 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecU8 ToManaged() { return _unmanaged.IntoManaged(); }
+            public Tuple3_Vec3f32_U8_I64 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public static class VecU8Extensions
+    public partial struct Tuple3_Vec3f32_U8_I64
     {
-        public static VecU8 Vec(this byte[] s) { return VecU8.From(s); }
-    }
+        public Tuple3_Vec3f32_U8_I64(Vec3f32 x0, byte x1, long x2) { this.x0 = x0; this.x1 = x1; this.x2 = x2; }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple3_Vec3f32_U8_I64((Vec3f32, byte, long) value) => new(value.Item1, value.Item2, value.Item3);
 
-    // This must be a class because we only ever want to hold on to the
-    // same instance, as we overwrite fields when this is sent over the FFI
-    // boundary
-    public partial class VecUtf8String
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (Vec3f32, byte, long)(Tuple3_Vec3f32_U8_I64 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (Vec3f32, byte, long) ToValueTuple() => (x0, x1, x2);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out Vec3f32 _x0, out byte _x1, out long _x2)
+        {
+            _x0 = x0;
+            _x1 = x1;
+            _x2 = x2;
+        }
+    }
+
+    /// A span of time, `nanos` is always below one second.
+    public partial struct Duration
     {
-        internal IntPtr _ptr;
-        internal ulong _len;
-        internal ulong _capacity;
+        public ulong secs;
+        public uint nanos;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecUtf8String : IDisposable
+    public partial struct Duration 
     {
-        // An internal helper to create an empty object.
+        public Duration() { }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecUtf8String() { }
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.secs = secs;
+            _unmanaged.nanos = nanos;
+            return _unmanaged;
+        }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecUtf8String From(Span<Utf8String> _data)
+        internal unsafe Unmanaged AsUnmanaged()
         {
-            var _temp = new Utf8String.Unmanaged[_data.Length];
-            for (var i = 0; i < _data.Length; ++i)
-            {
-                _temp[i] = _data[i].IntoUnmanaged();
-            }
-            fixed (void* _data_ptr = _temp)
+            var _unmanaged = new Unmanaged();
+            _unmanaged.secs = secs;
+            _unmanaged.nanos = nanos;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public ulong secs;
+            public uint nanos;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Duration ToManaged()
             {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                return _out.IntoManaged();
+                var _managed = new Duration();
+                _managed.secs = secs;
+                _managed.nanos = nanos;
+                return _managed;
             }
         }
 
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecUtf8String Empty()
+        public override string ToString()
         {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
-            return _out.IntoManaged();
+            return "Duration { ... }";
         }
 
-        public int Count
+        [CustomMarshaller(typeof(Duration), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
         {
+            private Duration _managed;
+            private Unmanaged _unmanaged;
+
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
-        }
+            public Marshaller(Duration managed) { _managed = managed; }
 
-        public unsafe Utf8String this[int i]
-        {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
-            {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                var _element = Marshal.PtrToStructure<Utf8String.Unmanaged>(new IntPtr(_ptr.ToInt64() + i * sizeof(Utf8String.Unmanaged)));
-                return _element.IntoManaged();
-            }
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Duration managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Duration ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
+    }
+
+    public partial struct Duration
+    {
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged IntoUnmanaged()
+        public static implicit operator Duration(TimeSpan value) => FromTimeSpan(value);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator TimeSpan(Duration value) => value.ToTimeSpan();
+
+        // Rust durations can't be negative, and sub-tick nanoseconds get truncated on the way back.
+        public static Duration FromTimeSpan(TimeSpan value)
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            if (value < TimeSpan.Zero) throw new ArgumentOutOfRangeException(nameof(value), value, null);
+            var rval = new Duration();
+            rval.secs = (ulong) (value.Ticks / TimeSpan.TicksPerSecond);
+            rval.nanos = (uint) (value.Ticks % TimeSpan.TicksPerSecond * 100);
             return rval;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged AsUnmanaged()
+        public TimeSpan ToTimeSpan() => TimeSpan.FromTicks(checked((long) secs * TimeSpan.TicksPerSecond + nanos / 100));
+    }
+
+
+    public partial class ServiceAsyncBasic : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceAsyncBasic() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncBasic New()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            return rval;
+            var self = new ServiceAsyncBasic();
+            self._context = Interop.service_async_basic_new().AsOk();
+            return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            if (_ptr == IntPtr.Zero) return;
-            var _unmanaged = new Unmanaged();
-            _unmanaged._ptr = _ptr;
-            _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
-            _ptr = IntPtr.Zero;
-            _len = 0;
-            _capacity = 0;
+            Interop.service_async_basic_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        public Task Call()
         {
-            return "VecUtf8String { ... }";
+            return Interop.service_async_basic_call(_context);
         }
 
+        public IntPtr Context => _context;
+    }
 
-        public partial class InteropHelper
-        {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_17163327104750939811")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_13145557392013674812")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
-        }
 
-        [CustomMarshaller(typeof(VecUtf8String), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
+    public partial class ServiceAsyncSleep : IDisposable
+    {
+        private IntPtr _context;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _ptr;
-            internal ulong _len;
-            internal ulong _capacity;
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecUtf8String IntoManaged()
-            {
-                var rval = new VecUtf8String();
-                rval._len = _len;
-                rval._capacity = _capacity;
-                rval._ptr = _ptr;
-                return rval;
-            }
+        private ServiceAsyncSleep() {}
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncSleep New()
+        {
+            var self = new ServiceAsyncSleep();
+            self._context = Interop.service_async_sleep_new().AsOk();
+            return self;
+        }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_async_sleep_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
-        public ref struct Marshaller
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Task<ulong> ReturnAfterMs(ulong x, ulong ms)
         {
-            private VecUtf8String _managed;
-            private Unmanaged _unmanaged;
+            return Interop.service_async_sleep_return_after_ms(_context, x, ms);
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecUtf8String managed) { _managed = managed; }
+        public IntPtr Context => _context;
+    }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecUtf8String managed) { _managed = managed; }
+    public partial class ServiceAsyncVecString : IDisposable
+    {
+        private IntPtr _context;
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+        private ServiceAsyncVecString() {}
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncVecString New()
+        {
+            var self = new ServiceAsyncVecString();
+            self._context = Interop.service_async_vec_string_new().AsOk();
+            return self;
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecUtf8String ToManaged() { return _unmanaged.IntoManaged(); }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_async_vec_string_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void Free() {}
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Task<Utf8String> HandleString(Utf8String s)
+        {
+            return Interop.service_async_vec_string_handle_string(_context, s);
         }
 
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Task<VecUtf8String> HandleVecString(VecUtf8String s)
+        {
+            return Interop.service_async_vec_string_handle_vec_string(_context, s);
+        }
 
-    public static class VecUtf8StringExtensions
-    {
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static VecUtf8String IntoVec(this Utf8String[] s) { return VecUtf8String.From(s); }
+        public Task<UseString> HandleNestedString(Utf8String s)
+        {
+            return Interop.service_async_vec_string_handle_nested_string(_context, s);
+        }
+
+        public IntPtr Context => _context;
     }
 
 
-    // This must be a class because we only ever want to hold on to the
-    // same instance, as we overwrite fields when this is sent over the FFI
-    // boundary
-    public partial class VecVec3f32
+    public partial class ServiceAsyncResult : IDisposable
     {
-        internal IntPtr _ptr;
-        internal ulong _len;
-        internal ulong _capacity;
-    }
+        private IntPtr _context;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecVec3f32 : IDisposable
-    {
-        // An internal helper to create an empty object.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecVec3f32() { }
+        private ServiceAsyncResult() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecVec3f32 From(Span<Vec3f32> _data)
+        public static ServiceAsyncResult New()
         {
-            var rval = new VecVec3f32();
-            fixed (void* _data_ptr = _data)
-            {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                rval._len = _out._len;
-                rval._capacity = _out._capacity;
-                rval._ptr = _out._ptr;
-            }
-            return rval;
+            var self = new ServiceAsyncResult();
+            self._context = Interop.service_async_result_new().AsOk();
+            return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecVec3f32 Empty()
+        public void Dispose()
         {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
-            return _out.IntoManaged();
+            Interop.service_async_result_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
-        public int Count
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Task Success()
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
+            return Interop.service_async_result_success(_context);
         }
 
-        public unsafe Vec3f32 this[int i]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Task Fail()
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
-            {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                return Marshal.PtrToStructure<Vec3f32>(new IntPtr(_ptr.ToInt64() + i * sizeof(Vec3f32)));
-            }
+            return Interop.service_async_result_fail(_context);
         }
 
+        public IntPtr Context => _context;
+    }
+
+
+    public partial class ServiceAsyncStructs : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceAsyncStructs() {}
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged IntoUnmanaged()
+        public static ServiceAsyncStructs New()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            _ptr = IntPtr.Zero; // Mark this instance as moved.
-            return rval;
+            var self = new ServiceAsyncStructs();
+            self._context = Interop.service_async_structs_new().AsOk();
+            return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Unmanaged AsUnmanaged()
+        public void Dispose()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
-            var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            return rval;
+            Interop.service_async_structs_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        public Task<NestedArray> ProcessStruct(NestedArray x)
         {
-            if (_ptr == IntPtr.Zero) return;
-            var _unmanaged = new Unmanaged();
-            _unmanaged._ptr = _ptr;
-            _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
-            _ptr = IntPtr.Zero;
-            _len = 0;
-            _capacity = 0;
+            return Interop.service_async_structs_process_struct(_context, x);
         }
 
+        public IntPtr Context => _context;
+    }
+
+
+    public partial class ServiceBasic : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceBasic() {}
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public override string ToString()
+        public static ServiceBasic New()
         {
-            return "VecVec3f32 { ... }";
+            var self = new ServiceBasic();
+            self._context = Interop.service_basic_new().AsOk();
+            return self;
         }
 
-
-        public partial class InteropHelper
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
         {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_1781563852271199874")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_12107015372467426116")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+            Interop.service_basic_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
-        [CustomMarshaller(typeof(VecVec3f32), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta { }
+        public IntPtr Context => _context;
+    }
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _ptr;
-            internal ulong _len;
-            internal ulong _capacity;
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecVec3f32 IntoManaged()
-            {
-                var rval = new VecVec3f32();
-                rval._len = _len;
-                rval._capacity = _capacity;
-                rval._ptr = _ptr;
-                return rval;
-            }
+    public partial class ServiceMain : IDisposable
+    {
+        private IntPtr _context;
 
+        private ServiceMain() {}
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMain New(uint x)
+        {
+            var self = new ServiceMain();
+            self._context = Interop.service_main_new(x).AsOk();
+            return self;
         }
 
-        public ref struct Marshaller
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
         {
-            private VecVec3f32 _managed;
-            private Unmanaged _unmanaged;
+            Interop.service_main_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecVec3f32 managed) { _managed = managed; }
+        public IntPtr Context => _context;
+    }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecVec3f32 managed) { _managed = managed; }
+    public partial class ServiceDependent : IDisposable
+    {
+        private IntPtr _context;
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+        private ServiceDependent() {}
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceDependent FromMain(IntPtr main)
+        {
+            var self = new ServiceDependent();
+            self._context = Interop.service_dependent_from_main(main).AsOk();
+            return self;
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecVec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_dependent_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
 
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void Free() {}
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Get()
+        {
+            return Interop.service_dependent_get(_context);
         }
+
+        public IntPtr Context => _context;
     }
 
-    public static class VecVec3f32Extensions
+
+    public partial class ServiceResult : IDisposable
     {
-        public static VecVec3f32 Vec(this Vec3f32[] s) { return VecVec3f32.From(s); }
-    }
+        private IntPtr _context;
+
+        private ServiceResult() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceResult New()
+        {
+            var self = new ServiceResult();
+            self._context = Interop.service_result_new().AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_result_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Test()
+        {
+            Interop.service_result_test(_context).AsOk();
+        }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint ResultU32()
+        {
+            return Interop.service_result_result_u32(_context).AsOk();
+        }
 
-    public partial class ServiceAsyncBasic : IDisposable
-    {
-        private IntPtr _context;
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String ResultString()
+        {
+            return Interop.service_result_result_string(_context).AsOk();
+        }
 
-        private ServiceAsyncBasic() {}
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionEnumPayload ResultOptionEnum()
+        {
+            return Interop.service_result_result_option_enum(_context).AsOk();
+        }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceAsyncBasic New()
+        public uint ResultSlice(SliceU32 slice, ulong i)
         {
-            var self = new ServiceAsyncBasic();
-            self._context = Interop.service_async_basic_new().AsOk();
-            return self;
+            return Interop.service_result_result_slice(_context, slice, i).AsOk();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        public uint ResultStd(uint x)
         {
-            Interop.service_async_basic_destroy(_context).AsOk();
-            _context = IntPtr.Zero;
+            return Interop.service_result_result_std(_context, x).AsOk();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task Call()
+        public uint ResultStdMapped(uint x)
         {
-            return Interop.service_async_basic_call(_context);
+            return Interop.service_result_result_std_mapped(_context, x).AsOk();
         }
 
         public IntPtr Context => _context;
     }
 
 
-    public partial class ServiceAsyncSleep : IDisposable
+    /// Some struct we want to expose as a class.
+    public partial class ServiceOnPanic : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceAsyncSleep() {}
+        private ServiceOnPanic() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceAsyncSleep New()
+        public static ServiceOnPanic New()
         {
-            var self = new ServiceAsyncSleep();
-            self._context = Interop.service_async_sleep_new().AsOk();
+            var self = new ServiceOnPanic();
+            self._context = Interop.service_on_panic_new().AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_sleep_destroy(_context).AsOk();
+            Interop.service_on_panic_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
+        /// Methods returning a Result<(), _> are the default and do not
+        /// need annotations.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task<ulong> ReturnAfterMs(ulong x, ulong ms)
+        public void ReturnResult(uint anon1)
         {
-            return Interop.service_async_sleep_return_after_ms(_context, x, ms);
+            Interop.service_on_panic_return_result(_context, anon1).AsOk();
+        }
+
+        /// Methods returning a value need an `on_panic` annotation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint ReturnDefaultValue(uint x)
+        {
+            return Interop.service_on_panic_return_default_value(_context, x);
+        }
+
+        /// This function has no panic safeguards. It will be a bit faster to
+        /// call, but if it panics your host app will abort.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr ReturnUbOnPanic()
+        {
+            return Interop.service_on_panic_return_ub_on_panic(_context);
         }
 
         public IntPtr Context => _context;
     }
 
 
-    public partial class ServiceAsyncVecString : IDisposable
+    /// Some struct we want to expose as a class.
+    public partial class ServiceCallbacks : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceAsyncVecString() {}
+        private ServiceCallbacks() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceAsyncVecString New()
+        public static ServiceCallbacks New()
         {
-            var self = new ServiceAsyncVecString();
-            self._context = Interop.service_async_vec_string_new().AsOk();
+            var self = new ServiceCallbacks();
+            self._context = Interop.service_callbacks_new().AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_vec_string_destroy(_context).AsOk();
+            Interop.service_callbacks_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task<Utf8String> HandleString(Utf8String s)
+        public void CallbackSimple(MyCallback callback)
         {
-            return Interop.service_async_vec_string_handle_string(_context, s);
+            Interop.service_callbacks_callback_simple(_context, callback).AsOk();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task<VecUtf8String> HandleVecString(VecUtf8String s)
+        public void CallbackSimple(MyCallbackDelegate callback)
         {
-            return Interop.service_async_vec_string_handle_vec_string(_context, s);
+            Interop.service_callbacks_callback_simple(_context, callback).AsOk();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task<UseString> HandleNestedString(Utf8String s)
+        public void CallbackFfiReturn(SumDelegateReturn callback)
         {
-            return Interop.service_async_vec_string_handle_nested_string(_context, s);
+            Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk();
         }
 
-        public IntPtr Context => _context;
-    }
-
-
-    public partial class ServiceAsyncResult : IDisposable
-    {
-        private IntPtr _context;
-
-        private ServiceAsyncResult() {}
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void CallbackFfiReturn(SumDelegateReturnDelegate callback)
+        {
+            Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk();
+        }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceAsyncResult New()
+        public void CallbackWithSlice(SumDelegateReturn callback, SliceI32 input)
         {
-            var self = new ServiceAsyncResult();
-            self._context = Interop.service_async_result_new().AsOk();
-            return self;
+            Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        public void CallbackWithSlice(SumDelegateReturnDelegate callback, SliceI32 input)
         {
-            Interop.service_async_result_destroy(_context).AsOk();
-            _context = IntPtr.Zero;
+            Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task Success()
+        public void SetDelegateTable(CallbackTable table)
         {
-            return Interop.service_async_result_success(_context);
+            Interop.service_callbacks_set_delegate_table(_context, table);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task Fail()
+        public void InvokeDelegates()
         {
-            return Interop.service_async_result_fail(_context);
+            Interop.service_callbacks_invoke_delegates(_context).AsOk();
         }
 
         public IntPtr Context => _context;
     }
 
 
-    public partial class ServiceAsyncStructs : IDisposable
+    public partial class ServiceIgnoringMethods : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceAsyncStructs() {}
+        private ServiceIgnoringMethods() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceAsyncStructs New()
+        public static ServiceIgnoringMethods New()
         {
-            var self = new ServiceAsyncStructs();
-            self._context = Interop.service_async_structs_new().AsOk();
+            var self = new ServiceIgnoringMethods();
+            self._context = Interop.service_ignoring_methods_new().AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_structs_destroy(_context).AsOk();
+            Interop.service_ignoring_methods_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Task<NestedArray> ProcessStruct(NestedArray x)
-        {
-            return Interop.service_async_structs_process_struct(_context, x);
-        }
-
         public IntPtr Context => _context;
     }
 
 
-    public partial class ServiceBasic : IDisposable
+    /// Some struct we want to expose as a class.
+    public partial class ServiceMultipleCtors : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceBasic() {}
+        private ServiceMultipleCtors() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceBasic New()
+        public static ServiceMultipleCtors NewWith(uint some_value)
         {
-            var self = new ServiceBasic();
-            self._context = Interop.service_basic_new().AsOk();
+            var self = new ServiceMultipleCtors();
+            self._context = Interop.service_multiple_ctors_new_with(some_value).AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMultipleCtors NewWithout()
+        {
+            var self = new ServiceMultipleCtors();
+            self._context = Interop.service_multiple_ctors_new_without().AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMultipleCtors NewWithString([MarshalAs(UnmanagedType.LPStr)] string anon0)
+        {
+            var self = new ServiceMultipleCtors();
+            self._context = Interop.service_multiple_ctors_new_with_string(anon0).AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMultipleCtors NewFailing(byte some_value)
+        {
+            var self = new ServiceMultipleCtors();
+            self._context = Interop.service_multiple_ctors_new_failing(some_value).AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMultipleCtors NewStd(uint some_value)
+        {
+            var self = new ServiceMultipleCtors();
+            self._context = Interop.service_multiple_ctors_new_std(some_value).AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_basic_destroy(_context).AsOk();
+            Interop.service_multiple_ctors_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
@@ -14084,452 +19384,520 @@ namespace My.Company
     }
 
 
-    public partial class ServiceMain : IDisposable
+    /// Some struct we want to expose as a class.
+    public partial class ServiceVariousSlices : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceMain() {}
+        private ServiceVariousSlices() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceMain New(uint x)
+        public static ServiceVariousSlices New()
         {
-            var self = new ServiceMain();
-            self._context = Interop.service_main_new(x).AsOk();
+            var self = new ServiceVariousSlices();
+            self._context = Interop.service_various_slices_new().AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_main_destroy(_context).AsOk();
+            Interop.service_various_slices_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
-        public IntPtr Context => _context;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public byte MutSelf(SliceU8 slice)
+        {
+            return Interop.service_various_slices_mut_self(_context, slice);
+        }
 
+        /// Single line.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void MutSelfVoid(SliceBool slice)
+        {
+            Interop.service_various_slices_mut_self_void(_context, slice);
+        }
 
-    public partial class ServiceDependent : IDisposable
-    {
-        private IntPtr _context;
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public byte MutSelfRef(ref byte x, ref byte y)
+        {
+            return Interop.service_various_slices_mut_self_ref(_context, ref x, ref y);
+        }
 
-        private ServiceDependent() {}
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public byte MutSelfRefSlice(ref byte x, ref byte y, SliceU8 slice)
+        {
+            return Interop.service_various_slices_mut_self_ref_slice(_context, ref x, ref y, slice);
+        }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceDependent FromMain(IntPtr main)
+        public byte MutSelfRefSliceLimited(ref byte x, ref byte y, SliceU8 slice, SliceU8 slice2)
         {
-            var self = new ServiceDependent();
-            self._context = Interop.service_dependent_from_main(main).AsOk();
-            return self;
+            return Interop.service_various_slices_mut_self_ref_slice_limited(_context, ref x, ref y, slice, slice2);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        public void MutSelfFfiError(SliceMutU8 slice)
+        {
+            Interop.service_various_slices_mut_self_ffi_error(_context, slice).AsOk();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void MutSelfNoError(SliceMutU8 slice)
+        {
+            Interop.service_various_slices_mut_self_no_error(_context, slice).AsOk();
+        }
+
+        /// Warning, you _must_ discard the returned slice object before calling into this service
+        /// again, as otherwise undefined behavior might happen.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SliceU32 ReturnSlice()
+        {
+            return Interop.service_various_slices_return_slice(_context);
+        }
+
+        /// Warning, you _must_ discard the returned slice object before calling into this service
+        /// again, as otherwise undefined behavior might happen.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SliceMutU32 ReturnSliceMut()
+        {
+            return Interop.service_various_slices_return_slice_mut(_context);
+        }
+
+        /// Hands out a copy of the data lazily, so the returned iterator stays valid even if the
+        /// service is used again.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IterU32 ReturnIter()
         {
-            Interop.service_dependent_destroy(_context).AsOk();
-            _context = IntPtr.Zero;
+            return Interop.service_various_slices_return_iter(_context);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint Get()
+        public Tuple2_U64_U32 ReturnLenAndFirst()
         {
-            return Interop.service_dependent_get(_context);
+            return Interop.service_various_slices_return_len_and_first(_context);
         }
 
         public IntPtr Context => _context;
     }
 
 
-    public partial class ServiceResult : IDisposable
+    /// Some struct we want to expose as a class.
+    public partial class ServiceStrings : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceResult() {}
+        private ServiceStrings() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceResult New()
+        public static ServiceStrings New()
         {
-            var self = new ServiceResult();
-            self._context = Interop.service_result_new().AsOk();
+            var self = new ServiceStrings();
+            self._context = Interop.service_strings_new().AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceStrings NewString(Utf8String x)
+        {
+            var self = new ServiceStrings();
+            self._context = Interop.service_strings_new_string(x).AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_result_destroy(_context).AsOk();
+            Interop.service_strings_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Test()
+        public void PassCstr([MarshalAs(UnmanagedType.LPStr)] string anon1)
         {
-            Interop.service_result_test(_context).AsOk();
+            Interop.service_strings_pass_cstr(_context, anon1);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint ResultU32()
+        public IntPtr ReturnCstr()
         {
-            return Interop.service_result_result_u32(_context).AsOk();
+            return Interop.service_strings_return_cstr(_context);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Utf8String ResultString()
+        public uint PassStr(Utf8Str s)
         {
-            return Interop.service_result_result_string(_context).AsOk();
+            return Interop.service_strings_pass_str(_context, s);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public OptionEnumPayload ResultOptionEnum()
+        public uint PassStr(ReadOnlySpan<byte> s)
         {
-            return Interop.service_result_result_option_enum(_context).AsOk();
+            return Interop.service_strings_pass_str(_context, s);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint ResultSlice(SliceU32 slice, ulong i)
+        public uint PassStr(string s)
         {
-            return Interop.service_result_result_slice(_context, slice, i).AsOk();
+            return Interop.service_strings_pass_str(_context, s);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void CallbackString(Utf8String s, StringCallback cb)
+        {
+            Interop.service_strings_callback_string(_context, s, cb);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void CallbackString(Utf8String s, StringCallbackDelegate cb)
+        {
+            Interop.service_strings_callback_string(_context, s, cb);
         }
 
         public IntPtr Context => _context;
     }
 
 
-    /// Some struct we want to expose as a class.
-    public partial class ServiceOnPanic : IDisposable
+    /// Some key-value storage shared by multiple services.
+    public partial interface IStorage
+    {
+        DynStorage AsStorage();
+        /// Returns the value stored at `key`.
+        uint Get(uint key);
+        /// Stores `val` at `key`.
+        void Set(uint key, uint val);
+    }
+
+
+    /// Storage backed by a fixed size array.
+    public partial class ServiceStorageArray : IDisposable, IStorage
     {
         private IntPtr _context;
 
-        private ServiceOnPanic() {}
+        private ServiceStorageArray() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceOnPanic New()
+        public static ServiceStorageArray New()
         {
-            var self = new ServiceOnPanic();
-            self._context = Interop.service_on_panic_new().AsOk();
+            var self = new ServiceStorageArray();
+            self._context = Interop.service_storage_array_new().AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_on_panic_destroy(_context).AsOk();
+            Interop.service_storage_array_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
-        /// Methods returning a Result<(), _> are the default and do not
-        /// need annotations.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void ReturnResult(uint anon1)
+        public DynStorage AsStorage()
         {
-            Interop.service_on_panic_return_result(_context, anon1).AsOk();
+            return Interop.service_storage_array_as_storage(_context);
         }
 
-        /// Methods returning a value need an `on_panic` annotation.
+        /// Returns the value stored at `key`.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint ReturnDefaultValue(uint x)
+        public uint Get(uint key)
         {
-            return Interop.service_on_panic_return_default_value(_context, x);
+            return Interop.storage_get(AsStorage(), key);
         }
 
-        /// This function has no panic safeguards. It will be a bit faster to
-        /// call, but if it panics your host app will abort.
+        /// Stores `val` at `key`.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public IntPtr ReturnUbOnPanic()
+        public void Set(uint key, uint val)
         {
-            return Interop.service_on_panic_return_ub_on_panic(_context);
+            Interop.storage_set(AsStorage(), key, val);
         }
 
         public IntPtr Context => _context;
     }
 
 
-    /// Some struct we want to expose as a class.
-    public partial class ServiceCallbacks : IDisposable
+    /// Storage adding a fixed offset to all values it returns.
+    public partial class ServiceStorageOffset : IDisposable, IStorage
     {
         private IntPtr _context;
 
-        private ServiceCallbacks() {}
+        private ServiceStorageOffset() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceCallbacks New()
+        public static ServiceStorageOffset New(uint offset)
         {
-            var self = new ServiceCallbacks();
-            self._context = Interop.service_callbacks_new().AsOk();
+            var self = new ServiceStorageOffset();
+            self._context = Interop.service_storage_offset_new(offset).AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_callbacks_destroy(_context).AsOk();
+            Interop.service_storage_offset_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
+        /// Services can provide interface methods themselves.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackSimple(MyCallback callback)
+        public uint Get(uint key)
         {
-            Interop.service_callbacks_callback_simple(_context, callback).AsOk();
+            return Interop.service_storage_offset_get(_context, key);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackSimple(MyCallbackDelegate callback)
+        public uint Offset()
         {
-            Interop.service_callbacks_callback_simple(_context, callback).AsOk();
+            return Interop.service_storage_offset_offset(_context);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackFfiReturn(SumDelegateReturn callback)
+        public DynStorage AsStorage()
         {
-            Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk();
+            return Interop.service_storage_offset_as_storage(_context);
         }
 
+        /// Stores `val` at `key`.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackFfiReturn(SumDelegateReturnDelegate callback)
+        public void Set(uint key, uint val)
         {
-            Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk();
+            Interop.storage_set(AsStorage(), key, val);
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackWithSlice(SumDelegateReturn callback, SliceI32 input)
-        {
-            Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk();
-        }
+        public IntPtr Context => _context;
+    }
+
+
+    /// Service holding on to a foreign implementation.
+    public partial class ServiceForeignLogger : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceForeignLogger() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackWithSlice(SumDelegateReturnDelegate callback, SliceI32 input)
+        public static ServiceForeignLogger New(LogSinkVTable sink)
         {
-            Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk();
+            var self = new ServiceForeignLogger();
+            self._context = Interop.service_foreign_logger_new(sink).AsOk();
+            return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void SetDelegateTable(CallbackTable table)
+        public void Dispose()
         {
-            Interop.service_callbacks_set_delegate_table(_context, table);
+            Interop.service_foreign_logger_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void InvokeDelegates()
+        public void Log(uint x)
         {
-            Interop.service_callbacks_invoke_delegates(_context).AsOk();
+            Interop.service_foreign_logger_log(_context, x).AsOk();
         }
 
         public IntPtr Context => _context;
     }
 
 
-    public partial class ServiceIgnoringMethods : IDisposable
+    /// A sink implemented in a foreign language receiving log messages.
+    public abstract partial class LogSinkBase
     {
-        private IntPtr _context;
-
-        private ServiceIgnoringMethods() {}
+        private LogSinkVTable _vtable;
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceIgnoringMethods New()
+        protected LogSinkBase()
         {
-            var self = new ServiceIgnoringMethods();
-            self._context = Interop.service_ignoring_methods_new().AsOk();
-            return self;
+            _vtable = new LogSinkVTable
+            {
+                log = new LogSinkLog(Log),
+                count = new LogSinkCount(Count),
+            };
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
-        {
-            Interop.service_ignoring_methods_destroy(_context).AsOk();
-            _context = IntPtr.Zero;
-        }
+        /// Returns a vtable forwarding to this instance, valid as long as this instance is alive.
+        public LogSinkVTable VTable => _vtable;
 
-        public IntPtr Context => _context;
+        /// Called for each logged value.
+        public abstract void Log(uint level, uint x);
+
+        /// Returns how many values the sink has seen.
+        public abstract uint Count();
     }
 
 
-    /// Some struct we want to expose as a class.
-    public partial class ServiceMultipleCtors : IDisposable
+    /// Service exposing some of its methods as properties.
+    public partial class ServiceProperties : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceMultipleCtors() {}
+        private ServiceProperties() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceMultipleCtors NewWith(uint some_value)
+        public static ServiceProperties New()
         {
-            var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_with(some_value).AsOk();
+            var self = new ServiceProperties();
+            self._context = Interop.service_properties_new().AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceMultipleCtors NewWithout()
+        public void Dispose()
         {
-            var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_without().AsOk();
-            return self;
+            Interop.service_properties_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceMultipleCtors NewWithString([MarshalAs(UnmanagedType.LPStr)] string anon0)
+        public uint GetValue()
         {
-            var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_with_string(anon0).AsOk();
-            return self;
+            return Interop.service_properties_get_value(_context);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceMultipleCtors NewFailing(byte some_value)
+        public void SetValue(uint x)
         {
-            var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_failing(some_value).AsOk();
-            return self;
+            Interop.service_properties_set_value(_context, x);
         }
 
+        /// Properties can also be read-only.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        public uint GetUpdates()
         {
-            Interop.service_multiple_ctors_destroy(_context).AsOk();
-            _context = IntPtr.Zero;
+            return Interop.service_properties_get_updates(_context);
+        }
+
+        public uint Value
+        {
+            get => GetValue();
+            set => SetValue(value);
+        }
+
+        public uint Updates
+        {
+            get => GetUpdates();
         }
 
         public IntPtr Context => _context;
     }
 
 
-    /// Some struct we want to expose as a class.
-    public partial class ServiceVariousSlices : IDisposable
+    /// Service with static helpers next to its constructor.
+    public partial class ServiceStatics : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceVariousSlices() {}
+        private ServiceStatics() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceVariousSlices New()
+        public static ServiceStatics NewWith(uint scale)
         {
-            var self = new ServiceVariousSlices();
-            self._context = Interop.service_various_slices_new().AsOk();
+            var self = new ServiceStatics();
+            self._context = Interop.service_statics_new_with(scale).AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_various_slices_destroy(_context).AsOk();
+            Interop.service_statics_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte MutSelf(SliceU8 slice)
+        public uint Scale(uint x)
         {
-            return Interop.service_various_slices_mut_self(_context, slice);
+            return Interop.service_statics_scale(_context, x);
         }
 
-        /// Single line.
+        /// The scale used by default, not tied to any instance.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void MutSelfVoid(SliceBool slice)
+        public static uint DefaultScale()
         {
-            Interop.service_various_slices_mut_self_void(_context, slice);
+            return Interop.service_statics_default_scale();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte MutSelfRef(ref byte x, ref byte y)
+        public static uint Scaled(uint x, uint scale)
         {
-            return Interop.service_various_slices_mut_self_ref(_context, ref x, ref y);
+            return Interop.service_statics_scaled(x, scale);
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte MutSelfRefSlice(ref byte x, ref byte y, SliceU8 slice)
-        {
-            return Interop.service_various_slices_mut_self_ref_slice(_context, ref x, ref y, slice);
-        }
+        public IntPtr Context => _context;
+    }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte MutSelfRefSliceLimited(ref byte x, ref byte y, SliceU8 slice, SliceU8 slice2)
-        {
-            return Interop.service_various_slices_mut_self_ref_slice_limited(_context, ref x, ref y, slice, slice2);
-        }
+
+    /// Service guarded against use from other threads.
+    /// This service must only be used from the thread that created it.
+    public partial class ServiceThreadAffine : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceThreadAffine() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void MutSelfFfiError(SliceMutU8 slice)
+        public static ServiceThreadAffine New()
         {
-            Interop.service_various_slices_mut_self_ffi_error(_context, slice).AsOk();
+            var self = new ServiceThreadAffine();
+            self._context = Interop.service_thread_affine_new().AsOk();
+            return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void MutSelfNoError(SliceMutU8 slice)
+        public void Dispose()
         {
-            Interop.service_various_slices_mut_self_no_error(_context, slice).AsOk();
+            Interop.service_thread_affine_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
         }
 
-        /// Warning, you _must_ discard the returned slice object before calling into this service
-        /// again, as otherwise undefined behavior might happen.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SliceU32 ReturnSlice()
+        public void Increment()
         {
-            return Interop.service_various_slices_return_slice(_context);
+            Interop.service_thread_affine_increment(_context).AsOk();
         }
 
-        /// Warning, you _must_ discard the returned slice object before calling into this service
-        /// again, as otherwise undefined behavior might happen.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SliceMutU32 ReturnSliceMut()
+        public uint Value()
         {
-            return Interop.service_various_slices_return_slice_mut(_context);
+            return Interop.service_thread_affine_value(_context);
         }
 
         public IntPtr Context => _context;
     }
 
 
-    /// Some struct we want to expose as a class.
-    public partial class ServiceStrings : IDisposable
+    /// Service guarded against concurrent use.
+    /// Calls into this service are serialized by a lock.
+    public partial class ServiceMutex : IDisposable
     {
         private IntPtr _context;
 
-        private ServiceStrings() {}
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceStrings New()
-        {
-            var self = new ServiceStrings();
-            self._context = Interop.service_strings_new().AsOk();
-            return self;
-        }
+        private ServiceMutex() {}
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static ServiceStrings NewString(Utf8String x)
+        public static ServiceMutex New()
         {
-            var self = new ServiceStrings();
-            self._context = Interop.service_strings_new_string(x).AsOk();
+            var self = new ServiceMutex();
+            self._context = Interop.service_mutex_new().AsOk();
             return self;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_strings_destroy(_context).AsOk();
+            Interop.service_mutex_destroy(_context).AsOk();
             _context = IntPtr.Zero;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void PassCstr([MarshalAs(UnmanagedType.LPStr)] string anon1)
-        {
-            Interop.service_strings_pass_cstr(_context, anon1);
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public IntPtr ReturnCstr()
-        {
-            return Interop.service_strings_return_cstr(_context);
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackString(Utf8String s, StringCallback cb)
+        public uint Increment()
         {
-            Interop.service_strings_callback_string(_context, s, cb);
+            return Interop.service_mutex_increment(_context);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void CallbackString(Utf8String s, StringCallbackDelegate cb)
+        public uint Value()
         {
-            Interop.service_strings_callback_string(_context, s, cb);
+            return Interop.service_mutex_value(_context);
         }
 
         public IntPtr Context => _context;
@@ -14537,15 +19905,23 @@ namespace My.Company
 
 
 
+    public class InteropException : Exception
+    {
+        public InteropException() : base()
+        {
+        }
 
-    public class EnumException() : InteropException($"Enum variant mismatch.")  { }
+        public InteropException(string message) : base(message)
+        {
+        }
 
-    public class EnumException<T>(T t) : InteropException($"Enum variant mismatch.")
-    {
-        public T Value { get; } = t;
+        /// Creates an exception for an unexpected result, including details about the last error or panic in Rust.
+        public static InteropException FromLastError(string result)
+        {
+            var lastError = Utf8String.LastError();
+            return string.IsNullOrEmpty(lastError) ? new InteropException(result) : new InteropException($"{result}: {lastError}");
+        }
     }
-
-    public class InteropException(string text) : Exception(text) { }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -14646,23 +20022,33 @@ namespace My.Company
     {
         private Utf8String() { }
 
+        // Hands the UTF-16 contents of `s` to Rust, which converts them without an intermediate copy here. Like
+        // `Encoding.UTF8`, unpaired surrogates become U+FFFD.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static unsafe Utf8String From(string s)
         {
-            var rval = new Utf8String();
-            var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+            fixed (char* p = s)
+            {
+                InteropHelper.interoptopus_string_create_utf16((IntPtr)p, (ulong)s.Length, out var native);
+                return native.IntoManaged();
+            }
+        }
 
-            fixed (byte* p = utf8Bytes)
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String FromUtf8(ReadOnlySpan<byte> utf8)
+        {
+            fixed (byte* p = utf8)
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                var status = InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)utf8.Length, out var native);
+                if (status != 0) { throw new InteropException("Bytes are not valid UTF-8."); }
+                return native.IntoManaged();
             }
+        }
 
-            return rval;
+        /// Returns and clears details about the last error or panic in Rust on this thread, or an empty string.
+        public static string LastError()
+        {
+            return InteropHelper.interoptopus_last_error().IntoManaged().IntoString();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -14760,6 +20146,11 @@ namespace My.Company
             
             public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
 
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, out Unmanaged rval);
+
             [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_destroy")]
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
@@ -14769,6 +20160,11 @@ namespace My.Company
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             
             public static partial long interoptopus_string_clone(ref Unmanaged orig, ref Unmanaged cloned);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_last_error")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            
+            public static partial Unmanaged interoptopus_last_error();
         }
 
         [CustomMarshaller(typeof(Utf8String), MarshalMode.Default, typeof(Marshaller))]
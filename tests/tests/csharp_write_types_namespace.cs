@@ -3,9 +3,9 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      library
-// Hash:         0x8047a8b1c89b1a85
+// Hash:         0x697e3c498b89abcb
 // Namespace:    
-// Builder:      interoptopus_backend_csharp (0.15.0-alpha.20)
+// Builder:      interoptopus_backend_csharp (0.15.0-alpha.21)
 //
 // Do not edit this file manually.
 //
@@ -34,9 +34,9 @@ namespace My.Company
         static Interop()
         {
             var api_version = Interop.pattern_api_guard();
-            if (api_version != 0x8047a8b1c89b1a85)
+            if (api_version != 0x697e3c498b89abcb)
             {
-                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0x8047a8b1c89b1a85). You probably forgot to update / copy either the bindings or the library.");
+                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0x697e3c498b89abcb). You probably forgot to update / copy either the bindings or the library.");
             }
         }
 
@@ -54,11 +54,18 @@ namespace My.Company
         public const int COMPUTED_I32 = (int) -2147483647;
 
 
+        /// Creates a string from `len` UTF-8 bytes, returns `0` on success or `1` (and an empty string) if they are not valid UTF-8.
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_create")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_string_create(IntPtr utf8, ulong len, ref Utf8String rval);
 
 
+        /// Creates a string from `len` UTF-16 code units, replacing unpaired surrogates with `U+FFFD` like .NET does; always returns `0`.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_create_utf16")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_string_create_utf16(IntPtr utf16, ulong len, ref Utf8String rval);
+
+
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_destroy")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_string_destroy(Utf8String utf8);
@@ -69,11 +76,35 @@ namespace My.Company
         public static partial long interoptopus_string_clone(ref Utf8String utf8, ref Utf8String rval);
 
 
+        /// Returns and clears details about the last error or panic on this thread, or an empty string.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_last_error")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Utf8String interoptopus_last_error();
+
+
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_wire_destroy")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void interoptopus_wire_destroy(ref byte data, int len, int capacity);
 
 
+        /// Installs allocation hooks for all FFI buffers, returns `0` on success or `1` if it is too late to do so.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_allocator_set")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_allocator_set(InteropDelegate_fn_u64_u64_rval_MutPtrVoid alloc, InteropDelegate_fn_MutPtrVoid_u64_u64_rval_void free);
+
+
+        /// Allocates `size` bytes aligned to `align` that Rust may later free, returns null on failure.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_alloc")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IntPtr interoptopus_alloc(ulong size, ulong align);
+
+
+        /// Frees memory allocated by `interoptopus_alloc`, or owned by a buffer Rust handed out; invalid `size` and `align` values are ignored.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_free")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void interoptopus_free(IntPtr ptr, ulong size, ulong align);
+
+
         [LibraryImport(NativeLib, EntryPoint = "interoptopus_vec_create_6849152863081469284")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial long interoptopus_vec_create_6849152863081469284(IntPtr data, ulong len, ref VecU8 rval);
@@ -114,6 +145,38 @@ namespace My.Company
         public static partial long interoptopus_vec_destroy_4854562680055612543(VecEnumPayload ignored);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_map_create_6262311165280223196")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_map_create_6262311165280223196(IntPtr keys, IntPtr values, ulong len, ref MapU32Vec3f32 rval);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_map_destroy_9095550523068598099")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_map_destroy_9095550523068598099(MapU32Vec3f32 ignored);
+
+
+        /// Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_next_14415398661037898230")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_next_14415398661037898230(ref IterU32 iter, ref uint rval);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_destroy_11887687051161408401")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_destroy_11887687051161408401(IterU32 ignored);
+
+
+        /// Writes the next item into `rval` and returns 1, or returns 0 once the iterator is exhausted or panicked.
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_next_12283536038193100041")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_next_12283536038193100041(ref IterVec3f32 iter, ref Vec3f32 rval);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_iter_destroy_14403488365818035019")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_iter_destroy_14403488365818035019(IterVec3f32 ignored);
+
+
         [LibraryImport(NativeLib, EntryPoint = "alignment_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial Packed2 alignment_1(Packed1 a);
@@ -179,6 +242,26 @@ namespace My.Company
         public static partial ResultError behavior_panics_via_result();
 
 
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panics_ffi_error")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error behavior_panics_ffi_error();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panics_return_default")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint behavior_panics_return_default();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panics_callback")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint behavior_panics_callback();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "behavior_panic_callbacks")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint behavior_panic_callbacks();
+
+
         [LibraryImport(NativeLib, EntryPoint = "enums_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void enums_1(EnumPayload ignored);
@@ -209,6 +292,11 @@ namespace My.Company
         public static partial void fnptr_2(InteropDelegate_fn_CharArray_rval_void_native callback, CharArray x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "fnptr_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint fnptr_3(InteropDelegate_fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32 callback);
+
+
         [LibraryImport(NativeLib, EntryPoint = "generic_1a")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial uint generic_1a(Genericu32 x, Phantomu8 y);
@@ -281,6 +369,11 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial OptionVec namespaced_inner_option(OptionVec x);
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionVec namespaced_inner_option(Vec? x)
+        {
+            return namespaced_inner_option(OptionVec.FromNullable(x));
+        }
 
         [LibraryImport(NativeLib, EntryPoint = "namespaced_inner_slice")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -373,6 +466,26 @@ namespace My.Company
         public static partial byte primitive_u8(byte x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "primitive_u128")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial UInt128 primitive_u128(UInt128 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "primitive_i128")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Int128 primitive_i128(Int128 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "primitive_char")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Rune primitive_char(Rune x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "primitive_nonzero")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint primitive_nonzero(uint x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "primitive_void")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void primitive_void();
@@ -454,6 +567,11 @@ namespace My.Company
         public static partial bool struct3(BoolField x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "struct4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial WideNum struct4(WideNum x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_ascii_pointer_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial uint pattern_ascii_pointer_1([MarshalAs(UnmanagedType.LPStr)] string x);
@@ -544,6 +662,59 @@ namespace My.Company
         public static partial void pattern_string_11(ref Utf8String ignored);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_str_1(Utf8Str x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe uint pattern_str_1(ReadOnlySpan<byte> x)
+        {
+            fixed (byte* x_ptr = x)
+            {
+                return pattern_str_1(Utf8Str.From((IntPtr) x_ptr, (ulong) x.Length));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint pattern_str_1(string x)
+        {
+            return pattern_str_1(Encoding.UTF8.GetBytes(x));
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Utf8Str pattern_str_2();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Utf8Str pattern_str_3(Utf8Str x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_str_4(UseStr x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_str_5")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_str_5(Utf8Str x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe ResultU32Error pattern_str_5(ReadOnlySpan<byte> x)
+        {
+            fixed (byte* x_ptr = x)
+            {
+                return pattern_str_5(Utf8Str.From((IntPtr) x_ptr, (ulong) x.Length));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ResultU32Error pattern_str_5(string x)
+        {
+            return pattern_str_5(Encoding.UTF8.GetBytes(x));
+        }
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_slice_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial uint pattern_ffi_slice_1(SliceU32 ffi_slice);
@@ -665,15 +836,42 @@ namespace My.Company
             }
         }
 
+        /// Sums all elements, regardless of the number of dimensions or their strides.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_ndslice_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial float pattern_ndslice_1(NdSliceF32 slice);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_ndslice_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial int pattern_ndslice_2(NdSliceI32 slice, ulong row, ulong col);
+
+
+        /// Sets each element of a 2D slice to `row * 100 + col`.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_ndslice_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void pattern_ndslice_3(NdSliceMutU32 slice);
+
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_option_1")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial OptionInner pattern_ffi_option_1(OptionInner x);
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionInner pattern_ffi_option_1(Inner? x)
+        {
+            return pattern_ffi_option_1(OptionInner.FromNullable(x));
+        }
 
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_option_2")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial Inner pattern_ffi_option_2(OptionInner x);
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static Inner pattern_ffi_option_2(Inner? x)
+        {
+            return pattern_ffi_option_2(OptionInner.FromNullable(x));
+        }
 
         [LibraryImport(NativeLib, EntryPoint = "pattern_ffi_option_3")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -720,6 +918,16 @@ namespace My.Company
         public static partial ResultVoid pattern_result_4(ResultVoid x);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "pattern_result_5")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_result_5(uint x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_result_6")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_result_6(uint x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "pattern_api_guard")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial ulong pattern_api_guard();
@@ -831,37 +1039,101 @@ namespace My.Company
 
         [LibraryImport(NativeLib, EntryPoint = "pattern_callback_9")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static partial int pattern_callback_9(Pointers x);
+        public static partial uint pattern_callback_9(MyOwnedCallback callback, uint x);
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe int pattern_callback_9(PointersDelegate x)
+        public static unsafe uint pattern_callback_9(MyOwnedCallbackDelegate callback, uint x)
         {
-            var x_wrapped = new Pointers(x);
+            var callback_wrapped = new MyOwnedCallback(callback);
             try
             {
-                return pattern_callback_9(x_wrapped);
+                return pattern_callback_9(callback_wrapped, x);
             }
             finally
             {
-                x_wrapped.Dispose();
+                callback_wrapped.Dispose();
             }
         }
 
+        /// Keeps the callback until replaced, dropping the previous one.
         [LibraryImport(NativeLib, EntryPoint = "pattern_callback_10")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static partial void pattern_callback_10(StringCallbackString ignored);
+        public static partial void pattern_callback_10(MyOwnedCallback callback);
+
+        /// Keeps the callback until replaced, dropping the previous one.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe void pattern_callback_10(MyOwnedCallbackDelegate callback)
+        {
+            var callback_wrapped = new MyOwnedCallback(callback);
+            try
+            {
+                pattern_callback_10(callback_wrapped);
+            }
+            finally
+            {
+                callback_wrapped.Dispose();
+            }
+        }
+
+        /// Invokes the callback stored by `pattern_callback_10`, returns 0 if there is none.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_11")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_callback_11(uint x);
+
+
+        /// Returns the callback's result plus one, passing on errors and failures of the callback.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_12")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error pattern_callback_12(MyFallibleCallback callback, uint x);
+
+        /// Returns the callback's result plus one, passing on errors and failures of the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe ResultU32Error pattern_callback_12(MyFallibleCallbackDelegate callback, uint x)
+        {
+            var callback_wrapped = new MyFallibleCallback(callback);
+            try
+            {
+                return pattern_callback_12(callback_wrapped, x);
+            }
+            finally
+            {
+                callback_wrapped.Dispose();
+            }
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_13")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial byte pattern_callback_13(OnItemU32 callback, uint x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe byte pattern_callback_13(OnItemU32Delegate callback, uint x)
+        {
+            var callback_wrapped = new OnItemU32(callback);
+            try
+            {
+                return pattern_callback_13(callback_wrapped, x);
+            }
+            finally
+            {
+                callback_wrapped.Dispose();
+            }
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_callback_14")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial byte pattern_callback_14(OnItemF32 callback, float x);
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe void pattern_callback_10(StringCallbackStringDelegate ignored)
+        public static unsafe byte pattern_callback_14(OnItemF32Delegate callback, float x)
         {
-            var ignored_wrapped = new StringCallbackString(ignored);
+            var callback_wrapped = new OnItemF32(callback);
             try
             {
-                pattern_callback_10(ignored_wrapped);
+                return pattern_callback_14(callback_wrapped, x);
             }
             finally
             {
-                ignored_wrapped.Dispose();
+                callback_wrapped.Dispose();
             }
         }
 
@@ -910,6 +1182,98 @@ namespace My.Company
         public static partial UseSliceAndVec pattern_vec_8(UseSliceAndVec v);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "pattern_map_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial MapU32Vec3f32 pattern_map_1();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_map_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial OptionVec3f32 pattern_map_2(MapU32Vec3f32 m, uint key);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_map_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial MapU32Vec3f32 pattern_map_3(ref MapU32Vec3f32 m);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterU32 pattern_iter_1(uint n);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterVec3f32 pattern_iter_2();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_iter_3(IterU32 iter);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_iter_4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterU32 pattern_iter_4(uint n);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuple2_F32_U32 pattern_tuple_1(Tuple2_U32_F32 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuple3_Vec3f32_U8_I64 pattern_tuple_2(Tuple3_Vec3f32_U8_I64 x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuples pattern_tuple_3(Tuples x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_4")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_tuple_4(OptionTuple2_U32_U32 x);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint pattern_tuple_4(Tuple2_U32_U32? x)
+        {
+            return pattern_tuple_4(OptionTuple2_U32_U32.FromNullable(x));
+        }
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_tuple_5")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_tuple_5(Tuple2_U32_U32 tuple0);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_duration_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Duration pattern_duration_1(Duration x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_duration_2")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Duration pattern_duration_2(Timeout x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "pattern_duration_3")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Duration pattern_duration_3();
+
+
+        /// Works with any service implementing `Storage`.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_service_interface_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_service_interface_1(DynStorage storage, uint key, uint val);
+
+
+        /// Logs the given values to a foreign sink and returns what the sink counted.
+        [LibraryImport(NativeLib, EntryPoint = "pattern_foreign_trait_1")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint pattern_foreign_trait_1(LogSinkVTable sink, uint x);
+
+
         [LibraryImport(NativeLib, EntryPoint = "wire_accept_string_2")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void wire_accept_string_2(WireOfMyString input);
@@ -939,14 +1303,7 @@ namespace My.Company
         public static unsafe Task service_async_basic_call(IntPtr _context)
         {
             var (_cb, _cs) = _trampolineResultError.NewCall();
-            try
-            {
-                service_async_basic_call(_context, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_basic_call(_context, _cb).AsOk();
             return _cs;
         }
 
@@ -974,14 +1331,7 @@ namespace My.Company
         public static unsafe Task<ulong> service_async_sleep_return_after_ms(IntPtr _context, ulong x, ulong ms)
         {
             var (_cb, _cs) = _trampolineResultU64Error.NewCall();
-            try
-            {
-                service_async_sleep_return_after_ms(_context, x, ms, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_sleep_return_after_ms(_context, x, ms, _cb).AsOk();
             return _cs;
         }
 
@@ -1009,14 +1359,7 @@ namespace My.Company
         public static unsafe Task<Utf8String> service_async_vec_string_handle_string(IntPtr _context, Utf8String s)
         {
             var (_cb, _cs) = _trampolineResultUtf8StringError.NewCall();
-            try
-            {
-                service_async_vec_string_handle_string(_context, s, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_vec_string_handle_string(_context, s, _cb).AsOk();
             return _cs;
         }
 
@@ -1028,14 +1371,7 @@ namespace My.Company
         public static unsafe Task<VecUtf8String> service_async_vec_string_handle_vec_string(IntPtr _context, VecUtf8String s)
         {
             var (_cb, _cs) = _trampolineResultVecUtf8StringError.NewCall();
-            try
-            {
-                service_async_vec_string_handle_vec_string(_context, s, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_vec_string_handle_vec_string(_context, s, _cb).AsOk();
             return _cs;
         }
 
@@ -1047,14 +1383,7 @@ namespace My.Company
         public static unsafe Task<UseString> service_async_vec_string_handle_nested_string(IntPtr _context, Utf8String s)
         {
             var (_cb, _cs) = _trampolineResultUseStringError.NewCall();
-            try
-            {
-                service_async_vec_string_handle_nested_string(_context, s, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_vec_string_handle_nested_string(_context, s, _cb).AsOk();
             return _cs;
         }
 
@@ -1082,14 +1411,7 @@ namespace My.Company
         public static unsafe Task service_async_result_success(IntPtr _context)
         {
             var (_cb, _cs) = _trampolineResultError.NewCall();
-            try
-            {
-                service_async_result_success(_context, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_result_success(_context, _cb).AsOk();
             return _cs;
         }
 
@@ -1101,14 +1423,7 @@ namespace My.Company
         public static unsafe Task service_async_result_fail(IntPtr _context)
         {
             var (_cb, _cs) = _trampolineResultError.NewCall();
-            try
-            {
-                service_async_result_fail(_context, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_result_fail(_context, _cb).AsOk();
             return _cs;
         }
 
@@ -1136,14 +1451,7 @@ namespace My.Company
         public static unsafe Task<NestedArray> service_async_structs_process_struct(IntPtr _context, NestedArray x)
         {
             var (_cb, _cs) = _trampolineResultNestedArrayError.NewCall();
-            try
-            {
-                service_async_structs_process_struct(_context, x, _cb).AsOk();
-                return _cs;
-            }
-            finally
-            {
-            }
+            service_async_structs_process_struct(_context, x, _cb).AsOk();
             return _cs;
         }
 
@@ -1241,6 +1549,16 @@ namespace My.Company
         public static partial ResultU32Error service_result_result_slice(IntPtr _context, SliceU32 slice, ulong i);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "service_result_result_std")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error service_result_result_std(IntPtr _context, uint x);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_result_result_std_mapped")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultU32Error service_result_result_std_mapped(IntPtr _context, uint x);
+
+
         /// Destroys the given instance.
         ///
         /// # Safety
@@ -1404,6 +1722,11 @@ namespace My.Company
         public static partial ResultConstPtrServiceMultipleCtorsError service_multiple_ctors_new_failing(byte some_value);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "service_multiple_ctors_new_std")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceMultipleCtorsError service_multiple_ctors_new_std(uint some_value);
+
+
         /// Destroys the given instance.
         ///
         /// # Safety
@@ -1470,6 +1793,18 @@ namespace My.Company
         public static partial SliceMutU32 service_various_slices_return_slice_mut(IntPtr _context);
 
 
+        /// Hands out a copy of the data lazily, so the returned iterator stays valid even if the
+        /// service is used again.
+        [LibraryImport(NativeLib, EntryPoint = "service_various_slices_return_iter")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial IterU32 service_various_slices_return_iter(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_various_slices_return_len_and_first")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial Tuple2_U64_U32 service_various_slices_return_len_and_first(IntPtr _context);
+
+
         /// Destroys the given instance.
         ///
         /// # Safety
@@ -1501,6 +1836,25 @@ namespace My.Company
         public static partial IntPtr service_strings_return_cstr(IntPtr _context);
 
 
+        [LibraryImport(NativeLib, EntryPoint = "service_strings_pass_str")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_strings_pass_str(IntPtr _context, Utf8Str s);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe uint service_strings_pass_str(IntPtr _context, ReadOnlySpan<byte> s)
+        {
+            fixed (byte* s_ptr = s)
+            {
+                return service_strings_pass_str(_context, Utf8Str.From((IntPtr) s_ptr, (ulong) s.Length));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint service_strings_pass_str(IntPtr _context, string s)
+        {
+            return service_strings_pass_str(_context, Encoding.UTF8.GetBytes(s));
+        }
+
         [LibraryImport(NativeLib, EntryPoint = "service_strings_callback_string")]
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static partial void service_strings_callback_string(IntPtr _context, Utf8String s, StringCallback cb);
@@ -1519,6 +1873,214 @@ namespace My.Company
             }
         }
 
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_array_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageArrayError service_storage_array_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_array_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageArrayError service_storage_array_new();
+
+
+        /// Returns a [`Storage`] handle to this service, valid until the service is destroyed.
+        ///
+        /// While a `&mut self` method runs through the handle, no other method of this service may be called.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_array_as_storage")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial DynStorage service_storage_array_as_storage(IntPtr _context);
+
+
+        /// Returns the value stored at `key`.
+        [LibraryImport(NativeLib, EntryPoint = "storage_get")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint storage_get(DynStorage _handle, uint key);
+
+
+        /// Stores `val` at `key`.
+        [LibraryImport(NativeLib, EntryPoint = "storage_set")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void storage_set(DynStorage _handle, uint key, uint val);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageOffsetError service_storage_offset_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStorageOffsetError service_storage_offset_new(uint offset);
+
+
+        /// Services can provide interface methods themselves.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_get")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_storage_offset_get(IntPtr _context, uint key);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_offset")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_storage_offset_offset(IntPtr _context);
+
+
+        /// Returns a [`Storage`] handle to this service, valid until the service is destroyed.
+        ///
+        /// While a `&mut self` method runs through the handle, no other method of this service may be called.
+        [LibraryImport(NativeLib, EntryPoint = "service_storage_offset_as_storage")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial DynStorage service_storage_offset_as_storage(IntPtr _context);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_foreign_logger_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceForeignLoggerError service_foreign_logger_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_foreign_logger_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceForeignLoggerError service_foreign_logger_new(LogSinkVTable sink);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_foreign_logger_log")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultError service_foreign_logger_log(IntPtr _context, uint x);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServicePropertiesError service_properties_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServicePropertiesError service_properties_new();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_get_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_properties_get_value(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_set_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void service_properties_set_value(IntPtr _context, uint x);
+
+
+        /// Properties can also be read-only.
+        [LibraryImport(NativeLib, EntryPoint = "service_properties_get_updates")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_properties_get_updates(IntPtr _context);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStaticsError service_statics_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_new_with")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceStaticsError service_statics_new_with(uint scale);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_scale")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_statics_scale(IntPtr _context, uint x);
+
+
+        /// The scale used by default, not tied to any instance.
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_default_scale")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_statics_default_scale();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_statics_scaled")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_statics_scaled(uint x, uint scale);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceThreadAffineSyncError service_thread_affine_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceThreadAffineSyncError service_thread_affine_new();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_increment")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultSyncError service_thread_affine_increment(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_thread_affine_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_thread_affine_value(IntPtr _context);
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceMutexError service_mutex_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceMutexError service_mutex_new();
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_increment")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_mutex_increment(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_mutex_value")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint service_mutex_value(IntPtr _context);
+
+
     }
 
     public partial struct EnumPayload
@@ -1531,41 +2093,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct EnumPayload 
     {
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedB
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Vec3f32.Unmanaged _B;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedC
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal uint _C;
         }
 
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedB _B;
-
-            [FieldOffset(0)]
-            internal UnmanagedC _C;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal EnumPayload ToManaged()
             {
                 var _managed = new EnumPayload();
                 _managed._variant = _variant;
-                if (_variant == 1) _managed._B = _B._B.ToManaged();
-                if (_variant == 2) _managed._C = _C._C;
+                if (_variant == 1) _managed._B = _payload._B.ToManaged();
+                if (_variant == 2) _managed._C = _payload._C;
                 return _managed;
             }
         }
@@ -1575,8 +2125,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._B._B = _B.ToUnmanaged();
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 1) _unmanaged._payload._B = _B.ToUnmanaged();
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -1585,8 +2135,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 1) _unmanaged._B._B = _B.ToUnmanaged();
-            if (_variant == 2) _unmanaged._C._C = _C;
+            if (_variant == 1) _unmanaged._payload._B = _B.ToUnmanaged();
+            if (_variant == 2) _unmanaged._payload._C = _C;
             return _unmanaged;
         }
 
@@ -1598,9 +2148,9 @@ namespace My.Company
         public bool IsB => _variant == 1;
         public bool IsC => _variant == 2;
 
-        public void AsA() { if (_variant != 0) throw ExceptionForVariant(); }
-        public Vec3f32 AsB() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _B; } }
-        public uint AsC() { if (_variant != 2) { throw ExceptionForVariant(); } else { return _C; } }
+        public void AsA() { if (_variant != 0) throw new InteropException(); }
+        public Vec3f32 AsB() { if (_variant != 1) { throw new InteropException(); } else { return _B; } }
+        public uint AsC() { if (_variant != 2) { throw new InteropException(); } else { return _C; } }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -1608,16 +2158,7 @@ namespace My.Company
             if (_variant == 0) return "A";
             if (_variant == 1) return "B(...)";
             if (_variant == 2) return "C(...)";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException();
-            if (_variant == 1) return new EnumException<Vec3f32>(_B);
-            if (_variant == 2) return new EnumException<uint>(_C);
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(EnumPayload), MarshalMode.Default, typeof(Marshaller))]
@@ -1661,17 +2202,12 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial class Layer3 : IDisposable
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedA
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Layer1Utf8String.Unmanaged _A;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedB
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Layer2Utf8String.Unmanaged _B;
         }
 
@@ -1681,25 +2217,19 @@ namespace My.Company
             if (_variant == 1) { _B.Dispose(); }
         }
 
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedA _A;
-
-            [FieldOffset(0)]
-            internal UnmanagedB _B;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal Layer3 IntoManaged()
             {
                 var _managed = new Layer3();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._A = _A._A.IntoManaged();
-                if (_variant == 1) _managed._B = _B._B.IntoManaged();
+                if (_variant == 0) _managed._A = _payload._A.IntoManaged();
+                if (_variant == 1) _managed._B = _payload._B.IntoManaged();
                 return _managed;
             }
         }
@@ -1709,8 +2239,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._B._B = _B.IntoUnmanaged();
+            if (_variant == 0) _unmanaged._payload._A = _A.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._B = _B.IntoUnmanaged();
             return _unmanaged;
         }
 
@@ -1719,8 +2249,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._A._A = _A.AsUnmanaged();
-            if (_variant == 1) _unmanaged._B._B = _B.AsUnmanaged();
+            if (_variant == 0) _unmanaged._payload._A = _A.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._B = _B.AsUnmanaged();
             return _unmanaged;
         }
 
@@ -1730,23 +2260,15 @@ namespace My.Company
         public bool IsA => _variant == 0;
         public bool IsB => _variant == 1;
 
-        public Layer1Utf8String AsA() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _A; } }
-        public Layer2Utf8String AsB() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _B; } }
+        public Layer1Utf8String AsA() { if (_variant != 0) { throw new InteropException(); } else { return _A; } }
+        public Layer2Utf8String AsB() { if (_variant != 1) { throw new InteropException(); } else { return _B; } }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "A(...)";
             if (_variant == 1) return "B(...)";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Layer1Utf8String>(_A);
-            if (_variant == 1) return new EnumException<Layer2Utf8String>(_B);
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(Layer3), MarshalMode.Default, typeof(Marshaller))]
@@ -2234,21 +2756,24 @@ namespace My.Company
         }
     }
 
-    public partial struct ExtraTypef32
+    /// Some key-value storage shared by multiple services.
+    public partial struct DynStorage
     {
-        public float x;
+        IntPtr context;
+        IntPtr vtable;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ExtraTypef32 
+    public partial struct DynStorage 
     {
-        public ExtraTypef32() { }
+        public DynStorage() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.x = x;
+            _unmanaged.context = context;
+            _unmanaged.vtable = vtable;
             return _unmanaged;
         }
 
@@ -2256,20 +2781,23 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.x = x;
+            _unmanaged.context = context;
+            _unmanaged.vtable = vtable;
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public float x;
+            public IntPtr context;
+            public IntPtr vtable;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe ExtraTypef32 ToManaged()
+            internal unsafe DynStorage ToManaged()
             {
-                var _managed = new ExtraTypef32();
-                _managed.x = x;
+                var _managed = new DynStorage();
+                _managed.context = context;
+                _managed.vtable = vtable;
                 return _managed;
             }
         }
@@ -2278,24 +2806,24 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "ExtraTypef32 { ... }";
+            return "DynStorage { ... }";
         }
 
-        [CustomMarshaller(typeof(ExtraTypef32), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(DynStorage), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private ExtraTypef32 _managed;
+            private DynStorage _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ExtraTypef32 managed) { _managed = managed; }
+            public Marshaller(DynStorage managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ExtraTypef32 managed) { _managed = managed; }
+            public void FromManaged(DynStorage managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -2304,34 +2832,28 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ExtraTypef32 ToManaged() { return _unmanaged.ToManaged(); }
+            public DynStorage ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public partial struct FixedString
+    public partial struct ExtraTypef32
     {
-        public byte[] data;
+        public float x;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct FixedString 
+    public partial struct ExtraTypef32 
     {
-        public FixedString() { }
+        public ExtraTypef32() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            {
-                if (data == null) { throw new InvalidOperationException("Array 'data' must not be null"); }
-                if (data.Length != 32) { throw new InvalidOperationException("Array size mismatch for 'data'"); }
-                var src = new ReadOnlySpan<byte>(data, 0, 32);
-                var dst = new Span<byte>(_unmanaged.data, 32);
-                src.CopyTo(dst);
-            }
+            _unmanaged.x = x;
             return _unmanaged;
         }
 
@@ -2339,13 +2861,96 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            {
-                if (data == null) { throw new InvalidOperationException("Array 'data' must not be null"); }
-                if (data.Length != 32) { throw new InvalidOperationException("Array size mismatch for 'data'"); }
-                var src = new ReadOnlySpan<byte>(data, 0, 32);
-                var dst = new Span<byte>(_unmanaged.data, 32);
-                src.CopyTo(dst);
-            }
+            _unmanaged.x = x;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public float x;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe ExtraTypef32 ToManaged()
+            {
+                var _managed = new ExtraTypef32();
+                _managed.x = x;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "ExtraTypef32 { ... }";
+        }
+
+        [CustomMarshaller(typeof(ExtraTypef32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private ExtraTypef32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ExtraTypef32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ExtraTypef32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ExtraTypef32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct FixedString
+    {
+        public byte[] data;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct FixedString 
+    {
+        public FixedString() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            {
+                if (data == null) { throw new InvalidOperationException("Array 'data' must not be null"); }
+                if (data.Length != 32) { throw new InvalidOperationException("Array size mismatch for 'data'"); }
+                var src = new ReadOnlySpan<byte>(data, 0, 32);
+                var dst = new Span<byte>(_unmanaged.data, 32);
+                src.CopyTo(dst);
+            }
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            {
+                if (data == null) { throw new InvalidOperationException("Array 'data' must not be null"); }
+                if (data.Length != 32) { throw new InvalidOperationException("Array size mismatch for 'data'"); }
+                var src = new ReadOnlySpan<byte>(data, 0, 32);
+                var dst = new Span<byte>(_unmanaged.data, 32);
+                src.CopyTo(dst);
+            }
             return _unmanaged;
         }
 
@@ -2991,6 +3596,101 @@ namespace My.Company
         }
     }
 
+    /// A sink implemented in a foreign language receiving log messages.
+    public partial struct LogSinkVTable
+    {
+        /// Called for each logged value.
+        public LogSinkLog log;
+        /// Returns how many values the sink has seen.
+        public LogSinkCount count;
+        public IntPtr user_data;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct LogSinkVTable : IDisposable
+    {
+        public LogSinkVTable() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.log = log?.ToUnmanaged() ?? default;
+            _unmanaged.count = count?.ToUnmanaged() ?? default;
+            _unmanaged.user_data = user_data;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.log = log?.ToUnmanaged() ?? default;
+            _unmanaged.count = count?.ToUnmanaged() ?? default;
+            _unmanaged.user_data = user_data;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public LogSinkLog.Unmanaged log;
+            public LogSinkCount.Unmanaged count;
+            public IntPtr user_data;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe LogSinkVTable ToManaged()
+            {
+                var _managed = new LogSinkVTable();
+                _managed.log = log.ToManaged();
+                _managed.count = count.ToManaged();
+                _managed.user_data = user_data;
+                return _managed;
+            }
+        }
+
+        public void Dispose()
+        {
+            log.Dispose();
+            count.Dispose();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "LogSinkVTable { ... }";
+        }
+
+        [CustomMarshaller(typeof(LogSinkVTable), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private LogSinkVTable _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(LogSinkVTable managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(LogSinkVTable managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public LogSinkVTable ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
     public partial struct NestedArray
     {
         public EnumRenamed field_enum;
@@ -3531,21 +4231,23 @@ namespace My.Company
         }
     }
 
-    public partial struct Tupled
+    public partial struct Timeout
     {
-        public byte x0;
+        public Duration after;
+        public uint retries;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct Tupled 
+    public partial struct Timeout 
     {
-        public Tupled() { }
+        public Timeout() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.x0 = x0;
+            _unmanaged.after = after.ToUnmanaged();
+            _unmanaged.retries = retries;
             return _unmanaged;
         }
 
@@ -3553,20 +4255,23 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.x0 = x0;
+            _unmanaged.after = after.ToUnmanaged();
+            _unmanaged.retries = retries;
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public byte x0;
+            public Duration.Unmanaged after;
+            public uint retries;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe Tupled ToManaged()
+            internal unsafe Timeout ToManaged()
             {
-                var _managed = new Tupled();
-                _managed.x0 = x0;
+                var _managed = new Timeout();
+                _managed.after = after.ToManaged();
+                _managed.retries = retries;
                 return _managed;
             }
         }
@@ -3575,24 +4280,24 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "Tupled { ... }";
+            return "Timeout { ... }";
         }
 
-        [CustomMarshaller(typeof(Tupled), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(Timeout), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private Tupled _managed;
+            private Timeout _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Tupled managed) { _managed = managed; }
+            public Marshaller(Timeout managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(Tupled managed) { _managed = managed; }
+            public void FromManaged(Timeout managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -3601,28 +4306,28 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Tupled ToManaged() { return _unmanaged.ToManaged(); }
+            public Timeout ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public partial struct UseCStrPtr
+    public partial struct Tupled
     {
-        public string ascii_string;
+        public byte x0;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct UseCStrPtr 
+    public partial struct Tupled 
     {
-        public UseCStrPtr() { }
+        public Tupled() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.ascii_string = IntPtr.Zero;
+            _unmanaged.x0 = x0;
             return _unmanaged;
         }
 
@@ -3630,20 +4335,20 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.ascii_string = IntPtr.Zero;
+            _unmanaged.x0 = x0;
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public IntPtr ascii_string;
+            public byte x0;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe UseCStrPtr ToManaged()
+            internal unsafe Tupled ToManaged()
             {
-                var _managed = new UseCStrPtr();
-                _managed.ascii_string = string.Empty;
+                var _managed = new Tupled();
+                _managed.x0 = x0;
                 return _managed;
             }
         }
@@ -3652,24 +4357,24 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "UseCStrPtr { ... }";
+            return "Tupled { ... }";
         }
 
-        [CustomMarshaller(typeof(UseCStrPtr), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(Tupled), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private UseCStrPtr _managed;
+            private Tupled _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(UseCStrPtr managed) { _managed = managed; }
+            public Marshaller(Tupled managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(UseCStrPtr managed) { _managed = managed; }
+            public void FromManaged(Tupled managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -3678,30 +4383,30 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public UseCStrPtr ToManaged() { return _unmanaged.ToManaged(); }
+            public Tupled ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public partial class UseSliceAndVec
+    public partial struct Tuples
     {
-        public SliceUtf8String s1;
-        public VecUtf8String s2;
+        public Tuple2_U32_F32 pair;
+        public Tuple3_Vec3f32_U8_I64 triple;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class UseSliceAndVec : IDisposable
+    public partial struct Tuples 
     {
-        public UseSliceAndVec() { }
+        public Tuples() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal unsafe Unmanaged IntoUnmanaged()
+        internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.s1 = s1.ToUnmanaged();
-            _unmanaged.s2 = s2.IntoUnmanaged();
+            _unmanaged.pair = pair.ToUnmanaged();
+            _unmanaged.triple = triple.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -3709,86 +4414,79 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.s1 = s1.ToUnmanaged();
-            _unmanaged.s2 = s2.AsUnmanaged();
+            _unmanaged.pair = pair.ToUnmanaged();
+            _unmanaged.triple = triple.ToUnmanaged();
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public SliceUtf8String.Unmanaged s1;
-            public VecUtf8String.Unmanaged s2;
+            public Tuple2_U32_F32.Unmanaged pair;
+            public Tuple3_Vec3f32_U8_I64.Unmanaged triple;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe UseSliceAndVec IntoManaged()
+            internal unsafe Tuples ToManaged()
             {
-                var _managed = new UseSliceAndVec();
-                _managed.s1 = s1.ToManaged();
-                _managed.s2 = s2.IntoManaged();
+                var _managed = new Tuples();
+                _managed.pair = pair.ToManaged();
+                _managed.triple = triple.ToManaged();
                 return _managed;
             }
         }
 
-        public void Dispose()
-        {
-            s1.Dispose();
-            s2.Dispose();
-        }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "UseSliceAndVec { ... }";
+            return "Tuples { ... }";
         }
 
-        [CustomMarshaller(typeof(UseSliceAndVec), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(Tuples), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
         public ref struct Marshaller
         {
-            private UseSliceAndVec _managed;
+            private Tuples _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(UseSliceAndVec managed) { _managed = managed; }
+            public Marshaller(Tuples managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(UseSliceAndVec managed) { _managed = managed; }
+            public void FromManaged(Tuples managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public UseSliceAndVec ToManaged() { return _unmanaged.IntoManaged(); }
+            public Tuples ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public partial class UseString
+    public partial struct UseCStrPtr
     {
-        public Utf8String s1;
-        public Utf8String s2;
+        public string ascii_string;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class UseString : IDisposable
+    public partial struct UseCStrPtr 
     {
-        public UseString() { }
+        public UseCStrPtr() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal unsafe Unmanaged IntoUnmanaged()
+        internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.s1 = s1.IntoUnmanaged();
-            _unmanaged.s2 = s2.IntoUnmanaged();
+            _unmanaged.ascii_string = IntPtr.Zero;
             return _unmanaged;
         }
 
@@ -3796,22 +4494,270 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.s1 = s1.AsUnmanaged();
-            _unmanaged.s2 = s2.AsUnmanaged();
+            _unmanaged.ascii_string = IntPtr.Zero;
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public Utf8String.Unmanaged s1;
-            public Utf8String.Unmanaged s2;
+            public IntPtr ascii_string;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe UseString IntoManaged()
+            internal unsafe UseCStrPtr ToManaged()
             {
-                var _managed = new UseString();
-                _managed.s1 = s1.IntoManaged();
+                var _managed = new UseCStrPtr();
+                _managed.ascii_string = string.Empty;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "UseCStrPtr { ... }";
+        }
+
+        [CustomMarshaller(typeof(UseCStrPtr), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private UseCStrPtr _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(UseCStrPtr managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(UseCStrPtr managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public UseCStrPtr ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial class UseSliceAndVec
+    {
+        public SliceUtf8String s1;
+        public VecUtf8String s2;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class UseSliceAndVec : IDisposable
+    {
+        public UseSliceAndVec() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged IntoUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.ToUnmanaged();
+            _unmanaged.s2 = s2.IntoUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.ToUnmanaged();
+            _unmanaged.s2 = s2.AsUnmanaged();
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public SliceUtf8String.Unmanaged s1;
+            public VecUtf8String.Unmanaged s2;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe UseSliceAndVec IntoManaged()
+            {
+                var _managed = new UseSliceAndVec();
+                _managed.s1 = s1.ToManaged();
+                _managed.s2 = s2.IntoManaged();
+                return _managed;
+            }
+        }
+
+        public void Dispose()
+        {
+            s1.Dispose();
+            s2.Dispose();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "UseSliceAndVec { ... }";
+        }
+
+        [CustomMarshaller(typeof(UseSliceAndVec), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private UseSliceAndVec _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(UseSliceAndVec managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(UseSliceAndVec managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public UseSliceAndVec ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct UseStr
+    {
+        public Utf8Str s1;
+        public Utf8Str s2;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct UseStr 
+    {
+        public UseStr() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.ToUnmanaged();
+            _unmanaged.s2 = s2.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.ToUnmanaged();
+            _unmanaged.s2 = s2.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public Utf8Str.Unmanaged s1;
+            public Utf8Str.Unmanaged s2;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe UseStr ToManaged()
+            {
+                var _managed = new UseStr();
+                _managed.s1 = s1.ToManaged();
+                _managed.s2 = s2.ToManaged();
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "UseStr { ... }";
+        }
+
+        [CustomMarshaller(typeof(UseStr), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private UseStr _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(UseStr managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(UseStr managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public UseStr ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial class UseString
+    {
+        public Utf8String s1;
+        public Utf8String s2;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class UseString : IDisposable
+    {
+        public UseString() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged IntoUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.IntoUnmanaged();
+            _unmanaged.s2 = s2.IntoUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.s1 = s1.AsUnmanaged();
+            _unmanaged.s2 = s2.AsUnmanaged();
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public Utf8String.Unmanaged s1;
+            public Utf8String.Unmanaged s2;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe UseString IntoManaged()
+            {
+                var _managed = new UseString();
+                _managed.s1 = s1.IntoManaged();
                 _managed.s2 = s2.IntoManaged();
                 return _managed;
             }
@@ -4456,26 +5402,27 @@ namespace My.Company
         }
     }
 
-    ///FFI buffer for Wire data transfer
-    public partial struct WireBuffer
+    public partial struct WideNum
     {
-        public IntPtr data;
-        public int len;
-        public int capacity;
+        public UInt128 id;
+        public Int128 delta;
+        public Rune initial;
+        public uint handle;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct WireBuffer 
+    public partial struct WideNum 
     {
-        public WireBuffer() { }
+        public WideNum() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         internal unsafe Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.data = data;
-            _unmanaged.len = len;
-            _unmanaged.capacity = capacity;
+            _unmanaged.id = id;
+            _unmanaged.delta = delta;
+            _unmanaged.initial = initial;
+            _unmanaged.handle = handle;
             return _unmanaged;
         }
 
@@ -4483,26 +5430,29 @@ namespace My.Company
         internal unsafe Unmanaged AsUnmanaged()
         {
             var _unmanaged = new Unmanaged();
-            _unmanaged.data = data;
-            _unmanaged.len = len;
-            _unmanaged.capacity = capacity;
+            _unmanaged.id = id;
+            _unmanaged.delta = delta;
+            _unmanaged.initial = initial;
+            _unmanaged.handle = handle;
             return _unmanaged;
         }
 
         [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            public IntPtr data;
-            public int len;
-            public int capacity;
+            public UInt128 id;
+            public Int128 delta;
+            public Rune initial;
+            public uint handle;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal unsafe WireBuffer ToManaged()
+            internal unsafe WideNum ToManaged()
             {
-                var _managed = new WireBuffer();
-                _managed.data = data;
-                _managed.len = len;
-                _managed.capacity = capacity;
+                var _managed = new WideNum();
+                _managed.id = id;
+                _managed.delta = delta;
+                _managed.initial = initial;
+                _managed.handle = handle;
                 return _managed;
             }
         }
@@ -4511,7 +5461,95 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "WireBuffer { ... }";
+            return "WideNum { ... }";
+        }
+
+        [CustomMarshaller(typeof(WideNum), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private WideNum _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(WideNum managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(WideNum managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public WideNum ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    ///FFI buffer for Wire data transfer
+    public partial struct WireBuffer
+    {
+        public IntPtr data;
+        public int len;
+        public int capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct WireBuffer 
+    {
+        public WireBuffer() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.data = data;
+            _unmanaged.len = len;
+            _unmanaged.capacity = capacity;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.data = data;
+            _unmanaged.len = len;
+            _unmanaged.capacity = capacity;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public IntPtr data;
+            public int len;
+            public int capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe WireBuffer ToManaged()
+            {
+                var _managed = new WireBuffer();
+                _managed.data = data;
+                _managed.len = len;
+                _managed.capacity = capacity;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "WireBuffer { ... }";
         }
 
         [CustomMarshaller(typeof(WireBuffer), MarshalMode.Default, typeof(Marshaller))]
@@ -4781,10 +5819,19 @@ namespace My.Company
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate byte InteropDelegate_fn_u8_rval_u8(byte x0);
 
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint InteropDelegate_fn_u32_u32_u32_u32_u32_u32_u32_u32_rval_u32(uint x0, uint x1, uint x2, uint x3, uint x4, uint x5, uint x6, uint x7);
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate IntPtr InteropDelegate_fn_u64_u64_rval_MutPtrVoid(ulong x0, ulong x1);
+
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void InteropDelegate_fn_CharArray_rval_void(CharArray x0);
     public delegate void InteropDelegate_fn_CharArray_rval_void_native(CharArray.Unmanaged x0);
 
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void InteropDelegate_fn_MutPtrVoid_u64_u64_rval_void(IntPtr x0, ulong x1, ulong x2);
+
     public partial class SliceUseCStrPtr
     {
         GCHandle _handle;
@@ -5289,30 +6336,26 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct OptionEnumPayload 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal EnumPayload.Unmanaged _Some;
         }
 
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal OptionEnumPayload ToManaged()
             {
                 var _managed = new OptionEnumPayload();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -5322,7 +6365,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5331,7 +6374,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5341,23 +6384,15 @@ namespace My.Company
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public EnumPayload AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public EnumPayload AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return $"Some({AsSome().ToString()})";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<EnumPayload>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(OptionEnumPayload), MarshalMode.Default, typeof(Marshaller))]
@@ -5391,6 +6426,45 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct OptionEnumPayload
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionEnumPayload(EnumPayload? value) => value is { } x ? Some(x) : None;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator EnumPayload?(OptionEnumPayload value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionEnumPayload FromNullable(EnumPayload? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public EnumPayload? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public EnumPayload GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public EnumPayload GetValueOrDefault(EnumPayload defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out EnumPayload value)
+        {
+            value = GetValueOrDefault();
+            return IsSome;
+        }
+
+        public TResult Match<TResult>(Func<EnumPayload, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<EnumPayload> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
+        }
+
+        public OptionEnumPayload Map(Func<EnumPayload, EnumPayload> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
     ///Option that contains Some(value) or None.
     public partial struct OptionInner
     {
@@ -5401,30 +6475,26 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct OptionInner 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedSome
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Inner.Unmanaged _Some;
         }
 
 
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedSome _Some;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal OptionInner ToManaged()
             {
                 var _managed = new OptionInner();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Some = _Some._Some.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -5434,7 +6504,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5443,7 +6513,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Some._Some = _Some.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5453,23 +6523,15 @@ namespace My.Company
         public bool IsSome => _variant == 0;
         public bool IsNone => _variant == 1;
 
-        public Inner AsSome() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Some; } }
-        public void AsNone() { if (_variant != 1) throw ExceptionForVariant(); }
+        public Inner AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
             if (_variant == 0) return "Some(...)";
             if (_variant == 1) return "None";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<Inner>(_Some);
-            if (_variant == 1) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(OptionInner), MarshalMode.Default, typeof(Marshaller))]
@@ -5503,53 +6565,75 @@ namespace My.Company
         }
     }
 
-    ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceAsyncBasicError
+    #nullable enable
+    public partial struct OptionInner
     {
-        uint _variant;
-        IntPtr _Ok;
-        Error _Err;
-    }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionInner(Inner? value) => value is { } x ? Some(x) : None;
 
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceAsyncBasicError 
-    {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Inner?(OptionInner value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionInner FromNullable(Inner? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Inner? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Inner GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Inner GetValueOrDefault(Inner defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Inner value)
         {
-            internal uint _variant;
-            internal IntPtr _Ok;
+            value = GetValueOrDefault();
+            return IsSome;
         }
 
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        public TResult Match<TResult>(Func<Inner, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Inner> some, Action none)
         {
-            internal uint _variant;
-            internal Error.Unmanaged _Err;
+            if (IsSome) { some(_Some); } else { none(); }
         }
 
+        public OptionInner Map(Func<Inner, Inner> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
 
+    ///Option that contains Some(value) or None.
+    public partial struct OptionVec3f32
+    {
+        uint _variant;
+        Vec3f32 _Some;
+    }
 
-
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionVec3f32 
+    {
         [StructLayout(LayoutKind.Explicit)]
-        public unsafe struct Unmanaged
+        internal unsafe struct UnmanagedPayload
         {
             [FieldOffset(0)]
-            internal uint _variant;
+            internal Vec3f32.Unmanaged _Some;
+        }
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
 
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceAsyncBasicError ToManaged()
+            internal OptionVec3f32 ToManaged()
             {
-                var _managed = new ResultConstPtrServiceAsyncBasicError();
+                var _managed = new OptionVec3f32();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Some = _payload._Some.ToManaged();
                 return _managed;
             }
         }
@@ -5559,8 +6643,7 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5569,62 +6652,43 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Some = _Some.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceAsyncBasicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceAsyncBasicError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceAsyncBasicError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceAsyncBasicError Null => new() { _variant = 3 };
+        public static OptionVec3f32 Some(Vec3f32 value) => new() { _variant = 0, _Some = value };
+        public static OptionVec3f32 None => new() { _variant = 1 };
 
-        public bool IsOk => _variant == 0;
-        public bool IsErr => _variant == 1;
-        public bool IsPanic => _variant == 2;
-        public bool IsNull => _variant == 3;
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        public Vec3f32 AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            if (_variant == 0) return "Ok(...)";
-            if (_variant == 1) return $"Err({AsErr().ToString()})";
-            if (_variant == 2) return "Panic";
-            if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncBasicError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(OptionVec3f32), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceAsyncBasicError _managed;
+            private OptionVec3f32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
+            public Marshaller(OptionVec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
+            public void FromManaged(OptionVec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -5633,15 +6697,54 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceAsyncBasicError ToManaged() { return _unmanaged.ToManaged(); }
+            public OptionVec3f32 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct OptionVec3f32
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator OptionVec3f32(Vec3f32? value) => value is { } x ? Some(x) : None;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Vec3f32?(OptionVec3f32 value) => value.IsSome ? value._Some : null;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static OptionVec3f32 FromNullable(Vec3f32? value) => value;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec3f32? ToNullable() => this;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec3f32 GetValueOrDefault() => IsSome ? _Some : default!;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Vec3f32 GetValueOrDefault(Vec3f32 defaultValue) => IsSome ? _Some : defaultValue;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGetValue(out Vec3f32 value)
+        {
+            value = GetValueOrDefault();
+            return IsSome;
+        }
+
+        public TResult Match<TResult>(Func<Vec3f32, TResult> some, Func<TResult> none) => IsSome ? some(_Some) : none();
+
+        public void Match(Action<Vec3f32> some, Action none)
+        {
+            if (IsSome) { some(_Some); } else { none(); }
+        }
+
+        public OptionVec3f32 Map(Func<Vec3f32, Vec3f32> f) => IsSome ? Some(f(_Some)) : None;
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceAsyncResultError
+    public partial struct ResultConstPtrServiceAsyncBasicError
     {
         uint _variant;
         IntPtr _Ok;
@@ -5649,44 +6752,176 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceAsyncResultError 
+    public partial struct ResultConstPtrServiceAsyncBasicError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
+            internal UnmanagedPayload _payload;
 
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultConstPtrServiceAsyncBasicError ToManaged()
+            {
+                var _managed = new ResultConstPtrServiceAsyncBasicError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultConstPtrServiceAsyncBasicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceAsyncBasicError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceAsyncBasicError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceAsyncBasicError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultConstPtrServiceAsyncBasicError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultConstPtrServiceAsyncBasicError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultConstPtrServiceAsyncBasicError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultConstPtrServiceAsyncBasicError ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncBasicError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultConstPtrServiceAsyncResultError
+    {
+        uint _variant;
+        IntPtr _Ok;
+        Error _Err;
+    }
 
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultConstPtrServiceAsyncResultError 
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
             [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal Error.Unmanaged _Err;
+        }
+
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrServiceAsyncResultError ToManaged()
             {
                 var _managed = new ResultConstPtrServiceAsyncResultError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -5696,8 +6931,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5706,8 +6941,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5721,10 +6956,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -5733,17 +6971,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrServiceAsyncResultError), MarshalMode.Default, typeof(Marshaller))]
@@ -5777,6 +7005,34 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncResultError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
     public partial struct ResultConstPtrServiceAsyncSleepError
     {
@@ -5788,42 +7044,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultConstPtrServiceAsyncSleepError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrServiceAsyncSleepError ToManaged()
             {
                 var _managed = new ResultConstPtrServiceAsyncSleepError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -5833,8 +7076,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5843,8 +7086,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5858,10 +7101,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -5870,17 +7116,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrServiceAsyncSleepError), MarshalMode.Default, typeof(Marshaller))]
@@ -5914,6 +7150,34 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncSleepError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
     public partial struct ResultConstPtrServiceAsyncStructsError
     {
@@ -5925,42 +7189,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultConstPtrServiceAsyncStructsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrServiceAsyncStructsError ToManaged()
             {
                 var _managed = new ResultConstPtrServiceAsyncStructsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -5970,8 +7221,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5980,8 +7231,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -5995,10 +7246,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6007,17 +7261,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrServiceAsyncStructsError), MarshalMode.Default, typeof(Marshaller))]
@@ -6051,6 +7295,34 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncStructsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
     public partial struct ResultConstPtrServiceAsyncVecStringError
     {
@@ -6062,42 +7334,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultConstPtrServiceAsyncVecStringError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrServiceAsyncVecStringError ToManaged()
             {
                 var _managed = new ResultConstPtrServiceAsyncVecStringError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -6107,8 +7366,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6117,8 +7376,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6132,10 +7391,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6144,17 +7406,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrServiceAsyncVecStringError), MarshalMode.Default, typeof(Marshaller))]
@@ -6188,6 +7440,34 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceAsyncVecStringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
     public partial struct ResultConstPtrServiceBasicError
     {
@@ -6199,42 +7479,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultConstPtrServiceBasicError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrServiceBasicError ToManaged()
             {
                 var _managed = new ResultConstPtrServiceBasicError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -6244,8 +7511,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6254,8 +7521,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6269,10 +7536,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6281,17 +7551,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrServiceBasicError), MarshalMode.Default, typeof(Marshaller))]
@@ -6325,6 +7585,34 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceBasicError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
     public partial struct ResultConstPtrServiceCallbacksError
     {
@@ -6336,42 +7624,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultConstPtrServiceCallbacksError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrServiceCallbacksError ToManaged()
             {
                 var _managed = new ResultConstPtrServiceCallbacksError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -6381,8 +7656,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6391,8 +7666,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6406,10 +7681,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6418,17 +7696,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrServiceCallbacksError), MarshalMode.Default, typeof(Marshaller))]
@@ -6462,6 +7730,34 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceCallbacksError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
     public partial struct ResultConstPtrServiceDependentError
     {
@@ -6473,42 +7769,29 @@ namespace My.Company
     [NativeMarshalling(typeof(MarshallerMeta))]
     public partial struct ResultConstPtrServiceDependentError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             internal ResultConstPtrServiceDependentError ToManaged()
             {
                 var _managed = new ResultConstPtrServiceDependentError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -6518,8 +7801,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6528,8 +7811,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6543,10 +7826,13 @@ namespace My.Company
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6555,17 +7841,7 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
         [CustomMarshaller(typeof(ResultConstPtrServiceDependentError), MarshalMode.Default, typeof(Marshaller))]
@@ -6599,8 +7875,36 @@ namespace My.Company
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceDependentError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceIgnoringMethodsError
+    public partial struct ResultConstPtrServiceForeignLoggerError
     {
         uint _variant;
         IntPtr _Ok;
@@ -6608,44 +7912,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceIgnoringMethodsError 
+    public partial struct ResultConstPtrServiceForeignLoggerError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceIgnoringMethodsError ToManaged()
+            internal ResultConstPtrServiceForeignLoggerError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceIgnoringMethodsError();
+                var _managed = new ResultConstPtrServiceForeignLoggerError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -6655,8 +7946,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6665,25 +7956,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceIgnoringMethodsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceIgnoringMethodsError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceIgnoringMethodsError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceIgnoringMethodsError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceForeignLoggerError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceForeignLoggerError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceForeignLoggerError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceForeignLoggerError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6692,35 +7986,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceIgnoringMethodsError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceForeignLoggerError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceIgnoringMethodsError _managed;
+            private ResultConstPtrServiceForeignLoggerError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceForeignLoggerError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceForeignLoggerError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -6729,15 +8013,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceIgnoringMethodsError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceForeignLoggerError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceForeignLoggerError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceMainError
+    public partial struct ResultConstPtrServiceIgnoringMethodsError
     {
         uint _variant;
         IntPtr _Ok;
@@ -6745,44 +8057,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceMainError 
+    public partial struct ResultConstPtrServiceIgnoringMethodsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceMainError ToManaged()
+            internal ResultConstPtrServiceIgnoringMethodsError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceMainError();
+                var _managed = new ResultConstPtrServiceIgnoringMethodsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -6792,8 +8091,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6802,25 +8101,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceMainError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceMainError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceMainError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceMainError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceIgnoringMethodsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceIgnoringMethodsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceIgnoringMethodsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceIgnoringMethodsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6829,35 +8131,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultConstPtrServiceMainError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceIgnoringMethodsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceMainError _managed;
+            private ResultConstPtrServiceIgnoringMethodsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceMainError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceMainError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceIgnoringMethodsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -6866,15 +8158,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceMainError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceIgnoringMethodsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceIgnoringMethodsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceMultipleCtorsError
+    public partial struct ResultConstPtrServiceMainError
     {
         uint _variant;
         IntPtr _Ok;
@@ -6882,44 +8202,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceMultipleCtorsError 
+    public partial struct ResultConstPtrServiceMainError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceMultipleCtorsError ToManaged()
+            internal ResultConstPtrServiceMainError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceMultipleCtorsError();
+                var _managed = new ResultConstPtrServiceMainError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -6929,8 +8236,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -6939,25 +8246,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceMultipleCtorsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceMultipleCtorsError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceMultipleCtorsError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceMultipleCtorsError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceMainError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceMainError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceMainError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceMainError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -6966,35 +8276,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceMultipleCtorsError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceMainError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceMultipleCtorsError _managed;
+            private ResultConstPtrServiceMainError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceMainError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceMainError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7003,15 +8303,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceMultipleCtorsError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceMainError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceMainError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceOnPanicError
+    public partial struct ResultConstPtrServiceMultipleCtorsError
     {
         uint _variant;
         IntPtr _Ok;
@@ -7019,44 +8347,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceOnPanicError 
+    public partial struct ResultConstPtrServiceMultipleCtorsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceOnPanicError ToManaged()
+            internal ResultConstPtrServiceMultipleCtorsError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceOnPanicError();
+                var _managed = new ResultConstPtrServiceMultipleCtorsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -7066,8 +8381,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7076,25 +8391,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceOnPanicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceOnPanicError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceOnPanicError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceOnPanicError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceMultipleCtorsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceMultipleCtorsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceMultipleCtorsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceMultipleCtorsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -7103,35 +8421,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceOnPanicError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceMultipleCtorsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceOnPanicError _managed;
+            private ResultConstPtrServiceMultipleCtorsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceMultipleCtorsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7140,15 +8448,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceOnPanicError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceMultipleCtorsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceMultipleCtorsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceResultError
+    public partial struct ResultConstPtrServiceMutexError
     {
         uint _variant;
         IntPtr _Ok;
@@ -7156,44 +8492,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceResultError 
+    public partial struct ResultConstPtrServiceMutexError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceResultError ToManaged()
+            internal ResultConstPtrServiceMutexError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceResultError();
+                var _managed = new ResultConstPtrServiceMutexError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -7203,8 +8526,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7213,25 +8536,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceResultError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceResultError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceResultError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceResultError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceMutexError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceMutexError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceMutexError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceMutexError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -7240,35 +8566,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceResultError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceMutexError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceResultError _managed;
+            private ResultConstPtrServiceMutexError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceResultError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceMutexError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceResultError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceMutexError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7277,15 +8593,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceResultError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceMutexError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceMutexError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceStringsError
+    public partial struct ResultConstPtrServiceOnPanicError
     {
         uint _variant;
         IntPtr _Ok;
@@ -7293,44 +8637,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceStringsError 
+    public partial struct ResultConstPtrServiceOnPanicError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceStringsError ToManaged()
+            internal ResultConstPtrServiceOnPanicError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceStringsError();
+                var _managed = new ResultConstPtrServiceOnPanicError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -7340,8 +8671,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7350,25 +8681,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceStringsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceStringsError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceStringsError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceStringsError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceOnPanicError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceOnPanicError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceOnPanicError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceOnPanicError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -7377,35 +8711,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceStringsError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceOnPanicError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceStringsError _managed;
+            private ResultConstPtrServiceOnPanicError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceStringsError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceStringsError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceOnPanicError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7414,15 +8738,43 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceStringsError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceOnPanicError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceOnPanicError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultConstPtrServiceVariousSlicesError
+    public partial struct ResultConstPtrServicePropertiesError
     {
         uint _variant;
         IntPtr _Ok;
@@ -7430,44 +8782,31 @@ namespace My.Company
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultConstPtrServiceVariousSlicesError 
+    public partial struct ResultConstPtrServicePropertiesError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal IntPtr _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
-        {
-            internal uint _variant;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultConstPtrServiceVariousSlicesError ToManaged()
+            internal ResultConstPtrServicePropertiesError ToManaged()
             {
-                var _managed = new ResultConstPtrServiceVariousSlicesError();
+                var _managed = new ResultConstPtrServicePropertiesError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok;
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -7477,8 +8816,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7487,25 +8826,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultConstPtrServiceVariousSlicesError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
-        public static ResultConstPtrServiceVariousSlicesError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultConstPtrServiceVariousSlicesError Panic => new() { _variant = 2 };
-        public static ResultConstPtrServiceVariousSlicesError Null => new() { _variant = 3 };
+        public static ResultConstPtrServicePropertiesError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServicePropertiesError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServicePropertiesError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServicePropertiesError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public IntPtr AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -7514,35 +8856,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<IntPtr>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultConstPtrServiceVariousSlicesError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServicePropertiesError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultConstPtrServiceVariousSlicesError _managed;
+            private ResultConstPtrServicePropertiesError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServicePropertiesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServicePropertiesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7551,60 +8883,75 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultConstPtrServiceVariousSlicesError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServicePropertiesError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServicePropertiesError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultNestedArrayError
+    public partial struct ResultConstPtrServiceResultError
     {
         uint _variant;
-        NestedArray _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultNestedArrayError 
+    public partial struct ResultConstPtrServiceResultError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal NestedArray.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultNestedArrayError ToManaged()
+            internal ResultConstPtrServiceResultError ToManaged()
             {
-                var _managed = new ResultNestedArrayError();
+                var _managed = new ResultConstPtrServiceResultError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.ToManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -7614,8 +8961,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7624,25 +8971,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultNestedArrayError Ok(NestedArray value) => new() { _variant = 0, _Ok = value };
-        public static ResultNestedArrayError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultNestedArrayError Panic => new() { _variant = 2 };
-        public static ResultNestedArrayError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceResultError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceResultError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceResultError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceResultError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public NestedArray AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -7651,35 +9001,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<NestedArray>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultNestedArrayError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceResultError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultNestedArrayError _managed;
+            private ResultConstPtrServiceResultError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultNestedArrayError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultNestedArrayError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceResultError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7688,60 +9028,75 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultNestedArrayError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceResultError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceResultError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial struct ResultOptionEnumPayloadError
+    public partial struct ResultConstPtrServiceStaticsError
     {
         uint _variant;
-        OptionEnumPayload _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial struct ResultOptionEnumPayloadError 
+    public partial struct ResultConstPtrServiceStaticsError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal OptionEnumPayload.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultOptionEnumPayloadError ToManaged()
+            internal ResultConstPtrServiceStaticsError ToManaged()
             {
-                var _managed = new ResultOptionEnumPayloadError();
+                var _managed = new ResultConstPtrServiceStaticsError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.ToManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
@@ -7751,8 +9106,8 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7761,25 +9116,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultOptionEnumPayloadError Ok(OptionEnumPayload value) => new() { _variant = 0, _Ok = value };
-        public static ResultOptionEnumPayloadError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultOptionEnumPayloadError Panic => new() { _variant = 2 };
-        public static ResultOptionEnumPayloadError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceStaticsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStaticsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStaticsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStaticsError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public OptionEnumPayload AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -7788,35 +9146,25 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<OptionEnumPayload>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [CustomMarshaller(typeof(ResultOptionEnumPayloadError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceStaticsError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultOptionEnumPayloadError _managed;
+            private ResultConstPtrServiceStaticsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultOptionEnumPayloadError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStaticsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultOptionEnumPayloadError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStaticsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -7825,75 +9173,86 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultOptionEnumPayloadError ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceStaticsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial struct ResultConstPtrServiceStaticsError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     ///Result that contains value or an error.
-    public partial class ResultUseStringError
+    public partial struct ResultConstPtrServiceStorageArrayError
     {
         uint _variant;
-        UseString _Ok;
+        IntPtr _Ok;
         Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class ResultUseStringError : IDisposable
+    public partial struct ResultConstPtrServiceStorageArrayError 
     {
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedOk
-        {
-            internal uint _variant;
-            internal UseString.Unmanaged _Ok;
-        }
-
-        [StructLayout(LayoutKind.Sequential)]
-        internal unsafe struct UnmanagedErr
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            internal uint _variant;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
             internal Error.Unmanaged _Err;
         }
 
 
-
-        public void Dispose()
-        {
-            if (_variant == 0) { _Ok.Dispose(); }
-        }
-
-        [StructLayout(LayoutKind.Explicit)]
+        [StructLayout(LayoutKind.Sequential)]
         public unsafe struct Unmanaged
         {
-            [FieldOffset(0)]
             internal uint _variant;
-
-            [FieldOffset(0)]
-            internal UnmanagedOk _Ok;
-
-            [FieldOffset(0)]
-            internal UnmanagedErr _Err;
+            internal UnmanagedPayload _payload;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            internal ResultUseStringError IntoManaged()
+            internal ResultConstPtrServiceStorageArrayError ToManaged()
             {
-                var _managed = new ResultUseStringError();
+                var _managed = new ResultConstPtrServiceStorageArrayError();
                 _managed._variant = _variant;
-                if (_variant == 0) _managed._Ok = _Ok._Ok.IntoManaged();
-                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
                 return _managed;
             }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged IntoUnmanaged()
+        internal Unmanaged ToUnmanaged()
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.IntoUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
@@ -7902,25 +9261,28 @@ namespace My.Company
         {
             var _unmanaged = new Unmanaged();
             _unmanaged._variant = _variant;
-            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.AsUnmanaged();
-            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
             return _unmanaged;
         }
 
-        public static ResultUseStringError Ok(UseString value) => new() { _variant = 0, _Ok = value };
-        public static ResultUseStringError Err(Error value) => new() { _variant = 1, _Err = value };
-        public static ResultUseStringError Panic => new() { _variant = 2 };
-        public static ResultUseStringError Null => new() { _variant = 3 };
+        public static ResultConstPtrServiceStorageArrayError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStorageArrayError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStorageArrayError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStorageArrayError Null => new() { _variant = 3 };
 
         public bool IsOk => _variant == 0;
         public bool IsErr => _variant == 1;
         public bool IsPanic => _variant == 2;
         public bool IsNull => _variant == 3;
 
-        public UseString AsOk() { if (_variant != 0) { throw ExceptionForVariant(); } else { return _Ok; } }
-        public Error AsErr() { if (_variant != 1) { throw ExceptionForVariant(); } else { return _Err; } }
-        public void AsPanic() { if (_variant != 2) throw ExceptionForVariant(); }
-        public void AsNull() { if (_variant != 3) throw ExceptionForVariant(); }
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
@@ -7929,150 +9291,170 @@ namespace My.Company
             if (_variant == 1) return $"Err({AsErr().ToString()})";
             if (_variant == 2) return "Panic";
             if (_variant == 3) return "Null";
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
+            throw new InteropException();
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Exception ExceptionForVariant()
-        {
-            if (_variant == 0) return new EnumException<UseString>(_Ok);
-            if (_variant == 1) return new EnumException<Error>(_Err);
-            if (_variant == 2) return new EnumException();
-            if (_variant == 3) return new EnumException();
-            throw new InteropException("Illegal enum state detected. This is a severe error and should never happen.");
-        }
-
-        [CustomMarshaller(typeof(ResultUseStringError), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(ResultConstPtrServiceStorageArrayError), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         public ref struct Marshaller
         {
-            private ResultUseStringError _managed;
+            private ResultConstPtrServiceStorageArrayError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(ResultUseStringError managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStorageArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(ResultUseStringError managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStorageArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public ResultUseStringError ToManaged() { return _unmanaged.IntoManaged(); }
+            public ResultConstPtrServiceStorageArrayError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void CallbackCharArray2Native(CharArray.Unmanaged value, IntPtr callback_data); // 'True' native callback signature
-    public delegate void CallbackCharArray2Delegate(CharArray value); // Our C# signature
+    #nullable enable
+    public partial struct ResultConstPtrServiceStorageArrayError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
 
-    public partial class CallbackCharArray2
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultConstPtrServiceStorageOffsetError
     {
-        private CallbackCharArray2Delegate _managed; // C# callback
-        private CallbackCharArray2Native _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        IntPtr _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackCharArray2 : IDisposable
+    public partial struct ResultConstPtrServiceStorageOffsetError 
     {
-
-        internal CallbackCharArray2() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackCharArray2(CallbackCharArray2Delegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(CharArray.Unmanaged value, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(value.ToManaged());
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultConstPtrServiceStorageOffsetError ToManaged()
             {
-                _exception = e;
-                return;
+                var _managed = new ResultConstPtrServiceStorageOffsetError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(CharArray value)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackCharArray2Native>(_ptr);
-            __target(value.ToUnmanaged(), IntPtr.Zero);
-            return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultConstPtrServiceStorageOffsetError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStorageOffsetError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStorageOffsetError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStorageOffsetError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(CallbackCharArray2), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public CallbackCharArray2 ToManaged()
-            {
-                var rval = new CallbackCharArray2();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultConstPtrServiceStorageOffsetError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackCharArray2 _managed;
+            private ResultConstPtrServiceStorageOffsetError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackCharArray2 managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStorageOffsetError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackCharArray2 managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStorageOffsetError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8081,112 +9463,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackCharArray2 ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceStorageOffsetError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate byte CallbackFFISliceNative(SliceU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
-    public delegate byte CallbackFFISliceDelegate(SliceU8 slice); // Our C# signature
+    #nullable enable
+    public partial struct ResultConstPtrServiceStorageOffsetError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
 
-    public partial class CallbackFFISlice
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultConstPtrServiceStringsError
     {
-        private CallbackFFISliceDelegate _managed; // C# callback
-        private CallbackFFISliceNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        IntPtr _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackFFISlice : IDisposable
+    public partial struct ResultConstPtrServiceStringsError 
     {
-
-        internal CallbackFFISlice() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackFFISlice(CallbackFFISliceDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private byte CallTrampoline(SliceU8.Unmanaged slice, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(slice.ToManaged());
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultConstPtrServiceStringsError ToManaged()
             {
-                _exception = e;
-                return default;
+                var _managed = new ResultConstPtrServiceStringsError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte Call(SliceU8 slice)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackFFISliceNative>(_ptr);
-            return __target(slice.ToUnmanaged(), IntPtr.Zero);
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultConstPtrServiceStringsError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceStringsError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceStringsError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceStringsError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(CallbackFFISlice), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public CallbackFFISlice ToManaged()
-            {
-                var rval = new CallbackFFISlice();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultConstPtrServiceStringsError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackFFISlice _managed;
+            private ResultConstPtrServiceStringsError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackFFISlice managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceStringsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackFFISlice managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceStringsError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8195,112 +9608,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackFFISlice ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceStringsError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate Vec3f32.Unmanaged CallbackHugeVecSliceNative(SliceVec3f32.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
-    public delegate Vec3f32 CallbackHugeVecSliceDelegate(SliceVec3f32 slice); // Our C# signature
-
-    public partial class CallbackHugeVecSlice
-    {
-        private CallbackHugeVecSliceDelegate _managed; // C# callback
-        private CallbackHugeVecSliceNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
-    }
-
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackHugeVecSlice : IDisposable
+    #nullable enable
+    public partial struct ResultConstPtrServiceStringsError
     {
-
-        internal CallbackHugeVecSlice() { }
-
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackHugeVecSlice(CallbackHugeVecSliceDelegate managed)
-        {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
-        }
+        public IntPtr Unwrap() => AsOk();
 
-        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private Vec3f32.Unmanaged CallTrampoline(SliceVec3f32.Unmanaged slice, IntPtr callback_data)
-        {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(slice.ToManaged()).ToUnmanaged();
-            }
-            catch (Exception e)
-            {
-                _exception = e;
-                return default;
-            }
-        }
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Vec3f32 Call(SliceVec3f32 slice)
+        public bool TryGet(out IntPtr value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackHugeVecSliceNative>(_ptr);
-            return __target(slice.ToUnmanaged(), IntPtr.Zero).ToManaged();
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
         }
+    }
+    #nullable restore
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+    ///Result that contains value or an error.
+    public partial struct ResultConstPtrServiceThreadAffineSyncError
+    {
+        uint _variant;
+        IntPtr _Ok;
+        SyncError _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultConstPtrServiceThreadAffineSyncError 
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
+            internal SyncError.Unmanaged _Err;
         }
 
-        [CustomMarshaller(typeof(CallbackHugeVecSlice), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
-            public CallbackHugeVecSlice ToManaged()
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultConstPtrServiceThreadAffineSyncError ToManaged()
             {
-                var rval = new CallbackHugeVecSlice();
-                rval._ptr = _callback;
-                return rval;
+                var _managed = new ResultConstPtrServiceThreadAffineSyncError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
-
         }
 
-        public ref struct Marshaller
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
         {
-            private CallbackHugeVecSlice _managed;
-            private Unmanaged _unmanaged;
-
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackHugeVecSlice managed) { _managed = managed; }
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultConstPtrServiceThreadAffineSyncError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceThreadAffineSyncError Err(SyncError value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceThreadAffineSyncError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceThreadAffineSyncError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? SyncErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public SyncError AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultConstPtrServiceThreadAffineSyncError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultConstPtrServiceThreadAffineSyncError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultConstPtrServiceThreadAffineSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackHugeVecSlice managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceThreadAffineSyncError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8309,113 +9753,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackHugeVecSlice ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceThreadAffineSyncError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void CallbackSliceMutNative(SliceMutU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
-    public delegate void CallbackSliceMutDelegate(SliceMutU8 slice); // Our C# signature
+    #nullable enable
+    public partial struct ResultConstPtrServiceThreadAffineSyncError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr Unwrap() => AsOk();
 
-    public partial class CallbackSliceMut
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out IntPtr value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<SyncError, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out SyncError? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultConstPtrServiceVariousSlicesError
     {
-        private CallbackSliceMutDelegate _managed; // C# callback
-        private CallbackSliceMutNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        IntPtr _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackSliceMut : IDisposable
+    public partial struct ResultConstPtrServiceVariousSlicesError 
     {
-
-        internal CallbackSliceMut() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackSliceMut(CallbackSliceMutDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal IntPtr _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(SliceMutU8.Unmanaged slice, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(slice.ToManaged());
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultConstPtrServiceVariousSlicesError ToManaged()
             {
-                _exception = e;
-                return;
+                var _managed = new ResultConstPtrServiceVariousSlicesError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok;
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(SliceMutU8 slice)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackSliceMutNative>(_ptr);
-            __target(slice.ToUnmanaged(), IntPtr.Zero);
-            return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok;
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultConstPtrServiceVariousSlicesError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceVariousSlicesError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceVariousSlicesError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceVariousSlicesError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(CallbackSliceMut), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public CallbackSliceMut ToManaged()
-            {
-                var rval = new CallbackSliceMut();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public IntPtr AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultConstPtrServiceVariousSlicesError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private CallbackSliceMut _managed;
+            private ResultConstPtrServiceVariousSlicesError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackSliceMut managed) { _managed = managed; }
+            public Marshaller(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackSliceMut managed) { _managed = managed; }
+            public void FromManaged(ResultConstPtrServiceVariousSlicesError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8424,112 +9898,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackSliceMut ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultConstPtrServiceVariousSlicesError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate byte CallbackU8Native(byte value, IntPtr callback_data); // 'True' native callback signature
-    public delegate byte CallbackU8Delegate(byte value); // Our C# signature
-
-    public partial class CallbackU8
-    {
-        private CallbackU8Delegate _managed; // C# callback
-        private CallbackU8Native _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
-    }
-
-    [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class CallbackU8 : IDisposable
+    #nullable enable
+    public partial struct ResultConstPtrServiceVariousSlicesError
     {
-
-        internal CallbackU8() { }
-
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public CallbackU8(CallbackU8Delegate managed)
-        {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
-        }
+        public IntPtr Unwrap() => AsOk();
 
-        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private byte CallTrampoline(byte value, IntPtr callback_data)
-        {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(value);
-            }
-            catch (Exception e)
-            {
-                _exception = e;
-                return default;
-            }
-        }
+        public IntPtr UnwrapOr(IntPtr fallback) => IsOk ? _Ok : fallback;
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public byte Call(byte value)
+        public bool TryGet(out IntPtr value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<CallbackU8Native>(_ptr);
-            return __target(value, IntPtr.Zero);
+            value = IsOk ? _Ok : default!;
+            return IsOk;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        public TResult Match<TResult>(Func<IntPtr, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out IntPtr? value, out Error? error)
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
         }
+    }
+    #nullable restore
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
+    ///Result that contains value or an error.
+    public partial struct ResultNestedArrayError
+    {
+        uint _variant;
+        NestedArray _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultNestedArrayError 
+    {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
+            [FieldOffset(0)]
+            internal NestedArray.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        [CustomMarshaller(typeof(CallbackU8), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
+        public unsafe struct Unmanaged
         {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
 
-            public CallbackU8 ToManaged()
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultNestedArrayError ToManaged()
             {
-                var rval = new CallbackU8();
-                rval._ptr = _callback;
-                return rval;
+                var _managed = new ResultNestedArrayError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.ToManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
-
         }
 
-        public ref struct Marshaller
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
         {
-            private CallbackU8 _managed;
-            private Unmanaged _unmanaged;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultNestedArrayError Ok(NestedArray value) => new() { _variant = 0, _Ok = value };
+        public static ResultNestedArrayError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultNestedArrayError Panic => new() { _variant = 2 };
+        public static ResultNestedArrayError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
+
+        public NestedArray AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultNestedArrayError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultNestedArrayError _managed;
+            private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(CallbackU8 managed) { _managed = managed; }
+            public Marshaller(ResultNestedArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(CallbackU8 managed) { _managed = managed; }
+            public void FromManaged(ResultNestedArrayError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8538,112 +10043,143 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public CallbackU8 ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultNestedArrayError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate uint MyCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
-    public delegate uint MyCallbackDelegate(uint value); // Our C# signature
+    #nullable enable
+    public partial struct ResultNestedArrayError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public NestedArray Unwrap() => AsOk();
 
-    public partial class MyCallback
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public NestedArray UnwrapOr(NestedArray fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out NestedArray value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<NestedArray, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out NestedArray? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial struct ResultOptionEnumPayloadError
     {
-        private MyCallbackDelegate _managed; // C# callback
-        private MyCallbackNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        OptionEnumPayload _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallback : IDisposable
+    public partial struct ResultOptionEnumPayloadError 
     {
-
-        internal MyCallback() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallback(MyCallbackDelegate managed)
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            [FieldOffset(0)]
+            internal OptionEnumPayload.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private uint CallTrampoline(uint value, IntPtr callback_data)
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                return _managed(value);
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultOptionEnumPayloadError ToManaged()
             {
-                _exception = e;
-                return default;
+                var _managed = new ResultOptionEnumPayloadError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.ToManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint Call(uint value)
+        internal Unmanaged ToUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNative>(_ptr);
-            return __target(value, IntPtr.Zero);
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultOptionEnumPayloadError Ok(OptionEnumPayload value) => new() { _variant = 0, _Ok = value };
+        public static ResultOptionEnumPayloadError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultOptionEnumPayloadError Panic => new() { _variant = 2 };
+        public static ResultOptionEnumPayloadError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(MyCallback), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public MyCallback ToManaged()
-            {
-                var rval = new MyCallback();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public OptionEnumPayload AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultOptionEnumPayloadError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private MyCallback _managed;
+            private ResultOptionEnumPayloadError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallback managed) { _managed = managed; }
+            public Marshaller(ResultOptionEnumPayloadError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallback managed) { _managed = managed; }
+            public void FromManaged(ResultOptionEnumPayloadError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8652,148 +10188,210 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallback ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultOptionEnumPayloadError ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void MyCallbackContextualNative(IntPtr context, uint value, IntPtr callback_data); // 'True' native callback signature
-    public delegate void MyCallbackContextualDelegate(IntPtr context, uint value); // Our C# signature
+    #nullable enable
+    public partial struct ResultOptionEnumPayloadError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionEnumPayload Unwrap() => AsOk();
 
-    public partial class MyCallbackContextual
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OptionEnumPayload UnwrapOr(OptionEnumPayload fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out OptionEnumPayload value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<OptionEnumPayload, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out OptionEnumPayload? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
+    ///Result that contains value or an error.
+    public partial class ResultUseStringError
     {
-        private MyCallbackContextualDelegate _managed; // C# callback
-        private MyCallbackContextualNative _native; // Native callback 
-        private IntPtr _ptr; // Raw function pointer of native callback
-        private Exception _exception; // Set if the callback encountered an Exception
+        uint _variant;
+        UseString _Ok;
+        Error _Err;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallbackContextual : IDisposable
+    public partial class ResultUseStringError : IDisposable
     {
+        [StructLayout(LayoutKind.Explicit)]
+        internal unsafe struct UnmanagedPayload
+        {
+            [FieldOffset(0)]
+            internal UseString.Unmanaged _Ok;
+            [FieldOffset(0)]
+            internal Error.Unmanaged _Err;
+        }
 
-        internal MyCallbackContextual() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallbackContextual(MyCallbackContextualDelegate managed)
+        public void Dispose()
         {
-            _managed = managed;
-            _native = CallTrampoline;
-            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+            if (_variant == 0) { _Ok.Dispose(); }
         }
 
-        // Helper to invoke managed code from the native invocation.
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(IntPtr context, uint value, IntPtr callback_data)
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
         {
-            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
-            try
-            {
-                _managed(context, value);
-            }
-            catch (Exception e)
+            internal uint _variant;
+            internal UnmanagedPayload _payload;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultUseStringError IntoManaged()
             {
-                _exception = e;
-                return;
+                var _managed = new ResultUseStringError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _payload._Ok.IntoManaged();
+                if (_variant == 1) _managed._Err = _payload._Err.ToManaged();
+                return _managed;
             }
         }
 
-        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(IntPtr context, uint value)
+        internal Unmanaged IntoUnmanaged()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackContextualNative>(_ptr);
-            __target(context, value, IntPtr.Zero);
-            return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.IntoUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Dispose()
+        internal Unmanaged AsUnmanaged()
         {
-            // This means when the callback was invoked from Rust C# had an exception which
-            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
-            // the time to rethrow it.
-            if (_exception != null) throw _exception;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._payload._Ok = _Ok.AsUnmanaged();
+            if (_variant == 1) _unmanaged._payload._Err = _Err.ToUnmanaged();
+            return _unmanaged;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal Unmanaged ToUnmanaged()
-        {
-            var rval = new Unmanaged();
-            rval._callback = _ptr;
-            rval._data = IntPtr.Zero;
-            return rval;
-        }
+        public static ResultUseStringError Ok(UseString value) => new() { _variant = 0, _Ok = value };
+        public static ResultUseStringError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultUseStringError Panic => new() { _variant = 2 };
+        public static ResultUseStringError Null => new() { _variant = 3 };
 
-        [CustomMarshaller(typeof(MyCallbackContextual), MarshalMode.Default, typeof(Marshaller))]
-        private struct MarshallerMeta {  }
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
 
-        [StructLayout(LayoutKind.Sequential)]
-        public struct Unmanaged
-        {
-            internal IntPtr _callback;
-            internal IntPtr _data;
+        /// Converts this result's error into an exception, including details about the last error in Rust.
+        public InteropException ToException() => _variant == 1 ? ErrorException.FromError(_Err) : InteropException.FromLastError(ToString());
 
-            public MyCallbackContextual ToManaged()
-            {
-                var rval = new MyCallbackContextual();
-                rval._ptr = _callback;
-                return rval;
-            }
+        public UseString AsOk() { if (_variant != 0) { throw ToException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
         }
 
+        [CustomMarshaller(typeof(ResultUseStringError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
         public ref struct Marshaller
         {
-            private MyCallbackContextual _managed;
+            private ResultUseStringError _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallbackContextual managed) { _managed = managed; }
+            public Marshaller(ResultUseStringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallbackContextual managed) { _managed = managed; }
+            public void FromManaged(ResultUseStringError managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallbackContextual ToManaged() { return _unmanaged.ToManaged(); }
+            public ResultUseStringError ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
+    #nullable enable
+    public partial class ResultUseStringError
+    {
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public UseString Unwrap() => AsOk();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public UseString UnwrapOr(UseString fallback) => IsOk ? _Ok : fallback;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public bool TryGet(out UseString value)
+        {
+            value = IsOk ? _Ok : default!;
+            return IsOk;
+        }
+
+        public TResult Match<TResult>(Func<UseString, TResult> ok, Func<Error, TResult> err) => IsOk ? ok(_Ok) : IsErr ? err(_Err) : throw ToException();
+
+        // Allows `var (value, error) = result;`, exactly one of both is set.
+        public void Deconstruct(out UseString? value, out Error? error)
+        {
+            if (!IsOk && !IsErr) throw ToException();
+            value = IsOk ? _Ok : null;
+            error = IsErr ? _Err : null;
+        }
+    }
+    #nullable restore
+
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void MyCallbackVoidNative(IntPtr ptr, IntPtr callback_data); // 'True' native callback signature
-    public delegate void MyCallbackVoidDelegate(IntPtr ptr); // Our C# signature
+    public delegate void CallbackCharArray2Native(CharArray.Unmanaged value, IntPtr callback_data); // 'True' native callback signature
+    public delegate void CallbackCharArray2Delegate(CharArray value); // Our C# signature
 
-    public partial class MyCallbackVoid
+    public partial class CallbackCharArray2
     {
-        private MyCallbackVoidDelegate _managed; // C# callback
-        private MyCallbackVoidNative _native; // Native callback 
+        private CallbackCharArray2Delegate _managed; // C# callback
+        private CallbackCharArray2Native _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class MyCallbackVoid : IDisposable
+    public partial class CallbackCharArray2 : IDisposable
     {
 
-        internal MyCallbackVoid() { }
+        internal CallbackCharArray2() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public MyCallbackVoid(MyCallbackVoidDelegate managed)
+        public CallbackCharArray2(CallbackCharArray2Delegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -8802,12 +10400,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(IntPtr ptr, IntPtr callback_data)
+        private void CallTrampoline(CharArray.Unmanaged value, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed(ptr);
+                _managed(value.ToManaged());
             }
             catch (Exception e)
             {
@@ -8818,10 +10416,11 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(IntPtr ptr)
+        internal void Call(CharArray value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackVoidNative>(_ptr);
-            __target(ptr, IntPtr.Zero);
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackCharArray2Native>(_ptr);
+            // TODO
+            // __target(value.ToManaged());
             return;
         }
 
@@ -8843,7 +10442,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(MyCallbackVoid), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackCharArray2), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -8852,9 +10451,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public MyCallbackVoid ToManaged()
+            public CallbackCharArray2 ToManaged()
             {
-                var rval = new MyCallbackVoid();
+                var rval = new CallbackCharArray2();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -8863,17 +10462,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private MyCallbackVoid _managed;
+            private CallbackCharArray2 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(MyCallbackVoid managed) { _managed = managed; }
+            public Marshaller(CallbackCharArray2 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(MyCallbackVoid managed) { _managed = managed; }
+            public void FromManaged(CallbackCharArray2 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8882,7 +10481,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public MyCallbackVoid ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackCharArray2 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -8890,25 +10489,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void NestedStringCallbackNative(UseString.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
-    public delegate void NestedStringCallbackDelegate(UseString s); // Our C# signature
+    public delegate byte CallbackFFISliceNative(SliceU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte CallbackFFISliceDelegate(SliceU8 slice); // Our C# signature
 
-    public partial class NestedStringCallback
+    public partial class CallbackFFISlice
     {
-        private NestedStringCallbackDelegate _managed; // C# callback
-        private NestedStringCallbackNative _native; // Native callback 
+        private CallbackFFISliceDelegate _managed; // C# callback
+        private CallbackFFISliceNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class NestedStringCallback : IDisposable
+    public partial class CallbackFFISlice : IDisposable
     {
 
-        internal NestedStringCallback() { }
+        internal CallbackFFISlice() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public NestedStringCallback(NestedStringCallbackDelegate managed)
+        public CallbackFFISlice(CallbackFFISliceDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -8917,27 +10516,28 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(UseString.Unmanaged s, IntPtr callback_data)
+        private byte CallTrampoline(SliceU8.Unmanaged slice, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed(s.IntoManaged());
+                return _managed(slice.ToManaged());
             }
             catch (Exception e)
             {
                 _exception = e;
-                return;
+                return default;
             }
         }
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(UseString s)
+        internal byte Call(SliceU8 slice)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<NestedStringCallbackNative>(_ptr);
-            __target(s.IntoUnmanaged(), IntPtr.Zero);
-            return;
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackFFISliceNative>(_ptr);
+            // TODO
+            // return __target(slice.ToManaged());
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -8958,7 +10558,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(NestedStringCallback), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackFFISlice), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -8967,9 +10567,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public NestedStringCallback ToManaged()
+            public CallbackFFISlice ToManaged()
             {
-                var rval = new NestedStringCallback();
+                var rval = new CallbackFFISlice();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -8978,17 +10578,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private NestedStringCallback _managed;
+            private CallbackFFISlice _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(NestedStringCallback managed) { _managed = managed; }
+            public Marshaller(CallbackFFISlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(NestedStringCallback managed) { _managed = managed; }
+            public void FromManaged(CallbackFFISlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -8997,7 +10597,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public NestedStringCallback ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackFFISlice ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -9005,25 +10605,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void PointersNative(ref int x, ref int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate void PointersDelegate(ref int x, ref int y); // Our C# signature
+    public delegate Vec3f32.Unmanaged CallbackHugeVecSliceNative(SliceVec3f32.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
+    public delegate Vec3f32 CallbackHugeVecSliceDelegate(SliceVec3f32 slice); // Our C# signature
 
-    public partial class Pointers
+    public partial class CallbackHugeVecSlice
     {
-        private PointersDelegate _managed; // C# callback
-        private PointersNative _native; // Native callback 
+        private CallbackHugeVecSliceDelegate _managed; // C# callback
+        private CallbackHugeVecSliceNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class Pointers : IDisposable
+    public partial class CallbackHugeVecSlice : IDisposable
     {
 
-        internal Pointers() { }
+        internal CallbackHugeVecSlice() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Pointers(PointersDelegate managed)
+        public CallbackHugeVecSlice(CallbackHugeVecSliceDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -9032,27 +10632,28 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(ref int x, ref int y, IntPtr callback_data)
+        private Vec3f32.Unmanaged CallTrampoline(SliceVec3f32.Unmanaged slice, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed(ref x, ref y);
+                return _managed(slice.ToManaged()).ToUnmanaged();
             }
             catch (Exception e)
             {
                 _exception = e;
-                return;
+                return default;
             }
         }
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(ref int x, ref int y)
+        internal Vec3f32 Call(SliceVec3f32 slice)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<PointersNative>(_ptr);
-            __target(ref x, ref y, IntPtr.Zero);
-            return;
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackHugeVecSliceNative>(_ptr);
+            // TODO
+            // return __target(slice.ToManaged());
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -9073,7 +10674,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(Pointers), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackHugeVecSlice), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -9082,9 +10683,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public Pointers ToManaged()
+            public CallbackHugeVecSlice ToManaged()
             {
-                var rval = new Pointers();
+                var rval = new CallbackHugeVecSlice();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -9093,17 +10694,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private Pointers _managed;
+            private CallbackHugeVecSlice _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(Pointers managed) { _managed = managed; }
+            public Marshaller(CallbackHugeVecSlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(Pointers managed) { _managed = managed; }
+            public void FromManaged(CallbackHugeVecSlice managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9112,7 +10713,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Pointers ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackHugeVecSlice ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -9120,25 +10721,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void StringCallbackNative(Utf8String.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
-    public delegate void StringCallbackDelegate(Utf8String s); // Our C# signature
+    public delegate void CallbackSliceMutNative(SliceMutU8.Unmanaged slice, IntPtr callback_data); // 'True' native callback signature
+    public delegate void CallbackSliceMutDelegate(SliceMutU8 slice); // Our C# signature
 
-    public partial class StringCallback
+    public partial class CallbackSliceMut
     {
-        private StringCallbackDelegate _managed; // C# callback
-        private StringCallbackNative _native; // Native callback 
+        private CallbackSliceMutDelegate _managed; // C# callback
+        private CallbackSliceMutNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class StringCallback : IDisposable
+    public partial class CallbackSliceMut : IDisposable
     {
 
-        internal StringCallback() { }
+        internal CallbackSliceMut() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public StringCallback(StringCallbackDelegate managed)
+        public CallbackSliceMut(CallbackSliceMutDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -9147,12 +10748,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(Utf8String.Unmanaged s, IntPtr callback_data)
+        private void CallTrampoline(SliceMutU8.Unmanaged slice, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed(s.IntoManaged());
+                _managed(slice.ToManaged());
             }
             catch (Exception e)
             {
@@ -9163,10 +10764,11 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(Utf8String s)
+        internal void Call(SliceMutU8 slice)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<StringCallbackNative>(_ptr);
-            __target(s.IntoUnmanaged(), IntPtr.Zero);
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackSliceMutNative>(_ptr);
+            // TODO
+            // __target(slice.ToManaged());
             return;
         }
 
@@ -9188,7 +10790,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(StringCallback), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackSliceMut), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -9197,9 +10799,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public StringCallback ToManaged()
+            public CallbackSliceMut ToManaged()
             {
-                var rval = new StringCallback();
+                var rval = new CallbackSliceMut();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -9208,17 +10810,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private StringCallback _managed;
+            private CallbackSliceMut _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(StringCallback managed) { _managed = managed; }
+            public Marshaller(CallbackSliceMut managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(StringCallback managed) { _managed = managed; }
+            public void FromManaged(CallbackSliceMut managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9227,7 +10829,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public StringCallback ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackSliceMut ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -9235,25 +10837,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate Utf8String.Unmanaged StringCallbackStringNative(Utf8String.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
-    public delegate Utf8String StringCallbackStringDelegate(Utf8String s); // Our C# signature
+    public delegate byte CallbackU8Native(byte value, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte CallbackU8Delegate(byte value); // Our C# signature
 
-    public partial class StringCallbackString
+    public partial class CallbackU8
     {
-        private StringCallbackStringDelegate _managed; // C# callback
-        private StringCallbackStringNative _native; // Native callback 
+        private CallbackU8Delegate _managed; // C# callback
+        private CallbackU8Native _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class StringCallbackString : IDisposable
+    public partial class CallbackU8 : IDisposable
     {
 
-        internal StringCallbackString() { }
+        internal CallbackU8() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public StringCallbackString(StringCallbackStringDelegate managed)
+        public CallbackU8(CallbackU8Delegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -9262,12 +10864,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private Utf8String.Unmanaged CallTrampoline(Utf8String.Unmanaged s, IntPtr callback_data)
+        private byte CallTrampoline(byte value, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                return _managed(s.IntoManaged()).IntoUnmanaged();
+                return _managed(value);
             }
             catch (Exception e)
             {
@@ -9278,10 +10880,12 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public Utf8String Call(Utf8String s)
+        internal byte Call(byte value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<StringCallbackStringNative>(_ptr);
-            return __target(s.IntoUnmanaged(), IntPtr.Zero).IntoManaged();
+            var __target = Marshal.GetDelegateForFunctionPointer<CallbackU8Native>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -9302,7 +10906,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(StringCallbackString), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(CallbackU8), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -9311,9 +10915,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public StringCallbackString ToManaged()
+            public CallbackU8 ToManaged()
             {
-                var rval = new StringCallbackString();
+                var rval = new CallbackU8();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -9322,17 +10926,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private StringCallbackString _managed;
+            private CallbackU8 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(StringCallbackString managed) { _managed = managed; }
+            public Marshaller(CallbackU8 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(StringCallbackString managed) { _managed = managed; }
+            public void FromManaged(CallbackU8 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9341,7 +10945,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public StringCallbackString ToManaged() { return _unmanaged.ToManaged(); }
+            public CallbackU8 ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -9349,25 +10953,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void SumDelegate1Native(IntPtr callback_data); // 'True' native callback signature
-    public delegate void SumDelegate1Delegate(); // Our C# signature
+    public delegate uint LogSinkCountNative(IntPtr callback_data); // 'True' native callback signature
+    public delegate uint LogSinkCountDelegate(); // Our C# signature
 
-    public partial class SumDelegate1
+    public partial class LogSinkCount
     {
-        private SumDelegate1Delegate _managed; // C# callback
-        private SumDelegate1Native _native; // Native callback 
+        private LogSinkCountDelegate _managed; // C# callback
+        private LogSinkCountNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegate1 : IDisposable
+    public partial class LogSinkCount : IDisposable
     {
 
-        internal SumDelegate1() { }
+        internal LogSinkCount() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegate1(SumDelegate1Delegate managed)
+        public LogSinkCount(LogSinkCountDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -9376,27 +10980,28 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(IntPtr callback_data)
+        private uint CallTrampoline(IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed();
+                return _managed();
             }
             catch (Exception e)
             {
                 _exception = e;
-                return;
+                return default;
             }
         }
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call()
+        internal uint Call()
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate1Native>(_ptr);
-            __target(IntPtr.Zero);
-            return;
+            var __target = Marshal.GetDelegateForFunctionPointer<LogSinkCountNative>(_ptr);
+            // TODO
+            // return __target();
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -9417,7 +11022,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegate1), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(LogSinkCount), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -9426,9 +11031,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegate1 ToManaged()
+            public LogSinkCount ToManaged()
             {
-                var rval = new SumDelegate1();
+                var rval = new LogSinkCount();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -9437,17 +11042,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegate1 _managed;
+            private LogSinkCount _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegate1 managed) { _managed = managed; }
+            public Marshaller(LogSinkCount managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegate1 managed) { _managed = managed; }
+            public void FromManaged(LogSinkCount managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9456,7 +11061,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegate1 ToManaged() { return _unmanaged.ToManaged(); }
+            public LogSinkCount ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -9464,25 +11069,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate int SumDelegate2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate int SumDelegate2Delegate(int x, int y); // Our C# signature
+    public delegate void LogSinkLogNative(uint level, uint x, IntPtr callback_data); // 'True' native callback signature
+    public delegate void LogSinkLogDelegate(uint level, uint x); // Our C# signature
 
-    public partial class SumDelegate2
+    public partial class LogSinkLog
     {
-        private SumDelegate2Delegate _managed; // C# callback
-        private SumDelegate2Native _native; // Native callback 
+        private LogSinkLogDelegate _managed; // C# callback
+        private LogSinkLogNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegate2 : IDisposable
+    public partial class LogSinkLog : IDisposable
     {
 
-        internal SumDelegate2() { }
+        internal LogSinkLog() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegate2(SumDelegate2Delegate managed)
+        public LogSinkLog(LogSinkLogDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -9491,26 +11096,28 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private int CallTrampoline(int x, int y, IntPtr callback_data)
+        private void CallTrampoline(uint level, uint x, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                return _managed(x, y);
+                _managed(level, x);
             }
             catch (Exception e)
             {
                 _exception = e;
-                return default;
+                return;
             }
         }
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public int Call(int x, int y)
+        internal void Call(uint level, uint x)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate2Native>(_ptr);
-            return __target(x, y, IntPtr.Zero);
+            var __target = Marshal.GetDelegateForFunctionPointer<LogSinkLogNative>(_ptr);
+            // TODO
+            // __target(level, x);
+            return;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -9531,7 +11138,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegate2), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(LogSinkLog), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -9540,9 +11147,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegate2 ToManaged()
+            public LogSinkLog ToManaged()
             {
-                var rval = new SumDelegate2();
+                var rval = new LogSinkLog();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -9551,17 +11158,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegate2 _managed;
+            private LogSinkLog _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegate2 managed) { _managed = managed; }
+            public Marshaller(LogSinkLog managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegate2 managed) { _managed = managed; }
+            public void FromManaged(LogSinkLog managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9570,7 +11177,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegate2 ToManaged() { return _unmanaged.ToManaged(); }
+            public LogSinkLog ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -9578,25 +11185,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate ResultError.Unmanaged SumDelegateReturnNative(int x, int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate ResultError SumDelegateReturnDelegate(int x, int y); // Our C# signature
+    public delegate uint MyCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate uint MyCallbackDelegate(uint value); // Our C# signature
 
-    public partial class SumDelegateReturn
+    public partial class MyCallback
     {
-        private SumDelegateReturnDelegate _managed; // C# callback
-        private SumDelegateReturnNative _native; // Native callback 
+        private MyCallbackDelegate _managed; // C# callback
+        private MyCallbackNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegateReturn : IDisposable
+    public partial class MyCallback : IDisposable
     {
 
-        internal SumDelegateReturn() { }
+        internal MyCallback() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegateReturn(SumDelegateReturnDelegate managed)
+        public MyCallback(MyCallbackDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -9605,12 +11212,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private ResultError.Unmanaged CallTrampoline(int x, int y, IntPtr callback_data)
+        private uint CallTrampoline(uint value, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                return _managed(x, y).ToUnmanaged();
+                return _managed(value);
             }
             catch (Exception e)
             {
@@ -9621,10 +11228,12 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public ResultError Call(int x, int y)
+        internal uint Call(uint value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturnNative>(_ptr);
-            return __target(x, y, IntPtr.Zero).ToManaged();
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
@@ -9645,7 +11254,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegateReturn), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(MyCallback), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -9654,9 +11263,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegateReturn ToManaged()
+            public MyCallback ToManaged()
             {
-                var rval = new SumDelegateReturn();
+                var rval = new MyCallback();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -9665,17 +11274,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegateReturn _managed;
+            private MyCallback _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegateReturn managed) { _managed = managed; }
+            public Marshaller(MyCallback managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegateReturn managed) { _managed = managed; }
+            public void FromManaged(MyCallback managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9684,7 +11293,7 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegateReturn ToManaged() { return _unmanaged.ToManaged(); }
+            public MyCallback ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
@@ -9692,25 +11301,25 @@ namespace My.Company
     }
 
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-    public delegate void SumDelegateReturn2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
-    public delegate void SumDelegateReturn2Delegate(int x, int y); // Our C# signature
+    public delegate void MyCallbackContextualNative(IntPtr context, uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate void MyCallbackContextualDelegate(IntPtr context, uint value); // Our C# signature
 
-    public partial class SumDelegateReturn2
+    public partial class MyCallbackContextual
     {
-        private SumDelegateReturn2Delegate _managed; // C# callback
-        private SumDelegateReturn2Native _native; // Native callback 
+        private MyCallbackContextualDelegate _managed; // C# callback
+        private MyCallbackContextualNative _native; // Native callback 
         private IntPtr _ptr; // Raw function pointer of native callback
         private Exception _exception; // Set if the callback encountered an Exception
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class SumDelegateReturn2 : IDisposable
+    public partial class MyCallbackContextual : IDisposable
     {
 
-        internal SumDelegateReturn2() { }
+        internal MyCallbackContextual() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public SumDelegateReturn2(SumDelegateReturn2Delegate managed)
+        public MyCallbackContextual(MyCallbackContextualDelegate managed)
         {
             _managed = managed;
             _native = CallTrampoline;
@@ -9719,12 +11328,12 @@ namespace My.Company
 
         // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private void CallTrampoline(int x, int y, IntPtr callback_data)
+        private void CallTrampoline(IntPtr context, uint value, IntPtr callback_data)
         {
             // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
             try
             {
-                _managed(x, y);
+                _managed(context, value);
             }
             catch (Exception e)
             {
@@ -9735,10 +11344,11 @@ namespace My.Company
 
         // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public void Call(int x, int y)
+        internal void Call(IntPtr context, uint value)
         {
-            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturn2Native>(_ptr);
-            __target(x, y, IntPtr.Zero);
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackContextualNative>(_ptr);
+            // TODO
+            // __target(context, value);
             return;
         }
 
@@ -9760,7 +11370,7 @@ namespace My.Company
             return rval;
         }
 
-        [CustomMarshaller(typeof(SumDelegateReturn2), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(MyCallbackContextual), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta {  }
 
         [StructLayout(LayoutKind.Sequential)]
@@ -9769,9 +11379,9 @@ namespace My.Company
             internal IntPtr _callback;
             internal IntPtr _data;
 
-            public SumDelegateReturn2 ToManaged()
+            public MyCallbackContextual ToManaged()
             {
-                var rval = new SumDelegateReturn2();
+                var rval = new MyCallbackContextual();
                 rval._ptr = _callback;
                 return rval;
             }
@@ -9780,17 +11390,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private SumDelegateReturn2 _managed;
+            private MyCallbackContextual _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(SumDelegateReturn2 managed) { _managed = managed; }
+            public Marshaller(MyCallbackContextual managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(SumDelegateReturn2 managed) { _managed = managed; }
+            public void FromManaged(MyCallbackContextual managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -9799,401 +11409,2088 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public SumDelegateReturn2 ToManaged() { return _unmanaged.ToManaged(); }
+            public MyCallbackContextual ToManaged() { return _unmanaged.ToManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public class AsyncTrampolineResultError
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void MyCallbackVoidNative(IntPtr ptr, IntPtr callback_data); // 'True' native callback signature
+    public delegate void MyCallbackVoidDelegate(IntPtr ptr); // Our C# signature
+
+    public partial class MyCallbackVoid
     {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+        private MyCallbackVoidDelegate _managed; // C# callback
+        private MyCallbackVoidNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyCallbackVoid : IDisposable
+    {
+
+        internal MyCallbackVoid() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultError()
+        public MyCallbackVoid(MyCallbackVoidDelegate managed)
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
         }
 
+        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        private void CallTrampoline(IntPtr ptr, IntPtr callback_data)
         {
-            TaskCompletionSource tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultError.Unmanaged>(data);
-            var managed = unmanaged.ToManaged();
-            if (managed.IsOk) { tcs.SetResult(); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(ptr);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
         }
 
+        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task) NewCall()
+        internal void Call(IntPtr ptr)
         {
-            var tcs = new TaskCompletionSource();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
-
-            return (ac, tcs.Task);
+            var __target = Marshal.GetDelegateForFunctionPointer<MyCallbackVoidNative>(_ptr);
+            // TODO
+            // __target(ptr);
+            return;
         }
-    }
-
-    public class AsyncTrampolineResultNestedArrayError
-    {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<NestedArray>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultNestedArrayError()
+        public void Dispose()
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        internal Unmanaged ToUnmanaged()
         {
-            TaskCompletionSource<NestedArray> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultNestedArrayError.Unmanaged>(data);
-            var managed = unmanaged.ToManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<NestedArray>) NewCall()
+        [CustomMarshaller(typeof(MyCallbackVoid), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
         {
-            var tcs = new TaskCompletionSource<NestedArray>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public MyCallbackVoid ToManaged()
+            {
+                var rval = new MyCallbackVoid();
+                rval._ptr = _callback;
+                return rval;
+            }
 
-            return (ac, tcs.Task);
+        }
+
+        public ref struct Marshaller
+        {
+            private MyCallbackVoid _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyCallbackVoid managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyCallbackVoid managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyCallbackVoid ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
     }
 
-    public class AsyncTrampolineResultU64Error
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate ResultU32Error.Unmanaged MyFallibleCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate ResultU32Error MyFallibleCallbackDelegate(uint value); // Our C# signature
+
+    public partial class MyFallibleCallback
+    {
+        private MyFallibleCallbackDelegate _managed; // C# callback
+        private MyFallibleCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyFallibleCallback : IDisposable
     {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<ulong>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+
+        internal MyFallibleCallback() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultU64Error()
+        public MyFallibleCallback(MyFallibleCallbackDelegate managed)
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
         }
 
+        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        private ResultU32Error.Unmanaged CallTrampoline(uint value, IntPtr callback_data)
         {
-            TaskCompletionSource<ulong> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultU64Error.Unmanaged>(data);
-            var managed = unmanaged.ToManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value).ToUnmanaged();
+            }
+            catch (ErrorException e)
+            {
+                return ResultU32Error.Err(e.Error).ToUnmanaged();
+            }
+            catch (Exception)
+            {
+                return ResultU32Error.Panic.ToUnmanaged();
+            }
         }
 
+        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<ulong>) NewCall()
+        internal ResultU32Error Call(uint value)
         {
-            var tcs = new TaskCompletionSource<ulong>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
-
-            return (ac, tcs.Task);
+            var __target = Marshal.GetDelegateForFunctionPointer<MyFallibleCallbackNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
         }
-    }
-
-    public class AsyncTrampolineResultUseStringError
-    {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<UseString>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultUseStringError()
+        public void Dispose()
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        internal Unmanaged ToUnmanaged()
         {
-            TaskCompletionSource<UseString> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultUseStringError.Unmanaged>(data);
-            var managed = unmanaged.IntoManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<UseString>) NewCall()
+        [CustomMarshaller(typeof(MyFallibleCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
         {
-            var tcs = new TaskCompletionSource<UseString>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            internal IntPtr _callback;
+            internal IntPtr _data;
 
-            return (ac, tcs.Task);
+            public MyFallibleCallback ToManaged()
+            {
+                var rval = new MyFallibleCallback();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyFallibleCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyFallibleCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyFallibleCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyFallibleCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
     }
 
-    public class AsyncTrampolineResultUtf8StringError
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate uint MyOwnedCallbackNative(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate uint MyOwnedCallbackDelegate(uint value); // Our C# signature
+
+    public partial class MyOwnedCallback
+    {
+        private MyOwnedCallbackDelegate _managed; // C# callback
+        private MyOwnedCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+        private IntPtr _data; // Callback data of an owned callback received from Rust
+        private IntPtr _destructor; // Destructor of an owned callback received from Rust
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class MyOwnedCallback : IDisposable
     {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<Utf8String>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+
+        internal MyOwnedCallback() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultUtf8StringError()
+        public MyOwnedCallback(MyOwnedCallbackDelegate managed)
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
         }
 
+        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        private uint CallTrampoline(uint value, IntPtr callback_data)
         {
-            TaskCompletionSource<Utf8String> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultUtf8StringError.Unmanaged>(data);
-            var managed = unmanaged.IntoManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
         }
 
+        // Invokes the callback.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<Utf8String>) NewCall()
+        internal uint Call(uint value)
         {
-            var tcs = new TaskCompletionSource<Utf8String>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+            var __target = Marshal.GetDelegateForFunctionPointer<MyOwnedCallbackNative>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
 
-            return (ac, tcs.Task);
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // If Rust handed us an owned callback we are now responsible for releasing it.
+            if (_destructor != IntPtr.Zero)
+            {
+                Marshal.GetDelegateForFunctionPointer<DestructorNative>(_destructor)(_data);
+                _destructor = IntPtr.Zero;
+            }
+
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            // Keeps this callback alive until Rust drops it and invokes the destructor.
+            rval._data = GCHandle.ToIntPtr(GCHandle.Alloc(this));
+            rval._destructor = DestructorPtr;
+            return rval;
+        }
+
+        private delegate void DestructorNative(IntPtr data);
+        private static readonly DestructorNative Destructor = ReleaseHandle;
+        private static readonly IntPtr DestructorPtr = Marshal.GetFunctionPointerForDelegate(Destructor);
+
+        // Invoked by Rust once it dropped the callback.
+        private static void ReleaseHandle(IntPtr data)
+        {
+            GCHandle.FromIntPtr(data).Free();
+        }
+
+        [CustomMarshaller(typeof(MyOwnedCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+            internal IntPtr _destructor;
+
+            public MyOwnedCallback ToManaged()
+            {
+                var rval = new MyOwnedCallback();
+                rval._ptr = _callback;
+                rval._data = _data;
+                rval._destructor = _destructor;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private MyOwnedCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(MyOwnedCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(MyOwnedCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public MyOwnedCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
     }
 
-    public class AsyncTrampolineResultVecUtf8StringError
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void NestedStringCallbackNative(UseString.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
+    public delegate void NestedStringCallbackDelegate(UseString s); // Our C# signature
+
+    public partial class NestedStringCallback
     {
-        private static ulong Id = 0;
-        private static Dictionary<ulong, TaskCompletionSource<VecUtf8String>> InFlight = new(1024);
-        private AsyncCallbackCommon _delegate;
-        private IntPtr _callback_ptr;
+        private NestedStringCallbackDelegate _managed; // C# callback
+        private NestedStringCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class NestedStringCallback : IDisposable
+    {
+
+        internal NestedStringCallback() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal AsyncTrampolineResultVecUtf8StringError()
+        public NestedStringCallback(NestedStringCallbackDelegate managed)
         {
-            _delegate = Call;
-            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
         }
 
+        // Helper to invoke managed code from the native invocation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private static void Call(IntPtr data, IntPtr csPtr)
+        private void CallTrampoline(UseString.Unmanaged s, IntPtr callback_data)
         {
-            TaskCompletionSource<VecUtf8String> tcs;
-            
-            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
-            
-            var unmanaged = Marshal.PtrToStructure<ResultVecUtf8StringError.Unmanaged>(data);
-            var managed = unmanaged.IntoManaged();
-            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
-            else { tcs.SetException(managed.ExceptionForVariant()); }
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(s.IntoManaged());
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
         }
 
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        internal (AsyncCallbackCommonNative, Task<VecUtf8String>) NewCall()
-        {
-            var tcs = new TaskCompletionSource<VecUtf8String>();
-            var id = Id++;
-            
-            lock (InFlight) { InFlight.TryAdd(id, tcs); }
-            
-            var ac = new AsyncCallbackCommonNative {
-                _ptr = _callback_ptr,
-                _ts = (IntPtr) id,
-            };
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(UseString s)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<NestedStringCallbackNative>(_ptr);
+            // TODO
+            // __target(s.IntoManaged());
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(NestedStringCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public NestedStringCallback ToManaged()
+            {
+                var rval = new NestedStringCallback();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private NestedStringCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(NestedStringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(NestedStringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public NestedStringCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate byte OnItemF32Native(float value, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte OnItemF32Delegate(float value); // Our C# signature
+
+    public partial class OnItemF32
+    {
+        private OnItemF32Delegate _managed; // C# callback
+        private OnItemF32Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OnItemF32 : IDisposable
+    {
+
+        internal OnItemF32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OnItemF32(OnItemF32Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private byte CallTrampoline(float value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal byte Call(float value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<OnItemF32Native>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(OnItemF32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public OnItemF32 ToManaged()
+            {
+                var rval = new OnItemF32();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private OnItemF32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(OnItemF32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(OnItemF32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public OnItemF32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate byte OnItemU32Native(uint value, IntPtr callback_data); // 'True' native callback signature
+    public delegate byte OnItemU32Delegate(uint value); // Our C# signature
+
+    public partial class OnItemU32
+    {
+        private OnItemU32Delegate _managed; // C# callback
+        private OnItemU32Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class OnItemU32 : IDisposable
+    {
+
+        internal OnItemU32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public OnItemU32(OnItemU32Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private byte CallTrampoline(uint value, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(value);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal byte Call(uint value)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<OnItemU32Native>(_ptr);
+            // TODO
+            // return __target(value);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(OnItemU32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public OnItemU32 ToManaged()
+            {
+                var rval = new OnItemU32();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private OnItemU32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(OnItemU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(OnItemU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public OnItemU32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void StringCallbackNative(Utf8String.Unmanaged s, IntPtr callback_data); // 'True' native callback signature
+    public delegate void StringCallbackDelegate(Utf8String s); // Our C# signature
+
+    public partial class StringCallback
+    {
+        private StringCallbackDelegate _managed; // C# callback
+        private StringCallbackNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class StringCallback : IDisposable
+    {
+
+        internal StringCallback() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public StringCallback(StringCallbackDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(Utf8String.Unmanaged s, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(s.IntoManaged());
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(Utf8String s)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<StringCallbackNative>(_ptr);
+            // TODO
+            // __target(s.IntoManaged());
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(StringCallback), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public StringCallback ToManaged()
+            {
+                var rval = new StringCallback();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private StringCallback _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(StringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(StringCallback managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public StringCallback ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void SumDelegate1Native(IntPtr callback_data); // 'True' native callback signature
+    public delegate void SumDelegate1Delegate(); // Our C# signature
+
+    public partial class SumDelegate1
+    {
+        private SumDelegate1Delegate _managed; // C# callback
+        private SumDelegate1Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegate1 : IDisposable
+    {
+
+        internal SumDelegate1() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegate1(SumDelegate1Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed();
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call()
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate1Native>(_ptr);
+            // TODO
+            // __target();
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegate1), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegate1 ToManaged()
+            {
+                var rval = new SumDelegate1();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegate1 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegate1 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegate1 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegate1 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate int SumDelegate2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
+    public delegate int SumDelegate2Delegate(int x, int y); // Our C# signature
+
+    public partial class SumDelegate2
+    {
+        private SumDelegate2Delegate _managed; // C# callback
+        private SumDelegate2Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegate2 : IDisposable
+    {
+
+        internal SumDelegate2() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegate2(SumDelegate2Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private int CallTrampoline(int x, int y, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(x, y);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return default;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal int Call(int x, int y)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegate2Native>(_ptr);
+            // TODO
+            // return __target(x, y);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegate2), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegate2 ToManaged()
+            {
+                var rval = new SumDelegate2();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegate2 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegate2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegate2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegate2 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate ResultError.Unmanaged SumDelegateReturnNative(int x, int y, IntPtr callback_data); // 'True' native callback signature
+    public delegate ResultError SumDelegateReturnDelegate(int x, int y); // Our C# signature
+
+    public partial class SumDelegateReturn
+    {
+        private SumDelegateReturnDelegate _managed; // C# callback
+        private SumDelegateReturnNative _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegateReturn : IDisposable
+    {
+
+        internal SumDelegateReturn() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegateReturn(SumDelegateReturnDelegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private ResultError.Unmanaged CallTrampoline(int x, int y, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                return _managed(x, y).ToUnmanaged();
+            }
+            catch (ErrorException e)
+            {
+                return ResultError.Err(e.Error).ToUnmanaged();
+            }
+            catch (Exception)
+            {
+                return ResultError.Panic.ToUnmanaged();
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal ResultError Call(int x, int y)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturnNative>(_ptr);
+            // TODO
+            // return __target(x, y);
+            return default;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegateReturn), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegateReturn ToManaged()
+            {
+                var rval = new SumDelegateReturn();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegateReturn _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegateReturn managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegateReturn managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegateReturn ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void SumDelegateReturn2Native(int x, int y, IntPtr callback_data); // 'True' native callback signature
+    public delegate void SumDelegateReturn2Delegate(int x, int y); // Our C# signature
+
+    public partial class SumDelegateReturn2
+    {
+        private SumDelegateReturn2Delegate _managed; // C# callback
+        private SumDelegateReturn2Native _native; // Native callback 
+        private IntPtr _ptr; // Raw function pointer of native callback
+        private Exception _exception; // Set if the callback encountered an Exception
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SumDelegateReturn2 : IDisposable
+    {
+
+        internal SumDelegateReturn2() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public SumDelegateReturn2(SumDelegateReturn2Delegate managed)
+        {
+            _managed = managed;
+            _native = CallTrampoline;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        // Helper to invoke managed code from the native invocation.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void CallTrampoline(int x, int y, IntPtr callback_data)
+        {
+            // We ignore the last parameter, a generic callback pointer, as it's not needed in C#.
+            try
+            {
+                _managed(x, y);
+            }
+            catch (Exception e)
+            {
+                _exception = e;
+                return;
+            }
+        }
+
+        // Invokes the callback.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal void Call(int x, int y)
+        {
+            var __target = Marshal.GetDelegateForFunctionPointer<SumDelegateReturn2Native>(_ptr);
+            // TODO
+            // __target(x, y);
+            return;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            // This means when the callback was invoked from Rust C# had an exception which
+            // we caught (otherwise C# might not re-enter Rust, and we leak memory). Now is
+            // the time to rethrow it.
+            if (_exception != null) throw _exception;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var rval = new Unmanaged();
+            rval._callback = _ptr;
+            rval._data = IntPtr.Zero;
+            return rval;
+        }
+
+        [CustomMarshaller(typeof(SumDelegateReturn2), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta {  }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _callback;
+            internal IntPtr _data;
+
+            public SumDelegateReturn2 ToManaged()
+            {
+                var rval = new SumDelegateReturn2();
+                rval._ptr = _callback;
+                return rval;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SumDelegateReturn2 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SumDelegateReturn2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SumDelegateReturn2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SumDelegateReturn2 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public class AsyncTrampolineResultError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultError.Unmanaged>(data);
+            var managed = unmanaged.ToManaged();
+            if (managed.IsOk) { tcs.SetResult(); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task) NewCall()
+        {
+            var tcs = new TaskCompletionSource();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultNestedArrayError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<NestedArray>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultNestedArrayError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<NestedArray> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultNestedArrayError.Unmanaged>(data);
+            var managed = unmanaged.ToManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<NestedArray>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<NestedArray>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultU64Error
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<ulong>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultU64Error()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<ulong> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultU64Error.Unmanaged>(data);
+            var managed = unmanaged.ToManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<ulong>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<ulong>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultUseStringError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<UseString>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultUseStringError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<UseString> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultUseStringError.Unmanaged>(data);
+            var managed = unmanaged.IntoManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<UseString>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<UseString>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultUtf8StringError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<Utf8String>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultUtf8StringError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<Utf8String> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultUtf8StringError.Unmanaged>(data);
+            var managed = unmanaged.IntoManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<Utf8String>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<Utf8String>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    public class AsyncTrampolineResultVecUtf8StringError
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<VecUtf8String>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultVecUtf8StringError()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<VecUtf8String> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultVecUtf8StringError.Unmanaged>(data);
+            var managed = unmanaged.IntoManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(managed.ToException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<VecUtf8String>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<VecUtf8String>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecEnumPayload
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecEnumPayload : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecEnumPayload() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecEnumPayload From(Span<EnumPayload> _data)
+        {
+            var rval = new VecEnumPayload();
+            fixed (void* _data_ptr = _data)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                rval._len = _out._len;
+                rval._capacity = _out._capacity;
+                rval._ptr = _out._ptr;
+            }
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecEnumPayload Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe EnumPayload this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                return Marshal.PtrToStructure<EnumPayload>(new IntPtr(_ptr.ToInt64() + i * sizeof(EnumPayload)));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecEnumPayload { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_12058709700293828778")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_4854562680055612543")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecEnumPayload), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecEnumPayload IntoManaged()
+            {
+                var rval = new VecEnumPayload();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecEnumPayload _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecEnumPayload managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecEnumPayload managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecEnumPayload ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public static class VecEnumPayloadExtensions
+    {
+        public static VecEnumPayload Vec(this EnumPayload[] s) { return VecEnumPayload.From(s); }
+    }
+
+
+    // This must be a class because we only ever want to hold on to the
+    // same instance, as we overwrite fields when this is sent over the FFI
+    // boundary
+    public partial class VecVec3f32
+    {
+        internal IntPtr _ptr;
+        internal ulong _len;
+        internal ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class VecVec3f32 : IDisposable
+    {
+        // An internal helper to create an empty object.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private VecVec3f32() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecVec3f32 From(Span<Vec3f32> _data)
+        {
+            var rval = new VecVec3f32();
+            fixed (void* _data_ptr = _data)
+            {
+                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
+                rval._len = _out._len;
+                rval._capacity = _out._capacity;
+                rval._ptr = _out._ptr;
+            }
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe VecVec3f32 Empty()
+        {
+            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+        public int Count
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get { if (_ptr == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
+        }
+
+        public unsafe Vec3f32 this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                if (_ptr == IntPtr.Zero) throw new InteropException();
+                return Marshal.PtrToStructure<Vec3f32>(new IntPtr(_ptr.ToInt64() + i * sizeof(Vec3f32)));
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
+            var rval = new Unmanaged();
+            rval._len = _len;
+            rval._capacity = _capacity;
+            rval._ptr = _ptr;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+            _len = 0;
+            _capacity = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "VecVec3f32 { ... }";
+        }
+
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_1781563852271199874")]
+            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_12107015372467426116")]
+            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+        }
+
+        [CustomMarshaller(typeof(VecVec3f32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr _ptr;
+            internal ulong _len;
+            internal ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecVec3f32 IntoManaged()
+            {
+                var rval = new VecVec3f32();
+                rval._len = _len;
+                rval._capacity = _capacity;
+                rval._ptr = _ptr;
+                return rval;
+            }
+
+
+        }
+
+        public ref struct Marshaller
+        {
+            private VecVec3f32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(VecVec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(VecVec3f32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
-            return (ac, tcs.Task);
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public VecVec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
         }
     }
 
+    public static class VecVec3f32Extensions
+    {
+        public static VecVec3f32 Vec(this Vec3f32[] s) { return VecVec3f32.From(s); }
+    }
+
+
     // This must be a class because we only ever want to hold on to the
     // same instance, as we overwrite fields when this is sent over the FFI
     // boundary
-    public partial class VecEnumPayload
+    public partial class MapU32Vec3f32
     {
-        internal IntPtr _ptr;
+        internal IntPtr _keys;
+        internal IntPtr _values;
         internal ulong _len;
-        internal ulong _capacity;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecEnumPayload : IDisposable
+    public partial class MapU32Vec3f32 : IReadOnlyDictionary<uint, Vec3f32>, IDisposable
     {
         // An internal helper to create an empty object.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecEnumPayload() { }
+        private MapU32Vec3f32() { }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecEnumPayload From(Span<EnumPayload> _data)
+        public static unsafe MapU32Vec3f32 From(IReadOnlyDictionary<uint, Vec3f32> _data)
         {
-            var rval = new VecEnumPayload();
-            fixed (void* _data_ptr = _data)
+            var _keys = new uint[_data.Count];
+            var _values = new Vec3f32[_data.Count];
+            var i = 0;
+            foreach (var _entry in _data)
             {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                rval._len = _out._len;
-                rval._capacity = _out._capacity;
-                rval._ptr = _out._ptr;
+                _keys[i] = _entry.Key;
+                _values[i] = _entry.Value;
+                ++i;
+            }
+            fixed (void* _keys_ptr = _keys)
+            fixed (void* _values_ptr = _values)
+            {
+                InteropHelper.interoptopus_map_create((IntPtr) _keys_ptr, (IntPtr) _values_ptr, (ulong) _keys.Length, out var _out);
+                return _out.IntoManaged();
             }
-            return rval;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecEnumPayload Empty()
+        public static unsafe MapU32Vec3f32 Empty()
         {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
+            InteropHelper.interoptopus_map_create(IntPtr.Zero, IntPtr.Zero, 0, out var _out);
             return _out.IntoManaged();
         }
 
         public int Count
         {
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
+            get { if (_keys == IntPtr.Zero) { throw new InteropException(); } else { return (int) _len; } }
         }
 
-        public unsafe EnumPayload this[int i]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private unsafe uint KeyAt(int i)
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
+            if (i >= Count) throw new IndexOutOfRangeException();
+            return Marshal.PtrToStructure<uint>(new IntPtr(_keys.ToInt64() + i * sizeof(uint)));
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private unsafe Vec3f32 ValueAt(int i)
+        {
+            if (i >= Count) throw new IndexOutOfRangeException();
+            return Marshal.PtrToStructure<Vec3f32>(new IntPtr(_values.ToInt64() + i * sizeof(Vec3f32)));
+        }
+
+        // Keys are sorted by their Rust ordering, which might differ from C#, so we search linearly.
+        private int IndexOf(uint key)
+        {
+            for (var i = 0; i < Count; ++i)
             {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                return Marshal.PtrToStructure<EnumPayload>(new IntPtr(_ptr.ToInt64() + i * sizeof(EnumPayload)));
+                if (EqualityComparer<uint>.Default.Equals(KeyAt(i), key)) return i;
+            }
+            return -1;
+        }
+
+        public Vec3f32 this[uint key]
+        {
+            get { if (TryGetValue(key, out var value)) { return value; } else { throw new KeyNotFoundException(); } }
+        }
+
+        public bool ContainsKey(uint key) { return IndexOf(key) >= 0; }
+
+        public bool TryGetValue(uint key, out Vec3f32 value)
+        {
+            var i = IndexOf(key);
+            value = i >= 0 ? ValueAt(i) : default;
+            return i >= 0;
+        }
+
+        public IEnumerable<uint> Keys
+        {
+            get { for (var i = 0; i < Count; ++i) { yield return KeyAt(i); } }
+        }
+
+        public IEnumerable<Vec3f32> Values
+        {
+            get { for (var i = 0; i < Count; ++i) { yield return ValueAt(i); } }
+        }
+
+        public IEnumerator<KeyValuePair<uint, Vec3f32>> GetEnumerator()
+        {
+            for (var i = 0; i < Count; ++i)
+            {
+                yield return new KeyValuePair<uint, Vec3f32>(KeyAt(i), ValueAt(i));
             }
         }
 
+        IEnumerator IEnumerable.GetEnumerator() { return GetEnumerator(); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Unmanaged IntoUnmanaged()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
+            if (_keys == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
             var rval = new Unmanaged();
+            rval._keys = _keys;
+            rval._values = _values;
             rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
-            _ptr = IntPtr.Zero; // Mark this instance as moved.
+            _keys = IntPtr.Zero; // Mark this instance as moved.
             return rval;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Unmanaged AsUnmanaged()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
+            if (_keys == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
             var rval = new Unmanaged();
+            rval._keys = _keys;
+            rval._values = _values;
             rval._len = _len;
-            rval._capacity = _capacity;
-            rval._ptr = _ptr;
             return rval;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            if (_ptr == IntPtr.Zero) return;
+            if (_keys == IntPtr.Zero) return;
             var _unmanaged = new Unmanaged();
-            _unmanaged._ptr = _ptr;
+            _unmanaged._keys = _keys;
+            _unmanaged._values = _values;
             _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
-            _ptr = IntPtr.Zero;
+            InteropHelper.interoptopus_map_destroy(_unmanaged);
+            _keys = IntPtr.Zero;
+            _values = IntPtr.Zero;
             _len = 0;
-            _capacity = 0;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "VecEnumPayload { ... }";
+            return "MapU32Vec3f32 { ... }";
         }
 
 
         public partial class InteropHelper
         {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_12058709700293828778")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_4854562680055612543")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_map_create_6262311165280223196")]
+            internal static partial long interoptopus_map_create(IntPtr keys, IntPtr values, ulong len, out Unmanaged rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_map_destroy_9095550523068598099")]
+            internal static partial long interoptopus_map_destroy(Unmanaged map);
         }
 
-        [CustomMarshaller(typeof(VecEnumPayload), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(MapU32Vec3f32), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         [StructLayout(LayoutKind.Sequential)]
         public struct Unmanaged
         {
-            internal IntPtr _ptr;
+            internal IntPtr _keys;
+            internal IntPtr _values;
             internal ulong _len;
-            internal ulong _capacity;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecEnumPayload IntoManaged()
+            public MapU32Vec3f32 IntoManaged()
             {
-                var rval = new VecEnumPayload();
+                var rval = new MapU32Vec3f32();
+                rval._keys = _keys;
+                rval._values = _values;
                 rval._len = _len;
-                rval._capacity = _capacity;
-                rval._ptr = _ptr;
                 return rval;
             }
 
@@ -10202,17 +13499,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private VecEnumPayload _managed;
+            private MapU32Vec3f32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecEnumPayload managed) { _managed = managed; }
+            public Marshaller(MapU32Vec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecEnumPayload managed) { _managed = managed; }
+            public void FromManaged(MapU32Vec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -10221,81 +13518,63 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecEnumPayload ToManaged() { return _unmanaged.IntoManaged(); }
+            public MapU32Vec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public static class VecEnumPayloadExtensions
+    public static class MapU32Vec3f32Extensions
     {
-        public static VecEnumPayload Vec(this EnumPayload[] s) { return VecEnumPayload.From(s); }
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static MapU32Vec3f32 IntoMap(this IReadOnlyDictionary<uint, Vec3f32> s) { return MapU32Vec3f32.From(s); }
     }
 
 
     // This must be a class because we only ever want to hold on to the
     // same instance, as we overwrite fields when this is sent over the FFI
     // boundary
-    public partial class VecVec3f32
+    public partial class IterVec3f32
     {
         internal IntPtr _ptr;
-        internal ulong _len;
-        internal ulong _capacity;
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
-    public partial class VecVec3f32 : IDisposable
+    public partial class IterVec3f32 : IEnumerable<Vec3f32>, IDisposable
     {
         // An internal helper to create an empty object.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        private VecVec3f32() { }
-
-        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecVec3f32 From(Span<Vec3f32> _data)
-        {
-            var rval = new VecVec3f32();
-            fixed (void* _data_ptr = _data)
-            {
-                InteropHelper.interoptopus_vec_create((IntPtr) _data_ptr, (ulong)_data.Length, out var _out);
-                rval._len = _out._len;
-                rval._capacity = _out._capacity;
-                rval._ptr = _out._ptr;
-            }
-            return rval;
-        }
+        private IterVec3f32() { }
 
+        // Advances the iterator, returns false once it is exhausted.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public static unsafe VecVec3f32 Empty()
-        {
-            InteropHelper.interoptopus_vec_create(IntPtr.Zero, 0, out var _out);
-            return _out.IntoManaged();
-        }
-
-        public int Count
+        public bool TryNext(out Vec3f32 item)
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get { if (_ptr == IntPtr.Zero) { throw new NullReferenceException(); } else { return (int) _len; } }
+            var _unmanaged = AsUnmanaged();
+            return InteropHelper.interoptopus_iter_next(ref _unmanaged, out item) != 0;
         }
 
-        public unsafe Vec3f32 this[int i]
+        // Items can only be enumerated once, the handle is disposed afterwards.
+        public IEnumerator<Vec3f32> GetEnumerator()
         {
-            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            get
+            try
             {
-                if (i >= Count) throw new IndexOutOfRangeException();
-                if (_ptr == IntPtr.Zero) throw new NullReferenceException();
-                return Marshal.PtrToStructure<Vec3f32>(new IntPtr(_ptr.ToInt64() + i * sizeof(Vec3f32)));
+                while (TryNext(out var item)) { yield return item; }
+            }
+            finally
+            {
+                Dispose();
             }
         }
 
+        IEnumerator IEnumerable.GetEnumerator() { return GetEnumerator(); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Unmanaged IntoUnmanaged()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
             var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
             rval._ptr = _ptr;
             _ptr = IntPtr.Zero; // Mark this instance as moved.
             return rval;
@@ -10304,10 +13583,8 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Unmanaged AsUnmanaged()
         {
-            if (_ptr == IntPtr.Zero) throw new NullReferenceException(); // Don't use for serialization if moved already.
+            if (_ptr == IntPtr.Zero) throw new InteropException(); // Don't use for serialization if moved already.
             var rval = new Unmanaged();
-            rval._len = _len;
-            rval._capacity = _capacity;
             rval._ptr = _ptr;
             return rval;
         }
@@ -10318,45 +13595,37 @@ namespace My.Company
             if (_ptr == IntPtr.Zero) return;
             var _unmanaged = new Unmanaged();
             _unmanaged._ptr = _ptr;
-            _unmanaged._len = _len;
-            _unmanaged._capacity = _capacity;
-            InteropHelper.interoptopus_vec_destroy(_unmanaged);
+            InteropHelper.interoptopus_iter_destroy(_unmanaged);
             _ptr = IntPtr.Zero;
-            _len = 0;
-            _capacity = 0;
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
-            return "VecVec3f32 { ... }";
+            return "IterVec3f32 { ... }";
         }
 
 
         public partial class InteropHelper
         {
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_create_1781563852271199874")]
-            internal static partial long interoptopus_vec_create(IntPtr vec, ulong len, out Unmanaged rval);
-            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_vec_destroy_12107015372467426116")]
-            internal static partial long interoptopus_vec_destroy(Unmanaged vec);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_next_12283536038193100041")]
+            internal static partial long interoptopus_iter_next(ref Unmanaged iter, out Vec3f32 rval);
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_iter_destroy_14403488365818035019")]
+            internal static partial long interoptopus_iter_destroy(Unmanaged iter);
         }
 
-        [CustomMarshaller(typeof(VecVec3f32), MarshalMode.Default, typeof(Marshaller))]
+        [CustomMarshaller(typeof(IterVec3f32), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
         [StructLayout(LayoutKind.Sequential)]
         public struct Unmanaged
         {
             internal IntPtr _ptr;
-            internal ulong _len;
-            internal ulong _capacity;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecVec3f32 IntoManaged()
+            public IterVec3f32 IntoManaged()
             {
-                var rval = new VecVec3f32();
-                rval._len = _len;
-                rval._capacity = _capacity;
+                var rval = new IterVec3f32();
                 rval._ptr = _ptr;
                 return rval;
             }
@@ -10366,17 +13635,17 @@ namespace My.Company
 
         public ref struct Marshaller
         {
-            private VecVec3f32 _managed;
+            private IterVec3f32 _managed;
             private Unmanaged _unmanaged;
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public Marshaller(VecVec3f32 managed) { _managed = managed; }
+            public Marshaller(IterVec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public void FromManaged(VecVec3f32 managed) { _managed = managed; }
+            public void FromManaged(IterVec3f32 managed) { _managed = managed; }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
@@ -10385,18 +13654,124 @@ namespace My.Company
             public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-            public VecVec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
+            public IterVec3f32 ToManaged() { return _unmanaged.IntoManaged(); }
 
             [MethodImpl(MethodImplOptions.AggressiveOptimization)]
             public void Free() {}
         }
     }
 
-    public static class VecVec3f32Extensions
+
+    /// A tuple lowered to a struct, fields are named after their position.
+    public partial struct Tuple3_Vec3f32_U8_I64
     {
-        public static VecVec3f32 Vec(this Vec3f32[] s) { return VecVec3f32.From(s); }
+        public Vec3f32 x0;
+        public byte x1;
+        public long x2;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Tuple3_Vec3f32_U8_I64 
+    {
+        public Tuple3_Vec3f32_U8_I64() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0.ToUnmanaged();
+            _unmanaged.x1 = x1;
+            _unmanaged.x2 = x2;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x0 = x0.ToUnmanaged();
+            _unmanaged.x1 = x1;
+            _unmanaged.x2 = x2;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public Vec3f32.Unmanaged x0;
+            public byte x1;
+            public long x2;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Tuple3_Vec3f32_U8_I64 ToManaged()
+            {
+                var _managed = new Tuple3_Vec3f32_U8_I64();
+                _managed.x0 = x0.ToManaged();
+                _managed.x1 = x1;
+                _managed.x2 = x2;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Tuple3_Vec3f32_U8_I64 { ... }";
+        }
+
+        [CustomMarshaller(typeof(Tuple3_Vec3f32_U8_I64), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Tuple3_Vec3f32_U8_I64 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Tuple3_Vec3f32_U8_I64 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Tuple3_Vec3f32_U8_I64 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Tuple3_Vec3f32_U8_I64 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
     }
 
+    public partial struct Tuple3_Vec3f32_U8_I64
+    {
+        public Tuple3_Vec3f32_U8_I64(Vec3f32 x0, byte x1, long x2) { this.x0 = x0; this.x1 = x1; this.x2 = x2; }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator Tuple3_Vec3f32_U8_I64((Vec3f32, byte, long) value) => new(value.Item1, value.Item2, value.Item3);
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static implicit operator (Vec3f32, byte, long)(Tuple3_Vec3f32_U8_I64 value) => value.ToValueTuple();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public (Vec3f32, byte, long) ToValueTuple() => (x0, x1, x2);
+
+        // Allows `var (a, b) = tuple;` just like with a `ValueTuple`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Deconstruct(out Vec3f32 _x0, out byte _x1, out long _x2)
+        {
+            _x0 = x0;
+            _x1 = x1;
+            _x2 = x2;
+        }
+    }
 
 
     public partial class ServiceAsyncBasic : IDisposable
@@ -10695,13 +14070,25 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public OptionEnumPayload ResultOptionEnum()
         {
-            return Interop.service_result_result_option_enum(_context).AsOk();
+            return Interop.service_result_result_option_enum(_context).AsOk();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint ResultSlice(SliceU32 slice, ulong i)
+        {
+            return Interop.service_result_result_slice(_context, slice, i).AsOk();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint ResultStd(uint x)
+        {
+            return Interop.service_result_result_std(_context, x).AsOk();
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
-        public uint ResultSlice(SliceU32 slice, ulong i)
+        public uint ResultStdMapped(uint x)
         {
-            return Interop.service_result_result_slice(_context, slice, i).AsOk();
+            return Interop.service_result_result_std_mapped(_context, x).AsOk();
         }
 
         public IntPtr Context => _context;
@@ -10895,6 +14282,14 @@ namespace My.Company
             return self;
         }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMultipleCtors NewStd(uint some_value)
+        {
+            var self = new ServiceMultipleCtors();
+            self._context = Interop.service_multiple_ctors_new_std(some_value).AsOk();
+            return self;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
@@ -10987,6 +14382,20 @@ namespace My.Company
             return Interop.service_various_slices_return_slice_mut(_context);
         }
 
+        /// Hands out a copy of the data lazily, so the returned iterator stays valid even if the
+        /// service is used again.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IterU32 ReturnIter()
+        {
+            return Interop.service_various_slices_return_iter(_context);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Tuple2_U64_U32 ReturnLenAndFirst()
+        {
+            return Interop.service_various_slices_return_len_and_first(_context);
+        }
+
         public IntPtr Context => _context;
     }
 
@@ -11033,6 +14442,24 @@ namespace My.Company
             return Interop.service_strings_return_cstr(_context);
         }
 
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint PassStr(Utf8Str s)
+        {
+            return Interop.service_strings_pass_str(_context, s);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint PassStr(ReadOnlySpan<byte> s)
+        {
+            return Interop.service_strings_pass_str(_context, s);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint PassStr(string s)
+        {
+            return Interop.service_strings_pass_str(_context, s);
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackString(Utf8String s, StringCallback cb)
         {
@@ -11049,6 +14476,351 @@ namespace My.Company
     }
 
 
+    /// Some key-value storage shared by multiple services.
+    public partial interface IStorage
+    {
+        DynStorage AsStorage();
+        /// Returns the value stored at `key`.
+        uint Get(uint key);
+        /// Stores `val` at `key`.
+        void Set(uint key, uint val);
+    }
+
+
+    /// Storage backed by a fixed size array.
+    public partial class ServiceStorageArray : IDisposable, IStorage
+    {
+        private IntPtr _context;
+
+        private ServiceStorageArray() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceStorageArray New()
+        {
+            var self = new ServiceStorageArray();
+            self._context = Interop.service_storage_array_new().AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_storage_array_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public DynStorage AsStorage()
+        {
+            return Interop.service_storage_array_as_storage(_context);
+        }
+
+        /// Returns the value stored at `key`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Get(uint key)
+        {
+            return Interop.storage_get(AsStorage(), key);
+        }
+
+        /// Stores `val` at `key`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Set(uint key, uint val)
+        {
+            Interop.storage_set(AsStorage(), key, val);
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+    /// Storage adding a fixed offset to all values it returns.
+    public partial class ServiceStorageOffset : IDisposable, IStorage
+    {
+        private IntPtr _context;
+
+        private ServiceStorageOffset() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceStorageOffset New(uint offset)
+        {
+            var self = new ServiceStorageOffset();
+            self._context = Interop.service_storage_offset_new(offset).AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_storage_offset_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
+
+        /// Services can provide interface methods themselves.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Get(uint key)
+        {
+            return Interop.service_storage_offset_get(_context, key);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Offset()
+        {
+            return Interop.service_storage_offset_offset(_context);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public DynStorage AsStorage()
+        {
+            return Interop.service_storage_offset_as_storage(_context);
+        }
+
+        /// Stores `val` at `key`.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Set(uint key, uint val)
+        {
+            Interop.storage_set(AsStorage(), key, val);
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+    /// Service holding on to a foreign implementation.
+    public partial class ServiceForeignLogger : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceForeignLogger() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceForeignLogger New(LogSinkVTable sink)
+        {
+            var self = new ServiceForeignLogger();
+            self._context = Interop.service_foreign_logger_new(sink).AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_foreign_logger_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Log(uint x)
+        {
+            Interop.service_foreign_logger_log(_context, x).AsOk();
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+    /// A sink implemented in a foreign language receiving log messages.
+    public abstract partial class LogSinkBase
+    {
+        private LogSinkVTable _vtable;
+
+        protected LogSinkBase()
+        {
+            _vtable = new LogSinkVTable
+            {
+                log = new LogSinkLog(Log),
+                count = new LogSinkCount(Count),
+            };
+        }
+
+        /// Returns a vtable forwarding to this instance, valid as long as this instance is alive.
+        public LogSinkVTable VTable => _vtable;
+
+        /// Called for each logged value.
+        public abstract void Log(uint level, uint x);
+
+        /// Returns how many values the sink has seen.
+        public abstract uint Count();
+    }
+
+
+    /// Service exposing some of its methods as properties.
+    public partial class ServiceProperties : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceProperties() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceProperties New()
+        {
+            var self = new ServiceProperties();
+            self._context = Interop.service_properties_new().AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_properties_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint GetValue()
+        {
+            return Interop.service_properties_get_value(_context);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void SetValue(uint x)
+        {
+            Interop.service_properties_set_value(_context, x);
+        }
+
+        /// Properties can also be read-only.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint GetUpdates()
+        {
+            return Interop.service_properties_get_updates(_context);
+        }
+
+        public uint Value
+        {
+            get => GetValue();
+            set => SetValue(value);
+        }
+
+        public uint Updates
+        {
+            get => GetUpdates();
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+    /// Service with static helpers next to its constructor.
+    public partial class ServiceStatics : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceStatics() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceStatics NewWith(uint scale)
+        {
+            var self = new ServiceStatics();
+            self._context = Interop.service_statics_new_with(scale).AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_statics_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Scale(uint x)
+        {
+            return Interop.service_statics_scale(_context, x);
+        }
+
+        /// The scale used by default, not tied to any instance.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint DefaultScale()
+        {
+            return Interop.service_statics_default_scale();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static uint Scaled(uint x, uint scale)
+        {
+            return Interop.service_statics_scaled(x, scale);
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+    /// Service guarded against use from other threads.
+    /// This service must only be used from the thread that created it.
+    public partial class ServiceThreadAffine : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceThreadAffine() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceThreadAffine New()
+        {
+            var self = new ServiceThreadAffine();
+            self._context = Interop.service_thread_affine_new().AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_thread_affine_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Increment()
+        {
+            Interop.service_thread_affine_increment(_context).AsOk();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Value()
+        {
+            return Interop.service_thread_affine_value(_context);
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+    /// Service guarded against concurrent use.
+    /// Calls into this service are serialized by a lock.
+    public partial class ServiceMutex : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceMutex() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMutex New()
+        {
+            var self = new ServiceMutex();
+            self._context = Interop.service_mutex_new().AsOk();
+            return self;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Interop.service_mutex_destroy(_context).AsOk();
+            _context = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Increment()
+        {
+            return Interop.service_mutex_increment(_context);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public uint Value()
+        {
+            return Interop.service_mutex_value(_context);
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
 
 
     public partial class WireInterop {